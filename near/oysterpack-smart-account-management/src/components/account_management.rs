@@ -9,12 +9,13 @@ use oysterpack_smart_near::near_sdk::{
     borsh::{BorshDeserialize, BorshSerialize},
     env,
     json_types::ValidAccountId,
-    Promise,
+    AccountId, Promise,
 };
 use oysterpack_smart_near::{
-    asserts::{assert_min_near_attached, assert_yocto_near_attached},
-    domain::YoctoNear,
-    eventbus, ErrCode, ErrorConst,
+    asserts::{assert_min_near_attached, assert_yocto_near_attached, InsufficientFunds},
+    data::numbers::U64,
+    domain::{BlockHeight, YoctoNear},
+    eventbus, ErrCode, ErrorConst, Hash,
 };
 use std::{fmt::Debug, ops::Deref};
 
@@ -22,6 +23,7 @@ use crate::components::account_repository::AccountRepositoryComponent;
 use crate::components::account_storage_usage::AccountStorageUsageComponent;
 use oysterpack_smart_near::asserts::{assert_account_not_predecessor, ERR_INVALID};
 use oysterpack_smart_near::component::Deploy;
+use oysterpack_smart_near::deposit_policy::{assert_deposit_policy, Method};
 use oysterpack_smart_near::domain::StorageUsage;
 use std::collections::HashMap;
 use std::marker::PhantomData;
@@ -36,6 +38,12 @@ pub const ERR_INSUFFICIENT_STORAGE_BALANCE: ErrorConst = ErrorConst(
 /// 2. [`StorageManagement`] - NEP-145
 /// 3. [`AccountStorageUsage`]
 /// 4. [`PermissionsManagement`]
+/// 5. [`AdminApprovalsManagement`]
+/// 6. [`StorageSponsorship`]
+/// 7. [`StorageWithdrawal`]
+/// 8. [`AccountProfiles`]
+/// 9. [`AccountMetricsHistory`]
+/// 10. [`StorageUsageBoundsManagement`]
 ///
 /// ## Deployment
 /// - [`AccountManagementComponent::deploy`]
@@ -109,6 +117,28 @@ where
         storage_usage.into()
     }
 
+    /// computes the account storage usage bounds for the specified config
+    /// - if the storage usage bounds are not specified, then the min is measured and max is unbounded
+    /// - the component account storage mins are added to the min
+    ///
+    /// Used at deployment and can be used to recompute the bounds after a contract upgrade that
+    /// enlarges account records - see [`StorageUsageBoundsManagement`]
+    pub fn storage_usage_bounds(config: &AccountManagementComponentConfig) -> StorageUsageBounds {
+        let mut storage_usage_bounds =
+            config
+                .storage_usage_bounds
+                .unwrap_or_else(|| StorageUsageBounds {
+                    min: Self::measure_storage_usage(Default::default()),
+                    max: None,
+                });
+        if let Some(funcs) = config.component_account_storage_mins.as_ref() {
+            storage_usage_bounds.min = funcs
+                .iter()
+                .fold(storage_usage_bounds.min, |sum, f| sum + f());
+        }
+        storage_usage_bounds
+    }
+
     pub fn account_metrics() -> AccountMetrics {
         AccountMetrics::load()
     }
@@ -126,6 +156,7 @@ where
                 let account = AccountNearDataObject::new(account_id, storage_balance_bounds.min);
                 let storage_balance = account.storage_balance(storage_balance_bounds.min);
                 account.save();
+                AccountIndex::add(account_id);
                 eventbus::post(&AccountStorageEvent::Registered(storage_balance));
             }
             // the account storage usage is updated by the storage event handler - thus the object state
@@ -143,6 +174,7 @@ where
             let account = AccountNearDataObject::new(account_id, storage_balance_bounds.min);
             let storage_balance = account.storage_balance(storage_balance_bounds.min);
             account.save();
+            AccountIndex::add(account_id);
             eventbus::post(&AccountStorageEvent::Registered(storage_balance));
         }
     }
@@ -155,29 +187,9 @@ where
     type Config = AccountManagementComponentConfig;
 
     fn deploy(config: Self::Config) {
-        // configure storage usage bounds
-        {
-            let mut storage_usage_bounds =
-                config
-                    .storage_usage_bounds
-                    .unwrap_or_else(|| StorageUsageBounds {
-                        min: Self::measure_storage_usage(Default::default()),
-                        max: None,
-                    });
-
-            let storage_usage_bounds =
-                config
-                    .component_account_storage_mins
-                    .map_or(storage_usage_bounds, |funcs| {
-                        let account_storage_min: StorageUsage = funcs
-                            .iter()
-                            .fold(storage_usage_bounds.min, |sum, f| (sum + f()));
-                        storage_usage_bounds.min = account_storage_min;
-                        storage_usage_bounds
-                    });
+        AccountStorageUsageComponent::deploy(Self::storage_usage_bounds(&config));
 
-            AccountStorageUsageComponent::deploy(storage_usage_bounds);
-        }
+        AccountMetrics::init_history();
 
         // create admin account
         {
@@ -274,6 +286,30 @@ where
     }
 }
 
+impl<T> AccountManagementComponent<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default + 'static,
+{
+    /// Unregisters the account, which deletes the account and publishes the same events as
+    /// [`StorageManagement::storage_unregister`]
+    /// - used by contracts to unregister accounts on the account's behalf
+    /// - the caller is responsible for refunding the account's NEAR balance
+    ///
+    /// Returns the account's NEAR balance, or None if the account is not registered
+    pub fn unregister_account(&mut self, account_id: &str, force: bool) -> Option<YoctoNear> {
+        self.load_account_near_data(account_id).map(|account| {
+            let account_near_balance = account.near_balance();
+            eventbus::post(&StorageManagementEvent::PreUnregister {
+                account_id: account_id.to_string(),
+                force,
+            });
+            self.delete_account(account_id);
+            eventbus::post(&AccountStorageEvent::Unregistered(account_near_balance));
+            account_near_balance
+        })
+    }
+}
+
 impl<T> StorageManagement for AccountManagementComponent<T>
 where
     T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default + 'static,
@@ -320,49 +356,32 @@ where
         account.storage_balance(storage_balance_bounds.min)
     }
 
+    fn storage_deposit_many(&mut self, accounts: Vec<ValidAccountId>) -> Vec<StorageBalance> {
+        self.register_accounts_registration_only(accounts.clone());
+        let storage_balance_min = self.storage_balance_bounds().min;
+        accounts
+            .iter()
+            .map(|account_id| {
+                self.registered_account_near_data(account_id.as_ref())
+                    .storage_balance(storage_balance_min)
+            })
+            .collect()
+    }
+
     fn storage_withdraw(&mut self, amount: Option<YoctoNear>) -> StorageBalance {
         assert_yocto_near_attached();
-
-        let account_id = env::predecessor_account_id();
-        let mut account = self.registered_account_near_data(&account_id);
-        let storage_balance_bounds = self.storage_balance_bounds();
-        let account_available_balance = account
-            .storage_balance(storage_balance_bounds.min)
-            .available;
-        match amount {
-            Some(amount) => {
-                if amount > YoctoNear::ZERO {
-                    ERR_INSUFFICIENT_STORAGE_BALANCE.assert(|| account_available_balance >= amount);
-                    send_refund(amount + 1);
-                    account.decr_near_balance(amount);
-                    account.save();
-                }
-            }
-            None => {
-                // withdraw the total available balance
-                if account_available_balance > YoctoNear::ZERO {
-                    send_refund(account_available_balance + 1);
-                    account.decr_near_balance(account_available_balance);
-                    account.save();
-                }
-            }
+        let (amount, storage_balance) = self.withdraw_available_storage_balance(amount);
+        if amount > YoctoNear::ZERO {
+            send_refund(amount + 1);
         }
-
-        account.storage_balance(storage_balance_bounds.min)
+        storage_balance
     }
 
     fn storage_unregister(&mut self, force: Option<bool>) -> bool {
         assert_yocto_near_attached();
         let account_id = env::predecessor_account_id();
-        self.load_account_near_data(&account_id)
-            .map_or(false, |account| {
-                let account_near_balance = account.near_balance();
-                eventbus::post(&StorageManagementEvent::PreUnregister {
-                    account_id: account_id.clone(),
-                    force: force.unwrap_or(false),
-                });
-                self.delete_account(&account_id);
-                eventbus::post(&AccountStorageEvent::Unregistered(account_near_balance));
+        self.unregister_account(&account_id, force.unwrap_or(false))
+            .map_or(false, |account_near_balance| {
                 send_refund(account_near_balance + 1);
                 true
             })
@@ -390,27 +409,42 @@ where
     }
 
     fn ops_permissions_grant_admin(&mut self, account_id: ValidAccountId) {
+        assert_deposit_policy(Method::OpsPermissionsGrantAdmin);
         assert_account_not_predecessor(account_id.as_ref());
         self.assert_predecessor_is_admin();
 
         let mut account = self.registered_account_near_data(account_id.as_ref());
         if !account.is_admin() {
+            let args = account_id.as_ref().try_to_vec().unwrap();
+            if !AdminApprovals::check("ops_permissions_grant_admin", &args) {
+                return;
+            }
             account.grant_admin();
             account.save();
-            LOG_EVENT_PERMISSIONS_GRANT.log("admin")
+            LOG_EVENT_PERMISSIONS_GRANT.log("admin");
+            PermissionsAuditLog::record(PermissionsAuditEvent::Grant {
+                account_id: account_id.into(),
+                permissions: vec!["admin".to_string()],
+            });
         }
     }
 
     fn ops_permissions_revoke_admin(&mut self, account_id: ValidAccountId) {
+        assert_deposit_policy(Method::OpsPermissionsRevokeAdmin);
         assert_account_not_predecessor(account_id.as_ref());
         self.assert_predecessor_is_admin();
 
         let mut account = self.registered_account_near_data(account_id.as_ref());
         if account.is_admin() {
+            AdminApprovals::assert_admin_quorum(AdminApprovals::admin_count().saturating_sub(1));
             account.revoke_admin();
             Self::clear_permissions_if_has_no_permissions(&mut account);
             account.save();
-            LOG_EVENT_PERMISSIONS_REVOKE.log("admin")
+            LOG_EVENT_PERMISSIONS_REVOKE.log("admin");
+            PermissionsAuditLog::record(PermissionsAuditEvent::Revoke {
+                account_id: account_id.into(),
+                permissions: vec!["admin".to_string()],
+            });
         }
     }
 
@@ -420,6 +454,7 @@ where
     }
 
     fn ops_permissions_grant_operator(&mut self, account_id: ValidAccountId) {
+        assert_deposit_policy(Method::OpsPermissionsGrantOperator);
         assert_account_not_predecessor(account_id.as_ref());
         self.assert_predecessor_is_admin();
 
@@ -427,11 +462,16 @@ where
         if !account.is_operator() {
             account.grant_operator();
             account.save();
-            LOG_EVENT_PERMISSIONS_GRANT.log("operator")
+            LOG_EVENT_PERMISSIONS_GRANT.log("operator");
+            PermissionsAuditLog::record(PermissionsAuditEvent::Grant {
+                account_id: account_id.into(),
+                permissions: vec!["operator".to_string()],
+            });
         }
     }
 
     fn ops_permissions_revoke_operator(&mut self, account_id: ValidAccountId) {
+        assert_deposit_policy(Method::OpsPermissionsRevokeOperator);
         assert_account_not_predecessor(account_id.as_ref());
         self.assert_predecessor_is_admin();
 
@@ -440,11 +480,16 @@ where
             account.revoke_operator();
             Self::clear_permissions_if_has_no_permissions(&mut account);
             account.save();
-            LOG_EVENT_PERMISSIONS_REVOKE.log("operator")
+            LOG_EVENT_PERMISSIONS_REVOKE.log("operator");
+            PermissionsAuditLog::record(PermissionsAuditEvent::Revoke {
+                account_id: account_id.into(),
+                permissions: vec!["operator".to_string()],
+            });
         }
     }
 
     fn ops_permissions_grant(&mut self, account_id: ValidAccountId, permissions: Permissions) {
+        assert_deposit_policy(Method::OpsPermissionsGrant);
         self.assert_contract_supports_permissions(permissions);
         assert_account_not_predecessor(account_id.as_ref());
         self.assert_predecessor_is_admin();
@@ -453,11 +498,15 @@ where
         if !account.contains_permissions(permissions) {
             account.grant(permissions);
             account.save();
-            LOG_EVENT_PERMISSIONS_GRANT.log(format!(
-                "{:?}",
-                self.contract_permissions.permission_names(permissions)
-            ));
+            let permission_names = self.contract_permissions.permission_names(permissions);
+            LOG_EVENT_PERMISSIONS_GRANT.log(format!("{:?}", permission_names));
+            PermissionsAuditLog::record(PermissionsAuditEvent::Grant {
+                account_id: account_id.into(),
+                permissions: permission_names,
+            });
         }
+        // permanent grants override any previous expiry
+        Self::update_permission_expiries(&account, |expiries| expiries.remove(permissions));
     }
 
     fn ops_permissions_grant_permissions(
@@ -465,13 +514,45 @@ where
         account_id: ValidAccountId,
         permissions: Vec<u8>,
     ) {
+        assert_deposit_policy(Method::OpsPermissionsGrantPermissions);
         let permissions = permissions
             .iter()
             .fold(0_u64, |permissions, perm_bit| permissions | 1 << *perm_bit);
         self.ops_permissions_grant(account_id, permissions.into());
     }
 
+    fn ops_permissions_grant_with_expiry(
+        &mut self,
+        account_id: ValidAccountId,
+        permissions: Permissions,
+        expires_at: BlockHeight,
+    ) {
+        assert_deposit_policy(Method::OpsPermissionsGrantWithExpiry);
+        ERR_INVALID.assert(
+            || expires_at > BlockHeight::from_env(),
+            || "expiry must be a future block height",
+        );
+        self.assert_contract_supports_permissions(permissions);
+        assert_account_not_predecessor(account_id.as_ref());
+        self.assert_predecessor_is_admin();
+
+        let mut account = self.registered_account_near_data(account_id.as_ref());
+        account.grant(permissions);
+        account.save();
+        Self::update_permission_expiries(&account, |expiries| {
+            expiries.set(permissions, expires_at)
+        });
+        let permission_names = self.contract_permissions.permission_names(permissions);
+        LOG_EVENT_PERMISSIONS_GRANT
+            .log(format!("{:?} expires_at={}", permission_names, expires_at));
+        PermissionsAuditLog::record(PermissionsAuditEvent::Grant {
+            account_id: account_id.into(),
+            permissions: permission_names,
+        });
+    }
+
     fn ops_permissions_revoke(&mut self, account_id: ValidAccountId, permissions: Permissions) {
+        assert_deposit_policy(Method::OpsPermissionsRevoke);
         self.assert_contract_supports_permissions(permissions);
         assert_account_not_predecessor(account_id.as_ref());
         self.assert_predecessor_is_admin();
@@ -481,10 +562,13 @@ where
             account.revoke(permissions);
             Self::clear_permissions_if_has_no_permissions(&mut account);
             account.save();
-            LOG_EVENT_PERMISSIONS_REVOKE.log(format!(
-                "{:?}",
-                self.contract_permissions.permission_names(permissions)
-            ));
+            Self::update_permission_expiries(&account, |_| {});
+            let permission_names = self.contract_permissions.permission_names(permissions);
+            LOG_EVENT_PERMISSIONS_REVOKE.log(format!("{:?}", permission_names));
+            PermissionsAuditLog::record(PermissionsAuditEvent::Revoke {
+                account_id: account_id.into(),
+                permissions: permission_names,
+            });
         }
     }
 
@@ -493,6 +577,7 @@ where
         account_id: ValidAccountId,
         permissions: Vec<u8>,
     ) {
+        assert_deposit_policy(Method::OpsPermissionsRevokePermissions);
         let permissions = permissions
             .iter()
             .fold(0_u64, |permissions, perm_bit| permissions | 1 << perm_bit);
@@ -500,13 +585,74 @@ where
     }
 
     fn ops_permissions_revoke_all(&mut self, account_id: ValidAccountId) {
+        assert_deposit_policy(Method::OpsPermissionsRevokeAll);
         assert_account_not_predecessor(account_id.as_ref());
         self.assert_predecessor_is_admin();
         let mut account = self.registered_account_near_data(account_id.as_ref());
         if account.permissions().is_some() {
+            if account.is_admin() {
+                AdminApprovals::assert_admin_quorum(
+                    AdminApprovals::admin_count().saturating_sub(1),
+                );
+            }
             account.revoke_all();
             account.save();
+            Self::update_permission_expiries(&account, |_| {});
             LOG_EVENT_PERMISSIONS_REVOKE.log("all permissions were revoked");
+            PermissionsAuditLog::record(PermissionsAuditEvent::Revoke {
+                account_id: account_id.into(),
+                permissions: vec!["all".to_string()],
+            });
+        }
+    }
+
+    fn ops_permissions_grant_many(&mut self, grants: Vec<(ValidAccountId, Permissions)>) {
+        assert_deposit_policy(Method::OpsPermissionsGrantMany);
+        self.assert_permissions_batch(&grants);
+
+        let mut granted = Vec::with_capacity(grants.len());
+        for (account_id, permissions) in grants {
+            let mut account = self.registered_account_near_data(account_id.as_ref());
+            if !account.contains_permissions(permissions) {
+                account.grant(permissions);
+                account.save();
+                let permission_names = self.batch_permission_names(permissions);
+                granted.push(format!("{}={:?}", account_id.as_ref(), permission_names));
+                PermissionsAuditLog::record(PermissionsAuditEvent::Grant {
+                    account_id: account_id.into(),
+                    permissions: permission_names,
+                });
+            }
+            // permanent grants override any previous expiry
+            Self::update_permission_expiries(&account, |expiries| expiries.remove(permissions));
+        }
+        if !granted.is_empty() {
+            LOG_EVENT_PERMISSIONS_GRANT_MANY.log(granted.join(", "));
+        }
+    }
+
+    fn ops_permissions_revoke_many(&mut self, revokes: Vec<(ValidAccountId, Permissions)>) {
+        assert_deposit_policy(Method::OpsPermissionsRevokeMany);
+        self.assert_permissions_batch(&revokes);
+
+        let mut revoked = Vec::with_capacity(revokes.len());
+        for (account_id, permissions) in revokes {
+            let mut account = self.registered_account_near_data(account_id.as_ref());
+            if account.permissions().is_some() {
+                account.revoke(permissions);
+                Self::clear_permissions_if_has_no_permissions(&mut account);
+                account.save();
+                Self::update_permission_expiries(&account, |_| {});
+                let permission_names = self.batch_permission_names(permissions);
+                revoked.push(format!("{}={:?}", account_id.as_ref(), permission_names));
+                PermissionsAuditLog::record(PermissionsAuditEvent::Revoke {
+                    account_id: account_id.into(),
+                    permissions: permission_names,
+                });
+            }
+        }
+        if !revoked.is_empty() {
+            LOG_EVENT_PERMISSIONS_REVOKE_MANY.log(revoked.join(", "));
         }
     }
 
@@ -525,19 +671,32 @@ where
             .flatten()
     }
 
-    fn ops_permissions_granted(&self, account_id: ValidAccountId) -> Option<HashMap<u8, String>> {
+    fn ops_permissions_granted(
+        &self,
+        account_id: ValidAccountId,
+    ) -> Option<HashMap<u8, PermissionGrant>> {
+        let expiries = PermissionExpiries::load(&account_id).unwrap_or_default();
         self.ops_permissions(account_id).map(|perms| {
             let mut account_perms = HashMap::with_capacity(self.contract_permissions.0.len() + 2);
+            let mut grant = |perm_bit: u8, name: &str| {
+                account_perms.insert(
+                    perm_bit,
+                    PermissionGrant {
+                        name: name.to_string(),
+                        expires_at: expiries.expires_at(perm_bit),
+                    },
+                );
+            };
             for (perm_bit, name) in self.contract_permissions.0.iter() {
                 if perms.contains(1 << *perm_bit) {
-                    account_perms.insert(*perm_bit, name.to_string());
+                    grant(*perm_bit, name);
                 }
             }
             if perms.contains(Permissions::ADMIN) {
-                account_perms.insert(63, "admin".to_string());
+                grant(63, "admin");
             }
             if perms.contains(Permissions::OPERATOR) {
-                account_perms.insert(62, "operator".to_string());
+                grant(62, "operator");
             }
 
             account_perms
@@ -554,6 +713,321 @@ where
         }
         Some(perms)
     }
+
+    fn ops_permissions_audit_log(&self, from: U64, limit: u16) -> Vec<PermissionsAuditLogEntry> {
+        PermissionsAuditLog::entries(*from, limit)
+    }
+}
+
+impl<T> AdminApprovalsManagement for AccountManagementComponent<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default + 'static,
+{
+    fn ops_approvals_policy(&self) -> Option<ApprovalPolicy> {
+        AdminApprovals::policy()
+    }
+
+    fn ops_approvals_set_policy(&mut self, required_approvals: Option<u8>) {
+        self.assert_predecessor_is_admin();
+        ERR_INVALID.assert(
+            || required_approvals != Some(0),
+            || "required_approvals must be > 0",
+        );
+        if let Some(required_approvals) = required_approvals {
+            ERR_INVALID.assert(
+                || required_approvals as u32 <= AdminApprovals::admin_count(),
+                || {
+                    format!(
+                        "required_approvals ({}) must be <= the number of admins ({})",
+                        required_approvals,
+                        AdminApprovals::admin_count()
+                    )
+                },
+            );
+        }
+        let args = required_approvals.try_to_vec().unwrap();
+        if !AdminApprovals::check("ops_approvals_set_policy", &args) {
+            return;
+        }
+
+        match required_approvals {
+            None => {
+                AdminApprovals::set_policy(None);
+                LOG_EVENT_APPROVAL_POLICY_UPDATED.log("cleared");
+            }
+            Some(required_approvals) => {
+                AdminApprovals::set_policy(Some(ApprovalPolicy { required_approvals }));
+                LOG_EVENT_APPROVAL_POLICY_UPDATED
+                    .log(format!("required_approvals={}", required_approvals));
+            }
+        }
+    }
+
+    fn ops_approvals_pending(&self) -> Vec<PendingApproval> {
+        AdminApprovals::pending()
+    }
+
+    fn ops_approvals_approve(&mut self, operation_id: Hash) -> PendingApproval {
+        AdminApprovals::approve(operation_id)
+    }
+
+    fn ops_approvals_cancel(&mut self, operation_id: Hash) -> bool {
+        let cancelled = AdminApprovals::cancel(operation_id);
+        if cancelled {
+            LOG_EVENT_APPROVAL_CANCELLED.log(format!("operation_id={}", operation_id));
+        }
+        cancelled
+    }
+}
+
+impl<T> StorageWithdrawal for AccountManagementComponent<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default + 'static,
+{
+    fn ops_storage_withdraw_to(
+        &mut self,
+        receiver_id: ValidAccountId,
+        amount: Option<YoctoNear>,
+    ) -> StorageBalance {
+        assert_yocto_near_attached();
+        let (amount, storage_balance) = self.withdraw_available_storage_balance(amount);
+        if amount > YoctoNear::ZERO {
+            Promise::new(receiver_id.as_ref().to_string()).transfer(*amount + 1);
+            LOG_EVENT_STORAGE_WITHDRAWAL.log(format!(
+                "account_id={}, receiver_id={}, amount={}",
+                env::predecessor_account_id(),
+                receiver_id.as_ref(),
+                amount
+            ));
+        }
+        storage_balance
+    }
+}
+
+impl<T> AccountManagementComponent<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default + 'static,
+{
+    /// debits the specified amount from the predecessor account's available storage balance
+    /// - if amount is not specified, then the total available balance is debited
+    ///
+    /// Returns the amount that was debited along with the account's updated storage balance
+    ///
+    /// ## Panics
+    /// - if the predecessor account is not registered
+    /// - if the account has insufficient available storage balance
+    fn withdraw_available_storage_balance(
+        &mut self,
+        amount: Option<YoctoNear>,
+    ) -> (YoctoNear, StorageBalance) {
+        let account_id = env::predecessor_account_id();
+        let mut account = self.registered_account_near_data(&account_id);
+        let storage_balance_bounds = self.storage_balance_bounds();
+        let account_available_balance = account
+            .storage_balance(storage_balance_bounds.min)
+            .available;
+        let amount = match amount {
+            Some(amount) => {
+                ERR_INSUFFICIENT_STORAGE_BALANCE.assert_with_data(
+                    || account_available_balance >= amount,
+                    || InsufficientFunds {
+                        required: amount,
+                        available: account_available_balance,
+                    },
+                );
+                amount
+            }
+            // withdraw the total available balance
+            None => account_available_balance,
+        };
+        if amount > YoctoNear::ZERO {
+            account.decr_near_balance(amount);
+            account.save();
+        }
+        (amount, account.storage_balance(storage_balance_bounds.min))
+    }
+}
+
+impl<T> StorageSponsorship for AccountManagementComponent<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default + 'static,
+{
+    fn ops_storage_sponsor_accounts(&mut self, accounts: Vec<ValidAccountId>) -> Vec<AccountId> {
+        let sponsor = self.registered_account_near_data(env::predecessor_account_id().as_str());
+        ERR_NOT_AUTHORIZED.assert(|| sponsor.is_admin() || sponsor.is_operator());
+
+        let (unregistered_accounts, required_deposit) =
+            self.register_accounts_registration_only(accounts);
+
+        LOG_EVENT_STORAGE_SPONSORED.log(format!(
+            "sponsor={}, accounts={}, deposit={}",
+            env::predecessor_account_id(),
+            unregistered_accounts.len(),
+            required_deposit
+        ));
+        unregistered_accounts
+    }
+}
+
+impl<T> StorageUsageBoundsManagement for AccountManagementComponent<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default + 'static,
+{
+    fn ops_storage_usage_bounds_update(
+        &mut self,
+        bounds: StorageUsageBounds,
+    ) -> StorageBalanceBounds {
+        let account = self.registered_account_near_data(env::predecessor_account_id().as_str());
+        ERR_NOT_AUTHORIZED.assert(|| account.is_admin() || account.is_operator());
+
+        let current_bounds = self.ops_storage_usage_bounds();
+        ERR_INVALID.assert(
+            || bounds.min >= current_bounds.min,
+            || {
+                format!(
+                    "storage usage min cannot be lowered: {} -> {}",
+                    current_bounds.min, bounds.min
+                )
+            },
+        );
+        ERR_INVALID.assert(
+            || bounds.max.is_none_or(|max| max >= bounds.min),
+            || "storage usage max must not be less than the min",
+        );
+        AccountStorageUsageComponent::set_storage_usage_bounds(bounds);
+
+        LOG_EVENT_STORAGE_USAGE_BOUNDS_UPDATED.log(format!(
+            "min={}, max={:?}",
+            bounds.min,
+            bounds.max.map(|max| max.value())
+        ));
+        bounds.into()
+    }
+
+    fn ops_storage_top_up_accounts(&mut self, from: u64, limit: u64) -> Vec<AccountId> {
+        assert_deposit_policy(Method::OpsStorageTopUpAccounts);
+        let sponsor = self.registered_account_near_data(env::predecessor_account_id().as_str());
+        ERR_NOT_AUTHORIZED.assert(|| sponsor.is_admin() || sponsor.is_operator());
+
+        let storage_balance_min = self.storage_balance_bounds().min;
+        let accounts: Vec<(AccountId, AccountNearDataObject)> =
+            AccountIndex::account_ids(from, limit)
+                .into_iter()
+                .map(|account_id| {
+                    let account = self.registered_account_near_data(&account_id);
+                    (account_id, account)
+                })
+                .filter(|(_, account)| account.near_balance() < storage_balance_min)
+                .collect();
+
+        let required_deposit = accounts.iter().fold(YoctoNear::ZERO, |sum, (_, account)| {
+            sum + (storage_balance_min - account.near_balance())
+        });
+        assert_min_near_attached(required_deposit);
+        let account_ids: Vec<AccountId> = accounts
+            .into_iter()
+            .map(|(account_id, mut account)| {
+                let shortfall = storage_balance_min - account.near_balance();
+                account.incr_near_balance(shortfall);
+                account.save();
+                account_id
+            })
+            .collect();
+
+        let refund_amount = YoctoNear::from(env::attached_deposit()) - required_deposit;
+        if refund_amount > YoctoNear::ZERO {
+            send_refund(refund_amount);
+        }
+
+        LOG_EVENT_STORAGE_TOPPED_UP.log(format!(
+            "sponsor={}, accounts={}, deposit={}",
+            env::predecessor_account_id(),
+            account_ids.len(),
+            required_deposit
+        ));
+        account_ids
+    }
+
+    fn ops_storage_index_accounts(&mut self, account_ids: Vec<ValidAccountId>) -> u32 {
+        let operator = self.registered_account_near_data(env::predecessor_account_id().as_str());
+        ERR_NOT_AUTHORIZED.assert(|| operator.is_admin() || operator.is_operator());
+
+        let mut count = 0;
+        for account_id in account_ids.iter().map(|account_id| account_id.as_ref()) {
+            ERR_ACCOUNT_NOT_REGISTERED.assert(|| self.account_exists(account_id));
+            if !AccountIndex::contains(account_id) {
+                AccountIndex::add(account_id);
+                count += 1;
+            }
+        }
+
+        LOG_EVENT_ACCOUNTS_INDEXED.log(format!(
+            "operator={}, accounts={}",
+            env::predecessor_account_id(),
+            count
+        ));
+        count
+    }
+}
+
+impl<T> AccountMetricsHistory for AccountManagementComponent<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default + 'static,
+{
+    fn ops_account_metrics_history(&self, limit: Option<u16>) -> Vec<AccountMetricsSnapshot> {
+        AccountMetrics::history(limit.unwrap_or(AccountMetrics::MAX_SNAPSHOTS))
+    }
+}
+
+impl<T> AccountProfiles for AccountManagementComponent<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default + 'static,
+{
+    fn ops_account_profile_set(&mut self, profile: Option<AccountProfile>) {
+        assert_deposit_policy(Method::OpsAccountProfileSet);
+        let account_id = env::predecessor_account_id();
+        let account = self.registered_account_near_data(&account_id);
+
+        match profile {
+            Some(profile) => {
+                if let Some(name) = profile.name.as_ref() {
+                    ERR_INVALID.assert(|| !name.trim().is_empty(), || "name cannot be blank");
+                    ERR_INVALID.assert(
+                        || name.len() <= AccountProfile::MAX_NAME_LEN,
+                        || format!("name max length is {} bytes", AccountProfile::MAX_NAME_LEN),
+                    );
+                }
+                if let Some(url) = profile.url.as_ref() {
+                    ERR_INVALID.assert(|| !url.trim().is_empty(), || "url cannot be blank");
+                    ERR_INVALID.assert(
+                        || url.len() <= AccountProfile::MAX_URL_LEN,
+                        || format!("url max length is {} bytes", AccountProfile::MAX_URL_LEN),
+                    );
+                }
+
+                let storage_cost = profile.save(&account_id).cost();
+                let available = account
+                    .storage_balance(self.storage_balance_bounds().min)
+                    .available;
+                ERR_INSUFFICIENT_STORAGE_BALANCE.assert_with_data(
+                    || available >= storage_cost,
+                    || InsufficientFunds {
+                        required: storage_cost,
+                        available,
+                    },
+                );
+                LOG_EVENT_ACCOUNT_PROFILE.log(format!("account_id={}, action=set", account_id));
+            }
+            None => {
+                AccountProfile::delete(&account_id);
+                LOG_EVENT_ACCOUNT_PROFILE.log(format!("account_id={}, action=cleared", account_id));
+            }
+        }
+    }
+
+    fn ops_account_profile_get(&self, account_id: ValidAccountId) -> Option<AccountProfile> {
+        AccountProfile::load(account_id.as_ref())
+    }
 }
 
 impl<T> AccountManagementComponent<T>
@@ -601,6 +1075,23 @@ where
         }
     }
 
+    /// applies the update to the account's permission expiries and lazily cleans up expiries for
+    /// permissions that have expired or are no longer granted
+    fn update_permission_expiries<F>(account: &AccountNearDataObject, f: F)
+    where
+        F: FnOnce(&mut PermissionExpiries),
+    {
+        let account_id_hash = account.key().account_id_hash();
+        let current = PermissionExpiries::load(account_id_hash);
+        let mut expiries = current.clone().unwrap_or_default();
+        f(&mut expiries);
+        expiries.retain_granted(account.permissions());
+        let changed = current.map_or(!expiries.is_empty(), |current| current != expiries);
+        if changed {
+            expiries.save(account_id_hash);
+        }
+    }
+
     fn assert_contract_supports_permissions(&self, permissions: Permissions) {
         ERR_INVALID.assert(
             || self.contract_permissions.is_supported(permissions),
@@ -608,6 +1099,42 @@ where
         );
     }
 
+    /// validates the batch up front, i.e., before any permissions are changed
+    /// - the operator permission is allowed in batches, but the admin permission is not
+    fn assert_permissions_batch(&self, batch: &[(ValidAccountId, Permissions)]) {
+        ERR_INVALID.assert(
+            || batch.len() <= MAX_PERMISSIONS_BATCH_SIZE,
+            || format!("max batch size is {}", MAX_PERMISSIONS_BATCH_SIZE),
+        );
+        self.assert_predecessor_is_admin();
+        for (account_id, permissions) in batch {
+            assert_account_not_predecessor(account_id.as_ref());
+            ERR_INVALID.assert(
+                || !permissions.contains(Permissions::ADMIN),
+                || "admin permission can not be granted or revoked in a batch",
+            );
+            let mut contract_permissions = *permissions;
+            contract_permissions.revoke(Permissions::OPERATOR);
+            if contract_permissions.has_permissions() {
+                self.assert_contract_supports_permissions(contract_permissions);
+            }
+            ERR_INVALID.assert(
+                || permissions.has_permissions(),
+                || format!("no permissions were specified for: {}", account_id.as_ref()),
+            );
+        }
+    }
+
+    /// includes the operator permission, which is not a contract permission
+    fn batch_permission_names(&self, permissions: Permissions) -> Vec<String> {
+        let mut names = self.contract_permissions.permission_names(permissions);
+        if permissions.contains(Permissions::OPERATOR) {
+            names.push("operator".to_string());
+            names.sort();
+        }
+        names
+    }
+
     fn assert_predecessor_is_admin(&self) {
         let admin = self.registered_account_near_data(env::predecessor_account_id().as_str());
         ERR_NOT_AUTHORIZED.assert(|| admin.is_admin());
@@ -642,6 +1169,40 @@ where
         account.save();
     }
 
+    /// registers the accounts that are not registered with registration only semantics, i.e., each
+    /// account is registered with the min storage balance
+    /// - duplicate and already registered accounts are skipped
+    /// - the attached deposit must cover the min storage balance for each account that is registered
+    ///   and any excess deposit is refunded
+    ///
+    /// Returns the accounts that were registered and the deposit that was applied
+    fn register_accounts_registration_only(
+        &mut self,
+        accounts: Vec<ValidAccountId>,
+    ) -> (Vec<AccountId>, YoctoNear) {
+        let mut unregistered_accounts: Vec<AccountId> = Vec::with_capacity(accounts.len());
+        for account_id in accounts {
+            let account_id: AccountId = account_id.into();
+            if !unregistered_accounts.contains(&account_id) && !self.account_exists(&account_id) {
+                unregistered_accounts.push(account_id);
+            }
+        }
+
+        let storage_balance_min = self.storage_balance_bounds().min;
+        let required_deposit = storage_balance_min * unregistered_accounts.len() as u128;
+        assert_min_near_attached(required_deposit);
+        for account_id in unregistered_accounts.iter() {
+            self.register_account(account_id, storage_balance_min, true);
+        }
+
+        let refund_amount = YoctoNear::from(env::attached_deposit()) - required_deposit;
+        if refund_amount > YoctoNear::ZERO {
+            send_refund(refund_amount);
+        }
+
+        (unregistered_accounts, required_deposit)
+    }
+
     fn initial_deposit(
         deposit: YoctoNear,
         registration_only: bool,
@@ -708,9 +1269,9 @@ mod tests {
     fn deploy(
         account_id: &str,
         config: Option<AccountManagementComponentConfig>,
-    ) -> (VMContext, AccountManager) {
-        let ctx = new_context(account_id);
-        testing_env!(ctx.clone());
+    ) -> (TestCtx, AccountManager) {
+        let mut ctx = TestCtx::new(account_id);
+        ctx.apply();
 
         AccountManager::deploy(config.unwrap_or_else(|| AccountManagementComponentConfig {
             storage_usage_bounds: Some(StorageUsageBounds {
@@ -726,17 +1287,18 @@ mod tests {
 
     #[test]
     fn get_or_register_account() {
-        let (ctx, _account_manager) = deploy(ACCOUNT, None);
-        testing_env!(ctx.clone());
+        let (mut ctx, _account_manager) = deploy(ACCOUNT, None);
+        ctx.apply();
         let _alice = AccountManager::get_or_register_account("alice");
         let logs = test_utils::get_logs();
         println!("{:#?}", logs);
         assert_eq!(logs, vec![
             "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(97)",
+            "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(161)",
             "[INFO] [ACCOUNT_STORAGE_CHANGED] Registered(StorageBalance { total: YoctoNear(10000000000000000000000), available: YoctoNear(0) })",
         ]);
 
-        testing_env!(ctx.clone());
+        ctx.apply();
         let _alice = AccountManager::get_or_register_account("alice");
         let logs = test_utils::get_logs();
         println!("{:#?}", logs);
@@ -745,17 +1307,18 @@ mod tests {
 
     #[test]
     fn register_account_if_not_exists() {
-        let (ctx, _account_manager) = deploy(ACCOUNT, None);
-        testing_env!(ctx.clone());
+        let (mut ctx, _account_manager) = deploy(ACCOUNT, None);
+        ctx.apply();
         AccountManager::register_account_if_not_exists("alice");
         let logs = test_utils::get_logs();
         println!("{:#?}", logs);
         assert_eq!(logs, vec![
             "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(97)",
+            "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(161)",
             "[INFO] [ACCOUNT_STORAGE_CHANGED] Registered(StorageBalance { total: YoctoNear(10000000000000000000000), available: YoctoNear(0) })",
         ]);
 
-        testing_env!(ctx.clone());
+        ctx.apply();
         AccountManager::register_account_if_not_exists("alice");
         let logs = test_utils::get_logs();
         println!("{:#?}", logs);
@@ -767,7 +1330,6 @@ mod tests {
 mod tests_service {
     use super::*;
     use crate::StorageUsageBounds;
-    use oysterpack_smart_near::near_sdk;
     use oysterpack_smart_near_test::*;
 
     pub type AccountManager = AccountManagementComponent<()>;
@@ -780,8 +1342,8 @@ mod tests_service {
     fn deploy_and_use_module() {
         // Arrange
         let account_id = "bob";
-        let ctx = new_context(account_id);
-        testing_env!(ctx);
+        let mut ctx = TestCtx::new(account_id);
+        ctx.apply();
 
         // Act
         AccountManager::deploy(AccountManagementComponentConfig {
@@ -834,8 +1396,8 @@ mod tests_storage_management {
     ) where
         F: FnOnce(AccountManagementComponent<()>, StorageBalance),
     {
-        let mut ctx = new_context(PREDECESSOR_ACCOUNT_ID);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(PREDECESSOR_ACCOUNT_ID);
+        ctx.apply();
 
         AccountMetrics::register_account_storage_event_handler();
         AccountMetrics::reset();
@@ -848,8 +1410,7 @@ mod tests_storage_management {
         println!("storage_balance_bounds = {:?}", storage_balance_bounds);
 
         if already_registered {
-            ctx.attached_deposit = storage_balance_bounds.min.value();
-            testing_env!(ctx.clone());
+            ctx.with_deposit(storage_balance_bounds.min.value()).apply();
             let storage_balance = service.storage_deposit(
                 Some(to_valid_account_id(
                     account_id.unwrap_or(PREDECESSOR_ACCOUNT_ID),
@@ -859,9 +1420,9 @@ mod tests_storage_management {
             println!("registered account: {:?}", storage_balance);
         }
 
-        ctx.attached_deposit = deposit.value();
-        println!("deposit amount = {}", ctx.attached_deposit);
-        testing_env!(ctx.clone());
+        ctx.with_deposit(deposit.value());
+        println!("deposit amount = {}", ctx.context().attached_deposit);
+        ctx.apply();
 
         let storage_balance =
             service.storage_deposit(account_id.map(to_valid_account_id), registration_only);
@@ -949,18 +1510,9 @@ mod tests_storage_management {
                         // Assert overpayment was refunded
                         let receipts = deserialize_receipts();
                         assert_eq!(receipts.len(), 1);
-                        let receipt = &receipts[0];
-                        assert_eq!(&receipt.receiver_id, PREDECESSOR_ACCOUNT_ID);
-                        let action = &receipt.actions[0];
-                        match action {
-                            Action::Transfer(action) => {
-                                assert_eq!(
-                                    action.deposit,
-                                    service.storage_balance_bounds().min.value() * 2
-                                );
-                            }
-                            _ => panic!("expected Transfer"),
-                        }
+                        receipts[0]
+                            .assert_receiver(PREDECESSOR_ACCOUNT_ID)
+                            .assert_transfer(service.storage_balance_bounds().min.value() * 2);
                     },
                 );
             }
@@ -982,33 +1534,27 @@ mod tests_storage_management {
                         // Assert the deposit was refunded
                         let receipts = deserialize_receipts();
                         assert_eq!(receipts.len(), 1);
-                        let receipt = &receipts[0];
-                        assert_eq!(&receipt.receiver_id, PREDECESSOR_ACCOUNT_ID);
-                        let action = &receipt.actions[0];
-                        match action {
-                            Action::Transfer(action) => {
-                                assert_eq!(action.deposit, storage_balance_min().value());
-                            }
-                            _ => panic!("expected Transfer"),
-                        }
+                        receipts[0]
+                            .assert_receiver(PREDECESSOR_ACCOUNT_ID)
+                            .assert_transfer(storage_balance_min().value());
                     },
                 );
             }
 
             #[test]
-            #[should_panic(expected = "[ERR] [INSUFFICIENT_NEAR_DEPOSIT]")]
+            #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
             fn zero_deposit_attached() {
                 run_test(0.into(), false, |_service, _storage_balance| {});
             }
 
             #[test]
-            #[should_panic(expected = "[ERR] [INSUFFICIENT_NEAR_DEPOSIT]")]
+            #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
             fn zero_deposit_attached_already_registered() {
                 run_test(0.into(), true, |_service, _storage_balance| {});
             }
 
             #[test]
-            #[should_panic(expected = "[ERR] [INSUFFICIENT_NEAR_DEPOSIT]")]
+            #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
             fn one_deposit_attached_already_registered() {
                 run_test(1.into(), true, |_service, _storage_balance| {});
             }
@@ -1096,18 +1642,9 @@ mod tests_storage_management {
                         // Assert overpayment was refunded
                         let receipts = deserialize_receipts();
                         assert_eq!(receipts.len(), 1);
-                        let receipt = &receipts[0];
-                        assert_eq!(&receipt.receiver_id, PREDECESSOR_ACCOUNT_ID);
-                        let action = &receipt.actions[0];
-                        match action {
-                            Action::Transfer(action) => {
-                                assert_eq!(
-                                    action.deposit,
-                                    service.storage_balance_bounds().min.value() * 2
-                                );
-                            }
-                            _ => panic!("expected Transfer"),
-                        }
+                        receipts[0]
+                            .assert_receiver(PREDECESSOR_ACCOUNT_ID)
+                            .assert_transfer(service.storage_balance_bounds().min.value() * 2);
                     },
                 );
             }
@@ -1129,33 +1666,27 @@ mod tests_storage_management {
                         // Assert the deposit was refunded
                         let receipts = deserialize_receipts();
                         assert_eq!(receipts.len(), 1);
-                        let receipt = &receipts[0];
-                        assert_eq!(&receipt.receiver_id, PREDECESSOR_ACCOUNT_ID);
-                        let action = &receipt.actions[0];
-                        match action {
-                            Action::Transfer(action) => {
-                                assert_eq!(action.deposit, storage_balance_min().value());
-                            }
-                            _ => panic!("expected Transfer"),
-                        }
+                        receipts[0]
+                            .assert_receiver(PREDECESSOR_ACCOUNT_ID)
+                            .assert_transfer(storage_balance_min().value());
                     },
                 );
             }
 
             #[test]
-            #[should_panic(expected = "[ERR] [INSUFFICIENT_NEAR_DEPOSIT]")]
+            #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
             fn zero_deposit_attached() {
                 run_test(0.into(), false, |_service, _storage_balance| {});
             }
 
             #[test]
-            #[should_panic(expected = "[ERR] [INSUFFICIENT_NEAR_DEPOSIT]")]
+            #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
             fn zero_deposit_attached_already_registered() {
                 run_test(0.into(), true, |_service, _storage_balance| {});
             }
 
             #[test]
-            #[should_panic(expected = "[ERR] [INSUFFICIENT_NEAR_DEPOSIT]")]
+            #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
             fn one_deposit_attached_already_registered() {
                 run_test(1.into(), true, |_service, _storage_balance| {});
             }
@@ -1236,18 +1767,9 @@ mod tests_storage_management {
                         // Assert overpayment was refunded
                         let receipts = deserialize_receipts();
                         assert_eq!(receipts.len(), 1);
-                        let receipt = &receipts[0];
-                        assert_eq!(&receipt.receiver_id, PREDECESSOR_ACCOUNT_ID);
-                        let action = &receipt.actions[0];
-                        match action {
-                            Action::Transfer(action) => {
-                                assert_eq!(
-                                    action.deposit,
-                                    service.storage_balance_bounds().min.value() * 2
-                                );
-                            }
-                            _ => panic!("expected Transfer"),
-                        }
+                        receipts[0]
+                            .assert_receiver(PREDECESSOR_ACCOUNT_ID)
+                            .assert_transfer(service.storage_balance_bounds().min.value() * 2);
                     },
                 );
             }
@@ -1269,33 +1791,27 @@ mod tests_storage_management {
                         // Assert the deposit was refunded
                         let receipts = deserialize_receipts();
                         assert_eq!(receipts.len(), 1);
-                        let receipt = &receipts[0];
-                        assert_eq!(&receipt.receiver_id, PREDECESSOR_ACCOUNT_ID);
-                        let action = &receipt.actions[0];
-                        match action {
-                            Action::Transfer(action) => {
-                                assert_eq!(action.deposit, storage_balance_min().value());
-                            }
-                            _ => panic!("expected Transfer"),
-                        }
+                        receipts[0]
+                            .assert_receiver(PREDECESSOR_ACCOUNT_ID)
+                            .assert_transfer(storage_balance_min().value());
                     },
                 );
             }
 
             #[test]
-            #[should_panic(expected = "[ERR] [INSUFFICIENT_NEAR_DEPOSIT]")]
+            #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
             fn zero_deposit_attached() {
                 run_test(0.into(), false, |_service, _storage_balance| {});
             }
 
             #[test]
-            #[should_panic(expected = "[ERR] [INSUFFICIENT_NEAR_DEPOSIT]")]
+            #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
             fn zero_deposit_attached_already_registered() {
                 run_test(0.into(), true, |_service, _storage_balance| {});
             }
 
             #[test]
-            #[should_panic(expected = "[ERR] [INSUFFICIENT_NEAR_DEPOSIT]")]
+            #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
             fn one_deposit_attached_already_registered() {
                 run_test(1.into(), true, |_service, _storage_balance| {});
             }
@@ -1445,8 +1961,8 @@ mod tests_storage_management {
             fn deposit_with_account_already_maxed_out() {
                 // Arrange
                 let account = "alfio";
-                let mut ctx = new_context(account);
-                testing_env!(ctx.clone());
+                let mut ctx = TestCtx::new(account);
+                ctx.apply();
 
                 AccountManagementComponent::<()>::deploy(AccountManagementComponentConfig {
                     storage_usage_bounds: Some(StorageUsageBounds {
@@ -1459,10 +1975,9 @@ mod tests_storage_management {
 
                 let mut service = AccountManagementComponent::<()>::new(Default::default());
 
-                ctx.attached_deposit = YOCTO;
-                testing_env!(ctx.clone());
+                ctx.with_deposit(YOCTO).apply();
                 let storage_balance_1 = service.storage_deposit(None, None);
-                testing_env!(ctx.clone());
+                ctx.apply();
                 let storage_balance_2 = service.storage_deposit(None, None);
                 assert_eq!(storage_balance_1, storage_balance_2);
                 assert_eq!(
@@ -1501,7 +2016,7 @@ mod tests_storage_management {
             }
 
             #[test]
-            #[should_panic(expected = "[ERR] [INSUFFICIENT_NEAR_DEPOSIT]")]
+            #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
             fn zero_deposit_attached() {
                 run_test(0.into(), false, |_service, _storage_balance| {});
             }
@@ -1708,7 +2223,7 @@ mod tests_storage_management {
             }
 
             #[test]
-            #[should_panic(expected = "[ERR] [INSUFFICIENT_NEAR_DEPOSIT]")]
+            #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
             fn zero_deposit_attached() {
                 run_test(0.into(), false, |_service, _storage_balance| {});
             }
@@ -1906,7 +2421,7 @@ mod tests_storage_management {
             }
 
             #[test]
-            #[should_panic(expected = "[ERR] [INSUFFICIENT_NEAR_DEPOSIT]")]
+            #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
             fn zero_deposit_attached() {
                 run_test(0.into(), false, |_service, _storage_balance| {});
             }
@@ -2031,7 +2546,7 @@ mod tests_storage_management {
             }
 
             #[test]
-            #[should_panic(expected = "[ERR] [INSUFFICIENT_NEAR_DEPOSIT]")]
+            #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
             fn zero_deposit_attached() {
                 run_test(0.into(), false, |_service, _storage_balance| {});
             }
@@ -2152,7 +2667,7 @@ mod tests_storage_management {
             }
 
             #[test]
-            #[should_panic(expected = "[ERR] [INSUFFICIENT_NEAR_DEPOSIT]")]
+            #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
             fn zero_deposit_attached() {
                 run_test(0.into(), false, |_service, _storage_balance| {});
             }
@@ -2195,8 +2710,8 @@ mod tests_storage_management {
         ) where
             F: FnOnce(AccountManagementComponent<()>, StorageBalance),
         {
-            let mut ctx = new_context(PREDECESSOR_ACCOUNT_ID);
-            testing_env!(ctx.clone());
+            let mut ctx = TestCtx::new(PREDECESSOR_ACCOUNT_ID);
+            ctx.apply();
 
             AccountMetrics::register_account_storage_event_handler();
             AccountMetrics::reset();
@@ -2207,13 +2722,11 @@ mod tests_storage_management {
                 AccountManagementComponent::new(Default::default());
 
             if deposit.value() > 0 {
-                ctx.attached_deposit = deposit.value();
-                testing_env!(ctx.clone());
+                ctx.with_deposit(deposit.value()).apply();
                 service.storage_deposit(None, None);
             }
 
-            ctx.attached_deposit = withdraw_deposit.value();
-            testing_env!(ctx.clone());
+            ctx.with_deposit(withdraw_deposit.value()).apply();
             let storage_balance = service.storage_withdraw(withdrawal);
             test(service, storage_balance);
         }
@@ -2322,7 +2835,7 @@ mod tests_storage_management {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+        #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
         fn no_attached_deposit() {
             run_test(
                 STORAGE_USAGE_BOUNDS,
@@ -2334,7 +2847,7 @@ mod tests_storage_management {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+        #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
         fn two_yoctonear_attached() {
             run_test(
                 STORAGE_USAGE_BOUNDS,
@@ -2346,7 +2859,7 @@ mod tests_storage_management {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [INSUFFICIENT_STORAGE_BALANCE]")]
+        #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_STORAGE_BALANCE\""#)]
         fn insufficient_funds() {
             run_test(
                 STORAGE_USAGE_BOUNDS,
@@ -2358,7 +2871,7 @@ mod tests_storage_management {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [ACCOUNT_NOT_REGISTERED]")]
+        #[should_panic(expected = r#"{\"code\":\"ACCOUNT_NOT_REGISTERED\""#)]
         fn account_not_registered() {
             run_test(
                 STORAGE_USAGE_BOUNDS,
@@ -2368,6 +2881,84 @@ mod tests_storage_management {
                 |_service, _storage_balance| {},
             );
         }
+
+        #[test]
+        fn withdraw_to_receiver() {
+            let mut ctx = TestCtx::new(PREDECESSOR_ACCOUNT_ID);
+            ctx.apply();
+            AccountStorageUsageComponent::deploy(STORAGE_USAGE_BOUNDS);
+            let mut service: AccountManager = AccountManagementComponent::new(Default::default());
+
+            ctx.with_deposit(*storage_balance_min() * 2).apply();
+            service.storage_deposit(None, None);
+
+            // Act
+            ctx.with_deposit(1).apply();
+            let storage_balance = service.ops_storage_withdraw_to(
+                to_valid_account_id("cold"),
+                Some(storage_balance_min() / 2),
+            );
+
+            // Assert
+            assert_eq!(storage_balance.available, storage_balance_min() / 2);
+            assert_eq!(
+                service
+                    .storage_balance_of(to_valid_account_id(PREDECESSOR_ACCOUNT_ID))
+                    .unwrap(),
+                storage_balance
+            );
+            let receipts = deserialize_receipts();
+            assert_eq!(receipts.len(), 1);
+            assert_eq!(&receipts[0].receiver_id, "cold");
+            match &receipts[0].actions[0] {
+                Action::Transfer(transfer) => {
+                    assert_eq!(transfer.deposit, storage_balance_min().value() / 2 + 1);
+                }
+                _ => panic!("expected TransferAction"),
+            }
+            let logs = near_sdk::test_utils::get_logs();
+            println!("{:#?}", logs);
+            assert_eq!(
+                logs.last().unwrap(),
+                &format!(
+                    "[INFO] [STORAGE_WITHDRAWAL] account_id={}, receiver_id=cold, amount={}",
+                    PREDECESSOR_ACCOUNT_ID,
+                    storage_balance_min() / 2
+                )
+            );
+
+            // Act - withdraw the remaining available balance
+            ctx.apply();
+            let storage_balance =
+                service.ops_storage_withdraw_to(to_valid_account_id("cold"), None);
+
+            // Assert
+            assert_eq!(storage_balance.total, storage_balance_min());
+            assert_eq!(storage_balance.available, YoctoNear::ZERO);
+            let receipts = deserialize_receipts();
+            assert_eq!(receipts.len(), 1);
+            match &receipts[0].actions[0] {
+                Action::Transfer(transfer) => {
+                    assert_eq!(transfer.deposit, storage_balance_min().value() / 2 + 1);
+                }
+                _ => panic!("expected TransferAction"),
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_STORAGE_BALANCE\""#)]
+        fn withdraw_to_receiver_with_insufficient_funds() {
+            let mut ctx = TestCtx::new(PREDECESSOR_ACCOUNT_ID);
+            ctx.apply();
+            AccountStorageUsageComponent::deploy(STORAGE_USAGE_BOUNDS);
+            let mut service: AccountManager = AccountManagementComponent::new(Default::default());
+
+            ctx.with_deposit(*storage_balance_min()).apply();
+            service.storage_deposit(None, None);
+
+            ctx.with_deposit(1).apply();
+            service.ops_storage_withdraw_to(to_valid_account_id("cold"), Some(1.into()));
+        }
     }
 
     #[cfg(test)]
@@ -2385,8 +2976,8 @@ mod tests_storage_management {
         ) where
             F: FnOnce(AccountManagementComponent<()>, bool),
         {
-            let mut ctx = new_context(PREDECESSOR_ACCOUNT_ID);
-            testing_env!(ctx.clone());
+            let mut ctx = TestCtx::new(PREDECESSOR_ACCOUNT_ID);
+            ctx.apply();
 
             AccountMetrics::register_account_storage_event_handler();
             AccountMetrics::reset();
@@ -2398,13 +2989,11 @@ mod tests_storage_management {
                 AccountManagementComponent::new(Default::default());
 
             if deposit.value() > 0 {
-                ctx.attached_deposit = deposit.value();
-                testing_env!(ctx.clone());
+                ctx.with_deposit(deposit.value()).apply();
                 service.storage_deposit(None, None);
             }
 
-            ctx.attached_deposit = unregister_deposit.value();
-            testing_env!(ctx.clone());
+            ctx.with_deposit(unregister_deposit.value()).apply();
             StorageManagementEvent::clear_event_handlers();
             let result = service.storage_unregister(force);
             test(service, result);
@@ -2491,7 +3080,7 @@ mod tests_storage_management {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+        #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
         fn no_attached_deposit() {
             run_test(
                 STORAGE_USAGE_BOUNDS,
@@ -2503,7 +3092,7 @@ mod tests_storage_management {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+        #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
         fn two_yoctonear_attached() {
             run_test(
                 STORAGE_USAGE_BOUNDS,
@@ -2544,12 +3133,12 @@ mod tests_storage_management {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [UNREGISTER_FAILURE]")]
+        #[should_panic(expected = r#"{\"code\":\"UNREGISTER_FAILURE\""#)]
         fn unregister_panics() {
             // Arrange
             let account = "alfio";
-            let mut ctx = new_context(account);
-            testing_env!(ctx.clone());
+            let mut ctx = TestCtx::new(account);
+            ctx.apply();
 
             AccountManager::deploy(AccountManagementComponentConfig {
                 admin_account: to_valid_account_id("admin"),
@@ -2561,13 +3150,11 @@ mod tests_storage_management {
             });
 
             let mut service = AccountManager::new(Default::default());
-            ctx.attached_deposit = YOCTO;
-            testing_env!(ctx.clone());
+            ctx.with_deposit(YOCTO).apply();
             service.storage_deposit(None, None);
 
             // Act
-            ctx.attached_deposit = 1;
-            testing_env!(ctx.clone());
+            ctx.with_deposit(1).apply();
             eventbus::register(on_unregister_panic);
             service.storage_unregister(None);
         }
@@ -2576,8 +3163,8 @@ mod tests_storage_management {
         fn force_unregister_panics() {
             // Arrange
             let account = "alfio";
-            let mut ctx = new_context(account);
-            testing_env!(ctx.clone());
+            let mut ctx = TestCtx::new(account);
+            ctx.apply();
 
             AccountManager::deploy(AccountManagementComponentConfig {
                 storage_usage_bounds: Some(StorageUsageBounds {
@@ -2590,13 +3177,11 @@ mod tests_storage_management {
             eventbus::register(on_unregister_panic);
 
             let mut service = AccountManager::new(Default::default());
-            ctx.attached_deposit = YOCTO;
-            testing_env!(ctx.clone());
+            ctx.with_deposit(YOCTO).apply();
             service.storage_deposit(None, None);
 
             // Act
-            ctx.attached_deposit = 1;
-            testing_env!(ctx.clone());
+            ctx.with_deposit(1).apply();
             service.storage_unregister(Some(true));
             assert!(service
                 .storage_balance_of(to_valid_account_id(account))
@@ -2608,7 +3193,6 @@ mod tests_storage_management {
 #[cfg(test)]
 mod tests_account_storage_usage {
     use super::*;
-    use oysterpack_smart_near::near_sdk;
     use oysterpack_smart_near::YOCTO;
     use oysterpack_smart_near_test::*;
 
@@ -2617,8 +3201,8 @@ mod tests_account_storage_usage {
     #[test]
     fn test() {
         let account = "alfio";
-        let mut ctx = new_context(account);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(account);
+        ctx.apply();
 
         let storage_usage_bounds = StorageUsageBounds {
             min: AccountManager::measure_storage_usage(()),
@@ -2638,8 +3222,7 @@ mod tests_account_storage_usage {
             .storage_balance_of(to_valid_account_id(account))
             .is_none());
 
-        ctx.attached_deposit = YOCTO;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(YOCTO).apply();
         let storage_balance = service.storage_deposit(None, None);
         assert_eq!(
             service
@@ -2662,7 +3245,7 @@ mod tests_account_storage_usage {
 #[cfg(test)]
 mod tests_account_metrics {
     use super::*;
-    use oysterpack_smart_near::near_sdk::{self, test_utils};
+    use oysterpack_smart_near::near_sdk::test_utils;
     use oysterpack_smart_near::YOCTO;
     use oysterpack_smart_near_test::*;
 
@@ -2673,8 +3256,8 @@ mod tests_account_metrics {
         StorageManagementEvent::clear_event_handlers();
         // Arrange - 0 accounts register
         let account = "alfio";
-        let mut ctx = new_context(account);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(account);
+        ctx.apply();
 
         let metrics = AccountManager::account_metrics();
         println!("{:?}", metrics);
@@ -2685,6 +3268,11 @@ mod tests_account_metrics {
         let metrics = AccountManager::account_metrics();
         println!("{:?}", metrics);
         println!("measured storage_usage_bounds = {:?}", storage_usage_bounds);
+        // the account index entry stores the account ID, and the storage usage min is measured
+        // using a 37 char account ID
+        let account_storage_usage = |account_id: &str| {
+            StorageUsage::from(storage_usage_bounds.min.value() - (37 - account_id.len() as u64))
+        };
 
         let metrics = AccountManager::account_metrics();
         println!("before deploy: {:?}", metrics);
@@ -2708,8 +3296,7 @@ mod tests_account_metrics {
         assert_eq!(metrics.total_storage_usage, admin_account.storage_usage());
 
         // Arrange - register account
-        ctx.attached_deposit = YOCTO;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(YOCTO).apply();
         let storage_balance = service.storage_deposit(None, None);
         let account_data = service.load_account_data(account);
         assert!(account_data.is_none());
@@ -2729,12 +3316,11 @@ mod tests_account_metrics {
         );
         assert_eq!(
             metrics.total_storage_usage,
-            storage_usage_bounds.min + admin_account.storage_usage()
+            account_storage_usage(account) + admin_account.storage_usage()
         );
 
         // Arrange - deposit more funds
-        ctx.attached_deposit = YOCTO;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(YOCTO).apply();
         let storage_balance = service.storage_deposit(None, None);
         // Act
         let metrics = AccountManager::account_metrics();
@@ -2746,12 +3332,11 @@ mod tests_account_metrics {
         );
         assert_eq!(
             metrics.total_storage_usage,
-            storage_usage_bounds.min + admin_account.storage_usage()
+            account_storage_usage(account) + admin_account.storage_usage()
         );
 
         // Arrange - register another account
-        ctx.attached_deposit = YOCTO;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(YOCTO).apply();
         let bob_storage_balance = service.storage_deposit(Some(to_valid_account_id("bob")), None);
         let mut account_data: AccountDataObject<()> = AccountDataObject::new("bob", ());
         account_data.save();
@@ -2767,13 +3352,14 @@ mod tests_account_metrics {
             storage_balance.total + bob_storage_balance.total + admin_account.near_balance()
         );
         assert_eq!(
-            metrics.total_storage_usage.value(),
-            (storage_usage_bounds.min.value() * 2) + admin_account.storage_usage().value()
+            metrics.total_storage_usage,
+            account_storage_usage(account)
+                + account_storage_usage("bob")
+                + admin_account.storage_usage()
         );
 
         // Arrange - unregister account
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         StorageManagementEvent::clear_event_handlers();
         service.storage_unregister(None);
         // Act
@@ -2786,7 +3372,7 @@ mod tests_account_metrics {
         );
         assert_eq!(
             metrics.total_storage_usage,
-            storage_usage_bounds.min + admin_account.storage_usage()
+            account_storage_usage("bob") + admin_account.storage_usage()
         );
     }
 }
@@ -2794,8 +3380,7 @@ mod tests_account_metrics {
 #[cfg(test)]
 mod test_permission_management {
     use super::*;
-    use oysterpack_smart_near::near_sdk;
-    use oysterpack_smart_near::near_sdk::{test_utils, VMContext};
+    use oysterpack_smart_near::near_sdk::test_utils;
     use oysterpack_smart_near::YOCTO;
     use oysterpack_smart_near_test::*;
     use std::convert::TryInto;
@@ -2816,11 +3401,10 @@ mod test_permission_management {
     /// if admin is true, then the predecessor account is granted admin permission.
     fn test<F>(admin: bool, permissions: ContractPermissions, f: F)
     where
-        F: FnOnce(VMContext, AccountManager),
+        F: FnOnce(TestCtx, AccountManager),
     {
-        let mut ctx = new_context(PREDECESSOR_ACCOUNT);
-        ctx.predecessor_account_id = PREDECESSOR_ACCOUNT.to_string();
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(PREDECESSOR_ACCOUNT);
+        ctx.predecessor(PREDECESSOR_ACCOUNT).apply();
 
         let storage_usage_bounds = StorageUsageBounds {
             min: AccountManager::measure_storage_usage(()),
@@ -2836,8 +3420,7 @@ mod test_permission_management {
 
         {
             let mut ctx = ctx.clone();
-            ctx.attached_deposit = YOCTO;
-            testing_env!(ctx);
+            ctx.with_deposit(YOCTO).apply();
             account_manager.storage_deposit(None, None);
         }
 
@@ -2847,6 +3430,8 @@ mod test_permission_management {
             account.save();
         }
 
+        // permissions management methods require 1 yoctoNEAR to be attached
+        ctx.with_deposit(1).apply();
         f(ctx, account_manager);
     }
 
@@ -2863,14 +3448,10 @@ mod test_permission_management {
                 test(true, permissions(), |mut ctx, mut account_manager| {
                     // Arrange
                     let bob = "bob";
-                    ctx.predecessor_account_id = bob.to_string();
-                    ctx.attached_deposit = YOCTO;
-                    testing_env!(ctx.clone());
+                    ctx.predecessor(bob).with_deposit(YOCTO).apply();
                     account_manager.storage_deposit(None, None);
 
-                    ctx.predecessor_account_id = PREDECESSOR_ACCOUNT.to_string();
-                    ctx.attached_deposit = 0;
-                    testing_env!(ctx.clone());
+                    ctx.predecessor(PREDECESSOR_ACCOUNT).with_deposit(1).apply();
 
                     // account with admin permission implies all permissions
                     assert!(account_manager
@@ -2883,7 +3464,7 @@ mod test_permission_management {
                         .ops_permissions_granted(to_valid_account_id(PREDECESSOR_ACCOUNT))
                         .unwrap();
                     assert_eq!(accounts_perms.len(), 1);
-                    assert_eq!(accounts_perms.get(&63).unwrap(), "admin");
+                    assert_eq!(accounts_perms.get(&63).unwrap().name, "admin");
 
                     // grant admin
                     account_manager.ops_permissions_grant_admin(to_valid_account_id(bob));
@@ -2909,7 +3490,7 @@ mod test_permission_management {
                         .ops_permissions_granted(to_valid_account_id(bob))
                         .unwrap();
                     assert_eq!(accounts_perms.len(), 1);
-                    assert_eq!(accounts_perms.get(&62).unwrap(), "operator");
+                    assert_eq!(accounts_perms.get(&62).unwrap().name, "operator");
 
                     // revoke operator
                     account_manager.ops_permissions_revoke_operator(to_valid_account_id(bob));
@@ -2930,8 +3511,8 @@ mod test_permission_management {
                         .ops_permissions_granted(to_valid_account_id(bob))
                         .unwrap();
                     assert_eq!(accounts_perms.len(), 2);
-                    assert_eq!(accounts_perms.get(&0).unwrap(), "perm_0");
-                    assert_eq!(accounts_perms.get(&1).unwrap(), "perm_1");
+                    assert_eq!(accounts_perms.get(&0).unwrap().name, "perm_0");
+                    assert_eq!(accounts_perms.get(&1).unwrap().name, "perm_1");
 
                     // revoke permissions
                     account_manager.ops_permissions_revoke(to_valid_account_id(bob), PERM_0.into());
@@ -2971,9 +3552,9 @@ mod test_permission_management {
                         .ops_permissions_granted(to_valid_account_id(bob))
                         .unwrap();
                     assert_eq!(accounts_perms.len(), 3);
-                    assert_eq!(accounts_perms.get(&0).unwrap(), "perm_0");
-                    assert_eq!(accounts_perms.get(&1).unwrap(), "perm_1");
-                    assert_eq!(accounts_perms.get(&62).unwrap(), "operator");
+                    assert_eq!(accounts_perms.get(&0).unwrap().name, "perm_0");
+                    assert_eq!(accounts_perms.get(&1).unwrap().name, "perm_1");
+                    assert_eq!(accounts_perms.get(&62).unwrap().name, "operator");
 
                     // revoke all permissions
                     account_manager.ops_permissions_revoke_all(to_valid_account_id(bob));
@@ -3010,7 +3591,7 @@ mod test_permission_management {
 
                 #[test]
                 #[should_panic(
-                    expected = "[ERR] [INVALID] `account_id` cannot be the same as the predecessor account ID"
+                    expected = r#"{\"code\":\"INVALID\",\"message\":\"`account_id` cannot be the same as the predecessor account ID"#
                 )]
                 fn grant_admin_to_self() {
                     test(true, Default::default(), |_ctx, mut account_manager| {
@@ -3021,7 +3602,7 @@ mod test_permission_management {
 
                 #[test]
                 #[should_panic(
-                    expected = "[ERR] [INVALID] `account_id` cannot be the same as the predecessor account ID"
+                    expected = r#"{\"code\":\"INVALID\",\"message\":\"`account_id` cannot be the same as the predecessor account ID"#
                 )]
                 fn grant_operator_to_self() {
                     test(true, Default::default(), |_ctx, mut account_manager| {
@@ -3033,7 +3614,7 @@ mod test_permission_management {
 
                 #[test]
                 #[should_panic(
-                    expected = "[ERR] [INVALID] `account_id` cannot be the same as the predecessor account ID"
+                    expected = r#"{\"code\":\"INVALID\",\"message\":\"`account_id` cannot be the same as the predecessor account ID"#
                 )]
                 fn grant_to_self() {
                     test(true, permissions(), |_ctx, mut account_manager| {
@@ -3046,7 +3627,7 @@ mod test_permission_management {
 
                 #[test]
                 #[should_panic(
-                    expected = "[ERR] [INVALID] `account_id` cannot be the same as the predecessor account ID"
+                    expected = r#"{\"code\":\"INVALID\",\"message\":\"`account_id` cannot be the same as the predecessor account ID"#
                 )]
                 fn revoke_admin_to_self() {
                     test(true, Default::default(), |_ctx, mut account_manager| {
@@ -3057,7 +3638,7 @@ mod test_permission_management {
 
                 #[test]
                 #[should_panic(
-                    expected = "[ERR] [INVALID] `account_id` cannot be the same as the predecessor account ID"
+                    expected = r#"{\"code\":\"INVALID\",\"message\":\"`account_id` cannot be the same as the predecessor account ID"#
                 )]
                 fn revoke_operator_to_self() {
                     test(true, Default::default(), |_ctx, mut account_manager| {
@@ -3069,7 +3650,7 @@ mod test_permission_management {
 
                 #[test]
                 #[should_panic(
-                    expected = "[ERR] [INVALID] `account_id` cannot be the same as the predecessor account ID"
+                    expected = r#"{\"code\":\"INVALID\",\"message\":\"`account_id` cannot be the same as the predecessor account ID"#
                 )]
                 fn revoke_to_self() {
                     test(true, permissions(), |_ctx, mut account_manager| {
@@ -3082,7 +3663,7 @@ mod test_permission_management {
 
                 #[test]
                 #[should_panic(
-                    expected = "[ERR] [INVALID] `account_id` cannot be the same as the predecessor account ID"
+                    expected = r#"{\"code\":\"INVALID\",\"message\":\"`account_id` cannot be the same as the predecessor account ID"#
                 )]
                 fn revoke_all_to_self() {
                     test(true, permissions(), |_ctx, mut account_manager| {
@@ -3098,20 +3679,19 @@ mod test_permission_management {
 
                 #[test]
                 fn grant_revoke_admin() {
-                    test(true, permissions(), |ctx, mut account_manager| {
+                    test(true, permissions(), |mut ctx, mut account_manager| {
                         // Arrange
                         let bob = "bob";
                         {
                             // register account
                             let mut ctx = ctx.clone();
-                            ctx.attached_deposit = YOCTO;
-                            testing_env!(ctx.clone());
+                            ctx.with_deposit(YOCTO).apply();
                             account_manager
                                 .storage_deposit(Some(to_valid_account_id(bob)), Some(true));
                         }
 
                         // Act - grant
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager.ops_permissions_grant_admin(to_valid_account_id(bob));
                         let logs = test_utils::get_logs();
                         println!("{:#?}", logs);
@@ -3123,13 +3703,13 @@ mod test_permission_management {
                         assert_eq!(&logs[1], "[INFO] [PERMISSIONS_GRANT] admin");
 
                         // Act - grant admin again to user should have no effect
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager.ops_permissions_grant_admin(to_valid_account_id(bob));
                         let logs = test_utils::get_logs();
                         assert!(logs.is_empty());
 
                         // Act - revoke
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager.ops_permissions_revoke_admin(to_valid_account_id(bob));
                         let logs = test_utils::get_logs();
                         println!("{:#?}", logs);
@@ -3141,7 +3721,7 @@ mod test_permission_management {
                         assert_eq!(&logs[1], "[INFO] [PERMISSIONS_REVOKE] admin");
 
                         // Act - revoke again
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager.ops_permissions_revoke_admin(to_valid_account_id(bob));
                         let logs = test_utils::get_logs();
                         assert!(logs.is_empty());
@@ -3150,20 +3730,19 @@ mod test_permission_management {
 
                 #[test]
                 fn grant_revoke_operator() {
-                    test(true, permissions(), |ctx, mut account_manager| {
+                    test(true, permissions(), |mut ctx, mut account_manager| {
                         // Arrange
                         let bob = "bob";
                         {
                             // register account
                             let mut ctx = ctx.clone();
-                            ctx.attached_deposit = YOCTO;
-                            testing_env!(ctx.clone());
+                            ctx.with_deposit(YOCTO).apply();
                             account_manager
                                 .storage_deposit(Some(to_valid_account_id(bob)), Some(true));
                         }
 
                         // Act - grant
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager.ops_permissions_grant_operator(to_valid_account_id(bob));
                         let logs = test_utils::get_logs();
                         println!("{:#?}", logs);
@@ -3175,13 +3754,13 @@ mod test_permission_management {
                         assert_eq!(&logs[1], "[INFO] [PERMISSIONS_GRANT] operator");
 
                         // Act - grant admin again to user should have no effect
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager.ops_permissions_grant_operator(to_valid_account_id(bob));
                         let logs = test_utils::get_logs();
                         assert!(logs.is_empty());
 
                         // Act - revoke
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager.ops_permissions_revoke_operator(to_valid_account_id(bob));
                         let logs = test_utils::get_logs();
                         println!("{:#?}", logs);
@@ -3193,7 +3772,7 @@ mod test_permission_management {
                         assert_eq!(&logs[1], "[INFO] [PERMISSIONS_REVOKE] operator");
 
                         // Act - revoke again
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager.ops_permissions_revoke_operator(to_valid_account_id(bob));
                         let logs = test_utils::get_logs();
                         assert!(logs.is_empty());
@@ -3202,20 +3781,19 @@ mod test_permission_management {
 
                 #[test]
                 fn grant_revoke_contract_permissions() {
-                    test(true, permissions(), |ctx, mut account_manager| {
+                    test(true, permissions(), |mut ctx, mut account_manager| {
                         // Arrange
                         let bob = "bob";
                         {
                             // register account
                             let mut ctx = ctx.clone();
-                            ctx.attached_deposit = YOCTO;
-                            testing_env!(ctx.clone());
+                            ctx.with_deposit(YOCTO).apply();
                             account_manager
                                 .storage_deposit(Some(to_valid_account_id(bob)), Some(true));
                         }
 
                         // Act - grant
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager
                             .ops_permissions_grant(to_valid_account_id(bob), PERM_0.into());
                         let logs = test_utils::get_logs();
@@ -3228,13 +3806,13 @@ mod test_permission_management {
                         assert_eq!(&logs[1], "[INFO] [PERMISSIONS_GRANT] [\"perm_0\"]");
 
                         // Act - grant admin again to user should have no effect
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager
                             .ops_permissions_grant(to_valid_account_id(bob), PERM_0.into());
                         let logs = test_utils::get_logs();
                         assert!(logs.is_empty());
 
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager
                             .ops_permissions_grant(to_valid_account_id(bob), PERM_1.into());
                         let logs = test_utils::get_logs();
@@ -3243,7 +3821,7 @@ mod test_permission_management {
                         assert_eq!(&logs[0], "[INFO] [PERMISSIONS_GRANT] [\"perm_1\"]");
 
                         // Act - revoke
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager.ops_permissions_revoke(
                             to_valid_account_id(bob),
                             (PERM_0 | PERM_1).into(),
@@ -3261,20 +3839,20 @@ mod test_permission_management {
                         );
 
                         // Act - revoke again
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager
                             .ops_permissions_revoke(to_valid_account_id(bob), PERM_0.into());
                         let logs = test_utils::get_logs();
                         assert!(logs.is_empty());
 
                         // Act - grant
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager.ops_permissions_grant(
                             to_valid_account_id(bob),
                             (PERM_0 | PERM_1).into(),
                         );
 
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager.ops_permissions_revoke_all(to_valid_account_id(bob));
                         let logs = test_utils::get_logs();
                         println!("{:#?}", logs);
@@ -3288,7 +3866,7 @@ mod test_permission_management {
                             "[INFO] [PERMISSIONS_REVOKE] all permissions were revoked"
                         );
 
-                        testing_env!(ctx.clone());
+                        ctx.apply();
                         account_manager.ops_permissions_grant_permissions(
                             to_valid_account_id(bob),
                             vec![0, 1],
@@ -3312,6 +3890,345 @@ mod test_permission_management {
                 }
             }
         }
+
+        #[cfg(test)]
+        mod test_audit_log {
+            use super::*;
+
+            #[test]
+            fn grant_revoke() {
+                test(true, permissions(), |mut ctx, mut account_manager| {
+                    // Arrange
+                    let bob = "bob";
+                    {
+                        // register account
+                        let mut ctx = ctx.clone();
+                        ctx.with_deposit(YOCTO).apply();
+                        account_manager.storage_deposit(Some(to_valid_account_id(bob)), Some(true));
+                    }
+
+                    // Act
+                    ctx.block_index(10).apply();
+                    account_manager.ops_permissions_grant(to_valid_account_id(bob), PERM_0.into());
+                    account_manager.ops_permissions_grant_admin(to_valid_account_id(bob));
+                    // no effect - should not be recorded
+                    account_manager.ops_permissions_grant_admin(to_valid_account_id(bob));
+                    ctx.block_index(11).apply();
+                    account_manager.ops_permissions_revoke_all(to_valid_account_id(bob));
+
+                    // Assert
+                    let log = account_manager.ops_permissions_audit_log(0.into(), 10);
+                    assert_eq!(
+                        log,
+                        vec![
+                            PermissionsAuditLogEntry {
+                                id: 0.into(),
+                                block_height: 10.into(),
+                                predecessor: PREDECESSOR_ACCOUNT.to_string(),
+                                event: PermissionsAuditEvent::Grant {
+                                    account_id: bob.to_string(),
+                                    permissions: vec!["perm_0".to_string()],
+                                },
+                            },
+                            PermissionsAuditLogEntry {
+                                id: 1.into(),
+                                block_height: 10.into(),
+                                predecessor: PREDECESSOR_ACCOUNT.to_string(),
+                                event: PermissionsAuditEvent::Grant {
+                                    account_id: bob.to_string(),
+                                    permissions: vec!["admin".to_string()],
+                                },
+                            },
+                            PermissionsAuditLogEntry {
+                                id: 2.into(),
+                                block_height: 11.into(),
+                                predecessor: PREDECESSOR_ACCOUNT.to_string(),
+                                event: PermissionsAuditEvent::Revoke {
+                                    account_id: bob.to_string(),
+                                    permissions: vec!["all".to_string()],
+                                },
+                            },
+                        ]
+                    );
+
+                    let log = account_manager.ops_permissions_audit_log(1.into(), 1);
+                    assert_eq!(log.len(), 1);
+                    assert_eq!(log[0].id, 1.into());
+                    assert!(account_manager
+                        .ops_permissions_audit_log(3.into(), 10)
+                        .is_empty());
+                });
+            }
+
+            #[test]
+            fn bounded_log() {
+                test(true, permissions(), |mut ctx, _account_manager| {
+                    // Act
+                    let entries = PermissionsAuditLog::MAX_ENTRIES + 5;
+                    for i in 0..entries {
+                        ctx.apply();
+                        PermissionsAuditLog::record(PermissionsAuditEvent::OperatorCommand {
+                            operation: "command".to_string(),
+                            args: i.to_string(),
+                        });
+                    }
+
+                    // Assert - the oldest entries were overwritten
+                    assert_eq!(PermissionsAuditLog::next_id(), entries);
+                    let log = PermissionsAuditLog::entries(0, u16::MAX);
+                    assert_eq!(log.len(), PermissionsAuditLog::MAX_PAGE_SIZE as usize);
+                    assert_eq!(log[0].id, 5.into());
+                    assert_eq!(
+                        log[0].event,
+                        PermissionsAuditEvent::OperatorCommand {
+                            operation: "command".to_string(),
+                            args: "5".to_string(),
+                        }
+                    );
+                    let log = PermissionsAuditLog::entries(entries - 1, 10);
+                    assert_eq!(log.len(), 1);
+                    assert_eq!(log[0].id, (entries - 1).into());
+                });
+            }
+        }
+
+        #[cfg(test)]
+        mod test_grant_with_expiry {
+            use super::*;
+
+            #[test]
+            fn expired_permissions_are_revoked() {
+                test(true, permissions(), |mut ctx, mut account_manager| {
+                    // Arrange
+                    let bob = "bob";
+                    {
+                        // register account
+                        let mut ctx = ctx.clone();
+                        ctx.with_deposit(YOCTO).apply();
+                        account_manager.storage_deposit(Some(to_valid_account_id(bob)), Some(true));
+                    }
+
+                    // Act
+                    ctx.block_index(10).apply();
+                    account_manager.ops_permissions_grant(to_valid_account_id(bob), PERM_1.into());
+                    account_manager.ops_permissions_grant_with_expiry(
+                        to_valid_account_id(bob),
+                        PERM_0.into(),
+                        100.into(),
+                    );
+
+                    // Assert
+                    assert!(account_manager
+                        .ops_permissions_contains(to_valid_account_id(bob), PERM_0.into()));
+                    let granted = account_manager
+                        .ops_permissions_granted(to_valid_account_id(bob))
+                        .unwrap();
+                    assert_eq!(
+                        granted.get(&0).unwrap(),
+                        &PermissionGrant {
+                            name: "perm_0".to_string(),
+                            expires_at: Some(100.into())
+                        }
+                    );
+                    assert_eq!(granted.get(&1).unwrap().expires_at, None);
+
+                    // Act - the permission expires
+                    ctx.block_index(100).apply();
+
+                    // Assert
+                    assert!(!account_manager
+                        .ops_permissions_contains(to_valid_account_id(bob), PERM_0.into()));
+                    assert!(account_manager
+                        .ops_permissions_contains(to_valid_account_id(bob), PERM_1.into()));
+                    let granted = account_manager
+                        .ops_permissions_granted(to_valid_account_id(bob))
+                        .unwrap();
+                    assert_eq!(granted.len(), 1);
+                    assert!(granted.contains_key(&1));
+
+                    // Act - expiries are cleaned up when the account permissions are updated
+                    account_manager.ops_permissions_revoke(to_valid_account_id(bob), PERM_1.into());
+
+                    // Assert
+                    assert!(account_manager
+                        .ops_permissions(to_valid_account_id(bob))
+                        .is_none());
+                    assert!(PermissionExpiries::load(bob).is_none());
+                });
+            }
+
+            #[test]
+            fn permanent_grant_clears_expiry() {
+                test(true, permissions(), |mut ctx, mut account_manager| {
+                    // Arrange
+                    let bob = "bob";
+                    {
+                        // register account
+                        let mut ctx = ctx.clone();
+                        ctx.with_deposit(YOCTO).apply();
+                        account_manager.storage_deposit(Some(to_valid_account_id(bob)), Some(true));
+                    }
+                    ctx.block_index(10).apply();
+                    account_manager.ops_permissions_grant_with_expiry(
+                        to_valid_account_id(bob),
+                        PERM_0.into(),
+                        100.into(),
+                    );
+
+                    // Act
+                    account_manager.ops_permissions_grant(to_valid_account_id(bob), PERM_0.into());
+
+                    // Assert
+                    assert!(PermissionExpiries::load(bob).is_none());
+                    ctx.block_index(100).apply();
+                    assert!(account_manager
+                        .ops_permissions_contains(to_valid_account_id(bob), PERM_0.into()));
+                });
+            }
+
+            #[test]
+            #[should_panic(expected = r#"{\"code\":\"INVALID\""#)]
+            fn expiry_not_in_future() {
+                test(true, permissions(), |mut ctx, mut account_manager| {
+                    let bob = "bob";
+                    {
+                        // register account
+                        let mut ctx = ctx.clone();
+                        ctx.with_deposit(YOCTO).apply();
+                        account_manager.storage_deposit(Some(to_valid_account_id(bob)), Some(true));
+                    }
+                    ctx.block_index(10).apply();
+                    account_manager.ops_permissions_grant_with_expiry(
+                        to_valid_account_id(bob),
+                        PERM_0.into(),
+                        10.into(),
+                    );
+                });
+            }
+        }
+
+        #[cfg(test)]
+        mod test_batch {
+            use super::*;
+
+            fn register_accounts(ctx: &TestCtx, account_manager: &mut AccountManager) {
+                let mut ctx = ctx.clone();
+                ctx.with_deposit(YOCTO);
+                for account_id in ["alice", "bob"].iter() {
+                    ctx.apply();
+                    account_manager
+                        .storage_deposit(Some(to_valid_account_id(account_id)), Some(true));
+                }
+            }
+
+            /// excludes the account storage change logs
+            fn permissions_logs() -> Vec<String> {
+                test_utils::get_logs()
+                    .into_iter()
+                    .filter(|log| log.starts_with("[INFO] [PERMISSIONS_"))
+                    .collect()
+            }
+
+            #[test]
+            fn grant_many_and_revoke_many() {
+                test(true, permissions(), |mut ctx, mut account_manager| {
+                    // Arrange
+                    register_accounts(&ctx, &mut account_manager);
+                    ctx.apply();
+
+                    // Act
+                    account_manager.ops_permissions_grant_many(vec![
+                        (
+                            to_valid_account_id("alice"),
+                            (PERM_0 | Permissions::OPERATOR).into(),
+                        ),
+                        (to_valid_account_id("bob"), PERM_1.into()),
+                    ]);
+
+                    // Assert - a single aggregated event is logged
+                    assert_eq!(
+                        permissions_logs(),
+                        vec![
+                            r#"[INFO] [PERMISSIONS_GRANT_MANY] alice=["operator", "perm_0"], bob=["perm_1"]"#
+                        ]
+                    );
+                    assert!(
+                        account_manager.ops_permissions_is_operator(to_valid_account_id("alice"))
+                    );
+                    assert!(account_manager
+                        .ops_permissions_contains(to_valid_account_id("alice"), PERM_0.into()));
+                    assert!(account_manager
+                        .ops_permissions_contains(to_valid_account_id("bob"), PERM_1.into()));
+                    let log = account_manager.ops_permissions_audit_log(0.into(), 10);
+                    assert_eq!(log.len(), 2);
+                    assert_eq!(
+                        log[1].event,
+                        PermissionsAuditEvent::Grant {
+                            account_id: "bob".to_string(),
+                            permissions: vec!["perm_1".to_string()],
+                        }
+                    );
+
+                    // Act - rotate the operator from alice to bob
+                    ctx.apply();
+                    account_manager.ops_permissions_revoke_many(vec![(
+                        to_valid_account_id("alice"),
+                        Permissions::OPERATOR.into(),
+                    )]);
+                    account_manager.ops_permissions_grant_many(vec![(
+                        to_valid_account_id("bob"),
+                        Permissions::OPERATOR.into(),
+                    )]);
+
+                    // Assert
+                    assert_eq!(
+                        permissions_logs(),
+                        vec![
+                            r#"[INFO] [PERMISSIONS_REVOKE_MANY] alice=["operator"]"#,
+                            r#"[INFO] [PERMISSIONS_GRANT_MANY] bob=["operator"]"#,
+                        ]
+                    );
+                    assert!(
+                        !account_manager.ops_permissions_is_operator(to_valid_account_id("alice"))
+                    );
+                    assert!(account_manager
+                        .ops_permissions_contains(to_valid_account_id("alice"), PERM_0.into()));
+                    assert!(account_manager.ops_permissions_is_operator(to_valid_account_id("bob")));
+                });
+            }
+
+            #[test]
+            #[should_panic(
+                expected = r#"{\"code\":\"INVALID\",\"message\":\"admin permission can not be granted or revoked in a batch"#
+            )]
+            fn grant_many_with_admin_permission() {
+                test(true, permissions(), |mut ctx, mut account_manager| {
+                    register_accounts(&ctx, &mut account_manager);
+                    ctx.apply();
+
+                    account_manager.ops_permissions_grant_many(vec![
+                        (to_valid_account_id("alice"), PERM_0.into()),
+                        (to_valid_account_id("bob"), Permissions::ADMIN.into()),
+                    ]);
+                });
+            }
+
+            #[test]
+            #[should_panic(
+                expected = r#"{\"code\":\"INVALID\",\"message\":\"contract does not support specified permissions"#
+            )]
+            fn grant_many_with_unsupported_permissions() {
+                test(true, permissions(), |mut ctx, mut account_manager| {
+                    register_accounts(&ctx, &mut account_manager);
+                    ctx.apply();
+
+                    account_manager.ops_permissions_grant_many(vec![(
+                        to_valid_account_id("bob"),
+                        (1_u64 << 5).into(),
+                    )]);
+                });
+            }
+        }
     }
 
     #[cfg(test)]
@@ -3319,7 +4236,7 @@ mod test_permission_management {
         use super::*;
 
         #[test]
-        #[should_panic(expected = "[ERR] [NOT_AUTHORIZED]")]
+        #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
         fn grant_admin() {
             test(false, Default::default(), |_ctx, mut account_manager| {
                 account_manager.ops_permissions_grant_admin(to_valid_account_id("bob"));
@@ -3327,7 +4244,7 @@ mod test_permission_management {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [NOT_AUTHORIZED]")]
+        #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
         fn grant_operator() {
             test(false, Default::default(), |_ctx, mut account_manager| {
                 account_manager.ops_permissions_grant_operator(to_valid_account_id("bob"));
@@ -3335,7 +4252,7 @@ mod test_permission_management {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [NOT_AUTHORIZED]")]
+        #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
         fn grant() {
             test(false, permissions(), |_ctx, mut account_manager| {
                 account_manager.ops_permissions_grant(to_valid_account_id("bob"), (1 << 1).into());
@@ -3343,7 +4260,7 @@ mod test_permission_management {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [NOT_AUTHORIZED]")]
+        #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
         fn revoke_admin() {
             test(false, Default::default(), |_ctx, mut account_manager| {
                 account_manager.ops_permissions_revoke_admin(to_valid_account_id("bob"));
@@ -3351,7 +4268,7 @@ mod test_permission_management {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [NOT_AUTHORIZED]")]
+        #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
         fn revoke_operator() {
             test(false, Default::default(), |_ctx, mut account_manager| {
                 account_manager.ops_permissions_revoke_operator(to_valid_account_id("bob"));
@@ -3359,7 +4276,7 @@ mod test_permission_management {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [NOT_AUTHORIZED]")]
+        #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
         fn revoke() {
             test(false, permissions(), |_ctx, mut account_manager| {
                 account_manager.ops_permissions_revoke(to_valid_account_id("bob"), (1 << 1).into());
@@ -3367,7 +4284,7 @@ mod test_permission_management {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [NOT_AUTHORIZED]")]
+        #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
         fn revoke_all() {
             test(false, permissions(), |_ctx, mut account_manager| {
                 account_manager.ops_permissions_revoke_all(to_valid_account_id("bob"));
@@ -3405,3 +4322,897 @@ mod test_permission_management {
         }
     }
 }
+
+#[cfg(test)]
+mod tests_admin_approvals {
+    use super::*;
+    use oysterpack_smart_near::near_sdk::test_utils;
+    use oysterpack_smart_near::YOCTO;
+    use oysterpack_smart_near_test::*;
+
+    type AccountManager = AccountManagementComponent<()>;
+
+    const ADMIN: &str = "admin";
+    const ADMIN_2: &str = "admin2";
+    const ACCOUNT: &str = "bob";
+
+    /// deploys the account manager with 2 admins and 1 registered non-admin account
+    fn setup() -> (TestCtx, AccountManager) {
+        let mut ctx = TestCtx::new(ADMIN);
+        ctx.apply();
+        AccountManager::deploy(AccountManagementComponentConfig {
+            storage_usage_bounds: None,
+            admin_account: to_valid_account_id(ADMIN),
+            component_account_storage_mins: None,
+        });
+        let mut account_manager = AccountManager::new(Default::default());
+
+        for account_id in [ADMIN_2, ACCOUNT].iter() {
+            ctx.predecessor(account_id).with_deposit(YOCTO).apply();
+            account_manager.storage_deposit(None, None);
+        }
+
+        ctx.predecessor(ADMIN).with_deposit(1).apply();
+        account_manager.ops_permissions_grant_admin(to_valid_account_id(ADMIN_2));
+        ctx.with_deposit(0).apply();
+        (ctx, account_manager)
+    }
+
+    #[test]
+    fn approval_workflow() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+        assert!(account_manager.ops_approvals_policy().is_none());
+
+        // Act - policy can be set directly when no policy is in effect
+        account_manager.ops_approvals_set_policy(Some(2));
+
+        // Assert
+        assert_eq!(
+            account_manager.ops_approvals_policy(),
+            Some(ApprovalPolicy {
+                required_approvals: 2
+            })
+        );
+
+        // Act - clearing the policy requires quorum
+        account_manager.ops_approvals_set_policy(None);
+
+        // Assert - operation is pending approval
+        assert!(account_manager.ops_approvals_policy().is_some());
+        let pending = account_manager.ops_approvals_pending();
+        assert_eq!(pending.len(), 1);
+        let operation = &pending[0];
+        assert_eq!(operation.operation, "ops_approvals_set_policy");
+        assert_eq!(operation.proposed_by, ADMIN);
+        assert_eq!(operation.approvals, vec![ADMIN.to_string()]);
+        let logs = test_utils::get_logs();
+        println!("{:#?}", logs);
+        assert!(logs.contains(&format!(
+            "[INFO] [APPROVAL_PENDING] operation=ops_approvals_set_policy, operation_id={}",
+            operation.operation_id
+        )));
+
+        // Act - invoking the operation again by the same admin does not count twice
+        account_manager.ops_approvals_set_policy(None);
+        assert_eq!(
+            account_manager.ops_approvals_pending()[0].approvals.len(),
+            1
+        );
+
+        // Act - second admin approves
+        ctx.predecessor(ADMIN_2).apply();
+        let operation = account_manager.ops_approvals_approve(operation.operation_id);
+        assert_eq!(
+            operation.approvals,
+            vec![ADMIN.to_string(), ADMIN_2.to_string()]
+        );
+
+        // Act - operation is executed once quorum is reached
+        ctx.predecessor(ADMIN).apply();
+        account_manager.ops_approvals_set_policy(None);
+
+        // Assert
+        assert!(account_manager.ops_approvals_policy().is_none());
+        assert!(account_manager.ops_approvals_pending().is_empty());
+        let logs = test_utils::get_logs();
+        println!("{:#?}", logs);
+        assert_eq!(
+            logs.last().unwrap(),
+            "[INFO] [APPROVAL_POLICY_UPDATED] cleared"
+        );
+    }
+
+    #[test]
+    fn check_invoked_by_non_admin() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+        account_manager.ops_approvals_set_policy(Some(1));
+
+        // Act
+        ctx.predecessor(ACCOUNT).apply();
+        let approved = AdminApprovals::check("withdraw", &[1]);
+
+        // Assert - non-admin invocations are enqueued without approval
+        assert!(!approved);
+        let pending = AdminApprovals::pending();
+        assert_eq!(pending[0].proposed_by, ACCOUNT);
+        assert!(pending[0].approvals.is_empty());
+
+        // Act - different args are tracked as a separate operation
+        AdminApprovals::check("withdraw", &[2]);
+        assert_eq!(AdminApprovals::pending().len(), 2);
+
+        // Act - admin approval reaches quorum
+        ctx.predecessor(ADMIN).apply();
+        account_manager.ops_approvals_approve(pending[0].operation_id);
+        ctx.predecessor(ACCOUNT).apply();
+        assert!(AdminApprovals::check("withdraw", &[1]));
+        assert_eq!(AdminApprovals::pending().len(), 1);
+    }
+
+    #[test]
+    fn cancel() {
+        // Arrange
+        let (_ctx, mut account_manager) = setup();
+        account_manager.ops_approvals_set_policy(Some(2));
+        AdminApprovals::check("withdraw", &[1]);
+        let operation_id = AdminApprovals::operation_id("withdraw", &[1]);
+
+        // Act
+        assert!(account_manager.ops_approvals_cancel(operation_id));
+
+        // Assert
+        assert!(account_manager.ops_approvals_pending().is_empty());
+        assert!(!account_manager.ops_approvals_cancel(operation_id));
+    }
+
+    #[test]
+    fn pending_approvals_are_capped() {
+        // Arrange
+        let (_ctx, mut account_manager) = setup();
+        account_manager.ops_approvals_set_policy(Some(2));
+        for i in 0..AdminApprovals::MAX_PENDING {
+            AdminApprovals::check("withdraw", &[i as u8]);
+        }
+        assert_eq!(AdminApprovals::pending().len(), AdminApprovals::MAX_PENDING);
+
+        // Act - operations that are already pending can still be invoked
+        assert!(!AdminApprovals::check("withdraw", &[0]));
+
+        // Act - once an operation is cancelled, a new operation can be enqueued
+        account_manager.ops_approvals_cancel(AdminApprovals::operation_id("withdraw", &[0]));
+        AdminApprovals::check("withdraw", &[u8::MAX]);
+
+        // Assert
+        assert_eq!(AdminApprovals::pending().len(), AdminApprovals::MAX_PENDING);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"PENDING_APPROVALS_FULL\""#)]
+    fn pending_approvals_full() {
+        // Arrange
+        let (_ctx, mut account_manager) = setup();
+        account_manager.ops_approvals_set_policy(Some(2));
+        for i in 0..AdminApprovals::MAX_PENDING {
+            AdminApprovals::check("withdraw", &[i as u8]);
+        }
+
+        // Act
+        AdminApprovals::check("withdraw", &[u8::MAX]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
+    fn approve_as_non_admin() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+        account_manager.ops_approvals_set_policy(Some(2));
+        AdminApprovals::check("withdraw", &[1]);
+
+        // Act
+        ctx.predecessor(ACCOUNT).apply();
+        account_manager.ops_approvals_approve(AdminApprovals::operation_id("withdraw", &[1]));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"APPROVAL_NOT_PENDING\""#)]
+    fn approve_unknown_operation() {
+        // Arrange
+        let (_ctx, mut account_manager) = setup();
+
+        // Act
+        account_manager.ops_approvals_approve(AdminApprovals::operation_id("withdraw", &[1]));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"INVALID\",\"message\":\"required_approvals must be > 0"#
+    )]
+    fn set_policy_with_zero_required_approvals() {
+        // Arrange
+        let (_ctx, mut account_manager) = setup();
+
+        // Act
+        account_manager.ops_approvals_set_policy(Some(0));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"INVALID\",\"message\":\"required_approvals (3) must be <= the number of admins (2)"#
+    )]
+    fn set_policy_with_more_required_approvals_than_admins() {
+        // Arrange
+        let (_ctx, mut account_manager) = setup();
+        assert_eq!(AdminApprovals::admin_count(), 2);
+
+        // Act
+        account_manager.ops_approvals_set_policy(Some(3));
+    }
+
+    #[test]
+    fn grant_admin_requires_approval() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+        account_manager.ops_approvals_set_policy(Some(2));
+
+        // Act
+        ctx.with_deposit(1).apply();
+        account_manager.ops_permissions_grant_admin(to_valid_account_id(ACCOUNT));
+
+        // Assert - the grant is pending approval
+        assert!(!account_manager.ops_permissions_is_admin(to_valid_account_id(ACCOUNT)));
+        let pending = account_manager.ops_approvals_pending();
+        assert_eq!(pending[0].operation, "ops_permissions_grant_admin");
+
+        // Act - second admin approves, and the grant is executed when it is invoked again
+        ctx.predecessor(ADMIN_2).with_deposit(0).apply();
+        account_manager.ops_approvals_approve(pending[0].operation_id);
+        ctx.predecessor(ADMIN).with_deposit(1).apply();
+        account_manager.ops_permissions_grant_admin(to_valid_account_id(ACCOUNT));
+
+        // Assert
+        assert!(account_manager.ops_permissions_is_admin(to_valid_account_id(ACCOUNT)));
+        assert!(account_manager.ops_approvals_pending().is_empty());
+        assert_eq!(AdminApprovals::admin_count(), 3);
+    }
+
+    #[test]
+    fn admin_count() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+        assert_eq!(AdminApprovals::admin_count(), 2);
+
+        // Act
+        ctx.with_deposit(1).apply();
+        account_manager.ops_permissions_revoke_admin(to_valid_account_id(ADMIN_2));
+
+        // Assert
+        assert_eq!(AdminApprovals::admin_count(), 1);
+
+        // Act - granting admin again is counted once
+        account_manager.ops_permissions_grant_admin(to_valid_account_id(ADMIN_2));
+        account_manager.ops_permissions_grant_admin(to_valid_account_id(ADMIN_2));
+        assert_eq!(AdminApprovals::admin_count(), 2);
+
+        // Act - revoking all permissions revokes admin
+        account_manager.ops_permissions_revoke_all(to_valid_account_id(ADMIN_2));
+        assert_eq!(AdminApprovals::admin_count(), 1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"INVALID\",\"message\":\"required_approvals (2) must be <= the number of admins (1)"#
+    )]
+    fn revoke_admin_below_required_approvals() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+        account_manager.ops_approvals_set_policy(Some(2));
+
+        // Act
+        ctx.with_deposit(1).apply();
+        account_manager.ops_permissions_revoke_admin(to_valid_account_id(ADMIN_2));
+    }
+}
+
+#[cfg(test)]
+mod tests_storage_sponsorship {
+    use super::*;
+    use oysterpack_smart_near::near_sdk::test_utils;
+    use oysterpack_smart_near::YOCTO;
+    use oysterpack_smart_near_test::*;
+
+    type AccountManager = AccountManagementComponent<()>;
+
+    const ADMIN: &str = "admin";
+    const OPERATOR: &str = "operator";
+    const ACCOUNT: &str = "bob";
+
+    /// deploys the account manager and registers an operator account and a non-operator account
+    fn setup() -> (TestCtx, AccountManager) {
+        let mut ctx = TestCtx::new(ADMIN);
+        ctx.apply();
+        AccountManager::deploy(AccountManagementComponentConfig::new(to_valid_account_id(
+            ADMIN,
+        )));
+        let mut account_manager = AccountManager::new(Default::default());
+
+        for account_id in [OPERATOR, ACCOUNT].iter() {
+            ctx.predecessor(account_id).with_deposit(YOCTO).apply();
+            account_manager.storage_deposit(None, None);
+        }
+
+        ctx.predecessor(ADMIN).with_deposit(1).apply();
+        account_manager.ops_permissions_grant_operator(to_valid_account_id(OPERATOR));
+        ctx.with_deposit(0).apply();
+        (ctx, account_manager)
+    }
+
+    #[test]
+    fn sponsor_accounts() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+        let storage_balance_min = account_manager.storage_balance_bounds().min;
+        let account_count = AccountManager::account_metrics().total_registered_accounts;
+
+        // Act
+        ctx.predecessor(OPERATOR)
+            .with_deposit(*storage_balance_min * 3)
+            .apply();
+        let registered = account_manager.ops_storage_sponsor_accounts(vec![
+            to_valid_account_id("alice"),
+            to_valid_account_id(ACCOUNT),
+            to_valid_account_id("charlie"),
+            to_valid_account_id("alice"),
+        ]);
+
+        // Assert - already registered and duplicate accounts are skipped
+        assert_eq!(registered, vec!["alice".to_string(), "charlie".to_string()]);
+        for account_id in registered.iter() {
+            let storage_balance = account_manager
+                .storage_balance_of(to_valid_account_id(account_id))
+                .unwrap();
+            assert_eq!(storage_balance.total, storage_balance_min);
+            assert_eq!(storage_balance.available, YoctoNear::ZERO);
+        }
+        assert_eq!(
+            AccountManager::account_metrics().total_registered_accounts,
+            account_count + 2
+        );
+
+        let logs = test_utils::get_logs();
+        println!("{:#?}", logs);
+        assert_eq!(
+            logs.last().unwrap(),
+            &format!(
+                "[INFO] [STORAGE_SPONSORED] sponsor=operator, accounts=2, deposit={}",
+                storage_balance_min * 2
+            )
+        );
+
+        // Assert - excess deposit is refunded to the sponsor
+        let receipts = deserialize_receipts();
+        assert_eq!(receipts.len(), 1);
+        receipts[0]
+            .assert_receiver(OPERATOR)
+            .assert_transfer(*storage_balance_min);
+    }
+
+    #[test]
+    fn sponsor_accounts_as_admin_with_exact_deposit() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+        let storage_balance_min = account_manager.storage_balance_bounds().min;
+
+        // Act
+        ctx.with_deposit(*storage_balance_min).apply();
+        let registered =
+            account_manager.ops_storage_sponsor_accounts(vec![to_valid_account_id("alice")]);
+
+        // Assert
+        assert_eq!(registered, vec!["alice".to_string()]);
+        assert!(deserialize_receipts().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
+    fn sponsor_accounts_not_authorized() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+
+        // Act
+        ctx.predecessor(ACCOUNT).with_deposit(YOCTO).apply();
+        account_manager.ops_storage_sponsor_accounts(vec![to_valid_account_id("alice")]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
+    fn sponsor_accounts_with_insufficient_deposit() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+        let storage_balance_min = account_manager.storage_balance_bounds().min;
+
+        // Act
+        ctx.with_deposit(*storage_balance_min).apply();
+        account_manager.ops_storage_sponsor_accounts(vec![
+            to_valid_account_id("alice"),
+            to_valid_account_id("charlie"),
+        ]);
+    }
+}
+
+#[cfg(test)]
+mod tests_storage_usage_bounds_management {
+    use super::*;
+    use oysterpack_smart_near::near_sdk::test_utils;
+    use oysterpack_smart_near::YOCTO;
+    use oysterpack_smart_near_test::*;
+
+    type AccountManager = AccountManagementComponent<()>;
+
+    const ADMIN: &str = "admin";
+    const OPERATOR: &str = "operator";
+    const ACCOUNT: &str = "bob";
+
+    /// deploys the account manager and registers an operator account and a non-operator account
+    fn setup() -> (TestCtx, AccountManager) {
+        let mut ctx = TestCtx::new(ADMIN);
+        ctx.apply();
+        AccountManager::deploy(AccountManagementComponentConfig::new(to_valid_account_id(
+            ADMIN,
+        )));
+        let mut account_manager = AccountManager::new(Default::default());
+
+        for account_id in [OPERATOR, ACCOUNT].iter() {
+            ctx.predecessor(account_id).with_deposit(YOCTO).apply();
+            account_manager.storage_deposit(None, None);
+        }
+
+        ctx.predecessor(ADMIN).with_deposit(1).apply();
+        account_manager.ops_permissions_grant_operator(to_valid_account_id(OPERATOR));
+        ctx.with_deposit(0).apply();
+        (ctx, account_manager)
+    }
+
+    #[test]
+    fn raise_bounds_and_top_up_accounts() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+        let admin_balance = account_manager
+            .storage_balance_of(to_valid_account_id(ADMIN))
+            .unwrap()
+            .total;
+        let bounds = StorageUsageBounds {
+            min: ((2 * YOCTO / env::storage_byte_cost()) as u64).into(),
+            max: None,
+        };
+
+        // Act - raise the bounds
+        ctx.predecessor(OPERATOR).apply();
+        let storage_balance_bounds = account_manager.ops_storage_usage_bounds_update(bounds);
+
+        // Assert
+        assert_eq!(storage_balance_bounds.min, (2 * YOCTO).into());
+        assert_eq!(
+            account_manager.storage_balance_bounds(),
+            storage_balance_bounds
+        );
+        assert_eq!(account_manager.ops_storage_usage_bounds(), bounds);
+        let logs = test_utils::get_logs();
+        println!("{:#?}", logs);
+        assert_eq!(
+            logs.last().unwrap(),
+            &format!(
+                "[INFO] [STORAGE_USAGE_BOUNDS_UPDATED] min={}, max=None",
+                bounds.min
+            )
+        );
+        // accounts that have not been topped up have no available balance
+        let storage_balance = account_manager
+            .storage_balance_of(to_valid_account_id(ACCOUNT))
+            .unwrap();
+        assert_eq!(storage_balance.total, YOCTO.into());
+        assert_eq!(storage_balance.available, YoctoNear::ZERO);
+
+        // Act - top up the first 2 accounts
+        let required_deposit = (2 * YOCTO - *admin_balance) + YOCTO;
+        ctx.with_deposit(required_deposit + 100).apply();
+        let topped_up = account_manager.ops_storage_top_up_accounts(0, 2);
+
+        // Assert
+        assert_eq!(topped_up, vec![ADMIN.to_string(), OPERATOR.to_string()]);
+        for account_id in topped_up.iter() {
+            let storage_balance = account_manager
+                .storage_balance_of(to_valid_account_id(account_id))
+                .unwrap();
+            assert_eq!(storage_balance.total, (2 * YOCTO).into());
+        }
+        let logs = test_utils::get_logs();
+        println!("{:#?}", logs);
+        assert_eq!(
+            logs.last().unwrap(),
+            &format!(
+                "[INFO] [STORAGE_TOPPED_UP] sponsor=operator, accounts=2, deposit={}",
+                required_deposit
+            )
+        );
+        let receipts = deserialize_receipts();
+        assert_eq!(receipts.len(), 1);
+        receipts[0].assert_receiver(OPERATOR).assert_transfer(100);
+
+        // Act - top up the remaining accounts
+        ctx.with_deposit(YOCTO).apply();
+        let topped_up = account_manager.ops_storage_top_up_accounts(2, 10);
+
+        // Assert
+        assert_eq!(topped_up, vec![ACCOUNT.to_string()]);
+        assert!(deserialize_receipts().is_empty());
+
+        // Act - all accounts have been topped up
+        ctx.with_deposit(1).apply();
+        assert!(account_manager
+            .ops_storage_top_up_accounts(0, 10)
+            .is_empty());
+
+        // Assert - the attached deposit is refunded
+        let receipts = deserialize_receipts();
+        assert_eq!(receipts.len(), 1);
+        receipts[0].assert_receiver(OPERATOR).assert_transfer(1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
+    fn top_up_accounts_with_no_deposit() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+
+        // Act
+        ctx.with_deposit(0).apply();
+        account_manager.ops_storage_top_up_accounts(0, 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "storage usage min cannot be lowered")]
+    fn lower_min() {
+        // Arrange
+        let (_ctx, mut account_manager) = setup();
+        let bounds = account_manager.ops_storage_usage_bounds();
+
+        // Act
+        account_manager.ops_storage_usage_bounds_update(StorageUsageBounds {
+            min: (bounds.min.value() - 1).into(),
+            max: None,
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "storage usage max must not be less than the min")]
+    fn max_less_than_min() {
+        // Arrange
+        let (_ctx, mut account_manager) = setup();
+        let bounds = account_manager.ops_storage_usage_bounds();
+
+        // Act
+        account_manager.ops_storage_usage_bounds_update(StorageUsageBounds {
+            min: bounds.min,
+            max: Some((bounds.min.value() - 1).into()),
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
+    fn update_bounds_not_authorized() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+        let bounds = account_manager.ops_storage_usage_bounds();
+
+        // Act
+        ctx.predecessor(ACCOUNT).apply();
+        account_manager.ops_storage_usage_bounds_update(bounds);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
+    fn top_up_accounts_with_insufficient_deposit() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+        account_manager.ops_storage_usage_bounds_update(StorageUsageBounds {
+            min: ((2 * YOCTO / env::storage_byte_cost()) as u64).into(),
+            max: None,
+        });
+
+        // Act
+        ctx.with_deposit(YOCTO).apply();
+        account_manager.ops_storage_top_up_accounts(0, 10);
+    }
+
+    #[test]
+    fn index_accounts() {
+        // Arrange - simulate an account that was registered before the account index was introduced
+        let (mut ctx, mut account_manager) = setup();
+        let storage_usage = account_manager.ops_storage_usage(to_valid_account_id(ACCOUNT));
+        AccountIndex::remove(ACCOUNT);
+        assert!(!AccountIndex::contains(ACCOUNT));
+        assert!(account_manager.ops_storage_usage(to_valid_account_id(ACCOUNT)) < storage_usage);
+
+        // Act
+        ctx.predecessor(OPERATOR).apply();
+        let count = account_manager.ops_storage_index_accounts(vec![
+            to_valid_account_id(ACCOUNT),
+            to_valid_account_id(ADMIN),
+        ]);
+
+        // Assert - already indexed accounts are skipped
+        assert_eq!(count, 1);
+        assert!(AccountIndex::contains(ACCOUNT));
+        assert_eq!(AccountIndex::count(), 3);
+        // the index storage is charged to the account
+        assert_eq!(
+            account_manager.ops_storage_usage(to_valid_account_id(ACCOUNT)),
+            storage_usage
+        );
+        let logs = test_utils::get_logs();
+        println!("{:#?}", logs);
+        assert_eq!(
+            logs.last().unwrap(),
+            "[INFO] [ACCOUNTS_INDEXED] operator=operator, accounts=1"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
+    fn index_accounts_not_operator() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+
+        // Act
+        ctx.predecessor(ACCOUNT).apply();
+        account_manager.ops_storage_index_accounts(vec![to_valid_account_id(ACCOUNT)]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"ACCOUNT_NOT_REGISTERED\""#)]
+    fn index_accounts_not_registered() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+
+        // Act
+        ctx.predecessor(OPERATOR).apply();
+        account_manager.ops_storage_index_accounts(vec![to_valid_account_id("alice")]);
+    }
+}
+
+#[cfg(test)]
+mod tests_storage_deposit_many {
+    use super::*;
+    use oysterpack_smart_near::YOCTO;
+    use oysterpack_smart_near_test::*;
+
+    type AccountManager = AccountManagementComponent<()>;
+
+    const ADMIN: &str = "admin";
+    const ACCOUNT: &str = "bob";
+
+    #[test]
+    fn register_many_accounts() {
+        // Arrange
+        let mut ctx = TestCtx::new(ADMIN);
+        ctx.apply();
+        AccountManager::deploy(AccountManagementComponentConfig::new(to_valid_account_id(
+            ADMIN,
+        )));
+        let mut account_manager = AccountManager::new(Default::default());
+        ctx.predecessor(ACCOUNT).with_deposit(YOCTO).apply();
+        account_manager.storage_deposit(None, None);
+        let storage_balance_min = account_manager.storage_balance_bounds().min;
+
+        // Act - any account can register accounts
+        ctx.predecessor("exchange")
+            .with_deposit(*storage_balance_min * 3)
+            .apply();
+        let storage_balances = account_manager.storage_deposit_many(vec![
+            to_valid_account_id("alice"),
+            to_valid_account_id(ACCOUNT),
+            to_valid_account_id("charlie"),
+            to_valid_account_id("alice"),
+        ]);
+
+        // Assert - already registered and duplicate accounts are skipped
+        assert_eq!(storage_balances.len(), 4);
+        for i in [0, 2, 3].iter() {
+            assert_eq!(storage_balances[*i].total, storage_balance_min);
+            assert_eq!(storage_balances[*i].available, YoctoNear::ZERO);
+        }
+        assert_eq!(storage_balances[1].total, YOCTO.into());
+        assert!(account_manager.account_exists("charlie"));
+
+        // Assert - excess deposit is refunded to the predecessor
+        let receipts = deserialize_receipts();
+        assert_eq!(receipts.len(), 1);
+        receipts[0]
+            .assert_receiver("exchange")
+            .assert_transfer(*storage_balance_min);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
+    fn register_many_accounts_with_insufficient_deposit() {
+        // Arrange
+        let mut ctx = TestCtx::new(ADMIN);
+        ctx.apply();
+        AccountManager::deploy(AccountManagementComponentConfig::new(to_valid_account_id(
+            ADMIN,
+        )));
+        let mut account_manager = AccountManager::new(Default::default());
+        let storage_balance_min = account_manager.storage_balance_bounds().min;
+
+        // Act
+        ctx.with_deposit(*storage_balance_min).apply();
+        account_manager.storage_deposit_many(vec![
+            to_valid_account_id("alice"),
+            to_valid_account_id("charlie"),
+        ]);
+    }
+}
+
+#[cfg(test)]
+mod tests_account_profile {
+    use super::*;
+    use oysterpack_smart_near::near_sdk::test_utils;
+    use oysterpack_smart_near::YOCTO;
+    use oysterpack_smart_near_test::*;
+
+    type AccountManager = AccountManagementComponent<()>;
+
+    const ADMIN: &str = "admin";
+    const ACCOUNT: &str = "bob";
+
+    /// deploys the account manager and registers an account with enough storage balance to pay for
+    /// the profile storage
+    fn setup() -> (TestCtx, AccountManager) {
+        let mut ctx = TestCtx::new(ADMIN);
+        ctx.apply();
+        AccountManager::deploy(AccountManagementComponentConfig::new(to_valid_account_id(
+            ADMIN,
+        )));
+        let mut account_manager = AccountManager::new(Default::default());
+
+        ctx.predecessor(ACCOUNT).with_deposit(YOCTO).apply();
+        account_manager.storage_deposit(None, None);
+
+        ctx.with_deposit(1).apply();
+        (ctx, account_manager)
+    }
+
+    fn profile() -> AccountProfile {
+        AccountProfile {
+            name: Some("Bob".to_string()),
+            url: Some("https://bob.example.com".to_string()),
+            avatar_hash: Some(oysterpack_smart_near::Hash::from("avatar")),
+        }
+    }
+
+    #[test]
+    fn set_and_clear_profile() {
+        // Arrange
+        let (mut ctx, mut account_manager) = setup();
+        let storage_usage = account_manager
+            .ops_storage_usage(to_valid_account_id(ACCOUNT))
+            .unwrap();
+
+        // Act
+        account_manager.ops_account_profile_set(Some(profile()));
+
+        // Assert
+        assert_eq!(
+            account_manager.ops_account_profile_get(to_valid_account_id(ACCOUNT)),
+            Some(profile())
+        );
+        assert!(
+            account_manager
+                .ops_storage_usage(to_valid_account_id(ACCOUNT))
+                .unwrap()
+                > storage_usage
+        );
+        let logs = test_utils::get_logs();
+        println!("{:#?}", logs);
+        assert!(logs.contains(&"[INFO] [ACCOUNT_PROFILE] account_id=bob, action=set".to_string()));
+
+        // Act - update the profile
+        let updated_profile = AccountProfile {
+            name: Some("Bobby".to_string()),
+            ..Default::default()
+        };
+        account_manager.ops_account_profile_set(Some(updated_profile.clone()));
+        assert_eq!(
+            account_manager.ops_account_profile_get(to_valid_account_id(ACCOUNT)),
+            Some(updated_profile)
+        );
+
+        // Act - clear the profile
+        ctx.with_deposit(1).apply();
+        account_manager.ops_account_profile_set(None);
+
+        // Assert
+        assert!(account_manager
+            .ops_account_profile_get(to_valid_account_id(ACCOUNT))
+            .is_none());
+        assert_eq!(
+            account_manager
+                .ops_storage_usage(to_valid_account_id(ACCOUNT))
+                .unwrap(),
+            storage_usage
+        );
+        let logs = test_utils::get_logs();
+        println!("{:#?}", logs);
+        assert!(
+            logs.contains(&"[INFO] [ACCOUNT_PROFILE] account_id=bob, action=cleared".to_string())
+        );
+    }
+
+    #[test]
+    fn profile_deleted_when_account_unregistered() {
+        // Arrange
+        let (_ctx, mut account_manager) = setup();
+        account_manager.ops_account_profile_set(Some(profile()));
+
+        // Act
+        account_manager.storage_unregister(Some(true));
+
+        // Assert
+        assert!(account_manager
+            .ops_account_profile_get(to_valid_account_id(ACCOUNT))
+            .is_none());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"INVALID\",\"message\":\"name max length is 64 bytes\"}"#
+    )]
+    fn name_too_long() {
+        let (_ctx, mut account_manager) = setup();
+        account_manager.ops_account_profile_set(Some(AccountProfile {
+            name: Some("a".repeat(AccountProfile::MAX_NAME_LEN + 1)),
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"INVALID\",\"message\":\"url cannot be blank\"}"#)]
+    fn blank_url() {
+        let (_ctx, mut account_manager) = setup();
+        account_manager.ops_account_profile_set(Some(AccountProfile {
+            url: Some("  ".to_string()),
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"ACCOUNT_NOT_REGISTERED\""#)]
+    fn account_not_registered() {
+        let (mut ctx, mut account_manager) = setup();
+        ctx.predecessor("alice").apply();
+        account_manager.ops_account_profile_set(Some(profile()));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_STORAGE_BALANCE\""#)]
+    fn insufficient_storage_balance() {
+        let (mut ctx, mut account_manager) = setup();
+        ctx.predecessor("alice")
+            .with_deposit(*account_manager.storage_balance_bounds().min)
+            .apply();
+        account_manager.storage_deposit(None, Some(true));
+
+        ctx.with_deposit(1).apply();
+        account_manager.ops_account_profile_set(Some(profile()));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
+    fn requires_one_yocto() {
+        let (mut ctx, mut account_manager) = setup();
+        ctx.with_deposit(0).apply();
+        account_manager.ops_account_profile_set(None);
+    }
+}
@@ -19,8 +19,11 @@ where
     ) -> Account<T> {
         ERR_ACCOUNT_ALREADY_REGISTERED.assert(|| !AccountNearDataObject::exists(account_id));
 
-        let near_data = AccountNearDataObject::new(account_id, near_balance);
-        near_data.save();
+        AccountNearDataObject::new(account_id, near_balance).save();
+        AccountIndex::add(account_id);
+        // the account storage usage is updated by the storage event handler - thus the object state
+        // becomes stale, and we need to return a fresh updated instance from storage
+        let near_data = AccountNearDataObject::load(account_id).unwrap();
 
         match data {
             Some(data) => {
@@ -73,6 +76,8 @@ where
 
     fn delete_account(&mut self, account_id: &str) {
         if let Some((near_data, data)) = self.load_account(account_id) {
+            AccountIndex::remove(account_id);
+            AccountProfile::delete(account_id);
             near_data.delete();
             if let Some(data) = data {
                 data.delete();
@@ -84,7 +89,6 @@ where
 #[cfg(test)]
 mod tests_account_repository {
     use super::*;
-    use oysterpack_smart_near::near_sdk;
     use oysterpack_smart_near::YOCTO;
     use oysterpack_smart_near_test::*;
     use std::ops::Deref;
@@ -94,8 +98,8 @@ mod tests_account_repository {
     #[test]
     fn crud() {
         let account = "alfio";
-        let ctx = new_context(account);
-        testing_env!(ctx);
+        let mut ctx = TestCtx::new(account);
+        ctx.apply();
 
         let mut accounts = Accounts::default();
         let service: &mut dyn AccountRepository<String> = &mut accounts;
@@ -139,11 +143,11 @@ mod tests_account_repository {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [ACCOUNT_ALREADY_REGISTERED]")]
+    #[should_panic(expected = r#"{\"code\":\"ACCOUNT_ALREADY_REGISTERED\""#)]
     fn create_account_already_exists() {
         let account = "alfio";
-        let ctx = new_context(account);
-        testing_env!(ctx);
+        let mut ctx = TestCtx::new(account);
+        ctx.apply();
 
         let mut accounts = Accounts::default();
         let service: &mut dyn AccountRepository<String> = &mut accounts;
@@ -18,6 +18,12 @@ impl AccountStorageUsage for AccountStorageUsageComponent {
     }
 }
 
+impl AccountStorageUsageComponent {
+    pub(crate) fn set_storage_usage_bounds(bounds: StorageUsageBounds) {
+        Self::new_state(bounds).save();
+    }
+}
+
 impl Component for AccountStorageUsageComponent {
     type State = StorageUsageBounds;
 
@@ -1,21 +1,31 @@
 pub use account_data::*;
+pub use account_index::*;
 pub use account_metrics::*;
 pub use account_near_data::*;
+pub use account_profile::*;
 pub use account_storage_event::*;
+pub use admin_approvals::*;
 pub use contract_permissions::*;
 pub use oysterpack_smart_near::domain::AccountIdHash;
+pub use permission_expiries::*;
 pub use permissions::*;
+pub use permissions_audit_log::*;
 pub use storage_balance::*;
 pub use storage_balance_bounds::*;
 pub use storage_management_event::*;
 pub use storage_usage_bounds::*;
 
 mod account_data;
+mod account_index;
 mod account_metrics;
 mod account_near_data;
+mod account_profile;
 mod account_storage_event;
+mod admin_approvals;
 mod contract_permissions;
+mod permission_expiries;
 mod permissions;
+mod permissions_audit_log;
 mod storage_balance;
 mod storage_balance_bounds;
 mod storage_management_event;
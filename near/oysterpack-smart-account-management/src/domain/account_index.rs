@@ -0,0 +1,190 @@
+use crate::AccountStorageEvent;
+use oysterpack_smart_near::near_sdk::{env, AccountId};
+use oysterpack_smart_near::{data::Object, eventbus::post, Hash};
+use std::ops::Deref;
+
+const ACCOUNT_INDEX_COUNT_KEY: u128 = 1957734102257930451720166832184410863;
+const ACCOUNT_INDEX_ENTRY_KEY: u128 = 1957734123816480957112553196570413650;
+const ACCOUNT_INDEX_POSITION_KEY: u128 = 1957734145090227763851830017934556228;
+
+type AccountIndexCountObject = Object<u128, u64>;
+type AccountIndexEntryObject = Object<Hash, AccountId>;
+type AccountIndexPositionObject = Object<Hash, u64>;
+
+/// Index of registered account IDs, which enables registered accounts to be enumerated, e.g., to
+/// export accounts when migrating to a new contract
+///
+/// ## NOTES
+/// - account positions are not stable - when an account is removed, the last account is moved
+///   into its position
+/// - the index is maintained by the account repository when accounts are created and deleted
+/// - the account's index entry storage is charged to the account, which is why it is included in
+///   the account storage usage min - the account count is contract storage
+/// - accounts that were registered before the index was introduced can be backfilled via
+///   [`crate::StorageUsageBoundsManagement::ops_storage_index_accounts`]
+pub struct AccountIndex;
+
+impl AccountIndex {
+    /// number of indexed accounts
+    pub fn count() -> u64 {
+        AccountIndexCountObject::load(&ACCOUNT_INDEX_COUNT_KEY).map_or(0, |count| *count)
+    }
+
+    pub fn contains(account_id: &str) -> bool {
+        AccountIndexPositionObject::exists(&Self::position_key(account_id))
+    }
+
+    /// returns up to `limit` account IDs starting from the `from` position
+    pub fn account_ids(from: u64, limit: u64) -> Vec<AccountId> {
+        let end = Self::count().min(from.saturating_add(limit));
+        (from..end)
+            .filter_map(|position| {
+                AccountIndexEntryObject::load(&Self::entry_key(position))
+                    .map(|account_id| account_id.deref().clone())
+            })
+            .collect()
+    }
+
+    /// adds the account to the index - no-op if the account is already indexed
+    /// - tracks storage usage against the account - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn add(account_id: &str) {
+        if Self::contains(account_id) {
+            return;
+        }
+        let position = Self::count();
+        // the count is contract storage, i.e., it is updated outside the account's storage usage
+        // measurement
+        AccountIndexCountObject::new(ACCOUNT_INDEX_COUNT_KEY, position + 1).save();
+        let initial_storage_usage = env::storage_usage();
+        AccountIndexEntryObject::new(Self::entry_key(position), account_id.to_string()).save();
+        AccountIndexPositionObject::new(Self::position_key(account_id), position).save();
+        Self::track_storage_usage(account_id, initial_storage_usage);
+    }
+
+    /// removes the account from the index - no-op if the account is not indexed
+    /// - tracks storage usage against the account - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn remove(account_id: &str) {
+        let position = match AccountIndexPositionObject::load(&Self::position_key(account_id)) {
+            Some(position) => *position,
+            None => return,
+        };
+        let last_position = Self::count() - 1;
+        AccountIndexCountObject::new(ACCOUNT_INDEX_COUNT_KEY, last_position).save();
+        // the last account's entry is moved as is, thus the storage usage change is charged to the
+        // removed account
+        let initial_storage_usage = env::storage_usage();
+        if position != last_position {
+            let last_account_id = AccountIndexEntryObject::load(&Self::entry_key(last_position))
+                .unwrap()
+                .deref()
+                .clone();
+            AccountIndexEntryObject::new(Self::entry_key(position), last_account_id.clone()).save();
+            AccountIndexPositionObject::new(Self::position_key(&last_account_id), position).save();
+        }
+        AccountIndexEntryObject::delete_by_key(&Self::entry_key(last_position));
+        AccountIndexPositionObject::delete_by_key(&Self::position_key(account_id));
+        Self::track_storage_usage(account_id, initial_storage_usage);
+    }
+
+    fn entry_key(position: u64) -> Hash {
+        Hash::from((&position.to_le_bytes()[..], ACCOUNT_INDEX_ENTRY_KEY))
+    }
+
+    fn position_key(account_id: &str) -> Hash {
+        Hash::from((account_id, ACCOUNT_INDEX_POSITION_KEY))
+    }
+
+    fn track_storage_usage(account_id: &str, initial_storage_usage: u64) {
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AccountMetrics, AccountNearDataObject};
+    use oysterpack_smart_near::YOCTO;
+    use oysterpack_smart_near_test::*;
+
+    #[test]
+    fn add_and_remove() {
+        // Arrange
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        // Act
+        for account_id in ["alice", "bob", "charlie", "dave"].iter() {
+            AccountIndex::add(account_id);
+        }
+        AccountIndex::add("bob");
+
+        // Assert
+        assert_eq!(AccountIndex::count(), 4);
+        assert_eq!(
+            AccountIndex::account_ids(0, 10),
+            vec!["alice", "bob", "charlie", "dave"]
+        );
+        assert_eq!(AccountIndex::account_ids(1, 2), vec!["bob", "charlie"]);
+        assert!(AccountIndex::account_ids(4, 10).is_empty());
+
+        // Act - the last account is moved into the removed account's position
+        let storage_usage = env::storage_usage();
+        AccountIndex::remove("bob");
+        AccountIndex::remove("bob");
+
+        // Assert
+        assert!(env::storage_usage() < storage_usage);
+        assert!(!AccountIndex::contains("bob"));
+        assert_eq!(AccountIndex::count(), 3);
+        assert_eq!(
+            AccountIndex::account_ids(0, 10),
+            vec!["alice", "dave", "charlie"]
+        );
+
+        // Act - remove the last account
+        AccountIndex::remove("charlie");
+        AccountIndex::remove("alice");
+        AccountIndex::remove("dave");
+
+        // Assert
+        assert_eq!(AccountIndex::count(), 0);
+        assert!(AccountIndex::account_ids(0, 10).is_empty());
+    }
+
+    #[test]
+    fn storage_usage_is_charged_to_account() {
+        // Arrange
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+        AccountMetrics::register_account_storage_event_handler();
+        AccountNearDataObject::new("bob", YOCTO.into()).save();
+        let storage_usage = AccountNearDataObject::load("bob").unwrap().storage_usage();
+
+        // Act
+        let initial_storage_usage = env::storage_usage();
+        AccountIndex::add("bob");
+
+        // Assert - the count is contract storage, i.e., it is not charged to the account
+        let account_storage_usage = AccountNearDataObject::load("bob").unwrap().storage_usage();
+        assert!(account_storage_usage > storage_usage);
+        assert!(
+            account_storage_usage.value() - storage_usage.value()
+                < env::storage_usage() - initial_storage_usage
+        );
+
+        // Act
+        AccountIndex::remove("bob");
+
+        // Assert
+        assert_eq!(
+            AccountNearDataObject::load("bob").unwrap().storage_usage(),
+            storage_usage
+        );
+    }
+}
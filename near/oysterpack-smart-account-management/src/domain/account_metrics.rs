@@ -8,7 +8,7 @@ use crate::AccountNearDataObject;
 use lazy_static::lazy_static;
 use oysterpack_smart_near::{
     data::{numbers::U128, Object},
-    domain::{StorageUsage, YoctoNear},
+    domain::{EpochHeight, StorageUsage, YoctoNear},
     eventbus,
 };
 use std::sync::Mutex;
@@ -24,6 +24,28 @@ pub struct AccountMetrics {
     pub total_storage_usage: StorageUsage,
 }
 
+/// Account metrics as of the start of the epoch
+/// - snapshots are recorded at epoch boundaries, i.e., when the first account storage event is
+///   processed in a new epoch, which enables growth to be tracked without an off-chain indexer
+#[derive(
+    BorshSerialize, BorshDeserialize, Deserialize, Serialize, Copy, Clone, Debug, PartialEq, Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct AccountMetricsSnapshot {
+    pub epoch_height: EpochHeight,
+    pub metrics: AccountMetrics,
+}
+
+/// tracks the snapshot ring buffer state
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+struct AccountMetricsHistoryState {
+    /// ring buffer index where the next snapshot will be stored
+    next_index: u16,
+    /// number of snapshots that are stored
+    len: u16,
+    last_epoch_height: EpochHeight,
+}
+
 lazy_static! {
     static ref ACCOUNT_STORAGE_EVENT_HANDLER_REGISTERED: Mutex<bool> = Mutex::new(false);
 }
@@ -31,6 +53,12 @@ lazy_static! {
 const ACCOUNT_METRICS_KEY: u128 = 1952364736129901845182088441739779955;
 type DAO = Object<u128, AccountMetrics>;
 
+const ACCOUNT_METRICS_HISTORY_STATE_KEY: u128 = 1958133105764020893467521902215394688;
+/// the snapshot storage key is computed as `ACCOUNT_METRICS_SNAPSHOTS_KEY + index`
+const ACCOUNT_METRICS_SNAPSHOTS_KEY: u128 = 1958133142290813706290618331908186112;
+type AccountMetricsHistoryStateObject = Object<u128, AccountMetricsHistoryState>;
+type AccountMetricsSnapshotObject = Object<u128, AccountMetricsSnapshot>;
+
 impl AccountMetrics {
     pub fn load() -> AccountMetrics {
         let stats = DAO::load(&ACCOUNT_METRICS_KEY)
@@ -42,6 +70,91 @@ impl AccountMetrics {
         DAO::new(ACCOUNT_METRICS_KEY, *self).save();
     }
 
+    /// max number of epoch snapshots that are retained - once full, the oldest snapshot is overwritten
+    pub const MAX_SNAPSHOTS: u16 = 60;
+
+    /// Pre-allocates the snapshot history storage, which is paid for by the contract.
+    /// - snapshots are recorded while account storage usage is being tracked, thus the snapshot
+    ///   storage is allocated up front to ensure that recording a snapshot never changes the contract
+    ///   storage usage
+    /// - snapshots are not recorded until the history storage is allocated
+    /// - can be safely called multiple times
+    pub fn init_history() {
+        if Self::history_initialized() {
+            return;
+        }
+        for i in 0..Self::MAX_SNAPSHOTS {
+            AccountMetricsSnapshotObject::new(Self::snapshot_key(i), Default::default()).save();
+        }
+        AccountMetricsHistoryStateObject::new(
+            ACCOUNT_METRICS_HISTORY_STATE_KEY,
+            AccountMetricsHistoryState {
+                next_index: 0,
+                len: 0,
+                last_epoch_height: EpochHeight::from_env(),
+            },
+        )
+        .save();
+    }
+
+    /// returns true if the snapshot history storage has been allocated - see [`AccountMetrics::init_history`]
+    pub fn history_initialized() -> bool {
+        AccountMetricsHistoryStateObject::exists(&ACCOUNT_METRICS_HISTORY_STATE_KEY)
+    }
+
+    /// returns the most recent epoch snapshots ordered from oldest to newest
+    pub fn history(limit: u16) -> Vec<AccountMetricsSnapshot> {
+        let state = match Self::history_state() {
+            Some(state) => state,
+            None => return vec![],
+        };
+        let count = limit.min(state.len);
+        (0..count)
+            .rev()
+            .filter_map(|offset| {
+                let index =
+                    (state.next_index + Self::MAX_SNAPSHOTS - 1 - offset) % Self::MAX_SNAPSHOTS;
+                AccountMetricsSnapshotObject::load(&Self::snapshot_key(index))
+                    .map(|snapshot| *snapshot)
+            })
+            .collect()
+    }
+
+    /// records the current metrics as the snapshot for the current epoch, if the epoch has not yet
+    /// been snapshotted
+    fn record_snapshot(&self) {
+        let mut state = match Self::history_state() {
+            Some(state) => state,
+            None => return,
+        };
+        let epoch_height = EpochHeight::from_env();
+        if epoch_height <= state.last_epoch_height {
+            return;
+        }
+
+        AccountMetricsSnapshotObject::new(
+            Self::snapshot_key(state.next_index),
+            AccountMetricsSnapshot {
+                epoch_height,
+                metrics: *self,
+            },
+        )
+        .save();
+        state.next_index = (state.next_index + 1) % Self::MAX_SNAPSHOTS;
+        state.len = Self::MAX_SNAPSHOTS.min(state.len + 1);
+        state.last_epoch_height = epoch_height;
+        AccountMetricsHistoryStateObject::new(ACCOUNT_METRICS_HISTORY_STATE_KEY, state).save();
+    }
+
+    fn history_state() -> Option<AccountMetricsHistoryState> {
+        AccountMetricsHistoryStateObject::load(&ACCOUNT_METRICS_HISTORY_STATE_KEY)
+            .map(|state| *state)
+    }
+
+    fn snapshot_key(index: u16) -> u128 {
+        ACCOUNT_METRICS_SNAPSHOTS_KEY + index as u128
+    }
+
     #[cfg(test)]
     pub(crate) fn reset() {
         let mut stats = AccountMetrics::load();
@@ -52,7 +165,7 @@ impl AccountMetrics {
     }
 
     /// can be safely called multiple times and will only register the event handler once
-    pub(crate) fn register_account_storage_event_handler() {
+    pub fn register_account_storage_event_handler() {
         let mut registered = ACCOUNT_STORAGE_EVENT_HANDLER_REGISTERED.lock().unwrap();
         if !*registered {
             eventbus::register(AccountMetrics::on_account_storage_event);
@@ -64,6 +177,8 @@ impl AccountMetrics {
         event.log();
 
         let mut stats = AccountMetrics::load();
+        // the metrics are snapshotted before the first change is applied in a new epoch
+        stats.record_snapshot();
 
         match *event {
             AccountStorageEvent::Registered(storage_balance) => {
@@ -144,7 +259,7 @@ impl AccountMetrics {
 mod test {
     use super::*;
     use crate::StorageBalance;
-    use near_sdk::test_utils;
+    use near_sdk::{env, test_utils};
     use oysterpack_smart_near::domain::StorageUsageChange;
     use oysterpack_smart_near::*;
     use oysterpack_smart_near_test::*;
@@ -248,4 +363,68 @@ mod test {
         println!("{:#?}", logs);
         assert_eq!(logs.len(), 7);
     }
+
+    #[test]
+    fn history() {
+        // Arrange
+        let mut context = new_context("bob.near");
+        context.epoch_height = 10;
+        testing_env!(context.clone());
+        AccountMetrics::register_account_storage_event_handler();
+        AccountMetrics::reset();
+
+        // snapshots are not recorded until the history is initialized
+        eventbus::post(&AccountStorageEvent::Deposit(YOCTO.into()));
+        context.epoch_height = 11;
+        testing_env!(context.clone());
+        eventbus::post(&AccountStorageEvent::Deposit(YOCTO.into()));
+        assert!(AccountMetrics::history(AccountMetrics::MAX_SNAPSHOTS).is_empty());
+
+        AccountMetrics::init_history();
+
+        // Act - events within the same epoch the history was initialized in are not snapshotted
+        eventbus::post(&AccountStorageEvent::Deposit(YOCTO.into()));
+        assert!(AccountMetrics::history(AccountMetrics::MAX_SNAPSHOTS).is_empty());
+
+        // Act - the first event in the next epoch snapshots the metrics before the change is applied
+        context.epoch_height = 12;
+        testing_env!(context.clone());
+        let storage_usage = env::storage_usage();
+        eventbus::post(&AccountStorageEvent::Deposit(YOCTO.into()));
+        eventbus::post(&AccountStorageEvent::Deposit(YOCTO.into()));
+
+        // Assert
+        let history = AccountMetrics::history(AccountMetrics::MAX_SNAPSHOTS);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].epoch_height, 12.into());
+        assert_eq!(history[0].metrics.total_near_balance, (3 * YOCTO).into());
+        // snapshot storage is pre-allocated
+        assert_eq!(env::storage_usage(), storage_usage);
+
+        // Act - fill up the ring buffer
+        for epoch_height in 13..(13 + AccountMetrics::MAX_SNAPSHOTS as u64) {
+            context.epoch_height = epoch_height;
+            testing_env!(context.clone());
+            let storage_usage = env::storage_usage();
+            eventbus::post(&AccountStorageEvent::Deposit(YOCTO.into()));
+            assert_eq!(env::storage_usage(), storage_usage);
+        }
+
+        // Assert - the oldest snapshot was overwritten
+        let history = AccountMetrics::history(AccountMetrics::MAX_SNAPSHOTS);
+        assert_eq!(history.len(), AccountMetrics::MAX_SNAPSHOTS as usize);
+        assert_eq!(history[0].epoch_height, 13.into());
+        assert_eq!(history[0].metrics.total_near_balance, (5 * YOCTO).into());
+        assert_eq!(
+            history.last().unwrap().epoch_height,
+            (12 + AccountMetrics::MAX_SNAPSHOTS as u64).into()
+        );
+
+        let history = AccountMetrics::history(2);
+        assert_eq!(history.len(), 2);
+        assert_eq!(
+            history[1].epoch_height,
+            (12 + AccountMetrics::MAX_SNAPSHOTS as u64).into()
+        );
+    }
 }
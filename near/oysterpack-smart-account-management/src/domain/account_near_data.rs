@@ -1,5 +1,6 @@
 use crate::{
-    AccountIdHash, AccountStorageEvent, Permissions, StorageBalance, ERR_ACCOUNT_NOT_REGISTERED,
+    AccountIdHash, AccountStorageEvent, AdminApprovals, PermissionExpiries, Permissions,
+    StorageBalance, ERR_ACCOUNT_NOT_REGISTERED,
 };
 use oysterpack_smart_near::near_sdk::{
     borsh::{self, BorshDeserialize, BorshSerialize},
@@ -10,13 +11,16 @@ use oysterpack_smart_near::{
     domain::{StorageUsage, YoctoNear},
     eventbus, Hash,
 };
+use std::cell::Cell;
 use std::ops::{Deref, DerefMut};
 
 type DAO = Object<AccountNearDataHash, AccountNearData>;
 
 /// Persistent account NEAR related data
+/// - tracks whether the persisted account is an admin, which keeps the admin count in sync when
+///   the admin permission is granted or revoked - see [`AdminApprovals::admin_count`]
 #[derive(Clone, Debug, PartialEq)]
-pub struct AccountNearDataObject(DAO);
+pub struct AccountNearDataObject(DAO, Cell<bool>);
 
 impl AccountNearDataObject {
     /// Creates a new in memory account object
@@ -27,15 +31,27 @@ impl AccountNearDataObject {
             account_id.into(),
             AccountNearData::new(near_balance, 0.into()),
         );
-        Self(object)
+        Self(object, Cell::new(false))
     }
 
     /// tries to load the account from storage
+    /// - expired permissions are revoked - see [`PermissionExpiries`]
     pub fn load<ID>(account_id: ID) -> Option<Self>
     where
         ID: Into<AccountNearDataHash>,
     {
-        DAO::load(&account_id.into()).map(|object| Self(object))
+        DAO::load(&account_id.into()).map(|mut object| {
+            let admin = object.is_admin();
+            if object.permissions().is_some() {
+                if let Some(expiries) = PermissionExpiries::load(object.key().account_id_hash()) {
+                    let expired = expiries.expired();
+                    if expired.has_permissions() {
+                        object.revoke(expired);
+                    }
+                }
+            }
+            Self(object, Cell::new(admin))
+        })
     }
 
     /// ## Panics
@@ -58,6 +74,12 @@ impl AccountNearDataObject {
     }
 
     pub fn save(&self) {
+        // the admin count is contract storage, i.e., it is updated outside the account's storage
+        // usage measurement
+        if self.is_admin() != self.1.get() {
+            AdminApprovals::update_admin_count(self.is_admin());
+            self.1.set(self.is_admin());
+        }
         let storage_usage_before_save = env::storage_usage();
         self.0.save();
         let storage_usage_after_save = env::storage_usage();
@@ -80,7 +102,11 @@ impl AccountNearDataObject {
     /// tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`]
     pub fn delete(self) -> bool {
         let key = self.key().0;
+        if self.1.get() {
+            AdminApprovals::update_admin_count(false);
+        }
         let storage_usage_before_save = env::storage_usage();
+        PermissionExpiries::delete(key);
         let result = self.0.delete();
         let storage_usage_deleted = storage_usage_before_save - env::storage_usage();
         if storage_usage_deleted > 0 {
@@ -144,10 +170,15 @@ impl AccountNearData {
         self.permissions
     }
 
+    /// the available balance is zero if the NEAR balance is below the required min storage balance,
+    /// e.g., after the storage usage bounds were raised and before the account was topped up
     pub fn storage_balance(&self, required_min_storage_balance: YoctoNear) -> StorageBalance {
         StorageBalance {
             total: self.near_balance,
-            available: (self.near_balance.value() - required_min_storage_balance.value()).into(),
+            available: self
+                .near_balance
+                .saturating_sub(required_min_storage_balance.value())
+                .into(),
         }
     }
 
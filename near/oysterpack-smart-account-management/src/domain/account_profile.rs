@@ -0,0 +1,78 @@
+use crate::AccountStorageEvent;
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::StorageUsage;
+use oysterpack_smart_near::eventbus::post;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+};
+use oysterpack_smart_near::Hash;
+
+/// Human friendly account info that is displayed by frontends, e.g., for top stakers and treasurers
+/// - the profile fields are byte bounded, which keeps the profile storage small
+///
+/// The profile storage usage is charged to the account.
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq, Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct AccountProfile {
+    /// display name - max [`AccountProfile::MAX_NAME_LEN`] bytes
+    pub name: Option<String>,
+    /// max [`AccountProfile::MAX_URL_LEN`] bytes
+    pub url: Option<String>,
+    /// sha256 hash of the avatar image, which is stored off-chain
+    pub avatar_hash: Option<Hash>,
+}
+
+const ACCOUNT_PROFILE_KEY: u128 = 1958097160437221862419367512280315929;
+
+type AccountProfileObject = Object<Hash, AccountProfile>;
+
+impl AccountProfile {
+    pub const MAX_NAME_LEN: usize = 64;
+    pub const MAX_URL_LEN: usize = 256;
+
+    fn key(account_id: &str) -> Hash {
+        Hash::from((account_id, ACCOUNT_PROFILE_KEY))
+    }
+
+    pub fn load(account_id: &str) -> Option<Self> {
+        AccountProfileObject::load(&Self::key(account_id)).map(|profile| (*profile).clone())
+    }
+
+    /// tracks storage usage against the account - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    ///
+    /// Returns the profile storage usage
+    pub(crate) fn save(&self, account_id: &str) -> StorageUsage {
+        let initial_storage_usage = env::storage_usage();
+        Self::delete_profile(account_id);
+        let storage_usage_before_save = env::storage_usage();
+        AccountProfileObject::new(Self::key(account_id), self.clone()).save();
+        let profile_storage_usage = env::storage_usage() - storage_usage_before_save;
+        Self::track_storage_usage(account_id, initial_storage_usage);
+        profile_storage_usage.into()
+    }
+
+    /// tracks storage usage against the account - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn delete(account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        Self::delete_profile(account_id);
+        Self::track_storage_usage(account_id, initial_storage_usage);
+    }
+
+    fn delete_profile(account_id: &str) {
+        AccountProfileObject::delete_by_key(&Self::key(account_id));
+    }
+
+    fn track_storage_usage(account_id: &str, initial_storage_usage: u64) {
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{
+    AccountNearDataObject, ERR_APPROVAL_NOT_PENDING, ERR_NOT_AUTHORIZED,
+    ERR_PENDING_APPROVALS_FULL, LOG_EVENT_APPROVAL_EXECUTED, LOG_EVENT_APPROVAL_GRANTED,
+    LOG_EVENT_APPROVAL_PENDING,
+};
+use oysterpack_smart_near::asserts::ERR_INVALID;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::{data::Object, domain::BlockTime, Hash};
+use std::ops::Deref;
+
+/// M-of-N admin approval policy for sensitive admin operations
+/// - N is the number of accounts with admin permission
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct ApprovalPolicy {
+    /// number of distinct admin approvals required before an operation can be executed
+    pub required_approvals: u8,
+}
+
+/// Operation that is waiting for admin approvals before it can be executed
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct PendingApproval {
+    /// sha256 hash of the operation name and its args
+    pub operation_id: Hash,
+    /// contract function name
+    pub operation: String,
+    pub proposed_by: AccountId,
+    pub proposed_on: BlockTime,
+    /// admin accounts that have approved the operation
+    pub approvals: Vec<AccountId>,
+}
+
+const APPROVAL_POLICY_KEY: u128 = 1957551830413625806247361735890475837;
+const PENDING_APPROVALS_KEY: u128 = 1957551867327905364119347251650093322;
+const ADMIN_COUNT_KEY: u128 = 1957551902846153261740911573852308146;
+
+type ApprovalPolicyObject = Object<u128, ApprovalPolicy>;
+type PendingApprovalsObject = Object<u128, Vec<PendingApproval>>;
+type AdminCountObject = Object<u128, u32>;
+
+/// Pending approvals store, which is used to gate sensitive admin operations behind an M-of-N
+/// admin approval quorum
+///
+/// Sensitive operations use [`AdminApprovals::check`] before executing:
+/// 1. the first time the operation is invoked, it is enqueued into the pending approvals store
+/// 2. admins approve the pending operation
+/// 3. once quorum is reached, the next time the operation is invoked with the same args, it will
+///    be executed and removed from the pending approvals store
+pub struct AdminApprovals;
+
+impl AdminApprovals {
+    /// max number of operations that can be pending approval, which bounds the pending approvals
+    /// store size
+    pub const MAX_PENDING: usize = 20;
+
+    pub fn policy() -> Option<ApprovalPolicy> {
+        ApprovalPolicyObject::load(&APPROVAL_POLICY_KEY).map(|object| *object.deref())
+    }
+
+    /// if the policy is cleared, then any pending approvals are cleared as well
+    pub fn set_policy(policy: Option<ApprovalPolicy>) {
+        match policy {
+            None => {
+                ApprovalPolicyObject::delete_by_key(&APPROVAL_POLICY_KEY);
+                PendingApprovalsObject::delete_by_key(&PENDING_APPROVALS_KEY);
+            }
+            Some(policy) => ApprovalPolicyObject::new(APPROVAL_POLICY_KEY, policy).save(),
+        }
+    }
+
+    /// number of accounts with admin permission, i.e., the N in the M-of-N approval policy
+    /// - maintained when admin accounts are saved and deleted - see [`AccountNearDataObject`]
+    pub fn admin_count() -> u32 {
+        AdminCountObject::load(&ADMIN_COUNT_KEY).map_or(0, |count| *count)
+    }
+
+    pub(crate) fn update_admin_count(admin_granted: bool) {
+        let count = Self::admin_count();
+        let count = if admin_granted {
+            count + 1
+        } else {
+            count.saturating_sub(1)
+        };
+        AdminCountObject::new(ADMIN_COUNT_KEY, count).save();
+    }
+
+    /// ## Panics
+    /// if the number of admins would fall below the policy's required approvals, which would
+    /// deadlock every operation that requires approval
+    pub fn assert_admin_quorum(admin_count: u32) {
+        if let Some(policy) = Self::policy() {
+            ERR_INVALID.assert(
+                || admin_count >= policy.required_approvals as u32,
+                || {
+                    format!(
+                        "required_approvals ({}) must be <= the number of admins ({})",
+                        policy.required_approvals, admin_count
+                    )
+                },
+            );
+        }
+    }
+
+    pub fn pending() -> Vec<PendingApproval> {
+        PendingApprovalsObject::load(&PENDING_APPROVALS_KEY)
+            .map_or_else(Vec::new, |object| object.deref().clone())
+    }
+
+    pub fn operation_id(operation: &str, args: &[u8]) -> Hash {
+        let bytes: Vec<u8> = [operation.as_bytes(), args].concat();
+        Hash::from(bytes.as_slice())
+    }
+
+    /// Returns true if the operation is cleared to execute, i.e., if no approval policy is configured
+    /// or the operation has reached approval quorum.
+    ///
+    /// If the operation has not reached quorum, then it is enqueued into the pending approvals store
+    /// and the predecessor's approval is recorded if the predecessor is an admin.
+    ///
+    /// ## Panics
+    /// - `ERR_PENDING_APPROVALS_FULL` - if the operation is not pending and [`AdminApprovals::MAX_PENDING`]
+    ///   operations are already pending approval
+    pub fn check(operation: &str, args: &[u8]) -> bool {
+        let policy = match Self::policy() {
+            None => return true,
+            Some(policy) => policy,
+        };
+        let operation_id = Self::operation_id(operation, args);
+        let mut pending = Self::pending();
+        let index = match pending
+            .iter()
+            .position(|op| op.operation_id == operation_id)
+        {
+            Some(index) => index,
+            None => {
+                ERR_PENDING_APPROVALS_FULL.assert(|| pending.len() < Self::MAX_PENDING);
+                pending.push(PendingApproval {
+                    operation_id,
+                    operation: operation.to_string(),
+                    proposed_by: env::predecessor_account_id(),
+                    proposed_on: BlockTime::from_env(),
+                    approvals: vec![],
+                });
+                LOG_EVENT_APPROVAL_PENDING.log(format!(
+                    "operation={}, operation_id={}",
+                    operation, operation_id
+                ));
+                pending.len() - 1
+            }
+        };
+        if Self::predecessor_is_admin() {
+            Self::add_approval(&mut pending[index]);
+        }
+
+        if pending[index].approvals.len() >= policy.required_approvals as usize {
+            pending.remove(index);
+            Self::save_pending(pending);
+            LOG_EVENT_APPROVAL_EXECUTED.log(format!(
+                "operation={}, operation_id={}",
+                operation, operation_id
+            ));
+            true
+        } else {
+            Self::save_pending(pending);
+            false
+        }
+    }
+
+    /// Records the predecessor's approval for the pending operation
+    ///
+    /// ## Panics
+    /// - if the predecessor is not an admin
+    /// - `ERR_APPROVAL_NOT_PENDING` - if the operation is not pending approval
+    pub fn approve(operation_id: Hash) -> PendingApproval {
+        ERR_NOT_AUTHORIZED.assert(Self::predecessor_is_admin);
+        let mut pending = Self::pending();
+        let operation = match pending
+            .iter_mut()
+            .find(|op| op.operation_id == operation_id)
+        {
+            Some(operation) => operation,
+            None => {
+                ERR_APPROVAL_NOT_PENDING.panic();
+                unreachable!()
+            }
+        };
+        Self::add_approval(operation);
+        let operation = operation.clone();
+        Self::save_pending(pending);
+        operation
+    }
+
+    /// Removes the operation from the pending approvals store
+    ///
+    /// ## Panics
+    /// - if the predecessor is not an admin
+    pub fn cancel(operation_id: Hash) -> bool {
+        ERR_NOT_AUTHORIZED.assert(Self::predecessor_is_admin);
+        let mut pending = Self::pending();
+        let count = pending.len();
+        pending.retain(|op| op.operation_id != operation_id);
+        if pending.len() == count {
+            return false;
+        }
+        Self::save_pending(pending);
+        true
+    }
+
+    fn predecessor_is_admin() -> bool {
+        AccountNearDataObject::load(env::predecessor_account_id().as_str())
+            .map(|account| account.is_admin())
+            .unwrap_or_default()
+    }
+
+    fn add_approval(operation: &mut PendingApproval) {
+        let account_id = env::predecessor_account_id();
+        if !operation.approvals.contains(&account_id) {
+            LOG_EVENT_APPROVAL_GRANTED.log(format!(
+                "operation={}, approved_by={}",
+                operation.operation, account_id
+            ));
+            operation.approvals.push(account_id);
+        }
+    }
+
+    fn save_pending(pending: Vec<PendingApproval>) {
+        if pending.is_empty() {
+            PendingApprovalsObject::delete_by_key(&PENDING_APPROVALS_KEY);
+        } else {
+            PendingApprovalsObject::new(PENDING_APPROVALS_KEY, pending).save();
+        }
+    }
+}
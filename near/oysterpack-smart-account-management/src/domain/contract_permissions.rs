@@ -197,21 +197,27 @@ mod test_contract_permissions {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [INVALID] invalid permission bit - valid range is [0-61]")]
+    #[should_panic(
+        expected = r#"{\"code\":\"INVALID\",\"message\":\"invalid permission bit - valid range is [0-61]"#
+    )]
     fn create_with_invalid_bits() {
         test_utils::test_env::setup();
         let _contract_permissions: ContractPermissions = vec![(62_u8, "invalid")].into();
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [INVALID] duplicate permission bits were specified")]
+    #[should_panic(
+        expected = r#"{\"code\":\"INVALID\",\"message\":\"duplicate permission bits were specified"#
+    )]
     fn create_with_duplicate_bits() {
         test_utils::test_env::setup();
         let _contract_permissions: ContractPermissions = vec![(1, "1"), (1, "1")].into();
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [INVALID] permission names must be unique")]
+    #[should_panic(
+        expected = r#"{\"code\":\"INVALID\",\"message\":\"permission names must be unique"#
+    )]
     fn create_with_duplicate_perm_names() {
         test_utils::test_env::setup();
         let _contract_permissions: ContractPermissions = vec![(1, "1"), (2, "1")].into();
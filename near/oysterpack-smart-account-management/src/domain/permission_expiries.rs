@@ -0,0 +1,157 @@
+use crate::{AccountIdHash, AccountStorageEvent, Permissions};
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::BlockHeight;
+use oysterpack_smart_near::eventbus::post;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+};
+use std::collections::BTreeMap;
+
+/// Permission that is granted to an account
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct PermissionGrant {
+    /// permission name
+    pub name: String,
+    /// block height at which the permission expires - None if the permission does not expire
+    pub expires_at: Option<BlockHeight>,
+}
+
+/// Tracks the block heights at which time bound permission grants expire for an account, e.g., a
+/// temporary treasurer
+/// - maps permission bit to the block height at which the permission expires
+/// - expired permissions are treated as revoked when the account is loaded - see
+///   [`crate::AccountNearDataObject::load`]
+/// - expired entries are lazily cleaned up the next time the account permissions are updated
+///
+/// The storage usage is charged to the account.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct PermissionExpiries(BTreeMap<u8, BlockHeight>);
+
+const PERMISSION_EXPIRIES_KEY: u128 = 1958241576320498710463529186037254113;
+
+type PermissionExpiriesObject = Object<(AccountIdHash, u128), PermissionExpiries>;
+
+impl PermissionExpiries {
+    fn key(account_id_hash: AccountIdHash) -> (AccountIdHash, u128) {
+        (account_id_hash, PERMISSION_EXPIRIES_KEY)
+    }
+
+    pub fn load<ID: Into<AccountIdHash>>(account_id: ID) -> Option<Self> {
+        PermissionExpiriesObject::load(&Self::key(account_id.into()))
+            .map(|expiries| (*expiries).clone())
+    }
+
+    /// returns the block height at which the permission bit expires
+    pub fn expires_at(&self, perm_bit: u8) -> Option<BlockHeight> {
+        self.0.get(&perm_bit).cloned()
+    }
+
+    /// returns the permissions that have expired as of the current block
+    pub fn expired(&self) -> Permissions {
+        let block_height = BlockHeight::from_env();
+        self.0
+            .iter()
+            .filter(|(_, expires_at)| **expires_at <= block_height)
+            .fold(0_u64, |permissions, (perm_bit, _)| {
+                permissions | 1 << *perm_bit
+            })
+            .into()
+    }
+
+    /// sets the expiry for each of the specified permission bits
+    pub fn set(&mut self, permissions: Permissions, expires_at: BlockHeight) {
+        for perm_bit in Self::perm_bits(permissions) {
+            self.0.insert(perm_bit, expires_at);
+        }
+    }
+
+    /// clears the expiry for each of the specified permission bits
+    pub fn remove(&mut self, permissions: Permissions) {
+        for perm_bit in Self::perm_bits(permissions) {
+            self.0.remove(&perm_bit);
+        }
+    }
+
+    /// removes entries for permissions that are no longer granted, which includes expired permissions
+    pub fn retain_granted(&mut self, granted: Option<Permissions>) {
+        let expired = self.expired();
+        self.0.retain(|perm_bit, _| {
+            let perm: Permissions = (1_u64 << *perm_bit).into();
+            !expired.contains(perm) && granted.is_some_and(|granted| granted.contains(perm))
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// if empty, then the expiries are deleted from storage
+    ///
+    /// tracks storage usage against the account - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn save<ID: Into<AccountIdHash>>(&self, account_id: ID) {
+        let account_id_hash = account_id.into();
+        let initial_storage_usage = env::storage_usage();
+        if self.is_empty() {
+            PermissionExpiriesObject::delete_by_key(&Self::key(account_id_hash));
+        } else {
+            PermissionExpiriesObject::new(Self::key(account_id_hash), self.clone()).save();
+        }
+        Self::track_storage_usage(account_id_hash, initial_storage_usage);
+    }
+
+    /// storage usage is not tracked because this is only used when the account is deleted
+    pub(crate) fn delete(account_id_hash: AccountIdHash) {
+        PermissionExpiriesObject::delete_by_key(&Self::key(account_id_hash));
+    }
+
+    fn perm_bits(permissions: Permissions) -> Vec<u8> {
+        (0..64_u8)
+            .filter(|perm_bit| permissions.contains(1_u64 << *perm_bit))
+            .collect()
+    }
+
+    fn track_storage_usage(account_id_hash: AccountIdHash, initial_storage_usage: u64) {
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id_hash,
+                storage_usage_change.into(),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near_test::*;
+
+    #[test]
+    fn expired() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.block_index(100).apply();
+
+        let mut expiries = PermissionExpiries::default();
+        expiries.set((1 << 0).into(), 100.into());
+        expiries.set((1 << 1 | 1 << 2).into(), 200.into());
+        assert_eq!(expiries.expired(), (1 << 0).into());
+        assert_eq!(expiries.expires_at(2), Some(200.into()));
+
+        ctx.block_index(200).apply();
+        assert_eq!(expiries.expired(), (1 << 0 | 1 << 1 | 1 << 2).into());
+
+        // only unexpired permissions that are still granted are retained
+        ctx.block_index(150).apply();
+        expiries.remove((1 << 2).into());
+        expiries.retain_granted(Some((1 << 0 | 1 << 1 | 1 << 2).into()));
+        assert_eq!(expiries.expires_at(0), None);
+        assert_eq!(expiries.expires_at(1), Some(200.into()));
+        assert_eq!(expiries.expires_at(2), None);
+
+        expiries.retain_granted(None);
+        assert!(expiries.is_empty());
+    }
+}
@@ -0,0 +1,103 @@
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::{
+    data::{numbers::U64, Object},
+    domain::BlockHeight,
+};
+
+/// Access control event that is recorded in the [`PermissionsAuditLog`]
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub enum PermissionsAuditEvent {
+    /// permissions were granted to the account - includes admin changes
+    Grant {
+        account_id: AccountId,
+        permissions: Vec<String>,
+    },
+    /// permissions were revoked from the account - includes admin changes
+    Revoke {
+        account_id: AccountId,
+        permissions: Vec<String>,
+    },
+    /// operator command was executed
+    OperatorCommand {
+        /// contract function name
+        operation: String,
+        /// JSON encoded args
+        args: String,
+    },
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct PermissionsAuditLogEntry {
+    /// log sequence number - starts at zero and increases monotonically
+    pub id: U64,
+    pub block_height: BlockHeight,
+    pub predecessor: AccountId,
+    pub event: PermissionsAuditEvent,
+}
+
+const PERMISSIONS_AUDIT_LOG_NEXT_ID_KEY: u128 = 1957636512273649908216403947181346919;
+/// the entry storage key is computed as `PERMISSIONS_AUDIT_LOG_ENTRY_KEY + (id % MAX_ENTRIES)`
+const PERMISSIONS_AUDIT_LOG_ENTRY_KEY: u128 = 1957636547108305517232896108717963560;
+
+type NextIdObject = Object<u128, u64>;
+type PermissionsAuditLogEntryObject = Object<u128, PermissionsAuditLogEntry>;
+
+/// Append-only bounded log of access control events, which is stored as a ring buffer
+/// - once full, the oldest entry is overwritten
+/// - the log storage usage is paid for by the contract
+pub struct PermissionsAuditLog;
+
+impl PermissionsAuditLog {
+    /// max number of entries that are retained
+    pub const MAX_ENTRIES: u64 = 1000;
+    /// max number of entries that can be retrieved per call
+    pub const MAX_PAGE_SIZE: u16 = 100;
+
+    /// appends the event to the log - stamped with the current block height and predecessor
+    pub fn record(event: PermissionsAuditEvent) {
+        let id = Self::next_id();
+        PermissionsAuditLogEntryObject::new(
+            Self::entry_key(id),
+            PermissionsAuditLogEntry {
+                id: id.into(),
+                block_height: BlockHeight::from_env(),
+                predecessor: env::predecessor_account_id(),
+                event,
+            },
+        )
+        .save();
+        NextIdObject::new(PERMISSIONS_AUDIT_LOG_NEXT_ID_KEY, id + 1).save();
+    }
+
+    /// returns the retained entries starting from the specified entry ID, ordered from oldest to newest
+    /// - if entries have been overwritten, then the oldest retained entry is the starting point
+    /// - limit is capped at [`PermissionsAuditLog::MAX_PAGE_SIZE`]
+    pub fn entries(from: u64, limit: u16) -> Vec<PermissionsAuditLogEntry> {
+        let next_id = Self::next_id();
+        let from = from.max(next_id.saturating_sub(Self::MAX_ENTRIES));
+        let to = next_id.min(from + limit.min(Self::MAX_PAGE_SIZE) as u64);
+        (from..to)
+            .filter_map(|id| {
+                PermissionsAuditLogEntryObject::load(&Self::entry_key(id))
+                    .map(|entry| (*entry).clone())
+            })
+            .collect()
+    }
+
+    /// returns the total number of entries that have been recorded, which includes entries that
+    /// have been overwritten
+    pub fn next_id() -> u64 {
+        NextIdObject::load(&PERMISSIONS_AUDIT_LOG_NEXT_ID_KEY).map_or(0, |id| *id)
+    }
+
+    fn entry_key(id: u64) -> u128 {
+        PERMISSIONS_AUDIT_LOG_ENTRY_KEY + (id % Self::MAX_ENTRIES) as u128
+    }
+}
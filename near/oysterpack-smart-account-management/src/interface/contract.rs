@@ -1,9 +1,19 @@
 //! Contract Interfaces
 
 pub use access_control::*;
+pub use account_metrics_history::*;
+pub use account_profile::*;
 pub use account_storage_usage::*;
 pub use storage_management::*;
+pub use storage_sponsorship::*;
+pub use storage_usage_bounds_management::*;
+pub use storage_withdrawal::*;
 
 mod access_control;
+mod account_metrics_history;
+mod account_profile;
 mod account_storage_usage;
 mod storage_management;
+mod storage_sponsorship;
+mod storage_usage_bounds_management;
+mod storage_withdrawal;
@@ -1,6 +1,10 @@
-use crate::Permissions;
+use crate::{
+    ApprovalPolicy, PendingApproval, PermissionGrant, Permissions, PermissionsAuditLogEntry,
+};
+use oysterpack_smart_near::data::numbers::U64;
+use oysterpack_smart_near::domain::BlockHeight;
 use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
-use oysterpack_smart_near::{ErrCode, ErrorConst, Level, LogEvent};
+use oysterpack_smart_near::{ErrCode, ErrorConst, Hash, Level, LogEvent};
 use std::collections::HashMap;
 
 /// # **Contract Interface**: Permissions Management API
@@ -11,17 +15,25 @@ pub trait PermissionsManagement {
     fn ops_permissions_is_admin(&self, account_id: ValidAccountId) -> bool;
 
     /// Is restricted to admins
+    /// - is subject to the admin approval policy - see [`AdminApprovalsManagement`]
     ///
     /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
     /// - if predecessor account is not owner or admin
     /// - if `account_id` is not registered
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
     fn ops_permissions_grant_admin(&mut self, account_id: ValidAccountId);
 
     /// Is restricted to admins
     ///
     /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
     /// - if predecessor account is not owner or admin
     /// - if `account_id` is not registered
+    /// - if the number of admins would fall below the approval policy's required approvals
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
     fn ops_permissions_revoke_admin(&mut self, account_id: ValidAccountId);
 
     /// contract owner is admin by default
@@ -30,15 +42,21 @@ pub trait PermissionsManagement {
     /// Is restricted to admins
     ///
     /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
     /// - if predecessor account is not owner or admin
     /// - if `account_id` is not registered
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
     fn ops_permissions_grant_operator(&mut self, account_id: ValidAccountId);
 
     /// Is restricted to admins
     ///
     /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
     /// - if predecessor account is not owner or admin
     /// - if `account_id` is not registered
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
     fn ops_permissions_revoke_operator(&mut self, account_id: ValidAccountId);
 
     /// Is restricted to admins.
@@ -47,9 +65,12 @@ pub trait PermissionsManagement {
     /// must be used.
     ///
     /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
     /// - if predecessor account is not owner or admin
     /// - if `account_id` is not registered
     /// - if permissions are not supported by the contract
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
     fn ops_permissions_grant(&mut self, account_id: ValidAccountId, permissions: Permissions);
 
     /// Is restricted to admins.
@@ -61,29 +82,62 @@ pub trait PermissionsManagement {
     /// - permissions - array of permission bits
     ///
     /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
     /// - if predecessor account is not owner or admin
     /// - if `account_id` is not registered
     /// - if permissions are not supported by the contract
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
     fn ops_permissions_grant_permissions(
         &mut self,
         account_id: ValidAccountId,
         permissions: Vec<u8>,
     );
 
+    /// Is restricted to admins.
+    ///
+    /// Grants permissions that expire at the specified block height, e.g., temporary treasurer.
+    /// - expired permissions are treated as revoked
+    /// - if the permissions are already granted, then their expiry is updated
+    /// - granting the permissions via [`PermissionsManagement::ops_permissions_grant`] clears the expiry
+    ///
+    /// [`crate::Permissions::ADMIN`] and [`crate::Permissions::OPERATOR`] can not be granted.
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if predecessor account is not owner or admin
+    /// - if `account_id` is not registered
+    /// - if permissions are not supported by the contract
+    /// - if `expires_at` is not a future block height
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_permissions_grant_with_expiry(
+        &mut self,
+        account_id: ValidAccountId,
+        permissions: Permissions,
+        expires_at: BlockHeight,
+    );
+
     /// Is restricted to admins
     ///
     /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
     /// - if predecessor account is not owner or admin
     /// - if `account_id` is not registered
     /// - if permissions are not supported by the contract
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
     fn ops_permissions_revoke(&mut self, account_id: ValidAccountId, permissions: Permissions);
 
     /// Is restricted to admins
     ///
     /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
     /// - if predecessor account is not owner or admin
     /// - if `account_id` is not registered
     /// - if permissions are not supported by the contract
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
     fn ops_permissions_revoke_permissions(
         &mut self,
         account_id: ValidAccountId,
@@ -93,10 +147,54 @@ pub trait PermissionsManagement {
     /// Is restricted to admins
     ///
     /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
     /// - if predecessor account is not owner or admin
     /// - if `account_id` is not registered
+    /// - if the account is an admin and the number of admins would fall below the approval
+    ///   policy's required approvals
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
     fn ops_permissions_revoke_all(&mut self, account_id: ValidAccountId);
 
+    /// Is restricted to admins.
+    ///
+    /// Batch version of [`PermissionsManagement::ops_permissions_grant`], which enables permission
+    /// sets to be rotated in a single transaction, e.g., by a DAO.
+    /// - [`crate::Permissions::OPERATOR`] can be granted in the batch
+    /// - each account grant is recorded in the audit log, but a single aggregated
+    ///   [`LOG_EVENT_PERMISSIONS_GRANT_MANY`] event is logged for the batch
+    /// - the batch is atomic, i.e., if any grant is invalid, then no permissions are granted
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if predecessor account is not owner or admin
+    /// - if the batch contains more than [`MAX_PERMISSIONS_BATCH_SIZE`] grants
+    /// - if any account is not registered or is the predecessor account
+    /// - if [`crate::Permissions::ADMIN`] is specified
+    /// - if permissions are not supported by the contract
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_permissions_grant_many(&mut self, grants: Vec<(ValidAccountId, Permissions)>);
+
+    /// Is restricted to admins.
+    ///
+    /// Batch version of [`PermissionsManagement::ops_permissions_revoke`]
+    /// - [`crate::Permissions::OPERATOR`] can be revoked in the batch
+    /// - each account revoke is recorded in the audit log, but a single aggregated
+    ///   [`LOG_EVENT_PERMISSIONS_REVOKE_MANY`] event is logged for the batch
+    /// - the batch is atomic, i.e., if any revoke is invalid, then no permissions are revoked
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if predecessor account is not owner or admin
+    /// - if the batch contains more than [`MAX_PERMISSIONS_BATCH_SIZE`] revokes
+    /// - if any account is not registered or is the predecessor account
+    /// - if [`crate::Permissions::ADMIN`] is specified
+    /// - if permissions are not supported by the contract
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_permissions_revoke_many(&mut self, revokes: Vec<(ValidAccountId, Permissions)>);
+
     /// returns true if the account has all of the specified permissions
     fn ops_permissions_contains(
         &self,
@@ -108,11 +206,79 @@ pub trait PermissionsManagement {
     /// - returns None if the account is not registered
     fn ops_permissions(&self, account_id: ValidAccountId) -> Option<Permissions>;
 
-    fn ops_permissions_granted(&self, account_id: ValidAccountId) -> Option<HashMap<u8, String>>;
+    /// returns the account's granted permissions keyed by permission bit, which includes the
+    /// permission expiry
+    /// - returns None if the account is not registered or has no permissions
+    fn ops_permissions_granted(
+        &self,
+        account_id: ValidAccountId,
+    ) -> Option<HashMap<u8, PermissionGrant>>;
 
     /// lists the permission bits that are supported by the contract with a human friendly name
     /// - [`crate::Permissions::ADMIN`] and [`crate::Permissions::OPERATOR`] are excluded
     fn ops_permissions_contract_permissions(&self) -> Option<HashMap<u8, String>>;
+
+    /// Returns access control audit log entries starting from the specified entry ID, ordered from
+    /// oldest to newest
+    /// - every permission grant and revoke, which includes admin changes, and operator command is
+    ///   recorded with the block height and predecessor account
+    /// - the log is bounded and retains the most recent [`crate::PermissionsAuditLog::MAX_ENTRIES`]
+    ///   entries - if the `from` entry has been overwritten, then entries are returned starting
+    ///   from the oldest retained entry
+    /// - `limit` is capped at [`crate::PermissionsAuditLog::MAX_PAGE_SIZE`]
+    fn ops_permissions_audit_log(&self, from: U64, limit: u16) -> Vec<PermissionsAuditLogEntry>;
+}
+
+/// # **Contract Interface**: Admin Approvals API
+///
+/// Enables an M-of-N admin approval requirement for sensitive admin operations, e.g., fee updates,
+/// public key updates, and owner balance withdrawals.
+///
+/// When an approval policy is configured, sensitive operations are not executed immediately. Instead:
+/// 1. the first time the operation is invoked, it is enqueued into the pending approvals store
+///    - if the caller is an admin, then the invocation counts as the caller's approval
+/// 2. admins approve the pending operation via [`AdminApprovalsManagement::ops_approvals_approve`]
+/// 3. once quorum is reached, the operation is executed the next time it is invoked with the same args
+///
+/// ## Notes
+/// - pending operations are identified by the sha256 hash of the function name and its args
+/// - updating the approval policy is itself subject to the approval policy
+/// - granting admin permission is subject to the approval policy
+/// - required approvals can never exceed the number of admins - see [`crate::AdminApprovals::admin_count`]
+/// - the number of pending operations is capped at [`crate::AdminApprovals::MAX_PENDING`] - once
+///   reached, new operations are rejected with `ERR_PENDING_APPROVALS_FULL` until pending
+///   operations are executed or cancelled
+pub trait AdminApprovalsManagement {
+    fn ops_approvals_policy(&self) -> Option<ApprovalPolicy>;
+
+    /// Is restricted to admins
+    /// - if `required_approvals` is None, then the approval policy is cleared along with any pending
+    ///   approvals
+    ///
+    /// ## Panics
+    /// - if predecessor account is not admin
+    /// - if `required_approvals` is zero
+    /// - if `required_approvals` is greater than the number of admins
+    fn ops_approvals_set_policy(&mut self, required_approvals: Option<u8>);
+
+    fn ops_approvals_pending(&self) -> Vec<PendingApproval>;
+
+    /// Is restricted to admins
+    ///
+    /// Returns the updated pending approval
+    ///
+    /// ## Panics
+    /// - if predecessor account is not admin
+    /// - `ERR_APPROVAL_NOT_PENDING` - if the operation is not pending approval
+    fn ops_approvals_approve(&mut self, operation_id: Hash) -> PendingApproval;
+
+    /// Is restricted to admins
+    ///
+    /// Returns false if the operation was not pending approval
+    ///
+    /// ## Panics
+    /// - if predecessor account is not admin
+    fn ops_approvals_cancel(&mut self, operation_id: Hash) -> bool;
 }
 
 pub const ERR_NOT_AUTHORIZED: ErrorConst = ErrorConst(
@@ -120,5 +286,32 @@ pub const ERR_NOT_AUTHORIZED: ErrorConst = ErrorConst(
     "account is not authorized to perform the requested action",
 );
 
+pub const ERR_APPROVAL_NOT_PENDING: ErrorConst = ErrorConst(
+    ErrCode("APPROVAL_NOT_PENDING"),
+    "operation is not pending approval",
+);
+
+pub const ERR_PENDING_APPROVALS_FULL: ErrorConst = ErrorConst(
+    ErrCode("PENDING_APPROVALS_FULL"),
+    "max number of pending approvals has been reached - pending approvals must be executed or cancelled first",
+);
+
 pub const LOG_EVENT_PERMISSIONS_GRANT: LogEvent = LogEvent(Level::INFO, "PERMISSIONS_GRANT");
 pub const LOG_EVENT_PERMISSIONS_REVOKE: LogEvent = LogEvent(Level::INFO, "PERMISSIONS_REVOKE");
+/// log message format: `{account_id}={permission names}` entries separated by `, `
+pub const LOG_EVENT_PERMISSIONS_GRANT_MANY: LogEvent =
+    LogEvent(Level::INFO, "PERMISSIONS_GRANT_MANY");
+/// log message format: `{account_id}={permission names}` entries separated by `, `
+pub const LOG_EVENT_PERMISSIONS_REVOKE_MANY: LogEvent =
+    LogEvent(Level::INFO, "PERMISSIONS_REVOKE_MANY");
+
+/// max number of grants or revokes that can be submitted in a single batch - see
+/// [`PermissionsManagement::ops_permissions_grant_many`]
+pub const MAX_PERMISSIONS_BATCH_SIZE: usize = 50;
+
+pub const LOG_EVENT_APPROVAL_POLICY_UPDATED: LogEvent =
+    LogEvent(Level::INFO, "APPROVAL_POLICY_UPDATED");
+pub const LOG_EVENT_APPROVAL_PENDING: LogEvent = LogEvent(Level::INFO, "APPROVAL_PENDING");
+pub const LOG_EVENT_APPROVAL_GRANTED: LogEvent = LogEvent(Level::INFO, "APPROVAL_GRANTED");
+pub const LOG_EVENT_APPROVAL_EXECUTED: LogEvent = LogEvent(Level::INFO, "APPROVAL_EXECUTED");
+pub const LOG_EVENT_APPROVAL_CANCELLED: LogEvent = LogEvent(Level::INFO, "APPROVAL_CANCELLED");
@@ -0,0 +1,11 @@
+use crate::AccountMetricsSnapshot;
+
+/// # **Contract Interface**: Account Metrics History API
+///
+/// Account metrics are snapshotted at epoch boundaries, which enables account growth to be tracked
+/// without an off-chain indexer - see [`AccountMetricsSnapshot`]
+pub trait AccountMetricsHistory {
+    /// Returns the most recent epoch snapshots, ordered from oldest to newest
+    /// - `limit` defaults to all retained snapshots - see [`crate::AccountMetrics::MAX_SNAPSHOTS`]
+    fn ops_account_metrics_history(&self, limit: Option<u16>) -> Vec<AccountMetricsSnapshot>;
+}
@@ -0,0 +1,32 @@
+use crate::AccountProfile;
+use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// # **Contract Interface**: Account Profile API
+///
+/// Enables accounts to publish a small profile, e.g., display name and URL, which frontends can use
+/// to display human friendly info for accounts - see [`AccountProfile`].
+pub trait AccountProfiles {
+    /// Sets the predecessor account's profile, replacing any existing profile
+    /// - if the profile is None, then the profile is cleared and its storage is freed up
+    /// - the profile storage usage is charged to the account
+    /// - logs [`LOG_EVENT_ACCOUNT_PROFILE`]
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if the predecessor account is not registered
+    /// - if the name exceeds [`AccountProfile::MAX_NAME_LEN`] bytes, or the URL exceeds
+    ///   [`AccountProfile::MAX_URL_LEN`] bytes
+    /// - if the name or URL is blank
+    /// - [`crate::ERR_INSUFFICIENT_STORAGE_BALANCE`] - if the account's available storage balance
+    ///   does not cover the profile storage cost
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_account_profile_set(&mut self, profile: Option<AccountProfile>);
+
+    /// returns None if the account has no profile
+    fn ops_account_profile_get(&self, account_id: ValidAccountId) -> Option<AccountProfile>;
+}
+
+/// log message format: `account_id={account_id}, action={set|cleared}`
+pub const LOG_EVENT_ACCOUNT_PROFILE: LogEvent = LogEvent(Level::INFO, "ACCOUNT_PROFILE");
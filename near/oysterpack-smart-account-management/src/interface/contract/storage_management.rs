@@ -64,6 +64,24 @@ pub trait StorageManagement {
         registration_only: Option<bool>,
     ) -> StorageBalance;
 
+    /// Used to register many accounts in a single transaction with `registration_only=true`
+    /// semantics, e.g., for exchange onboarding scripts.
+    ///
+    /// - each account that is not registered is registered with the minimum required balance
+    /// - duplicate and already registered accounts are skipped
+    /// - any attached deposit above the minimum required balance for the accounts that are registered
+    ///   is refunded to the predecessor account
+    ///
+    /// ## Returns
+    /// The storage balances for the specified accounts in the same order.
+    ///
+    /// ## Panics
+    /// - If the attached deposit is less than the minimum required balance times the number of
+    ///   accounts that are registered
+    ///
+    /// `#[payable]`
+    fn storage_deposit_many(&mut self, accounts: Vec<ValidAccountId>) -> Vec<StorageBalance>;
+
     /// Used to withdraw NEAR from the predecessor account's storage available balance.
     /// If amount is not specified, then all of the account's storage available balance will be withdrawn.
     ///
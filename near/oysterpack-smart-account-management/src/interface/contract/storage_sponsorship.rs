@@ -0,0 +1,27 @@
+use oysterpack_smart_near::near_sdk::{json_types::ValidAccountId, AccountId};
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// # **Contract Interface**: Storage Sponsorship API
+///
+/// Enables the contract operators to pre-register accounts on their behalf by paying for the accounts'
+/// storage deposits, e.g., to support airdrop or onboarding campaigns.
+pub trait StorageSponsorship {
+    /// Registers the specified accounts that are not yet registered, paying the minimum required
+    /// storage balance for each account from the attached deposit.
+    /// - accounts that are already registered are skipped
+    /// - any attached deposit above the amount required to register the accounts is refunded to the
+    ///   predecessor account
+    ///
+    /// Returns the accounts that were registered.
+    ///
+    /// ## Panics
+    /// - if the predecessor account is not registered
+    /// - [`crate::ERR_NOT_AUTHORIZED`] - if the predecessor account does not have admin or operator
+    ///   permission
+    /// - if the attached deposit is insufficient to pay for the account registrations
+    ///
+    /// `#[payable]`
+    fn ops_storage_sponsor_accounts(&mut self, accounts: Vec<ValidAccountId>) -> Vec<AccountId>;
+}
+
+pub const LOG_EVENT_STORAGE_SPONSORED: LogEvent = LogEvent(Level::INFO, "STORAGE_SPONSORED");
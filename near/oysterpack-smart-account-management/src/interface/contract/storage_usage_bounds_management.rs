@@ -0,0 +1,81 @@
+use crate::{StorageBalanceBounds, StorageUsageBounds};
+use oysterpack_smart_near::near_sdk::{json_types::ValidAccountId, AccountId};
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// # **Contract Interface**: Storage Usage Bounds Management API
+///
+/// Account storage usage bounds are computed from the component account storage minimums when the
+/// contract is deployed. When a contract upgrade enlarges account records, the bounds need to be
+/// raised and the existing accounts need to be topped up to satisfy the new minimum storage balance.
+///
+/// ## Migration Workflow
+/// 1. index the accounts that were registered before the account index was introduced via
+///    [`StorageUsageBoundsManagement::ops_storage_index_accounts`]
+/// 2. raise the bounds via [`StorageUsageBoundsManagement::ops_storage_usage_bounds_update`]
+///    - [`crate::AccountManagementComponent::storage_usage_bounds`] can be used to recompute the
+///      bounds from the component account storage minimums
+/// 3. page through the registered accounts via [`StorageUsageBoundsManagement::ops_storage_top_up_accounts`]
+///    until all accounts have been topped up
+///
+/// Until an account is topped up, its available storage balance is zero.
+pub trait StorageUsageBoundsManagement {
+    /// Updates the account storage usage bounds
+    /// - logs [`LOG_EVENT_STORAGE_USAGE_BOUNDS_UPDATED`]
+    ///
+    /// Returns the updated storage balance bounds
+    ///
+    /// ## Panics
+    /// - if the predecessor account is not registered
+    /// - [`crate::ERR_NOT_AUTHORIZED`] - if the predecessor account does not have admin or operator
+    ///   permission
+    /// - [`oysterpack_smart_near::asserts::ERR_INVALID`]
+    ///   - if the min is lower than the current min, i.e., the min can only be raised
+    ///   - if the max is lower than the min
+    fn ops_storage_usage_bounds_update(
+        &mut self,
+        bounds: StorageUsageBounds,
+    ) -> StorageBalanceBounds;
+
+    /// Tops up the storage balance for registered accounts whose storage balance is below the
+    /// minimum required storage balance, paying the shortfall from the attached deposit
+    /// - accounts are paged through using the account index: up to `limit` accounts are checked
+    ///   starting from the `from` position
+    /// - any attached deposit above the amount required to top up the accounts is refunded to the
+    ///   predecessor account
+    /// - logs [`LOG_EVENT_STORAGE_TOPPED_UP`]
+    ///
+    /// Returns the accounts that were topped up
+    ///
+    /// ## Panics
+    /// - if the predecessor account is not registered
+    /// - [`crate::ERR_NOT_AUTHORIZED`] - if the predecessor account does not have admin or operator
+    ///   permission
+    /// - if the attached deposit is insufficient to top up the accounts
+    ///
+    /// `#[payable]` - requires at least 1 yoctoNEAR to be attached, even if no accounts need to be
+    /// topped up
+    fn ops_storage_top_up_accounts(&mut self, from: u64, limit: u64) -> Vec<AccountId>;
+
+    /// Adds registered accounts that are not yet indexed to the account index, i.e., backfills the
+    /// index for accounts that were registered before the account index was introduced
+    /// - the index storage is charged to each account - accounts whose storage balance no longer
+    ///   covers their storage usage are topped up via [`StorageUsageBoundsManagement::ops_storage_top_up_accounts`]
+    /// - accounts that are already indexed are skipped
+    /// - logs [`LOG_EVENT_ACCOUNTS_INDEXED`]
+    ///
+    /// Returns the number of accounts that were added to the index
+    ///
+    /// ## Panics
+    /// - if the predecessor account is not registered
+    /// - [`crate::ERR_NOT_AUTHORIZED`] - if the predecessor account does not have admin or operator
+    ///   permission
+    /// - [`crate::ERR_ACCOUNT_NOT_REGISTERED`] - if any of the accounts are not registered
+    fn ops_storage_index_accounts(&mut self, account_ids: Vec<ValidAccountId>) -> u32;
+}
+
+pub const LOG_EVENT_STORAGE_USAGE_BOUNDS_UPDATED: LogEvent =
+    LogEvent(Level::INFO, "STORAGE_USAGE_BOUNDS_UPDATED");
+
+pub const LOG_EVENT_STORAGE_TOPPED_UP: LogEvent = LogEvent(Level::INFO, "STORAGE_TOPPED_UP");
+
+pub const LOG_EVENT_ACCOUNTS_INDEXED: LogEvent = LogEvent(Level::INFO, "ACCOUNTS_INDEXED");
@@ -0,0 +1,32 @@
+use crate::StorageBalance;
+use oysterpack_smart_near::domain::YoctoNear;
+use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// # **Contract Interface**: Storage Withdrawal API
+///
+/// Extends [`crate::StorageManagement`] to enable accounts to direct storage balance withdrawals to
+/// a different receiver account, e.g., a cold wallet.
+pub trait StorageWithdrawal {
+    /// Withdraws the specified amount from the predecessor account's available storage balance and
+    /// transfers it to the receiver account.
+    /// - if amount is not specified, then the total available balance is withdrawn
+    /// - the attached yoctoNEAR is included in the transfer
+    /// - logs [`LOG_EVENT_STORAGE_WITHDRAWAL`]
+    ///
+    /// Returns the account's updated storage balance
+    ///
+    /// ## Panics
+    /// - if exactly 1 yoctoNEAR is not attached
+    /// - if the predecessor account is not registered
+    /// - if the amount is greater than the account's available storage balance
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_storage_withdraw_to(
+        &mut self,
+        receiver_id: ValidAccountId,
+        amount: Option<YoctoNear>,
+    ) -> StorageBalance;
+}
+
+pub const LOG_EVENT_STORAGE_WITHDRAWAL: LogEvent = LogEvent(Level::INFO, "STORAGE_WITHDRAWAL");
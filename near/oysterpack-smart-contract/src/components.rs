@@ -1,3 +1,4 @@
+pub mod contract_jobs;
 pub mod contract_metrics;
 pub mod contract_operator;
 pub mod contract_ownership;
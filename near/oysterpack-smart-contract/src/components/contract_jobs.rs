@@ -0,0 +1,15 @@
+//! [`ContractJobsComponent`]
+
+use crate::{ContractJobs, JobCursor, JobCursors, JobId};
+
+pub struct ContractJobsComponent;
+
+impl ContractJobs for ContractJobsComponent {
+    fn ops_jobs(&self) -> JobCursors {
+        JobCursor::load_job_cursors()
+    }
+
+    fn ops_job(&self, job_id: JobId) -> Option<JobCursor> {
+        JobCursor::load(job_id)
+    }
+}
@@ -1,7 +1,11 @@
 //! [`ContractMetricsComponent`]
 
 use crate::{ContractMetrics, ContractMetricsSnapshot};
-use crate::{ContractNearBalances, ContractStorageUsage, ContractStorageUsageCosts};
+use crate::{
+    ContractNearBalances, ContractStorageUsage, ContractStorageUsageBreakdown,
+    ContractStorageUsageCosts,
+};
+use crate::{MetricsHistory, MetricsHistoryConfig, MetricsSample};
 use oysterpack_smart_account_management::AccountMetrics;
 use oysterpack_smart_near::data::numbers::U128;
 use oysterpack_smart_near::domain::BlockTime;
@@ -33,6 +37,11 @@ impl ContractMetrics for ContractMetricsComponent {
         self.ops_metrics_contract_storage_usage().into()
     }
 
+    fn ops_storage_usage_breakdown(&self) -> ContractStorageUsageBreakdown {
+        let account_metrics = self.ops_metrics_accounts();
+        ContractStorageUsageBreakdown::new(account_metrics.total_storage_usage)
+    }
+
     fn ops_metrics(&self) -> ContractMetricsSnapshot {
         let storage_usage = self.ops_metrics_contract_storage_usage();
         ContractMetricsSnapshot {
@@ -47,11 +56,20 @@ impl ContractMetrics for ContractMetricsComponent {
     fn ops_metrics_accounts(&self) -> AccountMetrics {
         AccountMetrics::load()
     }
+
+    fn ops_metrics_history(&self, limit: Option<u16>) -> Vec<MetricsSample> {
+        MetricsHistory::samples(limit.unwrap_or(MetricsHistory::MAX_SAMPLES))
+    }
+
+    fn ops_metrics_history_config(&self) -> Option<MetricsHistoryConfig> {
+        MetricsHistory::config()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::StorageUsageCost;
     use near_sdk::env;
     use oysterpack_smart_account_management::components::account_management::{
         AccountManagementComponent, AccountManagementComponentConfig,
@@ -60,8 +78,7 @@ mod tests {
         AccountRepository, StorageManagement, StorageUsageBounds,
     };
     use oysterpack_smart_near::component::*;
-    use oysterpack_smart_near::domain::YoctoNear;
-    use oysterpack_smart_near::near_sdk::VMContext;
+    use oysterpack_smart_near::domain::{StorageUsage, YoctoNear};
     use oysterpack_smart_near::YOCTO;
     use oysterpack_smart_near_test::*;
 
@@ -84,11 +101,11 @@ mod tests {
 
     fn run_test<F>(test: F)
     where
-        F: FnOnce(VMContext, AccountManager),
+        F: FnOnce(TestCtx, AccountManager),
     {
         // Arrange
-        let ctx = new_context(ACCOUNT);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(ACCOUNT);
+        ctx.apply();
 
         // Act
         deploy_account_service();
@@ -106,8 +123,7 @@ mod tests {
             );
 
             // Arrange - register an account
-            ctx.attached_deposit = YOCTO;
-            testing_env!(ctx.clone());
+            ctx.with_deposit(YOCTO).apply();
             account_manager.storage_deposit(None, None);
 
             // Assert
@@ -128,8 +144,7 @@ mod tests {
             assert_eq!(storage_usage.accounts(), admin.storage_usage());
 
             // Arrange - register an account
-            ctx.attached_deposit = YOCTO;
-            testing_env!(ctx.clone());
+            ctx.with_deposit(YOCTO).apply();
             account_manager.storage_deposit(None, None);
 
             let account = account_manager.registered_account_near_data(ACCOUNT);
@@ -148,6 +163,46 @@ mod tests {
         });
     }
 
+    #[test]
+    fn storage_usage_breakdown() {
+        run_test(|_ctx, account_manager| {
+            // Act
+            let admin = account_manager.registered_account_near_data(ADMIN);
+            let breakdown = ContractMetricsComponent.ops_storage_usage_breakdown();
+            println!("{:#?}", breakdown);
+
+            // Assert
+            assert_eq!(breakdown.accounts.storage_usage, admin.storage_usage());
+            assert_eq!(breakdown.metrics, StorageUsageCost::default());
+
+            // Arrange - metrics history storage is attributed to metrics
+            MetricsHistory::set_config(Some(MetricsHistoryConfig {
+                block_interval: 10.into(),
+                max_samples: 3,
+            }));
+            MetricsHistory::record(None);
+
+            // Act
+            let breakdown = ContractMetricsComponent.ops_storage_usage_breakdown();
+            println!("{:#?}", breakdown);
+
+            // Assert
+            assert!(*breakdown.metrics.storage_usage > 0);
+            assert_eq!(
+                breakdown.metrics.cost,
+                breakdown.metrics.storage_usage.cost()
+            );
+            assert_eq!(breakdown.total.storage_usage, StorageUsage::from_env());
+            assert_eq!(
+                breakdown.total.storage_usage,
+                breakdown.accounts.storage_usage
+                    + breakdown.staking.storage_usage
+                    + breakdown.metrics.storage_usage
+                    + breakdown.other.storage_usage
+            );
+        });
+    }
+
     #[test]
     fn near_balances() {
         run_test(|mut ctx, mut account_manager| {
@@ -165,8 +220,7 @@ mod tests {
             assert_eq!(balances1.total(), balances1.owner() + balances1.accounts());
 
             // Arrange - register an account
-            ctx.attached_deposit = YOCTO;
-            testing_env!(ctx.clone());
+            ctx.with_deposit(YOCTO).apply();
             account_manager.storage_deposit(None, None);
 
             // Act
@@ -191,11 +245,11 @@ mod tests {
             println!("{:#?}", metrics);
 
             // Arrange - register an account
-            ctx.attached_deposit = YOCTO;
-            ctx.block_timestamp = 1;
-            ctx.block_index = 2;
-            ctx.epoch_height = 3;
-            testing_env!(ctx.clone());
+            ctx.with_deposit(YOCTO)
+                .block_timestamp(1)
+                .block_index(2)
+                .epoch_height(3)
+                .apply();
             account_manager.storage_deposit(None, None);
 
             let metrics = ContractMetricsComponent.ops_metrics();
@@ -1,16 +1,24 @@
 //! [`ContractOperatorComponent`]
 
-use crate::contract::contract_operator::CONTRACT_LOCKED_STORAGE_BALANCE;
 use crate::interface::contract::contract_operator::ContractOperator;
-use crate::{ContractNearBalances, ContractOwnerObject, ContractOwnershipAccountIdsObject};
+use crate::{
+    BalanceAlerts, ContractConfig, ContractOwnerObject, ContractOwnershipAccountIdsObject,
+    MetricsHistory, MetricsHistoryConfig, StorageBalanceLock, StorageBalanceLockReason,
+    StorageBalanceLockRequest, StorageBalanceLocks, LOG_EVENT_STORAGE_BALANCE_LOCKED,
+    LOG_EVENT_STORAGE_BALANCE_UNLOCKED,
+};
 use oysterpack_smart_account_management::components::account_management::AccountManagementComponent;
-use oysterpack_smart_account_management::AccountRepository;
+use oysterpack_smart_account_management::{
+    AccountRepository, PermissionsAuditEvent, PermissionsAuditLog,
+};
 use oysterpack_smart_near::{
-    domain::StorageUsage,
+    asserts::ERR_INVALID,
+    domain::{BlockHeight, StorageUsage},
     near_sdk::{
         borsh::{BorshDeserialize, BorshSerialize},
-        env,
+        serde_json,
     },
+    Level, LogFilter,
 };
 use std::fmt::Debug;
 
@@ -34,10 +42,76 @@ impl<T> ContractOperator for ContractOperatorComponent<T>
 where
     T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default,
 {
-    fn ops_operator_lock_storage_balance(&mut self, storage_usage: StorageUsage) {
+    fn ops_operator_lock_storage_balance(
+        &mut self,
+        storage_usage: StorageUsage,
+        lock: Option<StorageBalanceLockRequest>,
+    ) -> Option<StorageBalanceLock> {
         self.account_manager.assert_operator();
-        let storage_use_cost = env::storage_byte_cost() * *storage_usage as u128;
-        ContractNearBalances::set_balance(CONTRACT_LOCKED_STORAGE_BALANCE, storage_use_cost.into());
+        if let Some(lock) = lock.as_ref() {
+            ERR_INVALID.assert(
+                || !lock.name.trim().is_empty(),
+                || "lock name must not be blank",
+            );
+            if let Some(expires_at) = lock.expires_at {
+                ERR_INVALID.assert(
+                    || expires_at > BlockHeight::from_env(),
+                    || "lock expiry block height must be in the future",
+                );
+            }
+        }
+        PermissionsAuditLog::record(PermissionsAuditEvent::OperatorCommand {
+            operation: "ops_operator_lock_storage_balance".to_string(),
+            args: serde_json::to_string(&(storage_usage, &lock)).unwrap(),
+        });
+        let request = lock.unwrap_or_else(|| StorageBalanceLockRequest {
+            name: StorageBalanceLock::DEFAULT_LOCK_NAME.to_string(),
+            reason: StorageBalanceLockReason::ContractStorage,
+            expires_at: None,
+        });
+        let name = request.name.clone();
+        match StorageBalanceLocks::set_lock(request, storage_usage) {
+            Some(lock) => {
+                LOG_EVENT_STORAGE_BALANCE_LOCKED.log(format!("{} = {}", lock.name, lock.amount));
+                Some(lock)
+            }
+            None => {
+                LOG_EVENT_STORAGE_BALANCE_UNLOCKED.log(&name);
+                None
+            }
+        }
+    }
+
+    fn ops_operator_unlock_storage_balance(&mut self, name: String) -> StorageBalanceLock {
+        self.account_manager.assert_operator();
+        PermissionsAuditLog::record(PermissionsAuditEvent::OperatorCommand {
+            operation: "ops_operator_unlock_storage_balance".to_string(),
+            args: serde_json::to_string(&name).unwrap(),
+        });
+        let lock = StorageBalanceLocks::unlock(&name);
+        ERR_INVALID.assert(
+            || lock.is_some(),
+            || format!("lock does not exist: {}", name),
+        );
+        let lock = lock.unwrap();
+        LOG_EVENT_STORAGE_BALANCE_UNLOCKED.log(&lock.name);
+        lock
+    }
+
+    fn ops_operator_release_expired_storage_balance_locks(&mut self) -> Vec<StorageBalanceLock> {
+        let locks = StorageBalanceLocks::release_expired();
+        for lock in locks.iter() {
+            LOG_EVENT_STORAGE_BALANCE_UNLOCKED.log(format!("{} - expired", lock.name));
+        }
+        locks
+    }
+
+    fn ops_operator_storage_balance_locks(&self) -> Vec<StorageBalanceLock> {
+        StorageBalanceLocks::locks()
+    }
+
+    fn ops_operator_storage_balance_lock(&self, name: String) -> Option<StorageBalanceLock> {
+        StorageBalanceLocks::lock(&name)
     }
 
     fn ops_owner_grant_admin(&mut self) {
@@ -46,8 +120,91 @@ where
 
         let mut account =
             AccountManagementComponent::<T>::get_or_register_account(&account_ids.owner);
+        let is_admin = account.is_admin();
         account.grant_admin();
         account.save();
+        if !is_admin {
+            PermissionsAuditLog::record(PermissionsAuditEvent::Grant {
+                account_id: account_ids.owner.clone(),
+                permissions: vec!["admin".to_string()],
+            });
+        }
+    }
+
+    fn ops_operator_config_metrics_history(&mut self, config: Option<MetricsHistoryConfig>) {
+        self.account_manager.assert_operator();
+        if let Some(config) = config {
+            ERR_INVALID.assert(
+                || *config.block_interval > 0,
+                || "block_interval must be > 0",
+            );
+            ERR_INVALID.assert(
+                || config.max_samples > 0 && config.max_samples <= MetricsHistory::MAX_SAMPLES,
+                || {
+                    format!(
+                        "max_samples must be within range [1, {}]",
+                        MetricsHistory::MAX_SAMPLES
+                    )
+                },
+            );
+        }
+        PermissionsAuditLog::record(PermissionsAuditEvent::OperatorCommand {
+            operation: "ops_operator_config_metrics_history".to_string(),
+            args: serde_json::to_string(&config).unwrap(),
+        });
+        MetricsHistory::set_config(config);
+    }
+
+    fn ops_operator_config_balance_alerts(&mut self, config: Option<BalanceAlerts>) {
+        self.account_manager.assert_operator();
+        if let Some(config) = config.as_ref() {
+            ERR_INVALID.assert(
+                || config.available_balance.is_some() || config.gas_reserve.is_some(),
+                || "at least one balance threshold must be specified",
+            );
+        }
+        PermissionsAuditLog::record(PermissionsAuditEvent::OperatorCommand {
+            operation: "ops_operator_config_balance_alerts".to_string(),
+            args: serde_json::to_string(&config).unwrap(),
+        });
+        match config {
+            Some(config) => config.save(),
+            None => BalanceAlerts::clear(),
+        }
+    }
+
+    fn ops_operator_balance_alerts(&self) -> Option<BalanceAlerts> {
+        BalanceAlerts::load()
+    }
+
+    fn ops_operator_set_contract_config(
+        &mut self,
+        key: String,
+        value: Option<String>,
+    ) -> ContractConfig {
+        self.account_manager.assert_operator();
+        PermissionsAuditLog::record(PermissionsAuditEvent::OperatorCommand {
+            operation: "ops_operator_set_contract_config".to_string(),
+            args: serde_json::to_string(&(&key, &value)).unwrap(),
+        });
+        ContractConfig::set(key, value)
+    }
+
+    fn ops_contract_config(&self) -> ContractConfig {
+        ContractConfig::load()
+    }
+
+    fn ops_operator_set_log_level(&mut self, level: Level) {
+        self.account_manager.assert_operator();
+        PermissionsAuditLog::record(PermissionsAuditEvent::OperatorCommand {
+            operation: "ops_operator_set_log_level".to_string(),
+            args: serde_json::to_string(&level).unwrap(),
+        });
+        LogFilter::set_level(level);
+    }
+
+    fn ops_operator_log_level(&self) -> Level {
+        LogFilter::level()
     }
 }
 
@@ -56,10 +213,11 @@ mod tests {
     use super::*;
     use crate::components::contract_metrics::ContractMetricsComponent;
     use crate::components::contract_ownership::ContractOwnershipComponent;
-    use crate::ContractMetrics;
+    use crate::{ContractMetrics, CounterId, Counters, CONTRACT_LOCKED_STORAGE_BALANCE};
     use oysterpack_smart_account_management::components::account_management::AccountManagementComponentConfig;
     use oysterpack_smart_account_management::{PermissionsManagement, StorageManagement};
     use oysterpack_smart_near::component::Deploy;
+    use oysterpack_smart_near::near_sdk::{env, test_utils};
     use oysterpack_smart_near::YOCTO;
     use oysterpack_smart_near_test::*;
 
@@ -69,8 +227,8 @@ mod tests {
     fn lock_storage_balance() {
         // Arrange
         let operator = "bob";
-        let ctx = new_context(operator);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(operator);
+        ctx.apply();
 
         AccountManager::deploy(AccountManagementComponentConfig {
             storage_usage_bounds: None,
@@ -81,8 +239,8 @@ mod tests {
         let mut operator = ContractOperatorComponent::new(AccountManager::default());
 
         // act
-        testing_env!(ctx.clone());
-        operator.ops_operator_lock_storage_balance(1024.into());
+        ctx.apply();
+        operator.ops_operator_lock_storage_balance(1024.into(), None);
 
         let contract_balances = ContractMetricsComponent
             .ops_metrics_near_balances()
@@ -95,7 +253,7 @@ mod tests {
             1024 * env::storage_byte_cost()
         );
 
-        operator.ops_operator_lock_storage_balance(0.into());
+        operator.ops_operator_lock_storage_balance(0.into(), None);
         let contract_balances = ContractMetricsComponent
             .ops_metrics_near_balances()
             .balances();
@@ -103,12 +261,175 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [ACCOUNT_NOT_REGISTERED]")]
+    fn named_storage_balance_locks() {
+        // Arrange
+        let operator = "bob";
+        let mut ctx = TestCtx::new(operator);
+        ctx.apply();
+
+        AccountManager::deploy(AccountManagementComponentConfig {
+            storage_usage_bounds: None,
+            component_account_storage_mins: None,
+            admin_account: to_valid_account_id(operator),
+        });
+
+        let mut operator = ContractOperatorComponent::new(AccountManager::default());
+
+        // Act
+        operator.ops_operator_lock_storage_balance(1000.into(), None);
+        let upgrade_reserve = operator
+            .ops_operator_lock_storage_balance(
+                2000.into(),
+                Some(StorageBalanceLockRequest {
+                    name: "upgrade_reserve".to_string(),
+                    reason: StorageBalanceLockReason::UpgradeReserve,
+                    expires_at: None,
+                }),
+            )
+            .unwrap();
+        let audit_escrow = operator
+            .ops_operator_lock_storage_balance(
+                3000.into(),
+                Some(StorageBalanceLockRequest {
+                    name: "audit_escrow".to_string(),
+                    reason: StorageBalanceLockReason::AuditEscrow,
+                    expires_at: Some(100.into()),
+                }),
+            )
+            .unwrap();
+
+        // Assert
+        let logs = test_utils::get_logs();
+        println!("{:#?}", logs);
+        assert!(logs.contains(&format!(
+            "[INFO] [STORAGE_BALANCE_LOCKED] audit_escrow = {}",
+            3000 * env::storage_byte_cost()
+        )));
+        let locks = operator.ops_operator_storage_balance_locks();
+        assert_eq!(
+            locks
+                .iter()
+                .map(|lock| lock.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["audit_escrow", "contract_storage", "upgrade_reserve"]
+        );
+        assert_eq!(*audit_escrow.amount, 3000 * env::storage_byte_cost());
+        assert_eq!(audit_escrow.expires_at, Some(100.into()));
+        assert_eq!(
+            operator.ops_operator_storage_balance_lock("upgrade_reserve".to_string()),
+            Some(upgrade_reserve.clone())
+        );
+        let default_lock = operator
+            .ops_operator_storage_balance_lock(StorageBalanceLock::DEFAULT_LOCK_NAME.to_string())
+            .unwrap();
+        assert_eq!(default_lock.storage_usage, 1000.into());
+        assert_eq!(default_lock.balance_id, CONTRACT_LOCKED_STORAGE_BALANCE);
+
+        // each lock is tracked as a separate contract balance
+        let contract_balances = ContractMetricsComponent
+            .ops_metrics_near_balances()
+            .balances()
+            .unwrap();
+        assert_eq!(contract_balances.len(), 3);
+        assert_eq!(
+            contract_balances.get(&upgrade_reserve.balance_id),
+            Some(&upgrade_reserve.amount)
+        );
+        assert_eq!(
+            contract_balances.get(&audit_escrow.balance_id),
+            Some(&audit_escrow.amount)
+        );
+
+        // Act - expired locks are released
+        assert!(operator
+            .ops_operator_release_expired_storage_balance_locks()
+            .is_empty());
+        ctx.block_index(100).apply();
+        let released = operator.ops_operator_release_expired_storage_balance_locks();
+
+        // Assert
+        assert_eq!(released, vec![audit_escrow.clone()]);
+        assert!(operator
+            .ops_operator_storage_balance_lock("audit_escrow".to_string())
+            .is_none());
+        let contract_balances = ContractMetricsComponent
+            .ops_metrics_near_balances()
+            .balances()
+            .unwrap();
+        assert!(!contract_balances.contains_key(&audit_escrow.balance_id));
+
+        // Act - unlock
+        let unlocked = operator.ops_operator_unlock_storage_balance("upgrade_reserve".to_string());
+        operator
+            .ops_operator_unlock_storage_balance(StorageBalanceLock::DEFAULT_LOCK_NAME.to_string());
+
+        // Assert
+        assert_eq!(unlocked, upgrade_reserve);
+        assert!(operator.ops_operator_storage_balance_locks().is_empty());
+        assert!(ContractMetricsComponent
+            .ops_metrics_near_balances()
+            .balances()
+            .is_none());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"INVALID\",\"message\":\"lock does not exist: upgrade_reserve"#
+    )]
+    fn unlock_unknown_lock() {
+        // Arrange
+        let operator = "bob";
+        let mut ctx = TestCtx::new(operator);
+        ctx.apply();
+
+        AccountManager::deploy(AccountManagementComponentConfig {
+            storage_usage_bounds: None,
+            component_account_storage_mins: None,
+            admin_account: to_valid_account_id(operator),
+        });
+
+        let mut operator = ContractOperatorComponent::new(AccountManager::default());
+
+        // Act
+        operator.ops_operator_unlock_storage_balance("upgrade_reserve".to_string());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"INVALID\",\"message\":\"lock expiry block height must be in the future"#
+    )]
+    fn lock_with_expiry_in_the_past() {
+        // Arrange
+        let operator = "bob";
+        let mut ctx = TestCtx::new(operator);
+        ctx.block_index(100).apply();
+
+        AccountManager::deploy(AccountManagementComponentConfig {
+            storage_usage_bounds: None,
+            component_account_storage_mins: None,
+            admin_account: to_valid_account_id(operator),
+        });
+
+        let mut operator = ContractOperatorComponent::new(AccountManager::default());
+
+        // Act
+        operator.ops_operator_lock_storage_balance(
+            1000.into(),
+            Some(StorageBalanceLockRequest {
+                name: "audit_escrow".to_string(),
+                reason: StorageBalanceLockReason::AuditEscrow,
+                expires_at: Some(100.into()),
+            }),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"ACCOUNT_NOT_REGISTERED\""#)]
     fn with_unregistered_account() {
         // Arrange
         let operator = "bob";
-        let mut ctx = new_context(operator);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(operator);
+        ctx.apply();
 
         AccountManager::deploy(AccountManagementComponentConfig {
             storage_usage_bounds: None,
@@ -119,18 +440,17 @@ mod tests {
         let mut operator = ContractOperatorComponent::new(AccountManager::default());
 
         // act
-        ctx.predecessor_account_id = "not_registered".to_string();
-        testing_env!(ctx.clone());
-        operator.ops_operator_lock_storage_balance(1024.into());
+        ctx.predecessor("not_registered").apply();
+        operator.ops_operator_lock_storage_balance(1024.into(), None);
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [NOT_AUTHORIZED]")]
+    #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
     fn with_not_operator() {
         // Arrange
         let account = "bob";
-        let mut ctx = new_context(account);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(account);
+        ctx.apply();
 
         AccountManager::deploy(AccountManagementComponentConfig {
             storage_usage_bounds: None,
@@ -142,23 +462,21 @@ mod tests {
 
         {
             let mut ctx = ctx.clone();
-            ctx.attached_deposit = YOCTO;
-            testing_env!(ctx);
+            ctx.with_deposit(YOCTO).apply();
             operator.account_manager.storage_deposit(None, Some(true));
         }
 
         // act
-        ctx.predecessor_account_id = account.to_string();
-        testing_env!(ctx.clone());
-        operator.ops_operator_lock_storage_balance(1024.into());
+        ctx.predecessor(account).apply();
+        operator.ops_operator_lock_storage_balance(1024.into(), None);
     }
 
     #[test]
     fn grant_admin_to_owner_self() {
         // Arrange
         let account = "bob";
-        let mut ctx = new_context(account);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(account);
+        ctx.apply();
 
         AccountManager::deploy(AccountManagementComponentConfig {
             storage_usage_bounds: None,
@@ -173,8 +491,7 @@ mod tests {
             .account_manager
             .ops_permissions_is_admin(to_valid_account_id("owner")));
 
-        ctx.predecessor_account_id = "owner".to_string();
-        testing_env!(ctx.clone());
+        ctx.predecessor("owner").apply();
         operator.ops_owner_grant_admin();
         assert!(operator
             .account_manager
@@ -182,12 +499,12 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [OWNER_ACCESS_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"OWNER_ACCESS_REQUIRED\""#)]
     fn grant_admin_to_owner_self_not_as_owner() {
         // Arrange
         let account = "bob";
-        let mut ctx = new_context(account);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(account);
+        ctx.apply();
 
         AccountManager::deploy(AccountManagementComponentConfig {
             storage_usage_bounds: None,
@@ -199,8 +516,321 @@ mod tests {
 
         let mut operator = ContractOperatorComponent::new(AccountManager::default());
 
-        ctx.predecessor_account_id = "bob".to_string();
-        testing_env!(ctx.clone());
+        ctx.predecessor("bob").apply();
         operator.ops_owner_grant_admin();
     }
+
+    #[test]
+    fn config_metrics_history() {
+        // Arrange
+        let operator = "bob";
+        let mut ctx = TestCtx::new(operator);
+        ctx.apply();
+
+        AccountManager::deploy(AccountManagementComponentConfig {
+            storage_usage_bounds: None,
+            component_account_storage_mins: None,
+            admin_account: to_valid_account_id(operator),
+        });
+
+        let mut operator = ContractOperatorComponent::new(AccountManager::default());
+        assert!(ContractMetricsComponent
+            .ops_metrics_history_config()
+            .is_none());
+        assert!(!MetricsHistory::record(None));
+
+        // Act
+        let config = MetricsHistoryConfig {
+            block_interval: 10.into(),
+            max_samples: 3,
+        };
+        operator.ops_operator_config_metrics_history(Some(config));
+
+        // Assert
+        assert_eq!(
+            ContractMetricsComponent.ops_metrics_history_config(),
+            Some(config)
+        );
+
+        // Act - record samples
+        for i in 0..5 {
+            ctx.block_index(100 + i * 10).apply();
+            assert!(MetricsHistory::is_sample_due());
+            let mut counters = Counters::new();
+            counters.insert(CounterId::from(1), (i as u128).into());
+            assert!(MetricsHistory::record(Some(counters)));
+            // block interval has not elapsed
+            assert!(!MetricsHistory::record(None));
+        }
+
+        // Assert - the oldest samples are overwritten
+        let samples = ContractMetricsComponent.ops_metrics_history(None);
+        assert_eq!(samples.len(), 3);
+        let block_heights: Vec<u64> = samples
+            .iter()
+            .map(|sample| sample.block_time.height.value())
+            .collect();
+        assert_eq!(block_heights, vec![120, 130, 140]);
+        assert_eq!(
+            samples[2]
+                .counters
+                .as_ref()
+                .unwrap()
+                .get(&CounterId::from(1)),
+            Some(&4.into())
+        );
+        assert_eq!(samples[2].total_registered_accounts, 1.into());
+
+        let samples = ContractMetricsComponent.ops_metrics_history(Some(2));
+        let block_heights: Vec<u64> = samples
+            .iter()
+            .map(|sample| sample.block_time.height.value())
+            .collect();
+        assert_eq!(block_heights, vec![130, 140]);
+
+        // Act - disable
+        operator.ops_operator_config_metrics_history(None);
+
+        // Assert
+        assert!(ContractMetricsComponent
+            .ops_metrics_history_config()
+            .is_none());
+        assert!(ContractMetricsComponent
+            .ops_metrics_history(None)
+            .is_empty());
+        assert!(!MetricsHistory::is_sample_due());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"INVALID\",\"message\":\"max_samples must be within range [1, 500]"#
+    )]
+    fn config_metrics_history_with_invalid_max_samples() {
+        // Arrange
+        let operator = "bob";
+        let mut ctx = TestCtx::new(operator);
+        ctx.apply();
+
+        AccountManager::deploy(AccountManagementComponentConfig {
+            storage_usage_bounds: None,
+            component_account_storage_mins: None,
+            admin_account: to_valid_account_id(operator),
+        });
+
+        let mut operator = ContractOperatorComponent::new(AccountManager::default());
+
+        // Act
+        operator.ops_operator_config_metrics_history(Some(MetricsHistoryConfig {
+            block_interval: 10.into(),
+            max_samples: 0,
+        }));
+    }
+
+    #[test]
+    fn config_balance_alerts() {
+        // Arrange
+        let operator = "bob";
+        let mut ctx = TestCtx::new(operator);
+        ctx.apply();
+
+        AccountManager::deploy(AccountManagementComponentConfig {
+            storage_usage_bounds: None,
+            component_account_storage_mins: None,
+            admin_account: to_valid_account_id(operator),
+        });
+        ContractOwnershipComponent::deploy(to_valid_account_id("owner"));
+
+        let mut operator = ContractOperatorComponent::new(AccountManager::default());
+        assert!(operator.ops_operator_balance_alerts().is_none());
+        assert!(!BalanceAlerts::check());
+
+        // Act
+        let config = BalanceAlerts {
+            available_balance: Some((1000 * YOCTO).into()),
+            gas_reserve: Some((100 * YOCTO).into()),
+        };
+        operator.ops_operator_config_balance_alerts(Some(config));
+
+        // Assert
+        assert_eq!(operator.ops_operator_balance_alerts(), Some(config));
+
+        // Act - balances are below the thresholds
+        ctx.simulate_account_balance(10 * YOCTO).apply();
+        assert!(BalanceAlerts::check());
+
+        // Assert
+        let logs = test_utils::get_logs();
+        println!("{:#?}", logs);
+        assert_eq!(logs.len(), 2);
+        assert!(
+            logs[0].starts_with("[WARN] [ALERT_LOW_BALANCE] balance=available_balance, amount=")
+        );
+        assert!(logs[0].ends_with(&format!("threshold={}", 1000 * YOCTO)));
+        assert_eq!(
+            logs[1],
+            format!(
+                "[WARN] [ALERT_LOW_BALANCE] balance=gas_reserve, amount={}, threshold={}",
+                BalanceAlerts::gas_reserve_balance(),
+                100 * YOCTO
+            )
+        );
+
+        // Act - balances are above the thresholds
+        ctx.simulate_account_balance(10000 * YOCTO).apply();
+        assert!(!BalanceAlerts::check());
+        assert!(test_utils::get_logs().is_empty());
+
+        // Act - disable alerts
+        operator.ops_operator_config_balance_alerts(None);
+        assert!(operator.ops_operator_balance_alerts().is_none());
+        ctx.simulate_account_balance(10 * YOCTO).apply();
+        assert!(!BalanceAlerts::check());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"INVALID\",\"message\":\"at least one balance threshold must be specified\"}"#
+    )]
+    fn config_balance_alerts_without_thresholds() {
+        // Arrange
+        let operator = "bob";
+        let mut ctx = TestCtx::new(operator);
+        ctx.apply();
+
+        AccountManager::deploy(AccountManagementComponentConfig {
+            storage_usage_bounds: None,
+            component_account_storage_mins: None,
+            admin_account: to_valid_account_id(operator),
+        });
+
+        let mut operator = ContractOperatorComponent::new(AccountManager::default());
+
+        // Act
+        operator.ops_operator_config_balance_alerts(Some(BalanceAlerts {
+            available_balance: None,
+            gas_reserve: None,
+        }));
+    }
+
+    #[test]
+    fn set_contract_config() {
+        // Arrange
+        let operator = "bob";
+        let mut ctx = TestCtx::new(operator);
+        ctx.apply();
+
+        AccountManager::deploy(AccountManagementComponentConfig {
+            storage_usage_bounds: None,
+            component_account_storage_mins: None,
+            admin_account: to_valid_account_id(operator),
+        });
+
+        let mut operator = ContractOperatorComponent::new(AccountManager::default());
+        assert!(operator.ops_contract_config().0.is_empty());
+
+        // Act
+        operator.ops_operator_set_contract_config(
+            "website".to_string(),
+            Some("https://oysterpack.com".to_string()),
+        );
+        let config = operator.ops_operator_set_contract_config(
+            "brand_color".to_string(),
+            Some("#1a73e8".to_string()),
+        );
+
+        // Assert
+        assert_eq!(config, operator.ops_contract_config());
+        assert_eq!(config.0.len(), 2);
+        assert_eq!(config.get("website").unwrap(), "https://oysterpack.com");
+        let contract_config_logs = || {
+            test_utils::get_logs()
+                .into_iter()
+                .filter(|log| log.starts_with("[INFO] [CONTRACT_CONFIG]"))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            contract_config_logs(),
+            vec![
+                "[INFO] [CONTRACT_CONFIG] key=website, value=https://oysterpack.com".to_string(),
+                "[INFO] [CONTRACT_CONFIG] key=brand_color, value=#1a73e8".to_string()
+            ]
+        );
+
+        // Act - remove entry
+        let config = operator.ops_operator_set_contract_config("website".to_string(), None);
+
+        // Assert
+        assert_eq!(config.0.len(), 1);
+        assert!(config.get("website").is_none());
+        assert_eq!(
+            contract_config_logs().last().unwrap(),
+            "[INFO] [CONTRACT_CONFIG] key=website, removed"
+        );
+
+        // Act - remove last entry
+        operator.ops_operator_set_contract_config("brand_color".to_string(), None);
+        assert!(operator.ops_contract_config().0.is_empty());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"INVALID\",\"message\":\"max number of entries is 20\"}"#
+    )]
+    fn set_contract_config_max_entries() {
+        // Arrange
+        let operator = "bob";
+        let mut ctx = TestCtx::new(operator);
+        ctx.apply();
+
+        AccountManager::deploy(AccountManagementComponentConfig {
+            storage_usage_bounds: None,
+            component_account_storage_mins: None,
+            admin_account: to_valid_account_id(operator),
+        });
+
+        let mut operator = ContractOperatorComponent::new(AccountManager::default());
+        for i in 0..ContractConfig::MAX_ENTRIES {
+            operator.ops_operator_set_contract_config(i.to_string(), Some(i.to_string()));
+        }
+        // updating an existing entry is allowed
+        operator.ops_operator_set_contract_config("0".to_string(), Some("zero".to_string()));
+
+        // Act
+        operator.ops_operator_set_contract_config("new".to_string(), Some("value".to_string()));
+    }
+
+    #[test]
+    fn set_log_level() {
+        // Arrange
+        let operator = "bob";
+        let mut ctx = TestCtx::new(operator);
+        ctx.apply();
+
+        AccountManager::deploy(AccountManagementComponentConfig {
+            storage_usage_bounds: None,
+            component_account_storage_mins: None,
+            admin_account: to_valid_account_id(operator),
+        });
+
+        let mut operator = ContractOperatorComponent::new(AccountManager::default());
+        assert_eq!(operator.ops_operator_log_level(), Level::INFO);
+
+        // Act
+        operator.ops_operator_set_log_level(Level::WARN);
+        ctx.apply();
+        operator.ops_operator_lock_storage_balance(1024.into(), None);
+
+        // Assert - INFO events are suppressed
+        assert_eq!(operator.ops_operator_log_level(), Level::WARN);
+        assert!(test_utils::get_logs().is_empty());
+
+        // Act
+        operator.ops_operator_set_log_level(Level::INFO);
+        operator.ops_operator_lock_storage_balance(0.into(), None);
+
+        // Assert
+        assert!(test_utils::get_logs()
+            .iter()
+            .any(|log| log.starts_with("[INFO] [STORAGE_BALANCE_UNLOCKED]")));
+    }
 }
@@ -5,18 +5,27 @@
 use crate::components::contract_metrics::ContractMetricsComponent;
 use crate::components::contract_sale::ContractSaleComponent;
 use crate::{
-    ContractMetrics, ContractOwnerNearBalance, ContractOwnerObject, ContractOwnership,
-    ContractOwnershipAccountIdsObject, ERR_OWNER_BALANCE_OVERDRAW,
-    LOG_EVENT_CONTRACT_SALE_CANCELLED, LOG_EVENT_CONTRACT_TRANSFER_CANCELLED,
-    LOG_EVENT_CONTRACT_TRANSFER_FINALIZED, LOG_EVENT_CONTRACT_TRANSFER_INITIATED,
-};
-use oysterpack_smart_near::asserts::{
-    assert_request, assert_yocto_near_attached, ERR_CODE_BAD_REQUEST,
+    BalanceAlerts, ContractMetrics, ContractOwnerNearBalance, ContractOwnerObject,
+    ContractOwnership, ContractOwnershipAccountIdsObject, OwnerAutoSweep, OwnerDelegate,
+    OwnerGuardians, OwnerRecovery, OwnerRecoveryTimelock, OwnerVestingSchedule, OwnerVestingTerms,
+    ERR_GUARDIAN_ACCESS_REQUIRED, ERR_OWNER_BALANCE_OVERDRAW, ERR_OWNER_BALANCE_VESTING,
+    ERR_OWNER_RECOVERY_NOT_ELIGIBLE, LOG_EVENT_CONTRACT_SALE_CANCELLED,
+    LOG_EVENT_CONTRACT_TRANSFER_CANCELLED, LOG_EVENT_CONTRACT_TRANSFER_FINALIZED,
+    LOG_EVENT_CONTRACT_TRANSFER_INITIATED, LOG_EVENT_OWNER_AUTO_SWEEP_UPDATED,
+    LOG_EVENT_OWNER_BALANCE_SWEPT, LOG_EVENT_OWNER_DELEGATE_ADDED,
+    LOG_EVENT_OWNER_DELEGATE_REMOVED, LOG_EVENT_OWNER_GUARDIANS_UPDATED, LOG_EVENT_OWNER_RECOVERED,
+    LOG_EVENT_OWNER_RECOVERY_CANCELLED, LOG_EVENT_OWNER_RECOVERY_INITIATED,
+    LOG_EVENT_OWNER_RECOVERY_VOTE, LOG_EVENT_OWNER_VESTING,
 };
+use oysterpack_smart_account_management::{AccountMetrics, AccountNearDataObject, AdminApprovals};
+use oysterpack_smart_near::asserts::{assert_request, ERR_CODE_BAD_REQUEST};
 use oysterpack_smart_near::component::Deploy;
-use oysterpack_smart_near::domain::{AccountIdHash, YoctoNear};
+use oysterpack_smart_near::deposit_policy::{assert_deposit_policy, Method};
+use oysterpack_smart_near::domain::{AccountIdHash, BlockTimestamp, YoctoNear};
+use oysterpack_smart_near::near_sdk::borsh::BorshSerialize;
 use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
 use oysterpack_smart_near::near_sdk::{env, AccountId, Promise};
+use std::cmp::min;
 
 pub struct ContractOwnershipComponent;
 
@@ -54,53 +63,33 @@ impl ContractOwnership for ContractOwnershipComponent {
     }
 
     fn ops_owner_transfer(&mut self, new_owner: ValidAccountId) {
-        assert_yocto_near_attached();
-
-        let mut owner = ContractOwnerObject::assert_owner_access();
-        assert_request(
-            || new_owner.as_ref() != env::predecessor_account_id().as_str(),
-            || "you cannot transfer to yourself",
-        );
-        let new_owner_account_id_hash: AccountIdHash = new_owner.as_ref().as_str().into();
-        let current_prospective_owner_account_id_hash =
-            owner.prospective_owner_account_id_hash.as_ref().cloned();
-
-        let mut update_prospective_owner = || {
-            let mut account_ids = ContractOwnershipAccountIdsObject::load();
-            account_ids.prospective_owner = Some(new_owner.as_ref().to_string());
-            account_ids.save();
+        assert_deposit_policy(Method::OpsOwnerTransfer);
+        Self::transfer(new_owner);
+        OwnerVestingTerms::clear_pending();
+    }
 
-            owner.prospective_owner_account_id_hash = Some(new_owner_account_id_hash);
-            if owner.sale_price.take().is_some() {
-                LOG_EVENT_CONTRACT_SALE_CANCELLED
-                    .log("contract ownership transfer is being initiated");
-            }
-            if owner.bid.is_some() {
-                let mut account_ids = ContractOwnershipAccountIdsObject::load();
-                ContractSaleComponent::cancel_bid(
-                    &mut owner,
-                    &mut account_ids,
-                    "contract ownership transfer has been initiated",
-                );
-                account_ids.save();
-            }
+    fn ops_owner_transfer_with_vesting(
+        &mut self,
+        new_owner: ValidAccountId,
+        vesting: OwnerVestingTerms,
+    ) {
+        assert_deposit_policy(Method::OpsOwnerTransferWithVesting);
+        vesting.validate();
+        Self::transfer(new_owner);
+        vesting.save_pending();
+    }
 
-            LOG_EVENT_CONTRACT_TRANSFER_INITIATED.log(new_owner.as_ref());
-            owner.save();
-        };
+    fn ops_owner_prospective_vesting(&self) -> Option<OwnerVestingTerms> {
+        OwnerVestingTerms::load_pending()
+    }
 
-        match current_prospective_owner_account_id_hash {
-            None => update_prospective_owner(),
-            Some(prospective_owner_account_id_hash) => {
-                if prospective_owner_account_id_hash != new_owner_account_id_hash {
-                    update_prospective_owner()
-                }
-            }
-        }
+    fn ops_owner_vesting_schedule(&self) -> Option<OwnerVestingSchedule> {
+        OwnerVestingSchedule::load()
+            .filter(|schedule| !schedule.fully_vested(BlockTimestamp::from_env()))
     }
 
     fn ops_owner_cancel_transfer(&mut self) {
-        assert_yocto_near_attached();
+        assert_deposit_policy(Method::OpsOwnerCancelTransfer);
 
         let mut owner = ContractOwnerObject::assert_current_or_prospective_owner_access();
         if owner.prospective_owner_account_id_hash.take().is_some() {
@@ -110,12 +99,13 @@ impl ContractOwnership for ContractOwnershipComponent {
             account_ids.prospective_owner.take();
             account_ids.save();
 
+            OwnerVestingTerms::clear_pending();
             LOG_EVENT_CONTRACT_TRANSFER_CANCELLED.log("");
         }
     }
 
     fn ops_owner_finalize_transfer(&mut self) {
-        assert_yocto_near_attached();
+        assert_deposit_policy(Method::OpsOwnerFinalizeTransfer);
 
         let mut owner = ContractOwnerObject::assert_prospective_owner_access();
         let mut account_ids = ContractOwnershipAccountIdsObject::load();
@@ -131,35 +121,346 @@ impl ContractOwnership for ContractOwnershipComponent {
         account_ids.save();
 
         LOG_EVENT_CONTRACT_TRANSFER_FINALIZED.log("");
+        Self::on_owner_changed("contract ownership was transferred");
+        if let Some(vesting) = OwnerVestingTerms::load_pending() {
+            OwnerVestingTerms::clear_pending();
+            let schedule = vesting.schedule(BlockTimestamp::from_env());
+            schedule.save();
+            LOG_EVENT_OWNER_VESTING.log(format!(
+                "start={}, cliff={}, end={}",
+                schedule.start.value(),
+                schedule.cliff.value(),
+                schedule.end.value()
+            ));
+        }
     }
 
     fn ops_owner_withdraw_balance(
         &mut self,
         amount: Option<YoctoNear>,
     ) -> ContractOwnerNearBalance {
-        assert_yocto_near_attached();
+        assert_deposit_policy(Method::OpsOwnerWithdrawBalance);
         ContractOwnerObject::assert_owner_access();
+        if !AdminApprovals::check("ops_owner_withdraw_balance", &amount.try_to_vec().unwrap()) {
+            return self.ops_owner_balance();
+        }
 
         let mut owner_balance = self.ops_owner_balance();
+        let withdrawable = OwnerVestingSchedule::withdrawable_balance(owner_balance.available);
         let amount = match amount {
-            None => owner_balance.available,
+            None => withdrawable,
             Some(amount) => {
                 ERR_CODE_BAD_REQUEST.assert(
                     || amount > YoctoNear::ZERO,
                     || "withdraw amount cannot be zero",
                 );
                 ERR_OWNER_BALANCE_OVERDRAW.assert(|| owner_balance.available >= amount);
+                ERR_OWNER_BALANCE_VESTING.assert_with_message(
+                    || withdrawable >= amount,
+                    || format!("vested balance is {}", withdrawable),
+                );
                 amount
             }
         };
 
         let account_ids = ContractOwnershipAccountIdsObject::load();
         Promise::new(account_ids.owner.clone()).transfer(amount.value() + 1);
+        OwnerVestingSchedule::record_withdrawal(amount);
+        // the owner balance is not tracked via `ContractNearBalances`, thus the balance alerts
+        // need to be checked explicitly
+        BalanceAlerts::check();
 
         owner_balance.total -= amount + 1;
         owner_balance.available -= amount;
         owner_balance
     }
+
+    fn ops_owner_set_auto_sweep(&mut self, threshold: Option<YoctoNear>) {
+        assert_deposit_policy(Method::OpsOwnerSetAutoSweep);
+        ContractOwnerObject::assert_owner_access();
+        if !AdminApprovals::check("ops_owner_set_auto_sweep", &threshold.try_to_vec().unwrap()) {
+            return;
+        }
+
+        match threshold {
+            None => {
+                OwnerAutoSweep::clear();
+                LOG_EVENT_OWNER_AUTO_SWEEP_UPDATED.log("cleared");
+            }
+            Some(threshold) => {
+                OwnerAutoSweep { threshold }.save();
+                LOG_EVENT_OWNER_AUTO_SWEEP_UPDATED.log(format!("threshold={}", threshold));
+            }
+        }
+    }
+
+    fn ops_owner_auto_sweep(&self) -> Option<OwnerAutoSweep> {
+        OwnerAutoSweep::load()
+    }
+
+    fn ops_owner_sweep(&mut self) -> YoctoNear {
+        let policy = match OwnerAutoSweep::load() {
+            None => return YoctoNear::ZERO,
+            Some(policy) => policy,
+        };
+        let available = self.ops_owner_balance().available;
+        let amount = min(
+            policy.excess(available),
+            OwnerVestingSchedule::withdrawable_balance(available),
+        );
+        if amount == YoctoNear::ZERO {
+            return amount;
+        }
+        // the swept balance can be withdrawn from the owner's account storage balance, thus
+        // sweeping is subject to the same approval policy as owner balance withdrawals
+        if !AdminApprovals::check("ops_owner_sweep", &[]) {
+            return YoctoNear::ZERO;
+        }
+        OwnerVestingSchedule::record_withdrawal(amount);
+
+        // ensures account metrics are updated to reflect the owner's account balance change
+        AccountMetrics::register_account_storage_event_handler();
+        let mut owner_account =
+            AccountNearDataObject::registered_account(self.ops_owner().as_str());
+        owner_account.incr_near_balance(amount);
+        owner_account.save();
+
+        LOG_EVENT_OWNER_BALANCE_SWEPT
+            .log(format!("amount={}, threshold={}", amount, policy.threshold));
+        amount
+    }
+
+    fn ops_owner_add_full_access_delegate(&mut self, account_id: ValidAccountId) {
+        assert_deposit_policy(Method::OpsOwnerAddFullAccessDelegate);
+        ContractOwnerObject::assert_owner_account_access();
+        assert_request(
+            || account_id.as_ref() != env::predecessor_account_id().as_str(),
+            || "owner cannot be its own delegate",
+        );
+
+        OwnerDelegate::save(account_id.as_ref());
+        LOG_EVENT_OWNER_DELEGATE_ADDED.log(account_id.as_ref());
+    }
+
+    fn ops_owner_remove_delegate(&mut self) {
+        assert_deposit_policy(Method::OpsOwnerRemoveDelegate);
+        ContractOwnerObject::assert_owner_account_access();
+
+        if OwnerDelegate::clear() {
+            LOG_EVENT_OWNER_DELEGATE_REMOVED.log("");
+        }
+    }
+
+    fn ops_owner_delegate(&self) -> Option<AccountId> {
+        OwnerDelegate::load()
+    }
+
+    fn ops_owner_set_guardians(&mut self, guardians: Option<OwnerGuardians>) {
+        assert_deposit_policy(Method::OpsOwnerSetGuardians);
+        ContractOwnerObject::assert_owner_account_access();
+
+        match guardians {
+            None => {
+                OwnerGuardians::clear();
+                LOG_EVENT_OWNER_GUARDIANS_UPDATED.log("cleared");
+            }
+            Some(guardians) => {
+                guardians.validate(env::predecessor_account_id().as_str());
+                guardians.save();
+                LOG_EVENT_OWNER_GUARDIANS_UPDATED.log(format!(
+                    "guardians={:?}, quorum={}, delay={}",
+                    guardians.guardians, guardians.quorum, guardians.delay
+                ));
+            }
+        }
+        if OwnerRecovery::clear() {
+            LOG_EVENT_OWNER_RECOVERY_CANCELLED.log("guardians were updated");
+        }
+    }
+
+    fn ops_owner_guardians(&self) -> Option<OwnerGuardians> {
+        OwnerGuardians::load()
+    }
+
+    fn ops_owner_recovery_vote(&mut self, new_owner: ValidAccountId) {
+        assert_deposit_policy(Method::OpsOwnerRecoveryVote);
+        ERR_GUARDIAN_ACCESS_REQUIRED.assert(OwnerGuardians::is_guardian);
+        assert_request(
+            || new_owner.as_ref() != &self.ops_owner(),
+            || "new owner cannot be the current owner",
+        );
+        let mut recovery = OwnerRecovery::load().unwrap_or_default();
+        assert_request(
+            || recovery.timelock.is_none(),
+            || "owner recovery timelock has already started",
+        );
+
+        let guardian = env::predecessor_account_id();
+        let votes = recovery.vote(&guardian, new_owner.as_ref());
+        LOG_EVENT_OWNER_RECOVERY_VOTE.log(format!(
+            "guardian={}, new_owner={}, votes={}",
+            guardian,
+            new_owner.as_ref(),
+            votes
+        ));
+        let guardians = OwnerGuardians::load().unwrap();
+        if votes >= guardians.quorum as usize {
+            let timelock = OwnerRecoveryTimelock {
+                new_owner: new_owner.as_ref().to_string(),
+                eligible_at: BlockTimestamp::from_env() + *guardians.delay,
+            };
+            LOG_EVENT_OWNER_RECOVERY_INITIATED.log(format!(
+                "new_owner={}, eligible_at={}",
+                timelock.new_owner,
+                timelock.eligible_at.value()
+            ));
+            recovery.timelock = Some(timelock);
+        }
+        recovery.save();
+    }
+
+    fn ops_owner_recovery_finalize(&mut self) {
+        assert_deposit_policy(Method::OpsOwnerRecoveryFinalize);
+        let timelock = OwnerRecovery::load().and_then(|recovery| recovery.timelock);
+        ERR_GUARDIAN_ACCESS_REQUIRED.assert(|| {
+            OwnerGuardians::is_guardian()
+                || timelock
+                    .as_ref()
+                    .is_some_and(|timelock| timelock.new_owner == env::predecessor_account_id())
+        });
+        let timelock = match timelock {
+            Some(timelock) => timelock,
+            None => {
+                ERR_OWNER_RECOVERY_NOT_ELIGIBLE
+                    .panic_with_message("guardian quorum has not been reached");
+                unreachable!()
+            }
+        };
+        ERR_OWNER_RECOVERY_NOT_ELIGIBLE.assert_with_message(
+            || BlockTimestamp::from_env() >= timelock.eligible_at,
+            || format!("eligible_at={}", timelock.eligible_at.value()),
+        );
+
+        let mut owner = ContractOwnerObject::load();
+        let mut account_ids = ContractOwnershipAccountIdsObject::load();
+        if owner.sale_price.take().is_some() {
+            LOG_EVENT_CONTRACT_SALE_CANCELLED.log("contract ownership was recovered");
+        }
+        if owner.bid.is_some() {
+            ContractSaleComponent::cancel_bid(
+                &mut owner,
+                &mut account_ids,
+                "contract ownership was recovered",
+            );
+        }
+        owner.account_id_hash = timelock.new_owner.as_str().into();
+        owner.prospective_owner_account_id_hash.take();
+        account_ids.owner = timelock.new_owner.clone();
+        account_ids.prospective_owner.take();
+        owner.save();
+        account_ids.save();
+        OwnerVestingTerms::clear_pending();
+        OwnerRecovery::clear();
+
+        LOG_EVENT_OWNER_RECOVERED.log(timelock.new_owner);
+        // the guardians were appointed by the previous owner - the recovered owner must appoint its own
+        Self::on_owner_changed("contract ownership was recovered");
+    }
+
+    fn ops_owner_recovery_cancel(&mut self) {
+        assert_deposit_policy(Method::OpsOwnerRecoveryCancel);
+        ContractOwnerObject::assert_owner_account_access();
+
+        if OwnerRecovery::clear() {
+            LOG_EVENT_OWNER_RECOVERY_CANCELLED.log("");
+        }
+    }
+
+    fn ops_owner_recovery(&self) -> Option<OwnerRecovery> {
+        OwnerRecovery::load()
+    }
+}
+
+impl ContractOwnershipComponent {
+    /// Invoked whenever contract ownership changes hands, i.e., when the transfer is finalized,
+    /// when ownership is recovered, and when the contract is sold.
+    ///
+    /// The owner settings were configured by or for the previous owner, and are cleared:
+    /// - owner delegate - logs [`LOG_EVENT_OWNER_DELEGATE_REMOVED`]
+    /// - guardians and any owner recovery that is in progress - logs [`LOG_EVENT_OWNER_GUARDIANS_UPDATED`]
+    ///   and [`LOG_EVENT_OWNER_RECOVERY_CANCELLED`]
+    /// - owner vesting schedule - logs [`LOG_EVENT_OWNER_VESTING`]
+    /// - owner auto-sweep policy - logs [`LOG_EVENT_OWNER_AUTO_SWEEP_UPDATED`]
+    ///
+    /// The events are logged with the specified reason.
+    pub(crate) fn on_owner_changed(reason: &str) {
+        if OwnerDelegate::clear() {
+            LOG_EVENT_OWNER_DELEGATE_REMOVED.log(reason);
+        }
+        if OwnerGuardians::clear() {
+            LOG_EVENT_OWNER_GUARDIANS_UPDATED.log(format!("cleared: {}", reason));
+        }
+        if OwnerRecovery::clear() {
+            LOG_EVENT_OWNER_RECOVERY_CANCELLED.log(reason);
+        }
+        if OwnerVestingSchedule::clear() {
+            LOG_EVENT_OWNER_VESTING.log(format!("cleared: {}", reason));
+        }
+        if OwnerAutoSweep::clear() {
+            LOG_EVENT_OWNER_AUTO_SWEEP_UPDATED.log(format!("cleared: {}", reason));
+        }
+    }
+
+    /// initiates the ownership transfer to the new owner
+    /// - the owner balance must be fully vested, i.e., the owner cannot escape the vesting schedule
+    ///   by transferring ownership
+    fn transfer(new_owner: ValidAccountId) {
+        let mut owner = ContractOwnerObject::assert_owner_account_access();
+        ERR_OWNER_BALANCE_VESTING.assert_with_message(
+            || !OwnerVestingSchedule::is_vesting(),
+            || "ownership cannot be transferred while the owner balance is vesting",
+        );
+        assert_request(
+            || new_owner.as_ref() != env::predecessor_account_id().as_str(),
+            || "you cannot transfer to yourself",
+        );
+        let new_owner_account_id_hash: AccountIdHash = new_owner.as_ref().as_str().into();
+        let current_prospective_owner_account_id_hash =
+            owner.prospective_owner_account_id_hash.as_ref().cloned();
+
+        let mut update_prospective_owner = || {
+            let mut account_ids = ContractOwnershipAccountIdsObject::load();
+            account_ids.prospective_owner = Some(new_owner.as_ref().to_string());
+            account_ids.save();
+
+            owner.prospective_owner_account_id_hash = Some(new_owner_account_id_hash);
+            if owner.sale_price.take().is_some() {
+                LOG_EVENT_CONTRACT_SALE_CANCELLED
+                    .log("contract ownership transfer is being initiated");
+            }
+            if owner.bid.is_some() {
+                let mut account_ids = ContractOwnershipAccountIdsObject::load();
+                ContractSaleComponent::cancel_bid(
+                    &mut owner,
+                    &mut account_ids,
+                    "contract ownership transfer has been initiated",
+                );
+                account_ids.save();
+            }
+
+            LOG_EVENT_CONTRACT_TRANSFER_INITIATED.log(new_owner.as_ref());
+            owner.save();
+        };
+
+        match current_prospective_owner_account_id_hash {
+            None => update_prospective_owner(),
+            Some(prospective_owner_account_id_hash) => {
+                if prospective_owner_account_id_hash != new_owner_account_id_hash {
+                    update_prospective_owner()
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -173,8 +474,8 @@ mod tests {
         // Arrange
         let alfio = "alfio";
         let bob = "bob";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         // Set alfio as owner at deployment
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
@@ -185,8 +486,7 @@ mod tests {
         println!("{:?}", owner_balance);
 
         // Act - initiate transfer
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(bob));
         // Assert
         assert_eq!(
@@ -200,8 +500,7 @@ mod tests {
         println!("{:?}", owner_balance);
 
         // Act - initiate same transfer again
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(bob));
         // Assert - should have no effect
         assert_eq!(
@@ -215,8 +514,7 @@ mod tests {
         println!("{:?}", owner_balance);
 
         // Act - cancel the transfer
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_cancel_transfer();
         // Assert
         assert_eq!(alfio, ContractOwnershipComponent.ops_owner().as_str());
@@ -226,8 +524,7 @@ mod tests {
 
         // Act - withdraw all owner available balance
         // Act - cancel the transfer
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         let owner_balance = ContractOwnershipComponent.ops_owner_withdraw_balance(None);
         println!("after withdrawal: {:?}", owner_balance);
         // Assert
@@ -238,8 +535,7 @@ mod tests {
         );
 
         // Act - initiate transfer again
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(bob));
         // Assert
         assert_eq!(
@@ -253,8 +549,7 @@ mod tests {
         println!("{:?}", owner_balance);
 
         // Act - prospective owner cancels transfer
-        ctx.predecessor_account_id = bob.to_string();
-        testing_env!(ctx.clone());
+        ctx.predecessor(bob).apply();
         ContractOwnershipComponent.ops_owner_cancel_transfer();
         // Assert
         assert_eq!(alfio, ContractOwnershipComponent.ops_owner().as_str());
@@ -263,9 +558,7 @@ mod tests {
         println!("{:?}", owner_balance);
 
         // Act - initiate transfer again
-        ctx.predecessor_account_id = alfio.to_string();
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.predecessor(alfio).with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(bob));
         // Assert
         assert_eq!(
@@ -279,8 +572,7 @@ mod tests {
         println!("{:?}", owner_balance);
 
         // Act - finalize the transfer
-        ctx.predecessor_account_id = bob.to_string();
-        testing_env!(ctx.clone());
+        ctx.predecessor(bob).apply();
         ContractOwnershipComponent.ops_owner_finalize_transfer();
         // Assert
         // Assert
@@ -303,13 +595,12 @@ mod tests_transfer_ownership {
     fn change_transfer_recipient() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id("bob"));
 
         // Act
@@ -325,13 +616,12 @@ mod tests_transfer_ownership {
     fn while_contract_is_for_sale() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractSaleComponent.ops_contract_sell((1000 * YOCTO).into());
         assert!(ContractSaleComponent.ops_contract_sale_price().is_some());
 
@@ -359,21 +649,17 @@ mod tests_transfer_ownership {
     fn while_contract_has_bid() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
-        ctx.attached_deposit = YOCTO;
-        ctx.predecessor_account_id = "bob".to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(YOCTO).predecessor("bob").apply();
         ContractSaleComponent.ops_contract_buy(None);
         assert!(ContractSaleComponent.ops_contract_bid().is_some());
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = alfio.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).predecessor(alfio).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id("bob"));
         assert!(ContractSaleComponent.ops_contract_bid().is_none());
         assert_eq!(
@@ -398,26 +684,21 @@ mod tests_transfer_ownership {
     fn while_contract_for_sale_with_bid() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractSaleComponent.ops_contract_sell((1000 * YOCTO).into());
         assert!(ContractSaleComponent.ops_contract_sale_price().is_some());
 
-        ctx.attached_deposit = YOCTO;
-        ctx.predecessor_account_id = "bob".to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(YOCTO).predecessor("bob").apply();
         ContractSaleComponent.ops_contract_buy(None);
         assert!(ContractSaleComponent.ops_contract_bid().is_some());
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = alfio.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).predecessor(alfio).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id("bob"));
         assert!(ContractSaleComponent.ops_contract_bid().is_none());
         assert!(ContractSaleComponent.ops_contract_sale_price().is_none());
@@ -440,67 +721,62 @@ mod tests_transfer_ownership {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [OWNER_ACCESS_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"OWNER_ACCESS_REQUIRED\""#)]
     fn not_owner() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = "bob".to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).predecessor("bob").apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(alfio));
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [BAD_REQUEST]")]
+    #[should_panic(expected = r#"{\"code\":\"BAD_REQUEST\""#)]
     fn transfer_to_self_owner() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(alfio));
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn zero_deposit_attached() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        ctx.attached_deposit = 0;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(0).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id("bob"));
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn two_deposit_attached() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        ctx.attached_deposit = 2;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(2).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id("bob"));
     }
 }
@@ -516,18 +792,16 @@ mod tests_finalize_transfer_ownership {
         // Arrange
         let alfio = "alfio";
         let bob = "bob";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(bob));
 
         // Act
-        ctx.predecessor_account_id = bob.to_string();
-        testing_env!(ctx.clone());
+        ctx.predecessor(bob).apply();
         ContractOwnershipComponent.ops_owner_finalize_transfer();
 
         // Assert
@@ -538,84 +812,74 @@ mod tests_finalize_transfer_ownership {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [PROSPECTIVE_OWNER_ACCESS_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"PROSPECTIVE_OWNER_ACCESS_REQUIRED\""#)]
     fn not_prospective_owner() {
         // Arrange
         let alfio = "alfio";
         let bob = "bob";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(bob));
 
         // Act
-        ctx.predecessor_account_id = "alice".to_string();
-        testing_env!(ctx.clone());
+        ctx.predecessor("alice").apply();
         ContractOwnershipComponent.ops_owner_finalize_transfer();
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [CONTRACT_OWNER_TRANSFER_NOT_INITIATED]")]
+    #[should_panic(expected = r#"{\"code\":\"CONTRACT_OWNER_TRANSFER_NOT_INITIATED\""#)]
     fn no_transfer_in_progress() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = "alice".to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).predecessor("alice").apply();
         ContractOwnershipComponent.ops_owner_finalize_transfer();
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn zero_deposit_attached() {
         // Arrange
         let alfio = "alfio";
         let bob = "bob";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(bob));
 
         // Act
-        ctx.attached_deposit = 0;
-        ctx.predecessor_account_id = bob.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(0).predecessor(bob).apply();
         ContractOwnershipComponent.ops_owner_finalize_transfer();
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn two_yoctonear_deposit_attached() {
         // Arrange
         let alfio = "alfio";
         let bob = "bob";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(bob));
 
         // Act
-        ctx.attached_deposit = 2;
-        ctx.predecessor_account_id = bob.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(2).predecessor(bob).apply();
         ContractOwnershipComponent.ops_owner_finalize_transfer();
     }
 }
@@ -631,13 +895,12 @@ mod tests_cancel_ownership_transfer {
         // Arrange
         let alfio = "alfio";
         let bob = "bob";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(bob));
 
         // Act
@@ -657,19 +920,16 @@ mod tests_cancel_ownership_transfer {
         // Arrange
         let alfio = "alfio";
         let bob = "bob";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(bob));
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = bob.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).predecessor(bob).apply();
         ContractOwnershipComponent.ops_owner_cancel_transfer();
         // Assert
         assert!(ContractOwnershipComponent.ops_owner_prospective().is_none());
@@ -684,14 +944,13 @@ mod tests_cancel_ownership_transfer {
     fn cancelled_by_owner_with_no_transfer_initiated() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_cancel_transfer();
         // Assert
         let logs = test_utils::get_logs();
@@ -699,19 +958,17 @@ mod tests_cancel_ownership_transfer {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [CURRENT_OR_PROSPECTIVE_OWNER_ACCESS_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"CURRENT_OR_PROSPECTIVE_OWNER_ACCESS_REQUIRED\""#)]
     fn cancelled_by_non_owner_with_no_transfer_initiated() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = "bob".to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).predecessor("bob").apply();
         ContractOwnershipComponent.ops_owner_cancel_transfer();
         // Assert
         let logs = test_utils::get_logs();
@@ -719,23 +976,21 @@ mod tests_cancel_ownership_transfer {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn cancelled_by_owner_with_zero_deposit() {
         // Arrange
         let alfio = "alfio";
         let bob = "bob";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(bob));
 
         // Act
-        ctx.attached_deposit = 0;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(0).apply();
         ContractOwnershipComponent.ops_owner_cancel_transfer();
         // Assert
         assert!(ContractOwnershipComponent.ops_owner_prospective().is_none());
@@ -747,23 +1002,21 @@ mod tests_cancel_ownership_transfer {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn cancelled_by_owner_with_1_deposit() {
         // Arrange
         let alfio = "alfio";
         let bob = "bob";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(bob));
 
         // Act
-        ctx.attached_deposit = 2;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(2).apply();
         ContractOwnershipComponent.ops_owner_cancel_transfer();
         // Assert
         assert!(ContractOwnershipComponent.ops_owner_prospective().is_none());
@@ -775,24 +1028,21 @@ mod tests_cancel_ownership_transfer {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn cancelled_by_prospective_owner_with_zero_deposit() {
         // Arrange
         let alfio = "alfio";
         let bob = "bob";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(bob));
 
         // Act
-        ctx.attached_deposit = 0;
-        ctx.predecessor_account_id = bob.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(0).predecessor(bob).apply();
         ContractOwnershipComponent.ops_owner_cancel_transfer();
         // Assert
         assert!(ContractOwnershipComponent.ops_owner_prospective().is_none());
@@ -804,24 +1054,21 @@ mod tests_cancel_ownership_transfer {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn cancelled_by_prospective_owner_with_2_deposit() {
         // Arrange
         let alfio = "alfio";
         let bob = "bob";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(bob));
 
         // Act
-        ctx.attached_deposit = 2;
-        ctx.predecessor_account_id = bob.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(2).predecessor(bob).apply();
         ContractOwnershipComponent.ops_owner_cancel_transfer();
         // Assert
         assert!(ContractOwnershipComponent.ops_owner_prospective().is_none());
@@ -843,16 +1090,15 @@ mod owner_balance {
     fn withdraw_all_available_balance() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         let owner_balance_1 = ContractOwnershipComponent.ops_owner_balance();
         assert!(owner_balance_1.available > YoctoNear::ZERO);
         // Act
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         let owner_balance_2 = ContractOwnershipComponent.ops_owner_withdraw_balance(None);
         assert_eq!(owner_balance_2.available, YoctoNear::ZERO);
         let receipts = deserialize_receipts();
@@ -873,14 +1119,13 @@ mod owner_balance {
     fn withdraw_partial_available_balance() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         let initial_balance = ContractOwnershipComponent.ops_owner_balance();
         let amount = initial_balance.available.value() / 2;
         let owner_balance =
@@ -901,140 +1146,851 @@ mod owner_balance {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [OWNER_BALANCE_OVERDRAW]")]
+    #[should_panic(expected = r#"{\"code\":\"OWNER_BALANCE_OVERDRAW\""#)]
     fn over_withdraw_partial_available_balance() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         let initial_balance = ContractOwnershipComponent.ops_owner_balance();
         let amount = initial_balance.available.value() + 1;
         ContractOwnershipComponent.ops_owner_withdraw_balance(Some(amount.into()));
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [BAD_REQUEST] withdraw amount cannot be zero")]
+    #[should_panic(
+        expected = r#"{\"code\":\"BAD_REQUEST\",\"message\":\"withdraw amount cannot be zero"#
+    )]
     fn zero_withdraw_partial_available_balance() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_withdraw_balance(Some(YoctoNear::ZERO));
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [OWNER_ACCESS_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"OWNER_ACCESS_REQUIRED\""#)]
     fn withdraw_partial_available_balance_as_non_owner() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = "bob".to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).predecessor("bob").apply();
         ContractOwnershipComponent.ops_owner_withdraw_balance(Some(100.into()));
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [OWNER_ACCESS_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"OWNER_ACCESS_REQUIRED\""#)]
     fn withdraw_all_available_balance_as_non_owner() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = "bob".to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).predecessor("bob").apply();
         ContractOwnershipComponent.ops_owner_withdraw_balance(None);
     }
 
     #[test]
     #[should_panic(
-        expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED] exactly 1 yoctoNEAR must be attached"
+        expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\",\"message\":\"exactly 1 yoctoNEAR must be attached"#
     )]
     fn withdraw_all_available_balance_zero_deposit_attached() {
         // Arrange
         let alfio = "alfio";
-        let ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        testing_env!(ctx.clone());
+        ctx.apply();
         ContractOwnershipComponent.ops_owner_withdraw_balance(None);
     }
 
     #[test]
     #[should_panic(
-        expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED] exactly 1 yoctoNEAR must be attached"
+        expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\",\"message\":\"exactly 1 yoctoNEAR must be attached"#
     )]
     fn withdraw_all_available_balance_2_deposit_attached() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        ctx.attached_deposit = 2;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(2).apply();
         ContractOwnershipComponent.ops_owner_withdraw_balance(None);
     }
 
     #[test]
     #[should_panic(
-        expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED] exactly 1 yoctoNEAR must be attached"
+        expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\",\"message\":\"exactly 1 yoctoNEAR must be attached"#
     )]
     fn withdraw_partial_available_balance_zero_deposit_attached() {
         // Arrange
         let alfio = "alfio";
-        let ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        testing_env!(ctx.clone());
+        ctx.apply();
         ContractOwnershipComponent.ops_owner_withdraw_balance(Some(100.into()));
     }
 
     #[test]
     #[should_panic(
-        expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED] exactly 1 yoctoNEAR must be attached"
+        expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\",\"message\":\"exactly 1 yoctoNEAR must be attached"#
     )]
     fn withdraw_partial_available_balance_2_deposit_attached() {
         // Arrange
         let alfio = "alfio";
-        let mut ctx = new_context(alfio);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
         // Act
-        ctx.attached_deposit = 2;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(2).apply();
         ContractOwnershipComponent.ops_owner_withdraw_balance(Some(100.into()));
     }
 }
+
+#[cfg(test)]
+mod owner_auto_sweep {
+    use super::*;
+    use oysterpack_smart_account_management::ApprovalPolicy;
+    use oysterpack_smart_near::domain::YoctoNear;
+    use oysterpack_smart_near::near_sdk::test_utils;
+    use oysterpack_smart_near_test::*;
+
+    fn deploy_with_registered_owner(owner: &str) -> TestCtx {
+        let mut ctx = TestCtx::new(owner);
+        ctx.apply();
+        ContractOwnershipComponent::deploy(to_valid_account_id(owner));
+        AccountMetrics::register_account_storage_event_handler();
+        AccountNearDataObject::new(owner, YoctoNear::ZERO).save();
+        ctx
+    }
+
+    #[test]
+    fn sweep_excess_balance() {
+        // Arrange
+        let alfio = "alfio";
+        let mut ctx = deploy_with_registered_owner(alfio);
+        let initial_balance = ContractOwnershipComponent.ops_owner_balance();
+        let threshold: YoctoNear = (initial_balance.available.value() / 2).into();
+
+        ctx.with_deposit(1).apply();
+        ContractOwnershipComponent.ops_owner_set_auto_sweep(Some(threshold));
+        assert_eq!(
+            ContractOwnershipComponent.ops_owner_auto_sweep(),
+            Some(OwnerAutoSweep { threshold })
+        );
+
+        // Act - sweep is invoked by a keeper
+        ctx.predecessor("keeper").with_deposit(0).apply();
+        let amount = ContractOwnershipComponent.ops_owner_sweep();
+
+        // Assert
+        let logs = test_utils::get_logs();
+        println!("{:#?}", logs);
+        assert_eq!(
+            logs[1],
+            format!(
+                "[INFO] [OWNER_BALANCE_SWEPT] amount={}, threshold={}",
+                amount, threshold
+            )
+        );
+        assert!(amount > YoctoNear::ZERO);
+        let owner_account = AccountNearDataObject::load(alfio).unwrap();
+        assert_eq!(owner_account.near_balance(), amount);
+        assert_eq!(
+            ContractOwnershipComponent.ops_owner_balance().available,
+            threshold
+        );
+
+        // Act - sweeping again has no effect
+        assert_eq!(
+            ContractOwnershipComponent.ops_owner_sweep(),
+            YoctoNear::ZERO
+        );
+    }
+
+    #[test]
+    fn sweep_requires_admin_approval() {
+        // Arrange
+        let alfio = "alfio";
+        let mut ctx = deploy_with_registered_owner(alfio);
+        let threshold: YoctoNear = (ContractOwnershipComponent
+            .ops_owner_balance()
+            .available
+            .value()
+            / 2)
+        .into();
+        ctx.with_deposit(1).apply();
+        ContractOwnershipComponent.ops_owner_set_auto_sweep(Some(threshold));
+        let mut owner_account = AccountNearDataObject::load(alfio).unwrap();
+        owner_account.grant_admin();
+        owner_account.save();
+        AdminApprovals::set_policy(Some(ApprovalPolicy {
+            required_approvals: 1,
+        }));
+
+        // Act - sweep is invoked by a keeper
+        ctx.predecessor("keeper").with_deposit(0).apply();
+        let amount = ContractOwnershipComponent.ops_owner_sweep();
+
+        // Assert - the sweep is pending approval
+        assert_eq!(amount, YoctoNear::ZERO);
+        assert_eq!(
+            AccountNearDataObject::load(alfio).unwrap().near_balance(),
+            YoctoNear::ZERO
+        );
+        let pending = AdminApprovals::pending();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].operation, "ops_owner_sweep");
+
+        // Act - once approved, the next sweep is executed
+        ctx.predecessor(alfio).apply();
+        AdminApprovals::approve(pending[0].operation_id);
+        ctx.predecessor("keeper").apply();
+        let amount = ContractOwnershipComponent.ops_owner_sweep();
+
+        // Assert
+        assert!(amount > YoctoNear::ZERO);
+        assert_eq!(
+            AccountNearDataObject::load(alfio).unwrap().near_balance(),
+            amount
+        );
+        assert!(AdminApprovals::pending().is_empty());
+    }
+
+    #[test]
+    fn set_auto_sweep_requires_admin_approval() {
+        // Arrange
+        let alfio = "alfio";
+        let mut ctx = deploy_with_registered_owner(alfio);
+        AdminApprovals::set_policy(Some(ApprovalPolicy {
+            required_approvals: 1,
+        }));
+
+        // Act
+        ctx.with_deposit(1).apply();
+        ContractOwnershipComponent.ops_owner_set_auto_sweep(Some(YoctoNear::ZERO));
+
+        // Assert
+        assert!(ContractOwnershipComponent.ops_owner_auto_sweep().is_none());
+        let pending = AdminApprovals::pending();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].operation, "ops_owner_set_auto_sweep");
+    }
+
+    #[test]
+    fn sweep_without_policy() {
+        // Arrange
+        let alfio = "alfio";
+        deploy_with_registered_owner(alfio);
+
+        // Act
+        let amount = ContractOwnershipComponent.ops_owner_sweep();
+
+        // Assert
+        assert_eq!(amount, YoctoNear::ZERO);
+        assert_eq!(
+            AccountNearDataObject::load(alfio).unwrap().near_balance(),
+            YoctoNear::ZERO
+        );
+    }
+
+    #[test]
+    fn sweep_within_threshold() {
+        // Arrange
+        let alfio = "alfio";
+        let mut ctx = deploy_with_registered_owner(alfio);
+        let initial_balance = ContractOwnershipComponent.ops_owner_balance();
+        ctx.with_deposit(1).apply();
+        ContractOwnershipComponent
+            .ops_owner_set_auto_sweep(Some(initial_balance.total + YoctoNear::from(1)));
+
+        // Act
+        let amount = ContractOwnershipComponent.ops_owner_sweep();
+
+        // Assert
+        assert_eq!(amount, YoctoNear::ZERO);
+    }
+
+    #[test]
+    fn clear_auto_sweep() {
+        // Arrange
+        let alfio = "alfio";
+        let mut ctx = deploy_with_registered_owner(alfio);
+        ctx.with_deposit(1).apply();
+        ContractOwnershipComponent.ops_owner_set_auto_sweep(Some(YoctoNear::from(100)));
+
+        // Act
+        ContractOwnershipComponent.ops_owner_set_auto_sweep(None);
+
+        // Assert
+        assert!(ContractOwnershipComponent.ops_owner_auto_sweep().is_none());
+        assert_eq!(
+            test_utils::get_logs().last().unwrap(),
+            "[INFO] [OWNER_AUTO_SWEEP_UPDATED] cleared"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"OWNER_ACCESS_REQUIRED\""#)]
+    fn set_auto_sweep_as_non_owner() {
+        // Arrange
+        let alfio = "alfio";
+        let mut ctx = deploy_with_registered_owner(alfio);
+
+        // Act
+        ctx.predecessor("bob").with_deposit(1).apply();
+        ContractOwnershipComponent.ops_owner_set_auto_sweep(Some(YoctoNear::from(100)));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
+    fn set_auto_sweep_with_zero_deposit() {
+        // Arrange
+        let alfio = "alfio";
+        deploy_with_registered_owner(alfio);
+
+        // Act
+        ContractOwnershipComponent.ops_owner_set_auto_sweep(Some(YoctoNear::from(100)));
+    }
+}
+
+#[cfg(test)]
+mod tests_owner_delegate {
+    use super::*;
+    use near_sdk::test_utils;
+    use oysterpack_smart_near_test::*;
+
+    fn deploy_with_delegate(owner: &str, delegate: &str) -> TestCtx {
+        let mut ctx = TestCtx::new(owner);
+        ctx.apply();
+        ContractOwnershipComponent::deploy(to_valid_account_id(owner));
+
+        ctx.with_deposit(1).apply();
+        ContractOwnershipComponent
+            .ops_owner_add_full_access_delegate(to_valid_account_id(delegate));
+        ctx
+    }
+
+    #[test]
+    fn delegate_exercises_owner_access() {
+        // Arrange
+        let mut ctx = deploy_with_delegate("alfio", "bob");
+        assert_eq!(
+            ContractOwnershipComponent.ops_owner_delegate(),
+            Some("bob".to_string())
+        );
+        assert_eq!(
+            test_utils::get_logs().last().unwrap(),
+            "[INFO] [OWNER_DELEGATE_ADDED] bob"
+        );
+
+        // Act
+        ctx.predecessor("bob").apply();
+        ContractOwnershipComponent.ops_owner_set_auto_sweep(Some(YoctoNear::from(100)));
+
+        // Assert
+        assert!(ContractOwnerObject::has_owner_access());
+        assert_eq!(
+            ContractOwnershipComponent.ops_owner_auto_sweep(),
+            Some(OwnerAutoSweep {
+                threshold: YoctoNear::from(100)
+            })
+        );
+    }
+
+    #[test]
+    fn remove_delegate() {
+        // Arrange
+        let mut ctx = deploy_with_delegate("alfio", "bob");
+
+        // Act
+        ContractOwnershipComponent.ops_owner_remove_delegate();
+
+        // Assert
+        assert!(ContractOwnershipComponent.ops_owner_delegate().is_none());
+        assert_eq!(
+            test_utils::get_logs().last().unwrap(),
+            "[INFO] [OWNER_DELEGATE_REMOVED] "
+        );
+        ctx.predecessor("bob").apply();
+        assert!(!ContractOwnerObject::has_owner_access());
+    }
+
+    #[test]
+    fn delegate_is_cleared_when_ownership_is_transferred() {
+        // Arrange
+        let mut ctx = deploy_with_delegate("alfio", "bob");
+        ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id("alice"));
+
+        // Act
+        ctx.predecessor("alice").apply();
+        ContractOwnershipComponent.ops_owner_finalize_transfer();
+
+        // Assert
+        assert!(ContractOwnershipComponent.ops_owner_delegate().is_none());
+        assert_eq!(
+            test_utils::get_logs().last().unwrap(),
+            "[INFO] [OWNER_DELEGATE_REMOVED] contract ownership was transferred"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"OWNER_ACCESS_REQUIRED\""#)]
+    fn delegate_cannot_transfer_ownership() {
+        // Arrange
+        let mut ctx = deploy_with_delegate("alfio", "bob");
+
+        // Act
+        ctx.predecessor("bob").apply();
+        ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id("alice"));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"OWNER_ACCESS_REQUIRED\""#)]
+    fn delegate_cannot_replace_delegate() {
+        // Arrange
+        let mut ctx = deploy_with_delegate("alfio", "bob");
+
+        // Act
+        ctx.predecessor("bob").apply();
+        ContractOwnershipComponent.ops_owner_add_full_access_delegate(to_valid_account_id("alice"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"BAD_REQUEST\",\"message\":\"owner cannot be its own delegate\"}"#
+    )]
+    fn owner_as_delegate() {
+        deploy_with_delegate("alfio", "alfio");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
+    fn remove_delegate_with_zero_deposit() {
+        // Arrange
+        let mut ctx = deploy_with_delegate("alfio", "bob");
+
+        // Act
+        ctx.with_deposit(0).apply();
+        ContractOwnershipComponent.ops_owner_remove_delegate();
+    }
+}
+
+#[cfg(test)]
+mod tests_owner_vesting {
+    use super::*;
+    use near_sdk::test_utils;
+    use oysterpack_smart_near_test::*;
+
+    const ALFIO: &str = "alfio";
+    const BOB: &str = "bob";
+
+    /// transfers ownership from alfio to bob with a 100 ns cliff and 1000 ns vesting duration,
+    /// which is finalized at block timestamp 1000
+    fn transfer_with_vesting() -> TestCtx {
+        let mut ctx = TestCtx::new(ALFIO);
+        ctx.apply();
+        ContractOwnershipComponent::deploy(to_valid_account_id(ALFIO));
+
+        ctx.with_deposit(1).apply();
+        let vesting = OwnerVestingTerms {
+            cliff_duration: 100.into(),
+            vesting_duration: 1000.into(),
+        };
+        ContractOwnershipComponent
+            .ops_owner_transfer_with_vesting(to_valid_account_id(BOB), vesting);
+        assert_eq!(
+            ContractOwnershipComponent.ops_owner_prospective_vesting(),
+            Some(vesting)
+        );
+
+        ctx.predecessor(BOB).block_timestamp(1000).apply();
+        ContractOwnershipComponent.ops_owner_finalize_transfer();
+        ctx
+    }
+
+    #[test]
+    fn withdraw_vested_balance() {
+        // Arrange
+        let mut ctx = transfer_with_vesting();
+        let logs = test_utils::get_logs();
+        println!("{:#?}", logs);
+        assert!(
+            logs.contains(&"[INFO] [OWNER_VESTING] start=1000, cliff=1100, end=2000".to_string())
+        );
+        assert!(ContractOwnershipComponent
+            .ops_owner_prospective_vesting()
+            .is_none());
+        let schedule = ContractOwnershipComponent
+            .ops_owner_vesting_schedule()
+            .unwrap();
+
+        // Act
+        ctx.block_timestamp(1500).apply();
+        let available = ContractOwnershipComponent.ops_owner_balance().available;
+        let owner_balance = ContractOwnershipComponent.ops_owner_withdraw_balance(None);
+
+        // Assert
+        let vested = schedule.withdrawable(available, 1500.into());
+        assert!(vested > YoctoNear::ZERO && vested < available);
+        assert_eq!(owner_balance.available, available - vested);
+        assert_eq!(
+            ContractOwnershipComponent
+                .ops_owner_vesting_schedule()
+                .unwrap()
+                .withdrawn,
+            vested
+        );
+
+        // Act - once fully vested, the schedule no longer applies
+        ctx.block_timestamp(2000).apply();
+        assert!(ContractOwnershipComponent
+            .ops_owner_vesting_schedule()
+            .is_none());
+        let owner_balance = ContractOwnershipComponent.ops_owner_withdraw_balance(None);
+        assert_eq!(owner_balance.available, YoctoNear::ZERO);
+        assert!(OwnerVestingSchedule::load().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"OWNER_BALANCE_VESTING\""#)]
+    fn withdraw_before_cliff() {
+        // Arrange
+        let mut ctx = transfer_with_vesting();
+
+        // Act
+        ctx.block_timestamp(1099).apply();
+        ContractOwnershipComponent.ops_owner_withdraw_balance(Some(1.into()));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"OWNER_BALANCE_VESTING\""#)]
+    fn transfer_with_vesting_while_vesting() {
+        // Arrange
+        transfer_with_vesting();
+
+        // Act - the new owner cannot escape the vesting schedule by transferring ownership
+        ContractOwnershipComponent.ops_owner_transfer_with_vesting(
+            to_valid_account_id(ALFIO),
+            OwnerVestingTerms {
+                cliff_duration: 0.into(),
+                vesting_duration: 1.into(),
+            },
+        );
+    }
+
+    #[test]
+    fn cancel_transfer_clears_vesting_terms() {
+        // Arrange
+        let mut ctx = TestCtx::new(ALFIO);
+        ctx.apply();
+        ContractOwnershipComponent::deploy(to_valid_account_id(ALFIO));
+
+        ctx.with_deposit(1).apply();
+        ContractOwnershipComponent.ops_owner_transfer_with_vesting(
+            to_valid_account_id(BOB),
+            OwnerVestingTerms {
+                cliff_duration: 100.into(),
+                vesting_duration: 1000.into(),
+            },
+        );
+
+        // Act
+        ContractOwnershipComponent.ops_owner_cancel_transfer();
+
+        // Assert
+        assert!(ContractOwnershipComponent
+            .ops_owner_prospective_vesting()
+            .is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"OWNER_BALANCE_VESTING\""#)]
+    fn transfer_while_vesting() {
+        // Arrange
+        transfer_with_vesting();
+
+        // Act - the new owner cannot escape the vesting schedule by transferring ownership
+        ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(ALFIO));
+    }
+
+    #[test]
+    fn transfer_without_vesting_terms_clears_vesting_schedule() {
+        // Arrange
+        let mut ctx = transfer_with_vesting();
+        assert!(OwnerVestingSchedule::load().is_some());
+
+        // Act - once fully vested, the owner transfers ownership without vesting terms
+        ctx.block_timestamp(2000).apply();
+        assert!(ContractOwnershipComponent
+            .ops_owner_vesting_schedule()
+            .is_none());
+        ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(ALFIO));
+        ctx.predecessor(ALFIO).apply();
+        ContractOwnershipComponent.ops_owner_finalize_transfer();
+
+        // Assert
+        assert!(OwnerVestingSchedule::load().is_none());
+        let available = ContractOwnershipComponent.ops_owner_balance().available;
+        assert_eq!(
+            OwnerVestingSchedule::withdrawable_balance(available),
+            available
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests_owner_guardians {
+    use super::*;
+    use near_sdk::test_utils;
+    use oysterpack_smart_near_test::*;
+
+    const ALFIO: &str = "alfio";
+    const BOB: &str = "bob";
+    const GUARDIANS: [&str; 3] = ["guardian_1", "guardian_2", "guardian_3"];
+
+    /// alfio registers 3 guardians with a quorum of 2
+    fn deploy_with_guardians() -> TestCtx {
+        let mut ctx = TestCtx::new(ALFIO);
+        ctx.block_timestamp(1000).apply();
+        ContractOwnershipComponent::deploy(to_valid_account_id(ALFIO));
+
+        ctx.with_deposit(1).apply();
+        ContractOwnershipComponent.ops_owner_set_guardians(Some(OwnerGuardians {
+            guardians: GUARDIANS
+                .iter()
+                .map(|guardian| guardian.to_string())
+                .collect(),
+            quorum: 2,
+            delay: OwnerGuardians::MIN_DELAY.into(),
+        }));
+        ctx
+    }
+
+    fn vote(ctx: &mut TestCtx, guardian: &str, new_owner: &str) {
+        ctx.predecessor(guardian).apply();
+        ContractOwnershipComponent.ops_owner_recovery_vote(to_valid_account_id(new_owner));
+    }
+
+    #[test]
+    fn recover_ownership() {
+        // Arrange
+        let mut ctx = deploy_with_guardians();
+        assert_eq!(
+            ContractOwnershipComponent
+                .ops_owner_guardians()
+                .unwrap()
+                .quorum,
+            2
+        );
+
+        // Act
+        vote(&mut ctx, GUARDIANS[0], BOB);
+
+        // Assert - quorum has not been reached
+        assert_eq!(
+            test_utils::get_logs(),
+            vec![format!(
+                "[WARN] [OWNER_RECOVERY_VOTE] guardian={}, new_owner={}, votes=1",
+                GUARDIANS[0], BOB
+            )]
+        );
+        assert!(ContractOwnershipComponent
+            .ops_owner_recovery()
+            .unwrap()
+            .timelock
+            .is_none());
+
+        // Act
+        vote(&mut ctx, GUARDIANS[1], BOB);
+
+        // Assert - the timelock has started
+        let eligible_at = BlockTimestamp(1000 + OwnerGuardians::MIN_DELAY);
+        assert_eq!(
+            test_utils::get_logs().last().unwrap(),
+            &format!(
+                "[WARN] [OWNER_RECOVERY_INITIATED] new_owner={}, eligible_at={}",
+                BOB,
+                eligible_at.value()
+            )
+        );
+        assert_eq!(
+            ContractOwnershipComponent
+                .ops_owner_recovery()
+                .unwrap()
+                .timelock,
+            Some(OwnerRecoveryTimelock {
+                new_owner: BOB.to_string(),
+                eligible_at
+            })
+        );
+
+        // Act - the recovered owner finalizes once the timelock has elapsed
+        ctx.predecessor(BOB)
+            .block_timestamp(eligible_at.value())
+            .apply();
+        ContractOwnershipComponent.ops_owner_recovery_finalize();
+
+        // Assert
+        assert_eq!(ContractOwnershipComponent.ops_owner(), BOB);
+        assert!(ContractOwnerObject::is_owner());
+        assert!(ContractOwnershipComponent.ops_owner_recovery().is_none());
+        let logs = test_utils::get_logs();
+        assert!(logs.contains(&format!("[WARN] [OWNER_RECOVERED] {}", BOB)));
+        // the guardians that were appointed by the previous owner are cleared
+        assert!(ContractOwnershipComponent.ops_owner_guardians().is_none());
+        assert!(logs.contains(
+            &"[INFO] [OWNER_GUARDIANS_UPDATED] cleared: contract ownership was recovered"
+                .to_string()
+        ));
+    }
+
+    #[test]
+    fn finalize_transfer_clears_guardians_and_recovery() {
+        // Arrange
+        let mut ctx = deploy_with_guardians();
+        vote(&mut ctx, GUARDIANS[0], "alice");
+        assert!(ContractOwnershipComponent.ops_owner_recovery().is_some());
+
+        ctx.predecessor(ALFIO).apply();
+        ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(BOB));
+
+        // Act
+        ctx.predecessor(BOB).apply();
+        ContractOwnershipComponent.ops_owner_finalize_transfer();
+
+        // Assert
+        assert_eq!(ContractOwnershipComponent.ops_owner(), BOB);
+        assert!(ContractOwnershipComponent.ops_owner_guardians().is_none());
+        assert!(ContractOwnershipComponent.ops_owner_recovery().is_none());
+        let logs = test_utils::get_logs();
+        println!("{:#?}", logs);
+        assert!(logs.contains(
+            &"[INFO] [OWNER_GUARDIANS_UPDATED] cleared: contract ownership was transferred"
+                .to_string()
+        ));
+        assert!(logs.contains(
+            &"[INFO] [OWNER_RECOVERY_CANCELLED] contract ownership was transferred".to_string()
+        ));
+    }
+
+    #[test]
+    fn guardian_can_change_vote_until_quorum_is_reached() {
+        // Arrange
+        let mut ctx = deploy_with_guardians();
+        vote(&mut ctx, GUARDIANS[0], BOB);
+        vote(&mut ctx, GUARDIANS[1], "alice");
+
+        // Act
+        vote(&mut ctx, GUARDIANS[1], BOB);
+
+        // Assert
+        let recovery = ContractOwnershipComponent.ops_owner_recovery().unwrap();
+        assert_eq!(recovery.votes.len(), 2);
+        assert_eq!(recovery.timelock.unwrap().new_owner, BOB);
+    }
+
+    #[test]
+    fn owner_cancels_recovery() {
+        // Arrange
+        let mut ctx = deploy_with_guardians();
+        vote(&mut ctx, GUARDIANS[0], BOB);
+        vote(&mut ctx, GUARDIANS[1], BOB);
+
+        // Act
+        ctx.predecessor(ALFIO).apply();
+        ContractOwnershipComponent.ops_owner_recovery_cancel();
+
+        // Assert
+        assert!(ContractOwnershipComponent.ops_owner_recovery().is_none());
+        assert_eq!(
+            test_utils::get_logs(),
+            vec!["[INFO] [OWNER_RECOVERY_CANCELLED] ".to_string()]
+        );
+        assert_eq!(ContractOwnershipComponent.ops_owner(), ALFIO);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"OWNER_RECOVERY_NOT_ELIGIBLE\",\"message\":\"eligible_at="#
+    )]
+    fn finalize_before_timelock_elapsed() {
+        // Arrange
+        let mut ctx = deploy_with_guardians();
+        vote(&mut ctx, GUARDIANS[0], BOB);
+        vote(&mut ctx, GUARDIANS[1], BOB);
+
+        // Act
+        ctx.block_timestamp(ctx.context().block_timestamp + OwnerGuardians::MIN_DELAY - 1)
+            .apply();
+        ContractOwnershipComponent.ops_owner_recovery_finalize();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"OWNER_RECOVERY_NOT_ELIGIBLE\",\"message\":\"guardian quorum has not been reached\"}"#
+    )]
+    fn finalize_before_quorum() {
+        // Arrange
+        let mut ctx = deploy_with_guardians();
+        vote(&mut ctx, GUARDIANS[0], BOB);
+
+        // Act
+        ContractOwnershipComponent.ops_owner_recovery_finalize();
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"GUARDIAN_ACCESS_REQUIRED\""#)]
+    fn vote_by_non_guardian() {
+        let mut ctx = deploy_with_guardians();
+        vote(&mut ctx, BOB, BOB);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"BAD_REQUEST\",\"message\":\"quorum must be > 0 and cannot exceed the number of guardians\"}"#
+    )]
+    fn quorum_exceeds_guardians() {
+        let mut ctx = TestCtx::new(ALFIO);
+        ctx.apply();
+        ContractOwnershipComponent::deploy(to_valid_account_id(ALFIO));
+
+        ctx.with_deposit(1).apply();
+        ContractOwnershipComponent.ops_owner_set_guardians(Some(OwnerGuardians {
+            guardians: vec![BOB.to_string()],
+            quorum: 2,
+            delay: OwnerGuardians::MIN_DELAY.into(),
+        }));
+    }
+}
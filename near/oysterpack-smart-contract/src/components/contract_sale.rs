@@ -1,7 +1,7 @@
 //! [`ContractSaleComponent`]
 
 use crate::components::contract_ownership::ContractOwnershipComponent;
-use crate::{ContractBid, ContractSale};
+use crate::{ContractBid, ContractSale, OwnerVestingSchedule};
 use crate::{
     ContractBuyerBid, ContractOwner, ContractOwnerObject, ContractOwnership,
     ContractOwnershipAccountIdsObject, ERR_ACCESS_DENIED_MUST_BE_BUYER, ERR_CONTRACT_BID_TOO_LOW,
@@ -77,7 +77,7 @@ impl ContractSale for ContractSaleComponent {
 
     fn ops_contract_cancel_sale(&mut self) {
         assert_yocto_near_attached();
-        let mut contract_owner = ContractOwnerObject::assert_owner_access();
+        let mut contract_owner = ContractOwnerObject::assert_owner_account_access();
         if contract_owner.sale_price.take().is_some() {
             contract_owner.save();
             LOG_EVENT_CONTRACT_SALE_CANCELLED.log("");
@@ -337,7 +337,7 @@ impl ContractSaleComponent {
 
     fn validate_sell_contract_request(price: YoctoNear) -> ContractOwnerObject {
         assert_yocto_near_attached();
-        let contract_owner = ContractOwnerObject::assert_owner_access();
+        let contract_owner = ContractOwnerObject::assert_owner_account_access();
         ERR_CONTRACT_SALE_NOT_ALLOWED.assert(
             || !contract_owner.transfer_initiated(),
             || "contract cannot be sold after transfer process has been started",
@@ -356,6 +356,8 @@ impl ContractSaleComponent {
     ///    - set the new owner account ID
     ///    - clear the buyer account ID
     /// 5. log event: LOG_EVENT_CONTRACT_SOLD
+    /// 6. clear the owner settings that were configured by or for the previous owner - see
+    ///    [`ContractOwnershipComponent::on_owner_changed`]
     fn execute_contract_sale(
         owner: &mut ContractOwner,
         account_ids: &mut ContractOwnershipAccountIdsObject,
@@ -363,8 +365,11 @@ impl ContractSaleComponent {
         ContractBid::clear_near_balance();
 
         // transfer the owner's NEAR funds out to the owner's account
+        // - if the owner balance is vesting, then only the vested balance is transferred
         let owner_balance = ContractOwnershipComponent.ops_owner_balance();
-        Promise::new(account_ids.owner.clone()).transfer(owner_balance.available.value());
+        let amount = OwnerVestingSchedule::withdrawable_balance(owner_balance.available);
+        Promise::new(account_ids.owner.clone()).transfer(amount.value());
+        OwnerVestingSchedule::record_withdrawal(amount);
 
         // update the contract owner
         let (buyer_account_id_hash, bid) = owner
@@ -382,6 +387,8 @@ impl ContractSaleComponent {
             "buyer={}, price={}",
             &account_ids.owner, bid.amount
         ));
+        // the unvested balance is transferred to the buyer along with contract ownership
+        ContractOwnershipComponent::on_owner_changed("contract was sold");
     }
 }
 
@@ -402,9 +409,8 @@ mod tests {
         let alfio = "alfio";
         let bob = "bob";
 
-        let mut ctx = new_context(alfio);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
@@ -417,9 +423,7 @@ mod tests {
         service.ops_contract_cancel_bid();
 
         // Act - Bob will submit a bid to buy the contract
-        ctx.predecessor_account_id = bob.to_string();
-        ctx.attached_deposit = 1000;
-        testing_env!(ctx.clone());
+        ctx.predecessor(bob).with_deposit(1000).apply();
         service.ops_contract_buy(None);
         // Assert
         let logs = test_utils::get_logs();
@@ -431,7 +435,7 @@ mod tests {
         assert!(bid.bid.expiration.is_none());
 
         // Act - Bob raises the bid
-        testing_env!(ctx.clone());
+        ctx.apply();
         service.ops_contract_raise_bid(None);
         // Assert
         let logs = test_utils::get_logs();
@@ -443,7 +447,7 @@ mod tests {
         assert!(bid.bid.expiration.is_none());
 
         // Act - Bob raises the bid and updates expiration
-        testing_env!(ctx.clone());
+        ctx.apply();
         service.ops_contract_raise_bid(None);
         // Assert
         let logs = test_utils::get_logs();
@@ -455,8 +459,7 @@ mod tests {
         assert!(bid.bid.expiration.is_none());
 
         // Act - Bob sets an expiration
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         service.ops_contract_update_bid_expiration(ExpirationSetting::Relative(
             ExpirationDuration::Epochs(10),
         ));
@@ -473,7 +476,7 @@ mod tests {
         );
 
         // Act - Bob clears the expiration
-        testing_env!(ctx.clone());
+        ctx.apply();
         service.ops_contract_clear_bid_expiration();
         // Assert
         let logs = test_utils::get_logs();
@@ -485,7 +488,7 @@ mod tests {
         assert!(bid.bid.expiration.is_none());
 
         // Act - Bob lowers the bid
-        testing_env!(ctx.clone());
+        ctx.apply();
         service.ops_contract_lower_bid(1000.into(), None);
         // Assert
         let logs = test_utils::get_logs();
@@ -505,8 +508,7 @@ mod tests {
         }
 
         // Act - owner sells contract
-        ctx.predecessor_account_id = alfio.to_string();
-        testing_env!(ctx.clone());
+        ctx.predecessor(alfio).apply();
         service.ops_contract_sell(YOCTO.into());
         // Assert
         let logs = test_utils::get_logs();
@@ -522,7 +524,7 @@ mod tests {
         assert!(bid.bid.expiration.is_none());
 
         // Act - owner cancels sale
-        testing_env!(ctx.clone());
+        ctx.apply();
         service.ops_contract_cancel_sale();
         // Assert
         let logs = test_utils::get_logs();
@@ -535,12 +537,14 @@ mod tests {
         assert!(bid.bid.expiration.is_none());
 
         // Act - buyer cancels bid
-        ctx.predecessor_account_id = ContractSaleComponent
-            .ops_contract_bid()
-            .unwrap()
-            .buyer
-            .clone();
-        testing_env!(ctx.clone());
+        ctx.predecessor(
+            &ContractSaleComponent
+                .ops_contract_bid()
+                .unwrap()
+                .buyer
+                .clone(),
+        )
+        .apply();
         service.ops_contract_cancel_bid();
         // Assert
         let logs = test_utils::get_logs();
@@ -550,8 +554,7 @@ mod tests {
         assert_eq!(ContractBid::near_balance(), YoctoNear::ZERO);
 
         // Act - owner sells contract
-        ctx.predecessor_account_id = alfio.to_string();
-        testing_env!(ctx.clone());
+        ctx.predecessor(alfio).apply();
         service.ops_contract_sell(YOCTO.into());
         // Assert
         let logs = test_utils::get_logs();
@@ -562,9 +565,7 @@ mod tests {
         );
 
         // Act - Bob will submit a bid high enough to buy the contract
-        ctx.predecessor_account_id = bob.to_string();
-        ctx.attached_deposit = YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(bob).with_deposit(YOCTO).apply();
         let previous_owner = ContractOwnershipComponent.ops_owner();
         let owner_balance = ContractOwnershipComponent.ops_owner_balance();
         service.ops_contract_buy(None);
@@ -599,9 +600,8 @@ mod tests_sell_contract {
         // Arrange
         let alfio = "alfio";
 
-        let mut ctx = new_context(alfio);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
@@ -625,9 +625,8 @@ mod tests_sell_contract {
         // Arrange
         let alfio = "alfio";
 
-        let mut ctx = new_context(alfio);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
@@ -656,9 +655,8 @@ mod tests_sell_contract {
         // Arrange
         let alfio = "alfio";
 
-        let mut ctx = new_context(alfio);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(alfio);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(alfio));
 
@@ -684,20 +682,15 @@ mod tests_sell_contract {
         let owner = "alfio";
         let buyer = "bob";
 
-        let mut ctx = new_context(owner);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(owner);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(owner));
-        ctx.attached_deposit = 100;
-        ctx.predecessor_account_id = buyer.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(100).predecessor(buyer).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = owner.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).predecessor(owner).apply();
         ContractSaleComponent.ops_contract_sell(YOCTO.into());
         // Assert
         assert_eq!(
@@ -718,20 +711,15 @@ mod tests_sell_contract {
         let owner = "alfio";
         let buyer = "bob";
 
-        let mut ctx = new_context(owner);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(owner);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(owner));
-        ctx.attached_deposit = YOCTO;
-        ctx.predecessor_account_id = buyer.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(YOCTO).predecessor(buyer).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = owner.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).predecessor(owner).apply();
         ContractSaleComponent.ops_contract_sell(YOCTO.into());
         // Assert
         assert!(ContractSaleComponent.ops_contract_sale_price().is_none());
@@ -755,20 +743,15 @@ mod tests_sell_contract {
         let owner = "alfio";
         let buyer = "bob";
 
-        let mut ctx = new_context(owner);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(owner);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(owner));
-        ctx.attached_deposit = 2 * YOCTO;
-        ctx.predecessor_account_id = buyer.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(2 * YOCTO).predecessor(buyer).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = owner.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).predecessor(owner).apply();
         ContractSaleComponent.ops_contract_sell(YOCTO.into());
         // Assert
         assert!(ContractSaleComponent.ops_contract_sale_price().is_none());
@@ -792,24 +775,23 @@ mod tests_sell_contract {
         let owner = "alfio";
         let buyer = "bob";
 
-        let mut ctx = new_context(owner);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(owner);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(owner));
-        ctx.attached_deposit = 2 * YOCTO;
-        ctx.predecessor_account_id = buyer.to_string();
-        ctx.epoch_height = 100;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(2 * YOCTO)
+            .predecessor(buyer)
+            .epoch_height(100)
+            .apply();
         ContractSaleComponent.ops_contract_buy(Some(ExpirationSetting::Absolute(
             Expiration::Epoch(200.into()),
         )));
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = owner.to_string();
-        ctx.epoch_height = 201;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1)
+            .predecessor(owner)
+            .epoch_height(201)
+            .apply();
         ContractSaleComponent.ops_contract_sell(YOCTO.into());
         // Assert
         assert_eq!(
@@ -840,20 +822,15 @@ mod tests_sell_contract {
         let owner = "alfio";
         let buyer = "bob";
 
-        let mut ctx = new_context(owner);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(owner);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(owner));
-        ctx.attached_deposit = YOCTO;
-        ctx.predecessor_account_id = buyer.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(YOCTO).predecessor(buyer).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = owner.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).predecessor(owner).apply();
         ContractSaleComponent.ops_contract_sell((5 * YOCTO).into());
         ContractSaleComponent.ops_contract_sell(YOCTO.into());
         // Assert
@@ -882,20 +859,15 @@ mod tests_sell_contract {
         let owner = "alfio";
         let buyer = "bob";
 
-        let mut ctx = new_context(owner);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(owner);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(owner));
-        ctx.attached_deposit = 2 * YOCTO;
-        ctx.predecessor_account_id = buyer.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(2 * YOCTO).predecessor(buyer).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = owner.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).predecessor(owner).apply();
         ContractSaleComponent.ops_contract_sell((5 * YOCTO).into());
         ContractSaleComponent.ops_contract_sell(YOCTO.into());
         // Assert
@@ -920,99 +892,86 @@ mod tests_sell_contract {
 
     #[test]
     #[should_panic(
-        expected = "[ERR] [CONTRACT_SALE_NOT_ALLOWED] contract cannot be sold after transfer process has been started"
+        expected = r#"{\"code\":\"CONTRACT_SALE_NOT_ALLOWED\",\"message\":\"contract cannot be sold after transfer process has been started"#
     )]
     fn transfer_ownership_initiated() {
         // Arrange
         let owner = "alfio";
         let buyer = "bob";
 
-        let mut ctx = new_context(owner);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(owner);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(owner));
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(buyer));
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = owner.to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).predecessor(owner).apply();
         ContractSaleComponent.ops_contract_sell(YOCTO.into());
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [OWNER_ACCESS_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"OWNER_ACCESS_REQUIRED\""#)]
     fn not_owner() {
         // Arrange
         let owner = "alfio";
 
-        let mut ctx = new_context(owner);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(owner);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(owner));
 
         // Act
-        ctx.attached_deposit = 1;
-        ctx.predecessor_account_id = "bob".to_string();
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).predecessor("bob").apply();
         ContractSaleComponent.ops_contract_sell(YOCTO.into());
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn zero_deposit() {
         // Arrange
         let owner = "alfio";
 
-        let mut ctx = new_context(owner);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(owner);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(owner));
 
         // Act
-        ctx.attached_deposit = 0;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(0).apply();
         ContractSaleComponent.ops_contract_sell(YOCTO.into());
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn two_deposit() {
         // Arrange
         let owner = "alfio";
 
-        let mut ctx = new_context(owner);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(owner);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(owner));
 
         // Act
-        ctx.attached_deposit = 2;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(2).apply();
         ContractSaleComponent.ops_contract_sell(YOCTO.into());
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [CONTRACT_SALE_PRICE_MUST_NOT_BE_ZERO]")]
+    #[should_panic(expected = r#"{\"code\":\"CONTRACT_SALE_PRICE_MUST_NOT_BE_ZERO\""#)]
     fn zero_sale_price() {
         // Arrange
         let owner = "alfio";
 
-        let mut ctx = new_context(owner);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(owner);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(owner));
 
         // Act
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractSaleComponent.ops_contract_sell(YoctoNear::ZERO);
     }
 }
@@ -1021,7 +980,7 @@ mod tests_sell_contract {
 mod tests_buy_contract {
     use super::*;
     use crate::components::contract_ownership::ContractOwnershipComponent;
-    use near_sdk::{test_utils, VMContext};
+    use near_sdk::test_utils;
     use oysterpack_smart_near::component::*;
     use oysterpack_smart_near::YOCTO;
     use oysterpack_smart_near_test::*;
@@ -1030,12 +989,11 @@ mod tests_buy_contract {
     const BUYER_1: &str = "buyer1";
     const BUYER_2: &str = "buyer2";
 
-    fn arrange(sale_price: Option<YoctoNear>, bid: Option<ContractBuyerBid>) -> VMContext {
-        let ctx = new_context(OWNER);
+    fn arrange(sale_price: Option<YoctoNear>, bid: Option<ContractBuyerBid>) -> TestCtx {
+        let ctx = TestCtx::new(OWNER);
         {
             let mut ctx = ctx.clone();
-            ctx.attached_deposit = 1;
-            testing_env!(ctx.clone());
+            ctx.with_deposit(1).apply();
 
             ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
             if let Some(sale_price) = sale_price {
@@ -1043,9 +1001,9 @@ mod tests_buy_contract {
             }
 
             if let Some(bid) = bid {
-                ctx.predecessor_account_id = bid.buyer;
-                ctx.attached_deposit = bid.bid.amount.value();
-                testing_env!(ctx.clone());
+                ctx.predecessor(&bid.buyer)
+                    .with_deposit(bid.bid.amount.value())
+                    .apply();
                 ContractSaleComponent
                     .ops_contract_buy(bid.bid.expiration.as_ref().cloned().map(Into::into));
             }
@@ -1055,12 +1013,10 @@ mod tests_buy_contract {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [NEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"NEAR_DEPOSIT_REQUIRED\""#)]
     fn zero_yocto_near_attached() {
-        let mut ctx = new_context(OWNER);
-        ctx.attached_deposit = 0;
-        ctx.predecessor_account_id = BUYER_1.to_string();
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.with_deposit(0).predecessor(BUYER_1).apply();
         ContractSaleComponent.ops_contract_buy(None);
     }
 
@@ -1077,17 +1033,14 @@ mod tests_buy_contract {
             }),
         );
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractSaleComponent.ops_contract_sell(YOCTO.into());
 
-        ctx.predecessor_account_id = BUYER_1.to_string();
-        ctx.attached_deposit = 1001;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER_1).with_deposit(1001).apply();
         ContractSaleComponent.ops_contract_buy(None);
         let bid = ContractSaleComponent.ops_contract_bid().unwrap();
-        assert_eq!(bid.buyer, ctx.predecessor_account_id);
-        assert_eq!(bid.bid.amount, ctx.attached_deposit.into());
+        assert_eq!(bid.buyer, ctx.context().predecessor_account_id);
+        assert_eq!(bid.bid.amount, ctx.context().attached_deposit.into());
 
         let logs = test_utils::get_logs();
         println!("{:#?}", logs);
@@ -1108,18 +1061,84 @@ mod tests_buy_contract {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [CONTRACT_SALE_NOT_ALLOWED]")]
+    #[should_panic(expected = r#"{\"code\":\"CONTRACT_SALE_NOT_ALLOWED\""#)]
     fn with_contract_transfer_initiated() {
         let mut ctx = arrange(None, None);
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         ContractOwnershipComponent.ops_owner_transfer(to_valid_account_id(BUYER_2));
 
-        ctx.predecessor_account_id = BUYER_1.to_string();
-        ctx.attached_deposit = 10000;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER_1).with_deposit(10000).apply();
+        ContractSaleComponent.ops_contract_buy(None);
+    }
+
+    #[test]
+    fn contract_sale_clears_guardians_and_recovery() {
+        // Arrange
+        let mut ctx = arrange(Some(1000.into()), None);
+        ctx.with_deposit(1).apply();
+        ContractOwnershipComponent.ops_owner_set_guardians(Some(crate::OwnerGuardians {
+            guardians: vec!["guardian_1".to_string(), "guardian_2".to_string()],
+            quorum: 2,
+            delay: crate::OwnerGuardians::MIN_DELAY.into(),
+        }));
+        ctx.predecessor("guardian_1").apply();
+        ContractOwnershipComponent.ops_owner_recovery_vote(to_valid_account_id(BUYER_2));
+        assert!(ContractOwnershipComponent.ops_owner_recovery().is_some());
+
+        // Act
+        ctx.predecessor(BUYER_1).with_deposit(1000).apply();
+        ContractSaleComponent.ops_contract_buy(None);
+
+        // Assert
+        assert_eq!(ContractOwnershipComponent.ops_owner(), BUYER_1);
+        assert!(ContractOwnershipComponent.ops_owner_guardians().is_none());
+        assert!(ContractOwnershipComponent.ops_owner_recovery().is_none());
+        let logs = test_utils::get_logs();
+        println!("{:#?}", logs);
+        assert!(logs
+            .contains(&"[INFO] [OWNER_GUARDIANS_UPDATED] cleared: contract was sold".to_string()));
+        assert!(logs.contains(&"[INFO] [OWNER_RECOVERY_CANCELLED] contract was sold".to_string()));
+    }
+
+    /// the owner configures a delegate and an auto-sweep policy, and then the contract is sold
+    fn sell_contract_with_owner_settings() -> TestCtx {
+        let mut ctx = arrange(Some(1000.into()), None);
+        ctx.with_deposit(1).apply();
+        ContractOwnershipComponent
+            .ops_owner_add_full_access_delegate(to_valid_account_id("delegate"));
+        ContractOwnershipComponent.ops_owner_set_auto_sweep(Some(100.into()));
+
+        ctx.predecessor(BUYER_1).with_deposit(1000).apply();
         ContractSaleComponent.ops_contract_buy(None);
+        assert_eq!(ContractOwnershipComponent.ops_owner(), BUYER_1);
+        ctx
+    }
+
+    #[test]
+    fn contract_sale_clears_owner_delegate_and_auto_sweep() {
+        // Act
+        sell_contract_with_owner_settings();
+
+        // Assert
+        assert!(ContractOwnershipComponent.ops_owner_delegate().is_none());
+        assert!(ContractOwnershipComponent.ops_owner_auto_sweep().is_none());
+        let logs = test_utils::get_logs();
+        println!("{:#?}", logs);
+        assert!(logs.contains(&"[INFO] [OWNER_DELEGATE_REMOVED] contract was sold".to_string()));
+        assert!(logs
+            .contains(&"[INFO] [OWNER_AUTO_SWEEP_UPDATED] cleared: contract was sold".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"OWNER_ACCESS_REQUIRED\""#)]
+    fn previous_owner_delegate_is_rejected_after_contract_sale() {
+        // Arrange
+        let mut ctx = sell_contract_with_owner_settings();
+
+        // Act
+        ctx.predecessor("delegate").with_deposit(1).apply();
+        ContractOwnershipComponent.ops_owner_set_auto_sweep(None);
     }
 
     #[cfg(test)]
@@ -1131,9 +1150,7 @@ mod tests_buy_contract {
         fn no_expiration() {
             let mut ctx = arrange(None, None);
 
-            ctx.predecessor_account_id = BUYER_1.to_string();
-            ctx.attached_deposit = 100;
-            testing_env!(ctx.clone());
+            ctx.predecessor(BUYER_1).with_deposit(100).apply();
             ContractSaleComponent.ops_contract_buy(None);
 
             let bid = ContractSaleComponent.ops_contract_bid().unwrap();
@@ -1153,10 +1170,10 @@ mod tests_buy_contract {
         fn with_future_expiration() {
             let mut ctx = arrange(None, None);
 
-            ctx.predecessor_account_id = BUYER_1.to_string();
-            ctx.attached_deposit = 100;
-            ctx.epoch_height = 10;
-            testing_env!(ctx.clone());
+            ctx.predecessor(BUYER_1)
+                .with_deposit(100)
+                .epoch_height(10)
+                .apply();
             let expiration = Expiration::Epoch(20.into());
             ContractSaleComponent.ops_contract_buy(Some(expiration.into()));
 
@@ -1179,10 +1196,10 @@ mod tests_buy_contract {
         fn with_future_relative_expiration() {
             let mut ctx = arrange(None, None);
 
-            ctx.predecessor_account_id = BUYER_1.to_string();
-            ctx.attached_deposit = 100;
-            ctx.epoch_height = 10;
-            testing_env!(ctx.clone());
+            ctx.predecessor(BUYER_1)
+                .with_deposit(100)
+                .epoch_height(10)
+                .apply();
             let expiration = Expiration::Epoch(20.into());
             ContractSaleComponent.ops_contract_buy(Some(ExpirationSetting::Relative(
                 ExpirationDuration::Epochs(10),
@@ -1204,14 +1221,16 @@ mod tests_buy_contract {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [BAD_REQUEST] expiration cannot be set to expired")]
+        #[should_panic(
+            expected = r#"{\"code\":\"BAD_REQUEST\",\"message\":\"expiration cannot be set to expired"#
+        )]
         fn with_expired_bid() {
             let mut ctx = arrange(None, None);
 
-            ctx.predecessor_account_id = BUYER_1.to_string();
-            ctx.attached_deposit = 100;
-            ctx.epoch_height = 10;
-            testing_env!(ctx.clone());
+            ctx.predecessor(BUYER_1)
+                .with_deposit(100)
+                .epoch_height(10)
+                .apply();
             let expiration = Expiration::Epoch(5.into());
             ContractSaleComponent.ops_contract_buy(Some(expiration.into()));
         }
@@ -1226,9 +1245,7 @@ mod tests_buy_contract {
         fn higher_sale_price() {
             let mut ctx = arrange(Some(1000.into()), None);
 
-            ctx.predecessor_account_id = BUYER_1.to_string();
-            ctx.attached_deposit = 100;
-            testing_env!(ctx.clone());
+            ctx.predecessor(BUYER_1).with_deposit(100).apply();
             ContractSaleComponent.ops_contract_buy(None);
 
             let bid = ContractSaleComponent.ops_contract_bid().unwrap();
@@ -1248,9 +1265,9 @@ mod tests_buy_contract {
         fn with_matching_sale_price() {
             let mut ctx = arrange(Some((YOCTO * 1_000_000).into()), None);
 
-            ctx.predecessor_account_id = BUYER_1.to_string();
-            ctx.attached_deposit = YOCTO * 1_000_000;
-            testing_env!(ctx.clone());
+            ctx.predecessor(BUYER_1)
+                .with_deposit(YOCTO * 1_000_000)
+                .apply();
             ContractSaleComponent.ops_contract_buy(None);
             let logs = test_utils::get_logs();
             println!("{:#?}", logs);
@@ -1267,7 +1284,9 @@ mod tests_buy_contract {
             let receipts = deserialize_receipts();
             assert_eq!(&receipts[0].receiver_id, OWNER);
             match &receipts[0].actions[0] {
-                Action::Transfer(transfer) => assert!(transfer.deposit > ctx.attached_deposit),
+                Action::Transfer(transfer) => {
+                    assert!(transfer.deposit > ctx.context().attached_deposit)
+                }
                 _ => panic!("expected TransferAction"),
             }
         }
@@ -1276,9 +1295,9 @@ mod tests_buy_contract {
         fn with_lower_sale_price() {
             let mut ctx = arrange(Some(100.into()), None);
 
-            ctx.predecessor_account_id = BUYER_1.to_string();
-            ctx.attached_deposit = YOCTO * 1_000_000;
-            testing_env!(ctx.clone());
+            ctx.predecessor(BUYER_1)
+                .with_deposit(YOCTO * 1_000_000)
+                .apply();
             ContractSaleComponent.ops_contract_buy(None);
             let logs = test_utils::get_logs();
             println!("{:#?}", logs);
@@ -1295,7 +1314,9 @@ mod tests_buy_contract {
             let receipts = deserialize_receipts();
             assert_eq!(&receipts[0].receiver_id, OWNER);
             match &receipts[0].actions[0] {
-                Action::Transfer(transfer) => assert!(transfer.deposit > ctx.attached_deposit),
+                Action::Transfer(transfer) => {
+                    assert!(transfer.deposit > ctx.context().attached_deposit)
+                }
                 _ => panic!("expected TransferAction"),
             }
         }
@@ -1307,7 +1328,7 @@ mod tests_buy_contract {
         use oysterpack_smart_near::domain::ExpirationDuration;
 
         #[test]
-        #[should_panic(expected = "[ERR] [CONTRACT_BID_NOT_ATTACHED]")]
+        #[should_panic(expected = r#"{\"code\":\"CONTRACT_BID_NOT_ATTACHED\""#)]
         fn higher_prior_bid() {
             let mut ctx = arrange(
                 None,
@@ -1320,15 +1341,13 @@ mod tests_buy_contract {
                 }),
             );
 
-            ctx.predecessor_account_id = BUYER_1.to_string();
-            ctx.attached_deposit = 999;
-            testing_env!(ctx.clone());
+            ctx.predecessor(BUYER_1).with_deposit(999).apply();
             ContractSaleComponent.ops_contract_buy(None);
         }
 
         #[test]
         fn higher_prior_expired_bid() {
-            testing_env!(new_context(OWNER));
+            TestCtx::new(OWNER).apply();
             let mut ctx = arrange(
                 None,
                 Some(ContractBuyerBid {
@@ -1344,16 +1363,15 @@ mod tests_buy_contract {
 
             let bid = ContractSaleComponent.ops_contract_bid().unwrap();
 
-            ctx.predecessor_account_id = BUYER_1.to_string();
-            ctx.attached_deposit = 999;
+            ctx.predecessor(BUYER_1).with_deposit(999);
             if let Some(Expiration::Epoch(epoch)) = bid.bid.expiration {
-                ctx.epoch_height = epoch.value() + 1; // expires the bid
+                ctx.epoch_height(epoch.value() + 1); // expires the bid
             }
-            testing_env!(ctx.clone());
+            ctx.apply();
             ContractSaleComponent.ops_contract_buy(None);
             let bid = ContractSaleComponent.ops_contract_bid().unwrap();
-            assert_eq!(bid.buyer, ctx.predecessor_account_id);
-            assert_eq!(bid.bid.amount, ctx.attached_deposit.into());
+            assert_eq!(bid.buyer, ctx.context().predecessor_account_id);
+            assert_eq!(bid.bid.amount, ctx.context().attached_deposit.into());
 
             let logs = test_utils::get_logs();
             println!("{:#?}", logs);
@@ -1374,7 +1392,7 @@ mod tests_buy_contract {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [CONTRACT_BID_NOT_ATTACHED]")]
+        #[should_panic(expected = r#"{\"code\":\"CONTRACT_BID_NOT_ATTACHED\""#)]
         fn matching_prior_bid() {
             let mut ctx = arrange(
                 None,
@@ -1387,9 +1405,7 @@ mod tests_buy_contract {
                 }),
             );
 
-            ctx.predecessor_account_id = BUYER_1.to_string();
-            ctx.attached_deposit = 999;
-            testing_env!(ctx.clone());
+            ctx.predecessor(BUYER_1).with_deposit(999).apply();
             ContractSaleComponent.ops_contract_buy(None);
         }
 
@@ -1406,13 +1422,11 @@ mod tests_buy_contract {
                 }),
             );
 
-            ctx.predecessor_account_id = BUYER_1.to_string();
-            ctx.attached_deposit = 1001;
-            testing_env!(ctx.clone());
+            ctx.predecessor(BUYER_1).with_deposit(1001).apply();
             ContractSaleComponent.ops_contract_buy(None);
             let bid = ContractSaleComponent.ops_contract_bid().unwrap();
-            assert_eq!(bid.buyer, ctx.predecessor_account_id);
-            assert_eq!(bid.bid.amount, ctx.attached_deposit.into());
+            assert_eq!(bid.buyer, ctx.context().predecessor_account_id);
+            assert_eq!(bid.bid.amount, ctx.context().attached_deposit.into());
 
             let logs = test_utils::get_logs();
             println!("{:#?}", logs);
@@ -1447,9 +1461,8 @@ mod tests_cancel_contract_sale {
     #[test]
     fn cancel_prior_sale() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
@@ -1474,9 +1487,8 @@ mod tests_cancel_contract_sale {
     #[test]
     fn no_prior_sale() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.with_deposit(1).apply();
 
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
@@ -1502,74 +1514,62 @@ mod tests_raise_contract_bid {
     const BUYER: &str = "buyer";
 
     #[test]
-    #[should_panic(expected = "[ERR] [NO_ACTIVE_BID]")]
+    #[should_panic(expected = r#"{\"code\":\"NO_ACTIVE_BID\""#)]
     fn no_prior_bid() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(YOCTO).apply();
         // Act
         ContractSaleComponent.ops_contract_raise_bid(None);
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [ACCESS_DENIED_MUST_BE_BUYER]")]
+    #[should_panic(expected = r#"{\"code\":\"ACCESS_DENIED_MUST_BE_BUYER\""#)]
     fn not_current_bidder() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(YOCTO).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.predecessor_account_id = "BUYER2".to_string();
-        ctx.attached_deposit = YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor("BUYER2").with_deposit(YOCTO).apply();
         // Act
         ContractSaleComponent.ops_contract_raise_bid(None);
     }
 
     #[test]
     #[should_panic(
-        expected = "[ERR] [NEAR_DEPOSIT_REQUIRED] NEAR deposit is required - bid raise cannot be zero"
+        expected = r#"{\"code\":\"NEAR_DEPOSIT_REQUIRED\",\"message\":\"NEAR deposit is required - bid raise cannot be zero"#
     )]
     fn with_zero_deposit_attached() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 1000;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(1000).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.attached_deposit = 0;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(0).apply();
         ContractSaleComponent.ops_contract_raise_bid(None);
     }
 
     #[test]
     fn prexisting_bid_with_no_sale_price() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 1000;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(1000).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.attached_deposit = 500;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(500).apply();
         // Act
         let raised_bid = ContractSaleComponent.ops_contract_raise_bid(None);
 
@@ -1582,23 +1582,17 @@ mod tests_raise_contract_bid {
     #[test]
     fn prexisting_bid_and_raise_triggers_buy() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 1000;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(1000).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.predecessor_account_id = OWNER.to_string();
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.predecessor(OWNER).with_deposit(1).apply();
         ContractSaleComponent.ops_contract_sell(1500.into());
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 500;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(500).apply();
         // Act
         ContractSaleComponent.ops_contract_raise_bid(None);
 
@@ -1607,30 +1601,24 @@ mod tests_raise_contract_bid {
         assert!(ContractSaleComponent.ops_contract_sale_price().is_none());
         assert_eq!(
             ContractOwnershipComponent.ops_owner(),
-            ctx.predecessor_account_id
+            ctx.context().predecessor_account_id
         );
     }
 
     #[test]
     fn prexisting_bid_with_higher_sale_price() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 1000;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(1000).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.predecessor_account_id = OWNER.to_string();
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.predecessor(OWNER).with_deposit(1).apply();
         ContractSaleComponent.ops_contract_sell(2500.into());
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 500;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(500).apply();
         // Act
         ContractSaleComponent.ops_contract_raise_bid(None);
 
@@ -1642,18 +1630,14 @@ mod tests_raise_contract_bid {
     #[test]
     fn prexisting_bid_with_expiration() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 1000;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(1000).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.attached_deposit = 500;
-        ctx.epoch_height = 10;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(500).epoch_height(10).apply();
         // Act
         ContractSaleComponent.ops_contract_raise_bid(Some(ExpirationSetting::Relative(
             ExpirationDuration::Epochs(10),
@@ -1671,20 +1655,19 @@ mod tests_raise_contract_bid {
     #[test]
     fn prexisting_bid_with_prior_expiration_set() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 1000;
-        ctx.epoch_height = 10;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER)
+            .with_deposit(1000)
+            .epoch_height(10)
+            .apply();
         ContractSaleComponent.ops_contract_buy(Some(ExpirationSetting::Relative(
             ExpirationDuration::Epochs(10),
         )));
 
-        ctx.attached_deposit = 500;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(500).apply();
         // Act
         ContractSaleComponent.ops_contract_raise_bid(None);
 
@@ -1698,24 +1681,22 @@ mod tests_raise_contract_bid {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [BID_IS_EXPIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"BID_IS_EXPIRED\""#)]
     fn bid_expired() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 1000;
-        ctx.epoch_height = 10;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER)
+            .with_deposit(1000)
+            .epoch_height(10)
+            .apply();
         ContractSaleComponent.ops_contract_buy(Some(ExpirationSetting::Relative(
             ExpirationDuration::Epochs(10),
         )));
 
-        ctx.attached_deposit = 500;
-        ctx.epoch_height = 100;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(500).epoch_height(100).apply();
         // Act
         ContractSaleComponent.ops_contract_raise_bid(None);
     }
@@ -1735,17 +1716,14 @@ mod tests_lower_contract_bid {
     #[test]
     fn lowered() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).apply();
         // Act
         let lowered_bid = ContractSaleComponent.ops_contract_lower_bid(YOCTO.into(), None);
 
@@ -1763,18 +1741,14 @@ mod tests_lower_contract_bid {
     #[test]
     fn lowered_with_new_expiration() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.attached_deposit = 1;
-        ctx.block_index = 10;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).block_index(10).apply();
         // Act
         ContractSaleComponent.ops_contract_lower_bid(
             YOCTO.into(),
@@ -1799,64 +1773,64 @@ mod tests_lower_contract_bid {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [NO_ACTIVE_BID]")]
+    #[should_panic(expected = r#"{\"code\":\"NO_ACTIVE_BID\""#)]
     fn no_bid() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(1).apply();
         // Act
         ContractSaleComponent.ops_contract_lower_bid(YOCTO.into(), None);
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [BID_IS_EXPIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"BID_IS_EXPIRED\""#)]
     fn prior_bid_expired() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = YOCTO;
-        ctx.epoch_height = 5;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER)
+            .with_deposit(YOCTO)
+            .epoch_height(5)
+            .apply();
         // Act
         ContractSaleComponent.ops_contract_buy(Some(ExpirationSetting::Absolute(
             Expiration::Epoch(10.into()),
         )));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 1;
-        ctx.epoch_height = 11;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER)
+            .with_deposit(1)
+            .epoch_height(11)
+            .apply();
         // Act
         ContractSaleComponent.ops_contract_lower_bid(100.into(), None);
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [BAD_REQUEST] expiration cannot be set to expired")]
+    #[should_panic(
+        expected = r#"{\"code\":\"BAD_REQUEST\",\"message\":\"expiration cannot be set to expired"#
+    )]
     fn with_expired_expiration() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = YOCTO;
-        ctx.epoch_height = 5;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER)
+            .with_deposit(YOCTO)
+            .epoch_height(5)
+            .apply();
         // Act
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 1;
-        ctx.epoch_height = 11;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER)
+            .with_deposit(1)
+            .epoch_height(11)
+            .apply();
         // Act
         ContractSaleComponent.ops_contract_lower_bid(
             100.into(),
@@ -1865,101 +1839,81 @@ mod tests_lower_contract_bid {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [ACCESS_DENIED_MUST_BE_BUYER]")]
+    #[should_panic(expected = r#"{\"code\":\"ACCESS_DENIED_MUST_BE_BUYER\""#)]
     fn not_buyer() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.predecessor_account_id = "OTHER".to_string();
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.predecessor("OTHER").with_deposit(1).apply();
         // Act
         ContractSaleComponent.ops_contract_lower_bid(YOCTO.into(), None);
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn zero_deposit() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 0;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(0).apply();
         // Act
         ContractSaleComponent.ops_contract_lower_bid(YOCTO.into(), None);
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn two_deposit() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 2;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(2).apply();
         // Act
         ContractSaleComponent.ops_contract_lower_bid(YOCTO.into(), None);
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [BAD_REQUEST] bid cannot be zero")]
+    #[should_panic(expected = r#"{\"code\":\"BAD_REQUEST\",\"message\":\"bid cannot be zero"#)]
     fn lower_to_zero() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(1).apply();
         // Act
         ContractSaleComponent.ops_contract_lower_bid((10 * YOCTO).into(), None);
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [BAD_REQUEST] bid cannot be zero")]
+    #[should_panic(expected = r#"{\"code\":\"BAD_REQUEST\",\"message\":\"bid cannot be zero"#)]
     fn overflow() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(1).apply();
         // Act
         ContractSaleComponent.ops_contract_lower_bid((11 * YOCTO).into(), None);
     }
@@ -1979,18 +1933,14 @@ mod tests_contract_bid_expiration {
     #[test]
     fn update() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.attached_deposit = 1;
-        ctx.block_timestamp = 100;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).block_timestamp(100).apply();
         // Act
         let expiration = ExpirationSetting::Relative(ExpirationDuration::Seconds(60));
         ContractSaleComponent.ops_contract_update_bid_expiration(expiration);
@@ -2001,86 +1951,76 @@ mod tests_contract_bid_expiration {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn update_zero_deposit() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.attached_deposit = 0;
-        ctx.block_timestamp = 100;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(0).block_timestamp(100).apply();
         // Act
         let expiration = ExpirationSetting::Relative(ExpirationDuration::Seconds(60));
         ContractSaleComponent.ops_contract_update_bid_expiration(expiration);
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn update_two_deposit() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.attached_deposit = 2;
-        ctx.block_timestamp = 100;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(2).block_timestamp(100).apply();
         // Act
         let expiration = ExpirationSetting::Relative(ExpirationDuration::Seconds(60));
         ContractSaleComponent.ops_contract_update_bid_expiration(expiration);
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [ACCESS_DENIED_MUST_BE_BUYER]")]
+    #[should_panic(expected = r#"{\"code\":\"ACCESS_DENIED_MUST_BE_BUYER\""#)]
     fn update_not_buyer() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.predecessor_account_id = "OTHER".to_string();
-        ctx.attached_deposit = 1;
-        ctx.block_timestamp = 100;
-        testing_env!(ctx.clone());
+        ctx.predecessor("OTHER")
+            .with_deposit(1)
+            .block_timestamp(100)
+            .apply();
         // Act
         let expiration = ExpirationSetting::Relative(ExpirationDuration::Seconds(60));
         ContractSaleComponent.ops_contract_update_bid_expiration(expiration);
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [BAD_REQUEST] expiration cannot be set to expired")]
+    #[should_panic(
+        expected = r#"{\"code\":\"BAD_REQUEST\",\"message\":\"expiration cannot be set to expired"#
+    )]
     fn update_with_expired_setting() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         ContractSaleComponent.ops_contract_buy(None);
 
-        ctx.predecessor_account_id = "OTHER".to_string();
-        ctx.attached_deposit = 1;
-        ctx.block_timestamp = 100;
-        testing_env!(ctx.clone());
+        ctx.predecessor("OTHER")
+            .with_deposit(1)
+            .block_timestamp(100)
+            .apply();
         // Act
         let expiration = ExpirationSetting::Absolute(Expiration::Timestamp(60.into()));
         ContractSaleComponent.ops_contract_update_bid_expiration(expiration);
@@ -2089,19 +2029,15 @@ mod tests_contract_bid_expiration {
     #[test]
     fn clear() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         let expiration = ExpirationSetting::Relative(ExpirationDuration::Seconds(60));
         ContractSaleComponent.ops_contract_buy(Some(expiration));
 
-        ctx.attached_deposit = 1;
-        ctx.block_timestamp = 100;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(1).block_timestamp(100).apply();
         // Act
 
         ContractSaleComponent.ops_contract_clear_bid_expiration();
@@ -2112,65 +2048,55 @@ mod tests_contract_bid_expiration {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn clear_zero_deposit() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         let expiration = ExpirationSetting::Relative(ExpirationDuration::Seconds(60));
         ContractSaleComponent.ops_contract_buy(Some(expiration));
 
-        ctx.attached_deposit = 0;
-        ctx.block_timestamp = 100;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(0).block_timestamp(100).apply();
         // Act
         ContractSaleComponent.ops_contract_clear_bid_expiration();
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
     fn clear_two_deposit() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         let expiration = ExpirationSetting::Relative(ExpirationDuration::Seconds(60));
         ContractSaleComponent.ops_contract_buy(Some(expiration));
 
-        ctx.attached_deposit = 2;
-        ctx.block_timestamp = 100;
-        testing_env!(ctx.clone());
+        ctx.with_deposit(2).block_timestamp(100).apply();
         // Act
         ContractSaleComponent.ops_contract_clear_bid_expiration();
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [ACCESS_DENIED_MUST_BE_BUYER]")]
+    #[should_panic(expected = r#"{\"code\":\"ACCESS_DENIED_MUST_BE_BUYER\""#)]
     fn clear_not_buyer() {
         // Arrange
-        let mut ctx = new_context(OWNER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
         ContractOwnershipComponent::deploy(to_valid_account_id(OWNER));
 
-        ctx.predecessor_account_id = BUYER.to_string();
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx.clone());
+        ctx.predecessor(BUYER).with_deposit(10 * YOCTO).apply();
         let expiration = ExpirationSetting::Relative(ExpirationDuration::Seconds(60));
         ContractSaleComponent.ops_contract_buy(Some(expiration));
 
-        ctx.predecessor_account_id = "BUYER2".to_string();
-        ctx.attached_deposit = 1;
-        ctx.block_timestamp = 100;
-        testing_env!(ctx.clone());
+        ctx.predecessor("BUYER2")
+            .with_deposit(1)
+            .block_timestamp(100)
+            .apply();
         // Act
         ContractSaleComponent.ops_contract_clear_bid_expiration();
     }
@@ -1,11 +1,31 @@
+pub use balance_alerts::*;
 pub use contract_bid::*;
+pub use contract_config::*;
 pub use contract_near_balances::*;
 pub use contract_owner::*;
 pub use contract_storage_usage::*;
 pub use contract_storage_usage_costs::*;
+pub use job_cursor::*;
+pub use job_id::*;
+pub use metrics_history::*;
+pub use owner_auto_sweep::*;
+pub use owner_delegate::*;
+pub use owner_guardians::*;
+pub use owner_vesting::*;
+pub use storage_balance_locks::*;
 
+mod balance_alerts;
 mod contract_bid;
+mod contract_config;
 mod contract_near_balances;
 mod contract_owner;
 mod contract_storage_usage;
 mod contract_storage_usage_costs;
+mod job_cursor;
+mod job_id;
+mod metrics_history;
+mod owner_auto_sweep;
+mod owner_delegate;
+mod owner_guardians;
+mod owner_vesting;
+mod storage_balance_locks;
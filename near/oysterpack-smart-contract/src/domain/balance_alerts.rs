@@ -0,0 +1,88 @@
+use crate::components::contract_ownership::ContractOwnershipComponent;
+use crate::ContractOwnership;
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::YoctoNear;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+};
+use oysterpack_smart_near::{Level, LogEvent};
+use std::ops::Deref;
+
+/// Operator configured contract NEAR balance thresholds
+/// - when a state changing method detects that a balance has dropped below its threshold, then
+///   [`LOG_EVENT_ALERT_LOW_BALANCE`] is logged, which enables the contract balances to be monitored
+///   via the logs
+#[derive(
+    BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct BalanceAlerts {
+    /// threshold for the contract owner's available balance - see [`ContractOwnership::ops_owner_balance`]
+    pub available_balance: Option<YoctoNear>,
+    /// threshold for the contract's liquid balance that is not required to pay for contract storage,
+    /// i.e., the balance that is available to pay for gas and transfers
+    pub gas_reserve: Option<YoctoNear>,
+}
+
+/// log message format: `balance={available_balance|gas_reserve}, amount={amount}, threshold={threshold}`
+pub const LOG_EVENT_ALERT_LOW_BALANCE: LogEvent = LogEvent(Level::WARN, "ALERT_LOW_BALANCE");
+
+const BALANCE_ALERTS_KEY: u128 = 1957962870419652837011472993054071183;
+
+type BalanceAlertsObject = Object<u128, BalanceAlerts>;
+
+impl BalanceAlerts {
+    pub fn load() -> Option<BalanceAlerts> {
+        BalanceAlertsObject::load(&BALANCE_ALERTS_KEY).map(|object| *object.deref())
+    }
+
+    pub fn save(&self) {
+        BalanceAlertsObject::new(BALANCE_ALERTS_KEY, *self).save();
+    }
+
+    pub fn clear() {
+        BalanceAlertsObject::delete_by_key(&BALANCE_ALERTS_KEY);
+    }
+
+    /// returns the contract's liquid balance that is not required to pay for contract storage
+    pub fn gas_reserve_balance() -> YoctoNear {
+        env::account_balance()
+            .saturating_sub(env::storage_usage() as u128 * env::storage_byte_cost())
+            .into()
+    }
+
+    /// Checks the contract balances against the configured thresholds and logs [`LOG_EVENT_ALERT_LOW_BALANCE`]
+    /// for each balance that is below its threshold.
+    /// - invoked whenever [`ContractNearBalances`](crate::ContractNearBalances) are updated
+    ///
+    /// Returns true if any alerts were logged
+    pub fn check() -> bool {
+        let alerts = match Self::load() {
+            Some(alerts) => alerts,
+            None => return false,
+        };
+
+        let mut alerted = false;
+        if let Some(threshold) = alerts.available_balance {
+            let amount = ContractOwnershipComponent.ops_owner_balance().available;
+            alerted |= Self::alert("available_balance", amount, threshold);
+        }
+        if let Some(threshold) = alerts.gas_reserve {
+            alerted |= Self::alert("gas_reserve", Self::gas_reserve_balance(), threshold);
+        }
+        alerted
+    }
+
+    fn alert(balance: &str, amount: YoctoNear, threshold: YoctoNear) -> bool {
+        if amount >= threshold {
+            return false;
+        }
+        LOG_EVENT_ALERT_LOW_BALANCE.log(format!(
+            "balance={}, amount={}, threshold={}",
+            balance, amount, threshold
+        ));
+        true
+    }
+}
@@ -0,0 +1,87 @@
+use oysterpack_smart_near::asserts::ERR_INVALID;
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+use oysterpack_smart_near::{Level, LogEvent};
+use std::collections::BTreeMap;
+
+/// Operator managed key/value contract config, e.g., website, logo URL, brand colors, support contact
+/// - enables generic front-ends to render branded contract pages directly from chain state
+/// - the config is bounded by the number of entries and the key and value lengths, which keeps the
+///   contract storage usage small
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq, Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct ContractConfig(pub BTreeMap<String, String>);
+
+/// log message format: `key={key}, value={value}` or `key={key}, removed`
+pub const LOG_EVENT_CONTRACT_CONFIG: LogEvent = LogEvent(Level::INFO, "CONTRACT_CONFIG");
+
+const CONTRACT_CONFIG_KEY: u128 = 1958315309746181208915622770131858411;
+
+type ContractConfigObject = Object<u128, ContractConfig>;
+
+impl ContractConfig {
+    pub const MAX_ENTRIES: usize = 20;
+    /// max key length in bytes
+    pub const MAX_KEY_LEN: usize = 32;
+    /// max value length in bytes
+    pub const MAX_VALUE_LEN: usize = 256;
+
+    pub fn load() -> Self {
+        ContractConfigObject::load(&CONTRACT_CONFIG_KEY)
+            .map_or_else(Self::default, |config| (*config).clone())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&String> {
+        self.0.get(key)
+    }
+
+    /// Sets the config entry - if the value is None, then the entry is removed.
+    ///
+    /// Returns the updated config
+    ///
+    /// ## Panics
+    /// - if the key is blank or is longer than [`ContractConfig::MAX_KEY_LEN`] bytes
+    /// - if the value is blank or is longer than [`ContractConfig::MAX_VALUE_LEN`] bytes
+    /// - if the number of entries would exceed [`ContractConfig::MAX_ENTRIES`]
+    pub(crate) fn set(key: String, value: Option<String>) -> Self {
+        ERR_INVALID.assert(|| !key.trim().is_empty(), || "key cannot be blank");
+        ERR_INVALID.assert(
+            || key.len() <= Self::MAX_KEY_LEN,
+            || format!("key max length is {} bytes", Self::MAX_KEY_LEN),
+        );
+
+        let mut config = Self::load();
+        match value {
+            Some(value) => {
+                ERR_INVALID.assert(|| !value.trim().is_empty(), || "value cannot be blank");
+                ERR_INVALID.assert(
+                    || value.len() <= Self::MAX_VALUE_LEN,
+                    || format!("value max length is {} bytes", Self::MAX_VALUE_LEN),
+                );
+                ERR_INVALID.assert(
+                    || config.0.contains_key(&key) || config.0.len() < Self::MAX_ENTRIES,
+                    || format!("max number of entries is {}", Self::MAX_ENTRIES),
+                );
+                LOG_EVENT_CONTRACT_CONFIG.log(format!("key={}, value={}", key, value));
+                config.0.insert(key, value);
+            }
+            None => {
+                if config.0.remove(&key).is_some() {
+                    LOG_EVENT_CONTRACT_CONFIG.log(format!("key={}, removed", key));
+                }
+            }
+        }
+
+        if config.0.is_empty() {
+            ContractConfigObject::delete_by_key(&CONTRACT_CONFIG_KEY);
+        } else {
+            ContractConfigObject::new(CONTRACT_CONFIG_KEY, config.clone()).save();
+        }
+        config
+    }
+}
@@ -1,3 +1,4 @@
+use crate::BalanceAlerts;
 use oysterpack_smart_near::near_sdk::{
     borsh::{self, BorshDeserialize, BorshSerialize},
     env,
@@ -119,7 +120,7 @@ impl ContractNearBalances {
         let mut balance = balances.get(&id).cloned().unwrap_or(YoctoNear::ZERO);
         balance += amount;
         balances.insert(id, balance);
-        balances.save();
+        Self::save_near_balances(balances);
         balance
     }
 
@@ -134,7 +135,7 @@ impl ContractNearBalances {
         } else {
             balances.insert(id, balance);
         }
-        balances.save();
+        Self::save_near_balances(balances);
         balance
     }
 
@@ -147,7 +148,7 @@ impl ContractNearBalances {
         } else {
             balances.insert(id, amount);
         }
-        balances.save();
+        Self::save_near_balances(balances);
     }
 
     /// Clears the balance and removes the record from storage
@@ -155,14 +156,22 @@ impl ContractNearBalances {
         let mut balances = DAO::load(&NEAR_BALANCES_KEY)
             .unwrap_or_else(|| DAO::new(NEAR_BALANCES_KEY, NearBalances::new()));
         balances.remove(&id);
+        Self::save_near_balances(balances);
+    }
+
+    /// all balance updates are saved through this shared path, which checks the updated balances
+    /// against the operator configured [`BalanceAlerts`]
+    fn save_near_balances(balances: DAO) {
         balances.save();
+        BalanceAlerts::check();
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use oysterpack_smart_near::near_sdk::test_utils::test_env;
+    use crate::LOG_EVENT_ALERT_LOW_BALANCE;
+    use oysterpack_smart_near::near_sdk::test_utils::{self, test_env};
     use oysterpack_smart_near::YOCTO;
 
     const LIQUIDITY_BALANCE_ID: BalanceId = BalanceId(0);
@@ -235,4 +244,31 @@ mod tests {
             20 * YOCTO
         );
     }
+
+    #[test]
+    fn balance_updates_check_balance_alerts() {
+        // Arrange
+        test_env::setup();
+        BalanceAlerts {
+            available_balance: None,
+            gas_reserve: Some(u128::MAX.into()),
+        }
+        .save();
+
+        let alerts_logged = || {
+            test_utils::get_logs()
+                .iter()
+                .filter(|log| log.contains(LOG_EVENT_ALERT_LOW_BALANCE.1))
+                .count()
+        };
+
+        // Act
+        ContractNearBalances::incr_balance(LIQUIDITY_BALANCE_ID, YOCTO.into());
+        ContractNearBalances::decr_balance(LIQUIDITY_BALANCE_ID, YOCTO.into());
+        ContractNearBalances::set_balance(LIQUIDITY_BALANCE_ID, YOCTO.into());
+        ContractNearBalances::clear_balance(LIQUIDITY_BALANCE_ID);
+
+        // Assert
+        assert_eq!(alerts_logged(), 4);
+    }
 }
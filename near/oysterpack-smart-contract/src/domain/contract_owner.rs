@@ -1,5 +1,5 @@
 use crate::{
-    ContractBid, ERR_CONTRACT_OWNER_TRANSFER_NOT_INITIATED,
+    ContractBid, OwnerDelegate, ERR_CONTRACT_OWNER_TRANSFER_NOT_INITIATED,
     ERR_CURRENT_OR_PROSPECTIVE_OWNER_ACCESS_REQUIRED, ERR_OWNER_ACCESS_REQUIRED,
     ERR_PROSPECTIVE_OWNER_ACCESS_REQUIRED,
 };
@@ -55,8 +55,24 @@ impl ContractOwnerObject {
         }
     }
 
-    /// asserts that the predecessor account ID is the owner
+    /// asserts that the predecessor account ID is the owner or the owner delegate - see [`OwnerDelegate`]
     pub fn assert_owner_access() -> Self {
+        let owner = Self::load();
+        ERR_OWNER_ACCESS_REQUIRED.assert(|| {
+            owner.account_id_hash() == AccountIdHash::from(env::predecessor_account_id())
+                || OwnerDelegate::is_delegate()
+        });
+        owner
+    }
+
+    /// returns true if the predecessor account is the owner or the owner delegate - see [`OwnerDelegate`]
+    pub fn has_owner_access() -> bool {
+        Self::is_owner() || OwnerDelegate::is_delegate()
+    }
+
+    /// asserts that the predecessor account ID is the owner, i.e., the owner delegate is denied access
+    /// - used to guard contract ownership transfers and owner delegate management
+    pub fn assert_owner_account_access() -> Self {
         let owner = Self::load();
         ERR_OWNER_ACCESS_REQUIRED.assert(|| {
             owner.account_id_hash() == AccountIdHash::from(env::predecessor_account_id())
@@ -203,9 +219,9 @@ mod tests {
     #[test]
     fn is_not_owner() {
         let account = "bob";
-        let ctx = new_context(account);
+        let mut ctx = TestCtx::new(account);
 
-        testing_env!(ctx.clone());
+        ctx.apply();
         ContractOwnerObject::initialize_contract(to_valid_account_id("alice"));
         assert!(!ContractOwnerObject::is_owner());
     }
@@ -213,9 +229,9 @@ mod tests {
     #[test]
     fn is_owner() {
         let account = "bob";
-        let ctx = new_context(account);
+        let mut ctx = TestCtx::new(account);
 
-        testing_env!(ctx.clone());
+        ctx.apply();
         ContractOwnerObject::initialize_contract(to_valid_account_id(account));
         assert!(ContractOwnerObject::is_owner());
     }
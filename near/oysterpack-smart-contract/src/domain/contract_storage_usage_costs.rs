@@ -1,5 +1,6 @@
 use crate::ContractStorageUsage;
-use oysterpack_smart_near::domain::YoctoNear;
+use oysterpack_smart_near::data::ComponentStorageUsage;
+use oysterpack_smart_near::domain::{StorageUsage, YoctoNear};
 use oysterpack_smart_near::near_sdk::{
     borsh::{self, BorshDeserialize, BorshSerialize},
     env,
@@ -52,3 +53,65 @@ impl From<ContractStorageUsage> for ContractStorageUsageCosts {
         }
     }
 }
+
+/// Storage usage and its staking cost
+#[derive(
+    BorshSerialize, BorshDeserialize, Deserialize, Serialize, Copy, Clone, Debug, PartialEq, Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StorageUsageCost {
+    pub storage_usage: StorageUsage,
+    pub cost: YoctoNear,
+}
+
+impl From<StorageUsage> for StorageUsageCost {
+    fn from(storage_usage: StorageUsage) -> Self {
+        Self {
+            storage_usage,
+            cost: storage_usage.cost(),
+        }
+    }
+}
+
+/// Breaks down contract storage usage costs by component
+/// - computed from the maintained component storage usage counters, i.e., contract storage is not
+///   scanned
+#[derive(
+    BorshSerialize, BorshDeserialize, Deserialize, Serialize, Copy, Clone, Debug, PartialEq, Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct ContractStorageUsageBreakdown {
+    pub total: StorageUsageCost,
+    /// storage usage that is owned by all accounts registered with the contract, which includes the
+    /// FT balances
+    pub accounts: StorageUsageCost,
+    /// portion of the account storage usage that is used by FT balances
+    pub ft_balances: StorageUsageCost,
+    /// contract owned storage usage for staking pool history and queue records
+    pub staking: StorageUsageCost,
+    /// contract owned storage usage for the metrics history
+    pub metrics: StorageUsageCost,
+    /// remaining contract owned storage usage, e.g., contract code and configuration
+    pub other: StorageUsageCost,
+}
+
+impl ContractStorageUsageBreakdown {
+    pub fn new(accounts: StorageUsage) -> Self {
+        let total = StorageUsage::from_env();
+        let staking = ComponentStorageUsage::load(ComponentStorageUsage::STAKING);
+        let metrics = ComponentStorageUsage::load(ComponentStorageUsage::METRICS);
+        let other = total
+            .value()
+            .saturating_sub(accounts.value())
+            .saturating_sub(staking.value())
+            .saturating_sub(metrics.value());
+        Self {
+            total: total.into(),
+            accounts: accounts.into(),
+            ft_balances: ComponentStorageUsage::load(ComponentStorageUsage::FT_BALANCES).into(),
+            staking: staking.into(),
+            metrics: metrics.into(),
+            other: StorageUsage(other).into(),
+        }
+    }
+}
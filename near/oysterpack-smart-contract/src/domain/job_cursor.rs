@@ -0,0 +1,203 @@
+use crate::{JobId, ERR_JOB_NOT_STARTED};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+use oysterpack_smart_near::{
+    data::{numbers::U64, Object},
+    domain::BlockTime,
+};
+use std::{collections::HashMap, ops::Deref};
+
+pub type JobCursors = HashMap<JobId, JobCursor>;
+
+/// Tracks batch job progress, which enables long-running maintenance jobs to pick up where they
+/// left off in the next transaction
+#[derive(
+    BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Copy, Debug, PartialEq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct JobCursor {
+    /// next position to process, e.g., index into a collection
+    pub position: U64,
+    /// total number of items that have been processed
+    pub processed: U64,
+    pub started_on: BlockTime,
+    pub updated_on: BlockTime,
+    /// set when the job has run to completion
+    pub completed_on: Option<BlockTime>,
+}
+
+impl JobCursor {
+    fn new() -> Self {
+        let now = BlockTime::from_env();
+        Self {
+            position: 0.into(),
+            processed: 0.into(),
+            started_on: now,
+            updated_on: now,
+            completed_on: None,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.completed_on.is_some()
+    }
+}
+
+const JOB_CURSORS_KEY: u128 = 1957408418553637829036436766893826093;
+
+type JobCursorsObject = Object<u128, JobCursors>;
+
+impl JobCursor {
+    pub fn load_job_cursors() -> JobCursors {
+        JobCursorsObject::load(&JOB_CURSORS_KEY)
+            .map_or_else(JobCursors::new, |object| object.deref().clone())
+    }
+
+    pub fn load(id: JobId) -> Option<JobCursor> {
+        JobCursorsObject::load(&JOB_CURSORS_KEY).and_then(|object| object.get(&id).cloned())
+    }
+
+    /// Returns the job's current cursor, or starts the job if no cursor exists
+    /// - if the job previously ran to completion, then the job is restarted
+    pub fn resume(id: JobId) -> JobCursor {
+        match Self::load(id) {
+            Some(cursor) if !cursor.is_complete() => cursor,
+            _ => Self::start(id),
+        }
+    }
+
+    /// Starts the job from the beginning, replacing any existing cursor
+    pub fn start(id: JobId) -> JobCursor {
+        let cursor = JobCursor::new();
+        Self::save(id, cursor);
+        cursor
+    }
+
+    /// Moves the cursor forward to the specified position and records how many items were processed
+    ///
+    /// ## Panics
+    /// if the job has not been started
+    pub fn advance(id: JobId, position: u64, processed: u64) -> JobCursor {
+        let mut cursor = Self::load_started(id);
+        cursor.position = position.into();
+        cursor.processed = (*cursor.processed + processed).into();
+        cursor.updated_on = BlockTime::from_env();
+        Self::save(id, cursor);
+        cursor
+    }
+
+    /// Marks the job as completed
+    ///
+    /// ## Panics
+    /// if the job has not been started
+    pub fn complete(id: JobId) -> JobCursor {
+        let mut cursor = Self::load_started(id);
+        let now = BlockTime::from_env();
+        cursor.updated_on = now;
+        cursor.completed_on = Some(now);
+        Self::save(id, cursor);
+        cursor
+    }
+
+    /// Clears the job cursor and removes the record from storage
+    pub fn clear(id: JobId) {
+        if let Some(mut cursors) = JobCursorsObject::load(&JOB_CURSORS_KEY) {
+            if cursors.remove(&id).is_some() {
+                if cursors.is_empty() {
+                    cursors.delete();
+                } else {
+                    cursors.save();
+                }
+            }
+        }
+    }
+
+    fn load_started(id: JobId) -> JobCursor {
+        match Self::load(id) {
+            Some(cursor) => cursor,
+            None => {
+                ERR_JOB_NOT_STARTED.panic();
+                unreachable!()
+            }
+        }
+    }
+
+    fn save(id: JobId, cursor: JobCursor) {
+        let mut cursors = JobCursorsObject::load(&JOB_CURSORS_KEY)
+            .unwrap_or_else(|| JobCursorsObject::new(JOB_CURSORS_KEY, JobCursors::new()));
+        cursors.insert(id, cursor);
+        cursors.save();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near::data::numbers::U128;
+    use oysterpack_smart_near::near_sdk::serde_json;
+    use oysterpack_smart_near_test::*;
+
+    const GC_JOB: JobId = JobId(U128(0));
+    const SWEEP_JOB: JobId = JobId(U128(1));
+
+    #[test]
+    fn job_cursor_lifecycle() {
+        // Arrange
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+        assert!(JobCursor::load_job_cursors().is_empty());
+        assert!(JobCursor::load(GC_JOB).is_none());
+
+        // Act - start jobs
+        let cursor = JobCursor::resume(GC_JOB);
+        JobCursor::resume(SWEEP_JOB);
+
+        // Assert
+        assert_eq!(*cursor.position, 0);
+        assert_eq!(*cursor.processed, 0);
+        assert!(!cursor.is_complete());
+        assert_eq!(JobCursor::load_job_cursors().len(), 2);
+
+        // Act - advance the job in the next block
+        ctx.block_index(1).apply();
+        JobCursor::advance(GC_JOB, 10, 10);
+        let cursor = JobCursor::advance(GC_JOB, 20, 8);
+
+        // Assert
+        assert_eq!(*cursor.position, 20);
+        assert_eq!(*cursor.processed, 18);
+        assert_eq!(cursor.updated_on.height.value(), 1);
+        assert_eq!(JobCursor::resume(GC_JOB), cursor);
+
+        // Assert - cursors are keyed by the job ID string in JSON
+        let json = serde_json::to_value(JobCursor::load_job_cursors()).unwrap();
+        assert_eq!(json["0"]["position"], "20");
+        assert_eq!(json["1"]["processed"], "0");
+
+        // Act - complete the job
+        let cursor = JobCursor::complete(GC_JOB);
+        assert!(cursor.is_complete());
+        assert_eq!(JobCursor::load(GC_JOB).unwrap(), cursor);
+
+        // Act - resuming a completed job restarts it
+        let cursor = JobCursor::resume(GC_JOB);
+        assert_eq!(*cursor.position, 0);
+        assert!(!cursor.is_complete());
+
+        // Act - clear the jobs
+        JobCursor::clear(GC_JOB);
+        assert!(JobCursor::load(GC_JOB).is_none());
+        assert_eq!(JobCursor::load_job_cursors().len(), 1);
+        JobCursor::clear(SWEEP_JOB);
+        assert!(JobCursor::load_job_cursors().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"JOB_NOT_STARTED\""#)]
+    fn advance_job_not_started() {
+        TestCtx::new("bob").apply();
+        JobCursor::advance(GC_JOB, 1, 1);
+    }
+}
@@ -0,0 +1,46 @@
+use oysterpack_smart_near::data::numbers::U128;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+/// Job ID is used to identify long-running batch jobs that need to be chunked across multiple
+/// transactions
+/// - use ULID to generate unique IDs to avoid collisions between components
+/// - serialized to JSON as a string because JavaScript numbers cannot represent u128 values
+#[derive(
+    BorshSerialize,
+    BorshDeserialize,
+    Deserialize,
+    Serialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialOrd,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct JobId(pub U128);
+
+impl From<u128> for JobId {
+    fn from(id: u128) -> Self {
+        Self(id.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near::near_sdk::serde_json;
+
+    #[test]
+    fn json_serde() {
+        let job_id: JobId = 1957408418553637829036436766893826093.into();
+        let json = serde_json::to_string(&job_id).unwrap();
+        assert_eq!(json, r#""1957408418553637829036436766893826093""#);
+        assert_eq!(serde_json::from_str::<JobId>(&json).unwrap(), job_id);
+    }
+}
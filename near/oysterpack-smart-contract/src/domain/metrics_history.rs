@@ -0,0 +1,212 @@
+use crate::components::contract_metrics::ContractMetricsComponent;
+use crate::{ContractMetrics, ContractNearBalances};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+use oysterpack_smart_near::{
+    data::{
+        numbers::{U128, U64},
+        ComponentStorageUsage, Object,
+    },
+    domain::{BlockHeight, BlockTime},
+};
+use std::collections::HashMap;
+
+/// Counter ID is used to track component specific metrics in the metrics history, e.g., token supply
+/// - use ULID to generate unique IDs to avoid collisions between components
+/// - serialized to JSON as a string because JavaScript numbers cannot represent u128 values
+#[derive(
+    BorshSerialize,
+    BorshDeserialize,
+    Deserialize,
+    Serialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialOrd,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct CounterId(pub U128);
+
+impl From<u128> for CounterId {
+    fn from(id: u128) -> Self {
+        Self(id.into())
+    }
+}
+
+pub type Counters = HashMap<CounterId, U128>;
+
+/// Configures how often metrics are sampled and how many samples are retained
+#[derive(
+    BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct MetricsHistoryConfig {
+    /// minimum number of blocks between samples
+    pub block_interval: U64,
+    /// max number of samples that are retained - once full, the oldest sample is overwritten
+    pub max_samples: u16,
+}
+
+/// Block height stamped metrics sample
+#[derive(BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct MetricsSample {
+    pub block_time: BlockTime,
+    pub total_registered_accounts: U128,
+    pub near_balances: ContractNearBalances,
+    /// component specific counters
+    pub counters: Option<Counters>,
+}
+
+/// tracks the ring buffer state
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+struct MetricsHistoryState {
+    config: MetricsHistoryConfig,
+    /// ring buffer index where the next sample will be stored
+    next_index: u16,
+    /// number of samples that are stored
+    len: u16,
+    last_sample_block_height: Option<BlockHeight>,
+}
+
+const METRICS_HISTORY_STATE_KEY: u128 = 1957570348310474282367916201389437612;
+/// the sample storage key is computed as `METRICS_SAMPLES_KEY + index`
+const METRICS_SAMPLES_KEY: u128 = 1957570408117389236610946593120890880;
+
+type MetricsHistoryStateObject = Object<u128, MetricsHistoryState>;
+type MetricsSampleObject = Object<u128, MetricsSample>;
+
+/// Bounded metrics time series, which is stored as a ring buffer
+/// - metrics are sampled by components via [`MetricsHistory::record`], which records a sample
+///   only if the history is enabled and the configured block interval has elapsed since the last sample
+pub struct MetricsHistory;
+
+impl MetricsHistory {
+    /// max number of samples that can be retained
+    pub const MAX_SAMPLES: u16 = 500;
+
+    /// returns None if metrics history is disabled
+    pub fn config() -> Option<MetricsHistoryConfig> {
+        Self::state().map(|state| state.config)
+    }
+
+    /// Any existing history is cleared when the config is changed
+    /// - setting the config to None disables the metrics history
+    pub fn set_config(config: Option<MetricsHistoryConfig>) {
+        ComponentStorageUsage::track(ComponentStorageUsage::METRICS, || {
+            if let Some(state) = Self::state() {
+                for i in 0..state.len {
+                    MetricsSampleObject::delete_by_key(&Self::sample_key(i));
+                }
+                MetricsHistoryStateObject::delete_by_key(&METRICS_HISTORY_STATE_KEY);
+            }
+            if let Some(config) = config {
+                MetricsHistoryStateObject::new(
+                    METRICS_HISTORY_STATE_KEY,
+                    MetricsHistoryState {
+                        config,
+                        next_index: 0,
+                        len: 0,
+                        last_sample_block_height: None,
+                    },
+                )
+                .save();
+            }
+        });
+    }
+
+    /// returns true if metrics history is enabled and the block interval has elapsed since the last sample
+    pub fn is_sample_due() -> bool {
+        matches!(Self::state(), Some(state) if Self::sample_due(&state))
+    }
+
+    /// Records a metrics sample if a sample is due.
+    ///
+    /// Returns true if a sample was recorded
+    pub fn record(counters: Option<Counters>) -> bool {
+        let mut state = match Self::state() {
+            Some(state) if Self::sample_due(&state) => state,
+            _ => return false,
+        };
+
+        let metrics = ContractMetricsComponent;
+        let sample = MetricsSample {
+            block_time: BlockTime::from_env(),
+            total_registered_accounts: metrics.ops_metrics_total_registered_accounts(),
+            near_balances: metrics.ops_metrics_near_balances(),
+            counters,
+        };
+        ComponentStorageUsage::track(ComponentStorageUsage::METRICS, || {
+            MetricsSampleObject::new(Self::sample_key(state.next_index), sample).save();
+
+            state.next_index = (state.next_index + 1) % state.config.max_samples;
+            state.len = state.config.max_samples.min(state.len + 1);
+            state.last_sample_block_height = Some(BlockHeight::from_env());
+            MetricsHistoryStateObject::new(METRICS_HISTORY_STATE_KEY, state).save();
+        });
+        true
+    }
+
+    /// returns the most recent samples ordered from oldest to newest
+    pub fn samples(limit: u16) -> Vec<MetricsSample> {
+        let state = match Self::state() {
+            Some(state) => state,
+            None => return vec![],
+        };
+        let count = limit.min(state.len);
+        let max_samples = state.config.max_samples;
+        (0..count)
+            .rev()
+            .filter_map(|offset| {
+                let index = (state.next_index + max_samples - 1 - offset) % max_samples;
+                MetricsSampleObject::load(&Self::sample_key(index)).map(|sample| (*sample).clone())
+            })
+            .collect()
+    }
+
+    fn state() -> Option<MetricsHistoryState> {
+        MetricsHistoryStateObject::load(&METRICS_HISTORY_STATE_KEY).map(|state| *state)
+    }
+
+    fn sample_due(state: &MetricsHistoryState) -> bool {
+        match state.last_sample_block_height {
+            None => true,
+            Some(block_height) => {
+                BlockHeight::from_env() >= block_height + *state.config.block_interval
+            }
+        }
+    }
+
+    fn sample_key(index: u16) -> u128 {
+        METRICS_SAMPLES_KEY + index as u128
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near::near_sdk::serde_json;
+
+    #[test]
+    fn counter_id_json_serde() {
+        let counter_id: CounterId = 1957573071389163207429381738316150931.into();
+        let json = serde_json::to_string(&counter_id).unwrap();
+        assert_eq!(json, r#""1957573071389163207429381738316150931""#);
+        assert_eq!(
+            serde_json::from_str::<CounterId>(&json).unwrap(),
+            counter_id
+        );
+
+        let mut counters = Counters::new();
+        counters.insert(counter_id, 100.into());
+        let json = serde_json::to_string(&counters).unwrap();
+        assert_eq!(json, r#"{"1957573071389163207429381738316150931":"100"}"#);
+        assert_eq!(serde_json::from_str::<Counters>(&json).unwrap(), counters);
+    }
+}
@@ -0,0 +1,44 @@
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::YoctoNear;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+use std::ops::Deref;
+
+/// Owner balance auto-sweep policy
+/// - the contract owner's available balance in excess of the threshold is credited to the owner's
+///   account storage balance, where it can be used like any other account's funds, e.g., staked
+/// - the policy is cleared when contract ownership changes hands
+#[derive(
+    BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct OwnerAutoSweep {
+    /// owner available balance that is retained on the contract
+    pub threshold: YoctoNear,
+}
+
+const OWNER_AUTO_SWEEP_KEY: u128 = 1957497263013471185283650124802531339;
+
+type OwnerAutoSweepObject = Object<u128, OwnerAutoSweep>;
+
+impl OwnerAutoSweep {
+    pub fn load() -> Option<OwnerAutoSweep> {
+        OwnerAutoSweepObject::load(&OWNER_AUTO_SWEEP_KEY).map(|object| *object.deref())
+    }
+
+    pub fn save(&self) {
+        OwnerAutoSweepObject::new(OWNER_AUTO_SWEEP_KEY, *self).save();
+    }
+
+    /// returns true if the auto-sweep policy was cleared
+    pub fn clear() -> bool {
+        OwnerAutoSweepObject::delete_by_key(&OWNER_AUTO_SWEEP_KEY)
+    }
+
+    /// returns the amount that is in excess of the threshold
+    pub fn excess(&self, available_balance: YoctoNear) -> YoctoNear {
+        available_balance.saturating_sub(*self.threshold).into()
+    }
+}
@@ -0,0 +1,33 @@
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::near_sdk::{env, AccountId};
+
+/// Account that the contract owner nominated to exercise owner-only methods on its behalf, which
+/// enables the owner key to be kept offline and provides a recovery path if the owner key is lost
+/// - the delegate cannot transfer or sell contract ownership, nor manage the delegate
+/// - the delegate is cleared when contract ownership changes hands, i.e., when it is transferred,
+///   recovered, or sold
+pub struct OwnerDelegate;
+
+const OWNER_DELEGATE_KEY: u128 = 1958150487364128873591044206314452839;
+
+type OwnerDelegateObject = Object<u128, AccountId>;
+
+impl OwnerDelegate {
+    pub fn load() -> Option<AccountId> {
+        OwnerDelegateObject::load(&OWNER_DELEGATE_KEY).map(|account_id| (*account_id).clone())
+    }
+
+    pub fn save(account_id: &str) {
+        OwnerDelegateObject::new(OWNER_DELEGATE_KEY, account_id.to_string()).save();
+    }
+
+    /// returns true if a delegate was cleared
+    pub fn clear() -> bool {
+        OwnerDelegateObject::delete_by_key(&OWNER_DELEGATE_KEY)
+    }
+
+    /// returns true if the predecessor account is the owner delegate
+    pub fn is_delegate() -> bool {
+        Self::load().is_some_and(|account_id| account_id == env::predecessor_account_id())
+    }
+}
@@ -0,0 +1,145 @@
+use oysterpack_smart_near::asserts::assert_request;
+use oysterpack_smart_near::data::{numbers::U64, Object};
+use oysterpack_smart_near::domain::BlockTimestamp;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use std::ops::Deref;
+
+/// Guardian accounts that can reassign contract ownership if the owner key is lost
+/// - once `quorum` guardians vote for the same new owner, the recovery timelock starts - the
+///   ownership can be reassigned once the `delay` has elapsed
+/// - the current owner can cancel the recovery at any time before it is finalized
+/// - the delay is specified in nanoseconds
+#[derive(BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct OwnerGuardians {
+    pub guardians: Vec<AccountId>,
+    /// number of guardian votes that are required to initiate the recovery
+    pub quorum: u8,
+    /// recovery timelock
+    pub delay: U64,
+}
+
+const OWNER_GUARDIANS_KEY: u128 = 1958802947316205839147206638510294617;
+
+type OwnerGuardiansObject = Object<u128, OwnerGuardians>;
+
+impl OwnerGuardians {
+    pub const MAX_GUARDIANS: usize = 10;
+
+    /// 1 day
+    pub const MIN_DELAY: u64 = 24 * 60 * 60 * 1_000_000_000;
+
+    /// ## Panics
+    /// - if there are no guardians or more than [`OwnerGuardians::MAX_GUARDIANS`]
+    /// - if the guardians contain duplicates or the owner account
+    /// - if the quorum is zero or more than the number of guardians
+    /// - if the delay is less than [`OwnerGuardians::MIN_DELAY`]
+    pub fn validate(&self, owner: &str) {
+        assert_request(
+            || !self.guardians.is_empty() && self.guardians.len() <= Self::MAX_GUARDIANS,
+            || format!("number of guardians must be 1-{}", Self::MAX_GUARDIANS),
+        );
+        assert_request(
+            || {
+                self.guardians.iter().enumerate().all(|(i, guardian)| {
+                    guardian.as_str() != owner && !self.guardians[i + 1..].contains(guardian)
+                })
+            },
+            || "guardians must be unique and cannot include the owner",
+        );
+        assert_request(
+            || self.quorum > 0 && self.quorum as usize <= self.guardians.len(),
+            || "quorum must be > 0 and cannot exceed the number of guardians",
+        );
+        assert_request(
+            || *self.delay >= Self::MIN_DELAY,
+            || format!("delay must be at least {} ns", Self::MIN_DELAY),
+        );
+    }
+
+    pub fn load() -> Option<OwnerGuardians> {
+        OwnerGuardiansObject::load(&OWNER_GUARDIANS_KEY).map(|object| object.deref().clone())
+    }
+
+    pub(crate) fn save(&self) {
+        OwnerGuardiansObject::new(OWNER_GUARDIANS_KEY, self.clone()).save();
+    }
+
+    /// returns true if the guardians were cleared
+    pub(crate) fn clear() -> bool {
+        OwnerGuardiansObject::delete_by_key(&OWNER_GUARDIANS_KEY)
+    }
+
+    /// returns true if the predecessor account is a guardian
+    pub fn is_guardian() -> bool {
+        Self::load().is_some_and(|config| config.guardians.contains(&env::predecessor_account_id()))
+    }
+}
+
+/// Guardian vote for the account that ownership should be reassigned to
+#[derive(BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct OwnerRecoveryVote {
+    pub guardian: AccountId,
+    pub new_owner: AccountId,
+}
+
+/// Owner recovery that is in progress - see [`OwnerGuardians`]
+#[derive(
+    BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Debug, PartialEq, Eq, Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct OwnerRecovery {
+    /// each guardian has at most 1 vote
+    pub votes: Vec<OwnerRecoveryVote>,
+    /// set once the quorum is reached
+    pub timelock: Option<OwnerRecoveryTimelock>,
+}
+
+/// Owner recovery that has reached quorum and is waiting on the timelock
+#[derive(BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct OwnerRecoveryTimelock {
+    pub new_owner: AccountId,
+    /// ownership can be reassigned starting at this block timestamp
+    pub eligible_at: BlockTimestamp,
+}
+
+const OWNER_RECOVERY_KEY: u128 = 1958802981640273194520836471103852749;
+
+type OwnerRecoveryObject = Object<u128, OwnerRecovery>;
+
+impl OwnerRecovery {
+    pub fn load() -> Option<OwnerRecovery> {
+        OwnerRecoveryObject::load(&OWNER_RECOVERY_KEY).map(|object| object.deref().clone())
+    }
+
+    pub(crate) fn save(&self) {
+        OwnerRecoveryObject::new(OWNER_RECOVERY_KEY, self.clone()).save();
+    }
+
+    /// returns true if a recovery was cleared
+    pub(crate) fn clear() -> bool {
+        OwnerRecoveryObject::delete_by_key(&OWNER_RECOVERY_KEY)
+    }
+
+    /// records the guardian's vote, replacing any previous vote by the guardian
+    ///
+    /// returns the number of votes for the new owner
+    pub(crate) fn vote(&mut self, guardian: &str, new_owner: &str) -> usize {
+        self.votes.retain(|vote| vote.guardian != guardian);
+        self.votes.push(OwnerRecoveryVote {
+            guardian: guardian.to_string(),
+            new_owner: new_owner.to_string(),
+        });
+        self.votes
+            .iter()
+            .filter(|vote| vote.new_owner == new_owner)
+            .count()
+    }
+}
@@ -0,0 +1,205 @@
+use oysterpack_smart_near::asserts::assert_request;
+use oysterpack_smart_near::data::{
+    numbers::{U256, U64},
+    Object,
+};
+use oysterpack_smart_near::domain::{BlockTimestamp, YoctoNear};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+use std::ops::Deref;
+
+/// Owner balance vesting terms that are configured when contract ownership is transferred, e.g.,
+/// to support pool acquisitions with earn-out terms
+/// - the vesting schedule starts when the transfer is finalized
+/// - durations are specified in nanoseconds
+#[derive(
+    BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct OwnerVestingTerms {
+    /// nothing can be withdrawn until the cliff duration has elapsed
+    pub cliff_duration: U64,
+    /// the owner balance vests linearly over the vesting duration
+    pub vesting_duration: U64,
+}
+
+const OWNER_VESTING_TERMS_KEY: u128 = 1958471925634076013954882712051866209;
+
+type OwnerVestingTermsObject = Object<u128, OwnerVestingTerms>;
+
+impl OwnerVestingTerms {
+    /// ## Panics
+    /// - if the vesting duration is zero
+    /// - if the cliff duration is greater than the vesting duration
+    pub fn validate(&self) {
+        assert_request(
+            || *self.vesting_duration > 0,
+            || "vesting duration must be > 0",
+        );
+        assert_request(
+            || self.cliff_duration <= self.vesting_duration,
+            || "cliff duration cannot be greater than the vesting duration",
+        );
+    }
+
+    /// returns the vesting terms for the pending ownership transfer
+    pub fn load_pending() -> Option<OwnerVestingTerms> {
+        OwnerVestingTermsObject::load(&OWNER_VESTING_TERMS_KEY).map(|object| *object.deref())
+    }
+
+    pub(crate) fn save_pending(&self) {
+        OwnerVestingTermsObject::new(OWNER_VESTING_TERMS_KEY, *self).save();
+    }
+
+    /// returns true if pending terms were cleared
+    pub(crate) fn clear_pending() -> bool {
+        OwnerVestingTermsObject::delete_by_key(&OWNER_VESTING_TERMS_KEY)
+    }
+
+    /// creates the vesting schedule starting at the specified timestamp
+    pub fn schedule(&self, start: BlockTimestamp) -> OwnerVestingSchedule {
+        OwnerVestingSchedule {
+            start,
+            cliff: start + *self.cliff_duration,
+            end: start + *self.vesting_duration,
+            withdrawn: YoctoNear::ZERO,
+        }
+    }
+}
+
+/// Owner balance vesting schedule
+/// - nothing vests until the `cliff` is reached
+/// - once the cliff is reached, the owner balance vests linearly from `start` until `end`
+/// - the vested amount is based on the owner balance, which includes what has already been withdrawn
+///
+/// The schedule is cleared once the owner balance is fully vested.
+#[derive(
+    BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct OwnerVestingSchedule {
+    pub start: BlockTimestamp,
+    pub cliff: BlockTimestamp,
+    pub end: BlockTimestamp,
+    /// total amount that has been withdrawn since the schedule started
+    pub withdrawn: YoctoNear,
+}
+
+const OWNER_VESTING_SCHEDULE_KEY: u128 = 1958471963383536547180402394367063129;
+
+type OwnerVestingScheduleObject = Object<u128, OwnerVestingSchedule>;
+
+impl OwnerVestingSchedule {
+    pub fn load() -> Option<OwnerVestingSchedule> {
+        OwnerVestingScheduleObject::load(&OWNER_VESTING_SCHEDULE_KEY).map(|object| *object.deref())
+    }
+
+    pub(crate) fn save(&self) {
+        OwnerVestingScheduleObject::new(OWNER_VESTING_SCHEDULE_KEY, *self).save();
+    }
+
+    /// returns true if a vesting schedule was cleared
+    pub(crate) fn clear() -> bool {
+        OwnerVestingScheduleObject::delete_by_key(&OWNER_VESTING_SCHEDULE_KEY)
+    }
+
+    /// returns true if a vesting schedule is configured that is not yet fully vested
+    pub fn is_vesting() -> bool {
+        Self::load().is_some_and(|schedule| !schedule.fully_vested(BlockTimestamp::from_env()))
+    }
+
+    /// returns the amount of the owner's available balance that can be withdrawn based on the
+    /// current block timestamp
+    /// - if no vesting schedule is configured, then the full available balance is withdrawable
+    pub fn withdrawable_balance(available: YoctoNear) -> YoctoNear {
+        Self::load().map_or(available, |schedule| {
+            schedule.withdrawable(available, BlockTimestamp::from_env())
+        })
+    }
+
+    /// records the amount withdrawn against the vesting schedule
+    /// - the schedule is cleared once fully vested
+    pub(crate) fn record_withdrawal(amount: YoctoNear) {
+        if let Some(mut schedule) = Self::load() {
+            if schedule.fully_vested(BlockTimestamp::from_env()) {
+                Self::clear();
+            } else {
+                schedule.withdrawn += amount;
+                schedule.save();
+            }
+        }
+    }
+
+    /// vested amount is rounded down
+    pub fn vested(&self, balance: YoctoNear, timestamp: BlockTimestamp) -> YoctoNear {
+        if timestamp < self.cliff {
+            return YoctoNear::ZERO;
+        }
+        if timestamp >= self.end {
+            return balance;
+        }
+        let vested = U256::from(*balance) * U256::from(timestamp - self.start)
+            / U256::from(self.end - self.start);
+        vested.as_u128().into()
+    }
+
+    /// returns the amount of the available balance that has vested and not yet been withdrawn
+    pub fn withdrawable(&self, available: YoctoNear, timestamp: BlockTimestamp) -> YoctoNear {
+        let vested = self.vested(available + self.withdrawn, timestamp);
+        vested.saturating_sub(*self.withdrawn).into()
+    }
+
+    pub fn fully_vested(&self, timestamp: BlockTimestamp) -> bool {
+        timestamp >= self.end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near_test::*;
+
+    #[test]
+    fn withdrawable() {
+        let terms = OwnerVestingTerms {
+            cliff_duration: 100.into(),
+            vesting_duration: 400.into(),
+        };
+        terms.validate();
+        let mut schedule = terms.schedule(100.into());
+        assert_eq!(schedule.cliff, 200.into());
+        assert_eq!(schedule.end, 500.into());
+
+        let available: YoctoNear = 1000.into();
+        assert_eq!(
+            schedule.withdrawable(available, 199.into()),
+            YoctoNear::ZERO
+        );
+        assert_eq!(schedule.withdrawable(available, 200.into()), 250.into());
+
+        // withdrawals are credited against the vested balance
+        schedule.withdrawn = 250.into();
+        let available: YoctoNear = 750.into();
+        assert_eq!(
+            schedule.withdrawable(available, 200.into()),
+            YoctoNear::ZERO
+        );
+        assert_eq!(schedule.withdrawable(available, 300.into()), 250.into());
+        assert_eq!(schedule.withdrawable(available, 500.into()), available);
+        assert!(!schedule.fully_vested(499.into()));
+        assert!(schedule.fully_vested(500.into()));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"BAD_REQUEST\""#)]
+    fn cliff_after_end() {
+        TestCtx::new("bob").apply();
+        OwnerVestingTerms {
+            cliff_duration: 500.into(),
+            vesting_duration: 400.into(),
+        }
+        .validate();
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{BalanceId, ContractNearBalances, CONTRACT_LOCKED_STORAGE_BALANCE};
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::{BlockHeight, StorageUsage, YoctoNear};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+};
+use std::convert::TryInto;
+use std::ops::Deref;
+
+/// Reason code for locking a portion of the contract balance
+#[derive(BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub enum StorageBalanceLockReason {
+    /// reserved to pay for the contract's operational storage
+    ContractStorage,
+    /// reserved to pay for the storage required to upgrade the contract
+    UpgradeReserve,
+    /// held in escrow to pay for a contract audit
+    AuditEscrow,
+    Other(String),
+}
+
+/// Used to lock a named portion of the contract balance
+#[derive(BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StorageBalanceLockRequest {
+    /// unique lock name - locking with the same name replaces the existing lock
+    pub name: String,
+    pub reason: StorageBalanceLockReason,
+    /// if set, then the lock can be released by anyone once the block height is reached
+    pub expires_at: Option<BlockHeight>,
+}
+
+/// Named contract balance lock
+/// - each lock is tracked as a separate contract NEAR balance, which is excluded from the owner's
+///   available balance
+#[derive(BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StorageBalanceLock {
+    pub name: String,
+    pub reason: StorageBalanceLockReason,
+    pub storage_usage: StorageUsage,
+    /// computed as `storage_usage * storage_byte_cost` at the time the lock was created
+    pub amount: YoctoNear,
+    pub balance_id: BalanceId,
+    /// zero for the default lock, which does not track when it was locked
+    pub locked_at: BlockHeight,
+    pub expires_at: Option<BlockHeight>,
+}
+
+impl StorageBalanceLock {
+    /// name for the default lock, which is tracked via [`CONTRACT_LOCKED_STORAGE_BALANCE`]
+    pub const DEFAULT_LOCK_NAME: &'static str = "contract_storage";
+
+    pub fn expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| BlockHeight::from_env() >= expires_at)
+    }
+
+    /// balance ID is derived from the lock name
+    pub fn balance_id(name: &str) -> BalanceId {
+        if name == Self::DEFAULT_LOCK_NAME {
+            return CONTRACT_LOCKED_STORAGE_BALANCE;
+        }
+        let hash = env::sha256(name.as_bytes());
+        BalanceId(u128::from_le_bytes(hash[..16].try_into().unwrap()))
+    }
+}
+
+const STORAGE_BALANCE_LOCKS_KEY: u128 = 1957650722313508591726209458412218347;
+
+type StorageBalanceLocksObject = Object<u128, Vec<StorageBalanceLock>>;
+
+/// Named contract balance locks
+///
+/// The default lock is backed only by the [`CONTRACT_LOCKED_STORAGE_BALANCE`] balance, i.e., it is
+/// not stored as a separate record, which keeps backwards compatibility with contracts that lock
+/// the balance directly on deployment.
+pub struct StorageBalanceLocks;
+
+impl StorageBalanceLocks {
+    /// returns all locks, including the default lock, ordered by name
+    pub fn locks() -> Vec<StorageBalanceLock> {
+        let mut locks = Self::named_locks();
+        if let Some(lock) = Self::default_lock() {
+            locks.push(lock);
+        }
+        locks.sort_by(|a, b| a.name.cmp(&b.name));
+        locks
+    }
+
+    pub fn lock(name: &str) -> Option<StorageBalanceLock> {
+        if name == StorageBalanceLock::DEFAULT_LOCK_NAME {
+            return Self::default_lock();
+        }
+        Self::named_locks()
+            .into_iter()
+            .find(|lock| lock.name == name)
+    }
+
+    /// creates or replaces the lock
+    /// - if storage usage is zero, then the lock is removed
+    /// - the default lock never expires, i.e., `reason` and `expires_at` are ignored
+    ///
+    /// Returns the lock if it is active.
+    pub fn set_lock(
+        request: StorageBalanceLockRequest,
+        storage_usage: StorageUsage,
+    ) -> Option<StorageBalanceLock> {
+        let amount: YoctoNear = (env::storage_byte_cost() * *storage_usage as u128).into();
+        let balance_id = StorageBalanceLock::balance_id(&request.name);
+        ContractNearBalances::set_balance(balance_id, amount);
+
+        if request.name == StorageBalanceLock::DEFAULT_LOCK_NAME {
+            return Self::default_lock();
+        }
+
+        let mut locks = Self::named_locks();
+        locks.retain(|lock| lock.name != request.name);
+        let lock = if amount > YoctoNear::ZERO {
+            let lock = StorageBalanceLock {
+                name: request.name,
+                reason: request.reason,
+                storage_usage,
+                amount,
+                balance_id,
+                locked_at: BlockHeight::from_env(),
+                expires_at: request.expires_at,
+            };
+            locks.push(lock.clone());
+            Some(lock)
+        } else {
+            None
+        };
+        Self::save(locks);
+        lock
+    }
+
+    /// returns the released lock
+    pub fn unlock(name: &str) -> Option<StorageBalanceLock> {
+        let lock = Self::lock(name)?;
+        ContractNearBalances::clear_balance(lock.balance_id);
+        if name != StorageBalanceLock::DEFAULT_LOCK_NAME {
+            let mut locks = Self::named_locks();
+            locks.retain(|lock| lock.name != name);
+            Self::save(locks);
+        }
+        Some(lock)
+    }
+
+    /// releases all locks that have expired and returns them
+    pub fn release_expired() -> Vec<StorageBalanceLock> {
+        let (expired, active): (Vec<StorageBalanceLock>, Vec<StorageBalanceLock>) =
+            Self::named_locks()
+                .into_iter()
+                .partition(StorageBalanceLock::expired);
+        if !expired.is_empty() {
+            for lock in expired.iter() {
+                ContractNearBalances::clear_balance(lock.balance_id);
+            }
+            Self::save(active);
+        }
+        expired
+    }
+
+    fn named_locks() -> Vec<StorageBalanceLock> {
+        StorageBalanceLocksObject::load(&STORAGE_BALANCE_LOCKS_KEY)
+            .map_or_else(Vec::new, |locks| locks.deref().clone())
+    }
+
+    fn save(locks: Vec<StorageBalanceLock>) {
+        if locks.is_empty() {
+            StorageBalanceLocksObject::delete_by_key(&STORAGE_BALANCE_LOCKS_KEY);
+        } else {
+            StorageBalanceLocksObject::new(STORAGE_BALANCE_LOCKS_KEY, locks).save();
+        }
+    }
+
+    fn default_lock() -> Option<StorageBalanceLock> {
+        let amount = ContractNearBalances::near_balance(CONTRACT_LOCKED_STORAGE_BALANCE);
+        if amount == YoctoNear::ZERO {
+            return None;
+        }
+        Some(StorageBalanceLock {
+            name: StorageBalanceLock::DEFAULT_LOCK_NAME.to_string(),
+            reason: StorageBalanceLockReason::ContractStorage,
+            storage_usage: ((*amount / env::storage_byte_cost()) as u64).into(),
+            amount,
+            balance_id: CONTRACT_LOCKED_STORAGE_BALANCE,
+            locked_at: BlockHeight::default(),
+            expires_at: None,
+        })
+    }
+}
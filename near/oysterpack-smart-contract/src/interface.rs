@@ -1,6 +1,8 @@
+pub use contract::contract_jobs::*;
 pub use contract::contract_metrics::*;
 pub use contract::contract_operator::*;
 pub use contract::contract_ownership::*;
 pub use contract::contract_sale::*;
+pub use contract::gas_estimates::*;
 
 pub mod contract;
@@ -1,5 +1,7 @@
 //! Contract Interfaces
+pub mod contract_jobs;
 pub mod contract_metrics;
 pub mod contract_operator;
 pub mod contract_ownership;
 pub mod contract_sale;
+pub mod gas_estimates;
@@ -0,0 +1,16 @@
+use crate::{JobCursor, JobCursors, JobId};
+use oysterpack_smart_near::{ErrCode, ErrorConst};
+
+/// # **Contract Interface**: Contract Jobs API
+/// Provides status on long-running batch jobs, which are chunked across multiple transactions
+/// - job progress is tracked via [`JobCursor`]
+pub trait ContractJobs {
+    /// returns the cursors for all jobs that have been started
+    fn ops_jobs(&self) -> JobCursors;
+
+    /// returns None if the job has not been started
+    fn ops_job(&self, job_id: JobId) -> Option<JobCursor>;
+}
+
+pub const ERR_JOB_NOT_STARTED: ErrorConst =
+    ErrorConst(ErrCode("JOB_NOT_STARTED"), "job has not been started");
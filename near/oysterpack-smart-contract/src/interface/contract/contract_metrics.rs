@@ -1,4 +1,8 @@
-use crate::{ContractNearBalances, ContractStorageUsage, ContractStorageUsageCosts};
+use crate::{
+    ContractNearBalances, ContractStorageUsage, ContractStorageUsageBreakdown,
+    ContractStorageUsageCosts,
+};
+use crate::{MetricsHistoryConfig, MetricsSample};
 use oysterpack_smart_account_management::AccountMetrics;
 use oysterpack_smart_near::data::numbers::U128;
 use oysterpack_smart_near::domain::BlockTime;
@@ -18,9 +22,23 @@ pub trait ContractMetrics {
 
     fn ops_metrics_storage_usage_costs(&self) -> ContractStorageUsageCosts;
 
+    /// Breaks down the contract storage usage costs by component, i.e., accounts, FT balances,
+    /// staking state, and metrics
+    /// - computed from maintained per component counters, which keeps the view cheap
+    fn ops_storage_usage_breakdown(&self) -> ContractStorageUsageBreakdown;
+
     fn ops_metrics(&self) -> ContractMetricsSnapshot;
 
     fn ops_metrics_accounts(&self) -> AccountMetrics;
+
+    /// Returns the most recent metrics samples, ordered from oldest to newest, which can be used for
+    /// on-chain charting
+    /// - `limit` defaults to all retained samples
+    /// - returns an empty list if metrics history is disabled
+    fn ops_metrics_history(&self, limit: Option<u16>) -> Vec<MetricsSample>;
+
+    /// returns None if metrics history is disabled
+    fn ops_metrics_history_config(&self) -> Option<MetricsHistoryConfig>;
 }
 
 /// Provides a point in time metrics snapshot
@@ -1,5 +1,9 @@
-use crate::BalanceId;
+use crate::{
+    BalanceAlerts, BalanceId, ContractConfig, MetricsHistoryConfig, StorageBalanceLock,
+    StorageBalanceLockRequest,
+};
 use oysterpack_smart_near::domain::StorageUsage;
+use oysterpack_smart_near::{Level, LogEvent};
 
 pub trait ContractOperator {
     /// Locks a portion of the contract's balance to reserve to pay for contract storage usage.
@@ -8,11 +12,41 @@ pub trait ContractOperator {
     /// operate, i.e., pay for contract storage. This would prevent the contract owner from withdrawing
     /// all of his available balance, which might break the contract.
     ///
+    /// Multiple named locks are supported, which enables contract managed reserves to be tracked
+    /// separately, e.g., upgrade reserve, audit escrow. Each lock is tracked as a separate contract
+    /// NEAR balance. If `lock` is not specified, then the default lock is used, i.e.,
+    /// [`StorageBalanceLock::DEFAULT_LOCK_NAME`]. Locking with the same name replaces the existing lock.
+    ///
     /// setting the storage usage to zero, effectively unlocks the storage balance
     ///
+    /// Returns the lock if it is active.
+    ///
+    /// ## Panics
+    /// - requires operator permission
+    /// - if the lock name is blank
+    /// - if the lock expiry block height has already been reached
+    fn ops_operator_lock_storage_balance(
+        &mut self,
+        storage_usage: StorageUsage,
+        lock: Option<StorageBalanceLockRequest>,
+    ) -> Option<StorageBalanceLock>;
+
+    /// Releases the named lock and returns it.
+    ///
     /// ## Panics
     /// - requires operator permission
-    fn ops_operator_lock_storage_balance(&mut self, storage_usage: StorageUsage);
+    /// - if the lock does not exist
+    fn ops_operator_unlock_storage_balance(&mut self, name: String) -> StorageBalanceLock;
+
+    /// Releases all locks that have expired and returns them.
+    /// - can be invoked by anyone, e.g., keepers
+    fn ops_operator_release_expired_storage_balance_locks(&mut self) -> Vec<StorageBalanceLock>;
+
+    /// returns all contract balance locks ordered by name - includes expired locks that have not
+    /// yet been released
+    fn ops_operator_storage_balance_locks(&self) -> Vec<StorageBalanceLock>;
+
+    fn ops_operator_storage_balance_lock(&self, name: String) -> Option<StorageBalanceLock>;
 
     /// Allows the owner to grants admin permission himself
     ///
@@ -22,8 +56,64 @@ pub trait ContractOperator {
     /// ## Panics
     /// If not invoked by the owner account
     fn ops_owner_grant_admin(&mut self);
+
+    /// Configures the metrics history sampling, which is used to track metrics over time
+    /// - any existing metrics history is cleared
+    /// - setting the config to None disables the metrics history
+    ///
+    /// ## Panics
+    /// - requires operator permission
+    /// - if `block_interval` is zero
+    /// - if `max_samples` is zero or exceeds [`crate::MetricsHistory::MAX_SAMPLES`]
+    fn ops_operator_config_metrics_history(&mut self, config: Option<MetricsHistoryConfig>);
+
+    /// Configures the contract NEAR balance thresholds that trigger [`crate::LOG_EVENT_ALERT_LOW_BALANCE`]
+    /// alerts - see [`BalanceAlerts`]
+    /// - setting the config to None disables the balance alerts
+    ///
+    /// ## Panics
+    /// - requires operator permission
+    /// - if no thresholds are specified
+    fn ops_operator_config_balance_alerts(&mut self, config: Option<BalanceAlerts>);
+
+    fn ops_operator_balance_alerts(&self) -> Option<BalanceAlerts>;
+
+    /// Sets a contract config entry, e.g., website, logo URL, brand colors, support contact, which
+    /// is used by front-ends to render branded contract pages - see [`ContractConfig`]
+    /// - if the value is None, then the entry is removed
+    ///
+    /// Returns the updated contract config
+    ///
+    /// ## Panics
+    /// - requires operator permission
+    /// - if the key is blank or is longer than [`ContractConfig::MAX_KEY_LEN`] bytes
+    /// - if the value is blank or is longer than [`ContractConfig::MAX_VALUE_LEN`] bytes
+    /// - if the number of entries would exceed [`ContractConfig::MAX_ENTRIES`]
+    fn ops_operator_set_contract_config(
+        &mut self,
+        key: String,
+        value: Option<String>,
+    ) -> ContractConfig;
+
+    fn ops_contract_config(&self) -> ContractConfig;
+
+    /// Sets the min level of the log events that are logged, e.g., to suppress verbose
+    /// [`Level::INFO`] events in production to save gas - see [`oysterpack_smart_near::LogFilter`]
+    /// - [`Level::WARN`] events are always logged
+    ///
+    /// ## Panics
+    /// - requires operator permission
+    fn ops_operator_set_log_level(&mut self, level: Level);
+
+    fn ops_operator_log_level(&self) -> Level;
 }
 
 /// used by ['ContractOwnership::ops_owner_lock_balance`]
 pub const CONTRACT_LOCKED_STORAGE_BALANCE: BalanceId =
     BalanceId(1955299460766524333040021403508226880);
+
+pub const LOG_EVENT_STORAGE_BALANCE_LOCKED: LogEvent =
+    LogEvent(Level::INFO, "STORAGE_BALANCE_LOCKED");
+
+pub const LOG_EVENT_STORAGE_BALANCE_UNLOCKED: LogEvent =
+    LogEvent(Level::INFO, "STORAGE_BALANCE_UNLOCKED");
@@ -1,3 +1,6 @@
+use crate::{
+    OwnerAutoSweep, OwnerGuardians, OwnerRecovery, OwnerVestingSchedule, OwnerVestingTerms,
+};
 use oysterpack_smart_near::domain::YoctoNear;
 use oysterpack_smart_near::near_sdk::{
     borsh::{self, BorshDeserialize, BorshSerialize},
@@ -33,6 +36,7 @@ pub trait ContractOwnership {
     /// ## NOTES
     /// - any open contract sale is cancelled
     /// - any active bid is cancelled
+    /// - any vesting terms for a pending transfer are cleared
     ///
     /// ## Log Event
     /// [`LOG_EVENT_CONTRACT_TRANSFER_INITIATED`]
@@ -40,11 +44,42 @@ pub trait ContractOwnership {
     /// ## Panics
     /// - `ERR_OWNER_ACCESS_REQUIRED` - if the predecessor account is not the owner account
     /// - `ERR_YOCTONEAR_DEPOSIT_REQUIRED` - if 1 yoctoNEAR is not attached
+    /// - `ERR_OWNER_BALANCE_VESTING` - if the owner balance is still vesting
     /// - if the new owner account ID is not valid
     ///
     /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
     fn ops_owner_transfer(&mut self, new_owner: ValidAccountId);
 
+    /// Initiates the workflow to transfer contract ownership, where the owner balance is withdrawable
+    /// by the new owner on a vesting schedule, e.g., to support pool acquisitions with earn-out terms.
+    /// - the vesting schedule starts when the transfer is finalized
+    /// - the vesting schedule is enforced by [`ContractOwnership::ops_owner_withdraw_balance`] and
+    ///   [`ContractOwnership::ops_owner_sweep`], and remains in effect until fully vested, i.e.,
+    ///   ownership cannot be transferred while the owner balance is vesting
+    /// - the vesting schedule is cleared when contract ownership changes hands, unless it is
+    ///   replaced by the vesting terms for the new owner
+    ///
+    /// See [`ContractOwnership::ops_owner_transfer`]
+    ///
+    /// ## Panics
+    /// - `ERR_OWNER_ACCESS_REQUIRED` - if the predecessor account is not the owner account
+    /// - `ERR_YOCTONEAR_DEPOSIT_REQUIRED` - if 1 yoctoNEAR is not attached
+    /// - `ERR_CODE_BAD_REQUEST` - if the vesting terms are invalid
+    /// - `ERR_OWNER_BALANCE_VESTING` - if the owner balance is still vesting
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_owner_transfer_with_vesting(
+        &mut self,
+        new_owner: ValidAccountId,
+        vesting: OwnerVestingTerms,
+    );
+
+    /// Returns the vesting terms for the pending ownership transfer, if any
+    fn ops_owner_prospective_vesting(&self) -> Option<OwnerVestingTerms>;
+
+    /// Returns the owner balance vesting schedule, if the owner balance is still vesting
+    fn ops_owner_vesting_schedule(&self) -> Option<OwnerVestingSchedule>;
+
     /// Enables the transfer to be cancelled before it is finalized.
     ///
     /// The transfer can only be cancelled by both the current owner and the prospective owner.
@@ -64,6 +99,8 @@ pub trait ContractOwnership {
     /// ## Notes
     /// This effectively transfers any owner balance to the new owner. The owner can withdraw
     /// from its available balance before the transfer is finalized.
+    /// - the previous owner's vesting schedule is cleared, and replaced if the transfer has vesting
+    ///   terms
     ///
     /// ## Log Event
     /// [`LOG_EVENT_CONTRACT_TRANSFER_FINALIZED`]
@@ -79,6 +116,9 @@ pub trait ContractOwnership {
     /// Used by the contract owner to withdraw from the contract owner's available balance.
     ///
     /// If `amount` is None, then all available balance is withdrawn.
+    /// - if the owner balance is vesting, then only the vested balance can be withdrawn - see
+    ///   [`ContractOwnership::ops_owner_transfer_with_vesting`]
+    /// - subject to the admin approval policy - see [`oysterpack_smart_account_management::AdminApprovalsManagement`]
     ///
     /// Returns the updated contract owner NEAR balance.
     ///
@@ -86,11 +126,157 @@ pub trait ContractOwnership {
     /// - `ERR_OWNER_ACCESS_REQUIRED` - if the predecessor account is not the owner account
     /// - `ERR_YOCTONEAR_DEPOSIT_REQUIRED` - if 1 yoctoNEAR is not attached
     /// - `ERR_OWNER_BALANCE_OVERDRAW` - if there are insufficient funds to fulfill the request
+    /// - `ERR_OWNER_BALANCE_VESTING` - if the amount exceeds the vested balance
     /// - `ERR_CODE_BAD_REQUEST` - if specified amount is zero
     ///
     /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
     fn ops_owner_withdraw_balance(&mut self, amount: Option<YoctoNear>)
         -> ContractOwnerNearBalance;
+
+    /// Used by the contract owner to configure the owner balance auto-sweep policy.
+    ///
+    /// When set, the owner's available balance in excess of the threshold is credited to the owner's
+    /// account storage balance each time [`ContractOwnership::ops_owner_sweep`] is invoked.
+    /// If `threshold` is None, then the auto-sweep policy is cleared.
+    /// - the auto-sweep policy is also cleared whenever contract ownership changes hands
+    /// - subject to the admin approval policy - see [`oysterpack_smart_account_management::AdminApprovalsManagement`]
+    ///
+    /// ## Log Event
+    /// [`LOG_EVENT_OWNER_AUTO_SWEEP_UPDATED`]
+    ///
+    /// ## Panics
+    /// - `ERR_OWNER_ACCESS_REQUIRED` - if the predecessor account is not the owner account
+    /// - `ERR_YOCTONEAR_DEPOSIT_REQUIRED` - if 1 yoctoNEAR is not attached
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_owner_set_auto_sweep(&mut self, threshold: Option<YoctoNear>);
+
+    /// Returns the owner balance auto-sweep policy, if one is configured
+    fn ops_owner_auto_sweep(&self) -> Option<OwnerAutoSweep>;
+
+    /// Keeper method that can be invoked by any account to sweep the owner's excess available balance
+    /// into the owner's account storage balance, per the configured auto-sweep policy.
+    ///
+    /// Returns the amount that was swept. If no auto-sweep policy is configured or the owner's
+    /// available balance is within the threshold, then nothing is swept.
+    /// - if the owner balance is vesting, then only the vested balance is swept
+    /// - subject to the admin approval policy, i.e., when an approval policy is configured, the sweep
+    ///   is enqueued for admin approval and nothing is swept until the sweep is approved - because
+    ///   the swept balance can be withdrawn via `storage_withdraw`
+    ///
+    /// ## Log Event
+    /// [`LOG_EVENT_OWNER_BALANCE_SWEPT`]
+    ///
+    /// ## Panics
+    /// - `ERR_ACCOUNT_NOT_REGISTERED` - if there is an excess balance to sweep, but the owner does
+    ///   not have a registered account
+    fn ops_owner_sweep(&mut self) -> YoctoNear;
+
+    /// Used by the contract owner to nominate a delegate account that can exercise owner-only methods
+    /// on its behalf, e.g., to keep the owner key offline or to recover from a lost owner key.
+    ///
+    /// The delegate cannot transfer or sell contract ownership, nor manage the delegate. Any existing
+    /// delegate is replaced. The delegate is cleared whenever contract ownership changes hands, i.e.,
+    /// when it is transferred, recovered, or sold.
+    ///
+    /// ## Log Event
+    /// [`LOG_EVENT_OWNER_DELEGATE_ADDED`]
+    ///
+    /// ## Panics
+    /// - `ERR_OWNER_ACCESS_REQUIRED` - if the predecessor account is not the owner account
+    /// - `ERR_YOCTONEAR_DEPOSIT_REQUIRED` - if 1 yoctoNEAR is not attached
+    /// - `ERR_CODE_BAD_REQUEST` - if the delegate is the owner account
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_owner_add_full_access_delegate(&mut self, account_id: ValidAccountId);
+
+    /// Used by the contract owner to remove the owner delegate
+    ///
+    /// ## Log Event
+    /// [`LOG_EVENT_OWNER_DELEGATE_REMOVED`]
+    ///
+    /// ## Panics
+    /// - `ERR_OWNER_ACCESS_REQUIRED` - if the predecessor account is not the owner account
+    /// - `ERR_YOCTONEAR_DEPOSIT_REQUIRED` - if 1 yoctoNEAR is not attached
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_owner_remove_delegate(&mut self);
+
+    /// Returns the owner delegate, if one is nominated
+    fn ops_owner_delegate(&self) -> Option<AccountId>;
+
+    /// Used by the contract owner to register the guardians that can reassign contract ownership
+    /// if the owner key is lost - see [`OwnerGuardians`]
+    ///
+    /// If `guardians` is None, then the guardians are cleared. Any owner recovery that is in
+    /// progress is cancelled. The guardians are also cleared whenever contract ownership changes
+    /// hands, i.e., when the ownership transfer is finalized, the contract is sold, or ownership is
+    /// recovered - the new owner must appoint its own guardians.
+    ///
+    /// ## Log Event
+    /// [`LOG_EVENT_OWNER_GUARDIANS_UPDATED`]
+    ///
+    /// ## Panics
+    /// - `ERR_OWNER_ACCESS_REQUIRED` - if the predecessor account is not the owner account
+    /// - `ERR_YOCTONEAR_DEPOSIT_REQUIRED` - if 1 yoctoNEAR is not attached
+    /// - `ERR_CODE_BAD_REQUEST` - if the guardians config is invalid - see [`OwnerGuardians::validate`]
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_owner_set_guardians(&mut self, guardians: Option<OwnerGuardians>);
+
+    /// Returns the owner guardians, if configured
+    fn ops_owner_guardians(&self) -> Option<OwnerGuardians>;
+
+    /// Used by a guardian to vote for the account that contract ownership should be reassigned to.
+    /// - a guardian can change its vote until the quorum is reached
+    /// - once the quorum is reached, the recovery timelock starts and the votes are locked
+    ///
+    /// ## Log Events
+    /// - [`LOG_EVENT_OWNER_RECOVERY_VOTE`]
+    /// - [`LOG_EVENT_OWNER_RECOVERY_INITIATED`] - when the quorum is reached
+    ///
+    /// ## Panics
+    /// - `ERR_GUARDIAN_ACCESS_REQUIRED` - if the predecessor account is not a guardian
+    /// - `ERR_YOCTONEAR_DEPOSIT_REQUIRED` - if 1 yoctoNEAR is not attached
+    /// - `ERR_CODE_BAD_REQUEST` - if the new owner is the current owner, or if the recovery timelock
+    ///   has already started
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_owner_recovery_vote(&mut self, new_owner: ValidAccountId);
+
+    /// Reassigns contract ownership to the recovered owner once the recovery timelock has elapsed.
+    /// - can be invoked by any guardian or by the recovered owner
+    /// - the ownership change is applied the same way as [`ContractOwnership::ops_owner_finalize_transfer`],
+    ///   i.e., any pending transfer, contract sale, bid, owner delegate, owner vesting schedule,
+    ///   and the guardians are cleared
+    ///
+    /// ## Log Event
+    /// [`LOG_EVENT_OWNER_RECOVERED`]
+    ///
+    /// ## Panics
+    /// - `ERR_GUARDIAN_ACCESS_REQUIRED` - if the predecessor account is not a guardian or the
+    ///   recovered owner
+    /// - `ERR_YOCTONEAR_DEPOSIT_REQUIRED` - if 1 yoctoNEAR is not attached
+    /// - `ERR_OWNER_RECOVERY_NOT_ELIGIBLE` - if the quorum has not been reached or the timelock has
+    ///   not elapsed
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_owner_recovery_finalize(&mut self);
+
+    /// Used by the contract owner to cancel the owner recovery, which clears all guardian votes
+    ///
+    /// ## Log Event
+    /// [`LOG_EVENT_OWNER_RECOVERY_CANCELLED`]
+    ///
+    /// ## Panics
+    /// - `ERR_OWNER_ACCESS_REQUIRED` - if the predecessor account is not the owner account
+    /// - `ERR_YOCTONEAR_DEPOSIT_REQUIRED` - if 1 yoctoNEAR is not attached
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_owner_recovery_cancel(&mut self);
+
+    /// Returns the owner recovery that is in progress, if any
+    fn ops_owner_recovery(&self) -> Option<OwnerRecovery>;
 }
 
 /// log event for [`ContractOwnership::ops_owner_transfer`]
@@ -105,6 +291,53 @@ pub const LOG_EVENT_CONTRACT_TRANSFER_CANCELLED: LogEvent =
 pub const LOG_EVENT_CONTRACT_TRANSFER_FINALIZED: LogEvent =
     LogEvent(Level::INFO, "CONTRACT_TRANSFER_FINALIZED");
 
+/// log event for when the owner balance vesting schedule starts, i.e., when the ownership transfer
+/// is finalized - see [`ContractOwnership::ops_owner_transfer_with_vesting`]
+/// - log message format: `start={start}, cliff={cliff}, end={end}`
+/// - also logged with message `cleared: {reason}` when the vesting schedule is cleared because
+///   contract ownership changed hands
+pub const LOG_EVENT_OWNER_VESTING: LogEvent = LogEvent(Level::INFO, "OWNER_VESTING");
+
+/// log event for [`ContractOwnership::ops_owner_set_auto_sweep`] and when the auto-sweep policy is
+/// cleared because contract ownership changed hands
+pub const LOG_EVENT_OWNER_AUTO_SWEEP_UPDATED: LogEvent =
+    LogEvent(Level::INFO, "OWNER_AUTO_SWEEP_UPDATED");
+
+/// log event for [`ContractOwnership::ops_owner_sweep`]
+pub const LOG_EVENT_OWNER_BALANCE_SWEPT: LogEvent = LogEvent(Level::INFO, "OWNER_BALANCE_SWEPT");
+
+/// log event for [`ContractOwnership::ops_owner_add_full_access_delegate`]
+pub const LOG_EVENT_OWNER_DELEGATE_ADDED: LogEvent = LogEvent(Level::INFO, "OWNER_DELEGATE_ADDED");
+
+/// log event for [`ContractOwnership::ops_owner_remove_delegate`] and when the delegate is cleared
+/// because contract ownership changed hands
+pub const LOG_EVENT_OWNER_DELEGATE_REMOVED: LogEvent =
+    LogEvent(Level::INFO, "OWNER_DELEGATE_REMOVED");
+
+/// log event for [`ContractOwnership::ops_owner_set_guardians`] and when the guardians are cleared
+/// because contract ownership changed hands
+/// - log message format: `guardians={guardians}, quorum={quorum}, delay={delay}`, `cleared`, or
+///   `cleared: {reason}`
+pub const LOG_EVENT_OWNER_GUARDIANS_UPDATED: LogEvent =
+    LogEvent(Level::INFO, "OWNER_GUARDIANS_UPDATED");
+
+/// log event for [`ContractOwnership::ops_owner_recovery_vote`]
+/// - log message format: `guardian={guardian}, new_owner={new_owner}, votes={votes}`
+pub const LOG_EVENT_OWNER_RECOVERY_VOTE: LogEvent = LogEvent(Level::WARN, "OWNER_RECOVERY_VOTE");
+
+/// log event for when the guardian quorum is reached and the recovery timelock starts
+/// - log message format: `new_owner={new_owner}, eligible_at={eligible_at}`
+pub const LOG_EVENT_OWNER_RECOVERY_INITIATED: LogEvent =
+    LogEvent(Level::WARN, "OWNER_RECOVERY_INITIATED");
+
+/// log event for [`ContractOwnership::ops_owner_recovery_finalize`]
+pub const LOG_EVENT_OWNER_RECOVERED: LogEvent = LogEvent(Level::WARN, "OWNER_RECOVERED");
+
+/// log event for [`ContractOwnership::ops_owner_recovery_cancel`] and when the recovery is cleared
+/// because the guardians were updated or contract ownership was transferred
+pub const LOG_EVENT_OWNER_RECOVERY_CANCELLED: LogEvent =
+    LogEvent(Level::INFO, "OWNER_RECOVERY_CANCELLED");
+
 /// Contract owner total and available balance
 #[derive(
     BorshSerialize, BorshDeserialize, Deserialize, Serialize, Clone, Debug, PartialEq, Default,
@@ -142,3 +375,19 @@ pub const ERR_OWNER_BALANCE_OVERDRAW: ErrorConst = ErrorConst(
     ErrCode("OWNER_BALANCE_OVERDRAW"),
     "owner balance is insufficient to fulfill withdrawal",
 );
+
+pub const ERR_OWNER_BALANCE_VESTING: ErrorConst = ErrorConst(
+    ErrCode("OWNER_BALANCE_VESTING"),
+    "owner balance has not vested",
+);
+
+/// Indicates access was denied because guardian access was required - see [`OwnerGuardians`]
+pub const ERR_GUARDIAN_ACCESS_REQUIRED: ErrorConst = ErrorConst(
+    ErrCode("GUARDIAN_ACCESS_REQUIRED"),
+    "action requires guardian access",
+);
+
+pub const ERR_OWNER_RECOVERY_NOT_ELIGIBLE: ErrorConst = ErrorConst(
+    ErrCode("OWNER_RECOVERY_NOT_ELIGIBLE"),
+    "owner recovery is not eligible to be finalized",
+);
@@ -11,6 +11,8 @@ use oysterpack_smart_near::{ErrCode, ErrorConst, Level, LogEvent};
 ///
 /// When the sale transaction is executed, the sale amount will be released to the current owner and
 /// all of the owner's balance will be transferred out of the contract to the owner's NEAR account.
+/// The owner settings that were configured by or for the previous owner - owner delegate, guardians,
+/// vesting schedule, and auto-sweep policy - are cleared.
 ///
 /// TODO: enable buyers to pay with STAKE
 pub trait ContractSale {
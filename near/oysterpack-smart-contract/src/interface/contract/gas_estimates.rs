@@ -0,0 +1,10 @@
+use oysterpack_smart_near::domain::GasEstimate;
+
+/// # **Contract Interface**: Gas Estimates API
+/// Provides the minimum gas that clients should attach per contract method, which is computed from
+/// the receipts the method creates - see [`oysterpack_smart_near::domain::ReceiptGasBudget`]
+/// - only methods that create receipts are listed
+/// - estimates reflect the current contract config, e.g., operator configured callback gas
+pub trait GasEstimates {
+    fn ops_operator_gas_estimates(&self) -> Vec<GasEstimate>;
+}
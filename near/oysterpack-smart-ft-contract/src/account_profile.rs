@@ -0,0 +1,17 @@
+use crate::*;
+
+use near_sdk::json_types::ValidAccountId;
+use near_sdk::near_bindgen;
+use oysterpack_smart_account_management::{AccountProfile, AccountProfiles};
+
+#[near_bindgen]
+impl AccountProfiles for Contract {
+    #[payable]
+    fn ops_account_profile_set(&mut self, profile: Option<AccountProfile>) {
+        Self::account_manager().ops_account_profile_set(profile)
+    }
+
+    fn ops_account_profile_get(&self, account_id: ValidAccountId) -> Option<AccountProfile> {
+        Self::account_manager().ops_account_profile_get(account_id)
+    }
+}
@@ -0,0 +1,28 @@
+use crate::*;
+use oysterpack_smart_account_management::components::account_management::AccountManagementComponent;
+use oysterpack_smart_account_management::ContractPermissions;
+use oysterpack_smart_contract::components::contract_operator::ContractOperatorComponent;
+
+/// the FT contract does not store any additional account data
+pub type AccountData = ();
+
+pub type AccountManager = AccountManagementComponent<AccountData>;
+
+pub type FungibleTokenService = FungibleTokenComponent<AccountData>;
+
+pub type ContractOperator = ContractOperatorComponent<AccountData>;
+
+impl Contract {
+    pub(crate) fn account_manager() -> AccountManager {
+        FungibleTokenService::register_storage_management_event_handler();
+        AccountManager::new(ContractPermissions::default())
+    }
+
+    pub(crate) fn ft() -> FungibleTokenService {
+        FungibleTokenService::new(Self::account_manager())
+    }
+
+    pub(crate) fn contract_operator() -> ContractOperator {
+        ContractOperator::new(Self::account_manager())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::*;
+use oysterpack_smart_account_management::AccountMetrics;
+use oysterpack_smart_contract::components::contract_metrics::ContractMetricsComponent;
+use oysterpack_smart_contract::{
+    ContractMetrics, ContractMetricsSnapshot, ContractNearBalances, ContractStorageUsage,
+    ContractStorageUsageBreakdown, ContractStorageUsageCosts, MetricsHistoryConfig, MetricsSample,
+};
+use oysterpack_smart_near::data::numbers::U128;
+
+#[near_bindgen]
+impl ContractMetrics for Contract {
+    fn ops_metrics_total_registered_accounts(&self) -> U128 {
+        ContractMetricsComponent.ops_metrics_total_registered_accounts()
+    }
+
+    fn ops_metrics_contract_storage_usage(&self) -> ContractStorageUsage {
+        ContractMetricsComponent.ops_metrics_contract_storage_usage()
+    }
+
+    fn ops_metrics_near_balances(&self) -> ContractNearBalances {
+        ContractMetricsComponent.ops_metrics_near_balances()
+    }
+
+    fn ops_metrics_storage_usage_costs(&self) -> ContractStorageUsageCosts {
+        ContractMetricsComponent.ops_metrics_storage_usage_costs()
+    }
+
+    fn ops_storage_usage_breakdown(&self) -> ContractStorageUsageBreakdown {
+        ContractMetricsComponent.ops_storage_usage_breakdown()
+    }
+
+    fn ops_metrics(&self) -> ContractMetricsSnapshot {
+        ContractMetricsComponent.ops_metrics()
+    }
+
+    fn ops_metrics_accounts(&self) -> AccountMetrics {
+        ContractMetricsComponent.ops_metrics_accounts()
+    }
+
+    fn ops_metrics_history(&self, limit: Option<u16>) -> Vec<MetricsSample> {
+        ContractMetricsComponent.ops_metrics_history(limit)
+    }
+
+    fn ops_metrics_history_config(&self) -> Option<MetricsHistoryConfig> {
+        ContractMetricsComponent.ops_metrics_history_config()
+    }
+}
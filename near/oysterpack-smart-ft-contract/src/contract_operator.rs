@@ -0,0 +1,80 @@
+use crate::*;
+use oysterpack_smart_contract::{
+    BalanceAlerts, ContractConfig, ContractOperator, GasEstimates, MetricsHistoryConfig,
+    StorageBalanceLock, StorageBalanceLockRequest,
+};
+use oysterpack_smart_near::{
+    domain::{GasEstimate, StorageUsage},
+    near_sdk::near_bindgen,
+    Level,
+};
+
+#[near_bindgen]
+impl ContractOperator for Contract {
+    fn ops_operator_lock_storage_balance(
+        &mut self,
+        storage_usage: StorageUsage,
+        lock: Option<StorageBalanceLockRequest>,
+    ) -> Option<StorageBalanceLock> {
+        Self::contract_operator().ops_operator_lock_storage_balance(storage_usage, lock)
+    }
+
+    fn ops_operator_unlock_storage_balance(&mut self, name: String) -> StorageBalanceLock {
+        Self::contract_operator().ops_operator_unlock_storage_balance(name)
+    }
+
+    fn ops_operator_release_expired_storage_balance_locks(&mut self) -> Vec<StorageBalanceLock> {
+        Self::contract_operator().ops_operator_release_expired_storage_balance_locks()
+    }
+
+    fn ops_operator_storage_balance_locks(&self) -> Vec<StorageBalanceLock> {
+        Self::contract_operator().ops_operator_storage_balance_locks()
+    }
+
+    fn ops_operator_storage_balance_lock(&self, name: String) -> Option<StorageBalanceLock> {
+        Self::contract_operator().ops_operator_storage_balance_lock(name)
+    }
+
+    fn ops_owner_grant_admin(&mut self) {
+        Self::contract_operator().ops_owner_grant_admin();
+    }
+
+    fn ops_operator_config_metrics_history(&mut self, config: Option<MetricsHistoryConfig>) {
+        Self::contract_operator().ops_operator_config_metrics_history(config);
+    }
+
+    fn ops_operator_config_balance_alerts(&mut self, config: Option<BalanceAlerts>) {
+        Self::contract_operator().ops_operator_config_balance_alerts(config);
+    }
+
+    fn ops_operator_balance_alerts(&self) -> Option<BalanceAlerts> {
+        Self::contract_operator().ops_operator_balance_alerts()
+    }
+
+    fn ops_operator_set_contract_config(
+        &mut self,
+        key: String,
+        value: Option<String>,
+    ) -> ContractConfig {
+        Self::contract_operator().ops_operator_set_contract_config(key, value)
+    }
+
+    fn ops_contract_config(&self) -> ContractConfig {
+        Self::contract_operator().ops_contract_config()
+    }
+
+    fn ops_operator_set_log_level(&mut self, level: Level) {
+        Self::contract_operator().ops_operator_set_log_level(level)
+    }
+
+    fn ops_operator_log_level(&self) -> Level {
+        Self::contract_operator().ops_operator_log_level()
+    }
+}
+
+#[near_bindgen]
+impl GasEstimates for Contract {
+    fn ops_operator_gas_estimates(&self) -> Vec<GasEstimate> {
+        Self::ft().gas_estimates()
+    }
+}
@@ -0,0 +1,117 @@
+use crate::*;
+use near_sdk::{near_bindgen, AccountId};
+use oysterpack_smart_contract::{
+    ContractOwnerNearBalance, ContractOwnership, OwnerAutoSweep, OwnerGuardians, OwnerRecovery,
+    OwnerVestingSchedule, OwnerVestingTerms,
+};
+use oysterpack_smart_near::domain::YoctoNear;
+
+#[near_bindgen]
+impl ContractOwnership for Contract {
+    fn ops_owner(&self) -> AccountId {
+        ContractOwnershipComponent.ops_owner()
+    }
+
+    fn ops_owner_balance(&self) -> ContractOwnerNearBalance {
+        ContractOwnershipComponent.ops_owner_balance()
+    }
+
+    fn ops_owner_prospective(&self) -> Option<AccountId> {
+        ContractOwnershipComponent.ops_owner_prospective()
+    }
+
+    #[payable]
+    fn ops_owner_transfer(&mut self, new_owner: ValidAccountId) {
+        ContractOwnershipComponent.ops_owner_transfer(new_owner)
+    }
+
+    #[payable]
+    fn ops_owner_transfer_with_vesting(
+        &mut self,
+        new_owner: ValidAccountId,
+        vesting: OwnerVestingTerms,
+    ) {
+        ContractOwnershipComponent.ops_owner_transfer_with_vesting(new_owner, vesting)
+    }
+
+    fn ops_owner_prospective_vesting(&self) -> Option<OwnerVestingTerms> {
+        ContractOwnershipComponent.ops_owner_prospective_vesting()
+    }
+
+    fn ops_owner_vesting_schedule(&self) -> Option<OwnerVestingSchedule> {
+        ContractOwnershipComponent.ops_owner_vesting_schedule()
+    }
+
+    #[payable]
+    fn ops_owner_cancel_transfer(&mut self) {
+        ContractOwnershipComponent.ops_owner_cancel_transfer()
+    }
+
+    #[payable]
+    fn ops_owner_finalize_transfer(&mut self) {
+        ContractOwnershipComponent.ops_owner_finalize_transfer()
+    }
+
+    #[payable]
+    fn ops_owner_withdraw_balance(
+        &mut self,
+        amount: Option<YoctoNear>,
+    ) -> ContractOwnerNearBalance {
+        ContractOwnershipComponent.ops_owner_withdraw_balance(amount)
+    }
+
+    #[payable]
+    fn ops_owner_set_auto_sweep(&mut self, threshold: Option<YoctoNear>) {
+        ContractOwnershipComponent.ops_owner_set_auto_sweep(threshold)
+    }
+
+    fn ops_owner_auto_sweep(&self) -> Option<OwnerAutoSweep> {
+        ContractOwnershipComponent.ops_owner_auto_sweep()
+    }
+
+    fn ops_owner_sweep(&mut self) -> YoctoNear {
+        ContractOwnershipComponent.ops_owner_sweep()
+    }
+
+    #[payable]
+    fn ops_owner_add_full_access_delegate(&mut self, account_id: ValidAccountId) {
+        ContractOwnershipComponent.ops_owner_add_full_access_delegate(account_id)
+    }
+
+    #[payable]
+    fn ops_owner_remove_delegate(&mut self) {
+        ContractOwnershipComponent.ops_owner_remove_delegate()
+    }
+
+    fn ops_owner_delegate(&self) -> Option<AccountId> {
+        ContractOwnershipComponent.ops_owner_delegate()
+    }
+
+    #[payable]
+    fn ops_owner_set_guardians(&mut self, guardians: Option<OwnerGuardians>) {
+        ContractOwnershipComponent.ops_owner_set_guardians(guardians)
+    }
+
+    fn ops_owner_guardians(&self) -> Option<OwnerGuardians> {
+        ContractOwnershipComponent.ops_owner_guardians()
+    }
+
+    #[payable]
+    fn ops_owner_recovery_vote(&mut self, new_owner: ValidAccountId) {
+        ContractOwnershipComponent.ops_owner_recovery_vote(new_owner)
+    }
+
+    #[payable]
+    fn ops_owner_recovery_finalize(&mut self) {
+        ContractOwnershipComponent.ops_owner_recovery_finalize()
+    }
+
+    #[payable]
+    fn ops_owner_recovery_cancel(&mut self) {
+        ContractOwnershipComponent.ops_owner_recovery_cancel()
+    }
+
+    fn ops_owner_recovery(&self) -> Option<OwnerRecovery> {
+        ContractOwnershipComponent.ops_owner_recovery()
+    }
+}
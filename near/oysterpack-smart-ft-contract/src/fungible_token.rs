@@ -0,0 +1,105 @@
+use crate::*;
+use oysterpack_smart_fungible_token::{
+    FungibleToken, FungibleTokenMetadataProvider, FungibleTokenOperator, Memo, Metadata,
+    OperatorCommand, ResolveScreenedTransfer, ResolveTransferCall, TokenAmount, TokenService,
+    TransferCallMessage,
+};
+use oysterpack_smart_near::data::numbers::U64;
+use oysterpack_smart_near::domain::Gas;
+use oysterpack_smart_near::near_sdk::{AccountId, Promise, PromiseOrValue};
+
+#[near_bindgen]
+impl FungibleToken for Contract {
+    #[payable]
+    fn ft_transfer(
+        &mut self,
+        receiver_id: ValidAccountId,
+        amount: TokenAmount,
+        memo: Option<Memo>,
+    ) {
+        Self::ft().ft_transfer(receiver_id, amount, memo)
+    }
+
+    #[payable]
+    fn ft_transfer_call(
+        &mut self,
+        receiver_id: ValidAccountId,
+        amount: TokenAmount,
+        memo: Option<Memo>,
+        msg: TransferCallMessage,
+    ) -> Promise {
+        Self::ft().ft_transfer_call(receiver_id, amount, memo, msg)
+    }
+
+    fn ft_total_supply(&self) -> TokenAmount {
+        Self::ft().ft_total_supply()
+    }
+
+    fn ft_balance_of(&self, account_id: ValidAccountId) -> TokenAmount {
+        Self::ft().ft_balance_of(account_id)
+    }
+
+    fn ft_balances_of(&self, accounts: Vec<ValidAccountId>) -> Vec<TokenAmount> {
+        Self::ft().ft_balances_of(accounts)
+    }
+}
+
+#[near_bindgen]
+impl ResolveTransferCall for Contract {
+    #[private]
+    fn ft_resolve_transfer_call(
+        &mut self,
+        sender_id: ValidAccountId,
+        receiver_id: ValidAccountId,
+        amount: TokenAmount,
+    ) -> TokenAmount {
+        Self::ft().ft_resolve_transfer_call(sender_id, receiver_id, amount)
+    }
+}
+
+#[near_bindgen]
+impl ResolveScreenedTransfer for Contract {
+    #[private]
+    fn ft_resolve_screened_transfer(
+        &mut self,
+        sender_id: ValidAccountId,
+        receiver_id: ValidAccountId,
+        amount: TokenAmount,
+        memo: Option<Memo>,
+        msg: Option<TransferCallMessage>,
+    ) -> PromiseOrValue<TokenAmount> {
+        Self::ft().ft_resolve_screened_transfer(sender_id, receiver_id, amount, memo, msg)
+    }
+}
+
+#[near_bindgen]
+impl FungibleTokenMetadataProvider for Contract {
+    fn ft_metadata(&self) -> Metadata {
+        Self::ft().ft_metadata()
+    }
+}
+
+#[near_bindgen]
+impl FungibleTokenOperator for Contract {
+    fn ft_operator_command(&mut self, command: OperatorCommand) {
+        Self::ft().ft_operator_command(command)
+    }
+
+    fn ft_operator_transfer_callback_gas(&self) -> Gas {
+        Self::ft().ft_operator_transfer_callback_gas()
+    }
+
+    fn ft_operator_transfer_hook(&self) -> Option<AccountId> {
+        Self::ft().ft_operator_transfer_hook()
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    /// Returns the sequence number that was assigned to the last mint, burn, or transfer event
+    /// - every `FT_MINT`, `FT_BURN`, and `FT_TRANSFER` event includes its sequence number as `seq`,
+    ///   which enables indexers to detect missed events
+    pub fn ops_ft_last_sequence(&self) -> U64 {
+        Self::ft().ft_last_sequence().into()
+    }
+}
@@ -0,0 +1,150 @@
+mod access_control;
+mod account_metrics_history;
+mod account_profile;
+mod account_storage_usage;
+mod components;
+mod contract_metrics;
+mod contract_operator;
+mod contract_ownership;
+mod fungible_token;
+mod storage_management;
+
+use components::*;
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    json_types::ValidAccountId,
+    near_bindgen, serde_json, PanicOnDefault,
+};
+use oysterpack_smart_account_management::{
+    components::account_management::AccountManagementComponentConfig, AccountRepository,
+    StorageUsageBounds,
+};
+use oysterpack_smart_contract::{
+    components::contract_ownership::ContractOwnershipComponent, ContractOwnership,
+};
+use oysterpack_smart_contract::{ContractNearBalances, CONTRACT_LOCKED_STORAGE_BALANCE};
+use oysterpack_smart_fungible_token::components::fungible_token::{
+    FungibleTokenComponent, FungibleTokenConfig,
+};
+use oysterpack_smart_fungible_token::*;
+use oysterpack_smart_near::component::{Deploy, ManagesAccountData, LOG_EVENT_DEPLOYMENT};
+use std::convert::TryInto;
+
+near_sdk::setup_alloc!();
+
+/// Plain fungible token contract, which packages only the account management and fungible token
+/// components
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+pub struct Contract;
+
+#[near_bindgen]
+impl Contract {
+    /// If owner is not specified, then predecessor Account ID will be set as the contract owner.
+    /// - owner account is granted admin permission
+    /// - the initial token supply is minted to the owner's account
+    /// - decimals defaults to 24
+    #[init]
+    pub fn deploy(
+        name: String,
+        symbol: String,
+        token_supply: TokenAmount,
+        decimals: Option<u8>,
+        icon: Option<Icon>,
+        owner: Option<ValidAccountId>,
+    ) -> Self {
+        let metadata = Metadata {
+            spec: Spec(FT_METADATA_SPEC.to_string()),
+            name: Name(name),
+            symbol: Symbol(symbol),
+            decimals: decimals.unwrap_or(24),
+            icon,
+            reference: None,
+            reference_hash: None,
+        };
+        metadata.assert_valid();
+
+        let owner = owner.unwrap_or_else(|| env::predecessor_account_id().try_into().unwrap());
+        ContractOwnershipComponent::deploy(owner.clone());
+        LOG_EVENT_DEPLOYMENT.log("ContractOwnershipComponent");
+
+        AccountManager::deploy(AccountManagementComponentConfig {
+            storage_usage_bounds: None,
+            admin_account: owner.clone(),
+            component_account_storage_mins: Some(vec![FungibleTokenService::account_storage_min]),
+        });
+        LOG_EVENT_DEPLOYMENT.log("AccountManagementComponent");
+
+        // transfer any contract balance to the owner - minus the contract operational balance
+        {
+            // lock balance for contract operational storage balance
+            ContractNearBalances::set_balance(
+                CONTRACT_LOCKED_STORAGE_BALANCE,
+                (env::storage_byte_cost() * 10000).into(),
+            );
+
+            LOG_EVENT_DEPLOYMENT.log("locked balance for 10K contract storage");
+
+            let account_manager = Self::account_manager();
+            let mut owner_account = account_manager.registered_account_near_data(owner.as_ref());
+            let owner_balance = ContractOwnershipComponent.ops_owner_balance().available;
+            owner_account.incr_near_balance(owner_balance);
+            owner_account.save();
+
+            LOG_EVENT_DEPLOYMENT.log(format!("owner balance = {}", owner_balance));
+        }
+
+        FungibleTokenService::deploy(FungibleTokenConfig {
+            metadata: metadata.clone(),
+            token_supply: 0,
+        });
+        // the initial supply is minted to the owner to ensure the owner's balance and the token
+        // supply are in sync
+        if *token_supply > 0 {
+            Self::ft().ft_mint(owner.as_ref(), token_supply);
+        }
+        LOG_EVENT_DEPLOYMENT.log(format!(
+            "FungibleTokenComponent {}",
+            serde_json::to_string_pretty(&metadata).unwrap()
+        ));
+
+        Self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near_test::*;
+
+    #[test]
+    fn deploy() {
+        // Arrange
+        let mut ctx = TestCtx::new("owner");
+        ctx.apply();
+
+        // Act
+        let contract = Contract::deploy(
+            "Pearl".to_string(),
+            "PEARL".to_string(),
+            1_000_000.into(),
+            Some(6),
+            None,
+            None,
+        );
+
+        // Assert
+        assert_eq!(contract.ft_total_supply(), 1_000_000.into());
+        assert_eq!(
+            contract.ft_balance_of("owner".try_into().unwrap()),
+            1_000_000.into()
+        );
+        let metadata = contract.ft_metadata();
+        assert_eq!(metadata.symbol, Symbol("PEARL".to_string()));
+        assert_eq!(metadata.decimals, 6);
+        assert_eq!(contract.ops_owner(), "owner");
+        // the initial supply mint is the first event
+        assert_eq!(contract.ops_ft_last_sequence(), 1.into());
+    }
+}
@@ -5,42 +5,52 @@
 //!   - config: [`FungibleTokenConfig`]
 //! - use [`FungibleTokenComponent::register_storage_management_event_handler`]  to register event
 //!   handler for [`StorageManagementEvent::PreUnregister`] which integrates with [`AccountManagementComponent`]
+//! - when the `strict-invariants` feature is enabled, `TokenInvariants` are checked when the
+//!   component is dropped, i.e., after every contract call
 
 use crate::{
     contract::operator::{FungibleTokenOperator, OperatorCommand},
-    FungibleToken, FungibleTokenMetadataProvider, Memo, Metadata, ResolveTransferCall, TokenAmount,
-    TokenService, TransferCallMessage, ERR_CODE_FT_RESOLVE_TRANSFER, LOG_EVENT_FT_BURN,
-    LOG_EVENT_FT_LOCK, LOG_EVENT_FT_MINT, LOG_EVENT_FT_TRANSFER,
+    FungibleToken, FungibleTokenMetadataProvider, Memo, Metadata, Name, Reference,
+    ResolveTransferCall, Symbol, TokenAmount, TokenService, TransferCallMessage,
+    ERR_CODE_FT_RESOLVE_TRANSFER, LOG_EVENT_FT_BURN, LOG_EVENT_FT_LOCK, LOG_EVENT_FT_MINT,
+    LOG_EVENT_FT_REFERENCE, LOG_EVENT_FT_RENAME, LOG_EVENT_FT_SNAPSHOT, LOG_EVENT_FT_TRANSFER,
     LOG_EVENT_FT_TRANSFER_CALL_FAILURE, LOG_EVENT_FT_TRANSFER_CALL_PARTIAL_REFUND,
     LOG_EVENT_FT_TRANSFER_CALL_RECEIVER_DEBIT, LOG_EVENT_FT_TRANSFER_CALL_REFUND_NOT_APPLIED,
-    LOG_EVENT_FT_TRANSFER_CALL_SENDER_CREDIT, LOG_EVENT_FT_UNLOCK,
+    LOG_EVENT_FT_TRANSFER_CALL_SENDER_CREDIT, LOG_EVENT_FT_UNLOCK, MAX_TOKEN_BALANCE_SNAPSHOTS,
+};
+use crate::{
+    PreBalanceChangeEvent, PreTransferEvent, ResolveScreenedTransfer, ResolveScreenedTransferArgs,
+    ScreenTransferArgs, TransferHook, LOG_EVENT_FT_TRANSFER_REJECTED,
 };
 use oysterpack_smart_account_management::{
     components::account_management::AccountManagementComponent, AccountRepository,
-    AccountStorageEvent, StorageManagementEvent, ERR_ACCOUNT_NOT_REGISTERED,
-    ERR_CODE_UNREGISTER_FAILURE,
+    AccountStorageEvent, PermissionsAuditEvent, PermissionsAuditLog, StorageManagementEvent,
+    ERR_ACCOUNT_NOT_REGISTERED, ERR_CODE_UNREGISTER_FAILURE,
 };
 use oysterpack_smart_near::eventbus::{self, post};
 use oysterpack_smart_near::near_sdk::{
     borsh::{BorshDeserialize, BorshSerialize},
     env,
-    json_types::ValidAccountId,
+    json_types::{Base64VecU8, ValidAccountId},
     serde::{Deserialize, Serialize},
-    serde_json, AccountId, Promise, PromiseResult,
+    serde_json, AccountId, Promise, PromiseOrValue, PromiseResult,
 };
 use oysterpack_smart_near::{
-    asserts::{
-        assert_yocto_near_attached, ERR_CODE_BAD_REQUEST, ERR_INSUFFICIENT_FUNDS, ERR_INVALID,
-    },
+    asserts::{assert_sufficient_funds, ERR_CODE_BAD_REQUEST, ERR_INVALID},
+    deposit_policy::{assert_deposit_policy, Method},
     lazy_static::lazy_static,
-    {component::Deploy, data::Object, to_valid_account_id, Hash, TERA},
+    {
+        component::{Component, Deploy},
+        data::{ComponentStorageUsage, Object},
+        Hash, TERA,
+    },
 };
 use oysterpack_smart_near::{
     component::ManagesAccountData,
-    domain::{ActionType, ByteLen, Gas, SenderIsReceiver, StorageUsage, TGas, TransactionResource},
+    domain::{Gas, GasEstimate, ReceiptGasBudget, StorageUsage, TGas},
 };
 
-use std::{fmt::Debug, ops::Deref, ops::DerefMut, sync::Mutex};
+use std::{convert::TryInto, fmt::Debug, ops::Deref, sync::Mutex};
 
 pub struct FungibleTokenComponent<T>
 where
@@ -58,6 +68,19 @@ where
     }
 }
 
+/// checks the token invariants after the contract call - skipped if the call is already panicking
+#[cfg(feature = "strict-invariants")]
+impl<T> Drop for FungibleTokenComponent<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default,
+{
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            TokenInvariants::load().assert();
+        }
+    }
+}
+
 impl<T> Deploy for FungibleTokenComponent<T>
 where
     T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default,
@@ -65,8 +88,10 @@ where
     type Config = FungibleTokenConfig;
 
     fn deploy(config: Self::Config) {
-        MetadataObject::new(METADATA_KEY, config.metadata.clone()).save();
+        Self::new_state(config.metadata.clone()).save();
         TokenSupply::new(TOKEN_SUPPLY, config.token_supply).save();
+        #[cfg(feature = "strict-invariants")]
+        Object::<u128, u128>::new(INITIAL_TOKEN_SUPPLY_KEY, config.token_supply).save();
     }
 }
 
@@ -88,35 +113,21 @@ where
         amount: TokenAmount,
         memo: Option<Memo>,
     ) {
-        assert_yocto_near_attached();
-        ERR_CODE_BAD_REQUEST.assert(|| *amount > 0, || "transfer amount cannot be zero");
-
+        assert_deposit_policy(Method::FtTransfer);
         let sender_id = &env::predecessor_account_id();
-        ERR_CODE_BAD_REQUEST.assert(
-            || sender_id != receiver_id.as_ref(),
-            || "sender and receiver cannot be the same",
-        );
-
-        ERR_ACCOUNT_NOT_REGISTERED.assert_with_message(
-            || self.account_manager.account_exists(sender_id),
-            || "sender account is not registered",
-        );
-        ERR_ACCOUNT_NOT_REGISTERED.assert_with_message(
-            || self.account_manager.account_exists(receiver_id.as_ref()),
-            || "receiver account is not registered",
-        );
-
-        let sender_balance = self.ft_balance_of(to_valid_account_id(sender_id));
-        ERR_INSUFFICIENT_FUNDS.assert(|| *sender_balance >= *amount);
+        self.pre_transfer(sender_id, receiver_id.as_ref(), amount, &memo);
+        if let Some(hook) = TransferHook::load() {
+            Self::screen_transfer(hook, sender_id, receiver_id.as_ref(), amount, memo, None);
+            return;
+        }
 
         // transfer the tokens
-        AccountFTBalance::set_balance(sender_id, *sender_balance - *amount);
-        let receiver_balance = self.ft_balance_of(receiver_id.clone());
-        AccountFTBalance::set_balance(receiver_id.as_ref(), *receiver_balance + *amount);
+        let sender_balance = AccountTokenBalance::balance(sender_id);
+        AccountTokenBalance::set_balance(sender_id, sender_balance - *amount);
+        let receiver_balance = AccountTokenBalance::balance(receiver_id.as_ref());
+        AccountTokenBalance::set_balance(receiver_id.as_ref(), receiver_balance + *amount);
 
-        if let Some(memo) = memo {
-            LOG_EVENT_FT_TRANSFER.log(memo);
-        }
+        log_transfer(sender_id, receiver_id.as_ref(), amount, memo.as_ref());
     }
 
     fn ft_transfer_call(
@@ -126,6 +137,19 @@ where
         memo: Option<Memo>,
         msg: TransferCallMessage,
     ) -> Promise {
+        assert_deposit_policy(Method::FtTransferCall);
+        if let Some(hook) = TransferHook::load() {
+            let sender_id = &env::predecessor_account_id();
+            self.pre_transfer(sender_id, receiver_id.as_ref(), amount, &memo);
+            return Self::screen_transfer(
+                hook,
+                sender_id,
+                receiver_id.as_ref(),
+                amount,
+                memo,
+                Some(msg),
+            );
+        }
         self.ft_transfer(receiver_id.clone(), amount, memo);
 
         self.create_promise_transfer_receiver_ft_on_transfer(
@@ -133,6 +157,7 @@ where
             receiver_id.as_ref(),
             amount,
             msg,
+            None,
         )
     }
 
@@ -141,7 +166,14 @@ where
     }
 
     fn ft_balance_of(&self, account_id: ValidAccountId) -> TokenAmount {
-        AccountFTBalance::balance_of(account_id.as_ref())
+        AccountTokenBalance::balance(account_id.as_ref()).into()
+    }
+
+    fn ft_balances_of(&self, accounts: Vec<ValidAccountId>) -> Vec<TokenAmount> {
+        accounts
+            .into_iter()
+            .map(|account_id| self.ft_balance_of(account_id))
+            .collect()
     }
 }
 
@@ -151,7 +183,11 @@ where
 {
     fn ft_operator_command(&mut self, command: OperatorCommand) {
         self.account_manager.assert_operator();
-        let mut metadata = MetadataObject::load(&METADATA_KEY).unwrap();
+        PermissionsAuditLog::record(PermissionsAuditEvent::OperatorCommand {
+            operation: "ft_operator_command".to_string(),
+            args: serde_json::to_string(&command).unwrap(),
+        });
+        let mut metadata = Self::load_state().unwrap();
         match command {
             OperatorCommand::SetIcon(icon) => metadata.icon = Some(icon),
             OperatorCommand::ClearIcon => metadata.icon = None,
@@ -164,6 +200,8 @@ where
                 metadata.reference_hash = None;
             }
             OperatorCommand::SetTransferCallbackGas(gas) => set_transfer_callback_gas(gas),
+            OperatorCommand::SetTransferHook(account_id) => TransferHook::save(account_id.as_ref()),
+            OperatorCommand::ClearTransferHook => TransferHook::clear(),
         }
         metadata.save();
     }
@@ -171,6 +209,10 @@ where
     fn ft_operator_transfer_callback_gas(&self) -> Gas {
         transfer_callback_gas()
     }
+
+    fn ft_operator_transfer_hook(&self) -> Option<AccountId> {
+        TransferHook::load()
+    }
 }
 
 const TRANSFER_CALLBACK_GAS_KEY: u128 = 195443795528357944121615941514104351048;
@@ -195,45 +237,63 @@ where
         ERR_INVALID.assert(|| *amount > 0, || "mint amount cannot be zero");
         ERR_ACCOUNT_NOT_REGISTERED.assert(|| self.account_manager.account_exists(account_id));
 
-        let mut ft_balance = AccountFTBalance::get(account_id);
-        *ft_balance += *amount;
-        ft_balance.save(account_id);
+        let ft_balance = AccountTokenBalance::balance(account_id) + *amount;
+        AccountTokenBalance::set_balance(account_id, ft_balance);
 
         let mut token_supply = token_supply();
         *token_supply += *amount;
         token_supply.save();
 
-        LOG_EVENT_FT_MINT.log(format!("account: {}, amount: {}", account_id, amount));
-        (*ft_balance).into()
+        LOG_EVENT_FT_MINT.log(format!(
+            "account: {}, amount: {}, seq: {}",
+            account_id,
+            amount,
+            next_event_sequence()
+        ));
+        ft_balance.into()
     }
 
     fn ft_burn(&mut self, account_id: &str, amount: TokenAmount) -> TokenAmount {
         ERR_INVALID.assert(|| *amount > 0, || "burn amount cannot be zero");
         ERR_ACCOUNT_NOT_REGISTERED.assert(|| self.account_manager.account_exists(account_id));
 
-        let mut ft_balance = AccountFTBalance::load(account_id).unwrap();
-        let (available, locked) = *ft_balance.0;
+        let (available, locked) = AccountTokenBalance::balances(account_id);
         ERR_INVALID.assert(
             || (available + locked) >= *amount,
             || "account has insufficient funds",
         );
-        *ft_balance.0 = (available + locked - *amount, locked.saturating_sub(*amount));
-        ft_balance.save(account_id);
+        // locked tokens are burned first
+        let locked_balance = locked.saturating_sub(*amount);
+        let ft_balance = available + locked - *amount - locked_balance;
+        AccountTokenBalance::set_balances(account_id, ft_balance, locked_balance);
+
+        burn_tokens(*amount);
+        log_burn(account_id, *amount);
+        ft_balance.into()
+    }
+
+    fn ft_burn_available(&mut self, account_id: &str, amount: TokenAmount) -> TokenAmount {
+        ERR_INVALID.assert(|| *amount > 0, || "burn amount cannot be zero");
+        ERR_ACCOUNT_NOT_REGISTERED.assert(|| self.account_manager.account_exists(account_id));
+
+        let balance = AccountTokenBalance::balance(account_id);
+        ERR_INVALID.assert(|| balance >= *amount, || "account has insufficient funds");
+        let ft_balance = balance - *amount;
+        AccountTokenBalance::set_balance(account_id, ft_balance);
 
         burn_tokens(*amount);
-        LOG_EVENT_FT_BURN.log(format!("account: {}, amount: {}", account_id, amount));
-        (*ft_balance).into()
+        log_burn(account_id, *amount);
+        ft_balance.into()
     }
 
     fn ft_burn_all(&mut self, account_id: &str) {
-        if let Some(mut ft_balance) = AccountFTBalance::load(account_id) {
-            let (available, locked) = *ft_balance.0;
-            *ft_balance.0 = (0, 0);
-            ft_balance.save(account_id);
+        let (available, locked) = AccountTokenBalance::balances(account_id);
+        let amount = available + locked;
+        if amount > 0 {
+            AccountTokenBalance::set_balances(account_id, 0, 0);
 
-            let amount = available + locked;
             burn_tokens(amount);
-            LOG_EVENT_FT_BURN.log(format!("account: {}, amount: {}", account_id, amount));
+            log_burn(account_id, amount);
         }
     }
 
@@ -241,29 +301,21 @@ where
         ERR_INVALID.assert(|| *amount > 0, || "lock amount cannot be zero");
         ERR_ACCOUNT_NOT_REGISTERED.assert(|| self.account_manager.account_exists(account_id));
 
-        if let Some(mut ft_balance) = AccountFTBalance::load(account_id) {
-            let (available, locked) = *ft_balance.0;
-            ERR_INSUFFICIENT_FUNDS.assert(|| available >= *amount);
-            *ft_balance.0 = (available - *amount, locked + *amount);
-            ft_balance.0.save();
+        let (available, locked) = AccountTokenBalance::balances(account_id);
+        assert_sufficient_funds(amount, available.into());
+        AccountTokenBalance::set_balances(account_id, available - *amount, locked + *amount);
 
-            LOG_EVENT_FT_LOCK.log(format!("account: {}, amount: {}", account_id, amount));
-        } else {
-            ERR_INSUFFICIENT_FUNDS.panic();
-        }
+        LOG_EVENT_FT_LOCK.log(format!("account: {}, amount: {}", account_id, amount));
     }
 
     fn ft_lock_all(&mut self, account_id: &str) {
         ERR_ACCOUNT_NOT_REGISTERED.assert(|| self.account_manager.account_exists(account_id));
 
-        if let Some(mut ft_balance) = AccountFTBalance::load(account_id) {
-            let (available, locked) = *ft_balance.0;
-            if available > 0 {
-                *ft_balance.0 = (0, available + locked);
-                ft_balance.0.save();
+        let (available, locked) = AccountTokenBalance::balances(account_id);
+        if available > 0 {
+            AccountTokenBalance::set_balances(account_id, 0, available + locked);
 
-                LOG_EVENT_FT_LOCK.log(format!("account: {}, amount: {}", account_id, available));
-            }
+            LOG_EVENT_FT_LOCK.log(format!("account: {}, amount: {}", account_id, available));
         }
     }
 
@@ -271,32 +323,102 @@ where
         ERR_INVALID.assert(|| *amount > 0, || "unlock amount cannot be zero");
         ERR_ACCOUNT_NOT_REGISTERED.assert(|| self.account_manager.account_exists(account_id));
 
-        if let Some(mut ft_balance) = AccountFTBalance::load(account_id) {
-            let (available, locked) = *ft_balance.0;
-            ERR_INSUFFICIENT_FUNDS.assert(|| locked >= *amount);
-            *ft_balance.0 = (available + *amount, locked - *amount);
-            ft_balance.0.save();
+        let (available, locked) = AccountTokenBalance::balances(account_id);
+        assert_sufficient_funds(amount, locked.into());
+        AccountTokenBalance::set_balances(account_id, available + *amount, locked - *amount);
 
-            LOG_EVENT_FT_UNLOCK.log(format!("account: {}, amount: {}", account_id, amount));
-        }
+        LOG_EVENT_FT_UNLOCK.log(format!("account: {}, amount: {}", account_id, amount));
     }
 
     fn ft_unlock_all(&mut self, account_id: &str) {
         ERR_ACCOUNT_NOT_REGISTERED.assert(|| self.account_manager.account_exists(account_id));
 
-        if let Some(mut ft_balance) = AccountFTBalance::load(account_id) {
-            let (available, locked) = *ft_balance.0;
-            if locked > 0 {
-                *ft_balance.0 = (available + locked, 0);
-                ft_balance.0.save();
+        let (available, locked) = AccountTokenBalance::balances(account_id);
+        if locked > 0 {
+            AccountTokenBalance::set_balances(account_id, available + locked, 0);
 
-                LOG_EVENT_FT_UNLOCK.log(format!("account: {}, amount: {}", account_id, locked));
-            }
+            LOG_EVENT_FT_UNLOCK.log(format!("account: {}, amount: {}", account_id, locked));
+        }
+    }
+
+    fn ft_locked_balance(&self, account_id: &str) -> Option<TokenAmount> {
+        if !self.account_manager.account_exists(account_id) {
+            return None;
+        }
+        match AccountTokenBalance::balances(account_id).1 {
+            0 => None,
+            locked_balance => Some(locked_balance.into()),
+        }
+    }
+
+    fn ft_snapshot(&mut self) -> u64 {
+        let snapshot_id = AccountTokenBalanceSnapshots::take();
+        LOG_EVENT_FT_SNAPSHOT.log(format!(
+            "snapshot_id: {}, total_supply: {}",
+            snapshot_id,
+            *token_supply()
+        ));
+        snapshot_id
+    }
+
+    fn ft_last_sequence(&self) -> u64 {
+        last_event_sequence()
+    }
+
+    fn ft_balance_at(&self, account_id: &str, snapshot_id: u64) -> Option<TokenAmount> {
+        ERR_INVALID.assert(
+            || snapshot_id > 0 && snapshot_id <= AccountTokenBalanceSnapshots::current_id(),
+            || "snapshot does not exist",
+        );
+        ERR_INVALID.assert(
+            || snapshot_id > AccountTokenBalanceSnapshots::expired_id(),
+            || "snapshot has expired",
+        );
+        if self.account_manager.account_exists(account_id) {
+            Some(AccountTokenBalanceSnapshots::balance_at(account_id, snapshot_id).into())
+        } else {
+            None
         }
     }
 
-    fn ft_locked_balance(&mut self, account_id: &str) -> Option<TokenAmount> {
-        AccountFTBalance::load(account_id).map(|balance| (*balance.0).1.into())
+    fn ft_rename(&mut self, name: Name, symbol: Symbol) {
+        ERR_INVALID.assert(|| !name.trim().is_empty(), || "name cannot be blank");
+        ERR_INVALID.assert(|| !symbol.trim().is_empty(), || "symbol cannot be blank");
+        let mut metadata = Self::load_state().unwrap();
+        LOG_EVENT_FT_RENAME.log(format!(
+            "name: {} -> {}, symbol: {} -> {}",
+            metadata.name, name, metadata.symbol, symbol
+        ));
+        metadata.name = name;
+        metadata.symbol = symbol;
+        metadata.save();
+    }
+
+    fn ft_set_reference(&mut self, reference: Reference, reference_hash: Base64VecU8) {
+        ERR_INVALID.assert(
+            || !reference.trim().is_empty(),
+            || "reference cannot be blank",
+        );
+        ERR_INVALID.assert(
+            || reference_hash.0.len() == Hash::LENGTH,
+            || "reference_hash must be 32 bytes",
+        );
+        let reference_hash = Hash(reference_hash.0.try_into().unwrap());
+        LOG_EVENT_FT_REFERENCE.log(format!(
+            "reference: {}, reference_hash: {}",
+            reference, reference_hash
+        ));
+        let mut metadata = Self::load_state().unwrap();
+        metadata.reference = Some(reference);
+        metadata.reference_hash = Some(reference_hash);
+        metadata.save();
+    }
+
+    fn ft_verify_reference(&self, payload: &str) -> bool {
+        Self::load_state()
+            .unwrap()
+            .reference_hash
+            .is_some_and(|reference_hash| env::sha256(payload.as_bytes()) == reference_hash.0)
     }
 }
 
@@ -307,9 +429,16 @@ where
     fn account_storage_min() -> StorageUsage {
         let account_id = "19544499980228477895959808916967586760";
         let initial_storage = env::storage_usage();
-        AccountFTBalance::set_balance(account_id, 1);
+        // both the available and locked balance records are accounted for because an account's
+        // tokens can be locked at any time, e.g., by a STAKE lock
+        // the balance records are saved directly to bypass balance snapshots
+        let key = AccountTokenBalance::balance_key(account_id);
+        let locked_key = AccountTokenBalance::locked_balance_key(account_id);
+        AccountTokenBalance::save_balance(key, 1);
+        AccountTokenBalance::save_balance(locked_key, 1);
         let account_storage_usage = env::storage_usage() - initial_storage;
-        AccountFTBalance::set_balance(account_id, 0);
+        AccountTokenBalance::save_balance(key, 0);
+        AccountTokenBalance::save_balance(locked_key, 0);
         account_storage_usage.into()
     }
 }
@@ -318,16 +447,178 @@ impl<T> FungibleTokenComponent<T>
 where
     T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default,
 {
+    /// Transfers tokens from the specified sender account and notifies the receiver via `ft_on_transfer`,
+    /// i.e., this is used by the contract to transfer tokens that it owns using the
+    /// [`FungibleToken::ft_transfer_call`] protocol.
+    /// - the receiver `ft_on_transfer` call is allotted the specified gas
+    /// - any refund is credited back to the sender account
+    ///
+    /// ## Panics
+    /// - if the amount is zero
+    /// - if either account is not registered
+    /// - if the sender account has insufficient funds
+    pub fn ft_transfer_call_from(
+        &mut self,
+        sender_id: &str,
+        receiver_id: &str,
+        amount: TokenAmount,
+        msg: TransferCallMessage,
+        gas: Gas,
+    ) -> Promise {
+        self.transfer(sender_id, receiver_id, amount);
+        self.create_promise_transfer_receiver_ft_on_transfer(
+            sender_id,
+            receiver_id,
+            amount,
+            msg,
+            Some(gas),
+        )
+    }
+
+    /// Transfers tokens from the specified sender account to the receiver account
+    ///
+    /// ## Panics
+    /// - if the amount is zero
+    /// - if either account is not registered
+    /// - if the sender account has insufficient funds
+    pub fn transfer(&mut self, sender_id: &str, receiver_id: &str, amount: TokenAmount) {
+        ERR_INVALID.assert(|| *amount > 0, || "transfer amount cannot be zero");
+        ERR_ACCOUNT_NOT_REGISTERED.assert_with_message(
+            || self.account_manager.account_exists(sender_id),
+            || "sender account is not registered",
+        );
+        ERR_ACCOUNT_NOT_REGISTERED.assert_with_message(
+            || self.account_manager.account_exists(receiver_id),
+            || "receiver account is not registered",
+        );
+
+        let sender_balance = AccountTokenBalance::balance(sender_id);
+        assert_sufficient_funds(amount, sender_balance.into());
+        eventbus::post(&PreTransferEvent {
+            sender_id: sender_id.to_string(),
+            receiver_id: receiver_id.to_string(),
+            amount,
+            memo: None,
+        });
+        AccountTokenBalance::set_balance(sender_id, sender_balance - *amount);
+        let receiver_balance = AccountTokenBalance::balance(receiver_id);
+        AccountTokenBalance::set_balance(receiver_id, receiver_balance + *amount);
+        log_transfer(sender_id, receiver_id, amount, None);
+    }
+
+    /// validates the account initiated transfer and then posts the [`PreTransferEvent`]
+    fn pre_transfer(
+        &self,
+        sender_id: &str,
+        receiver_id: &str,
+        amount: TokenAmount,
+        memo: &Option<Memo>,
+    ) {
+        ERR_CODE_BAD_REQUEST.assert(|| *amount > 0, || "transfer amount cannot be zero");
+        ERR_CODE_BAD_REQUEST.assert(
+            || sender_id != receiver_id,
+            || "sender and receiver cannot be the same",
+        );
+
+        ERR_ACCOUNT_NOT_REGISTERED.assert_with_message(
+            || self.account_manager.account_exists(sender_id),
+            || "sender account is not registered",
+        );
+        ERR_ACCOUNT_NOT_REGISTERED.assert_with_message(
+            || self.account_manager.account_exists(receiver_id),
+            || "receiver account is not registered",
+        );
+
+        let sender_balance = AccountTokenBalance::balance(sender_id);
+        assert_sufficient_funds(amount, sender_balance.into());
+
+        eventbus::post(&PreTransferEvent {
+            sender_id: sender_id.to_string(),
+            receiver_id: receiver_id.to_string(),
+            amount,
+            memo: memo.clone(),
+        });
+    }
+
+    /// Locks the transfer amount on the sender's account and submits the transfer to the hook
+    /// contract for screening
+    /// - the transfer is applied by [`ResolveScreenedTransfer::ft_resolve_screened_transfer`]
+    fn screen_transfer(
+        hook: AccountId,
+        sender_id: &str,
+        receiver_id: &str,
+        amount: TokenAmount,
+        memo: Option<Memo>,
+        msg: Option<TransferCallMessage>,
+    ) -> Promise {
+        let (available, locked) = AccountTokenBalance::balances(sender_id);
+        AccountTokenBalance::set_balances(sender_id, available - *amount, locked + *amount);
+
+        let ft_screen_transfer = b"ft_screen_transfer".to_vec();
+        let ft_screen_transfer_args = serde_json::to_vec(&ScreenTransferArgs {
+            sender_id: sender_id.to_string(),
+            receiver_id: receiver_id.to_string(),
+            amount,
+            memo: memo.clone(),
+        })
+        .expect("");
+        let ft_screen_transfer_bytes: u64 =
+            (ft_screen_transfer.len() + ft_screen_transfer_args.len()) as u64;
+
+        let ft_resolve_screened_transfer = b"ft_resolve_screened_transfer".to_vec();
+        let ft_resolve_screened_transfer_args = serde_json::to_vec(&ResolveScreenedTransferArgs {
+            sender_id: sender_id.to_string(),
+            receiver_id: receiver_id.to_string(),
+            amount,
+            memo,
+            msg,
+        })
+        .expect("");
+        let ft_resolve_screened_transfer_bytes: u64 =
+            (ft_resolve_screened_transfer.len() + ft_resolve_screened_transfer_args.len()) as u64;
+
+        // compute how much gas is needed to complete this call and the screen transfer call
+        // and then give the rest of the gas to the callback because it may need to resume the
+        // transfer call workflow
+        let callback_gas = ReceiptGasBudget::new()
+            .function_call(ft_screen_transfer_bytes, TransferHook::GAS)
+            // the screen transfer result is a bool
+            .callback(ft_resolve_screened_transfer_bytes, 5, Gas::ZERO)
+            .compute(TGas(5).into()) // to complete this call
+            .assert_remaining()
+            .value();
+
+        // create the function call chain
+        {
+            let ft_screen_transfer = Promise::new(hook).function_call(
+                ft_screen_transfer,
+                ft_screen_transfer_args,
+                0,
+                TransferHook::GAS.value(),
+            );
+            let ft_resolve_screened_transfer = Promise::new(env::current_account_id())
+                .function_call(
+                    ft_resolve_screened_transfer,
+                    ft_resolve_screened_transfer_args,
+                    0,
+                    callback_gas,
+                );
+            ft_screen_transfer.then(ft_resolve_screened_transfer)
+        }
+    }
+
+    /// if `ft_on_transfer_gas` is not specified, then all remaining gas is allotted to the receiver call
     fn create_promise_transfer_receiver_ft_on_transfer(
         &self,
         sender_id: &str,
         receiver_id: &str,
         amount: TokenAmount,
         msg: TransferCallMessage,
+        ft_on_transfer_gas: Option<Gas>,
     ) -> Promise {
         let ft_on_transfer = b"ft_on_transfer".to_vec();
         let ft_on_transfer_args = serde_json::to_vec(&OnTransferArgs {
-            sender_id: env::predecessor_account_id(),
+            sender_id: sender_id.to_string(),
             amount,
             msg,
         })
@@ -346,37 +637,18 @@ where
 
         // compute how much gas is needed to complete this call and the resolve transfer callback
         // and then give the rest of the gas to the transfer receiver call
-        let ft_on_transfer_receipt_action_cost = {
-            let action_receipt = TransactionResource::ActionReceipt(SenderIsReceiver(false));
-            let func_call_action = TransactionResource::Action(ActionType::FunctionCall(
-                SenderIsReceiver(false),
-                ByteLen(ft_on_transfer_bytes),
-            ));
-            Gas::compute(vec![(action_receipt, 1), (func_call_action, 1)])
+        let ft_on_transfer_gas = match ft_on_transfer_gas {
+            Some(gas) => gas.value(),
+            None => ReceiptGasBudget::ft_transfer_call(
+                ft_on_transfer_bytes,
+                Gas::ZERO,
+                ft_resolve_transfer_call_bytes,
+                transfer_callback_gas(),
+            )
+            .compute(TGas(5).into()) // to complete this call
+            .assert_remaining()
+            .value(),
         };
-        let ft_resolve_transfer_call_receipt_action_cost = {
-            let action_receipt = TransactionResource::ActionReceipt(SenderIsReceiver(true));
-            let func_call_action = TransactionResource::Action(ActionType::FunctionCall(
-                SenderIsReceiver(true),
-                ByteLen(ft_resolve_transfer_call_bytes),
-            ));
-            // byte len for transfer amount is set to 100 because even though the underlying type
-            // is u128, it is marshalled as a string. Thus the number of bytes will vary depending on
-            // the amount value - we'll pick 100 to be conservative.
-            let data_receipt =
-                TransactionResource::DataReceipt(SenderIsReceiver(false), ByteLen(100));
-            Gas::compute(vec![
-                (action_receipt, 1),
-                (func_call_action, 1),
-                (data_receipt, 1),
-            ])
-        };
-        let ft_on_transfer_gas = env::prepaid_gas()
-            - env::used_gas()
-            - transfer_callback_gas().value()
-            - ft_on_transfer_receipt_action_cost.value()
-            - ft_resolve_transfer_call_receipt_action_cost.value()
-            - 5 * TERA; // to complete this call;
 
         // create the function call chain
         {
@@ -396,6 +668,45 @@ where
         }
     }
 
+    /// Returns the minimum gas that should be attached to the FT methods that create receipts
+    /// - the estimates account for the current transfer callback gas and transfer hook config
+    pub fn gas_estimates(&self) -> Vec<GasEstimate> {
+        // conservatively overestimated
+        const METHOD_COMPUTE_GAS: TGas = TGas(10);
+        const MIN_FT_ON_TRANSFER_GAS: TGas = TGas(10);
+        const RESOLVE_SCREENED_TRANSFER_COMPUTE_GAS: TGas = TGas(10);
+
+        let ft_transfer_call = ReceiptGasBudget::ft_transfer_call(
+            ReceiptGasBudget::CALLBACK_BYTES,
+            MIN_FT_ON_TRANSFER_GAS.into(),
+            ReceiptGasBudget::CALLBACK_BYTES,
+            transfer_callback_gas(),
+        )
+        .compute(TGas(5).into())
+        .total();
+        let (ft_transfer, ft_transfer_call) = if TransferHook::load().is_some() {
+            // when screened, the transfer is applied by the screen transfer callback
+            let screen_transfer = |callback_gas: Gas| {
+                ReceiptGasBudget::new()
+                    .function_call(ReceiptGasBudget::CALLBACK_BYTES, TransferHook::GAS)
+                    .callback(ReceiptGasBudget::CALLBACK_BYTES, 5, callback_gas)
+                    .compute(TGas(5).into())
+                    .total()
+            };
+            (
+                screen_transfer(RESOLVE_SCREENED_TRANSFER_COMPUTE_GAS.into()),
+                screen_transfer(ft_transfer_call + RESOLVE_SCREENED_TRANSFER_COMPUTE_GAS),
+            )
+        } else {
+            (Gas::ZERO, ft_transfer_call)
+        };
+
+        vec![
+            GasEstimate::new("ft_transfer", ft_transfer + METHOD_COMPUTE_GAS),
+            GasEstimate::new("ft_transfer_call", ft_transfer_call + METHOD_COMPUTE_GAS),
+        ]
+    }
+
     /// Used to register an event handler hook to handle account unregistrations
     ///
     /// can be safely called multiple times and will only register the event handler once
@@ -412,19 +723,22 @@ where
     /// When an account is forced unregistered, any tokens it owned will be burned, which reduces the total
     /// token supply.
     fn on_unregister_account(event: &StorageManagementEvent) {
-        if let StorageManagementEvent::PreUnregister { force, .. } = event {
-            let account_id = &env::predecessor_account_id();
-            if let Some(ft_balance) = AccountFTBalance::load(&account_id) {
+        if let StorageManagementEvent::PreUnregister { account_id, force } = event {
+            let (available, locked) = AccountTokenBalance::balances(account_id);
+            if available + locked > 0 {
                 ERR_CODE_UNREGISTER_FAILURE
                     .assert(|| *force, || "account has non-zero token balance");
-                let amount = *ft_balance;
-                AccountFTBalance::set_balance(account_id, 0);
+                let amount = available + locked;
+                AccountTokenBalance::set_balances(account_id, 0, 0);
                 burn_tokens(amount);
                 LOG_EVENT_FT_BURN.log(format!(
-                    "account forced unregistered with token balance: account: account: {}, amount: {}",
-                    account_id, amount
+                    "account forced unregistered with token balance: account: account: {}, amount: {}, seq: {}",
+                    account_id,
+                    amount,
+                    next_event_sequence()
                 ));
             }
+            AccountTokenBalanceSnapshots::delete(account_id);
         }
     }
 }
@@ -461,15 +775,130 @@ fn burn_tokens(amount: u128) {
     supply.save();
 }
 
-const METADATA_KEY: u128 = 19538272703993902201263844658248351047;
-type MetadataObject = Object<u128, Metadata>;
+/// sequence number that was assigned to the last mint, burn, or transfer event - zero means that no
+/// events have been emitted
+const EVENT_SEQUENCE_KEY: u128 = 1958151024530612894730175640298376514;
+type EventSequence = Object<u128, u64>;
+
+fn last_event_sequence() -> u64 {
+    EventSequence::load(&EVENT_SEQUENCE_KEY).map_or(0, |sequence| *sequence)
+}
+
+/// assigns the next event sequence number, which is included in the mint, burn, and transfer events
+/// to enable indexers to detect missed events
+fn next_event_sequence() -> u64 {
+    let sequence = last_event_sequence() + 1;
+    EventSequence::new(EVENT_SEQUENCE_KEY, sequence).save();
+    sequence
+}
+
+fn log_burn(account_id: &str, amount: u128) {
+    LOG_EVENT_FT_BURN.log(format!(
+        "account: {}, amount: {}, seq: {}",
+        account_id,
+        amount,
+        next_event_sequence()
+    ));
+}
+
+fn log_transfer(sender_id: &str, receiver_id: &str, amount: TokenAmount, memo: Option<&Memo>) {
+    let sequence = next_event_sequence();
+    match memo {
+        Some(memo) => LOG_EVENT_FT_TRANSFER.log(format!(
+            "sender: {}, receiver: {}, amount: {}, seq: {}, memo: {}",
+            sender_id, receiver_id, amount, sequence, memo
+        )),
+        None => LOG_EVENT_FT_TRANSFER.log(format!(
+            "sender: {}, receiver: {}, amount: {}, seq: {}",
+            sender_id, receiver_id, amount, sequence
+        )),
+    }
+}
+
+/// Token invariants that are checked after every contract call when the `strict-invariants` feature
+/// is enabled:
+/// - the sum of all account balances, including locked balances, plus the initial token supply
+///   must equal the total supply
+///
+/// The account balances total is only tracked while the feature is enabled. Thus, the feature must
+/// be enabled when the contract is deployed.
+#[cfg(feature = "strict-invariants")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenInvariants {
+    pub total_supply: TokenAmount,
+    /// token supply the contract was deployed with, which is not held by any account - see
+    /// [`FungibleTokenConfig::token_supply`]
+    pub initial_supply: TokenAmount,
+    pub account_balances_total: TokenAmount,
+}
+
+#[cfg(feature = "strict-invariants")]
+impl TokenInvariants {
+    pub fn load() -> Self {
+        Self {
+            total_supply: TokenSupply::load(&TOKEN_SUPPLY)
+                .map_or(0, |supply| *supply)
+                .into(),
+            initial_supply: Object::<u128, u128>::load(&INITIAL_TOKEN_SUPPLY_KEY)
+                .map_or(0, |supply| *supply)
+                .into(),
+            account_balances_total: AccountTokenBalancesTotal::load().into(),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        *self.total_supply == *self.account_balances_total + *self.initial_supply
+    }
+
+    /// ## Panics
+    /// with `ILLEGAL_STATE` error code and diagnostics if the invariants are violated
+    pub fn assert(&self) {
+        oysterpack_smart_near::asserts::ERR_ILLEGAL_STATE.assert(
+            || self.is_valid(),
+            || format!("token invariants violated: {:?}", self),
+        );
+    }
+}
+
+#[cfg(feature = "strict-invariants")]
+const ACCOUNT_TOKEN_BALANCES_TOTAL_KEY: u128 = 1958150993627481029374651820394857163;
+#[cfg(feature = "strict-invariants")]
+const INITIAL_TOKEN_SUPPLY_KEY: u128 = 1958150993627481029374651820394857164;
+
+/// sum of all account balances, including locked balances - see [`TokenInvariants`]
+#[cfg(feature = "strict-invariants")]
+struct AccountTokenBalancesTotal;
+
+#[cfg(feature = "strict-invariants")]
+impl AccountTokenBalancesTotal {
+    fn load() -> u128 {
+        Object::<u128, u128>::load(&ACCOUNT_TOKEN_BALANCES_TOTAL_KEY).map_or(0, |total| *total)
+    }
+
+    fn update(initial_balance: u128, balance: u128) {
+        if initial_balance == balance {
+            return;
+        }
+        let total = Self::load() + balance - initial_balance;
+        Object::<u128, u128>::new(ACCOUNT_TOKEN_BALANCES_TOTAL_KEY, total).save();
+    }
+}
+
+impl<T> Component for FungibleTokenComponent<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default,
+{
+    type State = Metadata;
+
+    const STATE_KEY: u128 = 19538272703993902201263844658248351047;
+}
 
 impl<T> FungibleTokenMetadataProvider for FungibleTokenComponent<T>
 where
     T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default,
 {
     fn ft_metadata(&self) -> Metadata {
-        MetadataObject::load(&METADATA_KEY).unwrap().deref().clone()
+        Self::load_state().unwrap().deref().clone()
     }
 }
 
@@ -519,40 +948,56 @@ where
         }
 
         // try to refund the refund amount from the receiver back to the sender
-        let refund_amount = if let Some(mut receiver_account_balance) =
-            AccountFTBalance::load(receiver_id.as_ref())
-        {
-            let refund_amount = if *receiver_account_balance < *refund_amount {
+        let receiver_account_balance = AccountTokenBalance::balance(receiver_id.as_ref());
+        let refund_amount = if receiver_account_balance > 0 {
+            let refund_amount = if receiver_account_balance < *refund_amount {
                 LOG_EVENT_FT_TRANSFER_CALL_PARTIAL_REFUND.log(
                     "partial refund will be applied because receiver account has insufficient fund",
                 );
-                *receiver_account_balance
+                receiver_account_balance
             } else {
                 *refund_amount
             };
-            *receiver_account_balance -= refund_amount;
-            receiver_account_balance.save(receiver_id.as_ref());
+            AccountTokenBalance::set_balance(
+                receiver_id.as_ref(),
+                receiver_account_balance - refund_amount,
+            );
 
             LOG_EVENT_FT_TRANSFER_CALL_RECEIVER_DEBIT.log(refund_amount);
 
-            match AccountFTBalance::load(sender_id.as_ref()) {
-                Some(mut sender_account_balance) => {
-                    *sender_account_balance += refund_amount;
-                    sender_account_balance.save(sender_id.as_ref());
+            match AccountTokenBalance::balance(sender_id.as_ref()) {
+                sender_account_balance if sender_account_balance > 0 => {
+                    AccountTokenBalance::set_balance(
+                        sender_id.as_ref(),
+                        sender_account_balance + refund_amount,
+                    );
                     LOG_EVENT_FT_TRANSFER_CALL_SENDER_CREDIT.log(refund_amount);
+                    log_transfer(
+                        receiver_id.as_ref(),
+                        sender_id.as_ref(),
+                        refund_amount.into(),
+                        None,
+                    );
                 }
-                None => {
+                _ => {
                     // - if balance is zero, then storage was cleaned up
                     // - the sender account most likely still exists, but might have been unregistered
                     //   while the transfer call workflow was in flight
                     if self.account_manager.account_exists(sender_id.as_ref()) {
-                        AccountFTBalance::set_balance(sender_id.as_ref(), refund_amount);
+                        AccountTokenBalance::set_balance(sender_id.as_ref(), refund_amount);
                         LOG_EVENT_FT_TRANSFER_CALL_SENDER_CREDIT.log(refund_amount);
+                        log_transfer(
+                            receiver_id.as_ref(),
+                            sender_id.as_ref(),
+                            refund_amount.into(),
+                            None,
+                        );
                     } else {
                         burn_tokens(refund_amount);
                         LOG_EVENT_FT_BURN.log(format!(
-                            "sender account is not registered: {}",
-                            refund_amount
+                            "sender account is not registered: {}, seq: {}",
+                            refund_amount,
+                            next_event_sequence()
                         ));
                     }
                 }
@@ -582,116 +1027,315 @@ where
     }
 }
 
-const FT_ACCOUNT_KEY: u128 = 1953845438124731969041175284518648060;
-type TokenBalance = u128;
-type LockedTokenBalance = u128;
-type AccountFTBalanceObject = Object<Hash, (TokenBalance, LockedTokenBalance)>;
-struct AccountFTBalance(AccountFTBalanceObject);
+impl<T> ResolveScreenedTransfer for FungibleTokenComponent<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq + Default,
+{
+    fn ft_resolve_screened_transfer(
+        &mut self,
+        sender_id: ValidAccountId,
+        receiver_id: ValidAccountId,
+        amount: TokenAmount,
+        memo: Option<Memo>,
+        msg: Option<TransferCallMessage>,
+    ) -> PromiseOrValue<TokenAmount> {
+        let approved = match env::promise_result(0) {
+            PromiseResult::Successful(value) => {
+                serde_json::from_slice::<bool>(&value).unwrap_or(false)
+            }
+            _ => false,
+        };
+        let sender_id = sender_id.as_ref();
+        let receiver_id = receiver_id.as_ref();
+        let log_rejected = |reason: &str| {
+            LOG_EVENT_FT_TRANSFER_REJECTED.log(format!(
+                "sender_id={}, receiver_id={}, amount={}, reason={}",
+                sender_id, receiver_id, amount, reason
+            ))
+        };
 
-impl AccountFTBalance {
-    fn ft_account_id_hash(account_id: &str) -> Hash {
-        Hash::from((account_id, FT_ACCOUNT_KEY))
+        let (available, locked) = AccountTokenBalance::balances(sender_id);
+        if locked < *amount {
+            log_rejected("locked transfer amount was released while the transfer was screened");
+            return PromiseOrValue::Value(0.into());
+        }
+        let rejected_reason = if !approved {
+            Some("transfer hook rejected the transfer")
+        } else if !self.account_manager.account_exists(receiver_id) {
+            Some("receiver account is not registered")
+        } else {
+            None
+        };
+        if let Some(reason) = rejected_reason {
+            AccountTokenBalance::set_balances(sender_id, available + *amount, locked - *amount);
+            log_rejected(reason);
+            return PromiseOrValue::Value(0.into());
+        }
+
+        // transfer the tokens
+        AccountTokenBalance::set_balances(sender_id, available, locked - *amount);
+        let receiver_balance = AccountTokenBalance::balance(receiver_id);
+        AccountTokenBalance::set_balance(receiver_id, receiver_balance + *amount);
+        log_transfer(sender_id, receiver_id, amount, memo.as_ref());
+
+        match msg {
+            Some(msg) => {
+                PromiseOrValue::Promise(self.create_promise_transfer_receiver_ft_on_transfer(
+                    sender_id,
+                    receiver_id,
+                    amount,
+                    msg,
+                    None,
+                ))
+            }
+            None => PromiseOrValue::Value(amount),
+        }
     }
+}
 
-    fn load(account_id: &str) -> Option<AccountFTBalance> {
-        let account_hash_id = AccountFTBalance::ft_account_id_hash(account_id);
-        AccountFTBalanceObject::load(&account_hash_id).map(Self)
+/// current snapshot ID - zero means that no snapshot has been taken
+const TOKEN_BALANCE_SNAPSHOT_ID_KEY: u128 = 1958043102559841376203871926505523317;
+const ACCOUNT_TOKEN_BALANCE_SNAPSHOTS_KEY: u128 = 1958043137781902648471302850211146094;
+
+type TokenBalanceSnapshotIdObject = Object<u128, u64>;
+/// (snapshot ID, total balance) records sorted by snapshot ID
+type AccountTokenBalanceSnapshotsObject = Object<Hash, Vec<(u64, TokenBalance)>>;
+
+/// Account token balance snapshots are recorded lazily:
+/// - taking a snapshot only increments the current snapshot ID
+/// - before an account balance is updated, the account's total balance is recorded for the current
+///   snapshot ID, unless it has already been recorded
+/// - the account balance at a snapshot is the balance recorded for the earliest snapshot ID that is
+///   greater than or equal to the snapshot ID - if there is none, then the balance has not changed
+///   since the snapshot was taken
+///
+/// Snapshots are recorded by [`AccountTokenBalance`] balance updates, which track the storage usage
+/// changes, i.e., the snapshot storage usage is charged to the account.
+///
+/// Only the most recent [`MAX_TOKEN_BALANCE_SNAPSHOTS`] snapshots are retained. When a balance is
+/// recorded, the account's records for expired snapshots are pruned, which caps the number of
+/// records per account.
+struct AccountTokenBalanceSnapshots;
+
+impl AccountTokenBalanceSnapshots {
+    fn key(account_id: &str) -> Hash {
+        Hash::from((account_id, ACCOUNT_TOKEN_BALANCE_SNAPSHOTS_KEY))
     }
 
-    fn get(account_id: &str) -> AccountFTBalance {
-        Self::load(account_id).unwrap_or_else(|| {
-            Self(AccountFTBalanceObject::new(
-                Self::ft_account_id_hash(account_id),
-                (0, 0),
-            ))
-        })
+    fn current_id() -> u64 {
+        TokenBalanceSnapshotIdObject::load(&TOKEN_BALANCE_SNAPSHOT_ID_KEY).map_or(0, |id| *id)
     }
 
-    fn balance_of(account_id: &str) -> TokenAmount {
-        Self::load(account_id).map_or(0.into(), |balance| (*balance).into())
+    /// snapshots with IDs less than or equal to the returned ID have expired
+    fn expired_id() -> u64 {
+        Self::current_id().saturating_sub(MAX_TOKEN_BALANCE_SNAPSHOTS)
     }
 
-    fn save(&self, account_id: &str) {
-        if *self.0 == (0, 0) {
-            let initial_storage_usage = env::storage_usage();
-            AccountFTBalanceObject::delete_by_key(self.0.key());
-            let storage_usage_change = initial_storage_usage - env::storage_usage();
-            if storage_usage_change > 0 {
-                post(&AccountStorageEvent::StorageUsageChanged(
-                    account_id.into(),
-                    (storage_usage_change as i64 * -1).into(),
-                ));
-            }
-        } else {
-            let initial_storage_usage = env::storage_usage();
-            self.0.save();
-            let storage_usage_change = env::storage_usage() - initial_storage_usage;
-            if storage_usage_change > 0 {
-                post(&AccountStorageEvent::StorageUsageChanged(
-                    account_id.into(),
-                    storage_usage_change.into(),
-                ));
-            }
-        }
+    /// returns the new snapshot ID
+    fn take() -> u64 {
+        let snapshot_id = Self::current_id() + 1;
+        TokenBalanceSnapshotIdObject::new(TOKEN_BALANCE_SNAPSHOT_ID_KEY, snapshot_id).save();
+        snapshot_id
+    }
+
+    fn snapshots(account_id: &str) -> Vec<(u64, TokenBalance)> {
+        AccountTokenBalanceSnapshotsObject::load(&Self::key(account_id))
+            .map_or_else(Vec::new, |snapshots| (*snapshots).clone())
+    }
+
+    /// records the account's current total balance for the current snapshot, if not already recorded
+    /// - must be invoked before the account balance is updated
+    fn record(account_id: &str) {
+        let snapshot_id = Self::current_id();
+        if snapshot_id == 0 {
+            return;
+        }
+        let mut snapshots = Self::snapshots(account_id);
+        if snapshots
+            .last()
+            .is_some_and(|(recorded_id, _)| *recorded_id == snapshot_id)
+        {
+            return;
+        }
+        let (balance, locked_balance) = AccountTokenBalance::balances(account_id);
+        // balances recorded for expired snapshots can no longer be looked up
+        let expired_id = Self::expired_id();
+        snapshots.retain(|(recorded_id, _)| *recorded_id > expired_id);
+        snapshots.push((snapshot_id, balance + locked_balance));
+        AccountTokenBalanceSnapshotsObject::new(Self::key(account_id), snapshots).save();
     }
 
-    /// tracks storage
-    /// - if balance is set to zero, then the balance record will be deleted from storage
-    fn set_balance(account_id: &str, balance: u128) {
-        let account_hash_id = Self::ft_account_id_hash(account_id);
-        match AccountFTBalanceObject::load(&account_hash_id) {
+    fn balance_at(account_id: &str, snapshot_id: u64) -> TokenBalance {
+        match Self::snapshots(account_id)
+            .into_iter()
+            .find(|(recorded_id, _)| *recorded_id >= snapshot_id)
+        {
+            Some((_, balance)) => balance,
             None => {
-                if balance == 0 {
-                    return;
-                }
-                let initial_storage_usage = env::storage_usage();
-                AccountFTBalanceObject::new(account_hash_id, (balance, 0)).save();
-                let storage_usage_change = env::storage_usage() - initial_storage_usage;
-                post(&AccountStorageEvent::StorageUsageChanged(
-                    account_id.into(),
-                    storage_usage_change.into(),
-                ));
-            }
-            Some(mut account_balance) => {
-                if balance == 0 {
-                    let initial_storage_usage = env::storage_usage();
-                    account_balance.delete();
-                    let storage_usage_change = initial_storage_usage - env::storage_usage();
-                    post(&AccountStorageEvent::StorageUsageChanged(
-                        account_id.into(),
-                        (storage_usage_change as i64 * -1).into(),
-                    ));
-                } else {
-                    *account_balance = (balance, (*account_balance).1);
-                    account_balance.save();
-                }
+                let (balance, locked_balance) = AccountTokenBalance::balances(account_id);
+                balance + locked_balance
             }
         }
     }
+
+    /// deletes the account's balance snapshots, which is done when the account is unregistered
+    /// - tracks storage usage
+    fn delete(account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        if AccountTokenBalanceSnapshotsObject::delete_by_key(&Self::key(account_id)) {
+            AccountTokenBalance::track_storage_usage(account_id, initial_storage_usage);
+        }
+    }
 }
 
-impl Deref for AccountFTBalance {
-    type Target = TokenBalance;
+/// legacy account balance record key, which stored the account's available and locked balances together
+/// - records stored under this key are migrated to [`AccountTokenBalance`] records
+const FT_ACCOUNT_KEY: u128 = 1953845438124731969041175284518648060;
+const ACCOUNT_TOKEN_BALANCE_KEY: u128 = 1957584931180212839870449061618513493;
+const ACCOUNT_LOCKED_TOKEN_BALANCE_KEY: u128 = 1957584958392810376049853366394752119;
 
-    fn deref(&self) -> &Self::Target {
-        let (balance, _) = self.0.deref();
-        balance
+type TokenBalance = u128;
+type LockedTokenBalance = u128;
+type LegacyAccountFTBalanceObject = Object<Hash, (TokenBalance, LockedTokenBalance)>;
+/// used to store both available and locked balances - the key determines which balance is stored
+type AccountTokenBalanceObject = Object<Hash, u128>;
+
+/// Account token balances are stored in their own lean records, which minimizes the storage that
+/// is read and written per transfer:
+/// - the available balance is stored under [`ACCOUNT_TOKEN_BALANCE_KEY`]
+/// - the locked balance is stored under [`ACCOUNT_LOCKED_TOKEN_BALANCE_KEY`], and only exists while
+///   the account has locked tokens
+///
+/// Zero balance records are deleted from storage. All storage usage changes are tracked, i.e.,
+/// [`AccountStorageEvent::StorageUsageChanged`] events are posted, and the changes are attributed
+/// to [`ComponentStorageUsage::FT_BALANCES`].
+///
+/// ## Migration
+/// Balances that were stored using the legacy layout ([`FT_ACCOUNT_KEY`]) are still readable, and
+/// are migrated the first time the account's balance is updated.
+struct AccountTokenBalance;
+
+impl AccountTokenBalance {
+    fn balance_key(account_id: &str) -> Hash {
+        Hash::from((account_id, ACCOUNT_TOKEN_BALANCE_KEY))
     }
-}
 
-impl DerefMut for AccountFTBalance {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        let (balance, _) = self.0.deref_mut();
-        balance
+    fn locked_balance_key(account_id: &str) -> Hash {
+        Hash::from((account_id, ACCOUNT_LOCKED_TOKEN_BALANCE_KEY))
     }
-}
 
-#[cfg(test)]
+    fn legacy_key(account_id: &str) -> Hash {
+        Hash::from((account_id, FT_ACCOUNT_KEY))
+    }
+
+    /// returns the account's available balance
+    fn balance(account_id: &str) -> TokenBalance {
+        match AccountTokenBalanceObject::load(&Self::balance_key(account_id)) {
+            Some(balance) => *balance,
+            None => LegacyAccountFTBalanceObject::load(&Self::legacy_key(account_id))
+                .map_or(0, |balances| balances.0),
+        }
+    }
+
+    /// returns the account's (available, locked) balances
+    fn balances(account_id: &str) -> (TokenBalance, LockedTokenBalance) {
+        if let Some(balances) = LegacyAccountFTBalanceObject::load(&Self::legacy_key(account_id)) {
+            return *balances;
+        }
+        let locked_balance = AccountTokenBalanceObject::load(&Self::locked_balance_key(account_id))
+            .map_or(0, |balance| *balance);
+        (Self::balance(account_id), locked_balance)
+    }
+
+    /// updates the account's available balance
+    /// - this is the fast path used by transfers, which only touches the available balance record
+    fn set_balance(account_id: &str, balance: TokenBalance) {
+        #[cfg(feature = "strict-invariants")]
+        AccountTokenBalancesTotal::update(Self::balance(account_id), balance);
+
+        Self::post_pre_balance_change_event(account_id);
+        let initial_storage_usage = env::storage_usage();
+        AccountTokenBalanceSnapshots::record(account_id);
+        let key = Self::balance_key(account_id);
+        if !AccountTokenBalanceObject::exists(&key) {
+            Self::migrate(account_id);
+        }
+        Self::save_balance(key, balance);
+        Self::track_storage_usage(account_id, initial_storage_usage);
+    }
+
+    /// updates the account's available and locked balances
+    fn set_balances(account_id: &str, balance: TokenBalance, locked_balance: LockedTokenBalance) {
+        #[cfg(feature = "strict-invariants")]
+        {
+            let (initial_balance, initial_locked_balance) = Self::balances(account_id);
+            AccountTokenBalancesTotal::update(
+                initial_balance + initial_locked_balance,
+                balance + locked_balance,
+            );
+        }
+
+        Self::post_pre_balance_change_event(account_id);
+        let initial_storage_usage = env::storage_usage();
+        AccountTokenBalanceSnapshots::record(account_id);
+        Self::migrate(account_id);
+        Self::save_balance(Self::balance_key(account_id), balance);
+        Self::save_balance(Self::locked_balance_key(account_id), locked_balance);
+        Self::track_storage_usage(account_id, initial_storage_usage);
+    }
+
+    fn post_pre_balance_change_event(account_id: &str) {
+        if PreBalanceChangeEvent::has_event_handlers() {
+            let (balance, locked_balance) = Self::balances(account_id);
+            post(&PreBalanceChangeEvent {
+                account_id: account_id.to_string(),
+                balance: (balance + locked_balance).into(),
+                total_supply: (*token_supply()).into(),
+            });
+        }
+    }
+
+    /// migrates the legacy balance record, if it exists
+    /// - storage usage changes must be tracked by the caller
+    fn migrate(account_id: &str) {
+        if let Some(legacy) = LegacyAccountFTBalanceObject::load(&Self::legacy_key(account_id)) {
+            let (balance, locked_balance) = *legacy;
+            legacy.delete();
+            Self::save_balance(Self::balance_key(account_id), balance);
+            Self::save_balance(Self::locked_balance_key(account_id), locked_balance);
+        }
+    }
+
+    /// zero balances are deleted from storage
+    fn save_balance(key: Hash, balance: u128) {
+        if balance == 0 {
+            AccountTokenBalanceObject::delete_by_key(&key);
+        } else {
+            AccountTokenBalanceObject::new(key, balance).save();
+        }
+    }
+
+    fn track_storage_usage(account_id: &str, initial_storage_usage: u64) {
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            ComponentStorageUsage::update(
+                ComponentStorageUsage::FT_BALANCES,
+                storage_usage_change.into(),
+            );
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
 mod tests_fungible_token {
     use super::*;
     use crate::FungibleToken;
     use crate::*;
-    use near_sdk::{test_utils, VMContext};
+    use near_sdk::test_utils;
     use oysterpack_smart_account_management::components::account_management::AccountManagementComponentConfig;
     use oysterpack_smart_account_management::StorageManagement;
     use oysterpack_smart_near::YOCTO;
@@ -727,8 +1371,8 @@ mod tests_fungible_token {
         // Arrange
         let sender = "sender";
         let receiver = "receiver";
-        let mut ctx = new_context(sender);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(sender);
+        ctx.apply();
 
         deploy_comps();
 
@@ -736,24 +1380,19 @@ mod tests_fungible_token {
 
         // register accounts
         {
-            ctx.attached_deposit = YOCTO;
-            testing_env!(ctx.clone());
+            ctx.with_deposit(YOCTO).apply();
             account_manager.storage_deposit(None, None);
 
-            ctx.attached_deposit = YOCTO;
-            ctx.predecessor_account_id = receiver.to_string();
-            testing_env!(ctx.clone());
+            ctx.with_deposit(YOCTO).predecessor(receiver).apply();
             account_manager.storage_deposit(None, None);
         }
 
         let mut stake = STAKE::new(account_manager);
         // mint some new stake for the sender
-        AccountFTBalance::set_balance(sender, 100);
+        stake.ft_mint(sender, 100.into());
 
         // Act
-        ctx.predecessor_account_id = sender.to_string();
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.predecessor(sender).with_deposit(1).apply();
         stake.ft_transfer(to_valid_account_id(receiver), 50.into(), None);
         stake.ft_transfer(
             to_valid_account_id(receiver),
@@ -762,23 +1401,25 @@ mod tests_fungible_token {
         );
         assert_eq!(*stake.ft_balance_of(to_valid_account_id(sender)), 0);
         assert_eq!(*stake.ft_balance_of(to_valid_account_id(receiver)), 100);
+        assert_eq!(
+            stake.ft_balances_of(vec![
+                to_valid_account_id(sender),
+                to_valid_account_id(receiver),
+                to_valid_account_id("unknown"),
+            ]),
+            vec![0.into(), 100.into(), 0.into()]
+        );
 
-        ctx.predecessor_account_id = receiver.to_string();
-        ctx.attached_deposit = 1;
-        testing_env!(ctx.clone());
+        ctx.predecessor(receiver).with_deposit(1).apply();
         stake.ft_transfer_call(to_valid_account_id(sender), 50.into(), None, "msg".into());
 
         let receipts = deserialize_receipts();
         assert_eq!(receipts.len(), 2);
 
-        ctx.predecessor_account_id = receiver.to_string();
-        ctx.attached_deposit = 0;
-        testing_env_with_promise_results(
-            ctx.clone(),
-            vec![PromiseResult::Successful(
-                serde_json::to_vec(&TokenAmount::from(10)).unwrap(),
-            )],
-        );
+        ctx.predecessor(receiver).with_deposit(0);
+        ctx.apply_with_promise_results(vec![PromiseResult::Successful(
+            serde_json::to_vec(&TokenAmount::from(10)).unwrap(),
+        )]);
         stake.ft_resolve_transfer_call(
             to_valid_account_id(sender),
             to_valid_account_id(receiver),
@@ -794,6 +1435,192 @@ mod tests_fungible_token {
         );
     }
 
+    #[test]
+    fn balance_snapshots() {
+        // Arrange
+        let sender = "sender";
+        let receiver = "receiver";
+        let mut ctx = TestCtx::new(sender);
+        ctx.apply();
+
+        deploy_comps();
+
+        let mut account_manager = AccountManager::default();
+
+        // register accounts
+        {
+            ctx.with_deposit(YOCTO).apply();
+            account_manager.storage_deposit(None, None);
+
+            ctx.with_deposit(YOCTO).predecessor(receiver).apply();
+            account_manager.storage_deposit(None, None);
+        }
+
+        let mut stake = STAKE::new(account_manager);
+        stake.ft_mint(sender, 100.into());
+
+        // Act - take snapshot
+        let snapshot_1 = stake.ft_snapshot();
+        assert_eq!(snapshot_1, 1);
+        let logs = test_utils::get_logs();
+        assert!(logs
+            .iter()
+            .any(|log| log.contains("[INFO] [FT_SNAPSHOT] snapshot_id: 1")));
+
+        // balances that have not changed since the snapshot are the current balances
+        assert_eq!(stake.ft_balance_at(sender, snapshot_1), Some(100.into()));
+        assert_eq!(stake.ft_balance_at(receiver, snapshot_1), Some(0.into()));
+
+        // Act - transfer after the snapshot
+        ctx.predecessor(sender).with_deposit(1).apply();
+        stake.ft_transfer(to_valid_account_id(receiver), 30.into(), None);
+        stake.ft_transfer(to_valid_account_id(receiver), 10.into(), None);
+        stake.ft_lock(sender, 20.into());
+
+        // Assert - snapshot balances are unchanged
+        assert_eq!(stake.ft_balance_at(sender, snapshot_1), Some(100.into()));
+        assert_eq!(stake.ft_balance_at(receiver, snapshot_1), Some(0.into()));
+
+        // Act - take another snapshot
+        let snapshot_2 = stake.ft_snapshot();
+        stake.ft_burn(sender, 50.into());
+        let snapshot_3 = stake.ft_snapshot();
+
+        // Assert - locked balances are included
+        assert_eq!(stake.ft_balance_at(sender, snapshot_1), Some(100.into()));
+        assert_eq!(stake.ft_balance_at(sender, snapshot_2), Some(60.into()));
+        assert_eq!(stake.ft_balance_at(sender, snapshot_3), Some(10.into()));
+        assert_eq!(stake.ft_balance_at(receiver, snapshot_2), Some(40.into()));
+        assert_eq!(stake.ft_balance_at(receiver, snapshot_3), Some(40.into()));
+        assert_eq!(stake.ft_balance_at("unknown", snapshot_3), None);
+    }
+
+    #[test]
+    fn balance_snapshots_are_capped() {
+        // Arrange
+        let account = "sender";
+        let mut ctx = TestCtx::new(account);
+        ctx.with_deposit(YOCTO).apply();
+
+        deploy_comps();
+
+        let mut account_manager = AccountManager::default();
+        account_manager.storage_deposit(None, None);
+        let mut stake = STAKE::new(account_manager);
+
+        // Act - the balance changes after every snapshot
+        let first_snapshot = stake.ft_snapshot();
+        for _ in 0..(2 * MAX_TOKEN_BALANCE_SNAPSHOTS) {
+            ctx.apply();
+            stake.ft_mint(account, 1.into());
+            stake.ft_snapshot();
+        }
+
+        // Assert
+        assert_eq!(
+            AccountTokenBalanceSnapshots::snapshots(account).len() as u64,
+            MAX_TOKEN_BALANCE_SNAPSHOTS
+        );
+        let current_id = AccountTokenBalanceSnapshots::current_id();
+        let oldest_retained = current_id - MAX_TOKEN_BALANCE_SNAPSHOTS + 1;
+        assert_eq!(
+            stake.ft_balance_at(account, oldest_retained),
+            Some(((oldest_retained - first_snapshot) as u128).into())
+        );
+        assert_eq!(
+            stake.ft_balance_at(account, current_id),
+            Some(((2 * MAX_TOKEN_BALANCE_SNAPSHOTS) as u128).into())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"INVALID\",\"message\":\"snapshot has expired\"}"#)]
+    fn balance_at_expired_snapshot() {
+        let mut ctx = TestCtx::new("sender");
+        ctx.apply();
+
+        deploy_comps();
+
+        let mut stake = STAKE::new(AccountManager::default());
+        for _ in 0..=MAX_TOKEN_BALANCE_SNAPSHOTS {
+            stake.ft_snapshot();
+        }
+        stake.ft_balance_at("sender", 1);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"INVALID\",\"message\":\"snapshot does not exist\"}"#)]
+    fn balance_at_unknown_snapshot() {
+        let mut ctx = TestCtx::new("sender");
+        ctx.apply();
+
+        deploy_comps();
+
+        let stake = STAKE::new(AccountManager::default());
+        stake.ft_balance_at("sender", 1);
+    }
+
+    #[test]
+    fn legacy_account_balance_migration() {
+        // Arrange
+        let sender = "sender";
+        let receiver = "receiver";
+        let mut ctx = TestCtx::new(sender);
+        ctx.apply();
+
+        deploy_comps();
+
+        let mut account_manager = AccountManager::default();
+
+        // register accounts
+        {
+            ctx.with_deposit(YOCTO).apply();
+            account_manager.storage_deposit(None, None);
+
+            ctx.with_deposit(YOCTO).predecessor(receiver).apply();
+            account_manager.storage_deposit(None, None);
+        }
+
+        let mut stake = STAKE::new(account_manager);
+        // store the sender balance using the legacy layout
+        LegacyAccountFTBalanceObject::new(AccountTokenBalance::legacy_key(sender), (100, 50))
+            .save();
+        // the legacy balance is backed by the token supply
+        #[cfg(feature = "strict-invariants")]
+        {
+            let mut supply = token_supply();
+            *supply += 150;
+            supply.save();
+            AccountTokenBalancesTotal::update(0, 150);
+        }
+
+        // Assert - legacy balances are readable
+        assert_eq!(stake.ft_balance_of(to_valid_account_id(sender)), 100.into());
+        assert_eq!(stake.ft_locked_balance(sender), Some(50.into()));
+
+        // Act
+        ctx.predecessor(sender).with_deposit(1).apply();
+        stake.ft_transfer(to_valid_account_id(receiver), 40.into(), None);
+
+        // Assert - the legacy balance record was migrated
+        assert!(!LegacyAccountFTBalanceObject::exists(
+            &AccountTokenBalance::legacy_key(sender)
+        ));
+        assert_eq!(stake.ft_balance_of(to_valid_account_id(sender)), 60.into());
+        assert_eq!(stake.ft_locked_balance(sender), Some(50.into()));
+        assert_eq!(
+            stake.ft_balance_of(to_valid_account_id(receiver)),
+            40.into()
+        );
+        // storage usage changes are tracked for the migrated account
+        let logs = test_utils::get_logs();
+        println!("logs: {:#?}", logs);
+        assert_eq!(
+            logs[0],
+            "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(72)"
+        );
+    }
+
     const SENDER: &str = "sender";
     const RECEIVER: &str = "receiver";
 
@@ -803,11 +1630,11 @@ mod tests_fungible_token {
         receiver_balance: Option<TokenAmount>,
         test: F,
     ) where
-        F: FnOnce(VMContext, STAKE),
+        F: FnOnce(TestCtx, STAKE),
     {
         // Arrange
-        let ctx = new_context(SENDER);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(SENDER);
+        ctx.apply();
 
         deploy_comps();
 
@@ -821,9 +1648,9 @@ mod tests_fungible_token {
 
             if let Some(balance) = sender_balance {
                 let mut ctx = ctx.clone();
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = account_manager.storage_balance_bounds().min.value();
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER)
+                    .with_deposit(account_manager.storage_balance_bounds().min.value())
+                    .apply();
                 account_manager.storage_deposit(None, Some(true));
 
                 if *balance > 0 {
@@ -833,9 +1660,9 @@ mod tests_fungible_token {
 
             if let Some(balance) = receiver_balance {
                 let mut ctx = ctx.clone();
-                ctx.predecessor_account_id = RECEIVER.to_string();
-                ctx.attached_deposit = account_manager.storage_balance_bounds().min.value();
-                testing_env!(ctx.clone());
+                ctx.predecessor(RECEIVER)
+                    .with_deposit(account_manager.storage_balance_bounds().min.value())
+                    .apply();
                 account_manager.storage_deposit(None, Some(true));
 
                 if *balance > 0 {
@@ -854,9 +1681,7 @@ mod tests_fungible_token {
         #[test]
         fn valid_transfer_with_no_memo() {
             run_test(Some(1000.into()), Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer(to_valid_account_id(RECEIVER), 400.into(), None);
 
                 assert_eq!(stake.ft_balance_of(to_valid_account_id(SENDER)), 600.into());
@@ -867,10 +1692,14 @@ mod tests_fungible_token {
 
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
-                assert_eq!(logs.len(), 1);
+                assert_eq!(logs.len(), 2);
                 assert_eq!(
                     &logs[0],
-                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(104)"
+                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(88)"
+                );
+                assert_eq!(
+                    &logs[1],
+                    "[INFO] [FT_TRANSFER] sender: sender, receiver: receiver, amount: 400, seq: 2"
                 );
             });
         }
@@ -878,9 +1707,7 @@ mod tests_fungible_token {
         #[test]
         fn valid_transfer_full_amount() {
             run_test(Some(1000.into()), Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer(to_valid_account_id(RECEIVER), 1000.into(), None);
 
                 assert_eq!(stake.ft_balance_of(to_valid_account_id(SENDER)), 0.into());
@@ -893,11 +1720,11 @@ mod tests_fungible_token {
                 println!("{:#?}", logs);
                 assert_eq!(
                     &logs[0],
-                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(-104)" // caused by sender debit zeroing FT balance
+                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(-88)" // caused by sender debit zeroing FT balance
                 );
                 assert_eq!(
                     &logs[1],
-                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(104)" // caused by receiver credit
+                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(88)" // caused by receiver credit
                 );
             });
         }
@@ -905,9 +1732,7 @@ mod tests_fungible_token {
         #[test]
         fn valid_transfer_with_memo() {
             run_test(Some(1000.into()), Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer(
                     to_valid_account_id(RECEIVER),
                     400.into(),
@@ -925,21 +1750,19 @@ mod tests_fungible_token {
                 assert_eq!(logs.len(), 2);
                 assert_eq!(
                     &logs[0],
-                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(104)"
+                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(88)"
                 );
-                assert_eq!(&logs[1], "[INFO] [FT_TRANSFER] memo");
+                assert_eq!(&logs[1], "[INFO] [FT_TRANSFER] sender: sender, receiver: receiver, amount: 400, seq: 2, memo: memo");
             });
         }
 
         #[test]
         #[should_panic(
-            expected = "[ERR] [ACCOUNT_NOT_REGISTERED] sender account is not registered"
+            expected = r#"{\"code\":\"ACCOUNT_NOT_REGISTERED\",\"message\":\"sender account is not registered"#
         )]
         fn sender_not_registered() {
             run_test(None, Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer(
                     to_valid_account_id(RECEIVER),
                     400.into(),
@@ -950,13 +1773,11 @@ mod tests_fungible_token {
 
         #[test]
         #[should_panic(
-            expected = "[ERR] [ACCOUNT_NOT_REGISTERED] receiver account is not registered"
+            expected = r#"{\"code\":\"ACCOUNT_NOT_REGISTERED\",\"message\":\"receiver account is not registered"#
         )]
         fn receiver_not_registered() {
             run_test(Some(1000.into()), None, |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer(
                     to_valid_account_id(RECEIVER),
                     400.into(),
@@ -966,12 +1787,12 @@ mod tests_fungible_token {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [BAD_REQUEST] sender and receiver cannot be the same")]
+        #[should_panic(
+            expected = r#"{\"code\":\"BAD_REQUEST\",\"message\":\"sender and receiver cannot be the same"#
+        )]
         fn sender_is_receiver() {
             run_test(Some(1000.into()), Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer(
                     to_valid_account_id(SENDER),
                     400.into(),
@@ -981,12 +1802,10 @@ mod tests_fungible_token {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+        #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
         fn yocto_not_attached() {
             run_test(Some(1000.into()), Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 0;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(0).apply();
                 stake.ft_transfer(
                     to_valid_account_id(RECEIVER),
                     400.into(),
@@ -996,12 +1815,12 @@ mod tests_fungible_token {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [BAD_REQUEST] transfer amount cannot be zero")]
+        #[should_panic(
+            expected = r#"{\"code\":\"BAD_REQUEST\",\"message\":\"transfer amount cannot be zero"#
+        )]
         fn zero_transfer_amount() {
             run_test(Some(1000.into()), Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer(
                     to_valid_account_id(RECEIVER),
                     0.into(),
@@ -1011,12 +1830,10 @@ mod tests_fungible_token {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [INSUFFICIENT_FUNDS]")]
+        #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_FUNDS\""#)]
         fn insufficient_funds() {
             run_test(Some(1000.into()), Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer(
                     to_valid_account_id(RECEIVER),
                     1001.into(),
@@ -1033,9 +1850,7 @@ mod tests_fungible_token {
         #[test]
         fn valid_transfer_with_no_memo() {
             run_test(Some(1000.into()), Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer_call(
                     to_valid_account_id(RECEIVER),
                     400.into(),
@@ -1051,10 +1866,14 @@ mod tests_fungible_token {
 
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
-                assert_eq!(logs.len(), 1);
+                assert_eq!(logs.len(), 2);
                 assert_eq!(
                     &logs[0],
-                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(104)"
+                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(88)"
+                );
+                assert_eq!(
+                    &logs[1],
+                    "[INFO] [FT_TRANSFER] sender: sender, receiver: receiver, amount: 400, seq: 2"
                 );
 
                 let receipts = deserialize_receipts();
@@ -1103,9 +1922,7 @@ mod tests_fungible_token {
         #[test]
         fn valid_transfer_full_amount_with_no_memo() {
             run_test(Some(1000.into()), Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer_call(
                     to_valid_account_id(RECEIVER),
                     1000.into(),
@@ -1121,14 +1938,18 @@ mod tests_fungible_token {
 
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
-                assert_eq!(logs.len(), 2);
+                assert_eq!(logs.len(), 3);
                 assert_eq!(
                     &logs[0],
-                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(-104)"
+                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(-88)"
                 );
                 assert_eq!(
                     &logs[1],
-                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(104)"
+                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(88)"
+                );
+                assert_eq!(
+                    &logs[2],
+                    "[INFO] [FT_TRANSFER] sender: sender, receiver: receiver, amount: 1000, seq: 2"
                 );
 
                 let receipts = deserialize_receipts();
@@ -1177,9 +1998,7 @@ mod tests_fungible_token {
         #[test]
         fn valid_transfer_with_memo() {
             run_test(Some(1000.into()), Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer_call(
                     to_valid_account_id(RECEIVER),
                     400.into(),
@@ -1198,9 +2017,9 @@ mod tests_fungible_token {
                 assert_eq!(logs.len(), 2);
                 assert_eq!(
                     &logs[0],
-                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(104)"
+                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(88)"
                 );
-                assert_eq!(&logs[1], "[INFO] [FT_TRANSFER] memo");
+                assert_eq!(&logs[1], "[INFO] [FT_TRANSFER] sender: sender, receiver: receiver, amount: 400, seq: 2, memo: memo");
 
                 let receipts = deserialize_receipts();
                 assert_eq!(receipts.len(), 2);
@@ -1247,13 +2066,11 @@ mod tests_fungible_token {
 
         #[test]
         #[should_panic(
-            expected = "[ERR] [ACCOUNT_NOT_REGISTERED] sender account is not registered"
+            expected = r#"{\"code\":\"ACCOUNT_NOT_REGISTERED\",\"message\":\"sender account is not registered"#
         )]
         fn sender_not_registered() {
             run_test(None, Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer_call(
                     to_valid_account_id(RECEIVER),
                     400.into(),
@@ -1265,13 +2082,11 @@ mod tests_fungible_token {
 
         #[test]
         #[should_panic(
-            expected = "[ERR] [ACCOUNT_NOT_REGISTERED] receiver account is not registered"
+            expected = r#"{\"code\":\"ACCOUNT_NOT_REGISTERED\",\"message\":\"receiver account is not registered"#
         )]
         fn receiver_not_registered() {
             run_test(Some(1000.into()), None, |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer_call(
                     to_valid_account_id(RECEIVER),
                     400.into(),
@@ -1282,12 +2097,12 @@ mod tests_fungible_token {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [BAD_REQUEST] sender and receiver cannot be the same")]
+        #[should_panic(
+            expected = r#"{\"code\":\"BAD_REQUEST\",\"message\":\"sender and receiver cannot be the same"#
+        )]
         fn sender_is_receiver() {
             run_test(Some(1000.into()), Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer_call(
                     to_valid_account_id(SENDER),
                     400.into(),
@@ -1298,12 +2113,10 @@ mod tests_fungible_token {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED]")]
+        #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
         fn yocto_not_attached() {
             run_test(Some(1000.into()), Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 0;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(0).apply();
                 stake.ft_transfer_call(
                     to_valid_account_id(RECEIVER),
                     400.into(),
@@ -1314,12 +2127,12 @@ mod tests_fungible_token {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [BAD_REQUEST] transfer amount cannot be zero")]
+        #[should_panic(
+            expected = r#"{\"code\":\"BAD_REQUEST\",\"message\":\"transfer amount cannot be zero"#
+        )]
         fn zero_transfer_amount() {
             run_test(Some(1000.into()), Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer_call(
                     to_valid_account_id(RECEIVER),
                     0.into(),
@@ -1330,12 +2143,10 @@ mod tests_fungible_token {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [INSUFFICIENT_FUNDS]")]
+        #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_FUNDS\""#)]
         fn insufficient_funds() {
             run_test(Some(1000.into()), Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = SENDER.to_string();
-                ctx.attached_deposit = 1;
-                testing_env!(ctx.clone());
+                ctx.predecessor(SENDER).with_deposit(1).apply();
                 stake.ft_transfer_call(
                     to_valid_account_id(RECEIVER),
                     1001.into(),
@@ -1353,13 +2164,12 @@ mod tests_fungible_token {
         #[test]
         fn zero_refund() {
             run_test(None, Some(1000.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = ctx.current_account_id.clone();
+                ctx.predecessor(DEFAULT_CONTRACT_ACCOUNT_ID);
                 let refund_amount = TokenAmount(0.into());
                 let refund_amount_bytes = serde_json::to_vec(&refund_amount).unwrap();
-                testing_env_with_promise_results(
-                    ctx,
-                    vec![PromiseResult::Successful(refund_amount_bytes)],
-                );
+                ctx.apply_with_promise_results(vec![PromiseResult::Successful(
+                    refund_amount_bytes,
+                )]);
                 stake.ft_resolve_transfer_call(
                     to_valid_account_id(SENDER),
                     to_valid_account_id(RECEIVER),
@@ -1374,13 +2184,12 @@ mod tests_fungible_token {
         #[test]
         fn full_refund_sender_with_zero_balance() {
             run_test(Some(0.into()), Some(1000.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = ctx.current_account_id.clone();
+                ctx.predecessor(DEFAULT_CONTRACT_ACCOUNT_ID);
                 let refund_amount = TokenAmount(500.into());
                 let refund_amount_bytes = serde_json::to_vec(&refund_amount).unwrap();
-                testing_env_with_promise_results(
-                    ctx,
-                    vec![PromiseResult::Successful(refund_amount_bytes)],
-                );
+                ctx.apply_with_promise_results(vec![PromiseResult::Successful(
+                    refund_amount_bytes,
+                )]);
                 let actual_used_amount = stake.ft_resolve_transfer_call(
                     to_valid_account_id(SENDER),
                     to_valid_account_id(RECEIVER),
@@ -1390,13 +2199,17 @@ mod tests_fungible_token {
 
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
-                assert_eq!(logs.len(), 3);
+                assert_eq!(logs.len(), 4);
                 assert_eq!(&logs[0], "[INFO] [FT_TRANSFER_CALL_RECEIVER_DEBIT] 500");
                 assert_eq!(
                     &logs[1],
-                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(104)"
+                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(88)"
                 );
                 assert_eq!(&logs[2], "[INFO] [FT_TRANSFER_CALL_SENDER_CREDIT] 500");
+                assert_eq!(
+                    &logs[3],
+                    "[INFO] [FT_TRANSFER] sender: receiver, receiver: sender, amount: 500, seq: 2"
+                );
 
                 assert_eq!(stake.ft_balance_of(to_valid_account_id(SENDER)), 500.into());
                 assert_eq!(
@@ -1409,13 +2222,12 @@ mod tests_fungible_token {
         #[test]
         fn full_refund_sender_with_non_zero_balance() {
             run_test(Some(100.into()), Some(1000.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = ctx.current_account_id.clone();
+                ctx.predecessor(DEFAULT_CONTRACT_ACCOUNT_ID);
                 let refund_amount = TokenAmount(500.into());
                 let refund_amount_bytes = serde_json::to_vec(&refund_amount).unwrap();
-                testing_env_with_promise_results(
-                    ctx,
-                    vec![PromiseResult::Successful(refund_amount_bytes)],
-                );
+                ctx.apply_with_promise_results(vec![PromiseResult::Successful(
+                    refund_amount_bytes,
+                )]);
                 let actual_used_amount = stake.ft_resolve_transfer_call(
                     to_valid_account_id(SENDER),
                     to_valid_account_id(RECEIVER),
@@ -1425,9 +2237,13 @@ mod tests_fungible_token {
 
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
-                assert_eq!(logs.len(), 2);
+                assert_eq!(logs.len(), 3);
                 assert_eq!(&logs[0], "[INFO] [FT_TRANSFER_CALL_RECEIVER_DEBIT] 500");
                 assert_eq!(&logs[1], "[INFO] [FT_TRANSFER_CALL_SENDER_CREDIT] 500");
+                assert_eq!(
+                    &logs[2],
+                    "[INFO] [FT_TRANSFER] sender: receiver, receiver: sender, amount: 500, seq: 3"
+                );
 
                 assert_eq!(stake.ft_balance_of(to_valid_account_id(SENDER)), 600.into());
                 assert_eq!(
@@ -1440,14 +2256,13 @@ mod tests_fungible_token {
         #[test]
         fn partial_refund_with_sender_zero_balance() {
             run_test(Some(0.into()), Some(1000.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = ctx.current_account_id.clone();
+                ctx.predecessor(DEFAULT_CONTRACT_ACCOUNT_ID);
                 let transfer_amount = TokenAmount(500.into());
                 let refund_amount = TokenAmount(100.into());
                 let refund_amount_bytes = serde_json::to_vec(&refund_amount).unwrap();
-                testing_env_with_promise_results(
-                    ctx,
-                    vec![PromiseResult::Successful(refund_amount_bytes)],
-                );
+                ctx.apply_with_promise_results(vec![PromiseResult::Successful(
+                    refund_amount_bytes,
+                )]);
                 let actual_used_amount = stake.ft_resolve_transfer_call(
                     to_valid_account_id(SENDER),
                     to_valid_account_id(RECEIVER),
@@ -1457,13 +2272,17 @@ mod tests_fungible_token {
 
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
-                assert_eq!(logs.len(), 3);
+                assert_eq!(logs.len(), 4);
                 assert_eq!(&logs[0], "[INFO] [FT_TRANSFER_CALL_RECEIVER_DEBIT] 100");
                 assert_eq!(
                     &logs[1],
-                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(104)"
+                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(88)"
                 );
                 assert_eq!(&logs[2], "[INFO] [FT_TRANSFER_CALL_SENDER_CREDIT] 100");
+                assert_eq!(
+                    &logs[3],
+                    "[INFO] [FT_TRANSFER] sender: receiver, receiver: sender, amount: 100, seq: 2"
+                );
 
                 assert_eq!(stake.ft_balance_of(to_valid_account_id(SENDER)), 100.into());
                 assert_eq!(
@@ -1476,14 +2295,13 @@ mod tests_fungible_token {
         #[test]
         fn over_refund() {
             run_test(Some(100.into()), Some(1000.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = ctx.current_account_id.clone();
+                ctx.predecessor(DEFAULT_CONTRACT_ACCOUNT_ID);
                 let transfer_amount = TokenAmount(500.into());
                 let refund_amount = TokenAmount(1000.into());
                 let refund_amount_bytes = serde_json::to_vec(&refund_amount).unwrap();
-                testing_env_with_promise_results(
-                    ctx,
-                    vec![PromiseResult::Successful(refund_amount_bytes)],
-                );
+                ctx.apply_with_promise_results(vec![PromiseResult::Successful(
+                    refund_amount_bytes,
+                )]);
                 let actual_used_amount = stake.ft_resolve_transfer_call(
                     to_valid_account_id(SENDER),
                     to_valid_account_id(RECEIVER),
@@ -1493,10 +2311,14 @@ mod tests_fungible_token {
 
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
-                assert_eq!(logs.len(), 3);
+                assert_eq!(logs.len(), 4);
                 assert_eq!(&logs[0], "[ERR] [FT_RESOLVE_TRANSFER] refund amount was greater than the transfer amount - full transfer amount will be refunded");
                 assert_eq!(&logs[1], "[INFO] [FT_TRANSFER_CALL_RECEIVER_DEBIT] 500");
                 assert_eq!(&logs[2], "[INFO] [FT_TRANSFER_CALL_SENDER_CREDIT] 500");
+                assert_eq!(
+                    &logs[3],
+                    "[INFO] [FT_TRANSFER] sender: receiver, receiver: sender, amount: 500, seq: 3"
+                );
 
                 assert_eq!(stake.ft_balance_of(to_valid_account_id(SENDER)), 600.into());
                 assert_eq!(
@@ -1509,9 +2331,9 @@ mod tests_fungible_token {
         #[test]
         fn deserialization_failure() {
             run_test(Some(100.into()), Some(1000.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = ctx.current_account_id.clone();
+                ctx.predecessor(DEFAULT_CONTRACT_ACCOUNT_ID);
                 let transfer_amount = TokenAmount(500.into());
-                testing_env_with_promise_results(ctx, vec![PromiseResult::Successful(vec![])]);
+                ctx.apply_with_promise_results(vec![PromiseResult::Successful(vec![])]);
                 let actual_refund_amount = stake.ft_resolve_transfer_call(
                     to_valid_account_id(SENDER),
                     to_valid_account_id(RECEIVER),
@@ -1535,9 +2357,9 @@ mod tests_fungible_token {
         #[test]
         fn transfer_call_promise_failed() {
             run_test(Some(100.into()), Some(1000.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = ctx.current_account_id.clone();
+                ctx.predecessor(DEFAULT_CONTRACT_ACCOUNT_ID);
                 let transfer_amount = TokenAmount(500.into());
-                testing_env_with_promise_results(ctx, vec![PromiseResult::Failed]);
+                ctx.apply_with_promise_results(vec![PromiseResult::Failed]);
                 let actual_used_amount = stake.ft_resolve_transfer_call(
                     to_valid_account_id(SENDER),
                     to_valid_account_id(RECEIVER),
@@ -1547,13 +2369,17 @@ mod tests_fungible_token {
 
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
-                assert_eq!(logs.len(), 3);
+                assert_eq!(logs.len(), 4);
                 assert_eq!(
                     &logs[0],
                     "[WARN] [FT_TRANSFER_CALL_FAILURE] full transfer amount will be refunded"
                 );
                 assert_eq!(&logs[1], "[INFO] [FT_TRANSFER_CALL_RECEIVER_DEBIT] 500");
                 assert_eq!(&logs[2], "[INFO] [FT_TRANSFER_CALL_SENDER_CREDIT] 500");
+                assert_eq!(
+                    &logs[3],
+                    "[INFO] [FT_TRANSFER] sender: receiver, receiver: sender, amount: 500, seq: 3"
+                );
 
                 assert_eq!(stake.ft_balance_of(to_valid_account_id(SENDER)), 600.into());
                 assert_eq!(
@@ -1566,9 +2392,9 @@ mod tests_fungible_token {
         #[test]
         fn transfer_call_promise_failed_and_receiver_not_registered() {
             run_test(Some(100.into()), None, |mut ctx, mut stake| {
-                ctx.predecessor_account_id = ctx.current_account_id.clone();
+                ctx.predecessor(DEFAULT_CONTRACT_ACCOUNT_ID);
                 let transfer_amount = TokenAmount(500.into());
-                testing_env_with_promise_results(ctx, vec![PromiseResult::Failed]);
+                ctx.apply_with_promise_results(vec![PromiseResult::Failed]);
                 let actual_used_amount = stake.ft_resolve_transfer_call(
                     to_valid_account_id(SENDER),
                     to_valid_account_id(RECEIVER),
@@ -1596,14 +2422,13 @@ mod tests_fungible_token {
         #[test]
         fn refund_specified_with_receiver_not_registered() {
             run_test(Some(100.into()), None, |mut ctx, mut stake| {
-                ctx.predecessor_account_id = ctx.current_account_id.clone();
+                ctx.predecessor(DEFAULT_CONTRACT_ACCOUNT_ID);
                 let transfer_amount = TokenAmount(500.into());
                 let refund_amount = TokenAmount(100.into());
                 let refund_amount_bytes = serde_json::to_vec(&refund_amount).unwrap();
-                testing_env_with_promise_results(
-                    ctx,
-                    vec![PromiseResult::Successful(refund_amount_bytes)],
-                );
+                ctx.apply_with_promise_results(vec![PromiseResult::Successful(
+                    refund_amount_bytes,
+                )]);
                 let actual_transfer_amount = stake.ft_resolve_transfer_call(
                     to_valid_account_id(SENDER),
                     to_valid_account_id(RECEIVER),
@@ -1627,14 +2452,13 @@ mod tests_fungible_token {
         #[test]
         fn refund_specified_with_receiver_having_insufficient_funds() {
             run_test(Some(100.into()), Some(100.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = ctx.current_account_id.clone();
+                ctx.predecessor(DEFAULT_CONTRACT_ACCOUNT_ID);
                 let transfer_amount = TokenAmount(500.into());
                 let refund_amount = TokenAmount(200.into());
                 let refund_amount_bytes = serde_json::to_vec(&refund_amount).unwrap();
-                testing_env_with_promise_results(
-                    ctx,
-                    vec![PromiseResult::Successful(refund_amount_bytes)],
-                );
+                ctx.apply_with_promise_results(vec![PromiseResult::Successful(
+                    refund_amount_bytes,
+                )]);
                 let actual_used_amount = stake.ft_resolve_transfer_call(
                     to_valid_account_id(SENDER),
                     to_valid_account_id(RECEIVER),
@@ -1644,17 +2468,21 @@ mod tests_fungible_token {
 
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
-                assert_eq!(logs.len(), 4);
+                assert_eq!(logs.len(), 5);
                 assert_eq!(
                     &logs[0],
                     "[WARN] [FT_TRANSFER_CALL_PARTIAL_REFUND] partial refund will be applied because receiver account has insufficient fund"
                 );
                 assert_eq!(
                     &logs[1],
-                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(-104)"
+                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(-88)"
                 );
                 assert_eq!(&logs[2], "[INFO] [FT_TRANSFER_CALL_RECEIVER_DEBIT] 100");
                 assert_eq!(&logs[3], "[INFO] [FT_TRANSFER_CALL_SENDER_CREDIT] 100");
+                assert_eq!(
+                    &logs[4],
+                    "[INFO] [FT_TRANSFER] sender: receiver, receiver: sender, amount: 100, seq: 3"
+                );
 
                 assert_eq!(stake.ft_balance_of(to_valid_account_id(SENDER)), 200.into());
                 assert_eq!(stake.ft_balance_of(to_valid_account_id(RECEIVER)), 0.into());
@@ -1664,14 +2492,13 @@ mod tests_fungible_token {
         #[test]
         fn refund_specified_receiver_having_zero_balance() {
             run_test(Some(100.into()), Some(0.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = ctx.current_account_id.clone();
+                ctx.predecessor(DEFAULT_CONTRACT_ACCOUNT_ID);
                 let transfer_amount = TokenAmount(500.into());
                 let refund_amount = TokenAmount(200.into());
                 let refund_amount_bytes = serde_json::to_vec(&refund_amount).unwrap();
-                testing_env_with_promise_results(
-                    ctx,
-                    vec![PromiseResult::Successful(refund_amount_bytes)],
-                );
+                ctx.apply_with_promise_results(vec![PromiseResult::Successful(
+                    refund_amount_bytes,
+                )]);
                 let actual_used_amount = stake.ft_resolve_transfer_call(
                     to_valid_account_id(SENDER),
                     to_valid_account_id(RECEIVER),
@@ -1695,14 +2522,13 @@ mod tests_fungible_token {
         #[test]
         fn refund_specified_sender_not_registered() {
             run_test(None, Some(1000.into()), |mut ctx, mut stake| {
-                ctx.predecessor_account_id = ctx.current_account_id.clone();
+                ctx.predecessor(DEFAULT_CONTRACT_ACCOUNT_ID);
                 let transfer_amount = TokenAmount(500.into());
                 let refund_amount = TokenAmount(200.into());
                 let refund_amount_bytes = serde_json::to_vec(&refund_amount).unwrap();
-                testing_env_with_promise_results(
-                    ctx,
-                    vec![PromiseResult::Successful(refund_amount_bytes)],
-                );
+                ctx.apply_with_promise_results(vec![PromiseResult::Successful(
+                    refund_amount_bytes,
+                )]);
                 let initial_token_supply = stake.ft_total_supply();
                 let actual_used_amount = stake.ft_resolve_transfer_call(
                     to_valid_account_id(SENDER),
@@ -1717,7 +2543,7 @@ mod tests_fungible_token {
                 assert_eq!(&logs[0], "[INFO] [FT_TRANSFER_CALL_RECEIVER_DEBIT] 200");
                 assert_eq!(
                     &logs[1],
-                    "[INFO] [FT_BURN] sender account is not registered: 200"
+                    "[INFO] [FT_BURN] sender account is not registered: 200, seq: 2"
                 );
 
                 assert_eq!(stake.ft_balance_of(to_valid_account_id(SENDER)), 0.into());
@@ -1739,7 +2565,6 @@ mod tests_fungible_token {
 mod tests_operator {
     use super::*;
     use crate::*;
-    use near_sdk::VMContext;
     use oysterpack_smart_account_management::components::account_management::AccountManagementComponentConfig;
     use oysterpack_smart_account_management::{PermissionsManagement, StorageManagement};
     use oysterpack_smart_near::YOCTO;
@@ -1774,8 +2599,8 @@ mod tests_operator {
     fn operator_commands() {
         // Arrange
         let operator = "operator";
-        let mut ctx = new_context(operator);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(operator);
+        ctx.apply();
 
         deploy_comps();
 
@@ -1783,22 +2608,19 @@ mod tests_operator {
 
         // register operator account
         {
-            ctx.attached_deposit = YOCTO;
-            testing_env!(ctx.clone());
+            ctx.with_deposit(YOCTO).apply();
             account_manager.storage_deposit(None, None);
 
-            ctx.attached_deposit = 0;
-            ctx.predecessor_account_id = ADMIN.to_string();
-            testing_env!(ctx.clone());
+            ctx.with_deposit(1).predecessor(ADMIN).apply();
             account_manager.ops_permissions_grant_operator(to_valid_account_id(operator));
+            ctx.with_deposit(0);
         }
 
         let mut stake = STAKE::new(account_manager);
 
-        fn run_operator_commands(mut ctx: VMContext, stake: &mut STAKE, account_id: &str) {
+        fn run_operator_commands(mut ctx: TestCtx, stake: &mut STAKE, account_id: &str) {
             // Act
-            ctx.predecessor_account_id = account_id.to_string();
-            testing_env!(ctx.clone());
+            ctx.predecessor(account_id).apply();
             let icon = Icon("data://image/svg+xml,<svg></svg>".to_string());
             let command = OperatorCommand::SetIcon(icon.clone());
             println!("{}", serde_json::to_string(&command).unwrap());
@@ -1808,8 +2630,7 @@ mod tests_operator {
             assert_eq!(metadata.icon, Some(icon));
 
             // Act
-            ctx.predecessor_account_id = account_id.to_string();
-            testing_env!(ctx.clone());
+            ctx.predecessor(account_id).apply();
             let reference = Reference("http://stake.json".to_string());
             let hash = Hash::from("reference");
             let command = OperatorCommand::SetReference(reference.clone(), hash);
@@ -1821,8 +2642,7 @@ mod tests_operator {
             assert_eq!(metadata.reference_hash, Some(hash));
 
             // Act
-            ctx.predecessor_account_id = account_id.to_string();
-            testing_env!(ctx.clone());
+            ctx.predecessor(account_id).apply();
             let command = OperatorCommand::ClearIcon;
             println!("{}", serde_json::to_string(&command).unwrap());
             stake.ft_operator_command(command);
@@ -1831,8 +2651,7 @@ mod tests_operator {
             assert!(metadata.icon.is_none());
 
             // Act
-            ctx.predecessor_account_id = account_id.to_string();
-            testing_env!(ctx.clone());
+            ctx.predecessor(account_id).apply();
             let command = OperatorCommand::ClearReference;
             println!("{}", serde_json::to_string(&command).unwrap());
             stake.ft_operator_command(command);
@@ -1842,8 +2661,7 @@ mod tests_operator {
             assert!(metadata.reference_hash.is_none());
 
             // Act
-            ctx.predecessor_account_id = account_id.to_string();
-            testing_env!(ctx.clone());
+            ctx.predecessor(account_id).apply();
             let command = OperatorCommand::SetTransferCallbackGas(TGas(15));
             println!("{}", serde_json::to_string(&command).unwrap());
             stake.ft_operator_command(command);
@@ -1856,12 +2674,12 @@ mod tests_operator {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [NOT_AUTHORIZED]")]
+    #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
     fn operator_commands_as_not_operator() {
         // Arrange
         let account = "account";
-        let mut ctx = new_context(account);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(account);
+        ctx.apply();
 
         deploy_comps();
 
@@ -1869,8 +2687,7 @@ mod tests_operator {
 
         // register normal account with no permissions
         {
-            ctx.attached_deposit = YOCTO;
-            testing_env!(ctx.clone());
+            ctx.with_deposit(YOCTO).apply();
             account_manager.storage_deposit(None, None);
         }
 
@@ -1879,12 +2696,12 @@ mod tests_operator {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [ACCOUNT_NOT_REGISTERED]")]
+    #[should_panic(expected = r#"{\"code\":\"ACCOUNT_NOT_REGISTERED\""#)]
     fn operator_commands_with_unregistered_account() {
         // Arrange
         let account = "account";
-        let ctx = new_context(account);
-        testing_env!(ctx);
+        let mut ctx = TestCtx::new(account);
+        ctx.apply();
 
         deploy_comps();
 
@@ -1900,7 +2717,7 @@ mod tests_token_service {
     use super::*;
 
     use crate::*;
-    use near_sdk::{test_utils, VMContext};
+    use near_sdk::test_utils;
     use oysterpack_smart_account_management::components::account_management::AccountManagementComponentConfig;
     use oysterpack_smart_account_management::StorageManagement;
     use oysterpack_smart_near::YOCTO;
@@ -1936,11 +2753,11 @@ mod tests_token_service {
     /// - if the balances are Some then register them and mint tokens for them
     fn run_test<F>(account_balance: Option<TokenAmount>, test: F)
     where
-        F: FnOnce(VMContext, STAKE),
+        F: FnOnce(TestCtx, STAKE),
     {
         // Arrange
-        let ctx = new_context(ACCOUNT);
-        testing_env!(ctx.clone());
+        let mut ctx = TestCtx::new(ACCOUNT);
+        ctx.apply();
 
         deploy_comps();
 
@@ -1954,9 +2771,9 @@ mod tests_token_service {
 
             if let Some(balance) = account_balance {
                 let mut ctx = ctx.clone();
-                ctx.predecessor_account_id = ACCOUNT.to_string();
-                ctx.attached_deposit = account_manager.storage_balance_bounds().min.value();
-                testing_env!(ctx.clone());
+                ctx.predecessor(ACCOUNT)
+                    .with_deposit(account_manager.storage_balance_bounds().min.value())
+                    .apply();
                 account_manager.storage_deposit(None, Some(true));
 
                 if *balance > 0 {
@@ -1974,8 +2791,8 @@ mod tests_token_service {
 
         #[test]
         fn account_registered_with_zero_token_balance() {
-            run_test(Some(0.into()), |ctx, mut stake| {
-                testing_env!(ctx);
+            run_test(Some(0.into()), |mut ctx, mut stake| {
+                ctx.apply();
                 let initial_token_supply = stake.ft_total_supply();
                 stake.ft_mint(ACCOUNT, 1000.into());
                 assert_eq!(
@@ -1988,9 +2805,12 @@ mod tests_token_service {
                 assert_eq!(logs.len(), 2);
                 assert_eq!(
                     &logs[0],
-                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(104)"
+                    "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(88)"
+                );
+                assert_eq!(
+                    &logs[1],
+                    "[INFO] [FT_MINT] account: bob, amount: 1000, seq: 1"
                 );
-                assert_eq!(&logs[1], "[INFO] [FT_MINT] account: bob, amount: 1000");
 
                 assert_eq!(
                     stake.ft_balance_of(to_valid_account_id(ACCOUNT)),
@@ -2001,8 +2821,8 @@ mod tests_token_service {
 
         #[test]
         fn account_registered_with_token_balance() {
-            run_test(Some(1000.into()), |ctx, mut stake| {
-                testing_env!(ctx);
+            run_test(Some(1000.into()), |mut ctx, mut stake| {
+                ctx.apply();
                 let initial_token_supply = stake.ft_total_supply();
                 stake.ft_mint(ACCOUNT, 1000.into());
                 assert_eq!(
@@ -2013,7 +2833,10 @@ mod tests_token_service {
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
                 assert_eq!(logs.len(), 1);
-                assert_eq!(&logs[0], "[INFO] [FT_MINT] account: bob, amount: 1000");
+                assert_eq!(
+                    &logs[0],
+                    "[INFO] [FT_MINT] account: bob, amount: 1000, seq: 2"
+                );
 
                 assert_eq!(
                     stake.ft_balance_of(to_valid_account_id(ACCOUNT)),
@@ -2023,19 +2846,21 @@ mod tests_token_service {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [ACCOUNT_NOT_REGISTERED]")]
+        #[should_panic(expected = r#"{\"code\":\"ACCOUNT_NOT_REGISTERED\""#)]
         fn account_not_registered() {
-            run_test(None, |ctx, mut stake| {
-                testing_env!(ctx);
+            run_test(None, |mut ctx, mut stake| {
+                ctx.apply();
                 stake.ft_mint(ACCOUNT, 1000.into());
             });
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [INVALID] mint amount cannot be zero")]
+        #[should_panic(
+            expected = r#"{\"code\":\"INVALID\",\"message\":\"mint amount cannot be zero"#
+        )]
         fn zero_amount() {
-            run_test(Some(1000.into()), |ctx, mut stake| {
-                testing_env!(ctx);
+            run_test(Some(1000.into()), |mut ctx, mut stake| {
+                ctx.apply();
                 stake.ft_mint(ACCOUNT, 0.into());
             });
         }
@@ -2047,8 +2872,8 @@ mod tests_token_service {
 
         #[test]
         fn burn_account_partial_balance() {
-            run_test(Some(10000.into()), |ctx, mut stake| {
-                testing_env!(ctx);
+            run_test(Some(10000.into()), |mut ctx, mut stake| {
+                ctx.apply();
                 let initial_token_supply = stake.ft_total_supply();
                 stake.ft_burn(ACCOUNT, 1000.into());
                 assert_eq!(
@@ -2059,7 +2884,10 @@ mod tests_token_service {
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
                 assert_eq!(logs.len(), 1);
-                assert_eq!(&logs[0], "[INFO] [FT_BURN] account: bob, amount: 1000");
+                assert_eq!(
+                    &logs[0],
+                    "[INFO] [FT_BURN] account: bob, amount: 1000, seq: 2"
+                );
 
                 assert_eq!(
                     stake.ft_balance_of(to_valid_account_id(ACCOUNT)),
@@ -2070,11 +2898,11 @@ mod tests_token_service {
 
         #[test]
         fn burn_account_partial_balance_with_locked_balance() {
-            run_test(Some(10000.into()), |ctx, mut stake| {
-                testing_env!(ctx.clone());
+            run_test(Some(10000.into()), |mut ctx, mut stake| {
+                ctx.apply();
                 stake.ft_lock(ACCOUNT, 500.into());
 
-                testing_env!(ctx.clone());
+                ctx.apply();
                 let initial_token_supply = stake.ft_total_supply();
                 stake.ft_burn(ACCOUNT, 1000.into());
                 assert_eq!(
@@ -2084,8 +2912,13 @@ mod tests_token_service {
 
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
-                assert_eq!(logs.len(), 1);
-                assert_eq!(&logs[0], "[INFO] [FT_BURN] account: bob, amount: 1000");
+                assert_eq!(
+                    logs,
+                    vec![
+                        "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(-88)",
+                        "[INFO] [FT_BURN] account: bob, amount: 1000, seq: 2",
+                    ]
+                );
 
                 assert_eq!(
                     stake.ft_balance_of(to_valid_account_id(ACCOUNT)),
@@ -2094,10 +2927,51 @@ mod tests_token_service {
             });
         }
 
+        #[test]
+        fn burn_available_balance_with_locked_balance() {
+            run_test(Some(10000.into()), |mut ctx, mut stake| {
+                ctx.apply();
+                stake.ft_lock(ACCOUNT, 500.into());
+
+                ctx.apply();
+                let initial_token_supply = stake.ft_total_supply();
+                let balance = stake.ft_burn_available(ACCOUNT, 1000.into());
+                assert_eq!(balance, 8500.into());
+                assert_eq!(
+                    stake.ft_total_supply(),
+                    (*initial_token_supply - 1000).into()
+                );
+
+                let logs = test_utils::get_logs();
+                println!("{:#?}", logs);
+                assert_eq!(
+                    logs,
+                    vec!["[INFO] [FT_BURN] account: bob, amount: 1000, seq: 2",]
+                );
+
+                // locked balance is not burned
+                assert_eq!(stake.ft_locked_balance(ACCOUNT), Some(500.into()));
+            });
+        }
+
+        #[test]
+        #[should_panic(
+            expected = r#"{\"code\":\"INVALID\",\"message\":\"account has insufficient funds\"}"#
+        )]
+        fn burn_available_balance_with_insufficient_available_balance() {
+            run_test(Some(10000.into()), |mut ctx, mut stake| {
+                ctx.apply();
+                stake.ft_lock(ACCOUNT, 500.into());
+
+                ctx.apply();
+                stake.ft_burn_available(ACCOUNT, 10000.into());
+            });
+        }
+
         #[test]
         fn burn_account_full_balance() {
-            run_test(Some(10000.into()), |ctx, mut stake| {
-                testing_env!(ctx);
+            run_test(Some(10000.into()), |mut ctx, mut stake| {
+                ctx.apply();
                 let initial_token_supply = stake.ft_total_supply();
                 stake.ft_burn(ACCOUNT, 10000.into());
                 assert_eq!(
@@ -2110,8 +2984,8 @@ mod tests_token_service {
                 assert_eq!(
                     logs,
                     vec![
-                        "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(-104)",
-                        "[INFO] [FT_BURN] account: bob, amount: 10000",
+                        "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(-88)",
+                        "[INFO] [FT_BURN] account: bob, amount: 10000, seq: 2",
                     ]
                 );
 
@@ -2120,7 +2994,7 @@ mod tests_token_service {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [ACCOUNT_NOT_REGISTERED]")]
+        #[should_panic(expected = r#"{\"code\":\"ACCOUNT_NOT_REGISTERED\""#)]
         fn account_not_registered() {
             run_test(None, |_ctx, mut stake| {
                 stake.ft_burn(ACCOUNT, 10000.into());
@@ -2128,7 +3002,9 @@ mod tests_token_service {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [INVALID] burn amount cannot be zero")]
+        #[should_panic(
+            expected = r#"{\"code\":\"INVALID\",\"message\":\"burn amount cannot be zero"#
+        )]
         fn burn_zero_amount() {
             run_test(Some(1000.into()), |_ctx, mut stake| {
                 stake.ft_burn(ACCOUNT, 0.into());
@@ -2136,7 +3012,9 @@ mod tests_token_service {
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [INVALID] account has insufficient funds")]
+        #[should_panic(
+            expected = r#"{\"code\":\"INVALID\",\"message\":\"account has insufficient funds"#
+        )]
         fn account_has_insufficient_funds() {
             run_test(Some(1.into()), |_ctx, mut stake| {
                 stake.ft_burn(ACCOUNT, 10000.into());
@@ -2150,8 +3028,8 @@ mod tests_token_service {
 
         #[test]
         fn account_registered_with_balance() {
-            run_test(Some(10000.into()), |ctx, mut stake| {
-                testing_env!(ctx);
+            run_test(Some(10000.into()), |mut ctx, mut stake| {
+                ctx.apply();
                 let initial_token_supply = stake.ft_total_supply();
                 stake.ft_burn_all(ACCOUNT);
                 assert_eq!(
@@ -2164,8 +3042,8 @@ mod tests_token_service {
                 assert_eq!(
                     logs,
                     vec![
-                        "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(-104)",
-                        "[INFO] [FT_BURN] account: bob, amount: 10000",
+                        "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(-88)",
+                        "[INFO] [FT_BURN] account: bob, amount: 10000, seq: 2",
                     ]
                 );
 
@@ -2175,8 +3053,8 @@ mod tests_token_service {
 
         #[test]
         fn account_registered_with_zero_balance() {
-            run_test(Some(0.into()), |ctx, mut stake| {
-                testing_env!(ctx);
+            run_test(Some(0.into()), |mut ctx, mut stake| {
+                ctx.apply();
                 let initial_token_supply = stake.ft_total_supply();
                 stake.ft_burn_all(ACCOUNT);
                 assert_eq!(stake.ft_total_supply(), initial_token_supply);
@@ -2190,8 +3068,8 @@ mod tests_token_service {
 
         #[test]
         fn account_not_registered() {
-            run_test(Some(0.into()), |ctx, mut stake| {
-                testing_env!(ctx);
+            run_test(Some(0.into()), |mut ctx, mut stake| {
+                ctx.apply();
                 let initial_token_supply = stake.ft_total_supply();
                 stake.ft_burn_all("doesnotexist");
                 assert_eq!(stake.ft_total_supply(), initial_token_supply);
@@ -2210,13 +3088,13 @@ mod tests_token_service {
 
         #[test]
         fn with_balance() {
-            run_test(Some(1000.into()), |ctx, mut stake| {
+            run_test(Some(1000.into()), |mut ctx, mut stake| {
                 // Arrange
-                testing_env!(ctx.clone());
+                ctx.apply();
                 let initial_token_supply = stake.ft_total_supply();
 
                 // Act
-                testing_env!(ctx.clone());
+                ctx.apply();
                 stake.ft_lock(ACCOUNT, 400.into());
                 // Assert
                 assert_eq!(stake.ft_total_supply(), (*initial_token_supply).into());
@@ -2227,13 +3105,18 @@ mod tests_token_service {
 
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
-                assert_eq!(logs.len(), 1);
-                assert_eq!(&logs[0], "[INFO] [FT_LOCK] account: bob, amount: 400");
+                assert_eq!(
+                    logs,
+                    vec![
+                        "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(88)",
+                        "[INFO] [FT_LOCK] account: bob, amount: 400",
+                    ]
+                );
 
                 assert_eq!(stake.ft_locked_balance(ACCOUNT).unwrap(), 400.into());
 
                 // Act
-                testing_env!(ctx.clone());
+                ctx.apply();
                 stake.ft_lock(ACCOUNT, 600.into());
                 // Assert
                 assert_eq!(stake.ft_total_supply(), (*initial_token_supply).into());
@@ -2241,13 +3124,18 @@ mod tests_token_service {
 
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
-                assert_eq!(logs.len(), 1);
-                assert_eq!(&logs[0], "[INFO] [FT_LOCK] account: bob, amount: 600");
+                assert_eq!(
+                    logs,
+                    vec![
+                        "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(-88)",
+                        "[INFO] [FT_LOCK] account: bob, amount: 600",
+                    ]
+                );
 
                 assert_eq!(stake.ft_locked_balance(ACCOUNT).unwrap(), 1000.into());
 
                 // Act
-                testing_env!(ctx.clone());
+                ctx.apply();
                 stake.ft_unlock(ACCOUNT, 600.into());
                 // Assert
                 assert_eq!(stake.ft_total_supply(), (*initial_token_supply).into());
@@ -2257,65 +3145,693 @@ mod tests_token_service {
                 );
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
-                assert_eq!(logs.len(), 1);
-                assert_eq!(&logs[0], "[INFO] [FT_UNLOCK] account: bob, amount: 600");
+                assert_eq!(
+                    logs,
+                    vec![
+                        "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(88)",
+                        "[INFO] [FT_UNLOCK] account: bob, amount: 600",
+                    ]
+                );
 
                 assert_eq!(stake.ft_locked_balance(ACCOUNT).unwrap(), 400.into());
 
                 // Act
-                testing_env!(ctx.clone());
+                ctx.apply();
                 stake.ft_lock_all(ACCOUNT);
 
                 assert_eq!(stake.ft_balance_of(to_valid_account_id(ACCOUNT)), 0.into());
                 let logs = test_utils::get_logs();
                 println!("{:#?}", logs);
-                assert_eq!(logs.len(), 1);
-                assert_eq!(&logs[0], "[INFO] [FT_LOCK] account: bob, amount: 600");
+                assert_eq!(
+                    logs,
+                    vec![
+                        "[INFO] [ACCOUNT_STORAGE_CHANGED] StorageUsageChange(-88)",
+                        "[INFO] [FT_LOCK] account: bob, amount: 600",
+                    ]
+                );
 
                 assert_eq!(stake.ft_locked_balance(ACCOUNT).unwrap(), 1000.into());
             });
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [INSUFFICIENT_FUNDS]")]
-        fn with_insufficient_balance() {
-            run_test(Some(0.into()), |ctx, mut stake| {
-                // Arrange
-                testing_env!(ctx.clone());
+        fn locked_balance_storage_is_covered_by_account_storage_min() {
+            run_test(Some(0.into()), |mut ctx, mut stake| {
+                let account_storage_usage = || {
+                    AccountManager::default()
+                        .load_account_near_data(ACCOUNT)
+                        .unwrap()
+                        .storage_usage()
+                };
+                let initial_storage_usage = account_storage_usage();
+
+                // Act - both the available and locked balance records are stored
+                ctx.apply();
                 stake.ft_mint(ACCOUNT, 1000.into());
+                ctx.apply();
+                stake.ft_lock(ACCOUNT, 400.into());
 
-                // Act
-                testing_env!(ctx);
-                stake.ft_lock(ACCOUNT, 1400.into());
-            });
-        }
+                // Assert
+                assert_eq!(
+                    account_storage_usage() - initial_storage_usage,
+                    STAKE::account_storage_min()
+                );
+            });
+        }
 
         #[test]
-        #[should_panic(expected = "[ERR] [INSUFFICIENT_FUNDS]")]
+        fn locked_balance_is_none_when_nothing_is_locked() {
+            run_test(Some(1000.into()), |mut ctx, mut stake| {
+                assert_eq!(stake.ft_locked_balance(ACCOUNT), None);
+
+                ctx.apply();
+                stake.ft_lock(ACCOUNT, 400.into());
+                assert_eq!(stake.ft_locked_balance(ACCOUNT), Some(400.into()));
+
+                ctx.apply();
+                stake.ft_unlock_all(ACCOUNT);
+                assert_eq!(stake.ft_locked_balance(ACCOUNT), None);
+                assert_eq!(stake.ft_locked_balance("unregistered"), None);
+            });
+        }
+
+        #[test]
+        #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_FUNDS\""#)]
+        fn with_insufficient_balance() {
+            run_test(Some(0.into()), |mut ctx, mut stake| {
+                // Arrange
+                ctx.apply();
+                stake.ft_mint(ACCOUNT, 1000.into());
+
+                // Act
+                ctx.apply();
+                stake.ft_lock(ACCOUNT, 1400.into());
+            });
+        }
+
+        #[test]
+        #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_FUNDS\""#)]
         fn with_zero_balance() {
-            run_test(Some(0.into()), |ctx, mut stake| {
+            run_test(Some(0.into()), |mut ctx, mut stake| {
                 // Act
-                testing_env!(ctx);
+                ctx.apply();
                 stake.ft_lock(ACCOUNT, 400.into());
             });
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [ACCOUNT_NOT_REGISTERED]")]
+        #[should_panic(expected = r#"{\"code\":\"ACCOUNT_NOT_REGISTERED\""#)]
         fn account_not_registered() {
-            run_test(None, |ctx, mut stake| {
-                testing_env!(ctx);
+            run_test(None, |mut ctx, mut stake| {
+                ctx.apply();
                 stake.ft_lock(ACCOUNT, 1000.into());
             });
         }
 
         #[test]
-        #[should_panic(expected = "[ERR] [INVALID] lock amount cannot be zero")]
+        #[should_panic(
+            expected = r#"{\"code\":\"INVALID\",\"message\":\"lock amount cannot be zero"#
+        )]
         fn zero_amount() {
-            run_test(Some(1000.into()), |ctx, mut stake| {
-                testing_env!(ctx);
+            run_test(Some(1000.into()), |mut ctx, mut stake| {
+                ctx.apply();
                 stake.ft_lock(ACCOUNT, 0.into());
             });
         }
     }
+
+    #[cfg(test)]
+    mod tests_rename {
+        use super::*;
+
+        #[test]
+        fn rename() {
+            run_test(None, |mut ctx, mut stake| {
+                ctx.apply();
+                stake.ft_rename("PEARL STAKE".into(), "PEARL".into());
+
+                let logs = test_utils::get_logs();
+                assert_eq!(
+                    logs,
+                    vec!["[INFO] [FT_RENAME] name: STAKE -> PEARL STAKE, symbol: STAKE -> PEARL"]
+                );
+
+                let metadata = stake.ft_metadata();
+                assert_eq!(metadata.name, "PEARL STAKE".into());
+                assert_eq!(metadata.symbol, "PEARL".into());
+                assert_eq!(metadata.decimals, 24);
+            });
+        }
+
+        #[test]
+        #[should_panic(expected = r#"{\"code\":\"INVALID\",\"message\":\"name cannot be blank"#)]
+        fn blank_name() {
+            run_test(None, |mut ctx, mut stake| {
+                ctx.apply();
+                stake.ft_rename(" ".into(), "PEARL".into());
+            });
+        }
+
+        #[test]
+        #[should_panic(expected = r#"{\"code\":\"INVALID\",\"message\":\"symbol cannot be blank"#)]
+        fn blank_symbol() {
+            run_test(None, |mut ctx, mut stake| {
+                ctx.apply();
+                stake.ft_rename("PEARL STAKE".into(), "".into());
+            });
+        }
+    }
+
+    #[cfg(test)]
+    mod tests_reference {
+        use super::*;
+
+        const DOC: &str = r#"{"description":"STAKE"}"#;
+
+        #[test]
+        fn set_and_verify_reference() {
+            run_test(None, |mut ctx, mut stake| {
+                ctx.apply();
+                assert!(!stake.ft_verify_reference(DOC));
+
+                let reference_hash = env::sha256(DOC.as_bytes());
+                stake.ft_set_reference(
+                    "https://stake.json".into(),
+                    Base64VecU8(reference_hash.clone()),
+                );
+
+                let logs = test_utils::get_logs();
+                assert_eq!(
+                    logs,
+                    vec![format!(
+                        "[INFO] [FT_REFERENCE] reference: https://stake.json, reference_hash: {}",
+                        Hash(reference_hash.clone().try_into().unwrap())
+                    )]
+                );
+
+                let metadata = stake.ft_metadata();
+                assert_eq!(metadata.reference, Some("https://stake.json".into()));
+                assert_eq!(
+                    metadata.reference_hash,
+                    Some(Hash(reference_hash.try_into().unwrap()))
+                );
+
+                assert!(stake.ft_verify_reference(DOC));
+                assert!(!stake.ft_verify_reference(r#"{"description":"tampered"}"#));
+                assert!(!stake.ft_verify_reference(""));
+            });
+        }
+
+        #[test]
+        #[should_panic(
+            expected = r#"{\"code\":\"INVALID\",\"message\":\"reference_hash must be 32 bytes"#
+        )]
+        fn invalid_reference_hash_length() {
+            run_test(None, |mut ctx, mut stake| {
+                ctx.apply();
+                stake.ft_set_reference("https://stake.json".into(), Base64VecU8(vec![1; 31]));
+            });
+        }
+
+        #[test]
+        #[should_panic(
+            expected = r#"{\"code\":\"INVALID\",\"message\":\"reference cannot be blank"#
+        )]
+        fn blank_reference() {
+            run_test(None, |mut ctx, mut stake| {
+                ctx.apply();
+                stake.ft_set_reference(" ".into(), Base64VecU8(vec![1; 32]));
+            });
+        }
+    }
+
+    #[cfg(test)]
+    mod tests_event_sequence {
+        use super::*;
+
+        #[test]
+        fn mint_burn_and_transfer_events_are_sequenced() {
+            run_test(Some(0.into()), |mut ctx, mut stake| {
+                ctx.apply();
+                assert_eq!(stake.ft_last_sequence(), 0);
+
+                stake.ft_mint(ACCOUNT, 1000.into());
+                assert_eq!(stake.ft_last_sequence(), 1);
+
+                stake.transfer(ACCOUNT, ADMIN, 100.into());
+                assert_eq!(stake.ft_last_sequence(), 2);
+
+                stake.ft_burn(ACCOUNT, 100.into());
+                assert_eq!(stake.ft_last_sequence(), 3);
+
+                // locking does not change account balances
+                stake.ft_lock(ACCOUNT, 100.into());
+                stake.ft_unlock(ACCOUNT, 100.into());
+                assert_eq!(stake.ft_last_sequence(), 3);
+
+                let logs = test_utils::get_logs();
+                println!("{:#?}", logs);
+                assert!(logs
+                    .contains(&"[INFO] [FT_MINT] account: bob, amount: 1000, seq: 1".to_string()));
+                assert!(logs.contains(
+                    &"[INFO] [FT_TRANSFER] sender: bob, receiver: admin, amount: 100, seq: 2"
+                        .to_string()
+                ));
+                assert!(logs
+                    .contains(&"[INFO] [FT_BURN] account: bob, amount: 100, seq: 3".to_string()));
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_transfer_hook {
+    use super::*;
+    use crate::*;
+    use near_sdk::test_utils;
+    use oysterpack_smart_account_management::components::account_management::AccountManagementComponentConfig;
+    use oysterpack_smart_account_management::StorageManagement;
+    use oysterpack_smart_near::YOCTO;
+    use oysterpack_smart_near_test::*;
+
+    type AccountDataType = ();
+    type AccountManager = AccountManagementComponent<AccountDataType>;
+    type STAKE = FungibleTokenComponent<AccountDataType>;
+
+    const ADMIN: &str = "admin";
+    const SENDER: &str = "sender";
+    const RECEIVER: &str = "receiver";
+    const HOOK: &str = "hook";
+
+    fn deploy_comps() {
+        AccountManager::deploy(AccountManagementComponentConfig::new(to_valid_account_id(
+            ADMIN,
+        )));
+
+        STAKE::deploy(FungibleTokenConfig {
+            metadata: Metadata {
+                spec: FT_METADATA_SPEC.into(),
+                name: "STAKE".into(),
+                symbol: "STAKE".into(),
+                icon: None,
+                reference: None,
+                reference_hash: None,
+                decimals: 24,
+            },
+            token_supply: YOCTO,
+        });
+    }
+
+    /// registers the sender and receiver accounts, mints 1000 tokens for the sender, and configures
+    /// the transfer hook if specified
+    fn run_test<F>(hook: Option<&str>, test: F)
+    where
+        F: FnOnce(TestCtx, STAKE),
+    {
+        let mut ctx = TestCtx::new(SENDER);
+        ctx.apply();
+
+        deploy_comps();
+
+        let mut account_manager = AccountManager::default();
+        for account_id in [SENDER, RECEIVER].iter() {
+            ctx.predecessor(account_id)
+                .with_deposit(account_manager.storage_balance_bounds().min.value())
+                .apply();
+            account_manager.storage_deposit(None, Some(true));
+        }
+
+        let mut stake = STAKE::new(account_manager);
+        stake.ft_mint(SENDER, 1000.into());
+
+        if let Some(hook) = hook {
+            ctx.predecessor(ADMIN).with_deposit(0).apply();
+            stake.ft_operator_command(OperatorCommand::SetTransferHook(to_valid_account_id(hook)));
+        }
+
+        ctx.predecessor(SENDER).with_deposit(0);
+        test(ctx, stake);
+    }
+
+    fn resolve_screened_transfer(
+        mut ctx: TestCtx,
+        stake: &mut STAKE,
+        result: PromiseResult,
+        msg: Option<TransferCallMessage>,
+    ) -> PromiseOrValue<TokenAmount> {
+        ctx.predecessor(DEFAULT_CONTRACT_ACCOUNT_ID).with_deposit(0);
+        ctx.apply_with_promise_results(vec![result]);
+        stake.ft_resolve_screened_transfer(
+            to_valid_account_id(SENDER),
+            to_valid_account_id(RECEIVER),
+            400.into(),
+            Some("memo".into()),
+            msg,
+        )
+    }
+
+    #[test]
+    fn operator_set_and_clear_transfer_hook() {
+        run_test(Some(HOOK), |mut ctx, mut stake| {
+            assert_eq!(stake.ft_operator_transfer_hook(), Some(HOOK.to_string()));
+
+            ctx.predecessor(ADMIN).apply();
+            stake.ft_operator_command(OperatorCommand::ClearTransferHook);
+            assert!(stake.ft_operator_transfer_hook().is_none());
+        });
+    }
+
+    #[test]
+    fn ft_transfer_approved() {
+        run_test(Some(HOOK), |mut ctx, mut stake| {
+            // Act
+            ctx.with_deposit(1).apply();
+            stake.ft_transfer(
+                to_valid_account_id(RECEIVER),
+                400.into(),
+                Some("memo".into()),
+            );
+
+            // Assert - transfer amount is locked while the transfer is screened
+            let receipts = deserialize_receipts();
+            assert_eq!(receipts.len(), 2);
+            assert_eq!(receipts[0].receiver_id, HOOK);
+            assert_eq!(stake.ft_balance_of(to_valid_account_id(SENDER)), 600.into());
+            assert_eq!(stake.ft_locked_balance(SENDER), Some(400.into()));
+            assert_eq!(stake.ft_balance_of(to_valid_account_id(RECEIVER)), 0.into());
+
+            // Act
+            let amount = resolve_screened_transfer(
+                ctx.clone(),
+                &mut stake,
+                PromiseResult::Successful(b"true".to_vec()),
+                None,
+            );
+
+            // Assert
+            match amount {
+                PromiseOrValue::Value(amount) => assert_eq!(amount, 400.into()),
+                PromiseOrValue::Promise(_) => panic!("expected value"),
+            }
+            assert_eq!(stake.ft_balance_of(to_valid_account_id(SENDER)), 600.into());
+            assert_eq!(stake.ft_locked_balance(SENDER), None);
+            assert_eq!(
+                stake.ft_balance_of(to_valid_account_id(RECEIVER)),
+                400.into()
+            );
+            let logs = test_utils::get_logs();
+            println!("{:#?}", logs);
+            assert!(logs.contains(&"[INFO] [FT_TRANSFER] sender: sender, receiver: receiver, amount: 400, seq: 2, memo: memo".to_string()));
+        });
+    }
+
+    #[test]
+    fn ft_transfer_rejected() {
+        run_test(Some(HOOK), |mut ctx, mut stake| {
+            ctx.with_deposit(1).apply();
+            stake.ft_transfer(to_valid_account_id(RECEIVER), 400.into(), None);
+
+            // Act
+            let amount = resolve_screened_transfer(
+                ctx.clone(),
+                &mut stake,
+                PromiseResult::Successful(b"false".to_vec()),
+                None,
+            );
+
+            // Assert
+            match amount {
+                PromiseOrValue::Value(amount) => assert_eq!(amount, 0.into()),
+                PromiseOrValue::Promise(_) => panic!("expected value"),
+            }
+            assert_eq!(
+                stake.ft_balance_of(to_valid_account_id(SENDER)),
+                1000.into()
+            );
+            assert_eq!(stake.ft_locked_balance(SENDER), None);
+            assert_eq!(stake.ft_balance_of(to_valid_account_id(RECEIVER)), 0.into());
+            let logs = test_utils::get_logs();
+            println!("{:#?}", logs);
+            assert!(logs.contains(
+                &"[WARN] [FT_TRANSFER_REJECTED] sender_id=sender, receiver_id=receiver, amount=400, reason=transfer hook rejected the transfer"
+                    .to_string()
+            ));
+        });
+    }
+
+    #[test]
+    fn ft_transfer_hook_call_failed() {
+        run_test(Some(HOOK), |mut ctx, mut stake| {
+            ctx.with_deposit(1).apply();
+            stake.ft_transfer(to_valid_account_id(RECEIVER), 400.into(), None);
+
+            // Act
+            resolve_screened_transfer(ctx.clone(), &mut stake, PromiseResult::Failed, None);
+
+            // Assert
+            assert_eq!(
+                stake.ft_balance_of(to_valid_account_id(SENDER)),
+                1000.into()
+            );
+            assert_eq!(stake.ft_locked_balance(SENDER), None);
+            assert_eq!(stake.ft_balance_of(to_valid_account_id(RECEIVER)), 0.into());
+        });
+    }
+
+    #[test]
+    fn ft_transfer_call_approved() {
+        run_test(Some(HOOK), |mut ctx, mut stake| {
+            ctx.with_deposit(1).apply();
+            stake.ft_transfer_call(
+                to_valid_account_id(RECEIVER),
+                400.into(),
+                None,
+                "msg".into(),
+            );
+            assert_eq!(stake.ft_locked_balance(SENDER), Some(400.into()));
+
+            // Act
+            let result = resolve_screened_transfer(
+                ctx.clone(),
+                &mut stake,
+                PromiseResult::Successful(b"true".to_vec()),
+                Some("msg".into()),
+            );
+
+            // Assert - the transfer call workflow is resumed
+            assert!(matches!(result, PromiseOrValue::Promise(_)));
+            assert_eq!(stake.ft_locked_balance(SENDER), None);
+            assert_eq!(
+                stake.ft_balance_of(to_valid_account_id(RECEIVER)),
+                400.into()
+            );
+        });
+    }
+
+    #[test]
+    fn gas_estimates() {
+        run_test(None, |mut ctx, mut stake| {
+            let estimates = stake.gas_estimates();
+            assert_eq!(estimates[0].method, "ft_transfer");
+            assert_eq!(estimates[0].gas, TGas(10).into());
+            assert_eq!(estimates[1].method, "ft_transfer_call");
+            let ft_transfer_call_gas = estimates[1].gas;
+            assert!(ft_transfer_call_gas > TGas(30).into());
+
+            // the ft_transfer_call estimate is enough gas to run the transfer call workflow
+            ctx.with_deposit(1)
+                .with_gas(ft_transfer_call_gas.value())
+                .apply();
+            stake.ft_transfer_call(
+                to_valid_account_id(RECEIVER),
+                400.into(),
+                None,
+                "msg".into(),
+            );
+
+            // screened transfers require gas for the hook contract call
+            ctx.predecessor(ADMIN)
+                .with_deposit(0)
+                .with_gas(300 * TERA)
+                .apply();
+            stake.ft_operator_command(OperatorCommand::SetTransferHook(to_valid_account_id(HOOK)));
+            let screened_estimates = stake.gas_estimates();
+            assert!(screened_estimates[0].gas > TransferHook::GAS + TGas(10));
+            assert!(screened_estimates[1].gas > ft_transfer_call_gas + TransferHook::GAS);
+        });
+    }
+
+    /// event handlers are global, thus the veto is scoped to the memo to not impact other tests
+    fn veto_transfer(event: &PreTransferEvent) {
+        if event.memo == Some("blocked".into()) {
+            ERR_CODE_FT_TRANSFER_REJECTED
+                .error("receiver is blocked")
+                .panic();
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"FT_TRANSFER_REJECTED\",\"message\":\"receiver is blocked\"}"#
+    )]
+    fn pre_transfer_event_handler_vetoes_transfer() {
+        run_test(None, |mut ctx, mut stake| {
+            eventbus::register(veto_transfer);
+
+            ctx.with_deposit(1).apply();
+            stake.ft_transfer(
+                to_valid_account_id(RECEIVER),
+                400.into(),
+                Some("blocked".into()),
+            );
+        });
+    }
+
+    thread_local! {
+        /// event handlers are global, thus events are recorded per test thread
+        static PRE_BALANCE_CHANGE_EVENTS: std::cell::RefCell<Vec<PreBalanceChangeEvent>> =
+            const { std::cell::RefCell::new(vec![]) };
+    }
+
+    fn record_pre_balance_change(event: &PreBalanceChangeEvent) {
+        PRE_BALANCE_CHANGE_EVENTS.with(|events| events.borrow_mut().push(event.clone()));
+    }
+
+    #[test]
+    fn pre_balance_change_event_posted_before_balance_update() {
+        run_test(None, |mut ctx, mut stake| {
+            eventbus::register(record_pre_balance_change);
+            let total_supply = stake.ft_total_supply();
+
+            // Act
+            ctx.with_deposit(1).apply();
+            stake.ft_transfer(to_valid_account_id(RECEIVER), 400.into(), None);
+
+            // Assert
+            let events = PRE_BALANCE_CHANGE_EVENTS.with(|events| events.borrow().clone());
+            assert_eq!(
+                events,
+                vec![
+                    PreBalanceChangeEvent {
+                        account_id: SENDER.to_string(),
+                        balance: 1000.into(),
+                        total_supply,
+                    },
+                    PreBalanceChangeEvent {
+                        account_id: RECEIVER.to_string(),
+                        balance: 0.into(),
+                        total_supply,
+                    },
+                ]
+            );
+        });
+    }
+}
+
+#[cfg(all(test, feature = "strict-invariants"))]
+mod tests_strict_invariants {
+    use super::*;
+    use crate::*;
+    use oysterpack_smart_account_management::components::account_management::AccountManagementComponentConfig;
+    use oysterpack_smart_account_management::StorageManagement;
+    use oysterpack_smart_near::YOCTO;
+    use oysterpack_smart_near_test::*;
+
+    type AccountManager = AccountManagementComponent<()>;
+    type STAKE = FungibleTokenComponent<()>;
+
+    const ADMIN: &str = "admin";
+    const SENDER: &str = "sender";
+    const RECEIVER: &str = "receiver";
+
+    fn setup(token_supply: u128) -> STAKE {
+        let mut ctx = TestCtx::new(SENDER);
+        ctx.apply();
+        AccountManager::deploy(AccountManagementComponentConfig::new(to_valid_account_id(
+            ADMIN,
+        )));
+        STAKE::deploy(FungibleTokenConfig {
+            metadata: Metadata {
+                spec: FT_METADATA_SPEC.into(),
+                name: "STAKE".into(),
+                symbol: "STAKE".into(),
+                icon: None,
+                reference: None,
+                reference_hash: None,
+                decimals: 24,
+            },
+            token_supply,
+        });
+
+        let mut account_manager = AccountManager::default();
+        for account_id in [SENDER, RECEIVER].iter() {
+            ctx.predecessor(account_id).with_deposit(YOCTO).apply();
+            account_manager.storage_deposit(None, None);
+        }
+
+        ctx.predecessor(SENDER).with_deposit(1).apply();
+        STAKE::new(account_manager)
+    }
+
+    #[test]
+    fn invariants_hold() {
+        // Arrange
+        let mut stake = setup(0);
+
+        // Act
+        stake.ft_mint(SENDER, 100.into());
+        stake.ft_transfer(to_valid_account_id(RECEIVER), 40.into(), None);
+        stake.ft_lock(RECEIVER, 10.into());
+        stake.ft_burn(RECEIVER, 20.into());
+        stake.ft_unlock_all(RECEIVER);
+        stake.ft_burn_available(SENDER, 10.into());
+
+        // Assert
+        assert_eq!(
+            TokenInvariants::load(),
+            TokenInvariants {
+                total_supply: 70.into(),
+                initial_supply: TokenAmount::ZERO,
+                account_balances_total: 70.into()
+            }
+        );
+        drop(stake);
+    }
+
+    #[test]
+    fn invariants_hold_with_initial_supply() {
+        // Arrange
+        let mut stake = setup(1000);
+
+        // Act
+        stake.ft_mint(SENDER, 100.into());
+        stake.ft_burn(SENDER, 40.into());
+
+        // Assert
+        assert_eq!(
+            TokenInvariants::load(),
+            TokenInvariants {
+                total_supply: 1060.into(),
+                initial_supply: 1000.into(),
+                account_balances_total: 60.into()
+            }
+        );
+        drop(stake);
+    }
+
+    #[test]
+    #[should_panic(expected = "token invariants violated")]
+    fn invariants_violated() {
+        // Arrange
+        let mut stake = setup(0);
+        stake.ft_mint(SENDER, 100.into());
+
+        // Act
+        let mut token_supply = token_supply();
+        *token_supply += 1;
+        token_supply.save();
+
+        // Assert
+        drop(stake);
+    }
 }
@@ -1,9 +1,13 @@
+mod balance_change;
 mod memo;
 mod metadata;
 mod token_amount;
 mod transfer_call_message;
+mod transfer_hook;
 
+pub use balance_change::*;
 pub use memo::*;
 pub use metadata::*;
 pub use token_amount::*;
 pub use transfer_call_message::*;
+pub use transfer_hook::*;
@@ -0,0 +1,64 @@
+use crate::TokenAmount;
+use oysterpack_smart_near::eventbus::{Event, EventHandlers};
+use oysterpack_smart_near::lazy_static::lazy_static;
+use oysterpack_smart_near::near_sdk::AccountId;
+use std::sync::Mutex;
+
+/// Posted before an account's token balance is updated, which provides an in-contract hook to
+/// checkpoint state that is derived from the account's balance, e.g., reward accrual
+/// - the event is posted for every balance update, including mints, burns, locks, and unlocks
+/// - the event is only posted if event handlers are registered
+///
+/// Storage that is used by event handlers is not tracked by the token, i.e., handlers are
+/// responsible for tracking their own storage usage.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PreBalanceChangeEvent {
+    pub account_id: AccountId,
+    /// account's total balance, including locked balance, before the update
+    pub balance: TokenAmount,
+    /// total token supply before the update
+    pub total_supply: TokenAmount,
+}
+
+lazy_static! {
+    static ref EVENT_HANDLERS: Mutex<EventHandlers<PreBalanceChangeEvent>> =
+        Mutex::new(EventHandlers::new());
+}
+
+impl Event for PreBalanceChangeEvent {
+    fn handlers<F>(f: F)
+    where
+        F: FnOnce(&EventHandlers<Self>),
+    {
+        match EVENT_HANDLERS.lock() {
+            Ok(guard) => f(&guard),
+            Err(poisoned) => f(&poisoned.into_inner()),
+        };
+    }
+
+    fn handlers_mut<F>(f: F)
+    where
+        F: FnOnce(&mut EventHandlers<Self>),
+    {
+        match EVENT_HANDLERS.lock() {
+            Ok(mut guard) => f(&mut guard),
+            Err(poisoned) => f(&mut poisoned.into_inner()),
+        };
+    }
+}
+
+impl PreBalanceChangeEvent {
+    pub fn has_event_handlers() -> bool {
+        match EVENT_HANDLERS.lock() {
+            Ok(guard) => guard.len() > 0,
+            Err(poisoned) => poisoned.into_inner().len() > 0,
+        }
+    }
+
+    pub fn clear_event_handlers() {
+        match EVENT_HANDLERS.lock() {
+            Ok(mut guard) => guard.clear(),
+            Err(poisoned) => poisoned.into_inner().clear(),
+        };
+    }
+}
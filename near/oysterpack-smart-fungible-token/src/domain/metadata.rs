@@ -10,10 +10,11 @@ use std::ops::Deref;
 
 /// The following fields are immutable once the FT contract is deployed:
 /// - [`Metadata::spec`]
-/// - [`Metadata::name`]
-/// - [`Metadata::symbol`]
 /// - [`Metadata::decimals`]
 ///
+/// [`Metadata::name`] and [`Metadata::symbol`] can be updated via [`crate::TokenService::ft_rename`],
+/// e.g., to rebrand the token.
+///
 /// The following fields can be updated by the contract owner or accounts that have the admin permission:
 /// - [`Metadata::icon`]
 /// - [`Metadata::reference`]
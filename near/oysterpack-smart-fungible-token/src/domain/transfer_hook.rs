@@ -0,0 +1,88 @@
+use crate::{Memo, TokenAmount};
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::Gas;
+use oysterpack_smart_near::eventbus::{Event, EventHandlers};
+use oysterpack_smart_near::lazy_static::lazy_static;
+use oysterpack_smart_near::near_sdk::AccountId;
+use oysterpack_smart_near::TERA;
+use std::sync::Mutex;
+
+/// Posted before tokens are transferred between accounts, which provides an in-contract hook to
+/// screen transfers, e.g., for compliance
+///
+/// Registered event handlers can:
+/// - veto the transfer by panicking - handlers should use [`crate::ERR_CODE_FT_TRANSFER_REJECTED`]
+/// - annotate the transfer by logging
+///
+/// The event is posted by [`crate::FungibleToken::ft_transfer`], [`crate::FungibleToken::ft_transfer_call`],
+/// and [`crate::components::fungible_token::FungibleTokenComponent::transfer`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct PreTransferEvent {
+    pub sender_id: AccountId,
+    pub receiver_id: AccountId,
+    pub amount: TokenAmount,
+    pub memo: Option<Memo>,
+}
+
+lazy_static! {
+    static ref EVENT_HANDLERS: Mutex<EventHandlers<PreTransferEvent>> =
+        Mutex::new(EventHandlers::new());
+}
+
+impl Event for PreTransferEvent {
+    fn handlers<F>(f: F)
+    where
+        F: FnOnce(&EventHandlers<Self>),
+    {
+        match EVENT_HANDLERS.lock() {
+            Ok(guard) => f(&guard),
+            Err(poisoned) => f(&poisoned.into_inner()),
+        };
+    }
+
+    fn handlers_mut<F>(f: F)
+    where
+        F: FnOnce(&mut EventHandlers<Self>),
+    {
+        match EVENT_HANDLERS.lock() {
+            Ok(mut guard) => f(&mut guard),
+            Err(poisoned) => f(&mut poisoned.into_inner()),
+        };
+    }
+}
+
+impl PreTransferEvent {
+    pub fn clear_event_handlers() {
+        match EVENT_HANDLERS.lock() {
+            Ok(mut guard) => guard.clear(),
+            Err(poisoned) => poisoned.into_inner().clear(),
+        };
+    }
+}
+
+/// External hook contract that screens account initiated transfers before they are applied - see
+/// [`crate::TransferScreener`]
+/// - while the transfer is being screened, the transfer amount is locked on the sender's account
+pub struct TransferHook;
+
+const TRANSFER_HOOK_KEY: u128 = 1958150693208744561930266571093818367;
+
+type TransferHookObject = Object<u128, AccountId>;
+
+impl TransferHook {
+    /// gas that is allotted to the hook contract `ft_screen_transfer` call
+    pub const GAS: Gas = Gas(10 * TERA);
+
+    /// returns the hook contract account ID
+    pub fn load() -> Option<AccountId> {
+        TransferHookObject::load(&TRANSFER_HOOK_KEY).map(|account_id| (*account_id).clone())
+    }
+
+    pub(crate) fn save(account_id: &str) {
+        TransferHookObject::new(TRANSFER_HOOK_KEY, account_id.to_string()).save();
+    }
+
+    pub(crate) fn clear() {
+        TransferHookObject::delete_by_key(&TRANSFER_HOOK_KEY);
+    }
+}
@@ -1,6 +1,7 @@
 pub use contract::fungible_token::*;
 pub use contract::metadata::*;
 pub use contract::operator::*;
+pub use contract::transfer_hook::*;
 pub use token_service::*;
 
 pub mod contract;
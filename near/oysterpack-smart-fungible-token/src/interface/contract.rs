@@ -3,3 +3,4 @@
 pub mod fungible_token;
 pub mod metadata;
 pub mod operator;
+pub mod transfer_hook;
@@ -46,7 +46,7 @@ pub trait FungibleToken: ResolveTransferCall {
     /// - `memo` - an optional string field in a free form to associate a memo with this transfer.
     ///
     /// ## Log Events
-    /// - [`LOG_EVENT_FT_TRANSFER`] - if there is a memo
+    /// - [`LOG_EVENT_FT_TRANSFER`] - includes the event sequence number and the memo, if specified
     ///
     /// ## NOTES
     /// The 1 yoctoNEAR that is attached is not credited to the sender account because crediting the
@@ -109,6 +109,14 @@ pub trait FungibleToken: ResolveTransferCall {
 
     /// If the account doesn't exist, then zero is returned.
     fn ft_balance_of(&self, account_id: ValidAccountId) -> TokenAmount;
+
+    /// Bulk version of [`FungibleToken::ft_balance_of`], which enables balances to be retrieved for
+    /// many accounts in a single view call
+    /// - balances are returned in the same order as the specified accounts
+    /// - zero is returned for accounts that don't exist
+    ///
+    /// NOTE: this is not part of the NEP-141 standard
+    fn ft_balances_of(&self, accounts: Vec<ValidAccountId>) -> Vec<TokenAmount>;
 }
 
 /// # **Contract Interface**: [Fungible Token Transfer Call Resolver API][1]
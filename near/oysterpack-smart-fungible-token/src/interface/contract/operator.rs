@@ -1,6 +1,10 @@
 use crate::{Icon, Reference};
 use oysterpack_smart_near::domain::{Gas, TGas};
-use oysterpack_smart_near::near_sdk::serde::{Deserialize, Serialize};
+use oysterpack_smart_near::near_sdk::{
+    json_types::ValidAccountId,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
 use oysterpack_smart_near::Hash;
 
 /// # **Contract Interface**: Fungible Token Operator API
@@ -14,6 +18,9 @@ pub trait FungibleTokenOperator {
 
     /// returns the amount of gas configured for the resolve transfer callback
     fn ft_operator_transfer_callback_gas(&self) -> Gas;
+
+    /// returns the hook contract that screens transfers - see [`OperatorCommand::SetTransferHook`]
+    fn ft_operator_transfer_hook(&self) -> Option<AccountId>;
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -24,4 +31,9 @@ pub enum OperatorCommand {
     SetReference(Reference, Hash),
     ClearReference,
     SetTransferCallbackGas(TGas),
+    /// registers the hook contract that screens account initiated transfers before they are applied
+    /// - see [`crate::TransferScreener`]
+    /// - replaces any existing hook contract
+    SetTransferHook(ValidAccountId),
+    ClearTransferHook,
 }
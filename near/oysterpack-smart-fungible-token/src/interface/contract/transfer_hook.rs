@@ -0,0 +1,78 @@
+use crate::{Memo, TokenAmount, TransferCallMessage};
+use oysterpack_smart_near::near_sdk::{
+    json_types::ValidAccountId,
+    serde::{Deserialize, Serialize},
+    AccountId, PromiseOrValue,
+};
+use oysterpack_smart_near::{ErrCode, Level, LogEvent};
+
+/// # **Contract Interface**: Transfer Screener API
+///
+/// Interface that the hook contract must implement to screen transfers, e.g., for compliance - see
+/// [`crate::TransferHook`]
+///
+/// The hook contract is configured by the operator via [`crate::OperatorCommand::SetTransferHook`].
+///
+/// ## NOTES
+/// - only account initiated transfers, i.e., [`crate::FungibleToken::ft_transfer`] and
+///   [`crate::FungibleToken::ft_transfer_call`], are screened by the hook contract
+/// - the transfer amount is locked on the sender's account while the transfer is being screened,
+///   and the transfer is applied by [`ResolveScreenedTransfer::ft_resolve_screened_transfer`]
+/// - if the hook contract call fails, then the transfer is rejected
+pub trait TransferScreener {
+    /// returns true if the transfer is approved
+    fn ft_screen_transfer(
+        &self,
+        sender_id: ValidAccountId,
+        receiver_id: ValidAccountId,
+        amount: TokenAmount,
+        memo: Option<Memo>,
+    ) -> bool;
+}
+
+pub trait ResolveScreenedTransfer {
+    /// Callback to apply the transfer once it has been screened by the hook contract
+    /// - the locked transfer amount is unlocked on the sender's account
+    /// - if the transfer was approved, then it is applied - if `msg` is specified, then the
+    ///   receiver is notified via `ft_on_transfer`, i.e., the [`crate::FungibleToken::ft_transfer_call`]
+    ///   workflow is resumed
+    /// - if the transfer was rejected, then [`LOG_EVENT_FT_TRANSFER_REJECTED`] is logged and zero is returned
+    ///
+    /// Returns the amount that was transferred
+    ///
+    /// The callback should be designed to never panic.
+    ///
+    /// #\[private\]
+    fn ft_resolve_screened_transfer(
+        &mut self,
+        sender_id: ValidAccountId,
+        receiver_id: ValidAccountId,
+        amount: TokenAmount,
+        memo: Option<Memo>,
+        msg: Option<TransferCallMessage>,
+    ) -> PromiseOrValue<TokenAmount>;
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct ScreenTransferArgs {
+    pub sender_id: AccountId,
+    pub receiver_id: AccountId,
+    pub amount: TokenAmount,
+    pub memo: Option<Memo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct ResolveScreenedTransferArgs {
+    pub sender_id: AccountId,
+    pub receiver_id: AccountId,
+    pub amount: TokenAmount,
+    pub memo: Option<Memo>,
+    pub msg: Option<TransferCallMessage>,
+}
+
+pub const LOG_EVENT_FT_TRANSFER_REJECTED: LogEvent = LogEvent(Level::WARN, "FT_TRANSFER_REJECTED");
+
+/// used to veto transfers - see [`crate::PreTransferEvent`]
+pub const ERR_CODE_FT_TRANSFER_REJECTED: ErrCode = ErrCode("FT_TRANSFER_REJECTED");
@@ -1,4 +1,5 @@
-use crate::TokenAmount;
+use crate::{Name, Reference, Symbol, TokenAmount};
+use oysterpack_smart_near::near_sdk::json_types::Base64VecU8;
 use oysterpack_smart_near::{Level, LogEvent};
 
 /// Provides basic functions to operate the fungible token.
@@ -30,6 +31,22 @@ pub trait TokenService {
     /// - if amount is zero
     fn ft_burn(&mut self, account_id: &str, amount: TokenAmount) -> TokenAmount;
 
+    /// Debits tokens from the account's available balance and burns them, which decreases the total supply.
+    /// - logs [`LOG_EVENT_FT_BURN`]
+    ///
+    /// Returns updated token balance
+    ///
+    /// **Use Case:** locked STAKE is used as collateral and must not be burned when STAKE is unstaked.
+    ///
+    /// ## Notes
+    /// - locked tokens are never burned
+    ///
+    /// ## Panics
+    /// - if the account is not registered
+    /// - if amount is zero
+    /// - if the account's available balance is insufficient
+    fn ft_burn_available(&mut self, account_id: &str, amount: TokenAmount) -> TokenAmount;
+
     /// Attempts to burn the account's total token balance.
     /// - logs [`LOG_EVENT_FT_BURN`]
     fn ft_burn_all(&mut self, account_id: &str);
@@ -63,8 +80,69 @@ pub trait TokenService {
     /// - if the account is not registered
     fn ft_unlock_all(&mut self, account_id: &str);
 
-    /// Returns the accounts locked balance or None if the account is not registered.
-    fn ft_locked_balance(&mut self, account_id: &str) -> Option<TokenAmount>;
+    /// Returns the account's locked balance
+    /// - returns None if the account is not registered or has no locked balance
+    fn ft_locked_balance(&self, account_id: &str) -> Option<TokenAmount>;
+
+    /// Takes a snapshot of all account token balances, which enables the balances at the time of the
+    /// snapshot to be looked up via [`TokenService::ft_balance_at`]
+    /// - account balances are recorded lazily, i.e., the account's balance is recorded for the
+    ///   snapshot the first time the balance changes after the snapshot is taken
+    /// - the balance snapshot storage usage is charged to the account
+    /// - only the most recent [`MAX_TOKEN_BALANCE_SNAPSHOTS`] snapshots are retained, which bounds the
+    ///   per account snapshot storage - older snapshot balances are pruned when the account balance
+    ///   changes
+    /// - logs [`LOG_EVENT_FT_SNAPSHOT`]
+    ///
+    /// Returns the snapshot ID
+    ///
+    /// **Use Case:** STAKE holder votes are weighted by their STAKE balances at the time the proposal
+    /// was created.
+    ///
+    /// **NOTE:** access control is the responsibility of the contract
+    fn ft_snapshot(&mut self) -> u64;
+
+    /// Returns the sequence number that was assigned to the last mint, burn, or transfer event - zero
+    /// means that no events have been emitted
+    ///
+    /// Every [`LOG_EVENT_FT_MINT`], [`LOG_EVENT_FT_BURN`], and [`crate::LOG_EVENT_FT_TRANSFER`] event is
+    /// assigned the next sequence number, which is included in the event as `seq`. The sequence
+    /// numbers are contiguous, which enables indexers to detect missed events and to consume the
+    /// account balance change stream with exactly-once semantics.
+    fn ft_last_sequence(&self) -> u64;
+
+    /// Returns the account's total token balance, i.e., available + locked, at the time the specified
+    /// snapshot was taken, or None if the account is not registered.
+    ///
+    /// ## Panics
+    /// - if the snapshot does not exist
+    /// - if the snapshot has expired, i.e., it is no longer one of the most recent
+    ///   [`MAX_TOKEN_BALANCE_SNAPSHOTS`] snapshots
+    fn ft_balance_at(&self, account_id: &str, snapshot_id: u64) -> Option<TokenAmount>;
+
+    /// Updates the token name and symbol, i.e., to rebrand the token without redeploying.
+    /// - logs [`LOG_EVENT_FT_RENAME`]
+    ///
+    /// **NOTE:** access control is the responsibility of the contract
+    ///
+    /// ## Panics
+    /// - if name or symbol is blank
+    fn ft_rename(&mut self, name: Name, symbol: Symbol);
+
+    /// Sets the metadata reference to the off-chain JSON document along with the document's sha256 hash
+    /// - logs [`LOG_EVENT_FT_REFERENCE`]
+    ///
+    /// **NOTE:** access control is the responsibility of the contract
+    ///
+    /// ## Panics
+    /// - if reference is blank
+    /// - if reference hash is not 32 bytes
+    fn ft_set_reference(&mut self, reference: Reference, reference_hash: Base64VecU8);
+
+    /// Returns true if the sha256 hash of the specified document matches the metadata reference hash.
+    /// This makes the off-chain document tamper-evident.
+    /// - returns false if the metadata reference is not set
+    fn ft_verify_reference(&self, payload: &str) -> bool;
 }
 
 pub const LOG_EVENT_FT_MINT: LogEvent = LogEvent(Level::INFO, "FT_MINT");
@@ -72,3 +150,11 @@ pub const LOG_EVENT_FT_BURN: LogEvent = LogEvent(Level::INFO, "FT_BURN");
 
 pub const LOG_EVENT_FT_LOCK: LogEvent = LogEvent(Level::INFO, "FT_LOCK");
 pub const LOG_EVENT_FT_UNLOCK: LogEvent = LogEvent(Level::INFO, "FT_UNLOCK");
+
+pub const LOG_EVENT_FT_SNAPSHOT: LogEvent = LogEvent(Level::INFO, "FT_SNAPSHOT");
+
+/// max number of token balance snapshots that are retained - see [`TokenService::ft_snapshot`]
+pub const MAX_TOKEN_BALANCE_SNAPSHOTS: u64 = 32;
+
+pub const LOG_EVENT_FT_RENAME: LogEvent = LogEvent(Level::INFO, "FT_RENAME");
+pub const LOG_EVENT_FT_REFERENCE: LogEvent = LogEvent(Level::INFO, "FT_REFERENCE");
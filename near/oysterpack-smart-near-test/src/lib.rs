@@ -27,6 +27,155 @@ pub fn new_context(predecessor_account_id: &str) -> VMContext {
         .build()
 }
 
+/// Fluent NEAR test context builder, which simulates a sequence of transactions against the same
+/// contract.
+///
+/// Each time the context is applied, the account balance is synced with the current env account
+/// balance, i.e., the account balance carries over from the previous transaction, which includes
+/// the previous attached deposit.
+///
+/// ## Example
+/// ```rust
+/// use oysterpack_smart_near_test::*;
+/// use oysterpack_smart_near::YOCTO;
+///
+/// let mut ctx = TestCtx::new("owner");
+/// ctx.apply();
+/// ctx.predecessor("bob").with_deposit(YOCTO).simulate_earnings(YOCTO).apply();
+/// assert_eq!(near_sdk::env::predecessor_account_id(), "bob");
+/// assert_eq!(
+///     near_sdk::env::account_balance(),
+///     DEFAULT_CONTRACT_ACCOUNT_BALANCE + 2 * YOCTO
+/// );
+/// ```
+#[derive(Clone)]
+pub struct TestCtx {
+    context: VMContext,
+    account_balance: Option<Balance>,
+    earnings: Balance,
+    stake_action: Option<Balance>,
+    applied: bool,
+}
+
+impl TestCtx {
+    /// see [`new_context`]
+    pub fn new(predecessor_account_id: &str) -> Self {
+        Self {
+            context: new_context(predecessor_account_id),
+            account_balance: None,
+            earnings: 0,
+            stake_action: None,
+            applied: false,
+        }
+    }
+
+    pub fn context(&self) -> VMContext {
+        self.context.clone()
+    }
+
+    /// sets the predecessor account ID, which is also used as the signer account ID
+    pub fn predecessor(&mut self, account_id: &str) -> &mut Self {
+        self.context.predecessor_account_id = account_id.to_string();
+        self.context.signer_account_id = account_id.to_string();
+        self
+    }
+
+    /// sets the contract account ID, e.g., to simulate deploying the contract to another account
+    pub fn current_account(&mut self, account_id: &str) -> &mut Self {
+        self.context.current_account_id = account_id.to_string();
+        self
+    }
+
+    pub fn with_deposit(&mut self, deposit: Balance) -> &mut Self {
+        self.context.attached_deposit = deposit;
+        self
+    }
+
+    pub fn with_gas(&mut self, prepaid_gas: Gas) -> &mut Self {
+        self.context.prepaid_gas = prepaid_gas;
+        self
+    }
+
+    pub fn view_mode(&mut self, is_view: bool) -> &mut Self {
+        self.context.is_view = is_view;
+        self
+    }
+
+    pub fn account_locked_balance(&mut self, account_locked_balance: Balance) -> &mut Self {
+        self.context.account_locked_balance = account_locked_balance;
+        self
+    }
+
+    pub fn block_index(&mut self, block_index: u64) -> &mut Self {
+        self.context.block_index = block_index;
+        self
+    }
+
+    pub fn block_timestamp(&mut self, block_timestamp: u64) -> &mut Self {
+        self.context.block_timestamp = block_timestamp;
+        self
+    }
+
+    pub fn epoch_height(&mut self, epoch_height: u64) -> &mut Self {
+        self.context.epoch_height = epoch_height;
+        self
+    }
+
+    /// overrides the account balance when the context is next applied, e.g., to simulate the
+    /// account balance dropping below a threshold
+    pub fn simulate_account_balance(&mut self, account_balance: Balance) -> &mut Self {
+        self.account_balance = Some(account_balance);
+        self
+    }
+
+    /// simulates earnings that are received by the contract before the next transaction, i.e.,
+    /// the amount is added to the account balance when the context is next applied
+    pub fn simulate_earnings(&mut self, amount: Balance) -> &mut Self {
+        self.earnings += amount;
+        self
+    }
+
+    /// simulates a stake action that completes before the next transaction, i.e., when the context
+    /// is next applied, NEAR is moved between the account balance and the locked balance such that
+    /// the locked balance matches the specified amount
+    pub fn simulate_stake_action(&mut self, locked_balance: Balance) -> &mut Self {
+        self.stake_action = Some(locked_balance);
+        self
+    }
+
+    fn sync_account_balance(&mut self) {
+        if self.applied {
+            self.context.account_balance = env::account_balance();
+        }
+        if let Some(account_balance) = self.account_balance.take() {
+            self.context.account_balance = account_balance;
+        }
+        self.context.account_balance += self.earnings;
+        self.earnings = 0;
+        if let Some(locked_balance) = self.stake_action.take() {
+            let total_balance = self.context.account_balance + self.context.account_locked_balance;
+            self.context.account_balance = total_balance - locked_balance;
+            self.context.account_locked_balance = locked_balance;
+        }
+        self.applied = true;
+    }
+
+    /// syncs the account balance and sets up the NEAR runtime test env using the context
+    pub fn apply(&mut self) -> &mut Self {
+        self.sync_account_balance();
+        testing_env!(self.context.clone());
+        self
+    }
+
+    /// syncs the account balance and injects the `PromiseResult`s into the NEAR runtime test env -
+    /// see [`testing_env_with_promise_results`]
+    pub fn apply_with_promise_results(&mut self, promise_results: Vec<PromiseResult>) -> &mut Self {
+        self.sync_account_balance();
+        testing_env_with_promise_results(self.context.clone(), promise_results);
+        self
+    }
+}
+
 /// Used to inject `PromiseResult`s into the NEAR runtime test environment. This enables callbacks
 /// to be unit tested.
 pub fn testing_env_with_promise_results(context: VMContext, promise_results: Vec<PromiseResult>) {
@@ -140,6 +289,60 @@ pub struct DeleteAccountAction {
     pub beneficiary_id: AccountId,
 }
 
+/// receipt matchers
+impl Receipt {
+    /// asserts that the receipt contains a single action
+    pub fn assert_single_action(&self) -> &Action {
+        assert_eq!(
+            self.actions.len(),
+            1,
+            "expected a single action: {:?}",
+            self
+        );
+        &self.actions[0]
+    }
+
+    pub fn assert_receiver(&self, receiver_id: &str) -> &Self {
+        assert_eq!(self.receiver_id, receiver_id);
+        self
+    }
+
+    /// asserts that the receipt is a single stake action on the current contract for the specified
+    /// stake amount
+    pub fn assert_stake_action(&self, stake: Balance) -> &StakeAction {
+        self.assert_receiver(&env::current_account_id());
+        match self.assert_single_action() {
+            Action::Stake(action) => {
+                assert_eq!(action.stake, stake);
+                action
+            }
+            action => panic!("expected StakeAction: {:?}", action),
+        }
+    }
+
+    /// asserts that the receipt is a single transfer action for the specified amount
+    pub fn assert_transfer(&self, amount: Balance) -> &TransferAction {
+        match self.assert_single_action() {
+            Action::Transfer(action) => {
+                assert_eq!(action.deposit, amount);
+                action
+            }
+            action => panic!("expected TransferAction: {:?}", action),
+        }
+    }
+
+    /// asserts that the receipt is a single function call action for the specified method
+    pub fn assert_function_call(&self, method_name: &str) -> &FunctionCallAction {
+        match self.assert_single_action() {
+            Action::FunctionCall(action) => {
+                assert_eq!(action.method_name, method_name);
+                action
+            }
+            action => panic!("expected FunctionCallAction: {:?}", action),
+        }
+    }
+}
+
 pub fn deserialize_receipts() -> Vec<Receipt> {
     get_created_receipts()
         .iter()
@@ -205,6 +408,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ctx() {
+        // Arrange
+        let mut ctx = TestCtx::new(&bob());
+        ctx.with_deposit(YOCTO).apply();
+        assert_eq!(
+            env::account_balance(),
+            DEFAULT_CONTRACT_ACCOUNT_BALANCE + YOCTO
+        );
+
+        // Act
+        ctx.predecessor("alice")
+            .simulate_earnings(YOCTO)
+            .with_deposit(0)
+            .block_index(10)
+            .apply();
+
+        // Assert
+        assert_eq!(env::predecessor_account_id(), "alice");
+        assert_eq!(env::signer_account_id(), "alice");
+        assert_eq!(env::block_index(), 10);
+        assert_eq!(env::attached_deposit(), 0);
+        assert_eq!(
+            env::account_balance(),
+            DEFAULT_CONTRACT_ACCOUNT_BALANCE + 2 * YOCTO
+        );
+    }
+
+    #[test]
+    fn receipt_matchers() {
+        // Arrange
+        let mut ctx = TestCtx::new(&bob());
+        ctx.apply();
+
+        // Act
+        near_sdk::Promise::new(bob()).transfer(YOCTO);
+        near_sdk::Promise::new(env::current_account_id()).function_call(
+            b"callback".to_vec(),
+            vec![],
+            0,
+            10,
+        );
+
+        // Assert
+        let receipts = deserialize_receipts();
+        receipts[0].assert_receiver(&bob()).assert_transfer(YOCTO);
+        assert_eq!(receipts[1].assert_function_call("callback").gas, 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected TransferAction")]
+    fn receipt_matchers_with_unexpected_action() {
+        // Arrange
+        let mut ctx = TestCtx::new(&bob());
+        ctx.apply();
+
+        // Act
+        near_sdk::Promise::new(bob()).function_call(b"callback".to_vec(), vec![], 0, 10);
+
+        // Assert
+        deserialize_receipts()[0].assert_transfer(YOCTO);
+    }
+
     #[test]
     fn promise_result_failure() {
         // Arrange
@@ -3,7 +3,9 @@
 mod component;
 mod deploy;
 mod manages_account_data;
+mod versioned_state;
 
 pub use component::*;
 pub use deploy::*;
 pub use manages_account_data::*;
+pub use versioned_state::*;
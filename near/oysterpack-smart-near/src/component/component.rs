@@ -7,9 +7,10 @@
 //!   changes it is the component's responsibility to save it to storage.
 //! - [`crate::component::Deploy`] - defines a pattern to standardize component deployment
 
-use crate::data::Object;
+use crate::component::{StateUpgrade, StateVersion, VersionedState};
 use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
 use std::fmt::Debug;
+use std::ops::{Deref, DerefMut};
 
 /// Defines abstraction for a stateful component
 pub trait Component {
@@ -19,24 +20,110 @@ pub trait Component {
     /// - it is recommended to generate a ULID for the key to avoid collisions
     const STATE_KEY: u128;
 
+    /// state schema version
+    /// - when the state schema changes, the version must be bumped and an upgrade from the previous
+    ///   version must be registered via [`Component::state_upgrades`]
+    const STATE_VERSION: StateVersion = 0;
+
+    /// state upgrades that are applied automatically when the state is loaded
+    fn state_upgrades() -> Vec<StateUpgrade> {
+        vec![]
+    }
+
     /// loads the service state from storage using the key defined by [`state_key`]()
+    /// - the state is upgraded to [`Component::STATE_VERSION`]
     fn load_state() -> Option<ComponentState<Self::State>> {
-        ComponentState::<Self::State>::load(&Self::STATE_KEY)
+        VersionedState::load(
+            Self::STATE_KEY,
+            Self::STATE_VERSION,
+            &Self::state_upgrades(),
+        )
+        .map(|(state, legacy)| ComponentState {
+            key: Self::STATE_KEY,
+            state,
+            legacy,
+        })
     }
 
     /// creates new in-memory state, i.e., the state is not persisted to storage
     fn new_state(state: Self::State) -> ComponentState<Self::State> {
-        ComponentState::<Self::State>::new(Self::STATE_KEY, state)
+        ComponentState::new(Self::STATE_KEY, Self::STATE_VERSION, state)
     }
 }
 
 /// service state type
-pub type ComponentState<T> = Object<u128, T>;
+/// - the state is stored within a [`VersionedState`] envelope
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComponentState<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq,
+{
+    key: u128,
+    state: VersionedState<T>,
+    /// true if the state was loaded from storage that predates state versioning
+    legacy: bool,
+}
+
+impl<T> ComponentState<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq,
+{
+    /// state is created in memory, i.e., it is not persisted to storage
+    pub fn new(key: u128, version: StateVersion, state: T) -> Self {
+        Self {
+            key,
+            state: VersionedState { version, state },
+            legacy: false,
+        }
+    }
+
+    pub fn key(&self) -> u128 {
+        self.key
+    }
+
+    pub fn version(&self) -> StateVersion {
+        self.state.version
+    }
+
+    /// saves the state to persistent storage on the NEAR blockchain
+    /// - state that was loaded from legacy storage is migrated into the versioned state envelope
+    pub fn save(&self) {
+        self.state.save(self.key, self.legacy);
+    }
+
+    /// Deletes the state from storage and consumes the object
+    ///
+    /// Returns true if the state existed.
+    pub fn delete(self) -> bool {
+        VersionedState::<T>::delete(self.key)
+    }
+}
+
+impl<T> Deref for ComponentState<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state.state
+    }
+}
+
+impl<T> DerefMut for ComponentState<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.state.state
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::component::Deploy;
+    use crate::data::Object;
     use lazy_static::lazy_static;
     use oysterpack_smart_near_test::*;
     use std::ops::DerefMut;
@@ -76,8 +163,8 @@ mod tests {
     #[test]
     fn service() {
         // Arrange
-        let ctx = new_context("bob");
-        testing_env!(ctx);
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
 
         Foo::deploy(0);
 
@@ -98,4 +185,98 @@ mod tests {
             assert_eq!(*foo.state, 1);
         }
     }
+
+    struct Bar;
+
+    impl Component for Bar {
+        /// version 0 state was a single u128 value
+        type State = (u128, u128);
+
+        const STATE_KEY: u128 = 1957898301174295802516379437294563618;
+
+        const STATE_VERSION: StateVersion = 1;
+
+        fn state_upgrades() -> Vec<StateUpgrade> {
+            vec![StateUpgrade {
+                from_version: 0,
+                upgrade: |bytes| {
+                    let value = u128::try_from_slice(bytes).unwrap();
+                    (value, 0_u128).try_to_vec().unwrap()
+                },
+            }]
+        }
+    }
+
+    #[test]
+    fn legacy_state_is_upgraded() {
+        // Arrange
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+        Object::<u128, u128>::new(Bar::STATE_KEY, 5).save();
+
+        // Act
+        let state = Bar::load_state().unwrap();
+
+        // Assert
+        assert_eq!(state.version(), 1);
+        assert_eq!(*state, (5, 0));
+        // the upgraded state is persisted when the state is saved
+        assert!(Object::<u128, u128>::exists(&Bar::STATE_KEY));
+        state.save();
+        assert!(!Object::<u128, u128>::exists(&Bar::STATE_KEY));
+        assert_eq!(*Bar::load_state().unwrap(), (5, 0));
+
+        // Act - delete
+        assert!(Bar::load_state().unwrap().delete());
+        assert!(Bar::load_state().is_none());
+    }
+
+    #[test]
+    fn versioned_state_is_upgraded() {
+        // Arrange
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+        ComponentState::new(Bar::STATE_KEY, 0, 5_u128).save();
+
+        // Act
+        let state = Bar::load_state().unwrap();
+
+        // Assert
+        assert_eq!(state.version(), 1);
+        assert_eq!(*state, (5, 0));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"ILLEGAL_STATE\",\"message\":\"stored state version is newer than the current version: 2 > 1\"}"#
+    )]
+    fn stored_state_version_is_newer() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+        ComponentState::new(Bar::STATE_KEY, 2, (5_u128, 0_u128)).save();
+
+        Bar::load_state();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"ILLEGAL_STATE\",\"message\":\"state upgrade is not registered for version: 0\"}"#
+    )]
+    fn state_upgrade_not_registered() {
+        struct Baz;
+
+        impl Component for Baz {
+            type State = (u128, u128);
+
+            const STATE_KEY: u128 = Bar::STATE_KEY;
+
+            const STATE_VERSION: StateVersion = 1;
+        }
+
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+        Object::<u128, u128>::new(Bar::STATE_KEY, 5).save();
+
+        Baz::load_state();
+    }
 }
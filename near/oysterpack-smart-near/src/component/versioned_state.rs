@@ -0,0 +1,155 @@
+//! Provides support for versioning component state, which enables the component state schema to
+//! evolve across contract upgrades
+//!
+//! ## Design
+//! - component state is stored within a [`VersionedState`] envelope, which tags the state with its
+//!   schema version
+//! - when the state schema changes, the component bumps its [`crate::component::Component::STATE_VERSION`]
+//!   and registers a [`StateUpgrade`] that transforms the state from the previous version
+//! - when the state is loaded, any registered upgrades are applied automatically in version order
+//! - the upgraded state is persisted the next time the state is saved
+//!
+//! State that was stored before versioning was introduced, i.e., stored raw using the component
+//! `STATE_KEY`, is treated as version 0.
+
+use crate::asserts::ERR_ILLEGAL_STATE;
+use crate::data::Object;
+use crate::Hash;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use std::fmt::Debug;
+use std::io::{Error, Write};
+
+/// State schema version
+pub type StateVersion = u32;
+
+/// Versioned envelope that the component state is stored in
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct VersionedState<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq,
+{
+    pub version: StateVersion,
+    pub state: T,
+}
+
+/// Upgrades the Borsh serialized state from the `from_version` to the next version
+#[derive(Clone, Copy)]
+pub struct StateUpgrade {
+    pub from_version: StateVersion,
+    /// transforms the Borsh serialized state from `from_version` to `from_version + 1`
+    pub upgrade: fn(&[u8]) -> Vec<u8>,
+}
+
+/// used to derive the versioned state storage key from the component `STATE_KEY`
+const VERSIONED_STATE_KEY: &str = "VersionedState";
+
+type VersionedStateObject<T> = Object<Hash, VersionedState<T>>;
+type RawVersionedStateObject = Object<Hash, VersionedState<RawState>>;
+/// state that was stored before versioning was introduced
+type LegacyStateObject = Object<u128, RawState>;
+
+/// Borsh serialized state
+/// - (de)serializes the raw bytes as is, i.e., without a length prefix
+#[derive(Clone, Debug, PartialEq)]
+struct RawState(Vec<u8>);
+
+impl BorshSerialize for RawState {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_all(&self.0)
+    }
+}
+
+impl BorshDeserialize for RawState {
+    fn deserialize(buf: &mut &[u8]) -> Result<Self, Error> {
+        let bytes = buf.to_vec();
+        *buf = &[];
+        Ok(Self(bytes))
+    }
+}
+
+impl<T> VersionedState<T>
+where
+    T: BorshSerialize + BorshDeserialize + Clone + Debug + PartialEq,
+{
+    /// Loads the state and upgrades it to the specified version by applying the registered upgrades
+    /// in version order
+    /// - if no versioned state is found, then falls back to loading the legacy state, which is
+    ///   treated as version 0
+    ///
+    /// Returns the state along with a flag indicating whether the state was loaded from legacy storage
+    ///
+    /// ## Panics
+    /// - if the stored state version is newer than the specified version
+    /// - if an upgrade is not registered for any of the versions that need to be upgraded
+    pub(crate) fn load(
+        state_key: u128,
+        version: StateVersion,
+        upgrades: &[StateUpgrade],
+    ) -> Option<(Self, bool)> {
+        let (stored, legacy) = match RawVersionedStateObject::load(&Self::key(state_key)) {
+            Some(stored) => ((*stored).clone(), false),
+            None => {
+                let legacy = LegacyStateObject::load(&state_key)?;
+                (
+                    VersionedState {
+                        version: 0,
+                        state: (*legacy).clone(),
+                    },
+                    true,
+                )
+            }
+        };
+        ERR_ILLEGAL_STATE.assert(
+            || stored.version <= version,
+            || {
+                format!(
+                    "stored state version is newer than the current version: {} > {}",
+                    stored.version, version
+                )
+            },
+        );
+
+        let mut bytes = stored.state.0;
+        for from_version in stored.version..version {
+            let upgrade = upgrades
+                .iter()
+                .find(|upgrade| upgrade.from_version == from_version);
+            ERR_ILLEGAL_STATE.assert(
+                || upgrade.is_some(),
+                || {
+                    format!(
+                        "state upgrade is not registered for version: {}",
+                        from_version
+                    )
+                },
+            );
+            bytes = (upgrade.unwrap().upgrade)(&bytes);
+        }
+
+        Some((
+            Self {
+                version,
+                state: T::try_from_slice(&bytes).unwrap(),
+            },
+            legacy,
+        ))
+    }
+
+    /// saves the versioned state
+    /// - if `legacy` is true, then the legacy state is deleted
+    pub(crate) fn save(&self, state_key: u128, legacy: bool) {
+        VersionedStateObject::new(Self::key(state_key), self.clone()).save();
+        if legacy {
+            LegacyStateObject::delete_by_key(&state_key);
+        }
+    }
+
+    pub(crate) fn delete(state_key: u128) -> bool {
+        let legacy = LegacyStateObject::delete_by_key(&state_key);
+        VersionedStateObject::<T>::delete_by_key(&Self::key(state_key)) || legacy
+    }
+
+    fn key(state_key: u128) -> Hash {
+        Hash::from((VERSIONED_STATE_KEY, state_key))
+    }
+}
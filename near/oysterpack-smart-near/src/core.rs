@@ -1,10 +1,13 @@
 pub mod asserts;
+mod callback_guard;
+pub mod deposit_policy;
 mod errors;
 pub mod eventbus;
 mod hash;
 mod log_events;
 mod promise;
 
+pub use callback_guard::*;
 pub use errors::*;
 pub use hash::*;
 pub use log_events::*;
@@ -1,6 +1,9 @@
 use crate::domain::YoctoNear;
 use crate::{ErrCode, ErrorConst};
-use near_sdk::env;
+use near_sdk::{
+    env,
+    serde::{Deserialize, Serialize},
+};
 use std::fmt::Display;
 
 pub const ERR_CODE_BAD_REQUEST: ErrCode = ErrCode("BAD_REQUEST");
@@ -25,6 +28,29 @@ pub const ERR_INSUFFICIENT_FUNDS: ErrorConst = ErrorConst(
     "account has insufficient funds to fulfill request",
 );
 
+/// error data for insufficient funds and deposit errors
+/// - amounts should be types that serialize as JSON strings, e.g., [`YoctoNear`], to avoid
+///   precision loss on the client side
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct InsufficientFunds<T> {
+    pub required: T,
+    pub available: T,
+}
+
+/// panics with [`ERR_INSUFFICIENT_FUNDS`] and [`InsufficientFunds`] error data
+pub fn assert_sufficient_funds<T>(required: T, available: T)
+where
+    T: PartialOrd + Serialize,
+{
+    if available < required {
+        ERR_INSUFFICIENT_FUNDS.panic_with_data(InsufficientFunds {
+            required,
+            available,
+        });
+    }
+}
+
 pub fn assert_request<F, Msg, MsgF>(check: F, msg: MsgF)
 where
     F: FnOnce() -> bool,
@@ -37,27 +63,31 @@ where
 /// used to protect functions that transfer value against FCAK calls
 pub fn assert_yocto_near_attached() {
     if env::attached_deposit() != 1 {
-        env::panic(ERR_YOCTONEAR_DEPOSIT_REQUIRED.to_string().as_bytes())
+        ERR_YOCTONEAR_DEPOSIT_REQUIRED.panic()
     }
 }
 
 /// used to protect functions that transfer value against FCAK calls
 pub fn assert_min_near_attached(min: YoctoNear) {
-    assert!(
-        env::attached_deposit() >= *min,
-        "{} attached NEAR amount is insufficient - minimum required amount is: {} yoctoNEAR",
-        ERR_CODE_INSUFFICIENT_NEAR_DEPOSIT,
-        min
-    )
+    let attached_deposit: YoctoNear = env::attached_deposit().into();
+    if attached_deposit < min {
+        ERR_CODE_INSUFFICIENT_NEAR_DEPOSIT
+            .error(format!(
+                "attached NEAR amount is insufficient - minimum required amount is: {} yoctoNEAR",
+                min
+            ))
+            .panic_with_data(InsufficientFunds {
+                required: min,
+                available: attached_deposit,
+            });
+    }
 }
 
 pub fn assert_near_attached<Msg: Display>(msg: Msg) {
-    assert!(
-        env::attached_deposit() > 0,
-        "{} - {}",
-        ERR_NEAR_DEPOSIT_REQUIRED,
-        msg
-    )
+    if env::attached_deposit() == 0 {
+        ERR_NEAR_DEPOSIT_REQUIRED
+            .panic_with_message(format!("{} - {}", ERR_NEAR_DEPOSIT_REQUIRED.1, msg));
+    }
 }
 
 pub fn assert_account_not_predecessor(account_id: &str) {
@@ -74,41 +104,38 @@ mod tests {
 
     #[test]
     fn assert_yocto_near_attached_check_passes() {
-        let mut ctx = new_context("bob");
-        ctx.attached_deposit = 1;
-        testing_env!(ctx);
+        let mut ctx = TestCtx::new("bob");
+        ctx.with_deposit(1).apply();
 
         assert_yocto_near_attached();
     }
 
     #[test]
     #[should_panic(
-        expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED] exactly 1 yoctoNEAR must be attached"
+        expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\",\"message\":\"exactly 1 yoctoNEAR must be attached"#
     )]
     fn assert_yocto_near_attached_with_zero_deposit() {
-        let ctx = new_context("bob");
-        testing_env!(ctx);
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
 
         assert_yocto_near_attached();
     }
 
     #[test]
     #[should_panic(
-        expected = "[ERR] [YOCTONEAR_DEPOSIT_REQUIRED] exactly 1 yoctoNEAR must be attached"
+        expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\",\"message\":\"exactly 1 yoctoNEAR must be attached"#
     )]
     fn assert_yocto_near_attached_with_2_deposit() {
-        let mut ctx = new_context("bob");
-        ctx.attached_deposit = 2;
-        testing_env!(ctx);
+        let mut ctx = TestCtx::new("bob");
+        ctx.with_deposit(2).apply();
 
         assert_yocto_near_attached();
     }
 
     #[test]
     fn assert_min_near_attached_check_passes() {
-        let mut ctx = new_context("bob");
-        ctx.attached_deposit = 100;
-        testing_env!(ctx);
+        let mut ctx = TestCtx::new("bob");
+        ctx.with_deposit(100).apply();
 
         assert_min_near_attached(100.into());
         assert_min_near_attached(50.into());
@@ -116,13 +143,32 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "[ERR] [INSUFFICIENT_NEAR_DEPOSIT] attached NEAR amount is insufficient - minimum required amount is: 200 yoctoNEAR"
+        expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\",\"message\":\"attached NEAR amount is insufficient - minimum required amount is: 200 yoctoNEAR"#
     )]
     fn assert_min_near_attached_insufficient_depoist() {
-        let mut ctx = new_context("bob");
-        ctx.attached_deposit = 199;
-        testing_env!(ctx);
+        let mut ctx = TestCtx::new("bob");
+        ctx.with_deposit(199).apply();
 
         assert_min_near_attached(200.into());
     }
+
+    #[test]
+    fn assert_sufficient_funds_check_passes() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        assert_sufficient_funds(YoctoNear::from(100), 100.into());
+        assert_sufficient_funds(YoctoNear::from(100), 200.into());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"INSUFFICIENT_FUNDS\",\"message\":\"account has insufficient funds to fulfill request\",\"data\":{\"required\":\"100\",\"available\":\"10\"}}"#
+    )]
+    fn assert_sufficient_funds_with_insufficient_funds() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        assert_sufficient_funds(YoctoNear::from(100), 10.into());
+    }
 }
@@ -0,0 +1,152 @@
+use crate::data::Object;
+use crate::{ErrCode, ErrorConst, Hash};
+use near_sdk::env;
+
+/// Guards promise callbacks that the contract schedules on itself, e.g., stake action `*_finalize`
+/// callbacks.
+///
+/// A callback is only allowed to run if:
+/// 1. it was invoked by the contract itself, i.e., `env::predecessor_account_id() == env::current_account_id()`
+/// 2. there is an in-flight nonce for the callback, i.e., [`CallbackGuard::issue`] was called when
+///    the callback promise was scheduled
+///
+/// Each nonce is consumed when the callback is entered, which means a callback cannot run more
+/// times than it was scheduled.
+///
+/// ## Example
+/// ```rust
+/// use oysterpack_smart_near::CallbackGuard;
+/// use oysterpack_smart_near_test::*;
+///
+/// let mut ctx = TestCtx::new("bob");
+/// ctx.apply();
+/// // when the callback promise is scheduled
+/// CallbackGuard::issue("ops_stake_finalize");
+///
+/// // when the callback is invoked
+/// ctx.predecessor(DEFAULT_CONTRACT_ACCOUNT_ID).apply();
+/// CallbackGuard::enter("ops_stake_finalize");
+/// ```
+pub struct CallbackGuard;
+
+pub const ERR_CALLBACK_NOT_AUTHORIZED: ErrorConst = ErrorConst(
+    ErrCode("CALLBACK_NOT_AUTHORIZED"),
+    "callback can only be invoked by the contract itself for a scheduled callback",
+);
+
+const CALLBACK_GUARD_KEY: u128 = 1958387034192746410357721953301574919;
+
+/// (key, callback hash) -> number of in-flight nonces
+type CallbackNoncesObject = Object<(u128, Hash), u64>;
+
+impl CallbackGuard {
+    /// Issues an in-flight nonce for the callback - must be called when the callback promise is
+    /// scheduled
+    ///
+    /// Returns the number of in-flight nonces for the callback
+    pub fn issue(callback: &str) -> u64 {
+        let in_flight = Self::in_flight(callback) + 1;
+        CallbackNoncesObject::new(Self::key(callback), in_flight).save();
+        in_flight
+    }
+
+    /// Asserts the callback is authorized to run and consumes an in-flight nonce - must be called
+    /// at the start of the callback
+    ///
+    /// ## Panics
+    /// - with [`ERR_CALLBACK_NOT_AUTHORIZED`] if the predecessor is not the contract itself
+    /// - with [`ERR_CALLBACK_NOT_AUTHORIZED`] if there is no in-flight nonce for the callback
+    pub fn enter(callback: &str) {
+        ERR_CALLBACK_NOT_AUTHORIZED
+            .assert(|| env::predecessor_account_id() == env::current_account_id());
+        let released = Self::release(callback);
+        ERR_CALLBACK_NOT_AUTHORIZED.assert(|| released);
+    }
+
+    /// Consumes an in-flight nonce on behalf of a callback that was lost, e.g., it ran out of gas,
+    /// which means the callback will not be authorized to run if it is later invoked
+    ///
+    /// Returns false if there is no in-flight nonce for the callback
+    pub fn release(callback: &str) -> bool {
+        let in_flight = Self::in_flight(callback);
+        if in_flight == 0 {
+            return false;
+        }
+        if in_flight == 1 {
+            CallbackNoncesObject::delete_by_key(&Self::key(callback));
+        } else {
+            CallbackNoncesObject::new(Self::key(callback), in_flight - 1).save();
+        }
+        true
+    }
+
+    /// returns the number of in-flight nonces for the callback
+    pub fn in_flight(callback: &str) -> u64 {
+        CallbackNoncesObject::load(&Self::key(callback)).map_or(0, |in_flight| *in_flight)
+    }
+
+    fn key(callback: &str) -> (u128, Hash) {
+        (CALLBACK_GUARD_KEY, callback.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near_test::*;
+
+    const CALLBACK: &str = "ops_stake_finalize";
+
+    #[test]
+    fn issue_and_enter() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        assert_eq!(CallbackGuard::issue(CALLBACK), 1);
+        assert_eq!(CallbackGuard::issue(CALLBACK), 2);
+        assert_eq!(CallbackGuard::in_flight("ops_stake_start_finalize"), 0);
+
+        ctx.predecessor(&env::current_account_id()).apply();
+        CallbackGuard::enter(CALLBACK);
+        assert_eq!(CallbackGuard::in_flight(CALLBACK), 1);
+        CallbackGuard::enter(CALLBACK);
+        assert_eq!(CallbackGuard::in_flight(CALLBACK), 0);
+    }
+
+    #[test]
+    fn release() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        assert!(!CallbackGuard::release(CALLBACK));
+        CallbackGuard::issue(CALLBACK);
+        CallbackGuard::issue(CALLBACK);
+        assert!(CallbackGuard::release(CALLBACK));
+        assert_eq!(CallbackGuard::in_flight(CALLBACK), 1);
+        assert!(CallbackGuard::release(CALLBACK));
+        assert_eq!(CallbackGuard::in_flight(CALLBACK), 0);
+        assert!(!CallbackGuard::release(CALLBACK));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"CALLBACK_NOT_AUTHORIZED\""#)]
+    fn enter_from_other_account() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        CallbackGuard::issue(CALLBACK);
+        CallbackGuard::enter(CALLBACK);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"CALLBACK_NOT_AUTHORIZED\""#)]
+    fn enter_without_in_flight_nonce() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        CallbackGuard::issue(CALLBACK);
+        ctx.predecessor(&env::current_account_id()).apply();
+        CallbackGuard::enter(CALLBACK);
+        CallbackGuard::enter(CALLBACK);
+    }
+}
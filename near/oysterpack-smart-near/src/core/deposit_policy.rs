@@ -0,0 +1,363 @@
+//! Declarative registry of per-method deposit requirements.
+//!
+//! Security sensitive methods must consistently require exactly 1 yoctoNEAR to be attached, which
+//! protects them against function call access key (FCAK) calls, because function call access keys
+//! cannot attach deposits. The registry makes the requirement explicit and auditable in one place,
+//! and [`assert_deposit_policy`] is used to guard the method entry points. Methods are identified
+//! by [`Method`], which guarantees at compile time that every guarded method has a registered policy.
+
+use crate::asserts::{assert_min_near_attached, assert_yocto_near_attached, ERR_CODE_BAD_REQUEST};
+use crate::domain::YoctoNear;
+use near_sdk::env;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DepositPolicy {
+    /// exactly 1 yoctoNEAR must be attached
+    OneYocto,
+    /// at least the specified amount must be attached
+    MinDeposit(YoctoNear),
+    /// no deposit is allowed to be attached
+    NoDeposit,
+}
+
+impl DepositPolicy {
+    /// panics if the attached deposit does not satisfy the policy
+    pub fn assert(&self) {
+        match self {
+            DepositPolicy::OneYocto => assert_yocto_near_attached(),
+            DepositPolicy::MinDeposit(min) => assert_min_near_attached(*min),
+            DepositPolicy::NoDeposit => ERR_CODE_BAD_REQUEST.assert(
+                || env::attached_deposit() == 0,
+                || "attached deposit is not allowed",
+            ),
+        }
+    }
+}
+
+/// Methods that are guarded by a deposit policy
+/// - the deposit policy is resolved via an exhaustive match, i.e., every method is guaranteed at
+///   compile time to have a registered deposit policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    // [NEP-141] fungible token transfers
+    FtTransfer,
+    FtTransferCall,
+    // contract ownership
+    OpsOwnerTransfer,
+    OpsOwnerTransferWithVesting,
+    OpsOwnerCancelTransfer,
+    OpsOwnerFinalizeTransfer,
+    OpsOwnerWithdrawBalance,
+    OpsOwnerSetAutoSweep,
+    OpsOwnerSetEarningsMode,
+    OpsOwnerSetEarningsFeeSplit,
+    OpsOwnerAddFullAccessDelegate,
+    OpsOwnerRemoveDelegate,
+    OpsOwnerSetGuardians,
+    OpsOwnerRecoveryVote,
+    OpsOwnerRecoveryFinalize,
+    OpsOwnerRecoveryCancel,
+    // account profiles
+    OpsAccountProfileSet,
+    // account storage
+    OpsStorageTopUpAccounts,
+    // fungible token metadata
+    OpsFtRename,
+    OpsFtSetReference,
+    // permissions management
+    OpsPermissionsGrantAdmin,
+    OpsPermissionsRevokeAdmin,
+    OpsPermissionsGrantOperator,
+    OpsPermissionsRevokeOperator,
+    OpsPermissionsGrant,
+    OpsPermissionsGrantPermissions,
+    OpsPermissionsGrantWithExpiry,
+    OpsPermissionsRevoke,
+    OpsPermissionsRevokePermissions,
+    OpsPermissionsRevokeAll,
+    OpsPermissionsGrantMany,
+    OpsPermissionsRevokeMany,
+    // staking pool treasury
+    OpsStakeGrantTreasurer,
+    OpsStakeRevokeTreasurer,
+    OpsStakeTreasurySetBeneficiary,
+    OpsStakeTreasurySetDividendPolicy,
+    // staking pool unstaked balance transfers
+    OpsStakeTransferUnstaked,
+    OpsStakeWithdrawTo,
+    // staking pool governance
+    OpsGovPropose,
+    OpsGovVote,
+    // staking pool STAKE locks
+    OpsFtLock,
+    OpsFtUnlock,
+    OpsFtAdminUnlock,
+    OpsFtApproveLocker,
+    OpsFtRevokeLockerApproval,
+    OpsStakeGrantLocker,
+    OpsStakeRevokeLocker,
+    OpsStakeGrantStaker,
+    OpsStakeRevokeStaker,
+    // staking pool liquidity pool
+    OpsLiquidityRemove,
+    OpsLiquiditySwap,
+    // staking pool rewards farm
+    OpsFarmClaim,
+}
+
+impl Method {
+    /// all methods that are guarded by a deposit policy
+    pub const ALL: &'static [Method] = &[
+        Method::FtTransfer,
+        Method::FtTransferCall,
+        Method::OpsOwnerTransfer,
+        Method::OpsOwnerTransferWithVesting,
+        Method::OpsOwnerCancelTransfer,
+        Method::OpsOwnerFinalizeTransfer,
+        Method::OpsOwnerWithdrawBalance,
+        Method::OpsOwnerSetAutoSweep,
+        Method::OpsOwnerSetEarningsMode,
+        Method::OpsOwnerSetEarningsFeeSplit,
+        Method::OpsOwnerAddFullAccessDelegate,
+        Method::OpsOwnerRemoveDelegate,
+        Method::OpsOwnerSetGuardians,
+        Method::OpsOwnerRecoveryVote,
+        Method::OpsOwnerRecoveryFinalize,
+        Method::OpsOwnerRecoveryCancel,
+        Method::OpsAccountProfileSet,
+        Method::OpsStorageTopUpAccounts,
+        Method::OpsFtRename,
+        Method::OpsFtSetReference,
+        Method::OpsPermissionsGrantAdmin,
+        Method::OpsPermissionsRevokeAdmin,
+        Method::OpsPermissionsGrantOperator,
+        Method::OpsPermissionsRevokeOperator,
+        Method::OpsPermissionsGrant,
+        Method::OpsPermissionsGrantPermissions,
+        Method::OpsPermissionsGrantWithExpiry,
+        Method::OpsPermissionsRevoke,
+        Method::OpsPermissionsRevokePermissions,
+        Method::OpsPermissionsRevokeAll,
+        Method::OpsPermissionsGrantMany,
+        Method::OpsPermissionsRevokeMany,
+        Method::OpsStakeGrantTreasurer,
+        Method::OpsStakeRevokeTreasurer,
+        Method::OpsStakeTreasurySetBeneficiary,
+        Method::OpsStakeTreasurySetDividendPolicy,
+        Method::OpsStakeTransferUnstaked,
+        Method::OpsStakeWithdrawTo,
+        Method::OpsGovPropose,
+        Method::OpsGovVote,
+        Method::OpsFtLock,
+        Method::OpsFtUnlock,
+        Method::OpsFtAdminUnlock,
+        Method::OpsFtApproveLocker,
+        Method::OpsFtRevokeLockerApproval,
+        Method::OpsStakeGrantLocker,
+        Method::OpsStakeRevokeLocker,
+        Method::OpsStakeGrantStaker,
+        Method::OpsStakeRevokeStaker,
+        Method::OpsLiquidityRemove,
+        Method::OpsLiquiditySwap,
+        Method::OpsFarmClaim,
+    ];
+
+    /// contract method name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Method::FtTransfer => "ft_transfer",
+            Method::FtTransferCall => "ft_transfer_call",
+            Method::OpsOwnerTransfer => "ops_owner_transfer",
+            Method::OpsOwnerTransferWithVesting => "ops_owner_transfer_with_vesting",
+            Method::OpsOwnerCancelTransfer => "ops_owner_cancel_transfer",
+            Method::OpsOwnerFinalizeTransfer => "ops_owner_finalize_transfer",
+            Method::OpsOwnerWithdrawBalance => "ops_owner_withdraw_balance",
+            Method::OpsOwnerSetAutoSweep => "ops_owner_set_auto_sweep",
+            Method::OpsOwnerSetEarningsMode => "ops_owner_set_earnings_mode",
+            Method::OpsOwnerSetEarningsFeeSplit => "ops_owner_set_earnings_fee_split",
+            Method::OpsOwnerAddFullAccessDelegate => "ops_owner_add_full_access_delegate",
+            Method::OpsOwnerRemoveDelegate => "ops_owner_remove_delegate",
+            Method::OpsOwnerSetGuardians => "ops_owner_set_guardians",
+            Method::OpsOwnerRecoveryVote => "ops_owner_recovery_vote",
+            Method::OpsOwnerRecoveryFinalize => "ops_owner_recovery_finalize",
+            Method::OpsOwnerRecoveryCancel => "ops_owner_recovery_cancel",
+            Method::OpsAccountProfileSet => "ops_account_profile_set",
+            Method::OpsStorageTopUpAccounts => "ops_storage_top_up_accounts",
+            Method::OpsFtRename => "ops_ft_rename",
+            Method::OpsFtSetReference => "ops_ft_set_reference",
+            Method::OpsPermissionsGrantAdmin => "ops_permissions_grant_admin",
+            Method::OpsPermissionsRevokeAdmin => "ops_permissions_revoke_admin",
+            Method::OpsPermissionsGrantOperator => "ops_permissions_grant_operator",
+            Method::OpsPermissionsRevokeOperator => "ops_permissions_revoke_operator",
+            Method::OpsPermissionsGrant => "ops_permissions_grant",
+            Method::OpsPermissionsGrantPermissions => "ops_permissions_grant_permissions",
+            Method::OpsPermissionsGrantWithExpiry => "ops_permissions_grant_with_expiry",
+            Method::OpsPermissionsRevoke => "ops_permissions_revoke",
+            Method::OpsPermissionsRevokePermissions => "ops_permissions_revoke_permissions",
+            Method::OpsPermissionsRevokeAll => "ops_permissions_revoke_all",
+            Method::OpsPermissionsGrantMany => "ops_permissions_grant_many",
+            Method::OpsPermissionsRevokeMany => "ops_permissions_revoke_many",
+            Method::OpsStakeGrantTreasurer => "ops_stake_grant_treasurer",
+            Method::OpsStakeRevokeTreasurer => "ops_stake_revoke_treasurer",
+            Method::OpsStakeTreasurySetBeneficiary => "ops_stake_treasury_set_beneficiary",
+            Method::OpsStakeTreasurySetDividendPolicy => "ops_stake_treasury_set_dividend_policy",
+            Method::OpsStakeTransferUnstaked => "ops_stake_transfer_unstaked",
+            Method::OpsStakeWithdrawTo => "ops_stake_withdraw_to",
+            Method::OpsGovPropose => "ops_gov_propose",
+            Method::OpsGovVote => "ops_gov_vote",
+            Method::OpsFtLock => "ops_ft_lock",
+            Method::OpsFtUnlock => "ops_ft_unlock",
+            Method::OpsFtAdminUnlock => "ops_ft_admin_unlock",
+            Method::OpsFtApproveLocker => "ops_ft_approve_locker",
+            Method::OpsFtRevokeLockerApproval => "ops_ft_revoke_locker_approval",
+            Method::OpsStakeGrantLocker => "ops_stake_grant_locker",
+            Method::OpsStakeRevokeLocker => "ops_stake_revoke_locker",
+            Method::OpsStakeGrantStaker => "ops_stake_grant_staker",
+            Method::OpsStakeRevokeStaker => "ops_stake_revoke_staker",
+            Method::OpsLiquidityRemove => "ops_liquidity_remove",
+            Method::OpsLiquiditySwap => "ops_liquidity_swap",
+            Method::OpsFarmClaim => "ops_farm_claim",
+        }
+    }
+
+    /// returns the deposit policy that is registered for the method
+    pub fn deposit_policy(&self) -> DepositPolicy {
+        match self {
+            // [NEP-141] fungible token transfers
+            Method::FtTransfer => DepositPolicy::OneYocto,
+            Method::FtTransferCall => DepositPolicy::OneYocto,
+            // contract ownership
+            Method::OpsOwnerTransfer => DepositPolicy::OneYocto,
+            Method::OpsOwnerTransferWithVesting => DepositPolicy::OneYocto,
+            Method::OpsOwnerCancelTransfer => DepositPolicy::OneYocto,
+            Method::OpsOwnerFinalizeTransfer => DepositPolicy::OneYocto,
+            Method::OpsOwnerWithdrawBalance => DepositPolicy::OneYocto,
+            Method::OpsOwnerSetAutoSweep => DepositPolicy::OneYocto,
+            Method::OpsOwnerSetEarningsMode => DepositPolicy::OneYocto,
+            Method::OpsOwnerSetEarningsFeeSplit => DepositPolicy::OneYocto,
+            Method::OpsOwnerAddFullAccessDelegate => DepositPolicy::OneYocto,
+            Method::OpsOwnerRemoveDelegate => DepositPolicy::OneYocto,
+            Method::OpsOwnerSetGuardians => DepositPolicy::OneYocto,
+            Method::OpsOwnerRecoveryVote => DepositPolicy::OneYocto,
+            Method::OpsOwnerRecoveryFinalize => DepositPolicy::OneYocto,
+            Method::OpsOwnerRecoveryCancel => DepositPolicy::OneYocto,
+            // account profiles
+            Method::OpsAccountProfileSet => DepositPolicy::OneYocto,
+            // account storage - the required top up deposit is computed by the method
+            Method::OpsStorageTopUpAccounts => DepositPolicy::MinDeposit(1.into()),
+            // fungible token metadata
+            Method::OpsFtRename => DepositPolicy::OneYocto,
+            Method::OpsFtSetReference => DepositPolicy::OneYocto,
+            // permissions management
+            Method::OpsPermissionsGrantAdmin => DepositPolicy::OneYocto,
+            Method::OpsPermissionsRevokeAdmin => DepositPolicy::OneYocto,
+            Method::OpsPermissionsGrantOperator => DepositPolicy::OneYocto,
+            Method::OpsPermissionsRevokeOperator => DepositPolicy::OneYocto,
+            Method::OpsPermissionsGrant => DepositPolicy::OneYocto,
+            Method::OpsPermissionsGrantPermissions => DepositPolicy::OneYocto,
+            Method::OpsPermissionsGrantWithExpiry => DepositPolicy::OneYocto,
+            Method::OpsPermissionsRevoke => DepositPolicy::OneYocto,
+            Method::OpsPermissionsRevokePermissions => DepositPolicy::OneYocto,
+            Method::OpsPermissionsRevokeAll => DepositPolicy::OneYocto,
+            Method::OpsPermissionsGrantMany => DepositPolicy::OneYocto,
+            Method::OpsPermissionsRevokeMany => DepositPolicy::OneYocto,
+            // staking pool treasury
+            Method::OpsStakeGrantTreasurer => DepositPolicy::OneYocto,
+            Method::OpsStakeRevokeTreasurer => DepositPolicy::OneYocto,
+            Method::OpsStakeTreasurySetBeneficiary => DepositPolicy::OneYocto,
+            Method::OpsStakeTreasurySetDividendPolicy => DepositPolicy::OneYocto,
+            // staking pool unstaked balance transfers
+            Method::OpsStakeTransferUnstaked => DepositPolicy::OneYocto,
+            Method::OpsStakeWithdrawTo => DepositPolicy::OneYocto,
+            // staking pool governance
+            Method::OpsGovPropose => DepositPolicy::OneYocto,
+            Method::OpsGovVote => DepositPolicy::OneYocto,
+            // staking pool STAKE locks
+            Method::OpsFtLock => DepositPolicy::OneYocto,
+            Method::OpsFtUnlock => DepositPolicy::OneYocto,
+            Method::OpsFtAdminUnlock => DepositPolicy::OneYocto,
+            Method::OpsFtApproveLocker => DepositPolicy::OneYocto,
+            Method::OpsFtRevokeLockerApproval => DepositPolicy::OneYocto,
+            Method::OpsStakeGrantLocker => DepositPolicy::OneYocto,
+            Method::OpsStakeRevokeLocker => DepositPolicy::OneYocto,
+            Method::OpsStakeGrantStaker => DepositPolicy::OneYocto,
+            Method::OpsStakeRevokeStaker => DepositPolicy::OneYocto,
+            // staking pool liquidity pool
+            Method::OpsLiquidityRemove => DepositPolicy::OneYocto,
+            Method::OpsLiquiditySwap => DepositPolicy::OneYocto,
+            // staking pool rewards farm
+            Method::OpsFarmClaim => DepositPolicy::OneYocto,
+        }
+    }
+}
+
+/// Asserts that the attached deposit satisfies the deposit policy registered for the method.
+///
+/// ## Panics
+/// if the attached deposit does not satisfy the policy
+pub fn assert_deposit_policy(method: Method) {
+    method.deposit_policy().assert()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near_test::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn one_yocto() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.with_deposit(1).apply();
+
+        assert_deposit_policy(Method::FtTransfer);
+        assert_eq!(Method::FtTransfer.deposit_policy(), DepositPolicy::OneYocto);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\",\"message\":\"exactly 1 yoctoNEAR must be attached"#
+    )]
+    fn one_yocto_with_zero_deposit() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        assert_deposit_policy(Method::OpsPermissionsGrantAdmin);
+    }
+
+    #[test]
+    fn min_deposit() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.with_deposit(100).apply();
+
+        DepositPolicy::MinDeposit(100.into()).assert();
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_NEAR_DEPOSIT\""#)]
+    fn min_deposit_insufficient() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.with_deposit(99).apply();
+
+        DepositPolicy::MinDeposit(100.into()).assert();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"BAD_REQUEST\",\"message\":\"attached deposit is not allowed"#
+    )]
+    fn no_deposit_with_deposit_attached() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.with_deposit(1).apply();
+
+        DepositPolicy::NoDeposit.assert();
+    }
+
+    #[test]
+    fn method_names_are_unique() {
+        let names: HashSet<&str> = Method::ALL.iter().map(Method::name).collect();
+        assert_eq!(names.len(), Method::ALL.len());
+        assert_eq!(Method::FtTransfer.name(), "ft_transfer");
+        assert_eq!(Method::OpsFarmClaim.name(), "ops_farm_claim");
+    }
+}
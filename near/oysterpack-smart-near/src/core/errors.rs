@@ -2,6 +2,7 @@ use near_sdk::{
     borsh::{self, BorshDeserialize, BorshSerialize},
     env,
     serde::{Deserialize, Serialize},
+    serde_json::{self, Value},
 };
 use std::fmt::{self, Display, Formatter};
 
@@ -54,8 +55,18 @@ impl<Msg> Error<Msg>
 where
     Msg: Display,
 {
+    /// panics with the JSON encoded [`ErrorPayload`]
     pub fn panic(&self) {
-        env::panic(self.to_string().as_bytes())
+        self.payload().panic()
+    }
+
+    /// panics with the JSON encoded [`ErrorPayload`], which includes the specified error data
+    pub fn panic_with_data<Data: Serialize>(&self, data: Data) {
+        self.payload().with_data(data).panic()
+    }
+
+    pub fn payload(&self) -> ErrorPayload {
+        ErrorPayload::new(self.0, &self.1)
     }
 
     pub fn log(&self) {
@@ -86,14 +97,24 @@ impl Display for ErrorConst {
 }
 
 impl ErrorConst {
+    /// panics with the JSON encoded [`ErrorPayload`]
     pub fn panic(&self) {
-        env::panic(self.to_string().as_bytes())
+        self.payload().panic()
     }
 
     pub fn panic_with_message<Msg: Display>(&self, msg: Msg) {
         self.0.error(msg).panic()
     }
 
+    /// panics with the JSON encoded [`ErrorPayload`], which includes the specified error data
+    pub fn panic_with_data<Data: Serialize>(&self, data: Data) {
+        self.payload().with_data(data).panic()
+    }
+
+    pub fn payload(&self) -> ErrorPayload {
+        ErrorPayload::new(self.0, self.1)
+    }
+
     pub fn assert<F>(&self, check: F)
     where
         F: FnOnce() -> bool,
@@ -113,6 +134,18 @@ impl ErrorConst {
         self.0.assert(check, msg)
     }
 
+    /// the error data is only computed if the check fails
+    pub fn assert_with_data<F, DataF, Data>(&self, check: F, data: DataF)
+    where
+        F: FnOnce() -> bool,
+        DataF: FnOnce() -> Data,
+        Data: Serialize,
+    {
+        if !check() {
+            self.panic_with_data(data());
+        }
+    }
+
     pub fn log(&self) {
         env::log(self.to_string().as_bytes());
     }
@@ -131,6 +164,63 @@ impl Display for Err {
     }
 }
 
+/// Machine-readable error that contracts panic with
+///
+/// The panic message is the JSON encoded payload, e.g.,
+/// ```json
+/// {"code":"INSUFFICIENT_FUNDS","message":"account has insufficient funds to fulfill request","data":{"required":"100","available":"10"}}
+/// ```
+/// - `data` is optional and is used to provide error specific details
+/// - clients can use [`ErrorPayload::from_panic_message`] to parse the error from the transaction
+///   failure message
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ErrorPayload {
+    pub code: String,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl ErrorPayload {
+    pub fn new<Msg: Display>(code: ErrCode, message: Msg) -> Self {
+        Self {
+            code: code.0.to_string(),
+            message: message.to_string(),
+            data: None,
+        }
+    }
+
+    pub fn with_data<Data: Serialize>(mut self, data: Data) -> Self {
+        self.data = Some(serde_json::to_value(data).unwrap());
+        self
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    /// extracts the error payload from the panic message
+    /// - the runtime wraps the contract panic message, e.g., `Smart contract panicked: {...}`, thus
+    ///   the payload is parsed starting from the first `{`
+    ///
+    /// Returns None if the message does not contain an error payload
+    pub fn from_panic_message(msg: &str) -> Option<Self> {
+        let start = msg.find('{')?;
+        serde_json::from_str(&msg[start..]).ok()
+    }
+
+    pub fn panic(&self) {
+        env::panic(self.to_json().as_bytes())
+    }
+}
+
+impl Display for ErrorPayload {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_json())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,6 +280,39 @@ mod tests {
         ERR.assert(|| false, || "BOOM");
     }
 
+    #[test]
+    fn error_payload_json() {
+        const ERR: ErrorConst = ErrorConst(ErrCode("INSUFFICIENT_FUNDS"), "not enough");
+
+        let payload = ERR.payload();
+        assert_eq!(
+            payload.to_json(),
+            r#"{"code":"INSUFFICIENT_FUNDS","message":"not enough"}"#
+        );
+
+        let payload = payload.with_data(vec!["100", "10"]);
+        assert_eq!(
+            payload.to_json(),
+            r#"{"code":"INSUFFICIENT_FUNDS","message":"not enough","data":["100","10"]}"#
+        );
+
+        let panic_msg = format!("Smart contract panicked: {}", payload);
+        assert_eq!(ErrorPayload::from_panic_message(&panic_msg), Some(payload));
+        assert_eq!(ErrorPayload::from_panic_message("BOOM"), None);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"{\"code\":\"INVALID_ACCOUNT_ID\",\"message\":\"BOOM\",\"data\":1}"#
+    )]
+    fn error_const_panic_with_data() {
+        let context = new_context("bob");
+        testing_env!(context);
+
+        const ERR: ErrorConst = ErrorConst(ErrCode("INVALID_ACCOUNT_ID"), "BOOM");
+        ERR.assert_with_data(|| false, || 1);
+    }
+
     #[test]
     fn into_result() {
         const ERR: ErrCode = ErrCode("INVALID_ACCOUNT_ID");
@@ -6,6 +6,7 @@ use near_sdk::{
     env,
 };
 use std::convert::TryInto;
+use std::fmt::{self, Display, Formatter};
 
 /// sha256 hashed data
 /// - JSON serialization format is Base64 encoded bytes
@@ -143,6 +144,13 @@ fn u128_to_bytes(value: u128) -> [u8; 16] {
     ]
 }
 
+/// Base64 encoded bytes, i.e., same as the JSON serialization format
+impl Display for Hash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", base64::encode(self.0))
+    }
+}
+
 impl Serialize for Hash {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
     where
@@ -195,7 +203,9 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [INVALID_HASH] hash length must be 32 bytes")]
+    #[should_panic(
+        expected = r#"{\"code\":\"INVALID_HASH\",\"message\":\"hash length must be 32 bytes"#
+    )]
     fn serde_json_33_char_string() {
         test_env::setup();
         let data = "Alfio Zappala II";
@@ -1,7 +1,27 @@
-use near_sdk::env;
+use crate::data::Object;
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+};
+use std::cell::Cell;
 use std::fmt::{self, Debug, Display, Formatter};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// levels are ordered by severity, i.e., `INFO` < `WARN`
+#[derive(
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+)]
+#[serde(crate = "near_sdk::serde")]
 pub enum Level {
     INFO,
     WARN,
@@ -19,11 +39,14 @@ impl Display for LogEvent {
 }
 
 impl LogEvent {
+    /// the event is not logged if its level is filtered out - see [`LogFilter`]
     pub fn log<Msg>(&self, msg: Msg)
     where
         Msg: Display,
     {
-        env::log(self.message(msg).as_bytes());
+        if LogFilter::enabled(self.0) {
+            env::log(self.message(msg).as_bytes());
+        }
     }
 
     pub fn message<Msg>(&self, msg: Msg) -> String
@@ -34,6 +57,60 @@ impl LogEvent {
     }
 }
 
+/// Runtime log level filter, which enables verbose [`Level::INFO`] events, e.g., liquidity and
+/// storage change events, to be suppressed in production to save gas
+/// - [`Level::WARN`] events are always logged
+/// - defaults to [`Level::INFO`], i.e., all events are logged
+/// - the level is cached for the duration of the contract call, i.e., storage is read at most once
+///   per call no matter how many events are logged
+pub struct LogFilter;
+
+const LOG_FILTER_KEY: u128 = 1958868147029367410582941763025918463;
+
+type LogFilterObject = Object<u128, Level>;
+
+thread_local! {
+    /// each contract call runs in a fresh WASM instance, which scopes the cache to the call
+    static LOG_FILTER_LEVEL: Cell<Option<Level>> = const { Cell::new(None) };
+}
+
+impl LogFilter {
+    /// returns the min level that is logged
+    pub fn level() -> Level {
+        LOG_FILTER_LEVEL.with(|cache| {
+            cache.get().unwrap_or_else(|| {
+                let level = Self::load();
+                cache.set(Some(level));
+                level
+            })
+        })
+    }
+
+    pub fn set_level(level: Level) {
+        if level == Level::INFO {
+            LogFilterObject::delete_by_key(&LOG_FILTER_KEY);
+        } else {
+            LogFilterObject::new(LOG_FILTER_KEY, level).save();
+        }
+        LOG_FILTER_LEVEL.with(|cache| cache.set(Some(level)));
+    }
+
+    /// returns true if events at the specified level are logged
+    /// - the filter is only looked up for levels below [`Level::WARN`]
+    pub fn enabled(level: Level) -> bool {
+        level >= Level::WARN || level >= Self::level()
+    }
+
+    /// short-circuits when no filter is stored, which is the default, to avoid deserializing the
+    /// object
+    fn load() -> Level {
+        if !LogFilterObject::exists(&LOG_FILTER_KEY) {
+            return Level::INFO;
+        }
+        LogFilterObject::load(&LOG_FILTER_KEY).map_or(Level::INFO, |level| *level)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,4 +122,40 @@ mod tests {
         LogEvent(Level::INFO, "FOO").log("message");
         println!("{:#?}", get_logs());
     }
+
+    #[test]
+    fn log_filter() {
+        test_env::setup();
+        assert_eq!(LogFilter::level(), Level::INFO);
+
+        LogFilter::set_level(Level::WARN);
+        LogEvent(Level::INFO, "FOO").log("suppressed");
+        LogEvent(Level::WARN, "BAR").log("message");
+        assert_eq!(get_logs(), vec!["[WARN] [BAR] message".to_string()]);
+
+        LogFilter::set_level(Level::INFO);
+        LogEvent(Level::INFO, "FOO").log("message");
+        assert_eq!(
+            get_logs(),
+            vec![
+                "[WARN] [BAR] message".to_string(),
+                "[INFO] [FOO] message".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn log_filter_level_is_cached_per_call() {
+        test_env::setup();
+        // no filter is stored by default
+        assert!(!LogFilterObject::exists(&LOG_FILTER_KEY));
+        assert!(LogFilter::enabled(Level::INFO));
+
+        LogFilter::set_level(Level::WARN);
+        assert!(LogFilterObject::exists(&LOG_FILTER_KEY));
+        // the level is not read back from storage within the same call
+        LogFilterObject::delete_by_key(&LOG_FILTER_KEY);
+        assert_eq!(LogFilter::level(), Level::WARN);
+        assert!(!LogFilter::enabled(Level::INFO));
+    }
 }
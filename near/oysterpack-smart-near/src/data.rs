@@ -1,6 +1,8 @@
 //! Provides support to work with data stored on the NEAR blockchain
 
+mod component_storage_usage;
 pub mod numbers;
 mod object;
 
+pub use component_storage_usage::*;
 pub use object::*;
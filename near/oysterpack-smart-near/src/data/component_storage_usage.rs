@@ -0,0 +1,112 @@
+use crate::data::Object;
+use crate::domain::{StorageUsage, StorageUsageChange};
+use crate::Hash;
+use near_sdk::env;
+
+const COMPONENT_STORAGE_USAGE_KEY: u128 = 1958150969845120736495018273640519284;
+
+type ComponentStorageUsageObject = Object<Hash, u64>;
+
+/// Maintains per component storage usage counters, which enables contract storage usage to be
+/// broken down by component without scanning contract storage
+/// - components are responsible for tracking their own storage usage changes
+/// - the counter records themselves are not attributed to any component
+pub struct ComponentStorageUsage;
+
+impl ComponentStorageUsage {
+    /// FT account balance records
+    pub const FT_BALANCES: &'static str = "ft_balances";
+    /// contract owned staking pool history and queue records
+    pub const STAKING: &'static str = "staking";
+    /// contract metrics history
+    pub const METRICS: &'static str = "metrics";
+
+    /// returns the storage usage that is attributed to the component
+    pub fn load(component: &str) -> StorageUsage {
+        ComponentStorageUsageObject::load(&Self::key(component))
+            .map_or(StorageUsage::default(), |storage_usage| {
+                (*storage_usage).into()
+            })
+    }
+
+    /// applies the storage usage change to the component's counter
+    /// - the counter saturates at zero
+    pub fn update(component: &str, change: StorageUsageChange) {
+        if *change == 0 {
+            return;
+        }
+        let storage_usage = *Self::load(component) as i64 + *change;
+        ComponentStorageUsageObject::new(Self::key(component), storage_usage.max(0) as u64).save();
+    }
+
+    /// runs the function and attributes the storage usage change to the component
+    pub fn track<T, F>(component: &str, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let initial_storage_usage = env::storage_usage();
+        let result = f();
+        let change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        Self::update(component, change.into());
+        result
+    }
+
+    fn key(component: &str) -> Hash {
+        Hash::from((component, COMPONENT_STORAGE_USAGE_KEY))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use oysterpack_smart_near_test::*;
+
+    type Data = Object<u128, u128>;
+
+    #[test]
+    fn track() {
+        // Arrange
+        let context = new_context("bob");
+        testing_env!(context);
+        assert_eq!(
+            ComponentStorageUsage::load(ComponentStorageUsage::METRICS),
+            StorageUsage::default()
+        );
+
+        // Act
+        let storage_usage = env::storage_usage();
+        ComponentStorageUsage::track(ComponentStorageUsage::METRICS, || {
+            Data::new(1, 1).save();
+        });
+        let change = env::storage_usage() - storage_usage;
+
+        // Assert
+        let metrics_storage_usage = ComponentStorageUsage::load(ComponentStorageUsage::METRICS);
+        assert!(*metrics_storage_usage > 0);
+        assert!(*metrics_storage_usage < change);
+        assert_eq!(
+            ComponentStorageUsage::load(ComponentStorageUsage::STAKING),
+            StorageUsage::default()
+        );
+
+        // Act
+        ComponentStorageUsage::track(ComponentStorageUsage::METRICS, || {
+            Data::delete_by_key(&1);
+        });
+
+        // Assert
+        assert_eq!(
+            ComponentStorageUsage::load(ComponentStorageUsage::METRICS),
+            StorageUsage::default()
+        );
+
+        // Act - counters saturate at zero
+        ComponentStorageUsage::update(ComponentStorageUsage::METRICS, (-10_i64).into());
+
+        // Assert
+        assert_eq!(
+            ComponentStorageUsage::load(ComponentStorageUsage::METRICS),
+            StorageUsage::default()
+        );
+    }
+}
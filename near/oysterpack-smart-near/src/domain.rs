@@ -10,6 +10,7 @@ mod epoch_height;
 mod expiration;
 mod gas;
 mod public_key;
+mod receipt_gas_budget;
 mod storage_usage;
 mod storage_usage_change;
 mod tgas;
@@ -24,6 +25,7 @@ pub use epoch_height::*;
 pub use expiration::*;
 pub use gas::*;
 pub use public_key::*;
+pub use receipt_gas_budget::*;
 pub use storage_usage::*;
 pub use storage_usage_change::*;
 pub use tgas::*;
@@ -9,7 +9,7 @@ use near_sdk::{
 };
 use std::{
     fmt::{self, Display, Formatter},
-    ops::{Deref, DerefMut},
+    ops::{Add, AddAssign, Deref, DerefMut, Sub, SubAssign},
 };
 
 #[derive(
@@ -35,6 +35,12 @@ impl BlockHeight {
     pub fn value(&self) -> u64 {
         self.0
     }
+
+    /// returns the number of blocks that have elapsed since `earlier`
+    /// - returns 0 if `earlier` is after this block height
+    pub fn elapsed_since(&self, earlier: Self) -> u64 {
+        self.0.saturating_sub(earlier.0)
+    }
 }
 
 impl From<u64> for BlockHeight {
@@ -57,6 +63,43 @@ impl DerefMut for BlockHeight {
     }
 }
 
+impl Add<u64> for BlockHeight {
+    type Output = Self;
+
+    fn add(self, rhs: u64) -> Self::Output {
+        Self(self.0 + rhs)
+    }
+}
+
+impl AddAssign<u64> for BlockHeight {
+    fn add_assign(&mut self, rhs: u64) {
+        self.0 += rhs
+    }
+}
+
+impl Sub<u64> for BlockHeight {
+    type Output = Self;
+
+    fn sub(self, rhs: u64) -> Self::Output {
+        Self(self.0 - rhs)
+    }
+}
+
+impl SubAssign<u64> for BlockHeight {
+    fn sub_assign(&mut self, rhs: u64) {
+        self.0 -= rhs
+    }
+}
+
+/// returns the number of blocks between the two values
+impl Sub<BlockHeight> for BlockHeight {
+    type Output = u64;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.0 - rhs.0
+    }
+}
+
 impl Display for BlockHeight {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
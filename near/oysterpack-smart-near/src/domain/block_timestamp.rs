@@ -9,7 +9,7 @@ use near_sdk::{
 };
 use std::{
     fmt::{self, Display, Formatter},
-    ops::{Deref, DerefMut},
+    ops::{Add, AddAssign, Deref, DerefMut, Sub, SubAssign},
 };
 
 /// Current block timestamp, i.e, number of non-leap-nanoseconds since January 1, 1970 0:00:00 UTC.
@@ -36,6 +36,12 @@ impl BlockTimestamp {
     pub fn value(&self) -> u64 {
         self.0
     }
+
+    /// returns the number of nanoseconds that have elapsed since `earlier`
+    /// - returns 0 if `earlier` is after this timestamp
+    pub fn elapsed_since(&self, earlier: Self) -> u64 {
+        self.0.saturating_sub(earlier.0)
+    }
 }
 
 impl From<u64> for BlockTimestamp {
@@ -58,6 +64,43 @@ impl DerefMut for BlockTimestamp {
     }
 }
 
+impl Add<u64> for BlockTimestamp {
+    type Output = Self;
+
+    fn add(self, rhs: u64) -> Self::Output {
+        Self(self.0 + rhs)
+    }
+}
+
+impl AddAssign<u64> for BlockTimestamp {
+    fn add_assign(&mut self, rhs: u64) {
+        self.0 += rhs
+    }
+}
+
+impl Sub<u64> for BlockTimestamp {
+    type Output = Self;
+
+    fn sub(self, rhs: u64) -> Self::Output {
+        Self(self.0 - rhs)
+    }
+}
+
+impl SubAssign<u64> for BlockTimestamp {
+    fn sub_assign(&mut self, rhs: u64) {
+        self.0 -= rhs
+    }
+}
+
+/// returns the number of nanoseconds between the two values
+impl Sub<BlockTimestamp> for BlockTimestamp {
+    type Output = u64;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.0 - rhs.0
+    }
+}
+
 impl Display for BlockTimestamp {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -9,7 +9,7 @@ use near_sdk::{
 };
 use std::{
     fmt::{self, Display, Formatter},
-    ops::{Deref, DerefMut},
+    ops::{Add, AddAssign, Deref, DerefMut, Sub, SubAssign},
 };
 
 #[derive(
@@ -35,6 +35,12 @@ impl EpochHeight {
     pub fn value(&self) -> u64 {
         self.0
     }
+
+    /// returns the number of epochs that have elapsed since `earlier`
+    /// - returns 0 if `earlier` is after this epoch
+    pub fn elapsed_since(&self, earlier: Self) -> u64 {
+        self.0.saturating_sub(earlier.0)
+    }
 }
 
 impl From<u64> for EpochHeight {
@@ -57,6 +63,43 @@ impl DerefMut for EpochHeight {
     }
 }
 
+impl Add<u64> for EpochHeight {
+    type Output = Self;
+
+    fn add(self, rhs: u64) -> Self::Output {
+        Self(self.0 + rhs)
+    }
+}
+
+impl AddAssign<u64> for EpochHeight {
+    fn add_assign(&mut self, rhs: u64) {
+        self.0 += rhs
+    }
+}
+
+impl Sub<u64> for EpochHeight {
+    type Output = Self;
+
+    fn sub(self, rhs: u64) -> Self::Output {
+        Self(self.0 - rhs)
+    }
+}
+
+impl SubAssign<u64> for EpochHeight {
+    fn sub_assign(&mut self, rhs: u64) {
+        self.0 -= rhs
+    }
+}
+
+/// returns the number of epochs between the two values
+impl Sub<EpochHeight> for EpochHeight {
+    type Output = u64;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.0 - rhs.0
+    }
+}
+
 impl Display for EpochHeight {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -107,3 +150,24 @@ impl<'de> Visitor<'de> for YoctoNearVisitor {
         self.visit_str(&v)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let mut epoch = EpochHeight(100);
+        assert_eq!(epoch + 4, EpochHeight(104));
+        assert_eq!(epoch - 4, EpochHeight(96));
+        assert_eq!(EpochHeight(104) - epoch, 4);
+
+        epoch += 2;
+        assert_eq!(epoch, EpochHeight(102));
+        epoch -= 1;
+        assert_eq!(epoch, EpochHeight(101));
+
+        assert_eq!(epoch.elapsed_since(EpochHeight(99)), 2);
+        assert_eq!(epoch.elapsed_since(EpochHeight(105)), 0);
+    }
+}
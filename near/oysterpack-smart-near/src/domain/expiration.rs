@@ -1,7 +1,6 @@
 use crate::domain::{BlockHeight, BlockTimestamp, EpochHeight};
 use near_sdk::{
     borsh::{self, BorshDeserialize, BorshSerialize},
-    env,
     serde::{Deserialize, Serialize},
 };
 use std::fmt::{self, Display, Formatter};
@@ -33,9 +32,9 @@ pub enum Expiration {
 impl Expiration {
     pub fn expired(&self) -> bool {
         match *self {
-            Expiration::Epoch(epoch) => env::epoch_height() > epoch.value(),
-            Expiration::Block(block) => env::block_index() > block.value(),
-            Expiration::Timestamp(timestamp) => env::block_timestamp() > timestamp.value(),
+            Expiration::Epoch(epoch) => EpochHeight::from_env() > epoch,
+            Expiration::Block(block) => BlockHeight::from_env() > block,
+            Expiration::Timestamp(timestamp) => BlockTimestamp::from_env() > timestamp,
         }
     }
 }
@@ -54,14 +53,14 @@ impl From<ExpirationDuration> for Expiration {
     fn from(duration: ExpirationDuration) -> Self {
         match duration {
             ExpirationDuration::Epochs(duration) => {
-                Expiration::Epoch((env::epoch_height() + duration as u64).into())
+                Expiration::Epoch(EpochHeight::from_env() + duration as u64)
             }
             ExpirationDuration::Blocks(duration) => {
-                Expiration::Block((env::block_index() + duration as u64).into())
+                Expiration::Block(BlockHeight::from_env() + duration as u64)
+            }
+            ExpirationDuration::Seconds(duration) => {
+                Expiration::Timestamp(BlockTimestamp::from_env() + 1_000_000_000 * duration as u64)
             }
-            ExpirationDuration::Seconds(duration) => Expiration::Timestamp(
-                (env::block_timestamp() + (1_000_000_000 * duration as u64)).into(),
-            ),
         }
     }
 }
@@ -128,10 +127,9 @@ mod tests {
 
     #[test]
     fn epoch_expiration() {
-        let mut ctx = new_context("bob");
+        let mut ctx = TestCtx::new("bob");
 
-        ctx.epoch_height = 1000;
-        testing_env!(ctx.clone());
+        ctx.epoch_height(1000).apply();
 
         assert!(!Expiration::Epoch(1000.into()).expired());
         assert!(Expiration::Epoch(999.into()).expired());
@@ -144,10 +142,9 @@ mod tests {
 
     #[test]
     fn block_expiration() {
-        let mut ctx = new_context("bob");
+        let mut ctx = TestCtx::new("bob");
 
-        ctx.block_index = 1000;
-        testing_env!(ctx.clone());
+        ctx.block_index(1000).apply();
 
         assert!(!Expiration::Block(1000.into()).expired());
         assert!(Expiration::Block(999.into()).expired());
@@ -160,10 +157,9 @@ mod tests {
 
     #[test]
     fn timestamp_expiration() {
-        let mut ctx = new_context("bob");
+        let mut ctx = TestCtx::new("bob");
 
-        ctx.block_timestamp = 1000;
-        testing_env!(ctx.clone());
+        ctx.block_timestamp(1000).apply();
 
         assert!(!Expiration::Timestamp(1000.into()).expired());
         assert!(Expiration::Timestamp(999.into()).expired());
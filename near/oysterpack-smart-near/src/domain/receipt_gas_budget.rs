@@ -0,0 +1,319 @@
+use crate::domain::{
+    ActionType, ByteLen, Gas, SenderIsReceiver, TransactionResource, TransactionResourceCount,
+};
+use crate::{asserts::ERR_INVALID, TERA};
+use near_sdk::{
+    env,
+    serde::{Deserialize, Serialize},
+};
+
+/// Budgets the gas that is required to complete a function call given the receipts it will create:
+/// - runtime fees to create the receipts, which are computed via [`Gas::compute`]
+/// - gas that is attached to the function call receipts
+/// - gas that is required to complete the remaining compute within the current function call
+///
+/// Receipts are added in the order they are chained. Callbacks receive the result of the preceding
+/// receipt, i.e., the data receipt cost depends on whether the preceding receipt was on this contract.
+///
+/// ## Example
+/// ```rust
+/// use oysterpack_smart_near::domain::{ReceiptGasBudget, TGas};
+///
+/// // ft_on_transfer -> ft_resolve_transfer_call
+/// let budget = ReceiptGasBudget::new()
+///     .function_call(256, TGas(10).into())
+///     .callback(256, 100, TGas(5).into())
+///     .compute(TGas(5).into());
+/// assert!(budget.total() > TGas(20).into());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ReceiptGasBudget {
+    costs: Vec<(TransactionResource, TransactionResourceCount)>,
+    attached_gas: Gas,
+    compute_gas: Gas,
+    last_receipt_on_self: bool,
+}
+
+impl ReceiptGasBudget {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// function call receipt on another contract
+    /// - `bytes` - method name length plus the serialized args length
+    /// - `gas` - gas attached to the function call
+    pub fn function_call(mut self, bytes: u64, gas: Gas) -> Self {
+        self.costs.push((
+            TransactionResource::ActionReceipt(SenderIsReceiver(false)),
+            1,
+        ));
+        self.costs.push((
+            TransactionResource::Action(ActionType::FunctionCall(
+                SenderIsReceiver(false),
+                ByteLen(bytes),
+            )),
+            1,
+        ));
+        self.attached_gas += gas;
+        self.last_receipt_on_self = false;
+        self
+    }
+
+    /// callback function call receipt on this contract, which receives the result of the preceding
+    /// receipt
+    /// - `bytes` - method name length plus the serialized args length
+    /// - `result_bytes` - size of the preceding receipt's result
+    /// - `gas` - gas attached to the callback
+    pub fn callback(mut self, bytes: u64, result_bytes: u64, gas: Gas) -> Self {
+        self.costs.push((
+            TransactionResource::ActionReceipt(SenderIsReceiver(true)),
+            1,
+        ));
+        self.costs.push((
+            TransactionResource::Action(ActionType::FunctionCall(
+                SenderIsReceiver(true),
+                ByteLen(bytes),
+            )),
+            1,
+        ));
+        self.costs.push((
+            TransactionResource::DataReceipt(
+                SenderIsReceiver(self.last_receipt_on_self),
+                ByteLen(result_bytes),
+            ),
+            1,
+        ));
+        self.attached_gas += gas;
+        self.last_receipt_on_self = true;
+        self
+    }
+
+    /// stake action receipt on this contract
+    pub fn stake(mut self) -> Self {
+        self.costs.push((
+            TransactionResource::ActionReceipt(SenderIsReceiver(true)),
+            1,
+        ));
+        self.costs.push((
+            TransactionResource::Action(ActionType::Stake(SenderIsReceiver(true))),
+            1,
+        ));
+        self.last_receipt_on_self = true;
+        self
+    }
+
+    /// transfer action receipt to another account
+    pub fn transfer(mut self) -> Self {
+        self.costs.push((
+            TransactionResource::ActionReceipt(SenderIsReceiver(false)),
+            1,
+        ));
+        self.costs
+            .push((TransactionResource::Action(ActionType::Transfer), 1));
+        self.last_receipt_on_self = false;
+        self
+    }
+
+    /// gas that is required to complete the remaining compute within the current function call
+    pub fn compute(mut self, gas: Gas) -> Self {
+        self.compute_gas += gas;
+        self
+    }
+
+    /// runtime fees to create the receipts
+    pub fn receipts_gas(&self) -> Gas {
+        if self.costs.is_empty() {
+            return Gas::ZERO;
+        }
+        Gas::compute(self.costs.clone())
+    }
+
+    /// gas attached to the function call receipts
+    pub fn attached_gas(&self) -> Gas {
+        self.attached_gas
+    }
+
+    pub fn compute_gas(&self) -> Gas {
+        self.compute_gas
+    }
+
+    /// receipts gas + attached gas + compute gas
+    pub fn total(&self) -> Gas {
+        self.receipts_gas() + self.attached_gas + self.compute_gas
+    }
+
+    /// Returns the prepaid gas that remains after the budget is applied, which is normally attached
+    /// to the receipt that is allotted "all remaining gas"
+    /// - returns None if there is not enough prepaid gas to cover the budget
+    pub fn remaining(&self) -> Option<Gas> {
+        (env::prepaid_gas() - env::used_gas())
+            .checked_sub(*self.total())
+            .map(Gas)
+    }
+
+    /// Returns the prepaid gas that remains after the budget is applied - see [`Self::remaining`]
+    ///
+    /// ## Panics
+    /// with [`ERR_INVALID`] if there is not enough prepaid gas to cover the budget
+    pub fn assert_remaining(&self) -> Gas {
+        let remaining = self.remaining();
+        ERR_INVALID.assert(
+            || remaining.is_some(),
+            || {
+                let min_required_gas = env::used_gas() + *self.total();
+                format!(
+                    "not enough gas was attached - min required gas is {} TGas",
+                    min_required_gas / TERA + 1 // round up 1 TGas
+                )
+            },
+        );
+        remaining.unwrap()
+    }
+}
+
+/// presets for common workflows
+impl ReceiptGasBudget {
+    /// conservative size estimate for callback args
+    pub const CALLBACK_BYTES: u64 = 512;
+
+    /// stake action -> callback on this contract
+    /// - the stake action produces no result, but the data receipt size is conservatively estimated
+    pub fn stake_with_callback(callback_gas: Gas) -> Self {
+        Self::new()
+            .stake()
+            .callback(Self::CALLBACK_BYTES, 200, callback_gas)
+    }
+
+    /// ft_on_transfer on the receiver contract -> ft_resolve_transfer_call on this contract
+    ///
+    /// The transfer amount result byte length is set to 100 because even though the underlying type
+    /// is u128, it is marshalled as a string. Thus, the number of bytes will vary depending on the
+    /// amount value - 100 is conservative.
+    pub fn ft_transfer_call(
+        ft_on_transfer_bytes: u64,
+        ft_on_transfer_gas: Gas,
+        ft_resolve_transfer_call_bytes: u64,
+        ft_resolve_transfer_call_gas: Gas,
+    ) -> Self {
+        Self::new()
+            .function_call(ft_on_transfer_bytes, ft_on_transfer_gas)
+            .callback(
+                ft_resolve_transfer_call_bytes,
+                100,
+                ft_resolve_transfer_call_gas,
+            )
+    }
+}
+
+/// Minimum gas that should be attached when calling the contract method
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct GasEstimate {
+    pub method: String,
+    pub gas: Gas,
+}
+
+impl GasEstimate {
+    pub fn new(method: &str, gas: Gas) -> Self {
+        Self {
+            method: method.to_string(),
+            gas,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::TGas;
+    use near_sdk::test_utils::test_env;
+
+    #[test]
+    fn stake_with_callback_preset() {
+        test_env::setup();
+
+        let budget = ReceiptGasBudget::stake_with_callback(TGas(10).into());
+        let expected_receipts_gas = Gas::compute(vec![
+            (
+                TransactionResource::ActionReceipt(SenderIsReceiver(true)),
+                2,
+            ),
+            (
+                TransactionResource::Action(ActionType::Stake(SenderIsReceiver(true))),
+                1,
+            ),
+            (
+                TransactionResource::Action(ActionType::FunctionCall(
+                    SenderIsReceiver(true),
+                    ByteLen(512),
+                )),
+                1,
+            ),
+            (
+                TransactionResource::DataReceipt(SenderIsReceiver(true), ByteLen(200)),
+                1,
+            ),
+        ]);
+        assert_eq!(budget.receipts_gas(), expected_receipts_gas);
+        assert_eq!(budget.attached_gas(), TGas(10).into());
+        assert_eq!(budget.total(), expected_receipts_gas + TGas(10));
+    }
+
+    #[test]
+    fn ft_transfer_call_preset() {
+        test_env::setup();
+
+        let budget = ReceiptGasBudget::ft_transfer_call(100, TGas(10).into(), 200, TGas(5).into())
+            .compute(TGas(5).into());
+        let expected_receipts_gas = Gas::compute(vec![
+            (
+                TransactionResource::ActionReceipt(SenderIsReceiver(false)),
+                1,
+            ),
+            (
+                TransactionResource::Action(ActionType::FunctionCall(
+                    SenderIsReceiver(false),
+                    ByteLen(100),
+                )),
+                1,
+            ),
+            (
+                TransactionResource::ActionReceipt(SenderIsReceiver(true)),
+                1,
+            ),
+            (
+                TransactionResource::Action(ActionType::FunctionCall(
+                    SenderIsReceiver(true),
+                    ByteLen(200),
+                )),
+                1,
+            ),
+            (
+                TransactionResource::DataReceipt(SenderIsReceiver(false), ByteLen(100)),
+                1,
+            ),
+        ]);
+        assert_eq!(budget.receipts_gas(), expected_receipts_gas);
+        assert_eq!(budget.total(), expected_receipts_gas + TGas(20));
+        assert!(budget.remaining().unwrap() < Gas(env::prepaid_gas()) - budget.total());
+    }
+
+    #[test]
+    fn remaining_with_not_enough_prepaid_gas() {
+        test_env::setup();
+
+        let budget = ReceiptGasBudget::new().compute(Gas(env::prepaid_gas() + 1));
+        assert!(budget.remaining().is_none());
+        assert_eq!(ReceiptGasBudget::new().receipts_gas(), Gas::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "not enough gas was attached")]
+    fn assert_remaining_with_not_enough_prepaid_gas() {
+        test_env::setup();
+
+        ReceiptGasBudget::new()
+            .compute(Gas(env::prepaid_gas() + 1))
+            .assert_remaining();
+    }
+}
@@ -1,3 +1,7 @@
+mod stake_pool_registry;
+
+pub use stake_pool_registry::*;
+
 use near_sdk::{
     borsh::{self, BorshDeserialize, BorshSerialize},
     env, is_promise_success,
@@ -6,9 +10,9 @@ use near_sdk::{
     serde::{Deserialize, Serialize},
     AccountId, PanicOnDefault, Promise,
 };
-use oysterpack_smart_near::asserts::ERR_INVALID;
+use oysterpack_smart_near::asserts::{assert_yocto_near_attached, ERR_INVALID};
 use oysterpack_smart_near::domain::{
-    ActionType, Gas, SenderIsReceiver, TGas, TransactionResource, YoctoNear,
+    ActionType, BlockTimestamp, Gas, SenderIsReceiver, TGas, TransactionResource, YoctoNear,
 };
 use oysterpack_smart_near::{
     domain::{BasisPoints, PublicKey},
@@ -17,58 +21,111 @@ use oysterpack_smart_near::{
 
 near_sdk::setup_alloc!();
 
+/// STAKE pool factory, which enables anyone to create a STAKE pool by paying the creation fee
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
-pub struct Contract;
+pub struct Contract {
+    owner: AccountId,
+    /// charged for each STAKE pool that is created
+    creation_fee: YoctoNear,
+    /// creation fees that have been collected and not yet withdrawn by the owner
+    collected_fees: YoctoNear,
+}
 
+pub const ERR_NOT_AUTHORIZED: ErrCode = ErrCode("NOT_AUTHORIZED");
 pub const ERR_INSUFFICIENT_ATTACHED_DEPOSIT: ErrCode = ErrCode("INSUFFICIENT_ATTACHED_DEPOSIT");
 pub const ERR_STAKE_POOL_DEPLOY_FAILURE: ErrCode = ErrCode("STAKE_POOL_DEPLOY_FAILURE");
 
 pub const LOG_EVENT_STAKE_POOL_DEPLOY_SUCCESS: LogEvent =
     LogEvent(Level::INFO, "STAKE_POOL_DEPLOY_SUCCESS");
+pub const LOG_EVENT_CREATION_FEE_UPDATED: LogEvent = LogEvent(Level::INFO, "CREATION_FEE_UPDATED");
+pub const LOG_EVENT_CREATION_FEES_WITHDRAWN: LogEvent =
+    LogEvent(Level::INFO, "CREATION_FEES_WITHDRAWN");
+
+/// storage key used by near-sdk to store the contract state
+const STATE_KEY: &[u8] = b"STATE";
 
 /// conservatively overestimated
 const STAKE_DEPLOY_GAS: Gas = Gas(100 * TERA);
 
 #[near_bindgen]
 impl Contract {
+    /// ## Args
+    /// - owner - factory owner, who collects the creation fees - defaults to the predecessor account
+    /// - creation_fee - default is zero
     #[init]
-    pub fn init() -> Self {
-        Self
+    pub fn init(owner: Option<ValidAccountId>, creation_fee: Option<YoctoNear>) -> Self {
+        Self {
+            owner: owner.map_or_else(env::predecessor_account_id, |owner| {
+                owner.as_ref().to_string()
+            }),
+            creation_fee: creation_fee.unwrap_or(YoctoNear::ZERO),
+            collected_fees: YoctoNear::ZERO,
+        }
     }
 
-    /// Used to deploy an instance of the STAKE pool contract as child contract using the following
-    /// naming convention: `{stake_symbol}.{env::current_account_id}`
+    /// Migrates the factory from the previous contract version, which had no state
+    /// - STAKE pools that were deployed by the previous version were not tracked, and thus are not
+    ///   registered
     ///
     /// ## Args
-    /// - stake_symbol - will be used to create the child account ID, which will also be used as the STAKE FT symbol
+    /// - owner - factory owner, who collects the creation fees
+    /// - creation_fee - default is zero
+    ///
+    /// ## Panics
+    /// - if not called by the contract itself
+    /// - if the contract state is not the previous version state, e.g., it was already migrated
+    #[init(ignore_state)]
+    #[private]
+    pub fn migrate(owner: ValidAccountId, creation_fee: Option<YoctoNear>) -> Self {
+        ERR_INVALID.assert(
+            || env::storage_read(STATE_KEY).is_some_and(|state| state.is_empty()),
+            || "contract state is not the previous version state",
+        );
+        Self::init(Some(owner), creation_fee)
+    }
+
+    /// Used to create an instance of the STAKE pool contract as child contract using the following
+    /// naming convention: `{name}.{env::current_account_id}`
+    ///
+    /// The attached deposit must cover the creation fee plus the STAKE pool contract storage costs.
+    /// The creation fee is collected once the STAKE pool is successfully deployed. The rest of the
+    /// attached deposit is transferred to the STAKE pool account. If the deployment fails, then the
+    /// full attached deposit is refunded.
+    ///
+    /// ## Args
+    /// - name - will be used to create the child account ID, which will also be used as the STAKE FT symbol
     /// - stake_public_key - public key that binds the STAKE pool to the validator node
-    /// - owner - STAKE pool owner
-    /// - staking_fee - default is 0 BPS
-    /// - earnings_fee - default is 100 BPS
+    /// - fees - default is 0 BPS staking fee and 100 BPS earnings fee
+    /// - owner - STAKE pool owner - defaults to the predecessor account
+    ///
+    /// ## Panics
+    /// - if a STAKE pool with the same name has already been created
+    /// - if not enough deposit is attached
     #[payable]
-    pub fn deploy(
-        stake_symbol: String,
+    pub fn create_pool(
+        &mut self,
+        name: String,
         stake_public_key: PublicKey,
+        fees: Option<StakePoolFees>,
         owner: Option<ValidAccountId>,
-        staking_fee: Option<BasisPoints>,
-        earnings_fee: Option<BasisPoints>,
     ) -> Promise {
         let stake_pool_account_id = {
-            let stake_pool_account_id = format!(
-                "{}.{}",
-                stake_symbol.to_lowercase(),
-                env::current_account_id()
-            );
+            let stake_pool_account_id =
+                format!("{}.{}", name.to_lowercase(), env::current_account_id());
             to_valid_account_id(&stake_pool_account_id)
         };
+        ERR_INVALID.assert(
+            || !StakePoolRegistry::contains(stake_pool_account_id.as_ref()),
+            || "STAKE pool already exists",
+        );
 
         let stake_contract_wasm_bytes = {
             let stake_contract_wasm_bytes = Self::stake_contract_wasm_bytes();
             let contract_storage_costs =
                 stake_contract_wasm_bytes.len() as u128 * env::storage_byte_cost();
             // an extra NEAR is used to pay for contract operational storage costs
-            let min_required_deposit = contract_storage_costs + YOCTO;
+            let min_required_deposit = *self.creation_fee + contract_storage_costs + YOCTO;
             ERR_INSUFFICIENT_ATTACHED_DEPOSIT.assert(
                 || env::attached_deposit() >= min_required_deposit,
                 || format!("No enough deposit was attached for deploying the STAKE pool contract. Min required attached deposit is {} yoctoNEAR", min_required_deposit),
@@ -76,18 +133,20 @@ impl Contract {
             stake_contract_wasm_bytes
         };
 
+        let fees = fees.unwrap_or_default();
+        let owner = owner.unwrap_or_else(|| to_valid_account_id(&env::predecessor_account_id()));
         let deploy = Promise::new(stake_pool_account_id.as_ref().clone())
             .create_account()
-            .transfer(env::attached_deposit())
+            .transfer(env::attached_deposit() - *self.creation_fee)
             .deploy_contract(stake_contract_wasm_bytes)
             .function_call(
                 b"deploy".to_vec(),
                 near_sdk::serde_json::to_vec(&StakePoolDeployArgs {
                     stake_public_key,
-                    owner: owner.or(Some(to_valid_account_id(&env::predecessor_account_id()))),
-                    staking_fee,
-                    earnings_fee,
-                    stake_symbol: Some(stake_symbol),
+                    owner: Some(owner.clone()),
+                    staking_fee: fees.staking_fee,
+                    earnings_fee: fees.earnings_fee,
+                    stake_symbol: Some(name),
                 })
                 .unwrap(),
                 0,
@@ -98,6 +157,14 @@ impl Contract {
             Some(OnDeployArgs {
                 account_id: env::predecessor_account_id(),
                 deposit: env::attached_deposit().into(),
+                creation_fee: self.creation_fee,
+                pool: StakePool {
+                    account_id: stake_pool_account_id.as_ref().clone(),
+                    owner: owner.as_ref().clone(),
+                    stake_public_key,
+                    fees,
+                    created_on: BlockTimestamp::from_env(),
+                },
             }),
             YoctoNear::ZERO,
             Self::callback_gas(),
@@ -106,17 +173,95 @@ impl Contract {
     }
 
     #[private]
-    pub fn on_deploy(&mut self, account_id: AccountId, deposit: YoctoNear) {
+    pub fn on_deploy(
+        &mut self,
+        account_id: AccountId,
+        deposit: YoctoNear,
+        creation_fee: YoctoNear,
+        pool: StakePool,
+    ) {
         if is_promise_success() {
-            LOG_EVENT_STAKE_POOL_DEPLOY_SUCCESS.log("");
+            LOG_EVENT_STAKE_POOL_DEPLOY_SUCCESS.log(format!(
+                "account_id={}, owner={}, creation_fee={}",
+                pool.account_id, pool.owner, creation_fee
+            ));
+            self.collected_fees += creation_fee;
+            StakePoolRegistry::register(pool);
         } else {
             ERR_STAKE_POOL_DEPLOY_FAILURE.log("");
             Promise::new(account_id).transfer(*deposit);
         }
     }
+
+    pub fn owner(&self) -> AccountId {
+        self.owner.clone()
+    }
+
+    pub fn creation_fee(&self) -> YoctoNear {
+        self.creation_fee
+    }
+
+    pub fn collected_fees(&self) -> YoctoNear {
+        self.collected_fees
+    }
+
+    pub fn pool(&self, account_id: ValidAccountId) -> Option<StakePool> {
+        StakePoolRegistry::get(account_id.as_ref())
+    }
+
+    pub fn pool_count(&self) -> u64 {
+        StakePoolRegistry::count()
+    }
+
+    /// returns up to `limit` pools starting from the `from` position, ordered by creation
+    pub fn pools(&self, from: u64, limit: u64) -> Vec<StakePool> {
+        StakePoolRegistry::pools(from, limit)
+    }
+
+    /// ## Panics
+    /// - if exactly 1 yoctoNEAR is not attached
+    /// - [`ERR_NOT_AUTHORIZED`] - if not called by the owner
+    #[payable]
+    pub fn set_creation_fee(&mut self, creation_fee: YoctoNear) {
+        assert_yocto_near_attached();
+        self.assert_owner();
+        self.creation_fee = creation_fee;
+        LOG_EVENT_CREATION_FEE_UPDATED.log(creation_fee);
+    }
+
+    /// Transfers the collected creation fees to the owner
+    /// - if amount is not specified, then all collected fees are withdrawn
+    ///
+    /// Returns the collected fees balance after the withdrawal
+    ///
+    /// ## Panics
+    /// - if exactly 1 yoctoNEAR is not attached
+    /// - [`ERR_NOT_AUTHORIZED`] - if not called by the owner
+    /// - if the amount is more than the collected fees
+    #[payable]
+    pub fn withdraw_fees(&mut self, amount: Option<YoctoNear>) -> YoctoNear {
+        assert_yocto_near_attached();
+        self.assert_owner();
+        let amount = amount.unwrap_or(self.collected_fees);
+        ERR_INVALID.assert(
+            || amount <= self.collected_fees,
+            || "amount is more than the collected fees",
+        );
+        self.collected_fees -= amount;
+        LOG_EVENT_CREATION_FEES_WITHDRAWN.log(amount);
+        Promise::new(self.owner.clone()).transfer(*amount);
+        self.collected_fees
+    }
 }
 
 impl Contract {
+    fn assert_owner(&self) {
+        ERR_NOT_AUTHORIZED.assert(
+            || env::predecessor_account_id() == self.owner,
+            || "only the owner can perform this operation",
+        );
+    }
+
     fn stake_contract_wasm_bytes() -> Vec<u8> {
         include_bytes!("../../target/wasm32-unknown-unknown/release/oysterpack_smart_stake.wasm")
             .to_vec()
@@ -166,13 +311,15 @@ struct StakePoolDeployArgs {
 struct OnDeployArgs {
     account_id: AccountId,
     deposit: YoctoNear,
+    creation_fee: YoctoNear,
+    pool: StakePool,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use oysterpack_smart_near_test::{
-        near_sdk::{serde_json, test_utils},
+        near_sdk::{serde_json, test_utils, PromiseResult},
         *,
     };
 
@@ -190,20 +337,35 @@ mod tests {
         );
     }
 
+    fn stake_pool() -> StakePool {
+        StakePool {
+            account_id: format!("pearl.{}", env::current_account_id()),
+            owner: "bob".to_string(),
+            stake_public_key: staking_public_key(),
+            fees: StakePoolFees::default(),
+            created_on: BlockTimestamp::from_env(),
+        }
+    }
+
+    fn factory() -> Contract {
+        Contract::init(Some(to_valid_account_id("owner")), Some(YOCTO.into()))
+    }
+
     #[test]
-    fn deploy() {
-        let mut ctx = new_context("bob");
-        ctx.prepaid_gas = 300 * TERA;
-        ctx.attached_deposit = 10 * YOCTO;
-        testing_env!(ctx);
+    fn create_pool() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.with_gas(300 * TERA).with_deposit(10 * YOCTO).apply();
+        let mut factory = factory();
         let staking_fee = Some(BasisPoints(10));
         let earnings_fee = Some(BasisPoints(50));
-        Contract::deploy(
+        factory.create_pool(
             "PEARL".to_string(),
             staking_public_key(),
+            Some(StakePoolFees {
+                staking_fee,
+                earnings_fee,
+            }),
             None,
-            staking_fee,
-            earnings_fee,
         );
 
         let receipts = get_receipts();
@@ -226,7 +388,8 @@ mod tests {
                 let action = &receipt.actions[1];
                 match action {
                     Action::Transfer(action) => {
-                        assert_eq!(action.deposit, env::attached_deposit());
+                        // creation fee is retained by the factory
+                        assert_eq!(action.deposit, env::attached_deposit() - YOCTO);
                     }
                     _ => panic!("expected Transfer"),
                 }
@@ -271,6 +434,16 @@ mod tests {
                     let args: OnDeployArgs = serde_json::from_str(&action.args).unwrap();
                     assert_eq!(args.account_id, env::predecessor_account_id());
                     assert_eq!(args.deposit, env::attached_deposit().into());
+                    assert_eq!(args.creation_fee, YOCTO.into());
+                    assert_eq!(args.pool.account_id, receipts[0].receiver_id);
+                    assert_eq!(args.pool.owner, "bob");
+                    assert_eq!(
+                        args.pool.fees,
+                        StakePoolFees {
+                            staking_fee,
+                            earnings_fee
+                        }
+                    );
                 }
                 _ => panic!("expected FunctionCall"),
             }
@@ -279,32 +452,60 @@ mod tests {
 
     #[test]
     fn on_deploy_success() {
-        let mut ctx = new_context("bob");
-        testing_env!(ctx.clone());
-
-        ctx.predecessor_account_id = ctx.current_account_id.clone();
-        testing_env_with_promise_result_success(ctx.clone());
-
-        Contract.on_deploy("bob".to_string(), (10 * YOCTO).into());
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        ctx.predecessor(&ctx.context().current_account_id.clone())
+            .apply_with_promise_results(vec![PromiseResult::Successful(vec![])]);
+
+        let mut factory = factory();
+        factory.on_deploy(
+            "bob".to_string(),
+            (10 * YOCTO).into(),
+            YOCTO.into(),
+            stake_pool(),
+        );
 
         let logs = test_utils::get_logs();
         println!("{:#?}", logs);
-        assert_eq!(logs, vec!["[INFO] [STAKE_POOL_DEPLOY_SUCCESS] ",]);
+        assert_eq!(
+            logs,
+            vec![format!(
+                "[INFO] [STAKE_POOL_DEPLOY_SUCCESS] account_id=pearl.{}, owner=bob, creation_fee={}",
+                env::current_account_id(),
+                YOCTO
+            )]
+        );
+        assert_eq!(factory.collected_fees(), YOCTO.into());
+        assert_eq!(factory.pool_count(), 1);
+        assert_eq!(factory.pools(0, 10), vec![stake_pool()]);
+        assert_eq!(
+            factory.pool(to_valid_account_id(&stake_pool().account_id)),
+            Some(stake_pool())
+        );
     }
 
     #[test]
     fn on_deploy_failure() {
-        let mut ctx = new_context("bob");
-        testing_env!(ctx.clone());
-
-        ctx.predecessor_account_id = ctx.current_account_id.clone();
-        testing_env_with_promise_result_failure(ctx.clone());
-
-        Contract.on_deploy("bob".to_string(), (10 * YOCTO).into());
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        ctx.predecessor(&ctx.context().current_account_id.clone())
+            .apply_with_promise_results(vec![PromiseResult::Failed]);
+
+        let mut factory = factory();
+        factory.on_deploy(
+            "bob".to_string(),
+            (10 * YOCTO).into(),
+            YOCTO.into(),
+            stake_pool(),
+        );
 
         let logs = test_utils::get_logs();
         println!("{:#?}", logs);
         assert_eq!(logs, vec!["[ERR] [STAKE_POOL_DEPLOY_FAILURE] ",]);
+        assert_eq!(factory.collected_fees(), YoctoNear::ZERO);
+        assert_eq!(factory.pool_count(), 0);
 
         let receipts = deserialize_receipts();
         assert_eq!(receipts.len(), 1);
@@ -320,33 +521,171 @@ mod tests {
     }
 
     #[test]
-    fn serialize_deserialize_deploy_args() {
+    #[should_panic(expected = r#"{\"code\":\"INVALID\""#)]
+    fn create_pool_already_exists() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.with_gas(300 * TERA).with_deposit(10 * YOCTO).apply();
+        let mut factory = factory();
+        StakePoolRegistry::register(stake_pool());
+
+        factory.create_pool("PEARL".to_string(), staking_public_key(), None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_ATTACHED_DEPOSIT\""#)]
+    fn create_pool_without_creation_fee() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.with_gas(300 * TERA).apply();
+        let mut factory = factory();
+        let min_required_deposit =
+            Contract::stake_contract_wasm_bytes().len() as u128 * env::storage_byte_cost() + YOCTO;
+        ctx.with_deposit(min_required_deposit).apply();
+
+        factory.create_pool("PEARL".to_string(), staking_public_key(), None, None);
+    }
+
+    #[test]
+    fn migrate() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.predecessor(&ctx.context().current_account_id.clone())
+            .apply();
+        // the previous contract version state was empty
+        env::storage_write(STATE_KEY, &[]);
+
+        let factory = Contract::migrate(to_valid_account_id("owner"), Some(YOCTO.into()));
+
+        assert_eq!(factory.owner(), "owner");
+        assert_eq!(factory.creation_fee(), YOCTO.into());
+        assert_eq!(factory.collected_fees(), YoctoNear::ZERO);
+        assert_eq!(factory.pool_count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"INVALID\""#)]
+    fn migrate_already_migrated() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.predecessor(&ctx.context().current_account_id.clone())
+            .apply();
+        env::state_write(&factory());
+
+        Contract::migrate(to_valid_account_id("owner"), None);
+    }
+
+    #[test]
+    fn set_creation_fee() {
+        let mut ctx = TestCtx::new("owner");
+        ctx.with_deposit(1).apply();
+        let mut factory = factory();
+
+        factory.set_creation_fee((2 * YOCTO).into());
+
+        assert_eq!(factory.creation_fee(), (2 * YOCTO).into());
+        let logs = test_utils::get_logs();
+        assert_eq!(
+            logs,
+            vec![format!("[INFO] [CREATION_FEE_UPDATED] {}", 2 * YOCTO)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
+    fn set_creation_fee_not_owner() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.with_deposit(1).apply();
+        let mut factory = factory();
+
+        factory.set_creation_fee((2 * YOCTO).into());
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"YOCTONEAR_DEPOSIT_REQUIRED\""#)]
+    fn set_creation_fee_without_yocto() {
+        let mut ctx = TestCtx::new("owner");
+        ctx.apply();
+        let mut factory = factory();
+
+        factory.set_creation_fee((2 * YOCTO).into());
+    }
+
+    #[test]
+    fn withdraw_fees() {
+        let mut ctx = TestCtx::new("owner");
+        ctx.with_deposit(1).apply();
+        let mut factory = factory();
+        factory.collected_fees = (3 * YOCTO).into();
+
+        // Act - partial withdrawal
+        let collected_fees = factory.withdraw_fees(Some(YOCTO.into()));
+
+        // Assert
+        assert_eq!(collected_fees, (2 * YOCTO).into());
+        let receipts = deserialize_receipts();
+        receipts[0].assert_receiver("owner").assert_transfer(YOCTO);
+
+        // Act - withdraw all
+        let collected_fees = factory.withdraw_fees(None);
+
+        // Assert
+        assert_eq!(collected_fees, YoctoNear::ZERO);
+        let logs = test_utils::get_logs();
+        assert_eq!(
+            logs,
+            vec![
+                format!("[INFO] [CREATION_FEES_WITHDRAWN] {}", YOCTO),
+                format!("[INFO] [CREATION_FEES_WITHDRAWN] {}", 2 * YOCTO),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"INVALID\""#)]
+    fn withdraw_fees_more_than_collected() {
+        let mut ctx = TestCtx::new("owner");
+        ctx.with_deposit(1).apply();
+        let mut factory = factory();
+
+        factory.withdraw_fees(Some(YOCTO.into()));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
+    fn withdraw_fees_not_owner() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.with_deposit(1).apply();
+        let mut factory = factory();
+
+        factory.withdraw_fees(None);
+    }
+
+    #[test]
+    fn serialize_deserialize_create_pool_args() {
         let stake_public_key =
             serde_json::from_str("\"ed25519:GTi3gtSio5ZYYKTT8WVovqJEob6KqdmkTi8KqGSfwqdm\"")
                 .unwrap();
-        let args = DeployArgs {
-            stake_symbol: "PEARL".to_string(),
+        let args = CreatePoolArgs {
+            name: "PEARL".to_string(),
             stake_public_key,
+            fees: Some(StakePoolFees {
+                staking_fee: None,
+                earnings_fee: Some(BasisPoints(50)),
+            }),
             owner: None,
-            staking_fee: None,
-            earnings_fee: None,
         };
 
         let json = serde_json::to_string(&args).unwrap();
         println!("{}", json);
 
-        let json = r#"{"stake_symbol":"PEARL","stake_public_key":"ed25519:GTi3gtSio5ZYYKTT8WVovqJEob6KqdmkTi8KqGSfwqdm"}"#;
-        let args2: DeployArgs = serde_json::from_str(&json).unwrap();
+        let json = r#"{"name":"PEARL","stake_public_key":"ed25519:GTi3gtSio5ZYYKTT8WVovqJEob6KqdmkTi8KqGSfwqdm","fees":{"earnings_fee":50}}"#;
+        let args2: CreatePoolArgs = serde_json::from_str(json).unwrap();
         assert_eq!(args2, args);
     }
 
     #[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
     #[serde(crate = "near_sdk::serde")]
-    struct DeployArgs {
-        stake_symbol: String,
+    struct CreatePoolArgs {
+        name: String,
         stake_public_key: PublicKey,
+        fees: Option<StakePoolFees>,
         owner: Option<ValidAccountId>,
-        staking_fee: Option<BasisPoints>,
-        earnings_fee: Option<BasisPoints>,
     }
 }
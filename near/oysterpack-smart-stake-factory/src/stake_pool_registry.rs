@@ -0,0 +1,147 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::domain::{BasisPoints, BlockTimestamp, PublicKey};
+use oysterpack_smart_near::{data::Object, Hash};
+use std::ops::Deref;
+
+/// STAKE pool fees that are applied when the pool contract is deployed
+/// - if not specified, then the STAKE pool contract defaults are used
+#[derive(
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StakePoolFees {
+    pub staking_fee: Option<BasisPoints>,
+    pub earnings_fee: Option<BasisPoints>,
+}
+
+/// STAKE pool that was created by the factory
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StakePool {
+    /// `{name}.{factory_account_id}`
+    pub account_id: AccountId,
+    pub owner: AccountId,
+    pub stake_public_key: PublicKey,
+    pub fees: StakePoolFees,
+    pub created_on: BlockTimestamp,
+}
+
+const STAKE_POOL_COUNT_KEY: u128 = 1958150935218830471025639184726193047;
+const STAKE_POOL_ENTRY_KEY: u128 = 1958150947703615284930172658391046218;
+const STAKE_POOL_KEY: u128 = 1958150958362047195837461029384756102;
+
+type StakePoolCountObject = Object<u128, u64>;
+type StakePoolEntryObject = Object<Hash, AccountId>;
+type StakePoolObject = Object<Hash, StakePool>;
+
+/// Registry of the STAKE pools that were created by the factory, which enables the pools to be
+/// enumerated
+/// - pools are registered once the pool contract has been successfully deployed
+pub struct StakePoolRegistry;
+
+impl StakePoolRegistry {
+    /// number of registered pools
+    pub fn count() -> u64 {
+        StakePoolCountObject::load(&STAKE_POOL_COUNT_KEY).map_or(0, |count| *count)
+    }
+
+    pub fn contains(account_id: &str) -> bool {
+        StakePoolObject::exists(&Self::pool_key(account_id))
+    }
+
+    pub fn get(account_id: &str) -> Option<StakePool> {
+        StakePoolObject::load(&Self::pool_key(account_id)).map(|pool| pool.deref().clone())
+    }
+
+    /// returns up to `limit` pools starting from the `from` position, ordered by creation
+    pub fn pools(from: u64, limit: u64) -> Vec<StakePool> {
+        let end = Self::count().min(from.saturating_add(limit));
+        (from..end)
+            .filter_map(|position| StakePoolEntryObject::load(&Self::entry_key(position)))
+            .filter_map(|account_id| Self::get(&account_id))
+            .collect()
+    }
+
+    /// registers the pool - no-op if the pool is already registered
+    pub(crate) fn register(pool: StakePool) {
+        if Self::contains(&pool.account_id) {
+            return;
+        }
+        let position = Self::count();
+        StakePoolCountObject::new(STAKE_POOL_COUNT_KEY, position + 1).save();
+        StakePoolEntryObject::new(Self::entry_key(position), pool.account_id.clone()).save();
+        StakePoolObject::new(Self::pool_key(&pool.account_id), pool).save();
+    }
+
+    fn entry_key(position: u64) -> Hash {
+        Hash::from((&position.to_le_bytes()[..], STAKE_POOL_ENTRY_KEY))
+    }
+
+    fn pool_key(account_id: &str) -> Hash {
+        Hash::from((account_id, STAKE_POOL_KEY))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::serde_json;
+    use oysterpack_smart_near_test::*;
+
+    fn stake_pool(account_id: &str) -> StakePool {
+        StakePool {
+            account_id: account_id.to_string(),
+            owner: "owner".to_string(),
+            stake_public_key: serde_json::from_str(
+                "\"ed25519:GTi3gtSio5ZYYKTT8WVovqJEob6KqdmkTi8KqGSfwqdm\"",
+            )
+            .unwrap(),
+            fees: StakePoolFees::default(),
+            created_on: BlockTimestamp(100),
+        }
+    }
+
+    #[test]
+    fn register() {
+        // Arrange
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        // Act
+        for account_id in ["pearl.factory", "ruby.factory", "onyx.factory"].iter() {
+            StakePoolRegistry::register(stake_pool(account_id));
+        }
+        let mut pearl = stake_pool("pearl.factory");
+        pearl.owner = "alice".to_string();
+        StakePoolRegistry::register(pearl);
+
+        // Assert
+        assert_eq!(StakePoolRegistry::count(), 3);
+        assert!(StakePoolRegistry::contains("ruby.factory"));
+        assert!(!StakePoolRegistry::contains("jade.factory"));
+        assert_eq!(
+            StakePoolRegistry::get("pearl.factory"),
+            Some(stake_pool("pearl.factory"))
+        );
+        assert!(StakePoolRegistry::get("jade.factory").is_none());
+        assert_eq!(
+            StakePoolRegistry::pools(1, 10),
+            vec![stake_pool("ruby.factory"), stake_pool("onyx.factory")]
+        );
+        assert_eq!(StakePoolRegistry::pools(0, 1).len(), 1);
+        assert!(StakePoolRegistry::pools(3, 10).is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+//! End-to-end simulation harness for the STAKE contract
+//!
+//! The harness deploys the real STAKE contract WASM into a local NEAR sandbox node, which enables
+//! workflows that span multiple receipts and epochs to be exercised, e.g.,
+//! - stake actions and their callbacks
+//! - validator rewards
+//! - unstaked NEAR being locked until enough epochs have passed
+
+use near_workspaces::network::Sandbox;
+use near_workspaces::result::ExecutionFinalResult;
+use near_workspaces::types::{KeyType, NearToken, SecretKey};
+use near_workspaces::{Account, Contract, Worker};
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+pub use near_workspaces;
+
+/// relative to this crate's manifest directory
+pub const STAKE_WASM_PATH: &str =
+    "../target/wasm32-unknown-unknown/release/oysterpack_smart_stake.wasm";
+
+/// max number of blocks to fast forward while waiting for unstaked NEAR to unlock
+pub const MAX_FAST_FORWARD_BLOCKS: u64 = 10_000;
+
+/// number of blocks to fast forward per step while waiting for unstaked NEAR to unlock
+pub const FAST_FORWARD_STEP: u64 = 100;
+
+pub struct StakeSim {
+    pub worker: Worker<Sandbox>,
+    pub contract: Contract,
+    pub owner: Account,
+}
+
+impl StakeSim {
+    /// Starts a sandbox node, deploys the STAKE contract WASM, and initializes the contract
+    /// - the staking public key is a random ED25519 key, i.e., the key is not a validator key
+    ///   that is known to the network
+    /// - staking and earnings fees are set to zero to keep the simulated balances easy to reason about
+    pub async fn new() -> anyhow::Result<Self> {
+        let worker = near_workspaces::sandbox().await?;
+        let wasm = std::fs::read(Self::wasm_path())?;
+        let contract = worker.dev_deploy(&wasm).await?;
+        let owner = worker.dev_create_account().await?;
+
+        let stake_public_key = SecretKey::from_random(KeyType::ED25519)
+            .public_key()
+            .to_string();
+        contract
+            .call("deploy")
+            .args_json(json!({
+                "stake_public_key": stake_public_key,
+                "owner": owner.id(),
+                "staking_fee": 0,
+                "earnings_fee": 0,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?;
+
+        Ok(Self {
+            worker,
+            contract,
+            owner,
+        })
+    }
+
+    pub fn wasm_path() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(STAKE_WASM_PATH)
+    }
+
+    /// creates a new account and registers it with the STAKE contract
+    pub async fn create_staker(&self) -> anyhow::Result<Account> {
+        let account = self.worker.dev_create_account().await?;
+        let bounds: Value = self.contract.view("storage_balance_bounds").await?.json()?;
+        account
+            .call(self.contract.id(), "storage_deposit")
+            .args_json(json!({ "registration_only": true }))
+            .deposit(NearToken::from_yoctonear(yocto(&bounds["min"])))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?;
+        Ok(account)
+    }
+
+    pub async fn stake(
+        &self,
+        account: &Account,
+        amount: NearToken,
+    ) -> anyhow::Result<ExecutionFinalResult> {
+        Ok(account
+            .call(self.contract.id(), "ops_stake")
+            .deposit(amount)
+            .max_gas()
+            .transact()
+            .await?)
+    }
+
+    /// if amount is None, then all STAKE is unstaked
+    pub async fn unstake(
+        &self,
+        account: &Account,
+        amount: Option<NearToken>,
+    ) -> anyhow::Result<ExecutionFinalResult> {
+        Ok(account
+            .call(self.contract.id(), "ops_unstake")
+            .args_json(json!({ "amount": amount.map(|amount| amount.as_yoctonear().to_string()) }))
+            .max_gas()
+            .transact()
+            .await?)
+    }
+
+    /// if amount is None, then all available unstaked NEAR is withdrawn
+    pub async fn withdraw(
+        &self,
+        account: &Account,
+        amount: Option<NearToken>,
+    ) -> anyhow::Result<ExecutionFinalResult> {
+        Ok(account
+            .call(self.contract.id(), "ops_stake_withdraw")
+            .args_json(json!({ "amount": amount.map(|amount| amount.as_yoctonear().to_string()) }))
+            .max_gas()
+            .transact()
+            .await?)
+    }
+
+    pub async fn stake_balance(&self, account: &Account) -> anyhow::Result<Value> {
+        Ok(self
+            .contract
+            .view("ops_stake_balance")
+            .args_json(json!({ "account_id": account.id() }))
+            .await?
+            .json()?)
+    }
+
+    pub async fn pool_balances(&self) -> anyhow::Result<Value> {
+        Ok(self
+            .contract
+            .view("ops_stake_pool_balances")
+            .await?
+            .json()?)
+    }
+
+    pub async fn status(&self) -> anyhow::Result<Value> {
+        Ok(self.contract.view("ops_stake_status").await?.json()?)
+    }
+
+    pub async fn operator_command(&self, command: Value) -> anyhow::Result<ExecutionFinalResult> {
+        Ok(self
+            .owner
+            .call(self.contract.id(), "ops_stake_operator_command")
+            .args_json(json!({ "command": command }))
+            .max_gas()
+            .transact()
+            .await?)
+    }
+
+    pub async fn start_staking(&self) -> anyhow::Result<ExecutionFinalResult> {
+        self.operator_command(json!("StartStaking")).await
+    }
+
+    /// Simulates validator rewards by transferring NEAR to the contract account.
+    ///
+    /// The staking pool detects the contract balance increase as staking rewards, and distributes
+    /// them the next time earnings are updated.
+    pub async fn distribute_rewards(&self, amount: NearToken) -> anyhow::Result<()> {
+        self.owner
+            .transfer_near(self.contract.id(), amount)
+            .await?
+            .into_result()?;
+        self.operator_command(json!("UpdateEarnings"))
+            .await?
+            .into_result()?;
+        Ok(())
+    }
+
+    /// Fast forwards the sandbox node until all of the account's unstaked NEAR is available for
+    /// withdrawal.
+    ///
+    /// Returns the account's available unstaked balance.
+    pub async fn wait_for_unstaked_balance_available(
+        &self,
+        account: &Account,
+    ) -> anyhow::Result<u128> {
+        let mut blocks = 0;
+        loop {
+            let balance = self.stake_balance(account).await?;
+            let unstaked = &balance["unstaked"];
+            if unstaked.is_null() {
+                return Ok(0);
+            }
+            let (total, available) = (yocto(&unstaked["total"]), yocto(&unstaked["available"]));
+            if total == available {
+                return Ok(available);
+            }
+            anyhow::ensure!(
+                blocks < MAX_FAST_FORWARD_BLOCKS,
+                "unstaked NEAR did not unlock after fast forwarding {} blocks",
+                blocks
+            );
+            self.worker.fast_forward(FAST_FORWARD_STEP).await?;
+            blocks += FAST_FORWARD_STEP;
+        }
+    }
+}
+
+/// parses a JSON encoded yoctoNEAR amount, which is serialized as a string
+/// - null is parsed as zero
+pub fn yocto(value: &Value) -> u128 {
+    match value {
+        Value::Null => 0,
+        Value::String(amount) => amount.parse().expect("invalid yoctoNEAR amount"),
+        _ => panic!("expected yoctoNEAR amount to be a JSON string: {}", value),
+    }
+}
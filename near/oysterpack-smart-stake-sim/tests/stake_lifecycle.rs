@@ -0,0 +1,132 @@
+use oysterpack_smart_stake_sim::near_workspaces::types::NearToken;
+use oysterpack_smart_stake_sim::{yocto, StakeSim};
+use serde_json::json;
+
+/// stake -> validator rewards -> unstake -> withdraw
+/// - the pool is offline, i.e., staked NEAR is held by the contract
+#[tokio::test]
+async fn stake_rewards_unstake_withdraw() -> anyhow::Result<()> {
+    // Arrange
+    let sim = StakeSim::new().await?;
+    let alice = sim.create_staker().await?;
+    let bob = sim.create_staker().await?;
+
+    // Act - stake
+    sim.stake(&alice, NearToken::from_near(10))
+        .await?
+        .into_result()?;
+    sim.stake(&bob, NearToken::from_near(30))
+        .await?
+        .into_result()?;
+    // Assert
+    let balances = sim.pool_balances().await?;
+    assert_eq!(
+        yocto(&balances["total_staked"]),
+        NearToken::from_near(40).as_yoctonear()
+    );
+    let alice_balance = sim.stake_balance(&alice).await?;
+    let alice_stake_value = yocto(&alice_balance["staked"]["near_value"]);
+    assert_eq!(alice_stake_value, NearToken::from_near(10).as_yoctonear());
+
+    // Act - validator rewards
+    sim.distribute_rewards(NearToken::from_near(4)).await?;
+    // Assert - rewards are distributed proportionally to STAKE
+    let alice_balance = sim.stake_balance(&alice).await?;
+    let bob_balance = sim.stake_balance(&bob).await?;
+    let alice_stake_value = yocto(&alice_balance["staked"]["near_value"]);
+    let bob_stake_value = yocto(&bob_balance["staked"]["near_value"]);
+    assert!(alice_stake_value > NearToken::from_near(10).as_yoctonear());
+    assert!(bob_stake_value > NearToken::from_near(30).as_yoctonear());
+    assert_eq!(bob_stake_value / alice_stake_value, 3);
+
+    // Act - unstake all
+    sim.unstake(&alice, None).await?.into_result()?;
+    // Assert
+    let alice_balance = sim.stake_balance(&alice).await?;
+    assert!(alice_balance["staked"].is_null());
+    assert_eq!(
+        yocto(&alice_balance["unstaked"]["total"]),
+        alice_stake_value
+    );
+
+    // Act - withdraw once the unstaked NEAR is unlocked
+    let available = sim.wait_for_unstaked_balance_available(&alice).await?;
+    assert_eq!(available, alice_stake_value);
+    let account_balance_before_withdrawal = alice.view_account().await?.balance;
+    sim.withdraw(&alice, None).await?.into_result()?;
+    // Assert
+    let alice_balance = sim.stake_balance(&alice).await?;
+    assert!(alice_balance["unstaked"].is_null());
+    let account_balance_after_withdrawal = alice.view_account().await?.balance;
+    assert!(
+        account_balance_after_withdrawal.as_yoctonear()
+            > account_balance_before_withdrawal.as_yoctonear()
+    );
+    // bob's STAKE is unaffected
+    let bob_balance = sim.stake_balance(&bob).await?;
+    assert_eq!(yocto(&bob_balance["staked"]["near_value"]), bob_stake_value);
+
+    Ok(())
+}
+
+/// The staking public key is not a validator key and the staked amount is below the sandbox's
+/// validator seat price, which causes the stake action to fail.
+/// - the pool should go offline with reason `StakeActionFailed`
+/// - the account STAKE balances should not be affected
+#[tokio::test]
+async fn failed_stake_action() -> anyhow::Result<()> {
+    // Arrange
+    let sim = StakeSim::new().await?;
+    let alice = sim.create_staker().await?;
+    sim.stake(&alice, NearToken::from_near(10))
+        .await?
+        .into_result()?;
+
+    // Act
+    let result = sim.start_staking().await?;
+
+    // Assert
+    let logs = result.logs();
+    assert!(
+        logs.iter()
+            .any(|log| log.contains("[STATUS_OFFLINE] StakeActionFailed")),
+        "{:#?}",
+        logs
+    );
+    assert_eq!(
+        sim.status().await?,
+        json!({ "Offline": "StakeActionFailed" })
+    );
+    let alice_balance = sim.stake_balance(&alice).await?;
+    assert_eq!(
+        yocto(&alice_balance["staked"]["near_value"]),
+        NearToken::from_near(10).as_yoctonear()
+    );
+
+    // Act - staking while offline after the failed stake action still works
+    sim.stake(&alice, NearToken::from_near(5))
+        .await?
+        .into_result()?;
+    // Assert
+    assert_eq!(
+        sim.status().await?,
+        json!({ "Offline": "StakeActionFailed" })
+    );
+    let alice_balance = sim.stake_balance(&alice).await?;
+    assert_eq!(
+        yocto(&alice_balance["staked"]["near_value"]),
+        NearToken::from_near(15).as_yoctonear()
+    );
+
+    // Act - funds can still be unstaked and withdrawn
+    sim.unstake(&alice, None).await?.into_result()?;
+    let available = sim.wait_for_unstaked_balance_available(&alice).await?;
+    assert_eq!(available, NearToken::from_near(15).as_yoctonear());
+    sim.withdraw(&alice, None).await?.into_result()?;
+    // Assert
+    let alice_balance = sim.stake_balance(&alice).await?;
+    assert!(alice_balance["staked"].is_null());
+    assert!(alice_balance["unstaked"].is_null());
+
+    Ok(())
+}
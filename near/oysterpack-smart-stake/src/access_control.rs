@@ -1,6 +1,10 @@
 use crate::*;
 use near_sdk::json_types::ValidAccountId;
-use oysterpack_smart_account_management::{Permissions, PermissionsManagement};
+use oysterpack_smart_account_management::{
+    AdminApprovalsManagement, ApprovalPolicy, PendingApproval, PermissionGrant, Permissions,
+    PermissionsAuditLogEntry, PermissionsManagement,
+};
+use oysterpack_smart_near::{data::numbers::U64, domain::BlockHeight, Hash};
 use std::collections::HashMap;
 
 #[near_bindgen]
@@ -9,10 +13,12 @@ impl PermissionsManagement for Contract {
         Self::account_manager().ops_permissions_is_admin(account_id)
     }
 
+    #[payable]
     fn ops_permissions_grant_admin(&mut self, account_id: ValidAccountId) {
         Self::account_manager().ops_permissions_grant_admin(account_id);
     }
 
+    #[payable]
     fn ops_permissions_revoke_admin(&mut self, account_id: ValidAccountId) {
         Self::account_manager().ops_permissions_revoke_admin(account_id);
     }
@@ -21,18 +27,22 @@ impl PermissionsManagement for Contract {
         Self::account_manager().ops_permissions_is_operator(account_id)
     }
 
+    #[payable]
     fn ops_permissions_grant_operator(&mut self, account_id: ValidAccountId) {
         Self::account_manager().ops_permissions_grant_operator(account_id);
     }
 
+    #[payable]
     fn ops_permissions_revoke_operator(&mut self, account_id: ValidAccountId) {
         Self::account_manager().ops_permissions_revoke_operator(account_id);
     }
 
+    #[payable]
     fn ops_permissions_grant(&mut self, account_id: ValidAccountId, permissions: Permissions) {
         Self::account_manager().ops_permissions_grant(account_id, permissions);
     }
 
+    #[payable]
     fn ops_permissions_grant_permissions(
         &mut self,
         account_id: ValidAccountId,
@@ -41,10 +51,26 @@ impl PermissionsManagement for Contract {
         Self::account_manager().ops_permissions_grant_permissions(account_id, permissions);
     }
 
+    #[payable]
+    fn ops_permissions_grant_with_expiry(
+        &mut self,
+        account_id: ValidAccountId,
+        permissions: Permissions,
+        expires_at: BlockHeight,
+    ) {
+        Self::account_manager().ops_permissions_grant_with_expiry(
+            account_id,
+            permissions,
+            expires_at,
+        );
+    }
+
+    #[payable]
     fn ops_permissions_revoke(&mut self, account_id: ValidAccountId, permissions: Permissions) {
         Self::account_manager().ops_permissions_revoke(account_id, permissions);
     }
 
+    #[payable]
     fn ops_permissions_revoke_permissions(
         &mut self,
         account_id: ValidAccountId,
@@ -53,10 +79,21 @@ impl PermissionsManagement for Contract {
         Self::account_manager().ops_permissions_revoke_permissions(account_id, permissions);
     }
 
+    #[payable]
     fn ops_permissions_revoke_all(&mut self, account_id: ValidAccountId) {
         Self::account_manager().ops_permissions_revoke_all(account_id);
     }
 
+    #[payable]
+    fn ops_permissions_grant_many(&mut self, grants: Vec<(ValidAccountId, Permissions)>) {
+        Self::account_manager().ops_permissions_grant_many(grants);
+    }
+
+    #[payable]
+    fn ops_permissions_revoke_many(&mut self, revokes: Vec<(ValidAccountId, Permissions)>) {
+        Self::account_manager().ops_permissions_revoke_many(revokes);
+    }
+
     fn ops_permissions_contains(
         &self,
         account_id: ValidAccountId,
@@ -69,11 +106,41 @@ impl PermissionsManagement for Contract {
         Self::account_manager().ops_permissions(account_id)
     }
 
-    fn ops_permissions_granted(&self, account_id: ValidAccountId) -> Option<HashMap<u8, String>> {
+    fn ops_permissions_granted(
+        &self,
+        account_id: ValidAccountId,
+    ) -> Option<HashMap<u8, PermissionGrant>> {
         Self::account_manager().ops_permissions_granted(account_id)
     }
 
     fn ops_permissions_contract_permissions(&self) -> Option<HashMap<u8, String>> {
         Self::account_manager().ops_permissions_contract_permissions()
     }
+
+    fn ops_permissions_audit_log(&self, from: U64, limit: u16) -> Vec<PermissionsAuditLogEntry> {
+        Self::account_manager().ops_permissions_audit_log(from, limit)
+    }
+}
+
+#[near_bindgen]
+impl AdminApprovalsManagement for Contract {
+    fn ops_approvals_policy(&self) -> Option<ApprovalPolicy> {
+        Self::account_manager().ops_approvals_policy()
+    }
+
+    fn ops_approvals_set_policy(&mut self, required_approvals: Option<u8>) {
+        Self::account_manager().ops_approvals_set_policy(required_approvals);
+    }
+
+    fn ops_approvals_pending(&self) -> Vec<PendingApproval> {
+        Self::account_manager().ops_approvals_pending()
+    }
+
+    fn ops_approvals_approve(&mut self, operation_id: Hash) -> PendingApproval {
+        Self::account_manager().ops_approvals_approve(operation_id)
+    }
+
+    fn ops_approvals_cancel(&mut self, operation_id: Hash) -> bool {
+        Self::account_manager().ops_approvals_cancel(operation_id)
+    }
 }
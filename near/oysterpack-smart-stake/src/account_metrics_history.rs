@@ -0,0 +1,11 @@
+use crate::*;
+
+use near_sdk::near_bindgen;
+use oysterpack_smart_account_management::{AccountMetricsHistory, AccountMetricsSnapshot};
+
+#[near_bindgen]
+impl AccountMetricsHistory for Contract {
+    fn ops_account_metrics_history(&self, limit: Option<u16>) -> Vec<AccountMetricsSnapshot> {
+        Self::account_manager().ops_account_metrics_history(limit)
+    }
+}
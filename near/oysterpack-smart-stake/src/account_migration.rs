@@ -0,0 +1,14 @@
+use crate::*;
+use oysterpack_smart_staking_pool::{StakeAccountExport, StakeAccountMigration};
+
+#[near_bindgen]
+impl StakeAccountMigration for Contract {
+    fn ops_operator_export_accounts(&self, from: u64, limit: u64) -> Vec<StakeAccountExport> {
+        Self::staking_pool().ops_operator_export_accounts(from, limit)
+    }
+
+    #[payable]
+    fn ops_operator_import_accounts(&mut self, accounts: Vec<StakeAccountExport>) -> u32 {
+        Self::staking_pool().ops_operator_import_accounts(accounts)
+    }
+}
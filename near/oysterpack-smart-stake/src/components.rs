@@ -3,7 +3,9 @@ use oysterpack_smart_account_management::components::account_management::Account
 use oysterpack_smart_account_management::ContractPermissions;
 use oysterpack_smart_contract::components::contract_operator::ContractOperatorComponent;
 use oysterpack_smart_staking_pool::components::staking_pool::StakingPoolComponent;
-use oysterpack_smart_staking_pool::{StakeAccountData, PERMISSION_TREASURER};
+use oysterpack_smart_staking_pool::{
+    StakeAccountData, PERMISSION_LOCKER, PERMISSION_STAKER, PERMISSION_TREASURER,
+};
 use std::collections::HashMap;
 
 pub type AccountData = StakeAccountData;
@@ -16,11 +18,17 @@ pub type ContractOperator = ContractOperatorComponent<AccountData>;
 
 impl Contract {
     pub(crate) fn account_manager() -> AccountManager {
+        // the staking pool handler must run before the STAKE balance is burned
+        StakingPoolComponent::register_storage_management_event_handler();
         StakeFungibleToken::register_storage_management_event_handler();
+        // the rewards farm handlers must run after the STAKE balance is burned
+        StakingPoolComponent::register_rewards_farm_event_handlers();
 
         let contract_permissions = {
-            let mut permissions = HashMap::with_capacity(1);
+            let mut permissions = HashMap::with_capacity(3);
             permissions.insert(0, PERMISSION_TREASURER);
+            permissions.insert(1, PERMISSION_LOCKER);
+            permissions.insert(2, PERMISSION_STAKER);
             ContractPermissions(permissions)
         };
 
@@ -0,0 +1,14 @@
+use crate::*;
+use oysterpack_smart_contract::components::contract_jobs::ContractJobsComponent;
+use oysterpack_smart_contract::{ContractJobs, JobCursor, JobCursors, JobId};
+
+#[near_bindgen]
+impl ContractJobs for Contract {
+    fn ops_jobs(&self) -> JobCursors {
+        ContractJobsComponent.ops_jobs()
+    }
+
+    fn ops_job(&self, job_id: JobId) -> Option<JobCursor> {
+        ContractJobsComponent.ops_job(job_id)
+    }
+}
@@ -3,7 +3,7 @@ use oysterpack_smart_account_management::AccountMetrics;
 use oysterpack_smart_contract::components::contract_metrics::ContractMetricsComponent;
 use oysterpack_smart_contract::{
     ContractMetrics, ContractMetricsSnapshot, ContractNearBalances, ContractStorageUsage,
-    ContractStorageUsageCosts,
+    ContractStorageUsageBreakdown, ContractStorageUsageCosts, MetricsHistoryConfig, MetricsSample,
 };
 use oysterpack_smart_near::data::numbers::U128;
 
@@ -25,6 +25,10 @@ impl ContractMetrics for Contract {
         ContractMetricsComponent.ops_metrics_storage_usage_costs()
     }
 
+    fn ops_storage_usage_breakdown(&self) -> ContractStorageUsageBreakdown {
+        ContractMetricsComponent.ops_storage_usage_breakdown()
+    }
+
     fn ops_metrics(&self) -> ContractMetricsSnapshot {
         ContractMetricsComponent.ops_metrics()
     }
@@ -32,4 +36,12 @@ impl ContractMetrics for Contract {
     fn ops_metrics_accounts(&self) -> AccountMetrics {
         ContractMetricsComponent.ops_metrics_accounts()
     }
+
+    fn ops_metrics_history(&self, limit: Option<u16>) -> Vec<MetricsSample> {
+        ContractMetricsComponent.ops_metrics_history(limit)
+    }
+
+    fn ops_metrics_history_config(&self) -> Option<MetricsHistoryConfig> {
+        ContractMetricsComponent.ops_metrics_history_config()
+    }
 }
@@ -1,10 +1,16 @@
 use crate::*;
+use oysterpack_smart_contract::ContractOwnerObject;
 use oysterpack_smart_fungible_token::{
-    FungibleToken, FungibleTokenMetadataProvider, FungibleTokenOperator, Memo, Metadata,
-    OperatorCommand, ResolveTransferCall, TokenAmount, TransferCallMessage,
+    FungibleToken, FungibleTokenMetadataProvider, FungibleTokenOperator, Memo, Metadata, Name,
+    OperatorCommand, Reference, ResolveScreenedTransfer, ResolveTransferCall, Symbol, TokenAmount,
+    TokenService, TransferCallMessage,
 };
+use oysterpack_smart_near::data::numbers::U64;
+use oysterpack_smart_near::deposit_policy::{assert_deposit_policy, Method};
 use oysterpack_smart_near::domain::Gas;
-use oysterpack_smart_near::near_sdk::Promise;
+use oysterpack_smart_near::near_sdk::{
+    json_types::Base64VecU8, AccountId, Promise, PromiseOrValue,
+};
 
 #[near_bindgen]
 impl FungibleToken for Contract {
@@ -15,6 +21,10 @@ impl FungibleToken for Contract {
         amount: TokenAmount,
         memo: Option<Memo>,
     ) {
+        let mut staking_pool = Self::staking_pool();
+        // unvested STAKE cannot be transferred
+        staking_pool.assert_stake_vested(&env::predecessor_account_id(), amount);
+        let amount = staking_pool.ft_transfer_auto_register(receiver_id.as_ref(), amount);
         Self::ft_stake().ft_transfer(receiver_id, amount, memo)
     }
 
@@ -26,6 +36,10 @@ impl FungibleToken for Contract {
         memo: Option<Memo>,
         msg: TransferCallMessage,
     ) -> Promise {
+        let mut staking_pool = Self::staking_pool();
+        // unvested STAKE cannot be transferred
+        staking_pool.assert_stake_vested(&env::predecessor_account_id(), amount);
+        let amount = staking_pool.ft_transfer_auto_register(receiver_id.as_ref(), amount);
         Self::ft_stake().ft_transfer_call(receiver_id, amount, memo, msg)
     }
 
@@ -36,6 +50,10 @@ impl FungibleToken for Contract {
     fn ft_balance_of(&self, account_id: ValidAccountId) -> TokenAmount {
         Self::ft_stake().ft_balance_of(account_id)
     }
+
+    fn ft_balances_of(&self, accounts: Vec<ValidAccountId>) -> Vec<TokenAmount> {
+        Self::ft_stake().ft_balances_of(accounts)
+    }
 }
 
 #[near_bindgen]
@@ -51,6 +69,21 @@ impl ResolveTransferCall for Contract {
     }
 }
 
+#[near_bindgen]
+impl ResolveScreenedTransfer for Contract {
+    #[private]
+    fn ft_resolve_screened_transfer(
+        &mut self,
+        sender_id: ValidAccountId,
+        receiver_id: ValidAccountId,
+        amount: TokenAmount,
+        memo: Option<Memo>,
+        msg: Option<TransferCallMessage>,
+    ) -> PromiseOrValue<TokenAmount> {
+        Self::ft_stake().ft_resolve_screened_transfer(sender_id, receiver_id, amount, memo, msg)
+    }
+}
+
 #[near_bindgen]
 impl FungibleTokenMetadataProvider for Contract {
     fn ft_metadata(&self) -> Metadata {
@@ -58,6 +91,56 @@ impl FungibleTokenMetadataProvider for Contract {
     }
 }
 
+#[near_bindgen]
+impl Contract {
+    /// Renames the STAKE FT, i.e., to rebrand the token without redeploying the contract
+    /// - logs `FT_RENAME` event
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if the predecessor account is not the contract owner
+    /// - if name or symbol is blank
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    #[payable]
+    pub fn ops_ft_rename(&mut self, name: Name, symbol: Symbol) {
+        assert_deposit_policy(Method::OpsFtRename);
+        ContractOwnerObject::assert_owner_access();
+        Self::ft_stake().ft_rename(name, symbol)
+    }
+
+    /// Sets the STAKE FT metadata reference to the off-chain JSON document along with the document's
+    /// sha256 hash
+    /// - logs `FT_REFERENCE` event
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if the predecessor account is not the contract owner
+    /// - if reference is blank
+    /// - if reference hash is not 32 bytes
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    #[payable]
+    pub fn ops_ft_set_reference(&mut self, reference: Reference, reference_hash: Base64VecU8) {
+        assert_deposit_policy(Method::OpsFtSetReference);
+        ContractOwnerObject::assert_owner_access();
+        Self::ft_stake().ft_set_reference(reference, reference_hash)
+    }
+
+    /// Returns true if the sha256 hash of the specified document matches the STAKE FT metadata
+    /// reference hash, i.e., the off-chain document has not been tampered with
+    pub fn ops_ft_verify_reference(&self, payload: String) -> bool {
+        Self::ft_stake().ft_verify_reference(&payload)
+    }
+
+    /// Returns the sequence number that was assigned to the last STAKE mint, burn, or transfer event
+    /// - every `FT_MINT`, `FT_BURN`, and `FT_TRANSFER` event includes its sequence number as `seq`,
+    ///   which enables indexers to detect missed events
+    pub fn ops_ft_last_sequence(&self) -> U64 {
+        Self::ft_stake().ft_last_sequence().into()
+    }
+}
+
 #[near_bindgen]
 impl FungibleTokenOperator for Contract {
     fn ft_operator_command(&mut self, command: OperatorCommand) {
@@ -67,4 +150,8 @@ impl FungibleTokenOperator for Contract {
     fn ft_operator_transfer_callback_gas(&self) -> Gas {
         Self::ft_stake().ft_operator_transfer_callback_gas()
     }
+
+    fn ft_operator_transfer_hook(&self) -> Option<AccountId> {
+        Self::ft_stake().ft_operator_transfer_hook()
+    }
 }
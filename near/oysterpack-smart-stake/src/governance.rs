@@ -0,0 +1,32 @@
+use crate::*;
+use oysterpack_smart_near::data::numbers::U64;
+use oysterpack_smart_near::near_sdk::json_types::Base64VecU8;
+use oysterpack_smart_staking_pool::{
+    GovProposal, GovProposalResult, GovVote, StakeGovernance, VotingWindow,
+};
+
+#[near_bindgen]
+impl StakeGovernance for Contract {
+    #[payable]
+    fn ops_gov_propose(
+        &mut self,
+        text_hash: Base64VecU8,
+        options: Vec<String>,
+        voting_window: VotingWindow,
+    ) -> GovProposal {
+        Self::staking_pool().ops_gov_propose(text_hash, options, voting_window)
+    }
+
+    #[payable]
+    fn ops_gov_vote(&mut self, proposal_id: U64, option: u8) -> TokenAmount {
+        Self::staking_pool().ops_gov_vote(proposal_id, option)
+    }
+
+    fn ops_gov_result(&self, proposal_id: U64) -> Option<GovProposalResult> {
+        Self::staking_pool().ops_gov_result(proposal_id)
+    }
+
+    fn ops_gov_vote_of(&self, proposal_id: U64, account_id: ValidAccountId) -> Option<GovVote> {
+        Self::staking_pool().ops_gov_vote_of(proposal_id, account_id)
+    }
+}
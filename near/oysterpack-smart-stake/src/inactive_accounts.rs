@@ -0,0 +1,30 @@
+use crate::*;
+use oysterpack_smart_near::domain::EpochHeight;
+use oysterpack_smart_staking_pool::{ArchivedAccount, StakeInactiveAccounts};
+
+#[near_bindgen]
+impl StakeInactiveAccounts for Contract {
+    fn ops_stake_force_unregister_inactive(
+        &mut self,
+        accounts: Vec<ValidAccountId>,
+        min_inactive_epochs: u64,
+    ) -> u32 {
+        Self::staking_pool().ops_stake_force_unregister_inactive(accounts, min_inactive_epochs)
+    }
+
+    fn ops_stake_archive_inactive(
+        &mut self,
+        accounts: Vec<ValidAccountId>,
+        min_inactive_epochs: u64,
+    ) -> u32 {
+        Self::staking_pool().ops_stake_archive_inactive(accounts, min_inactive_epochs)
+    }
+
+    fn ops_stake_last_active_epoch(&self, account_id: ValidAccountId) -> Option<EpochHeight> {
+        Self::staking_pool().ops_stake_last_active_epoch(account_id)
+    }
+
+    fn ops_stake_archived_account(&self, account_id: ValidAccountId) -> Option<ArchivedAccount> {
+        Self::staking_pool().ops_stake_archived_account(account_id)
+    }
+}
@@ -1,23 +1,48 @@
 mod access_control;
+#[cfg(feature = "metrics")]
+mod account_metrics_history;
+mod account_migration;
+mod account_profile;
 mod account_storage_usage;
 mod components;
+mod contract_jobs;
+#[cfg(feature = "metrics")]
 mod contract_metrics;
 mod contract_operator;
 mod contract_ownership;
+mod contract_sale;
 mod fungible_token;
+mod governance;
+mod inactive_accounts;
+mod liquidity_pool;
+#[cfg(feature = "near-staking-pool")]
+mod near_staking_pool;
+mod owner_earnings;
+mod permissioned_pool;
+mod referrals;
+mod relayed_staking;
+mod rewards_beneficiary;
+mod rewards_farm;
+mod stake_locks;
+mod stake_on_transfer;
 mod staking_pool;
 mod storage_management;
+#[cfg(feature = "treasury")]
+mod treasury;
+mod vesting;
 
 use components::*;
 use near_sdk::{
     borsh::{self, BorshDeserialize, BorshSerialize},
     env,
     json_types::ValidAccountId,
-    near_bindgen, serde_json, PanicOnDefault,
+    near_bindgen,
+    serde::{Deserialize, Serialize},
+    serde_json, PanicOnDefault,
 };
 use oysterpack_smart_account_management::{
-    components::account_management::AccountManagementComponentConfig, AccountRepository,
-    StorageUsageBounds,
+    components::account_management::AccountManagementComponentConfig, AccountMetrics,
+    AccountRepository, PermissionsAuditEvent, PermissionsAuditLog, StorageUsageBounds,
 };
 use oysterpack_smart_contract::{
     components::contract_ownership::ContractOwnershipComponent, ContractOwnership,
@@ -27,8 +52,9 @@ use oysterpack_smart_fungible_token::components::fungible_token::{
     FungibleTokenComponent, FungibleTokenConfig,
 };
 use oysterpack_smart_fungible_token::*;
+use oysterpack_smart_near::asserts::ERR_INVALID;
 use oysterpack_smart_near::component::LOG_EVENT_DEPLOYMENT;
-use oysterpack_smart_near::domain::BasisPoints;
+use oysterpack_smart_near::domain::{BasisPoints, YoctoNear};
 use oysterpack_smart_near::{
     component::{Deploy, ManagesAccountData},
     domain::PublicKey,
@@ -36,6 +62,9 @@ use oysterpack_smart_near::{
 use oysterpack_smart_staking_pool::components::staking_pool::{
     StakingPoolComponent, StakingPoolComponentConfig,
 };
+use oysterpack_smart_staking_pool::{
+    EarningsVestingPeriod, RewardsFarm, StakingMode, StorageOpCosts, MAX_FEE,
+};
 use std::convert::TryInto;
 
 near_sdk::setup_alloc!();
@@ -46,19 +75,28 @@ pub struct Contract;
 
 #[near_bindgen]
 impl Contract {
-    /// If owner is not specified, then predecessor Account ID will be set as the contract owner.
+    /// Deploys the contract using the specified config - see [`DeployConfig`]
     /// - owner account is granted admin permission
-    /// - default fees: staking fee = 0 BPS, earnings fee = 100 BPS
-    /// - STAKE FT symbol defaults to the first part of the contract account ID and uppercased, e.g. if the contract
-    ///   account ID is "pearl.stake-v1.oysterpack.near", then the symbol will be "PEARL"
+    /// - the config is validated before anything is deployed
+    ///
+    /// ## Panics
+    /// - if the config is invalid - the error message describes which config field is invalid and
+    ///   why
     #[init]
-    pub fn deploy(
-        stake_public_key: PublicKey,
-        owner: Option<ValidAccountId>,
-        staking_fee: Option<BasisPoints>,
-        earnings_fee: Option<BasisPoints>,
-        stake_symbol: Option<String>,
-    ) -> Self {
+    pub fn deploy_with_config(config: DeployConfig) -> Self {
+        config.validate();
+        let DeployConfig {
+            stake_public_key,
+            owner,
+            staking_fee,
+            earnings_fee,
+            stake_symbol,
+            stake_metadata,
+            earnings_vesting_period,
+            min_stake,
+            staking_mode,
+        } = config;
+
         let owner = owner.unwrap_or_else(|| env::predecessor_account_id().try_into().unwrap());
         ContractOwnershipComponent::deploy(owner.clone());
         LOG_EVENT_DEPLOYMENT.log("ContractOwnershipComponent");
@@ -97,15 +135,21 @@ impl Contract {
             }
         });
 
+        let StakeMetadata {
+            name,
+            decimals,
+            icon,
+        } = stake_metadata.unwrap_or_default();
         let stake_metadata = Metadata {
             spec: Spec(FT_METADATA_SPEC.to_string()),
-            name: Name::from("STAKE"),
+            name: name.unwrap_or_else(|| Name::from("STAKE")),
             symbol: Symbol(stake_symbol.to_uppercase()),
-            decimals: 24,
-            icon: None,
+            decimals: decimals.unwrap_or(24),
+            icon,
             reference: None,
             reference_hash: None,
         };
+        stake_metadata.assert_valid();
         StakeFungibleToken::deploy(FungibleTokenConfig {
             metadata: stake_metadata.clone(),
             token_supply: 0,
@@ -119,11 +163,275 @@ impl Contract {
             stake_public_key,
             staking_fee: staking_fee.or(Some(0.into())),
             earnings_fee: earnings_fee.or(Some(100.into())),
+            earnings_vesting_period,
+            min_stake,
+            staking_mode,
         });
         LOG_EVENT_DEPLOYMENT.log("StakingPoolComponent");
 
         Self
     }
+
+    /// Backwards compatible deployment that takes positional args - delegates to
+    /// [`Contract::deploy_with_config`]
+    #[init]
+    #[allow(clippy::too_many_arguments)]
+    pub fn deploy(
+        stake_public_key: PublicKey,
+        owner: Option<ValidAccountId>,
+        staking_fee: Option<BasisPoints>,
+        earnings_fee: Option<BasisPoints>,
+        stake_symbol: Option<String>,
+        stake_metadata: Option<StakeMetadata>,
+        earnings_vesting_period: Option<EarningsVestingPeriod>,
+    ) -> Self {
+        Self::deploy_with_config(DeployConfig {
+            stake_public_key,
+            owner,
+            staking_fee,
+            earnings_fee,
+            stake_symbol,
+            stake_metadata,
+            earnings_vesting_period,
+            min_stake: None,
+            staking_mode: None,
+        })
+    }
+
+    /// Deploys the component onto the already initialized contract, which enables components that
+    /// were added by a contract upgrade to be rolled out incrementally
+    /// - idempotent, i.e., returns false if the component is already deployed
+    /// - components that require config, e.g., the rewards farm, are deployed using the specified
+    ///   [`ComponentConfig`]
+    /// - logs [`LOG_EVENT_DEPLOYMENT`] when the component is deployed
+    ///
+    /// ## Panics
+    /// - if predecessor account is not authorized - requires operator permission
+    /// - [`ERR_INVALID`] - if the config does not match the component, i.e., config is required
+    ///   for components that need it and must not be specified for components that don't
+    pub fn ops_deploy_component(
+        &mut self,
+        component: ComponentId,
+        config: Option<ComponentConfig>,
+    ) -> bool {
+        Self::account_manager().assert_operator();
+        ERR_INVALID.assert(
+            || component.requires_config() == config.is_some(),
+            || match config {
+                Some(_) => "component does not require config",
+                None => "component config is required",
+            },
+        );
+        if component.is_deployed() {
+            return false;
+        }
+        PermissionsAuditLog::record(PermissionsAuditEvent::OperatorCommand {
+            operation: "ops_deploy_component".to_string(),
+            args: serde_json::to_string(&(component, &config)).unwrap(),
+        });
+
+        match (component, config) {
+            (ComponentId::AccountMetricsHistory, _) => AccountMetrics::init_history(),
+            (ComponentId::StorageOpCosts, _) => {
+                StakingPoolComponent::deploy_storage_op_costs();
+            }
+            (
+                ComponentId::RewardsFarm,
+                Some(ComponentConfig::RewardsFarm {
+                    token_id,
+                    emission_rate,
+                }),
+            ) => {
+                Self::staking_pool().deploy_rewards_farm(token_id.into(), emission_rate);
+            }
+            (ComponentId::RewardsFarm, None) => unreachable!(),
+        }
+        LOG_EVENT_DEPLOYMENT.log(format!("{:?}", component));
+        true
+    }
+
+    /// returns the components that are deployed - see [`Contract::ops_deploy_component`]
+    pub fn ops_deployed_components(&self) -> Vec<ComponentId> {
+        ComponentId::ALL
+            .iter()
+            .copied()
+            .filter(ComponentId::is_deployed)
+            .collect()
+    }
+}
+
+/// Components that can be deployed onto an already initialized contract - see
+/// [`Contract::ops_deploy_component`]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ComponentId {
+    /// account metrics epoch snapshots - see [`AccountMetrics::init_history`]
+    AccountMetricsHistory,
+    /// storage usage allocated by stake operations - see [`StorageOpCosts`]
+    StorageOpCosts,
+    /// distributes a NEP-141 reward token to STAKE holders - see [`oysterpack_smart_staking_pool::StakeRewardsFarm`]
+    /// - requires [`ComponentConfig::RewardsFarm`]
+    RewardsFarm,
+}
+
+impl ComponentId {
+    pub const ALL: [ComponentId; 3] = [
+        ComponentId::AccountMetricsHistory,
+        ComponentId::StorageOpCosts,
+        ComponentId::RewardsFarm,
+    ];
+
+    /// the component state existence is used to check if the component is deployed
+    pub fn is_deployed(&self) -> bool {
+        match self {
+            ComponentId::AccountMetricsHistory => AccountMetrics::history_initialized(),
+            ComponentId::StorageOpCosts => StorageOpCosts::load().is_some(),
+            ComponentId::RewardsFarm => RewardsFarm::load().is_some(),
+        }
+    }
+
+    pub fn requires_config(&self) -> bool {
+        matches!(self, ComponentId::RewardsFarm)
+    }
+}
+
+/// Config for components that cannot be deployed with defaults - see [`Contract::ops_deploy_component`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ComponentConfig {
+    /// the NEP-141 reward token contract and the reward tokens that are emitted per second
+    RewardsFarm {
+        token_id: ValidAccountId,
+        emission_rate: TokenAmount,
+    },
+}
+
+/// Contract deployment config
+/// - If owner is not specified, then predecessor Account ID will be set as the contract owner.
+/// - default fees: staking fee = 0 BPS, earnings fee = 100 BPS
+/// - earnings are distributed immediately unless an earnings vesting period is specified - see
+///   [`EarningsVestingPeriod`]
+/// - STAKE FT symbol defaults to the first part of the contract account ID and uppercased, e.g. if the contract
+///   account ID is "pearl.stake-v1.oysterpack.near", then the symbol will be "PEARL"
+/// - STAKE FT name defaults to "STAKE" and decimals defaults to 24 - see [`StakeMetadata`]
+/// - the STAKE FT name and symbol can be changed after deployment by the owner via `ops_ft_rename`
+/// - there is no min stake by default - see [`oysterpack_smart_staking_pool::MinStake`]
+/// - staking mode defaults to [`StakingMode::Open`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DeployConfig {
+    pub stake_public_key: PublicKey,
+    pub owner: Option<ValidAccountId>,
+    pub staking_fee: Option<BasisPoints>,
+    pub earnings_fee: Option<BasisPoints>,
+    pub stake_symbol: Option<String>,
+    pub stake_metadata: Option<StakeMetadata>,
+    pub earnings_vesting_period: Option<EarningsVestingPeriod>,
+    pub min_stake: Option<YoctoNear>,
+    pub staking_mode: Option<StakingMode>,
+}
+
+impl DeployConfig {
+    /// max STAKE FT symbol length
+    pub const MAX_SYMBOL_LEN: usize = 32;
+    /// max STAKE FT decimals
+    pub const MAX_DECIMALS: u8 = 24;
+
+    /// ## Panics
+    /// - if the staking fee or earnings fee exceed [`MAX_FEE`]
+    /// - if the STAKE symbol is blank, contains whitespace, or is longer than [`DeployConfig::MAX_SYMBOL_LEN`]
+    /// - if the STAKE name is blank
+    /// - if the STAKE decimals are greater than [`DeployConfig::MAX_DECIMALS`]
+    /// - if the earnings vesting period is zero
+    pub fn validate(&self) {
+        for (field, fee) in [
+            ("staking_fee", self.staking_fee),
+            ("earnings_fee", self.earnings_fee),
+        ]
+        .iter()
+        {
+            if let Some(fee) = fee {
+                ERR_INVALID.assert(
+                    || *fee <= MAX_FEE,
+                    || {
+                        format!(
+                            "invalid deploy config: `{}` is {} BPS - max fee is {} BPS (10%)",
+                            field, fee, MAX_FEE
+                        )
+                    },
+                );
+            }
+        }
+
+        if let Some(symbol) = self.stake_symbol.as_ref() {
+            ERR_INVALID.assert(
+                || !symbol.trim().is_empty(),
+                || "invalid deploy config: `stake_symbol` must not be blank - omit it to default to the contract account ID prefix",
+            );
+            ERR_INVALID.assert(
+                || !symbol.chars().any(char::is_whitespace),
+                || {
+                    format!(
+                        "invalid deploy config: `stake_symbol` must not contain whitespace: {:?}",
+                        symbol
+                    )
+                },
+            );
+            ERR_INVALID.assert(
+                || symbol.len() <= Self::MAX_SYMBOL_LEN,
+                || {
+                    format!(
+                        "invalid deploy config: `stake_symbol` is {} chars - max length is {}",
+                        symbol.len(),
+                        Self::MAX_SYMBOL_LEN
+                    )
+                },
+            );
+        }
+
+        if let Some(metadata) = self.stake_metadata.as_ref() {
+            if let Some(name) = metadata.name.as_ref() {
+                ERR_INVALID.assert(
+                    || !name.0.trim().is_empty(),
+                    || "invalid deploy config: `stake_metadata.name` must not be blank - omit it to default to \"STAKE\"",
+                );
+            }
+            if let Some(decimals) = metadata.decimals {
+                ERR_INVALID.assert(
+                    || decimals <= Self::MAX_DECIMALS,
+                    || {
+                        format!(
+                            "invalid deploy config: `stake_metadata.decimals` is {} - max is {}",
+                            decimals,
+                            Self::MAX_DECIMALS
+                        )
+                    },
+                );
+            }
+        }
+
+        if let Some(period) = self.earnings_vesting_period {
+            let length = match period {
+                EarningsVestingPeriod::Blocks(blocks) => *blocks,
+                EarningsVestingPeriod::Epochs(epochs) => *epochs,
+            };
+            ERR_INVALID.assert(
+                || length > 0,
+                || "invalid deploy config: `earnings_vesting_period` must be > 0 - omit it to distribute earnings immediately",
+            );
+        }
+    }
+}
+
+/// Optional STAKE FT metadata that can be specified when the contract is deployed
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StakeMetadata {
+    /// defaults to "STAKE"
+    pub name: Option<Name>,
+    /// defaults to 24
+    pub decimals: Option<u8>,
+    pub icon: Option<Icon>,
 }
 
 #[cfg(test)]
@@ -146,3 +454,198 @@ mod tests {
         assert_eq!(symbol, "stake");
     }
 }
+
+#[cfg(test)]
+mod tests_deploy_config {
+    use super::*;
+    use oysterpack_smart_near::YOCTO;
+    use oysterpack_smart_near_test::*;
+    use oysterpack_smart_staking_pool::{MinStake, PermissionedPool};
+
+    const OWNER: &str = "owner";
+
+    fn config() -> DeployConfig {
+        serde_json::from_str(
+            r#"{
+            "stake_public_key": "ed25519:GTi3gtSio5ZYYKTT8WVovqJEob6KqdmkTi8KqGSfwqdm",
+            "owner": null,
+            "staking_fee": 50,
+            "earnings_fee": null,
+            "stake_symbol": "PEARL",
+            "stake_metadata": null,
+            "earnings_vesting_period": null,
+            "min_stake": "1000000000000000000000000",
+            "staking_mode": "Permissioned"
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn deploy_with_config() {
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
+
+        Contract::deploy_with_config(config());
+
+        assert_eq!(MinStake::amount(), YOCTO.into());
+        assert!(PermissionedPool::enabled());
+        assert_eq!(ContractOwnershipComponent.ops_owner(), OWNER);
+    }
+
+    #[test]
+    fn deploy_shim_uses_defaults() {
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
+
+        let config = config();
+        Contract::deploy(config.stake_public_key, None, None, None, None, None, None);
+
+        assert_eq!(MinStake::amount(), YoctoNear::ZERO);
+        assert!(!PermissionedPool::enabled());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid deploy config: `staking_fee` is 2000 BPS")]
+    fn staking_fee_exceeds_max_fee() {
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
+
+        let mut config = config();
+        config.staking_fee = Some(2000.into());
+        config.validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid deploy config: `stake_symbol` must not contain whitespace")]
+    fn stake_symbol_with_whitespace() {
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
+
+        let mut config = config();
+        config.stake_symbol = Some("PEARL STAKE".to_string());
+        config.validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid deploy config: `earnings_vesting_period` must be > 0")]
+    fn zero_earnings_vesting_period() {
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
+
+        let mut config = config();
+        config.earnings_vesting_period = Some(EarningsVestingPeriod::Epochs(0.into()));
+        config.validate();
+    }
+}
+
+#[cfg(test)]
+mod tests_deploy_component {
+    use super::*;
+    use near_sdk::test_utils;
+    use oysterpack_smart_near_test::*;
+
+    const OWNER: &str = "owner";
+
+    fn deploy() -> Contract {
+        Contract::deploy(
+            serde_json::from_str("\"ed25519:GTi3gtSio5ZYYKTT8WVovqJEob6KqdmkTi8KqGSfwqdm\"")
+                .unwrap(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn rewards_farm_config() -> ComponentConfig {
+        ComponentConfig::RewardsFarm {
+            token_id: to_valid_account_id("reward.token"),
+            emission_rate: 100.into(),
+        }
+    }
+
+    #[test]
+    fn deploy_component_is_idempotent() {
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
+
+        let mut contract = deploy();
+        assert_eq!(
+            contract.ops_deployed_components(),
+            vec![
+                ComponentId::AccountMetricsHistory,
+                ComponentId::StorageOpCosts
+            ]
+        );
+        assert!(!contract.ops_deploy_component(ComponentId::AccountMetricsHistory, None));
+        assert!(!contract.ops_deploy_component(ComponentId::StorageOpCosts, None));
+    }
+
+    #[test]
+    fn deploy_rewards_farm() {
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
+        let mut contract = deploy();
+
+        // Act
+        assert!(
+            contract.ops_deploy_component(ComponentId::RewardsFarm, Some(rewards_farm_config()))
+        );
+
+        // Assert
+        assert_eq!(
+            contract.ops_deployed_components(),
+            ComponentId::ALL.to_vec()
+        );
+        let farm = RewardsFarm::load().unwrap();
+        assert_eq!(farm.token_id, "reward.token");
+        assert_eq!(farm.emission_rate, 100.into());
+        let logs = test_utils::get_logs();
+        assert_eq!(logs.last().unwrap(), "[INFO] [DEPLOYMENT] RewardsFarm");
+
+        // Act - redeploying does not change the farm config
+        assert!(!contract.ops_deploy_component(
+            ComponentId::RewardsFarm,
+            Some(ComponentConfig::RewardsFarm {
+                token_id: to_valid_account_id("reward.token"),
+                emission_rate: 200.into(),
+            })
+        ));
+        assert_eq!(RewardsFarm::load().unwrap(), farm);
+    }
+
+    #[test]
+    #[should_panic(expected = "component config is required")]
+    fn deploy_rewards_farm_without_config() {
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
+        let mut contract = deploy();
+
+        contract.ops_deploy_component(ComponentId::RewardsFarm, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "component does not require config")]
+    fn deploy_component_with_unexpected_config() {
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
+        let mut contract = deploy();
+
+        contract.ops_deploy_component(ComponentId::StorageOpCosts, Some(rewards_farm_config()));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"NOT_AUTHORIZED\""#)]
+    fn deploy_component_not_operator() {
+        let mut ctx = TestCtx::new(OWNER);
+        ctx.apply();
+        let mut contract = deploy();
+
+        ctx.predecessor("bob").apply();
+        AccountManager::register_account_if_not_exists("bob");
+        contract.ops_deploy_component(ComponentId::StorageOpCosts, None);
+    }
+}
@@ -0,0 +1,43 @@
+use crate::*;
+use oysterpack_smart_near::domain::YoctoNear;
+use oysterpack_smart_near::near_sdk::json_types::U128;
+use oysterpack_smart_staking_pool::{
+    LiquidityPoolAccountBalance, LiquidityPoolBalances, StakeLiquidityPool,
+};
+
+#[near_bindgen]
+impl StakeLiquidityPool for Contract {
+    #[payable]
+    fn ops_liquidity_add(&mut self) -> LiquidityPoolAccountBalance {
+        Self::staking_pool().ops_liquidity_add()
+    }
+
+    #[payable]
+    fn ops_liquidity_remove(&mut self, shares: Option<U128>) -> YoctoNear {
+        Self::staking_pool().ops_liquidity_remove(shares)
+    }
+
+    #[payable]
+    fn ops_liquidity_swap(
+        &mut self,
+        amount: TokenAmount,
+        min_near_out: Option<YoctoNear>,
+    ) -> YoctoNear {
+        Self::staking_pool().ops_liquidity_swap(amount, min_near_out)
+    }
+
+    fn ops_liquidity_swap_quote(&self, amount: TokenAmount) -> YoctoNear {
+        Self::staking_pool().ops_liquidity_swap_quote(amount)
+    }
+
+    fn ops_liquidity_pool_balances(&self) -> LiquidityPoolBalances {
+        Self::staking_pool().ops_liquidity_pool_balances()
+    }
+
+    fn ops_liquidity_balance(
+        &self,
+        account_id: ValidAccountId,
+    ) -> Option<LiquidityPoolAccountBalance> {
+        Self::staking_pool().ops_liquidity_balance(account_id)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::*;
+use oysterpack_smart_staking_pool::{
+    EarningsFeeRecipient, EarningsFeeSplit, OwnerEarningsMode, OwnerRevenue,
+    StakingPoolOwnerEarnings,
+};
+
+#[near_bindgen]
+impl StakingPoolOwnerEarnings for Contract {
+    #[payable]
+    fn ops_owner_set_earnings_mode(&mut self, mode: OwnerEarningsMode) {
+        Self::staking_pool().ops_owner_set_earnings_mode(mode);
+    }
+
+    fn ops_owner_earnings_mode(&self) -> OwnerEarningsMode {
+        Self::staking_pool().ops_owner_earnings_mode()
+    }
+
+    #[payable]
+    fn ops_owner_set_earnings_fee_split(&mut self, recipients: Option<Vec<EarningsFeeRecipient>>) {
+        Self::staking_pool().ops_owner_set_earnings_fee_split(recipients);
+    }
+
+    fn ops_owner_earnings_fee_split(&self) -> Option<EarningsFeeSplit> {
+        Self::staking_pool().ops_owner_earnings_fee_split()
+    }
+
+    fn ops_owner_revenue(&self) -> OwnerRevenue {
+        Self::staking_pool().ops_owner_revenue()
+    }
+}
@@ -0,0 +1,23 @@
+use crate::*;
+use oysterpack_smart_staking_pool::StakePermissionedPool;
+
+#[near_bindgen]
+impl StakePermissionedPool for Contract {
+    fn ops_stake_permissioned_pool(&self) -> bool {
+        Self::staking_pool().ops_stake_permissioned_pool()
+    }
+
+    #[payable]
+    fn ops_stake_grant_staker(&mut self, account_id: ValidAccountId) {
+        Self::staking_pool().ops_stake_grant_staker(account_id);
+    }
+
+    #[payable]
+    fn ops_stake_revoke_staker(&mut self, account_id: ValidAccountId) {
+        Self::staking_pool().ops_stake_revoke_staker(account_id);
+    }
+
+    fn ops_stake_is_staker(&self, account_id: ValidAccountId) -> bool {
+        Self::staking_pool().ops_stake_is_staker(account_id)
+    }
+}
@@ -0,0 +1,23 @@
+use crate::*;
+use oysterpack_smart_near::domain::BasisPoints;
+use oysterpack_smart_near::near_sdk::{AccountId, PromiseOrValue};
+use oysterpack_smart_staking_pool::{StakeAccountBalances, StakeReferrals};
+
+#[near_bindgen]
+impl StakeReferrals for Contract {
+    #[payable]
+    fn ops_stake_with_referrer(
+        &mut self,
+        referrer: ValidAccountId,
+    ) -> PromiseOrValue<StakeAccountBalances> {
+        Self::staking_pool().ops_stake_with_referrer(referrer)
+    }
+
+    fn ops_stake_referrer(&self, account_id: ValidAccountId) -> Option<AccountId> {
+        Self::staking_pool().ops_stake_referrer(account_id)
+    }
+
+    fn ops_stake_referral_fee(&self) -> BasisPoints {
+        Self::staking_pool().ops_stake_referral_fee()
+    }
+}
@@ -0,0 +1,30 @@
+use crate::*;
+use oysterpack_smart_near::domain::PublicKey;
+use oysterpack_smart_near::near_sdk::json_types::Base64VecU8;
+use oysterpack_smart_near::near_sdk::PromiseOrValue;
+use oysterpack_smart_staking_pool::{
+    RelayKey, RelayedStakeRequest, RelayedStaking, StakeAccountBalances,
+};
+
+#[near_bindgen]
+impl RelayedStaking for Contract {
+    fn ops_stake_relay_set_key(&mut self, public_key: PublicKey) -> RelayKey {
+        Self::staking_pool().ops_stake_relay_set_key(public_key)
+    }
+
+    fn ops_stake_relay_clear_key(&mut self) {
+        Self::staking_pool().ops_stake_relay_clear_key()
+    }
+
+    fn ops_stake_relay_key(&self, account_id: ValidAccountId) -> Option<RelayKey> {
+        Self::staking_pool().ops_stake_relay_key(account_id)
+    }
+
+    fn ops_stake_relayed(
+        &mut self,
+        request: RelayedStakeRequest,
+        signature: Base64VecU8,
+    ) -> PromiseOrValue<StakeAccountBalances> {
+        Self::staking_pool().ops_stake_relayed(request, signature)
+    }
+}
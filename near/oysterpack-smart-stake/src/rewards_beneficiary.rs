@@ -0,0 +1,24 @@
+use crate::*;
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_staking_pool::{RewardsBeneficiary, StakeRewardsBeneficiary};
+
+#[near_bindgen]
+impl StakeRewardsBeneficiary for Contract {
+    fn ops_stake_set_rewards_beneficiary(
+        &mut self,
+        beneficiary: Option<ValidAccountId>,
+    ) -> Option<RewardsBeneficiary> {
+        Self::staking_pool().ops_stake_set_rewards_beneficiary(beneficiary)
+    }
+
+    fn ops_stake_rewards_beneficiary(
+        &self,
+        account_id: ValidAccountId,
+    ) -> Option<RewardsBeneficiary> {
+        Self::staking_pool().ops_stake_rewards_beneficiary(account_id)
+    }
+
+    fn ops_stake_skim_rewards(&mut self, account_id: ValidAccountId) -> TokenAmount {
+        Self::staking_pool().ops_stake_skim_rewards(account_id)
+    }
+}
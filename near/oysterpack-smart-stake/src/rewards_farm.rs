@@ -0,0 +1,20 @@
+use crate::*;
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::near_sdk::PromiseOrValue;
+use oysterpack_smart_staking_pool::{RewardsFarm, StakeRewardsFarm};
+
+#[near_bindgen]
+impl StakeRewardsFarm for Contract {
+    fn ops_farm(&self) -> Option<RewardsFarm> {
+        Self::staking_pool().ops_farm()
+    }
+
+    fn ops_farm_rewards(&self, account_id: ValidAccountId) -> TokenAmount {
+        Self::staking_pool().ops_farm_rewards(account_id)
+    }
+
+    #[payable]
+    fn ops_farm_claim(&mut self) -> PromiseOrValue<TokenAmount> {
+        Self::staking_pool().ops_farm_claim()
+    }
+}
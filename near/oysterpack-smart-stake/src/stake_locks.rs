@@ -0,0 +1,82 @@
+use crate::*;
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_staking_pool::StakeLocks;
+
+#[near_bindgen]
+impl StakeLocks for Contract {
+    #[payable]
+    fn ops_ft_lock(
+        &mut self,
+        account_id: ValidAccountId,
+        amount: TokenAmount,
+        lock_id: String,
+    ) -> TokenAmount {
+        Self::staking_pool().ops_ft_lock(account_id, amount, lock_id)
+    }
+
+    #[payable]
+    fn ops_ft_unlock(
+        &mut self,
+        account_id: ValidAccountId,
+        lock_id: String,
+        amount: Option<TokenAmount>,
+    ) -> TokenAmount {
+        Self::staking_pool().ops_ft_unlock(account_id, lock_id, amount)
+    }
+
+    #[payable]
+    fn ops_ft_admin_unlock(
+        &mut self,
+        account_id: ValidAccountId,
+        locker_id: ValidAccountId,
+        lock_id: String,
+        amount: Option<TokenAmount>,
+    ) -> TokenAmount {
+        Self::staking_pool().ops_ft_admin_unlock(account_id, locker_id, lock_id, amount)
+    }
+
+    #[payable]
+    fn ops_ft_approve_locker(&mut self, locker_id: ValidAccountId) {
+        Self::staking_pool().ops_ft_approve_locker(locker_id);
+    }
+
+    #[payable]
+    fn ops_ft_revoke_locker_approval(&mut self, locker_id: ValidAccountId) {
+        Self::staking_pool().ops_ft_revoke_locker_approval(locker_id);
+    }
+
+    fn ops_ft_is_locker_approved(
+        &self,
+        account_id: ValidAccountId,
+        locker_id: ValidAccountId,
+    ) -> bool {
+        Self::staking_pool().ops_ft_is_locker_approved(account_id, locker_id)
+    }
+
+    fn ops_ft_lock_balance(
+        &self,
+        account_id: ValidAccountId,
+        locker_id: ValidAccountId,
+        lock_id: String,
+    ) -> TokenAmount {
+        Self::staking_pool().ops_ft_lock_balance(account_id, locker_id, lock_id)
+    }
+
+    fn ops_ft_locked_balance(&self, account_id: ValidAccountId) -> Option<TokenAmount> {
+        Self::staking_pool().ops_ft_locked_balance(account_id)
+    }
+
+    #[payable]
+    fn ops_stake_grant_locker(&mut self, account_id: ValidAccountId) {
+        Self::staking_pool().ops_stake_grant_locker(account_id);
+    }
+
+    #[payable]
+    fn ops_stake_revoke_locker(&mut self, account_id: ValidAccountId) {
+        Self::staking_pool().ops_stake_revoke_locker(account_id);
+    }
+
+    fn ops_stake_is_locker(&self, account_id: ValidAccountId) -> bool {
+        Self::staking_pool().ops_stake_is_locker(account_id)
+    }
+}
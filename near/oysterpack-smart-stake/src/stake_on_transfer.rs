@@ -0,0 +1,11 @@
+use crate::*;
+use oysterpack_smart_near::near_sdk::PromiseOrValue;
+use oysterpack_smart_staking_pool::{StakeAccountBalances, StakeOnTransfer};
+
+#[near_bindgen]
+impl StakeOnTransfer for Contract {
+    #[payable]
+    fn stake_on_transfer(&mut self, msg: String) -> PromiseOrValue<StakeAccountBalances> {
+        Self::staking_pool().stake_on_transfer(msg)
+    }
+}
@@ -1,11 +1,17 @@
 use crate::*;
 use near_sdk::near_bindgen;
-use oysterpack_smart_near::domain::YoctoNear;
+use oysterpack_smart_near::data::numbers::U64;
+use oysterpack_smart_near::domain::{BasisPoints, BlockHeight, EpochHeight, YoctoNear};
 use oysterpack_smart_near::near_sdk::{AccountId, Promise, PromiseOrValue};
 use oysterpack_smart_staking_pool::{
-    Fees, NearStakingPool, NearStakingPoolAccount, StakeAccountBalances, StakeActionCallbacks,
-    StakingPool, StakingPoolBalances, StakingPoolOperator, StakingPoolOperatorCommand, Status,
-    Treasury,
+    EpochPerformance, FeeChangePreview, FeeHoliday, Fees, FinalizeIntent, GasReserve,
+    PendingCommand, PendingWithdrawal, RoundingMode, StakeAccountBalances, StakeActionCallbacks,
+    StakeAttestationGate, StakeCap, StakeCertificate, StakeKeyCustody, StakeOperation,
+    StakePreview, StakePriceOracle, StakePublicKeyRotation, StakeTokenValueSample,
+    StakeWaitlistPosition, StakeWaitlistSummary, StakedBalance, StakingPool, StakingPoolBalances,
+    StakingPoolBalancesSnapshot, StakingPoolHealth, StakingPoolOperator,
+    StakingPoolOperatorCommand, Status, StorageCost, StorageOpKind, UnstakeBatch,
+    UnstakeBatchingSummary, UnstakePreview, UnstakeProjection, WithdrawableAccounts,
 };
 
 #[near_bindgen]
@@ -14,21 +20,105 @@ impl StakingPool for Contract {
         Self::staking_pool().ops_stake_balance(account_id)
     }
 
+    fn ops_stake_balances(
+        &self,
+        accounts: Vec<ValidAccountId>,
+    ) -> Vec<Option<StakeAccountBalances>> {
+        Self::staking_pool().ops_stake_balances(accounts)
+    }
+
+    fn ops_stake_certificate(&self, account_id: ValidAccountId) -> Option<StakeCertificate> {
+        Self::staking_pool().ops_stake_certificate(account_id)
+    }
+
     #[payable]
-    fn ops_stake(&mut self) -> PromiseOrValue<StakeAccountBalances> {
-        Self::staking_pool().ops_stake()
+    fn ops_stake(
+        &mut self,
+        memo: Option<Memo>,
+        min_stake_out: Option<TokenAmount>,
+    ) -> PromiseOrValue<StakeAccountBalances> {
+        Self::staking_pool().ops_stake(memo, min_stake_out)
     }
 
-    fn ops_unstake(&mut self, amount: Option<YoctoNear>) -> PromiseOrValue<StakeAccountBalances> {
-        Self::staking_pool().ops_unstake(amount)
+    fn ops_unstake(
+        &mut self,
+        amount: Option<YoctoNear>,
+        memo: Option<Memo>,
+        min_near_out: Option<YoctoNear>,
+    ) -> PromiseOrValue<StakeAccountBalances> {
+        Self::staking_pool().ops_unstake(amount, memo, min_near_out)
+    }
+
+    fn ops_unstake_tokens(
+        &mut self,
+        amount: TokenAmount,
+        memo: Option<Memo>,
+        min_near_out: Option<YoctoNear>,
+    ) -> PromiseOrValue<StakeAccountBalances> {
+        Self::staking_pool().ops_unstake_tokens(amount, memo, min_near_out)
+    }
+
+    fn ops_stake_unstake_projection(
+        &self,
+        account_id: ValidAccountId,
+        amount: YoctoNear,
+    ) -> UnstakeProjection {
+        Self::staking_pool().ops_stake_unstake_projection(account_id, amount)
+    }
+
+    fn ops_stake_preview(&self, account_id: ValidAccountId, amount: YoctoNear) -> StakePreview {
+        Self::staking_pool().ops_stake_preview(account_id, amount)
+    }
+
+    fn ops_unstake_preview(
+        &self,
+        account_id: ValidAccountId,
+        amount: Option<YoctoNear>,
+    ) -> UnstakePreview {
+        Self::staking_pool().ops_unstake_preview(account_id, amount)
     }
 
     fn ops_restake(&mut self, amount: Option<YoctoNear>) -> PromiseOrValue<StakeAccountBalances> {
         Self::staking_pool().ops_restake(amount)
     }
 
-    fn ops_stake_withdraw(&mut self, amount: Option<YoctoNear>) -> StakeAccountBalances {
-        Self::staking_pool().ops_stake_withdraw(amount)
+    fn ops_stake_withdraw(
+        &mut self,
+        amount: Option<YoctoNear>,
+        memo: Option<Memo>,
+    ) -> StakeAccountBalances {
+        Self::staking_pool().ops_stake_withdraw(amount, memo)
+    }
+
+    #[payable]
+    fn ops_stake_withdraw_to(
+        &mut self,
+        receiver: ValidAccountId,
+        amount: Option<YoctoNear>,
+    ) -> StakeAccountBalances {
+        Self::staking_pool().ops_stake_withdraw_to(receiver, amount)
+    }
+
+    fn ops_unstake_all_and_withdraw(
+        &mut self,
+        amount: Option<YoctoNear>,
+    ) -> PromiseOrValue<StakeAccountBalances> {
+        Self::staking_pool().ops_unstake_all_and_withdraw(amount)
+    }
+
+    fn ops_stake_process_pending_withdrawals(&mut self) -> u32 {
+        Self::staking_pool().ops_stake_process_pending_withdrawals()
+    }
+
+    fn ops_stake_pending_withdrawal(
+        &self,
+        account_id: ValidAccountId,
+    ) -> Option<PendingWithdrawal> {
+        Self::staking_pool().ops_stake_pending_withdrawal(account_id)
+    }
+
+    fn ops_stake_withdrawable_accounts(&self, limit: u32) -> WithdrawableAccounts {
+        Self::staking_pool().ops_stake_withdrawable_accounts(limit)
     }
 
     #[payable]
@@ -37,8 +127,18 @@ impl StakingPool for Contract {
         receiver_id: ValidAccountId,
         amount: YoctoNear,
         memo: Option<Memo>,
-    ) -> TokenAmount {
-        Self::staking_pool().ops_stake_transfer(receiver_id, amount, memo)
+        rounding: Option<RoundingMode>,
+    ) -> StakedBalance {
+        Self::staking_pool().ops_stake_transfer(receiver_id, amount, memo, rounding)
+    }
+
+    #[payable]
+    fn ops_stake_transfer_unstaked(
+        &mut self,
+        receiver_id: ValidAccountId,
+        amount: YoctoNear,
+    ) -> StakeAccountBalances {
+        Self::staking_pool().ops_stake_transfer_unstaked(receiver_id, amount)
     }
 
     #[payable]
@@ -52,6 +152,11 @@ impl StakingPool for Contract {
         Self::staking_pool().ops_stake_transfer_call(receiver_id, amount, memo, msg)
     }
 
+    #[payable]
+    fn ops_stake_burn(&mut self, amount: TokenAmount) -> StakeAccountBalances {
+        Self::staking_pool().ops_stake_burn(amount)
+    }
+
     fn ops_stake_token_value(&self, amount: Option<TokenAmount>) -> YoctoNear {
         Self::staking_pool().ops_stake_token_value(amount)
     }
@@ -60,91 +165,125 @@ impl StakingPool for Contract {
         Self::staking_pool().ops_stake_token_value_with_earnings(amount)
     }
 
-    fn ops_stake_status(&self) -> Status {
-        Self::staking_pool().ops_stake_status()
+    fn ops_stake_compound(&mut self) -> TokenAmount {
+        Self::staking_pool().ops_stake_compound()
     }
 
-    fn ops_stake_pool_balances(&self) -> StakingPoolBalances {
-        Self::staking_pool().ops_stake_pool_balances()
+    fn ops_stake_process_epoch(&mut self) -> Option<UnstakeBatch> {
+        Self::staking_pool().ops_stake_process_epoch()
     }
 
-    fn ops_stake_fees(&self) -> Fees {
-        Self::staking_pool().ops_stake_fees()
+    fn ops_stake_admit_waitlist(&mut self) -> YoctoNear {
+        Self::staking_pool().ops_stake_admit_waitlist()
     }
 
-    fn ops_stake_public_key(&self) -> PublicKey {
-        Self::staking_pool().ops_stake_public_key()
+    fn ops_stake_retry_finalize(&mut self) -> Vec<FinalizeIntent> {
+        Self::staking_pool().ops_stake_retry_finalize()
     }
-}
 
-#[near_bindgen]
-impl NearStakingPool for Contract {
-    fn get_account_staked_balance(&self, account_id: ValidAccountId) -> YoctoNear {
-        Self::staking_pool().get_account_staked_balance(account_id)
+    fn ops_stake_finalize_retry_queue(&self) -> Vec<FinalizeIntent> {
+        Self::staking_pool().ops_stake_finalize_retry_queue()
     }
 
-    fn get_account_unstaked_balance(&self, account_id: ValidAccountId) -> YoctoNear {
-        Self::staking_pool().get_account_unstaked_balance(account_id)
+    fn ops_stake_token_value_twap(&self, epochs: u64) -> Option<YoctoNear> {
+        Self::staking_pool().ops_stake_token_value_twap(epochs)
     }
 
-    fn is_account_unstaked_balance_available(&self, account_id: ValidAccountId) -> bool {
-        Self::staking_pool().is_account_unstaked_balance_available(account_id)
+    fn ops_stake_token_value_history(&self) -> Vec<StakeTokenValueSample> {
+        Self::staking_pool().ops_stake_token_value_history()
     }
 
-    fn get_account_total_balance(&self, account_id: ValidAccountId) -> YoctoNear {
-        Self::staking_pool().get_account_total_balance(account_id)
+    fn ops_stake_operation(&self, id: U64) -> Option<StakeOperation> {
+        Self::staking_pool().ops_stake_operation(id)
     }
 
-    fn get_account(&self, account_id: ValidAccountId) -> NearStakingPoolAccount {
-        Self::staking_pool().get_account(account_id)
+    fn ops_stake_validator_performance(&self, limit: Option<u64>) -> Vec<EpochPerformance> {
+        Self::staking_pool().ops_stake_validator_performance(limit)
     }
 
-    #[payable]
-    fn deposit(&mut self) {
-        Self::staking_pool().deposit();
+    fn ops_stake_status(&self) -> Status {
+        Self::staking_pool().ops_stake_status()
     }
 
-    #[payable]
-    fn deposit_and_stake(&mut self) {
-        Self::staking_pool().deposit_and_stake();
+    fn ops_stake_pool_balances(&self) -> StakingPoolBalances {
+        Self::staking_pool().ops_stake_pool_balances()
     }
 
-    fn withdraw(&mut self, amount: YoctoNear) {
-        Self::staking_pool().withdraw(amount);
+    fn ops_stake_pool_balances_history(
+        &self,
+        limit: Option<u64>,
+    ) -> Vec<StakingPoolBalancesSnapshot> {
+        Self::staking_pool().ops_stake_pool_balances_history(limit)
     }
 
-    fn withdraw_all(&mut self) {
-        Self::staking_pool().withdraw_all();
+    fn ops_stake_pool_balances_at(
+        &self,
+        block_height: BlockHeight,
+    ) -> Option<StakingPoolBalancesSnapshot> {
+        Self::staking_pool().ops_stake_pool_balances_at(block_height)
     }
 
-    fn stake(&mut self, amount: YoctoNear) {
-        Self::staking_pool().stake(amount);
+    fn ops_stake_health(&self) -> StakingPoolHealth {
+        Self::staking_pool().ops_stake_health()
     }
 
-    fn unstake(&mut self, amount: YoctoNear) {
-        Self::staking_pool().unstake(amount);
+    fn ops_stake_fees(&self) -> Fees {
+        Self::staking_pool().ops_stake_fees()
     }
 
-    fn unstake_all(&mut self) {
-        Self::staking_pool().unstake_all();
+    fn ops_stake_public_key(&self) -> PublicKey {
+        Self::staking_pool().ops_stake_public_key()
+    }
+
+    fn ops_storage_cost_of(&self, operation: StorageOpKind) -> Option<StorageCost> {
+        Self::staking_pool().ops_storage_cost_of(operation)
     }
 }
 
 #[near_bindgen]
 impl StakeActionCallbacks for Contract {
+    fn ops_stake_finalize(
+        &mut self,
+        account_id: AccountId,
+        operation_id: Option<U64>,
+        intent_id: Option<U64>,
+    ) -> StakeAccountBalances {
+        Self::staking_pool().ops_stake_finalize(account_id, operation_id, intent_id)
+    }
+
+    fn ops_stake_start_finalize(&mut self, intent_id: Option<U64>) {
+        Self::staking_pool().ops_stake_start_finalize(intent_id);
+    }
+
+    fn ops_stake_stop_finalize(&mut self, intent_id: Option<U64>) {
+        Self::staking_pool().ops_stake_stop_finalize(intent_id)
+    }
+
     #[private]
-    fn ops_stake_finalize(&mut self, account_id: AccountId) -> StakeAccountBalances {
-        Self::staking_pool().ops_stake_finalize(account_id)
+    fn ops_stake_attestation_callback(
+        &mut self,
+        account_id: AccountId,
+        min_stake_out: Option<TokenAmount>,
+    ) -> PromiseOrValue<StakeAccountBalances> {
+        Self::staking_pool().ops_stake_attestation_callback(account_id, min_stake_out)
     }
 
     #[private]
-    fn ops_stake_start_finalize(&mut self) {
-        Self::staking_pool().ops_stake_start_finalize();
+    fn ops_stake_public_key_rotation_callback(
+        &mut self,
+        public_key: PublicKey,
+        custodian: AccountId,
+    ) -> bool {
+        Self::staking_pool().ops_stake_public_key_rotation_callback(public_key, custodian)
     }
 
     #[private]
-    fn ops_stake_stop_finalize(&mut self) {
-        Self::staking_pool().ops_stake_stop_finalize()
+    fn ops_farm_claim_callback(
+        &mut self,
+        account_id: AccountId,
+        amount: TokenAmount,
+    ) -> TokenAmount {
+        Self::staking_pool().ops_farm_claim_callback(account_id, amount)
     }
 }
 
@@ -153,40 +292,108 @@ impl StakingPoolOperator for Contract {
     fn ops_stake_operator_command(&mut self, command: StakingPoolOperatorCommand) {
         Self::staking_pool().ops_stake_operator_command(command);
     }
-}
 
-#[near_bindgen]
-impl Treasury for Contract {
-    #[payable]
-    fn ops_stake_treasury_deposit(&mut self) -> PromiseOrValue<StakeAccountBalances> {
-        Self::staking_pool().ops_stake_treasury_deposit()
+    fn ops_stake_price_oracle(&self) -> Option<StakePriceOracle> {
+        Self::staking_pool().ops_stake_price_oracle()
     }
 
-    #[payable]
-    fn ops_stake_treasury_distribution(&mut self) {
-        Self::staking_pool().ops_stake_treasury_distribution();
+    fn ops_stake_transfer_auto_registration(&self) -> bool {
+        Self::staking_pool().ops_stake_transfer_auto_registration()
+    }
+
+    fn ops_stake_storage_deposit_auto_stake(&self) -> bool {
+        Self::staking_pool().ops_stake_storage_deposit_auto_stake()
+    }
+
+    fn ops_stake_pool_balances_events(&self) -> bool {
+        Self::staking_pool().ops_stake_pool_balances_events()
     }
 
-    fn ops_stake_treasury_transfer_to_owner(&mut self, amount: Option<YoctoNear>) {
-        Self::staking_pool().ops_stake_treasury_transfer_to_owner(amount);
+    fn ops_stake_cap(&self) -> Option<StakeCap> {
+        Self::staking_pool().ops_stake_cap()
     }
 
-    fn ops_stake_grant_treasurer(&mut self, account_id: ValidAccountId) {
-        Self::staking_pool().ops_stake_grant_treasurer(account_id);
+    fn ops_stake_waitlist(&self) -> StakeWaitlistSummary {
+        Self::staking_pool().ops_stake_waitlist()
     }
 
-    fn ops_stake_revoke_treasurer(&mut self, account_id: ValidAccountId) {
-        Self::staking_pool().ops_stake_revoke_treasurer(account_id);
+    fn ops_stake_waitlist_position(
+        &self,
+        account_id: ValidAccountId,
+    ) -> Option<StakeWaitlistPosition> {
+        Self::staking_pool().ops_stake_waitlist_position(account_id)
     }
 
-    fn ops_stake_is_treasurer(&mut self, account_id: ValidAccountId) -> bool {
-        Self::staking_pool().ops_stake_is_treasurer(account_id)
+    fn ops_stake_unstake_batching(&self) -> UnstakeBatchingSummary {
+        Self::staking_pool().ops_stake_unstake_batching()
+    }
+
+    fn ops_stake_min_stake(&self) -> YoctoNear {
+        Self::staking_pool().ops_stake_min_stake()
+    }
+
+    fn ops_stake_attestation_gate(&self) -> Option<StakeAttestationGate> {
+        Self::staking_pool().ops_stake_attestation_gate()
+    }
+
+    fn ops_stake_gas_reserve(&self) -> Option<GasReserve> {
+        Self::staking_pool().ops_stake_gas_reserve()
+    }
+
+    fn ops_stake_key_custodian(&self) -> Option<StakeKeyCustody> {
+        Self::staking_pool().ops_stake_key_custodian()
+    }
+
+    fn ops_stake_public_key_history(&self) -> Vec<StakePublicKeyRotation> {
+        Self::staking_pool().ops_stake_public_key_history()
+    }
+
+    fn ops_stake_pending_commands(&self) -> Vec<PendingCommand> {
+        Self::staking_pool().ops_stake_pending_commands()
+    }
+
+    fn ops_stake_command_timelock(&self) -> U64 {
+        Self::staking_pool().ops_stake_command_timelock()
+    }
+
+    fn ops_stake_execute_pending_command(&mut self, id: U64) {
+        Self::staking_pool().ops_stake_execute_pending_command(id);
+    }
+
+    fn ops_stake_cancel_pending_command(&mut self, id: U64) {
+        Self::staking_pool().ops_stake_cancel_pending_command(id);
+    }
+
+    fn ops_stake_schedule_fee_holiday(
+        &mut self,
+        start_epoch: EpochHeight,
+        end_epoch: EpochHeight,
+        staking_fee_bps: BasisPoints,
+    ) {
+        Self::staking_pool().ops_stake_schedule_fee_holiday(
+            start_epoch,
+            end_epoch,
+            staking_fee_bps,
+        );
+    }
+
+    fn ops_stake_cancel_fee_holiday(&mut self, start_epoch: EpochHeight) {
+        Self::staking_pool().ops_stake_cancel_fee_holiday(start_epoch);
+    }
+
+    fn ops_stake_fee_holidays(&self) -> Vec<FeeHoliday> {
+        Self::staking_pool().ops_stake_fee_holidays()
+    }
+
+    fn ops_stake_fee_change_preview(&self, fees: Fees) -> Option<FeeChangePreview> {
+        Self::staking_pool().ops_stake_fee_change_preview(fees)
     }
 }
 
 #[near_bindgen]
 impl TransferReceiver for Contract {
-    #[private]
+    /// invoked internally for treasury deposits, or by the rewards farm token contract for rewards
+    /// farm deposits - the staking pool component authorizes the predecessor
     fn ft_on_transfer(
         &mut self,
         sender_id: ValidAccountId,
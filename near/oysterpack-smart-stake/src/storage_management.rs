@@ -3,35 +3,95 @@ use crate::*;
 use near_sdk::json_types::ValidAccountId;
 use near_sdk::near_bindgen;
 use oysterpack_smart_account_management::StorageBalance;
-use oysterpack_smart_account_management::{StorageBalanceBounds, StorageManagement};
+use oysterpack_smart_account_management::{
+    StorageBalanceBounds, StorageManagement, StorageSponsorship, StorageUsageBounds,
+    StorageUsageBoundsManagement, StorageWithdrawal,
+};
 use oysterpack_smart_near::domain::YoctoNear;
+use oysterpack_smart_near::near_sdk::AccountId;
 
+/// [`StorageManagement`] is exposed with an additional optional `stake` arg on `storage_deposit`,
+/// which is backwards compatible with the standard JSON API
 #[near_bindgen]
-impl StorageManagement for Contract {
+impl Contract {
+    /// see [`StorageManagement::storage_deposit`]
+    /// - if `stake` is true, then the deposit above the storage balance minimum is staked
+    /// - `stake` defaults to the `StakingPoolOperatorCommand::EnableStorageDepositAutoStake` mode,
+    ///   which is disabled by default
     #[payable]
-    fn storage_deposit(
+    pub fn storage_deposit(
         &mut self,
         account_id: Option<ValidAccountId>,
         registration_only: Option<bool>,
+        stake: Option<bool>,
     ) -> StorageBalance {
-        Self::account_manager().storage_deposit(account_id, registration_only)
+        Self::staking_pool().storage_deposit(account_id, registration_only, stake)
     }
 
+    /// see [`StorageManagement::storage_deposit_many`]
     #[payable]
-    fn storage_withdraw(&mut self, amount: Option<YoctoNear>) -> StorageBalance {
-        Self::account_manager().storage_withdraw(amount)
+    pub fn storage_deposit_many(&mut self, accounts: Vec<ValidAccountId>) -> Vec<StorageBalance> {
+        Self::staking_pool().storage_deposit_many(accounts)
     }
 
+    /// see [`StorageManagement::storage_withdraw`]
     #[payable]
-    fn storage_unregister(&mut self, force: Option<bool>) -> bool {
+    pub fn storage_withdraw(&mut self, amount: Option<YoctoNear>) -> StorageBalance {
+        Self::staking_pool().storage_withdraw(amount)
+    }
+
+    /// see [`StorageManagement::storage_unregister`]
+    #[payable]
+    pub fn storage_unregister(&mut self, force: Option<bool>) -> bool {
         Self::account_manager().storage_unregister(force)
     }
 
-    fn storage_balance_bounds(&self) -> StorageBalanceBounds {
+    /// see [`StorageManagement::storage_balance_bounds`]
+    pub fn storage_balance_bounds(&self) -> StorageBalanceBounds {
         Self::account_manager().storage_balance_bounds()
     }
 
-    fn storage_balance_of(&self, account_id: ValidAccountId) -> Option<StorageBalance> {
+    /// see [`StorageManagement::storage_balance_of`]
+    pub fn storage_balance_of(&self, account_id: ValidAccountId) -> Option<StorageBalance> {
         Self::account_manager().storage_balance_of(account_id)
     }
 }
+
+#[near_bindgen]
+impl StorageSponsorship for Contract {
+    #[payable]
+    fn ops_storage_sponsor_accounts(&mut self, accounts: Vec<ValidAccountId>) -> Vec<AccountId> {
+        Self::account_manager().ops_storage_sponsor_accounts(accounts)
+    }
+}
+
+#[near_bindgen]
+impl StorageUsageBoundsManagement for Contract {
+    fn ops_storage_usage_bounds_update(
+        &mut self,
+        bounds: StorageUsageBounds,
+    ) -> StorageBalanceBounds {
+        Self::account_manager().ops_storage_usage_bounds_update(bounds)
+    }
+
+    #[payable]
+    fn ops_storage_top_up_accounts(&mut self, from: u64, limit: u64) -> Vec<AccountId> {
+        Self::account_manager().ops_storage_top_up_accounts(from, limit)
+    }
+
+    fn ops_storage_index_accounts(&mut self, account_ids: Vec<ValidAccountId>) -> u32 {
+        Self::account_manager().ops_storage_index_accounts(account_ids)
+    }
+}
+
+#[near_bindgen]
+impl StorageWithdrawal for Contract {
+    #[payable]
+    fn ops_storage_withdraw_to(
+        &mut self,
+        receiver_id: ValidAccountId,
+        amount: Option<YoctoNear>,
+    ) -> StorageBalance {
+        Self::account_manager().ops_storage_withdraw_to(receiver_id, amount)
+    }
+}
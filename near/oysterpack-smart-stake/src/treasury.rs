@@ -0,0 +1,65 @@
+use crate::*;
+use near_sdk::near_bindgen;
+use oysterpack_smart_near::domain::{BasisPoints, YoctoNear};
+use oysterpack_smart_near::near_sdk::PromiseOrValue;
+use oysterpack_smart_staking_pool::{
+    StakeAccountBalances, Treasury, TreasuryBeneficiary, TreasuryDividendPolicy, TreasuryDonation,
+    TreasuryDonationTotals,
+};
+
+#[near_bindgen]
+impl Treasury for Contract {
+    #[payable]
+    fn ops_stake_treasury_deposit(&mut self) -> PromiseOrValue<StakeAccountBalances> {
+        Self::staking_pool().ops_stake_treasury_deposit()
+    }
+
+    #[payable]
+    fn ops_stake_treasury_distribution(&mut self) {
+        Self::staking_pool().ops_stake_treasury_distribution();
+    }
+
+    fn ops_stake_treasury_transfer_to_owner(&mut self, amount: Option<YoctoNear>) {
+        Self::staking_pool().ops_stake_treasury_transfer_to_owner(amount);
+    }
+
+    #[payable]
+    fn ops_stake_grant_treasurer(&mut self, account_id: ValidAccountId) {
+        Self::staking_pool().ops_stake_grant_treasurer(account_id);
+    }
+
+    #[payable]
+    fn ops_stake_revoke_treasurer(&mut self, account_id: ValidAccountId) {
+        Self::staking_pool().ops_stake_revoke_treasurer(account_id);
+    }
+
+    fn ops_stake_is_treasurer(&mut self, account_id: ValidAccountId) -> bool {
+        Self::staking_pool().ops_stake_is_treasurer(account_id)
+    }
+
+    #[payable]
+    fn ops_stake_treasury_set_beneficiary(&mut self, account_id: ValidAccountId, bps: BasisPoints) {
+        Self::staking_pool().ops_stake_treasury_set_beneficiary(account_id, bps);
+    }
+
+    fn ops_stake_treasury_beneficiary(&self) -> Option<TreasuryBeneficiary> {
+        Self::staking_pool().ops_stake_treasury_beneficiary()
+    }
+
+    #[payable]
+    fn ops_stake_treasury_set_dividend_policy(&mut self, policy: TreasuryDividendPolicy) {
+        Self::staking_pool().ops_stake_treasury_set_dividend_policy(policy);
+    }
+
+    fn ops_stake_treasury_dividend_policy(&self) -> TreasuryDividendPolicy {
+        Self::staking_pool().ops_stake_treasury_dividend_policy()
+    }
+
+    fn ops_stake_treasury_donations(&self, limit: Option<u16>) -> Vec<TreasuryDonation> {
+        Self::staking_pool().ops_stake_treasury_donations(limit)
+    }
+
+    fn ops_stake_treasury_donation_totals(&self) -> TreasuryDonationTotals {
+        Self::staking_pool().ops_stake_treasury_donation_totals()
+    }
+}
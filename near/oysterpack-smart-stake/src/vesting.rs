@@ -0,0 +1,27 @@
+use crate::*;
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::domain::BlockTimestamp;
+use oysterpack_smart_near::near_sdk::PromiseOrValue;
+use oysterpack_smart_staking_pool::{StakeAccountBalances, StakeVesting, VestingSchedule};
+
+#[near_bindgen]
+impl StakeVesting for Contract {
+    #[payable]
+    fn ops_stake_vest(
+        &mut self,
+        account_id: ValidAccountId,
+        start: Option<BlockTimestamp>,
+        cliff: BlockTimestamp,
+        end: BlockTimestamp,
+    ) -> PromiseOrValue<StakeAccountBalances> {
+        Self::staking_pool().ops_stake_vest(account_id, start, cliff, end)
+    }
+
+    fn ops_stake_vesting_schedule(&self, account_id: ValidAccountId) -> Option<VestingSchedule> {
+        Self::staking_pool().ops_stake_vesting_schedule(account_id)
+    }
+
+    fn ops_stake_unvested_balance(&self, account_id: ValidAccountId) -> TokenAmount {
+        Self::staking_pool().ops_stake_unvested_balance(account_id)
+    }
+}
@@ -1,13 +1,105 @@
+mod account_activity;
+mod archived_account;
+mod command_timelock;
+mod compound_bounty;
+mod earnings_buffer;
+mod earnings_fee_split;
+mod earnings_sources;
+mod fee_change_preview;
+mod fee_holidays;
 mod fees;
+mod finalize_retry_queue;
+mod gas_reserve;
+mod governance;
+mod liquidity_pool;
+mod min_stake;
+mod owner_earnings_mode;
+mod owner_revenue;
+mod pending_withdrawals;
+mod permissioned_pool;
+mod referral;
+mod relayed_stake;
+mod rewards_beneficiary;
+mod rewards_farm;
+mod rounding_mode;
 mod stake_account;
 mod stake_account_balances;
+mod stake_attestation;
+mod stake_cap;
+mod stake_certificate;
+mod stake_key_custody;
+mod stake_lock;
+mod stake_operation;
+mod stake_preview;
+mod stake_price_oracle;
+mod stake_token_value_history;
+mod stake_waitlist;
 mod staking_pool_balances;
+mod staking_pool_health;
+mod staking_pool_invariants;
 mod status;
+mod storage_deposit_auto_stake;
+mod storage_op_costs;
+mod transfer_auto_registration;
+mod treasury_beneficiary;
+mod treasury_dividend_policy;
+mod treasury_donations;
+mod unstake_batching;
+mod unstake_projection;
 mod unstaked_balances;
+mod validator_performance;
+mod vesting;
+mod withdrawal_index;
 
+pub use account_activity::*;
+pub use archived_account::*;
+pub use command_timelock::*;
+pub use compound_bounty::*;
+pub use earnings_buffer::*;
+pub use earnings_fee_split::*;
+pub use earnings_sources::*;
+pub use fee_change_preview::*;
+pub use fee_holidays::*;
 pub use fees::*;
+pub use finalize_retry_queue::*;
+pub use gas_reserve::*;
+pub use governance::*;
+pub use liquidity_pool::*;
+pub use min_stake::*;
+pub use owner_earnings_mode::*;
+pub use owner_revenue::*;
+pub use pending_withdrawals::*;
+pub use permissioned_pool::*;
+pub use referral::*;
+pub use relayed_stake::*;
+pub use rewards_beneficiary::*;
+pub use rewards_farm::*;
+pub use rounding_mode::*;
 pub use stake_account::*;
 pub use stake_account_balances::*;
+pub use stake_attestation::*;
+pub use stake_cap::*;
+pub use stake_certificate::*;
+pub use stake_key_custody::*;
+pub use stake_lock::*;
+pub use stake_operation::*;
+pub use stake_preview::*;
+pub use stake_price_oracle::*;
+pub use stake_token_value_history::*;
+pub use stake_waitlist::*;
 pub use staking_pool_balances::*;
+pub use staking_pool_health::*;
+pub use staking_pool_invariants::*;
 pub use status::*;
+pub use storage_deposit_auto_stake::*;
+pub use storage_op_costs::*;
+pub use transfer_auto_registration::*;
+pub use treasury_beneficiary::*;
+pub use treasury_dividend_policy::*;
+pub use treasury_donations::*;
+pub use unstake_batching::*;
+pub use unstake_projection::*;
 pub use unstaked_balances::*;
+pub use validator_performance::*;
+pub use vesting::*;
+pub use withdrawal_index::*;
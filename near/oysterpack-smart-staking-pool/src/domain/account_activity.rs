@@ -0,0 +1,60 @@
+use oysterpack_smart_account_management::AccountStorageEvent;
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::EpochHeight;
+use oysterpack_smart_near::eventbus::post;
+use oysterpack_smart_near::near_sdk::env;
+use oysterpack_smart_near::Hash;
+
+/// Tracks the epoch in which the account last staked, unstaked, restaked, or withdrew
+/// - used to find inactive accounts that can be force unregistered by the operator to keep the
+///   contract state size bounded
+///
+/// The activity record is stored separately from the account's [`crate::StakeAccountData`], but its
+/// storage usage is charged to the account.
+pub struct AccountActivity;
+
+const ACCOUNT_ACTIVITY_KEY: u128 = 1957874518402263906457142307695832951;
+
+type AccountActivityObject = Object<Hash, EpochHeight>;
+
+impl AccountActivity {
+    fn object_key(account_id: &str) -> Hash {
+        Hash::from((account_id, ACCOUNT_ACTIVITY_KEY))
+    }
+
+    /// returns the epoch in which the account was last active
+    /// - returns None if no activity has been recorded for the account
+    pub fn last_active_epoch(account_id: &str) -> Option<EpochHeight> {
+        AccountActivityObject::load(&Self::object_key(account_id)).map(|epoch| *epoch)
+    }
+
+    /// records the current epoch as the account's last active epoch
+    /// - storage is only written once per epoch
+    /// - tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub fn record(account_id: &str) {
+        let current_epoch = EpochHeight::from_env();
+        if Self::last_active_epoch(account_id) == Some(current_epoch) {
+            return;
+        }
+        let initial_storage_usage = env::storage_usage();
+        AccountActivityObject::new(Self::object_key(account_id), current_epoch).save();
+        Self::track_storage_usage(account_id, initial_storage_usage);
+    }
+
+    /// tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub fn delete(account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        AccountActivityObject::delete_by_key(&Self::object_key(account_id));
+        Self::track_storage_usage(account_id, initial_storage_usage);
+    }
+
+    fn track_storage_usage(account_id: &str, initial_storage_usage: u64) {
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use oysterpack_smart_account_management::AccountStorageEvent;
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::EpochHeight;
+use oysterpack_smart_near::eventbus::post;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::Hash;
+
+/// Minimal tombstone for an account with zero balances whose staking pool data was archived
+/// - the account's activity record and referrer are moved into the tombstone
+/// - the account's rewards beneficiary and relay key settings are deleted, i.e., the account needs
+///   to reconfigure them after it is restored
+/// - the storage that is released is deducted from the account's storage usage, i.e., it is released
+///   back to the account's storage balance
+/// - the account remains registered, and its data is restored on the account's next interaction
+///   with the staking pool, i.e., when the account stakes, unstakes, restakes, withdraws, or updates
+///   its staking pool settings
+/// - while the account is archived, its archived data is only available via the tombstone
+///
+/// The tombstone storage usage is charged to the account.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct ArchivedAccount {
+    pub archived_on: EpochHeight,
+    pub last_active_epoch: EpochHeight,
+    pub referrer: Option<AccountId>,
+}
+
+const ARCHIVED_ACCOUNT_KEY: u128 = 1958151007362918450173629384710562839;
+
+type ArchivedAccountObject = Object<Hash, ArchivedAccount>;
+
+impl ArchivedAccount {
+    fn object_key(account_id: &str) -> Hash {
+        Hash::from((account_id, ARCHIVED_ACCOUNT_KEY))
+    }
+
+    pub fn load(account_id: &str) -> Option<ArchivedAccount> {
+        ArchivedAccountObject::load(&Self::object_key(account_id)).map(|object| (*object).clone())
+    }
+
+    pub fn exists(account_id: &str) -> bool {
+        ArchivedAccountObject::exists(&Self::object_key(account_id))
+    }
+
+    /// tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn save(&self, account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        ArchivedAccountObject::new(Self::object_key(account_id), self.clone()).save();
+        Self::track_storage_usage(account_id, initial_storage_usage);
+    }
+
+    /// tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn delete(account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        ArchivedAccountObject::delete_by_key(&Self::object_key(account_id));
+        Self::track_storage_usage(account_id, initial_storage_usage);
+    }
+
+    fn track_storage_usage(account_id: &str, initial_storage_usage: u64) {
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+}
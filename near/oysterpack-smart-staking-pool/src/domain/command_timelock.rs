@@ -0,0 +1,117 @@
+use crate::StakingPoolOperatorCommand;
+use oysterpack_smart_near::asserts::ERR_ILLEGAL_STATE;
+use oysterpack_smart_near::data::{numbers::U64, ComponentStorageUsage, Object};
+use oysterpack_smart_near::domain::BlockHeight;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+/// Operator command that is queued by the command timelock
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct PendingCommand {
+    pub id: U64,
+    pub command: StakingPoolOperatorCommand,
+    pub queued_at: BlockHeight,
+    /// block height at which the command is eligible for execution
+    pub eligible_at: BlockHeight,
+}
+
+/// Delays sensitive operator commands, which gives stakers time to exit before the command takes
+/// effect, e.g., fee hikes
+/// - the timelock is disabled by default, i.e., the delay is zero
+/// - the queue is bounded to [`CommandTimelock::MAX_PENDING_COMMANDS`] commands
+pub struct CommandTimelock;
+
+const COMMAND_TIMELOCK_DELAY_KEY: u128 = 1958021937265104458830519247612098716;
+const PENDING_COMMANDS_KEY: u128 = 1958021968940273621955470318026457243;
+
+type CommandTimelockDelayObject = Object<u128, U64>;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+struct PendingCommands {
+    next_id: u64,
+    commands: Vec<PendingCommand>,
+}
+
+type PendingCommandsObject = Object<u128, PendingCommands>;
+
+impl CommandTimelock {
+    pub const MAX_PENDING_COMMANDS: usize = 10;
+
+    /// returns the number of blocks that timelocked commands are delayed by
+    pub fn delay() -> U64 {
+        CommandTimelockDelayObject::load(&COMMAND_TIMELOCK_DELAY_KEY)
+            .map_or_else(|| 0.into(), |delay| *delay)
+    }
+
+    pub(crate) fn set_delay(delay: U64) {
+        if *delay == 0 {
+            CommandTimelockDelayObject::delete_by_key(&COMMAND_TIMELOCK_DELAY_KEY);
+        } else {
+            CommandTimelockDelayObject::new(COMMAND_TIMELOCK_DELAY_KEY, delay).save();
+        }
+    }
+
+    /// returns the pending commands ordered by ID
+    pub fn pending_commands() -> Vec<PendingCommand> {
+        Self::load().commands
+    }
+
+    pub fn pending_command(id: U64) -> Option<PendingCommand> {
+        Self::load()
+            .commands
+            .into_iter()
+            .find(|command| command.id == id)
+    }
+
+    /// queues the command, which becomes eligible for execution once the delay has elapsed
+    ///
+    /// ## Panics
+    /// [`ERR_ILLEGAL_STATE`] if the queue is full - see [`CommandTimelock::MAX_PENDING_COMMANDS`]
+    pub(crate) fn queue(command: StakingPoolOperatorCommand) -> PendingCommand {
+        let mut pending_commands = Self::load();
+        ERR_ILLEGAL_STATE.assert(
+            || pending_commands.commands.len() < Self::MAX_PENDING_COMMANDS,
+            || {
+                format!(
+                    "pending command queue is full: max = {}",
+                    Self::MAX_PENDING_COMMANDS
+                )
+            },
+        );
+        let queued_at = BlockHeight::from_env();
+        let pending_command = PendingCommand {
+            id: pending_commands.next_id.into(),
+            command,
+            queued_at,
+            eligible_at: queued_at + *Self::delay(),
+        };
+        pending_commands.next_id += 1;
+        pending_commands.commands.push(pending_command.clone());
+        ComponentStorageUsage::track(ComponentStorageUsage::STAKING, || {
+            PendingCommandsObject::new(PENDING_COMMANDS_KEY, pending_commands).save();
+        });
+        pending_command
+    }
+
+    /// removes the pending command from the queue
+    pub(crate) fn remove(id: U64) -> Option<PendingCommand> {
+        let mut pending_commands = Self::load();
+        let index = pending_commands
+            .commands
+            .iter()
+            .position(|command| command.id == id)?;
+        let pending_command = pending_commands.commands.remove(index);
+        ComponentStorageUsage::track(ComponentStorageUsage::STAKING, || {
+            PendingCommandsObject::new(PENDING_COMMANDS_KEY, pending_commands).save();
+        });
+        Some(pending_command)
+    }
+
+    fn load() -> PendingCommands {
+        PendingCommandsObject::load(&PENDING_COMMANDS_KEY)
+            .map_or_else(PendingCommands::default, |commands| (*commands).clone())
+    }
+}
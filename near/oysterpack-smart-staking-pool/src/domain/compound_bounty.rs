@@ -0,0 +1,29 @@
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::{BasisPoints, YoctoNear};
+
+/// Bounty that is paid to the keeper that calls [`crate::StakingPool::ops_stake_compound`], which
+/// is expressed as a percentage of the newly recognized earnings
+/// - the bounty is minted as STAKE, i.e., it is paid for by the stakers out of the earnings
+/// - zero by default, i.e., no bounty is paid
+pub struct CompoundBounty;
+
+const COMPOUND_BOUNTY_KEY: u128 = 1958150884107392615728340917264538109;
+type CompoundBountyObject = Object<u128, BasisPoints>;
+
+impl CompoundBounty {
+    /// 1%
+    pub const MAX_FEE: BasisPoints = BasisPoints(100);
+
+    pub fn fee() -> BasisPoints {
+        CompoundBountyObject::load(&COMPOUND_BOUNTY_KEY).map_or(BasisPoints::ZERO, |fee| *fee)
+    }
+
+    pub(crate) fn set_fee(fee: BasisPoints) {
+        CompoundBountyObject::new(COMPOUND_BOUNTY_KEY, fee).save();
+    }
+
+    /// computes the bounty NEAR value for the specified earnings
+    pub fn bounty(earnings: YoctoNear) -> YoctoNear {
+        Self::fee() * earnings
+    }
+}
@@ -0,0 +1,154 @@
+use oysterpack_smart_near::data::{
+    numbers::{U256, U64},
+    Object,
+};
+use oysterpack_smart_near::domain::YoctoNear;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+};
+
+/// Period over which detected staking earnings are vested into the total staked balance
+/// - smooths out STAKE value increases, which prevents large stakers from timing deposits to
+///   capture a disproportionate share of earnings that were collected while they were not staked
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub enum EarningsVestingPeriod {
+    Blocks(U64),
+    Epochs(U64),
+}
+
+impl EarningsVestingPeriod {
+    /// current block height or epoch height depending on the period type
+    fn now(&self) -> u64 {
+        match self {
+            Self::Blocks(_) => env::block_index(),
+            Self::Epochs(_) => env::epoch_height(),
+        }
+    }
+
+    fn length(&self) -> u64 {
+        match self {
+            Self::Blocks(blocks) => blocks.0,
+            Self::Epochs(epochs) => epochs.0,
+        }
+    }
+}
+
+/// Buffers the stakers' share of detected earnings, which are vested linearly into the total staked
+/// balance over the configured [`EarningsVestingPeriod`]
+/// - when new earnings are detected, the unvested balance plus the new earnings are vested over a
+///   new full period
+/// - block and epoch heights are tracked using the period type's unit
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct EarningsBuffer {
+    pub period: EarningsVestingPeriod,
+    /// earnings that have not yet been vested into the total staked balance
+    pub balance: YoctoNear,
+    /// when the balance was last vested
+    pub vested_at: U64,
+    /// when the balance will be fully vested
+    pub vests_at: U64,
+}
+
+const EARNINGS_BUFFER_KEY: u128 = 1958150859234718203947562018374650193;
+type EarningsBufferObject = Object<u128, EarningsBuffer>;
+
+impl EarningsBuffer {
+    pub fn load() -> Option<Self> {
+        EarningsBufferObject::load(&EARNINGS_BUFFER_KEY).map(|buffer| *buffer)
+    }
+
+    /// initializes an empty buffer with the specified vesting period
+    pub(crate) fn init(period: EarningsVestingPeriod) {
+        let now = period.now().into();
+        Self {
+            period,
+            balance: YoctoNear::ZERO,
+            vested_at: now,
+            vests_at: now,
+        }
+        .save();
+    }
+
+    pub(crate) fn save(&self) {
+        EarningsBufferObject::new(EARNINGS_BUFFER_KEY, *self).save();
+    }
+
+    /// returns the portion of the buffered balance that has vested since it was last vested
+    pub fn vested(&self) -> YoctoNear {
+        let now = self.period.now();
+        if now >= self.vests_at.0 {
+            return self.balance;
+        }
+        if now <= self.vested_at.0 {
+            return YoctoNear::ZERO;
+        }
+        (U256::from(*self.balance) * U256::from(now - self.vested_at.0)
+            / U256::from(self.vests_at.0 - self.vested_at.0))
+        .as_u128()
+        .into()
+    }
+
+    /// vests the buffered balance and then buffers the new earnings
+    /// - returns the vested amount that should be added to the total staked balance
+    pub(crate) fn vest(&mut self, earnings: YoctoNear) -> YoctoNear {
+        let vested = self.vested();
+        let now = self.period.now();
+        self.balance -= vested;
+        self.vested_at = now.into();
+        if earnings > YoctoNear::ZERO {
+            self.balance += earnings;
+            self.vests_at = (now + self.period.length()).into();
+        } else if self.balance == YoctoNear::ZERO {
+            self.vests_at = self.vested_at;
+        }
+        vested
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near_test::*;
+
+    fn set_block_index(block_index: u64) {
+        let mut ctx = TestCtx::new("bob");
+        ctx.block_index(block_index).apply();
+    }
+
+    #[test]
+    fn vest_linearly_over_blocks() {
+        set_block_index(100);
+        EarningsBuffer::init(EarningsVestingPeriod::Blocks(10.into()));
+        let mut buffer = EarningsBuffer::load().unwrap();
+        assert_eq!(buffer.vest(1000.into()), YoctoNear::ZERO);
+        assert_eq!(buffer.balance, 1000.into());
+        assert_eq!(buffer.vests_at, 110.into());
+
+        set_block_index(105);
+        assert_eq!(buffer.vested(), 500.into());
+        assert_eq!(buffer.vest(YoctoNear::ZERO), 500.into());
+        assert_eq!(buffer.balance, 500.into());
+        assert_eq!(buffer.vested_at, 105.into());
+        assert_eq!(buffer.vests_at, 110.into());
+
+        // new earnings restart the vesting period for the remaining balance
+        set_block_index(108);
+        assert_eq!(buffer.vest(400.into()), 300.into());
+        assert_eq!(buffer.balance, 600.into());
+        assert_eq!(buffer.vests_at, 118.into());
+
+        set_block_index(200);
+        assert_eq!(buffer.vest(YoctoNear::ZERO), 600.into());
+        assert_eq!(buffer.balance, YoctoNear::ZERO);
+        assert_eq!(buffer.vests_at, buffer.vested_at);
+        assert_eq!(buffer.vested(), YoctoNear::ZERO);
+    }
+}
@@ -0,0 +1,82 @@
+use oysterpack_smart_near::data::{numbers::U256, Object};
+use oysterpack_smart_near::domain::{BasisPoints, YoctoNear};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+/// Account that receives a share of the earnings fee
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct EarningsFeeRecipient {
+    pub account_id: AccountId,
+    /// share of the staking pool earnings - the recipient BPS sum up to the earnings fee
+    pub bps: BasisPoints,
+}
+
+/// Splits the earnings fee between multiple recipients, e.g., owner, operating team, and a DAO
+/// - the earnings fee is split proportionally to the recipient BPS, which means the split remains
+///   consistent if the earnings fee is changed
+/// - any remainder due to rounding is paid to the owner
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct EarningsFeeSplit {
+    pub recipients: Vec<EarningsFeeRecipient>,
+}
+
+const EARNINGS_FEE_SPLIT_KEY: u128 = 1957985306124718893514630277165903470;
+type EarningsFeeSplitObject = Object<u128, EarningsFeeSplit>;
+
+impl EarningsFeeSplit {
+    pub const MAX_RECIPIENTS: usize = 10;
+
+    pub fn load() -> Option<Self> {
+        EarningsFeeSplitObject::load(&EARNINGS_FEE_SPLIT_KEY).map(|split| (*split).clone())
+    }
+
+    pub(crate) fn save(self) {
+        EarningsFeeSplitObject::new(EARNINGS_FEE_SPLIT_KEY, self).save();
+    }
+
+    pub(crate) fn delete() {
+        EarningsFeeSplitObject::delete_by_key(&EARNINGS_FEE_SPLIT_KEY);
+    }
+
+    pub fn total_bps(&self) -> BasisPoints {
+        self.recipients
+            .iter()
+            .map(|recipient| *recipient.bps)
+            .sum::<u16>()
+            .into()
+    }
+
+    /// splits the earnings fee proportionally between the recipients
+    /// - the rounding remainder is paid to the owner
+    pub fn split(&self, owner_id: &str, earnings_fee: YoctoNear) -> Vec<(AccountId, YoctoNear)> {
+        let total_bps = *self.total_bps();
+        let mut shares: Vec<(AccountId, YoctoNear)> = self
+            .recipients
+            .iter()
+            .map(|recipient| {
+                let share = (U256::from(*earnings_fee) * U256::from(*recipient.bps)
+                    / U256::from(total_bps))
+                .as_u128();
+                (recipient.account_id.clone(), share.into())
+            })
+            .collect();
+        let remainder = shares
+            .iter()
+            .fold(earnings_fee, |remainder, (_, share)| remainder - *share);
+        if remainder > YoctoNear::ZERO {
+            match shares
+                .iter_mut()
+                .find(|(account_id, _)| account_id.as_str() == owner_id)
+            {
+                Some((_, share)) => *share += remainder,
+                None => shares.push((owner_id.to_string(), remainder)),
+            }
+        }
+        shares
+    }
+}
@@ -0,0 +1,39 @@
+use oysterpack_smart_near::domain::YoctoNear;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+/// Cumulative staking pool earnings broken down by source
+///
+/// ## NOTES
+/// - classification is best effort: staking rewards are detected as locked balance growth beyond
+///   the balance that was submitted in the last stake action, and any remaining earnings are
+///   attributed to transaction fees
+#[derive(
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct EarningsSources {
+    /// validator staking rewards, i.e., locked balance growth
+    pub staking_rewards: YoctoNear,
+    /// contract transaction fee rebates, i.e., liquid balance growth
+    pub transaction_fees: YoctoNear,
+    /// explicit donations made via [`crate::Treasury::ops_stake_treasury_distribution`]
+    pub donations: YoctoNear,
+}
+
+impl EarningsSources {
+    pub fn total(&self) -> YoctoNear {
+        self.staking_rewards + self.transaction_fees + self.donations
+    }
+}
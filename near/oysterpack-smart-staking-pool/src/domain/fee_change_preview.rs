@@ -0,0 +1,97 @@
+use crate::{Fees, StakingPoolBalancesSnapshot};
+use oysterpack_smart_near::data::numbers::U256;
+use oysterpack_smart_near::domain::{BasisPoints, EpochHeight, YoctoNear};
+use oysterpack_smart_near::near_sdk::serde::{Deserialize, Serialize};
+
+/// Projected impact of a fee change, based on the trailing earnings history - see
+/// [`crate::StakingPoolBalancesHistory`]
+///
+/// ## NOTES
+/// - revenue and APY figures are annualized from the trailing window using
+///   [`FeeChangePreview::EPOCHS_PER_YEAR`]
+/// - only the earnings fee impact is projected - staking fee revenue depends on future deposit
+///   volume, which is not predictable from the earnings history
+/// - the staker APY does not account for gas reserve allocations
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct FeeChangePreview {
+    pub current_fees: Fees,
+    pub proposed_fees: Fees,
+
+    /// epoch of the first earnings snapshot in the trailing window
+    pub from_epoch: EpochHeight,
+    /// epoch of the most recent earnings snapshot in the trailing window
+    pub to_epoch: EpochHeight,
+    /// total staked balance at the start of the trailing window
+    pub total_staked: YoctoNear,
+    /// gross earnings that were recognized over the trailing window
+    pub earnings: YoctoNear,
+
+    /// annualized earnings fee revenue using the current fees
+    pub current_owner_revenue: YoctoNear,
+    /// annualized earnings fee revenue using the proposed fees
+    pub projected_owner_revenue: YoctoNear,
+
+    /// annualized staker yield net of the current earnings fee
+    pub current_staker_apy: BasisPoints,
+    /// annualized staker yield net of the proposed earnings fee
+    pub projected_staker_apy: BasisPoints,
+    /// [`FeeChangePreview::projected_staker_apy`] - [`FeeChangePreview::current_staker_apy`]
+    pub staker_apy_delta: i32,
+}
+
+impl FeeChangePreview {
+    /// NEAR epochs are ~12 hours long
+    pub const EPOCHS_PER_YEAR: u64 = 730;
+
+    /// returns None if the snapshots do not span at least 1 epoch, i.e., there is not enough
+    /// earnings history to project from
+    pub(crate) fn new(
+        current_fees: Fees,
+        proposed_fees: Fees,
+        snapshots: &[StakingPoolBalancesSnapshot],
+    ) -> Option<Self> {
+        let (first, last) = (snapshots.first()?, snapshots.last()?);
+        let epochs = last.epoch_height.elapsed_since(first.epoch_height);
+        let total_staked = first.balances.total_staked;
+        if epochs == 0 || total_staked == YoctoNear::ZERO {
+            return None;
+        }
+        let earnings: YoctoNear = last
+            .balances
+            .earnings_sources
+            .total()
+            .saturating_sub(*first.balances.earnings_sources.total())
+            .into();
+
+        let annualize = |amount: YoctoNear| -> YoctoNear {
+            (U256::from(*amount) * U256::from(Self::EPOCHS_PER_YEAR) / U256::from(epochs))
+                .as_u128()
+                .into()
+        };
+        let staker_apy = |earnings_fee: BasisPoints| -> BasisPoints {
+            let staker_earnings = earnings - earnings_fee * earnings;
+            let apy = U256::from(*staker_earnings)
+                * U256::from(10000)
+                * U256::from(Self::EPOCHS_PER_YEAR)
+                / (U256::from(*total_staked) * U256::from(epochs));
+            BasisPoints(apy.min(U256::from(u16::MAX)).as_u32() as u16)
+        };
+
+        let current_staker_apy = staker_apy(current_fees.earnings_fee);
+        let projected_staker_apy = staker_apy(proposed_fees.earnings_fee);
+        Some(Self {
+            current_fees,
+            proposed_fees,
+            from_epoch: first.epoch_height,
+            to_epoch: last.epoch_height,
+            total_staked,
+            earnings,
+            current_owner_revenue: annualize(current_fees.earnings_fee * earnings),
+            projected_owner_revenue: annualize(proposed_fees.earnings_fee * earnings),
+            current_staker_apy,
+            projected_staker_apy,
+            staker_apy_delta: *projected_staker_apy as i32 - *current_staker_apy as i32,
+        })
+    }
+}
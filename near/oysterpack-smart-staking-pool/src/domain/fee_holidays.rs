@@ -0,0 +1,115 @@
+use oysterpack_smart_near::data::{ComponentStorageUsage, Object};
+use oysterpack_smart_near::domain::{BasisPoints, EpochHeight};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// Promotional window during which a reduced staking fee applies
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct FeeHoliday {
+    /// first epoch that the reduced staking fee applies
+    pub start_epoch: EpochHeight,
+    /// last epoch that the reduced staking fee applies, i.e., the window is inclusive
+    pub end_epoch: EpochHeight,
+    pub staking_fee: BasisPoints,
+}
+
+impl FeeHoliday {
+    pub fn is_active(&self, epoch: EpochHeight) -> bool {
+        self.start_epoch <= epoch && epoch <= self.end_epoch
+    }
+
+    pub fn is_expired(&self, epoch: EpochHeight) -> bool {
+        self.end_epoch < epoch
+    }
+
+    pub fn overlaps(&self, other: &FeeHoliday) -> bool {
+        self.start_epoch <= other.end_epoch && other.start_epoch <= self.end_epoch
+    }
+}
+
+/// Schedule of fee holidays, ordered by start epoch
+/// - expired fee holidays are pruned when the schedule is updated
+pub struct FeeHolidays;
+
+const FEE_HOLIDAYS_KEY: u128 = 1958589271036457128903364115890274611;
+
+type FeeHolidaysObject = Object<u128, Vec<FeeHoliday>>;
+
+impl FeeHolidays {
+    /// max number of fee holidays that can be scheduled at any given time
+    pub const MAX_SCHEDULED: usize = 10;
+
+    /// returns the scheduled fee holidays that have not yet expired, ordered by start epoch
+    pub fn scheduled() -> Vec<FeeHoliday> {
+        let current_epoch = EpochHeight::from_env();
+        Self::load()
+            .into_iter()
+            .filter(|holiday| !holiday.is_expired(current_epoch))
+            .collect()
+    }
+
+    /// returns the fee holiday that is active for the specified epoch
+    pub fn active(epoch: EpochHeight) -> Option<FeeHoliday> {
+        Self::load()
+            .into_iter()
+            .find(|holiday| holiday.is_active(epoch))
+    }
+
+    /// adds the fee holiday to the schedule and prunes expired fee holidays
+    ///
+    /// returns false if the fee holiday overlaps with a scheduled fee holiday or if the max number
+    /// of fee holidays are already scheduled
+    pub(crate) fn schedule(fee_holiday: FeeHoliday) -> bool {
+        let mut holidays = Self::scheduled();
+        if holidays.len() >= Self::MAX_SCHEDULED
+            || holidays
+                .iter()
+                .any(|holiday| holiday.overlaps(&fee_holiday))
+        {
+            return false;
+        }
+        holidays.push(fee_holiday);
+        holidays.sort_by_key(|holiday| holiday.start_epoch);
+        Self::save(holidays);
+        true
+    }
+
+    /// removes the fee holiday with the specified start epoch from the schedule
+    pub(crate) fn cancel(start_epoch: EpochHeight) -> Option<FeeHoliday> {
+        let mut holidays = Self::scheduled();
+        let index = holidays
+            .iter()
+            .position(|holiday| holiday.start_epoch == start_epoch)?;
+        let fee_holiday = holidays.remove(index);
+        Self::save(holidays);
+        Some(fee_holiday)
+    }
+
+    fn load() -> Vec<FeeHoliday> {
+        FeeHolidaysObject::load(&FEE_HOLIDAYS_KEY)
+            .map_or_else(Vec::new, |holidays| (*holidays).clone())
+    }
+
+    fn save(holidays: Vec<FeeHoliday>) {
+        ComponentStorageUsage::track(ComponentStorageUsage::STAKING, || {
+            if holidays.is_empty() {
+                FeeHolidaysObject::delete_by_key(&FEE_HOLIDAYS_KEY);
+            } else {
+                FeeHolidaysObject::new(FEE_HOLIDAYS_KEY, holidays).save();
+            }
+        });
+    }
+}
+
+/// log message format: `start_epoch={start_epoch}, end_epoch={end_epoch}, staking_fee={bps}`
+pub const LOG_EVENT_FEE_HOLIDAY_SCHEDULED: LogEvent =
+    LogEvent(Level::INFO, "FEE_HOLIDAY_SCHEDULED");
+/// log message format: `start_epoch={start_epoch}, end_epoch={end_epoch}, staking_fee={bps}`
+pub const LOG_EVENT_FEE_HOLIDAY_CANCELLED: LogEvent =
+    LogEvent(Level::INFO, "FEE_HOLIDAY_CANCELLED");
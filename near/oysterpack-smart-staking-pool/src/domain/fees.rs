@@ -1,7 +1,12 @@
 use oysterpack_smart_near::domain::BasisPoints;
-use oysterpack_smart_near::near_sdk::serde::{Deserialize, Serialize};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
 
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default,
+)]
 #[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
 pub struct Fees {
     /// fee charged when staking funds
@@ -0,0 +1,176 @@
+use crate::PendingStakeActions;
+use oysterpack_smart_near::data::{numbers::U64, ComponentStorageUsage, Object};
+use oysterpack_smart_near::domain::{BlockHeight, YoctoNear};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::{CallbackGuard, Level, LogEvent};
+
+/// Stake action finalize callbacks - see [`crate::StakeActionCallbacks`]
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub enum FinalizeCallback {
+    /// `ops_stake_finalize`
+    Stake,
+    /// `ops_stake_start_finalize`
+    Start,
+    /// `ops_stake_stop_finalize`
+    Stop,
+}
+
+impl FinalizeCallback {
+    pub fn method_name(&self) -> &'static str {
+        match self {
+            FinalizeCallback::Stake => "ops_stake_finalize",
+            FinalizeCallback::Start => "ops_stake_start_finalize",
+            FinalizeCallback::Stop => "ops_stake_stop_finalize",
+        }
+    }
+}
+
+/// Finalize callback that was scheduled for a stake action, but has not yet run
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct FinalizeIntent {
+    /// idempotency key - each intent is finalized at most once, either by the callback or by
+    /// replaying it
+    pub id: U64,
+    pub callback: FinalizeCallback,
+    /// account that triggered the stake action
+    pub account_id: AccountId,
+    /// the stake operation that triggered the stake action
+    pub operation_id: Option<U64>,
+    /// the locked balance that was expected at the time the stake action was submitted
+    /// - informational only, i.e., later stake actions and earnings change the locked balance, thus
+    ///   it cannot be used to infer the outcome when the intent is replayed
+    pub expected_locked_balance: YoctoNear,
+    pub submitted_at: BlockHeight,
+}
+
+impl FinalizeIntent {
+    /// returns true if the callback is assumed to have been lost - see
+    /// [`PendingStakeActions::EXPIRY_BLOCKS`]
+    pub fn retryable(&self) -> bool {
+        BlockHeight::from_env() >= self.submitted_at + PendingStakeActions::EXPIRY_BLOCKS
+    }
+}
+
+/// Retry queue for stake action finalize callbacks
+/// - an intent is recorded when the stake action is submitted, and is removed when the finalize
+///   callback runs - if the callback fails, e.g., it runs out of gas, then its state changes are
+///   rolled back and the intent remains in the queue
+/// - intents that have not been finalized within [`PendingStakeActions::EXPIRY_BLOCKS`] can be
+///   replayed via [`crate::StakingPool::ops_stake_retry_finalize`]
+/// - the intent ID is passed to the finalize callback, which finalizes its own intent
+/// - the queue is bounded to [`FinalizeRetryQueue::MAX_ENTRIES`] intents - once full, the oldest
+///   intent is dropped and its callback nonce is released, i.e., the dropped intent can no longer
+///   be replayed, and its callback will not be authorized to run if it is later invoked
+pub struct FinalizeRetryQueue;
+
+const FINALIZE_RETRY_QUEUE_KEY: u128 = 1958826391057418263950174826350184739;
+const FINALIZE_INTENT_COUNT_KEY: u128 = 1958826391057418263950174826350184740;
+
+type FinalizeRetryQueueObject = Object<u128, Vec<FinalizeIntent>>;
+type FinalizeIntentCountObject = Object<u128, u64>;
+
+impl FinalizeRetryQueue {
+    pub const MAX_ENTRIES: usize = 100;
+
+    /// max number of intents that are replayed per call, which bounds the gas used
+    pub const MAX_RETRIES_BATCH: usize = 10;
+
+    /// returns the intents ordered from oldest to newest
+    pub fn intents() -> Vec<FinalizeIntent> {
+        FinalizeRetryQueueObject::load(&FINALIZE_RETRY_QUEUE_KEY)
+            .map_or_else(Vec::new, |intents| (*intents).clone())
+    }
+
+    /// assigns the next intent ID and appends the intent to the queue
+    ///
+    /// returns the intent ID, which must be passed to the finalize callback
+    pub(crate) fn record(
+        callback: FinalizeCallback,
+        account_id: &str,
+        operation_id: Option<U64>,
+        expected_locked_balance: YoctoNear,
+    ) -> U64 {
+        let id =
+            FinalizeIntentCountObject::load(&FINALIZE_INTENT_COUNT_KEY).map_or(0, |count| *count);
+        let mut intents = Self::intents();
+        if intents.len() >= Self::MAX_ENTRIES {
+            let dropped = intents.remove(0);
+            CallbackGuard::release(dropped.callback.method_name());
+            LOG_EVENT_FINALIZE_INTENT_DROPPED.log(format!(
+                "id={}, callback={}",
+                dropped.id,
+                dropped.callback.method_name()
+            ));
+        }
+        intents.push(FinalizeIntent {
+            id: id.into(),
+            callback,
+            account_id: account_id.to_string(),
+            operation_id,
+            expected_locked_balance,
+            submitted_at: BlockHeight::from_env(),
+        });
+        ComponentStorageUsage::track(ComponentStorageUsage::STAKING, || {
+            FinalizeIntentCountObject::new(FINALIZE_INTENT_COUNT_KEY, id + 1).save();
+        });
+        Self::save(intents);
+        id.into()
+    }
+
+    /// removes the intent - must be called by the finalize callback
+    /// - no-op if the intent was dropped because the queue was full
+    pub(crate) fn finalized(id: U64) {
+        let mut intents = Self::intents();
+        if let Some(index) = intents.iter().position(|intent| intent.id == id) {
+            intents.remove(index);
+            Self::save(intents);
+        }
+    }
+
+    /// removes and returns up to [`FinalizeRetryQueue::MAX_RETRIES_BATCH`] retryable intents
+    pub(crate) fn take_retryable() -> Vec<FinalizeIntent> {
+        let (mut retryable, pending): (Vec<_>, Vec<_>) = Self::intents()
+            .into_iter()
+            .partition(FinalizeIntent::retryable);
+        let mut remaining = retryable.split_off(retryable.len().min(Self::MAX_RETRIES_BATCH));
+        if retryable.is_empty() {
+            return retryable;
+        }
+        remaining.extend(pending);
+        remaining.sort_by_key(|intent| intent.id.0);
+        Self::save(remaining);
+        retryable
+    }
+
+    fn save(intents: Vec<FinalizeIntent>) {
+        ComponentStorageUsage::track(ComponentStorageUsage::STAKING, || {
+            if intents.is_empty() {
+                FinalizeRetryQueueObject::delete_by_key(&FINALIZE_RETRY_QUEUE_KEY);
+            } else {
+                FinalizeRetryQueueObject::new(FINALIZE_RETRY_QUEUE_KEY, intents).save();
+            }
+        });
+    }
+}
+
+/// logged when a lost finalize callback is replayed
+///
+/// log message format: `id={id}, callback={callback}, account_id={account_id}`
+pub const LOG_EVENT_FINALIZE_RETRY: LogEvent = LogEvent(Level::WARN, "FINALIZE_RETRY");
+/// logged when the locked balance is resynced after lost finalize callbacks are replayed
+///
+/// log message format: `locked_balance={locked_balance}, total_staked_balance={total_staked_balance}, action={none|stake|unstake}`
+pub const LOG_EVENT_FINALIZE_RESYNC: LogEvent = LogEvent(Level::WARN, "FINALIZE_RESYNC");
+/// logged when the retry queue is full and the oldest intent is dropped
+///
+/// log message format: `id={id}, callback={callback}`
+pub const LOG_EVENT_FINALIZE_INTENT_DROPPED: LogEvent =
+    LogEvent(Level::WARN, "FINALIZE_INTENT_DROPPED");
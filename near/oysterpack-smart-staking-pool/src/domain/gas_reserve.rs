@@ -0,0 +1,96 @@
+use oysterpack_smart_contract::{BalanceId, ContractNearBalances};
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::{BasisPoints, YoctoNear};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+/// Operator configured gas reserve, which diverts a slice of the staking pool earnings into a
+/// dedicated contract NEAR balance until the target balance is reached
+/// - the reserve is funded out of the stakers' share of the earnings, i.e., the owner earnings fee
+///   is not affected
+/// - the reserve is tracked as a contract NEAR balance - see [`GasReserve::BALANCE_ID`] - which
+///   excludes it from the owner's available balance, i.e., the funds stay on the contract account
+///   to pay for callback execution
+/// - once the target balance is reached, earnings are no longer diverted
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct GasReserve {
+    /// percentage of the earnings that is diverted into the gas reserve
+    pub fee: BasisPoints,
+    /// gas reserve balance target
+    pub target: YoctoNear,
+}
+
+const GAS_RESERVE_KEY: u128 = 1958163410927346109582716345098127364;
+type GasReserveObject = Object<u128, GasReserve>;
+
+impl GasReserve {
+    /// used to track the gas reserve on the contract NEAR balance
+    pub const BALANCE_ID: BalanceId = BalanceId(1958163427519043680419745110263798021);
+
+    /// 1%
+    pub const MAX_FEE: BasisPoints = BasisPoints(100);
+
+    pub fn load() -> Option<Self> {
+        GasReserveObject::load(&GAS_RESERVE_KEY).map(|reserve| *reserve)
+    }
+
+    pub(crate) fn save(&self) {
+        GasReserveObject::new(GAS_RESERVE_KEY, *self).save();
+    }
+
+    /// the gas reserve balance is retained when the config is cleared
+    pub(crate) fn clear() {
+        GasReserveObject::delete_by_key(&GAS_RESERVE_KEY);
+    }
+
+    pub fn balance() -> YoctoNear {
+        ContractNearBalances::near_balance(Self::BALANCE_ID)
+    }
+
+    pub(crate) fn incr_balance(amount: YoctoNear) -> YoctoNear {
+        ContractNearBalances::incr_balance(Self::BALANCE_ID, amount)
+    }
+
+    /// computes the amount of the specified earnings that is diverted into the gas reserve
+    /// - the allocation is capped by the amount that is still needed to reach the target balance
+    pub fn allocation(&self, earnings: YoctoNear) -> YoctoNear {
+        let shortfall: YoctoNear = self.target.saturating_sub(*Self::balance()).into();
+        (self.fee * earnings).min(shortfall)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near::YOCTO;
+    use oysterpack_smart_near_test::*;
+
+    #[test]
+    fn allocation() {
+        TestCtx::new("bob").apply();
+
+        let reserve = GasReserve {
+            fee: 100.into(),
+            target: (50 * YOCTO).into(),
+        };
+        assert_eq!(
+            reserve.allocation((1000 * YOCTO).into()),
+            (10 * YOCTO).into()
+        );
+
+        // allocation is capped by the target
+        GasReserve::incr_balance((45 * YOCTO).into());
+        assert_eq!(
+            reserve.allocation((1000 * YOCTO).into()),
+            (5 * YOCTO).into()
+        );
+
+        GasReserve::incr_balance((5 * YOCTO).into());
+        assert_eq!(reserve.allocation((1000 * YOCTO).into()), YoctoNear::ZERO);
+    }
+}
@@ -0,0 +1,185 @@
+use oysterpack_smart_account_management::AccountStorageEvent;
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::{numbers::U64, Object};
+use oysterpack_smart_near::domain::BlockHeight;
+use oysterpack_smart_near::eventbus::post;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+};
+use oysterpack_smart_near::Hash;
+
+/// Block height range, inclusive, during which votes can be cast on the proposal
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct VotingWindow {
+    pub starts_at: BlockHeight,
+    pub ends_at: BlockHeight,
+}
+
+impl VotingWindow {
+    pub fn status(&self) -> GovProposalStatus {
+        let block_height = BlockHeight::from_env();
+        if block_height < self.starts_at {
+            GovProposalStatus::Pending
+        } else if block_height <= self.ends_at {
+            GovProposalStatus::Active
+        } else {
+            GovProposalStatus::Closed
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub enum GovProposalStatus {
+    /// voting window has not started yet
+    Pending,
+    /// votes can be cast
+    Active,
+    /// voting window has ended
+    Closed,
+}
+
+/// Governance signaling proposal that STAKE holders vote on
+/// - the proposal text is published off-chain - the proposal only stores the text hash, which makes
+///   the text tamper-evident
+/// - votes are weighted by the voter's STAKE balance at the time the proposal was created, which
+///   prevents STAKE from being moved between accounts to vote multiple times
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct GovProposal {
+    pub id: U64,
+    /// sha256 hash of the off-chain proposal text
+    pub text_hash: Hash,
+    pub options: Vec<String>,
+    pub voting_window: VotingWindow,
+    /// STAKE balance snapshot that votes are weighted by
+    pub snapshot_id: U64,
+    /// STAKE total supply at the time the proposal was created
+    pub stake_supply: TokenAmount,
+    pub created_at: BlockHeight,
+    /// STAKE vote weight tally per option - indexed by option
+    pub tallies: Vec<TokenAmount>,
+    /// number of accounts that have voted
+    pub voters: u32,
+}
+
+impl GovProposal {
+    pub const MIN_OPTIONS: usize = 2;
+    pub const MAX_OPTIONS: usize = 10;
+
+    pub fn load(id: U64) -> Option<Self> {
+        GovProposalObject::load(&Self::key(id)).map(|proposal| (*proposal).clone())
+    }
+
+    pub(crate) fn save(&self) {
+        GovProposalObject::new(Self::key(self.id), self.clone()).save();
+    }
+
+    /// returns the next proposal ID
+    pub(crate) fn next_id() -> U64 {
+        let id = GovProposalIdObject::load(&GOV_PROPOSAL_ID_KEY).map_or(0, |id| *id) + 1;
+        GovProposalIdObject::new(GOV_PROPOSAL_ID_KEY, id).save();
+        id.into()
+    }
+
+    pub fn total_votes(&self) -> TokenAmount {
+        self.tallies
+            .iter()
+            .fold(TokenAmount::ZERO, |total, tally| total + *tally)
+    }
+
+    /// returns the option with the most votes
+    /// - returns None if no votes have been cast or if there is a tie
+    pub fn leading_option(&self) -> Option<u8> {
+        let max = self.tallies.iter().max().cloned()?;
+        if max == TokenAmount::ZERO
+            || self.tallies.iter().filter(|tally| **tally == max).count() > 1
+        {
+            return None;
+        }
+        self.tallies
+            .iter()
+            .position(|tally| *tally == max)
+            .map(|option| option as u8)
+    }
+
+    fn key(id: U64) -> u128 {
+        GOV_PROPOSAL_KEY + *id as u128
+    }
+}
+
+/// Proposal along with its current voting status
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct GovProposalResult {
+    pub proposal: GovProposal,
+    pub status: GovProposalStatus,
+    pub total_votes: TokenAmount,
+    /// option with the most votes - None if no votes have been cast or if there is a tie
+    pub leading_option: Option<u8>,
+}
+
+impl From<GovProposal> for GovProposalResult {
+    fn from(proposal: GovProposal) -> Self {
+        Self {
+            status: proposal.voting_window.status(),
+            total_votes: proposal.total_votes(),
+            leading_option: proposal.leading_option(),
+            proposal,
+        }
+    }
+}
+
+/// Vote cast by an account on a proposal
+/// - an account can change its vote while the voting window is active
+///
+/// The vote storage usage is charged to the voter account.
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct GovVote {
+    pub option: u8,
+    pub weight: TokenAmount,
+}
+
+const GOV_PROPOSAL_ID_KEY: u128 = 1958061735493368101542306158213405321;
+/// the proposal storage key is computed as `GOV_PROPOSAL_KEY + proposal ID`
+const GOV_PROPOSAL_KEY: u128 = 1958061768902519236180454003017712440;
+const GOV_VOTE_KEY: u128 = 1958061794217835542207063598719360653;
+
+type GovProposalIdObject = Object<u128, u64>;
+type GovProposalObject = Object<u128, GovProposal>;
+type GovVoteObject = Object<Hash, GovVote>;
+
+impl GovVote {
+    fn key(proposal_id: U64, account_id: &str) -> Hash {
+        Hash::from((
+            format!("{}:{}", account_id, *proposal_id).as_str(),
+            GOV_VOTE_KEY,
+        ))
+    }
+
+    pub fn load(proposal_id: U64, account_id: &str) -> Option<Self> {
+        GovVoteObject::load(&Self::key(proposal_id, account_id)).map(|vote| *vote)
+    }
+
+    /// tracks storage usage against the voter account - emits
+    /// [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn save(&self, proposal_id: U64, account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        GovVoteObject::new(Self::key(proposal_id, account_id), *self).save();
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+}
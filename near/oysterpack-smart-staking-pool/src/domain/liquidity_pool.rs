@@ -0,0 +1,278 @@
+use crate::UnstakedBalances;
+use oysterpack_smart_account_management::AccountStorageEvent;
+use oysterpack_smart_contract::{BalanceId, ContractNearBalances};
+use oysterpack_smart_near::data::{numbers::U256, Object};
+use oysterpack_smart_near::domain::{BasisPoints, YoctoNear};
+use oysterpack_smart_near::eventbus::post;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    json_types::U128,
+    serde::{Deserialize, Serialize},
+};
+use oysterpack_smart_near::Hash;
+
+/// STAKE/NEAR instant-swap liquidity pool
+///
+/// Liquidity providers deposit NEAR into the pool in exchange for pool shares. Stakers can swap
+/// STAKE for NEAR against the pool, which bypasses the unbonding delay. The swapped STAKE is unstaked
+/// on behalf of the pool, and once the unstaked NEAR unlocks it is returned to the pool's NEAR reserve.
+///
+/// The pool is priced as a constant-product market:
+/// - the NEAR reserve is the liquid NEAR that is available to pay out swaps
+/// - the unstaked reserve is the NEAR value of the STAKE that was swapped into the pool, which is
+///   still locked awaiting unbonding
+///
+/// Swaps are charged a fee, which is retained by the pool, i.e., it is earned by the liquidity providers.
+pub struct LiquidityPool;
+
+/// tracks the unstaked NEAR that is owned by the pool and the total pool shares
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct LiquidityPoolState {
+    /// unstaked NEAR from STAKE that was swapped into the pool
+    pub unstaked_balances: UnstakedBalances,
+    pub total_shares: u128,
+}
+
+/// Liquidity pool balances
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct LiquidityPoolBalances {
+    /// liquid NEAR that is available to pay out swaps and liquidity withdrawals
+    pub near_reserve: YoctoNear,
+    /// NEAR value of the STAKE that was swapped into the pool, which is still locked awaiting unbonding
+    pub unstaked_reserve: YoctoNear,
+    pub total_shares: U128,
+    /// fee that is charged on swaps
+    pub fee: BasisPoints,
+}
+
+impl LiquidityPoolBalances {
+    /// total NEAR value owned by the liquidity providers
+    pub fn total(&self) -> YoctoNear {
+        self.near_reserve + self.unstaked_reserve
+    }
+}
+
+/// Liquidity provider's share of the pool
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct LiquidityPoolAccountBalance {
+    pub shares: U128,
+    /// current NEAR value of the shares
+    pub near_value: YoctoNear,
+}
+
+const LIQUIDITY_POOL_STATE_KEY: u128 = 1957712406310926744318569921547037491;
+const LIQUIDITY_POOL_SHARES_KEY: u128 = 1957712427448063297119452876101936744;
+const LIQUIDITY_POOL_FEE_KEY: u128 = 1957712448218395110274862310449871925;
+
+type LiquidityPoolStateObject = Object<u128, LiquidityPoolState>;
+type LiquidityPoolSharesObject = Object<Hash, u128>;
+type LiquidityPoolFeeObject = Object<u128, BasisPoints>;
+
+impl LiquidityPool {
+    /// liquid NEAR that is owned by the liquidity providers
+    /// - tracked as a contract NEAR balance, which excludes it from the owner's available balance
+    pub const NEAR_RESERVE: BalanceId = BalanceId(1957712385601849317504229861374620183);
+
+    /// key for the pool's [`crate::LiquidityClaim`] on the unstaked NEAR that it owns
+    pub const LIQUIDITY_CLAIM_ID: &'static str = "liquidity_pool";
+
+    /// 0.3%
+    pub const DEFAULT_FEE: BasisPoints = BasisPoints(30);
+    /// 10%
+    pub const MAX_FEE: BasisPoints = BasisPoints(1000);
+
+    pub fn near_reserve() -> YoctoNear {
+        ContractNearBalances::near_balance(Self::NEAR_RESERVE)
+    }
+
+    pub(crate) fn incr_near_reserve(amount: YoctoNear) -> YoctoNear {
+        ContractNearBalances::incr_balance(Self::NEAR_RESERVE, amount)
+    }
+
+    pub(crate) fn decr_near_reserve(amount: YoctoNear) -> YoctoNear {
+        ContractNearBalances::decr_balance(Self::NEAR_RESERVE, amount)
+    }
+
+    pub fn state() -> LiquidityPoolState {
+        LiquidityPoolStateObject::load(&LIQUIDITY_POOL_STATE_KEY)
+            .map_or_else(LiquidityPoolState::default, |state| *state)
+    }
+
+    pub(crate) fn save_state(state: LiquidityPoolState) {
+        LiquidityPoolStateObject::new(LIQUIDITY_POOL_STATE_KEY, state).save();
+    }
+
+    /// fee that is charged on swaps
+    pub fn fee() -> BasisPoints {
+        LiquidityPoolFeeObject::load(&LIQUIDITY_POOL_FEE_KEY).map_or(Self::DEFAULT_FEE, |fee| *fee)
+    }
+
+    pub(crate) fn set_fee(fee: BasisPoints) {
+        LiquidityPoolFeeObject::new(LIQUIDITY_POOL_FEE_KEY, fee).save();
+    }
+
+    pub fn balances() -> LiquidityPoolBalances {
+        let state = Self::state();
+        LiquidityPoolBalances {
+            near_reserve: Self::near_reserve(),
+            unstaked_reserve: state.unstaked_balances.total(),
+            total_shares: state.total_shares.into(),
+            fee: Self::fee(),
+        }
+    }
+
+    fn shares_key(account_id: &str) -> Hash {
+        Hash::from((account_id, LIQUIDITY_POOL_SHARES_KEY))
+    }
+
+    pub fn shares(account_id: &str) -> u128 {
+        LiquidityPoolSharesObject::load(&Self::shares_key(account_id)).map_or(0, |shares| *shares)
+    }
+
+    /// if shares are zero, then the record is deleted
+    /// - tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn set_shares(account_id: &str, shares: u128) {
+        let initial_storage_usage = env::storage_usage();
+        if shares == 0 {
+            LiquidityPoolSharesObject::delete_by_key(&Self::shares_key(account_id));
+        } else {
+            LiquidityPoolSharesObject::new(Self::shares_key(account_id), shares).save();
+        }
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+
+    /// computes the number of shares that are issued for the NEAR deposit
+    /// - the first deposit is issued shares 1:1
+    pub fn deposit_shares(pool_value: YoctoNear, total_shares: u128, deposit: YoctoNear) -> u128 {
+        if total_shares == 0 || pool_value == YoctoNear::ZERO {
+            return *deposit;
+        }
+        (U256::from(*deposit) * U256::from(total_shares) / U256::from(*pool_value)).as_u128()
+    }
+
+    /// computes the NEAR value of the shares, rounded down
+    pub fn shares_value(pool_value: YoctoNear, total_shares: u128, shares: u128) -> YoctoNear {
+        if total_shares == 0 {
+            return YoctoNear::ZERO;
+        }
+        (U256::from(*pool_value) * U256::from(shares) / U256::from(total_shares))
+            .as_u128()
+            .into()
+    }
+
+    /// computes the amount of NEAR that is paid out for swapping STAKE with the specified NEAR value
+    ///
+    /// `amount_out = near_reserve * amount_in / (near_reserve + unstaked_reserve + amount_in)`
+    /// - where `amount_in` is the STAKE NEAR value minus the swap fee
+    /// - the pool's total value is used as the virtual STAKE reserve, which means the price gets worse
+    ///   as more of the pool's value becomes locked in unstaked NEAR
+    pub fn swap_output(
+        near_reserve: YoctoNear,
+        unstaked_reserve: YoctoNear,
+        stake_near_value: YoctoNear,
+        fee: BasisPoints,
+    ) -> YoctoNear {
+        let amount_in = stake_near_value - fee * stake_near_value;
+        if amount_in == YoctoNear::ZERO || near_reserve == YoctoNear::ZERO {
+            return YoctoNear::ZERO;
+        }
+        let denominator =
+            U256::from(*near_reserve) + U256::from(*unstaked_reserve) + U256::from(*amount_in);
+        (U256::from(*near_reserve) * U256::from(*amount_in) / denominator)
+            .as_u128()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near::YOCTO;
+
+    #[test]
+    fn swap_output() {
+        let near_reserve: YoctoNear = (1000 * YOCTO).into();
+
+        // zero fee against an empty unstaked reserve
+        let amount_out = LiquidityPool::swap_output(
+            near_reserve,
+            YoctoNear::ZERO,
+            YOCTO.into(),
+            BasisPoints::ZERO,
+        );
+        assert_eq!(*amount_out, 1000 * YOCTO / 1001);
+
+        // the fee is deducted from the input
+        let amount_out_with_fee = LiquidityPool::swap_output(
+            near_reserve,
+            YoctoNear::ZERO,
+            YOCTO.into(),
+            LiquidityPool::DEFAULT_FEE,
+        );
+        assert!(amount_out_with_fee < amount_out);
+        let amount_in = YOCTO - YOCTO * 30 / 10000;
+        assert_eq!(
+            *amount_out_with_fee,
+            (U256::from(1000 * YOCTO) * U256::from(amount_in)
+                / U256::from(1000 * YOCTO + amount_in))
+            .as_u128()
+        );
+
+        // locked unstaked reserve makes the price worse
+        let amount_out_with_unstaked_reserve =
+            LiquidityPool::swap_output(near_reserve, near_reserve, YOCTO.into(), BasisPoints::ZERO);
+        assert!(amount_out_with_unstaked_reserve < amount_out);
+
+        // swap can never drain the NEAR reserve
+        let amount_out = LiquidityPool::swap_output(
+            near_reserve,
+            YoctoNear::ZERO,
+            (1_000_000 * YOCTO).into(),
+            BasisPoints::ZERO,
+        );
+        assert!(amount_out < near_reserve);
+
+        // empty pool pays out nothing
+        assert_eq!(
+            LiquidityPool::swap_output(
+                YoctoNear::ZERO,
+                YoctoNear::ZERO,
+                YOCTO.into(),
+                BasisPoints::ZERO
+            ),
+            YoctoNear::ZERO
+        );
+    }
+
+    #[test]
+    fn deposit_shares_and_value() {
+        // first deposit is issued shares 1:1
+        assert_eq!(
+            LiquidityPool::deposit_shares(YoctoNear::ZERO, 0, YOCTO.into()),
+            YOCTO
+        );
+
+        // pool value grew by 10% from swap fees
+        let pool_value: YoctoNear = (11 * YOCTO).into();
+        let total_shares = 10 * YOCTO;
+        let shares = LiquidityPool::deposit_shares(pool_value, total_shares, (11 * YOCTO).into());
+        assert_eq!(shares, total_shares);
+        assert_eq!(
+            LiquidityPool::shares_value(pool_value, total_shares, total_shares),
+            pool_value
+        );
+        assert_eq!(
+            LiquidityPool::shares_value(pool_value, 0, total_shares),
+            YoctoNear::ZERO
+        );
+    }
+}
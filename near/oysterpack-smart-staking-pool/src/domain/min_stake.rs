@@ -0,0 +1,28 @@
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::YoctoNear;
+
+/// Minimum NEAR deposit that can be staked
+/// - applies to every path that stakes an attached deposit, i.e., [`crate::StakingPool::ops_stake`],
+///   `storage_deposit` with `stake=true`, [`crate::StakeOnTransfer::stake_on_transfer`], and
+///   [`crate::StakeReferrals::ops_stake_with_referrer`]
+/// - zero by default, i.e., there is no minimum
+/// - the minimum only applies to attached deposits - staking the account's storage available
+///   balance is not subject to the minimum
+pub struct MinStake;
+
+const MIN_STAKE_KEY: u128 = 1958617904452179126655480237193562710;
+type MinStakeObject = Object<u128, YoctoNear>;
+
+impl MinStake {
+    pub fn amount() -> YoctoNear {
+        MinStakeObject::load(&MIN_STAKE_KEY).map_or(YoctoNear::ZERO, |amount| *amount)
+    }
+
+    pub(crate) fn set(amount: YoctoNear) {
+        if amount == YoctoNear::ZERO {
+            MinStakeObject::delete_by_key(&MIN_STAKE_KEY);
+        } else {
+            MinStakeObject::new(MIN_STAKE_KEY, amount).save();
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+use std::fmt::{self, Display, Formatter};
+
+/// Specifies how the owner's earnings fee is paid out
+#[derive(
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub enum OwnerEarningsMode {
+    /// earnings fee is minted as STAKE on the owner's account, i.e., the owner's earnings are restaked
+    #[default]
+    Stake,
+    /// earnings fee is credited to the owner's unstaked balance, which the owner can withdraw as
+    /// liquid NEAR once it is unlocked
+    Near,
+}
+
+impl Display for OwnerEarningsMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+const OWNER_EARNINGS_MODE_KEY: u128 = 1957827403186250965478732018430976139;
+type OwnerEarningsModeObject = Object<u128, OwnerEarningsMode>;
+
+impl OwnerEarningsMode {
+    /// defaults to [`OwnerEarningsMode::Stake`]
+    pub fn load() -> Self {
+        OwnerEarningsModeObject::load(&OWNER_EARNINGS_MODE_KEY)
+            .map_or_else(Self::default, |mode| *mode)
+    }
+
+    pub(crate) fn save(self) {
+        OwnerEarningsModeObject::new(OWNER_EARNINGS_MODE_KEY, self).save();
+    }
+}
@@ -0,0 +1,71 @@
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::YoctoNear;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+/// Lifetime totals for the revenue that the pool ownership collected
+/// - NEAR values are recorded at the time of collection, i.e., using the STAKE token value at
+///   the time
+#[derive(
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct OwnerRevenue {
+    /// staking fees minted on the owner's account, i.e., net of referral fees
+    pub staking_fees: TokenAmount,
+    pub staking_fees_near_value: YoctoNear,
+    /// earnings fees paid out to the earnings fee recipients - see [`crate::EarningsFeeSplit`]
+    pub earnings_fees: YoctoNear,
+    /// earnings fees that were minted as STAKE, i.e., paid out in [`crate::OwnerEarningsMode::Stake`]
+    pub earnings_fees_minted: TokenAmount,
+    /// STAKE transferred from the treasury to the owner via
+    /// [`crate::Treasury::ops_stake_treasury_transfer_to_owner`]
+    pub treasury_transfers: TokenAmount,
+    pub treasury_transfers_near_value: YoctoNear,
+}
+
+const OWNER_REVENUE_KEY: u128 = 1958150837461927350118294738015629471;
+type OwnerRevenueObject = Object<u128, OwnerRevenue>;
+
+impl OwnerRevenue {
+    pub fn load() -> Self {
+        OwnerRevenueObject::load(&OWNER_REVENUE_KEY).map_or_else(Self::default, |revenue| *revenue)
+    }
+
+    fn save(self) {
+        OwnerRevenueObject::new(OWNER_REVENUE_KEY, self).save();
+    }
+
+    pub(crate) fn record_staking_fee(stake: TokenAmount, near_value: YoctoNear) {
+        let mut revenue = Self::load();
+        revenue.staking_fees += stake;
+        revenue.staking_fees_near_value += near_value;
+        revenue.save();
+    }
+
+    pub(crate) fn record_earnings_fee(near: YoctoNear, minted: TokenAmount) {
+        let mut revenue = Self::load();
+        revenue.earnings_fees += near;
+        revenue.earnings_fees_minted += minted;
+        revenue.save();
+    }
+
+    pub(crate) fn record_treasury_transfer(stake: TokenAmount, near_value: YoctoNear) {
+        let mut revenue = Self::load();
+        revenue.treasury_transfers += stake;
+        revenue.treasury_transfers_near_value += near_value;
+        revenue.save();
+    }
+}
@@ -0,0 +1,232 @@
+use oysterpack_smart_account_management::AccountStorageEvent;
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::EpochHeight;
+use oysterpack_smart_near::eventbus::post;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::Hash;
+
+/// Request to automatically withdraw the account's unstaked NEAR once it unlocks
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct PendingWithdrawal {
+    pub account_id: AccountId,
+    /// the withdrawal is processed once the current epoch height reaches this epoch
+    pub available_on_epoch: EpochHeight,
+}
+
+/// FIFO queue of pending withdrawals
+/// - because unstaked NEAR is locked for a fixed number of epochs, withdrawals are enqueued in
+///   `available_on_epoch` order, which means the queue can be processed from the head until a
+///   withdrawal is reached that is not yet due
+/// - each account can have at most 1 pending withdrawal - if the account requests another withdrawal,
+///   then its previous request is superseded and deleted
+/// - superseded and cancelled withdrawals leave empty queue slots behind, which are skipped when
+///   they reach the head of the queue
+///
+/// The pending withdrawal storage usage is charged to the account.
+pub struct PendingWithdrawals;
+
+/// tracks the queue's head and tail indices
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Default)]
+struct PendingWithdrawalQueue {
+    /// index of the next withdrawal to process
+    head: u64,
+    /// index where the next withdrawal will be enqueued
+    tail: u64,
+}
+
+const PENDING_WITHDRAWAL_QUEUE_KEY: u128 = 1957610276094138569011429531713830127;
+/// the pending withdrawal storage key is computed as `PENDING_WITHDRAWAL_KEY + index`
+const PENDING_WITHDRAWAL_KEY: u128 = 1957610306632745180837036946004183766;
+const ACCOUNT_PENDING_WITHDRAWAL_KEY: u128 = 1957610333152318950427217064395102313;
+
+type PendingWithdrawalQueueObject = Object<u128, PendingWithdrawalQueue>;
+type PendingWithdrawalObject = Object<u128, PendingWithdrawal>;
+/// maps the account to its pending withdrawal queue index
+type AccountPendingWithdrawalObject = Object<Hash, u64>;
+
+impl PendingWithdrawals {
+    /// enqueues the withdrawal, replacing any pending withdrawal for the account
+    /// - tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub fn enqueue(account_id: &str, available_on_epoch: EpochHeight) {
+        let initial_storage_usage = env::storage_usage();
+
+        // the account's previous pending withdrawal is superseded
+        Self::delete_account_withdrawal(account_id);
+        let mut queue = Self::queue();
+        PendingWithdrawalObject::new(
+            Self::withdrawal_key(queue.tail),
+            PendingWithdrawal {
+                account_id: account_id.to_string(),
+                available_on_epoch,
+            },
+        )
+        .save();
+        AccountPendingWithdrawalObject::new(Self::account_key(account_id), queue.tail).save();
+        queue.tail += 1;
+        PendingWithdrawalQueueObject::new(PENDING_WITHDRAWAL_QUEUE_KEY, queue).save();
+
+        Self::track_storage_usage(account_id, initial_storage_usage);
+    }
+
+    /// returns the account's pending withdrawal
+    pub fn get(account_id: &str) -> Option<PendingWithdrawal> {
+        AccountPendingWithdrawalObject::load(&Self::account_key(account_id)).and_then(|index| {
+            PendingWithdrawalObject::load(&Self::withdrawal_key(*index))
+                .map(|withdrawal| (*withdrawal).clone())
+        })
+    }
+
+    /// returns the number of queue slots between the head and the tail of the queue, which includes
+    /// the empty slots left behind by superseded and cancelled withdrawals that have not yet reached
+    /// the head of the queue
+    pub fn len() -> u64 {
+        let queue = Self::queue();
+        queue.tail - queue.head
+    }
+
+    /// Dequeues the next withdrawal that is due, i.e., the withdrawal's `available_on_epoch` has been
+    /// reached. Superseded withdrawals are cleaned up along the way.
+    ///
+    /// Returns None if the queue is empty or the withdrawal at the head of the queue is not yet due.
+    pub fn dequeue_due(current_epoch: EpochHeight) -> Option<PendingWithdrawal> {
+        let mut queue = Self::queue();
+        let initial_head = queue.head;
+        let mut result = None;
+        while queue.head < queue.tail {
+            let withdrawal_key = Self::withdrawal_key(queue.head);
+            let withdrawal = match PendingWithdrawalObject::load(&withdrawal_key) {
+                Some(withdrawal) => (*withdrawal).clone(),
+                None => {
+                    queue.head += 1;
+                    continue;
+                }
+            };
+            let account_key = Self::account_key(&withdrawal.account_id);
+            let account_index = AccountPendingWithdrawalObject::load(&account_key);
+            let superseded = !matches!(account_index, Some(index) if *index == queue.head);
+            if !superseded && withdrawal.available_on_epoch > current_epoch {
+                break;
+            }
+
+            let initial_storage_usage = env::storage_usage();
+            PendingWithdrawalObject::delete_by_key(&withdrawal_key);
+            if !superseded {
+                AccountPendingWithdrawalObject::delete_by_key(&account_key);
+            }
+            Self::track_storage_usage(&withdrawal.account_id, initial_storage_usage);
+            queue.head += 1;
+
+            if !superseded {
+                result = Some(withdrawal);
+                break;
+            }
+        }
+        if queue.head != initial_head {
+            PendingWithdrawalQueueObject::new(PENDING_WITHDRAWAL_QUEUE_KEY, queue).save();
+        }
+        result
+    }
+
+    /// cancels the account's pending withdrawal, i.e., the queued withdrawal is deleted
+    /// - tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub fn cancel(account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        Self::delete_account_withdrawal(account_id);
+        Self::track_storage_usage(account_id, initial_storage_usage);
+    }
+
+    /// deletes the account's queued withdrawal along with the account's queue index
+    fn delete_account_withdrawal(account_id: &str) {
+        let account_key = Self::account_key(account_id);
+        if let Some(index) = AccountPendingWithdrawalObject::load(&account_key) {
+            PendingWithdrawalObject::delete_by_key(&Self::withdrawal_key(*index));
+            AccountPendingWithdrawalObject::delete_by_key(&account_key);
+        }
+    }
+
+    fn queue() -> PendingWithdrawalQueue {
+        PendingWithdrawalQueueObject::load(&PENDING_WITHDRAWAL_QUEUE_KEY)
+            .map_or_else(PendingWithdrawalQueue::default, |queue| *queue)
+    }
+
+    fn withdrawal_key(index: u64) -> u128 {
+        PENDING_WITHDRAWAL_KEY + index as u128
+    }
+
+    fn account_key(account_id: &str) -> Hash {
+        Hash::from((account_id, ACCOUNT_PENDING_WITHDRAWAL_KEY))
+    }
+
+    fn track_storage_usage(account_id: &str, initial_storage_usage: u64) {
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near_test::*;
+
+    const ACCOUNT: &str = "alice";
+
+    #[test]
+    fn superseded_withdrawal_is_deleted() {
+        // Arrange
+        TestCtx::new(ACCOUNT).apply();
+        PendingWithdrawals::enqueue(ACCOUNT, 5.into());
+        let storage_usage = env::storage_usage();
+
+        // Act
+        PendingWithdrawals::enqueue(ACCOUNT, 6.into());
+
+        // Assert - the superseded withdrawal record is replaced
+        assert_eq!(env::storage_usage(), storage_usage);
+        assert_eq!(PendingWithdrawals::len(), 2);
+        assert_eq!(
+            PendingWithdrawals::get(ACCOUNT).unwrap().available_on_epoch,
+            6.into()
+        );
+        assert!(PendingWithdrawalObject::load(&PendingWithdrawals::withdrawal_key(0)).is_none());
+
+        // Act - the empty slot is skipped
+        let withdrawal = PendingWithdrawals::dequeue_due(6.into()).unwrap();
+
+        // Assert
+        assert_eq!(withdrawal.available_on_epoch, 6.into());
+        assert_eq!(PendingWithdrawals::len(), 0);
+    }
+
+    #[test]
+    fn cancelled_withdrawal_is_deleted() {
+        // Arrange
+        TestCtx::new(ACCOUNT).apply();
+        PendingWithdrawals::enqueue(ACCOUNT, 5.into());
+        let storage_usage = env::storage_usage();
+
+        // Act
+        PendingWithdrawals::cancel(ACCOUNT);
+
+        // Assert
+        assert!(PendingWithdrawals::get(ACCOUNT).is_none());
+        assert!(PendingWithdrawalObject::load(&PendingWithdrawals::withdrawal_key(0)).is_none());
+        assert!(env::storage_usage() < storage_usage);
+
+        // Act - the empty slot is skipped
+        assert!(PendingWithdrawals::dequeue_due(5.into()).is_none());
+
+        // Assert
+        assert_eq!(PendingWithdrawals::len(), 0);
+    }
+}
@@ -0,0 +1,47 @@
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+/// Who is allowed to stake
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub enum StakingMode {
+    /// any registered account can stake
+    #[default]
+    Open,
+    /// only accounts with staker permission can stake - see [`PermissionedPool`]
+    Permissioned,
+}
+
+/// When enabled, the staking pool is invite-only - see [`crate::StakePermissionedPool`]
+/// - disabled by default
+pub struct PermissionedPool;
+
+const PERMISSIONED_POOL_KEY: u128 = 1958531370548212689030187433618262903;
+type PermissionedPoolObject = Object<u128, bool>;
+
+impl PermissionedPool {
+    pub fn enabled() -> bool {
+        PermissionedPoolObject::load(&PERMISSIONED_POOL_KEY).is_some_and(|enabled| *enabled)
+    }
+
+    pub fn staking_mode() -> StakingMode {
+        if Self::enabled() {
+            StakingMode::Permissioned
+        } else {
+            StakingMode::Open
+        }
+    }
+
+    pub(crate) fn set_enabled(enabled: bool) {
+        if enabled {
+            PermissionedPoolObject::new(PERMISSIONED_POOL_KEY, true).save();
+        } else {
+            PermissionedPoolObject::delete_by_key(&PERMISSIONED_POOL_KEY);
+        }
+    }
+}
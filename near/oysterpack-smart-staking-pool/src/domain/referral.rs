@@ -0,0 +1,75 @@
+use oysterpack_smart_account_management::AccountStorageEvent;
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::BasisPoints;
+use oysterpack_smart_near::eventbus::post;
+use oysterpack_smart_near::near_sdk::{env, AccountId};
+use oysterpack_smart_near::Hash;
+
+/// Tracks the account that referred the staker
+/// - the referrer earns the [`Referral::fee`] share of the staking fees that are charged to the account
+///
+/// The referrer is stored separately from the account's [`crate::StakeAccountData`], but its storage
+/// usage is charged to the account.
+pub struct Referral;
+
+const REFERRER_KEY: u128 = 1957597731316224537712034518640218361;
+const REFERRAL_FEE_KEY: u128 = 1957597769846107932404711637458346112;
+
+type ReferrerObject = Object<Hash, AccountId>;
+type ReferralFeeObject = Object<u128, BasisPoints>;
+
+impl Referral {
+    /// 100%
+    pub const MAX_FEE: BasisPoints = BasisPoints(10000);
+
+    fn object_key(account_id: &str) -> Hash {
+        Hash::from((account_id, REFERRER_KEY))
+    }
+
+    pub fn referrer(account_id: &str) -> Option<AccountId> {
+        ReferrerObject::load(&Self::object_key(account_id)).map(|referrer| (*referrer).clone())
+    }
+
+    /// replaces any existing referrer
+    /// - tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub fn set_referrer(account_id: &str, referrer: &str) {
+        let initial_storage_usage = env::storage_usage();
+        ReferrerObject::new(Self::object_key(account_id), referrer.to_string()).save();
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+
+    /// tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub fn delete(account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        ReferrerObject::delete_by_key(&Self::object_key(account_id));
+        let storage_usage_change = initial_storage_usage - env::storage_usage();
+        if storage_usage_change > 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                (-(storage_usage_change as i64)).into(),
+            ));
+        }
+    }
+
+    /// share of the staking fee that is paid to the referrer
+    /// - defaults to zero, i.e., referral fees are disabled until the operator sets the fee
+    pub fn fee() -> BasisPoints {
+        ReferralFeeObject::load(&REFERRAL_FEE_KEY).map_or(BasisPoints::ZERO, |fee| *fee)
+    }
+
+    pub(crate) fn set_fee(fee: BasisPoints) {
+        ReferralFeeObject::new(REFERRAL_FEE_KEY, fee).save();
+    }
+
+    /// computes the referrer's share of the specified staking fee STAKE amount, rounded down
+    pub fn referral_fee(staking_fee: TokenAmount) -> TokenAmount {
+        (*(Self::fee() * *staking_fee)).into()
+    }
+}
@@ -0,0 +1,94 @@
+use oysterpack_smart_account_management::AccountStorageEvent;
+use oysterpack_smart_near::data::{numbers::U64, Object};
+use oysterpack_smart_near::domain::{PublicKey, YoctoNear};
+use oysterpack_smart_near::eventbus::post;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::Hash;
+
+/// Key that is registered by the account to authorize relayed stake requests
+///
+/// The relay key is stored separately from the account's [`crate::StakeAccountData`], but its storage
+/// usage is charged to the account.
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct RelayKey {
+    /// only ED25519 keys are supported
+    pub public_key: PublicKey,
+    /// nonce of the last relayed request that was processed
+    /// - the next relayed request nonce must be `nonce + 1`
+    pub nonce: U64,
+}
+
+const RELAY_KEY_KEY: u128 = 1957486102756347231558640185529271603;
+
+type RelayKeyObject = Object<Hash, RelayKey>;
+
+impl RelayKey {
+    fn object_key(account_id: &str) -> Hash {
+        Hash::from((account_id, RELAY_KEY_KEY))
+    }
+
+    pub fn load(account_id: &str) -> Option<RelayKey> {
+        RelayKeyObject::load(&Self::object_key(account_id)).map(|object| *object)
+    }
+
+    /// tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub fn save(&self, account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        RelayKeyObject::new(Self::object_key(account_id), *self).save();
+        let storage_usage_change = env::storage_usage() - initial_storage_usage;
+        if storage_usage_change > 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+
+    /// tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub fn delete(account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        RelayKeyObject::delete_by_key(&Self::object_key(account_id));
+        let storage_usage_change = initial_storage_usage - env::storage_usage();
+        if storage_usage_change > 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                (-(storage_usage_change as i64)).into(),
+            ));
+        }
+    }
+}
+
+/// Request that is signed by the account owner and submitted by a relayer on the account's behalf,
+/// i.e., the relayer pays for gas
+///
+/// The signature is computed over the borsh serialized request bytes.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct RelayedStakeRequest {
+    /// account that signed the request
+    pub account_id: AccountId,
+    /// staking pool contract the request is meant for - protects against replaying the request
+    /// against another staking pool
+    pub contract_id: AccountId,
+    pub nonce: U64,
+    pub action: RelayedStakeAction,
+}
+
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub enum RelayedStakeAction {
+    /// stakes the account's available storage balance - see [`crate::StakingPool::ops_stake`]
+    Stake,
+    /// see [`crate::StakingPool::ops_unstake`]
+    Unstake(Option<YoctoNear>),
+}
@@ -0,0 +1,136 @@
+use oysterpack_smart_account_management::AccountStorageEvent;
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::{numbers::U256, Object};
+use oysterpack_smart_near::domain::YoctoNear;
+use oysterpack_smart_near::eventbus::post;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::{Hash, YOCTO};
+
+/// Account that receives the staking rewards that are earned on the account's STAKE, e.g., a charity
+///
+/// The account's principal is tracked as its STAKE balance valued at the STAKE token value that was
+/// checkpointed when the rewards were last skimmed. Thus, the rewards are the growth in the STAKE
+/// NEAR value above the principal.
+///
+/// The rewards beneficiary is stored separately from the account's [`crate::StakeAccountData`], but
+/// its storage usage is charged to the account.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct RewardsBeneficiary {
+    pub beneficiary: AccountId,
+    /// STAKE token NEAR value at which the account's principal was last checkpointed
+    pub stake_token_value: YoctoNear,
+}
+
+const REWARDS_BENEFICIARY_KEY: u128 = 1958150981530642197384610275930184627;
+
+type RewardsBeneficiaryObject = Object<Hash, RewardsBeneficiary>;
+
+impl RewardsBeneficiary {
+    fn object_key(account_id: &str) -> Hash {
+        Hash::from((account_id, REWARDS_BENEFICIARY_KEY))
+    }
+
+    pub fn load(account_id: &str) -> Option<RewardsBeneficiary> {
+        RewardsBeneficiaryObject::load(&Self::object_key(account_id))
+            .map(|object| (*object).clone())
+    }
+
+    /// computes the NEAR value of the rewards that were earned on the STAKE balance since the
+    /// principal was last checkpointed, rounded down
+    pub fn rewards(&self, stake_balance: TokenAmount, stake_token_value: YoctoNear) -> YoctoNear {
+        if stake_token_value <= self.stake_token_value {
+            return YoctoNear::ZERO;
+        }
+        let growth = *stake_token_value - *self.stake_token_value;
+        (U256::from(*stake_balance) * U256::from(growth) / U256::from(YOCTO))
+            .as_u128()
+            .into()
+    }
+
+    /// tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn save(&self, account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        RewardsBeneficiaryObject::new(Self::object_key(account_id), self.clone()).save();
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+
+    /// tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn delete(account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        RewardsBeneficiaryObject::delete_by_key(&Self::object_key(account_id));
+        let storage_usage_change = initial_storage_usage - env::storage_usage();
+        if storage_usage_change > 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                (-(storage_usage_change as i64)).into(),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near_test::*;
+
+    #[test]
+    fn rewards() {
+        let beneficiary = RewardsBeneficiary {
+            beneficiary: "charity".to_string(),
+            stake_token_value: YOCTO.into(),
+        };
+
+        assert_eq!(
+            beneficiary.rewards((100 * YOCTO).into(), (YOCTO + YOCTO / 100).into()),
+            YOCTO.into()
+        );
+        assert_eq!(
+            beneficiary.rewards((100 * YOCTO).into(), YOCTO.into()),
+            YoctoNear::ZERO
+        );
+        assert_eq!(
+            beneficiary.rewards((100 * YOCTO).into(), (YOCTO - 1).into()),
+            YoctoNear::ZERO
+        );
+    }
+
+    #[test]
+    fn crud() {
+        // Arrange
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+        let beneficiary = RewardsBeneficiary {
+            beneficiary: "charity".to_string(),
+            stake_token_value: YOCTO.into(),
+        };
+
+        // Act
+        let storage_usage = env::storage_usage();
+        beneficiary.save("bob");
+
+        // Assert
+        let storage_usage_after_save = env::storage_usage();
+        assert!(storage_usage_after_save > storage_usage);
+        assert_eq!(RewardsBeneficiary::load("bob"), Some(beneficiary));
+        assert!(RewardsBeneficiary::load("alice").is_none());
+
+        // Act
+        RewardsBeneficiary::delete("bob");
+
+        // Assert
+        assert!(env::storage_usage() < storage_usage_after_save);
+        assert!(RewardsBeneficiary::load("bob").is_none());
+    }
+}
@@ -0,0 +1,275 @@
+use oysterpack_smart_account_management::AccountStorageEvent;
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::{numbers::U256, Object};
+use oysterpack_smart_near::domain::{BlockTimestamp, Gas};
+use oysterpack_smart_near::eventbus::post;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    json_types::U128,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::{Hash, TERA, YOCTO};
+
+/// Rewards farm that distributes an external NEP-141 reward token to STAKE holders proportionally
+/// to their STAKE balances over time - see [`crate::StakeRewardsFarm`]
+///
+/// Emitted rewards are tracked as the cumulative rewards per STAKE, i.e., `reward_per_share`, which
+/// is checkpointed per account by [`RewardsFarmAccount`] before the account's STAKE balance changes.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct RewardsFarm {
+    /// NEP-141 reward token contract
+    pub token_id: AccountId,
+    /// reward tokens that are emitted per second
+    pub emission_rate: TokenAmount,
+    /// reward tokens that have been deposited, but not yet emitted
+    pub undistributed_balance: TokenAmount,
+    /// cumulative reward tokens emitted per STAKE, scaled by [`RewardsFarm::PRECISION`]
+    pub reward_per_share: U128,
+    /// when rewards were last emitted
+    pub updated_at: BlockTimestamp,
+}
+
+const REWARDS_FARM_KEY: u128 = 1958151015836274910384756201938475621;
+const REWARDS_FARM_ACCOUNT_KEY: u128 = 1958151015836274910384756201938475622;
+
+type RewardsFarmObject = Object<u128, RewardsFarm>;
+type RewardsFarmAccountObject = Object<Hash, RewardsFarmAccount>;
+
+impl RewardsFarm {
+    pub const PRECISION: u128 = YOCTO;
+
+    /// gas that is allotted to the reward token contract `ft_transfer` call
+    pub const FT_TRANSFER_GAS: Gas = Gas(10 * TERA);
+
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+    pub fn new(token_id: AccountId, emission_rate: TokenAmount) -> Self {
+        Self {
+            token_id,
+            emission_rate,
+            undistributed_balance: TokenAmount::ZERO,
+            reward_per_share: 0.into(),
+            updated_at: BlockTimestamp::from_env(),
+        }
+    }
+
+    pub fn load() -> Option<Self> {
+        RewardsFarmObject::load(&REWARDS_FARM_KEY).map(|farm| (*farm).clone())
+    }
+
+    pub(crate) fn save(&self) {
+        RewardsFarmObject::new(REWARDS_FARM_KEY, self.clone()).save();
+    }
+
+    /// emits the rewards that have accrued since the farm was last updated, up to the undistributed
+    /// balance, across the specified STAKE supply
+    /// - if there is no STAKE supply, then no rewards are emitted for the elapsed time
+    /// - the rewards that are lost to rounding remain in the undistributed balance
+    ///
+    /// Returns the amount of reward tokens that were emitted
+    pub fn emit(&mut self, stake_supply: TokenAmount) -> TokenAmount {
+        let now = BlockTimestamp::from_env();
+        if now <= self.updated_at {
+            return TokenAmount::ZERO;
+        }
+        let elapsed_nanos = *now - *self.updated_at;
+        self.updated_at = now;
+        if *stake_supply == 0 || *self.emission_rate == 0 || *self.undistributed_balance == 0 {
+            return TokenAmount::ZERO;
+        }
+
+        let emission = U256::from(*self.emission_rate) * U256::from(elapsed_nanos)
+            / U256::from(Self::NANOS_PER_SEC);
+        let emission = emission.min(U256::from(*self.undistributed_balance));
+        let reward_per_share_increment =
+            emission * U256::from(Self::PRECISION) / U256::from(*stake_supply);
+        let emitted = (reward_per_share_increment * U256::from(*stake_supply)
+            / U256::from(Self::PRECISION))
+        .as_u128();
+
+        self.reward_per_share =
+            (self.reward_per_share.0 + reward_per_share_increment.as_u128()).into();
+        self.undistributed_balance -= emitted.into();
+        emitted.into()
+    }
+}
+
+/// Account's checkpointed [`RewardsFarm`] rewards
+/// - if the account has no record, then its rewards are accrued from when the farm was created
+///
+/// The farm account record is stored separately from the account's [`crate::StakeAccountData`], but
+/// its storage usage is charged to the account.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct RewardsFarmAccount {
+    /// farm reward per share when the account's rewards were last checkpointed
+    pub reward_per_share: U128,
+    /// rewards that have accrued, but have not yet been claimed
+    pub rewards: TokenAmount,
+}
+
+impl Default for RewardsFarmAccount {
+    fn default() -> Self {
+        Self {
+            reward_per_share: 0.into(),
+            rewards: TokenAmount::ZERO,
+        }
+    }
+}
+
+impl RewardsFarmAccount {
+    fn object_key(account_id: &str) -> Hash {
+        Hash::from((account_id, REWARDS_FARM_ACCOUNT_KEY))
+    }
+
+    pub fn load(account_id: &str) -> Option<RewardsFarmAccount> {
+        RewardsFarmAccountObject::load(&Self::object_key(account_id))
+            .map(|object| (*object).clone())
+    }
+
+    /// accrues the rewards that were earned on the STAKE balance since the account was last
+    /// checkpointed, rounded down, and then checkpoints the account at the specified reward per share
+    pub fn accrue(&mut self, stake_balance: TokenAmount, reward_per_share: U128) {
+        let growth = reward_per_share.0 - self.reward_per_share.0;
+        let rewards = (U256::from(*stake_balance) * U256::from(growth)
+            / U256::from(RewardsFarm::PRECISION))
+        .as_u128();
+        self.rewards += rewards.into();
+        self.reward_per_share = reward_per_share;
+    }
+
+    /// tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn save(&self, account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        RewardsFarmAccountObject::new(Self::object_key(account_id), self.clone()).save();
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+
+    /// tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn delete(account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        RewardsFarmAccountObject::delete_by_key(&Self::object_key(account_id));
+        let storage_usage_change = initial_storage_usage - env::storage_usage();
+        if storage_usage_change > 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                (-(storage_usage_change as i64)).into(),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near_test::*;
+
+    const SEC: u64 = 1_000_000_000;
+
+    #[test]
+    fn emit() {
+        // Arrange
+        let mut ctx = TestCtx::new("bob");
+        ctx.block_timestamp(100 * SEC).apply();
+        let mut farm = RewardsFarm::new("reward.near".to_string(), 10.into());
+        farm.undistributed_balance = 1000.into();
+
+        // Act - no time has elapsed
+        assert_eq!(farm.emit(100.into()), TokenAmount::ZERO);
+
+        // Act
+        ctx.block_timestamp(110 * SEC).apply();
+        let emitted = farm.emit(100.into());
+
+        // Assert
+        assert_eq!(emitted, 100.into());
+        assert_eq!(farm.undistributed_balance, 900.into());
+        assert_eq!(farm.reward_per_share, RewardsFarm::PRECISION.into());
+        assert_eq!(farm.updated_at, (110 * SEC).into());
+
+        // Act - emission is capped by the undistributed balance
+        ctx.block_timestamp(1000 * SEC).apply();
+        assert_eq!(farm.emit(100.into()), 900.into());
+        assert_eq!(farm.undistributed_balance, TokenAmount::ZERO);
+        assert_eq!(farm.reward_per_share, (10 * RewardsFarm::PRECISION).into());
+    }
+
+    #[test]
+    fn emit_with_no_stake_supply() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.block_timestamp(100 * SEC).apply();
+        let mut farm = RewardsFarm::new("reward.near".to_string(), 10.into());
+        farm.undistributed_balance = 1000.into();
+
+        ctx.block_timestamp(110 * SEC).apply();
+        assert_eq!(farm.emit(TokenAmount::ZERO), TokenAmount::ZERO);
+        assert_eq!(farm.undistributed_balance, 1000.into());
+        assert_eq!(farm.updated_at, (110 * SEC).into());
+    }
+
+    #[test]
+    fn emit_rounding() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.block_timestamp(100 * SEC).apply();
+        let mut farm = RewardsFarm::new("reward.near".to_string(), 10.into());
+        farm.undistributed_balance = 1000.into();
+
+        // 100 rewards emitted across 3 STAKE yocto cannot be evenly distributed
+        ctx.block_timestamp(110 * SEC).apply();
+        let emitted = farm.emit(3.into());
+        assert_eq!(emitted, 99.into());
+        assert_eq!(farm.undistributed_balance, 901.into());
+    }
+
+    #[test]
+    fn accrue() {
+        let mut account = RewardsFarmAccount::default();
+        account.accrue(100.into(), RewardsFarm::PRECISION.into());
+        assert_eq!(account.rewards, 100.into());
+        assert_eq!(account.reward_per_share, RewardsFarm::PRECISION.into());
+
+        account.accrue(50.into(), (RewardsFarm::PRECISION * 3 / 2).into());
+        assert_eq!(account.rewards, 125.into());
+
+        // no growth
+        account.accrue(50.into(), (RewardsFarm::PRECISION * 3 / 2).into());
+        assert_eq!(account.rewards, 125.into());
+    }
+
+    #[test]
+    fn crud() {
+        // Arrange
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+        let account = RewardsFarmAccount {
+            reward_per_share: RewardsFarm::PRECISION.into(),
+            rewards: 100.into(),
+        };
+
+        // Act
+        let storage_usage = env::storage_usage();
+        account.save("bob");
+
+        // Assert
+        let storage_usage_after_save = env::storage_usage();
+        assert!(storage_usage_after_save > storage_usage);
+        assert_eq!(RewardsFarmAccount::load("bob"), Some(account));
+        assert!(RewardsFarmAccount::load("alice").is_none());
+
+        // Act
+        RewardsFarmAccount::delete("bob");
+
+        // Assert
+        assert!(env::storage_usage() < storage_usage_after_save);
+        assert!(RewardsFarmAccount::load("bob").is_none());
+    }
+}
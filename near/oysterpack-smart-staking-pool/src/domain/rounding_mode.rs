@@ -0,0 +1,14 @@
+use oysterpack_smart_near::near_sdk::serde::{Deserialize, Serialize};
+
+/// Specifies how NEAR amounts are rounded when they are converted to STAKE
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub enum RoundingMode {
+    /// STAKE amount is rounded up, i.e., the STAKE amount is worth at least the NEAR amount
+    #[default]
+    Up,
+    /// STAKE amount is rounded down, i.e., the STAKE amount is worth at most the NEAR amount
+    Down,
+    /// fails if the NEAR amount cannot be converted to STAKE exactly
+    Fail,
+}
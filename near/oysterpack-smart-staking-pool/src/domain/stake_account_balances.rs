@@ -1,6 +1,7 @@
 use crate::UnstakedBalances;
 use oysterpack_smart_account_management::StorageBalance;
 use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::numbers::U64;
 use oysterpack_smart_near::domain::{EpochHeight, YoctoNear};
 use oysterpack_smart_near::near_sdk::serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -13,6 +14,8 @@ pub struct StakeAccountBalances {
     pub staked: Option<StakedBalance>,
     // unstaked funds that are pending withdrawal
     pub unstaked: Option<UnstakedBalance>,
+    /// ID of the stake operation that produced the balances - see [`crate::StakeOperation`]
+    pub operation_id: Option<U64>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
@@ -0,0 +1,96 @@
+use oysterpack_smart_account_management::AccountStorageEvent;
+use oysterpack_smart_near::data::{numbers::U64, Object};
+use oysterpack_smart_near::domain::{BlockHeight, Gas};
+use oysterpack_smart_near::eventbus::post;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::{Hash, TERA};
+
+/// External attestation contract, e.g., a KYC provider, that accounts must be verified by before
+/// they can stake - see [`crate::StakeAttestationVerifier`]
+/// - verified attestations are cached per account for [`StakeAttestationGate::ttl`] blocks, which
+///   avoids a cross-contract call on each stake
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakeAttestationGate {
+    pub account_id: AccountId,
+    /// number of blocks that a verified attestation is cached for
+    pub ttl: U64,
+}
+
+const STAKE_ATTESTATION_GATE_KEY: u128 = 1958150224614360172297712394158071823;
+type StakeAttestationGateObject = Object<u128, StakeAttestationGate>;
+
+impl StakeAttestationGate {
+    /// gas that is allotted to the attestation contract `is_verified` call
+    pub const GAS: Gas = Gas(10 * TERA);
+
+    pub fn load() -> Option<Self> {
+        StakeAttestationGateObject::load(&STAKE_ATTESTATION_GATE_KEY).map(|gate| (*gate).clone())
+    }
+
+    pub(crate) fn save(&self) {
+        StakeAttestationGateObject::new(STAKE_ATTESTATION_GATE_KEY, self.clone()).save();
+    }
+
+    pub(crate) fn clear() {
+        StakeAttestationGateObject::delete_by_key(&STAKE_ATTESTATION_GATE_KEY);
+    }
+}
+
+/// Per account cache of verified attestations, which stores the block height that the attestation
+/// expires at
+///
+/// The cached attestation is stored separately from the account's [`crate::StakeAccountData`], but
+/// its storage usage is charged to the account.
+pub struct StakeAttestation;
+
+const STAKE_ATTESTATION_KEY: u128 = 1958150251937008440566124875730919515;
+
+type StakeAttestationObject = Object<Hash, BlockHeight>;
+
+impl StakeAttestation {
+    fn object_key(account_id: &str) -> Hash {
+        Hash::from((account_id, STAKE_ATTESTATION_KEY))
+    }
+
+    /// returns the block height that the account's cached attestation expires at
+    pub fn expires_at(account_id: &str) -> Option<BlockHeight> {
+        StakeAttestationObject::load(&Self::object_key(account_id)).map(|height| *height)
+    }
+
+    /// returns true if the account has a cached attestation that has not yet expired
+    pub fn is_valid(account_id: &str) -> bool {
+        Self::expires_at(account_id).is_some_and(|expires_at| expires_at >= BlockHeight::from_env())
+    }
+
+    /// caches the account's verified attestation for the specified number of blocks
+    /// - tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub fn record(account_id: &str, ttl: U64) {
+        let initial_storage_usage = env::storage_usage();
+        let expires_at = BlockHeight::from(env::block_index().saturating_add(*ttl));
+        StakeAttestationObject::new(Self::object_key(account_id), expires_at).save();
+        Self::track_storage_usage(account_id, initial_storage_usage);
+    }
+
+    /// tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub fn delete(account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        StakeAttestationObject::delete_by_key(&Self::object_key(account_id));
+        Self::track_storage_usage(account_id, initial_storage_usage);
+    }
+
+    fn track_storage_usage(account_id: &str, initial_storage_usage: u64) {
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+}
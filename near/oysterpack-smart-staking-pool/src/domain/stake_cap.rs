@@ -0,0 +1,65 @@
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::{BasisPoints, YoctoNear};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+};
+
+/// Operator configured ceiling on the staking pool's total staked balance, which prevents the pool
+/// from overshooting the validator seat economics
+/// - NEAR that would push the total staked balance over the cap is not staked - it remains in the
+///   account's storage balance, or unstaked balance when restaking, where it can be withdrawn
+/// - the cap is enforced on every stake path, i.e., staking, restaking, vesting, and waitlist
+///   admission
+/// - the cap is not enforced retroactively, i.e., earnings can grow the total staked balance
+///   beyond the cap
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub enum StakeCap {
+    /// absolute total staked balance ceiling
+    Amount(YoctoNear),
+    /// ceiling is specified as a share of the current epoch's total validator stake
+    ValidatorTotalStakeShare(BasisPoints),
+}
+
+const STAKE_CAP_KEY: u128 = 1958114872032459181727369201855716642;
+type StakeCapObject = Object<u128, StakeCap>;
+
+impl StakeCap {
+    pub fn load() -> Option<Self> {
+        StakeCapObject::load(&STAKE_CAP_KEY).map(|cap| *cap)
+    }
+
+    pub(crate) fn save(&self) {
+        StakeCapObject::new(STAKE_CAP_KEY, *self).save();
+    }
+
+    pub(crate) fn clear() {
+        StakeCapObject::delete_by_key(&STAKE_CAP_KEY);
+    }
+
+    /// returns the total staked balance ceiling
+    pub fn limit(&self) -> YoctoNear {
+        match self {
+            StakeCap::Amount(amount) => *amount,
+            StakeCap::ValidatorTotalStakeShare(share) => {
+                share.of_rounded_down(env::validator_total_stake().into())
+            }
+        }
+    }
+
+    /// returns how much more NEAR can be staked before the cap is reached
+    pub fn capacity(&self, total_staked_balance: YoctoNear) -> YoctoNear {
+        self.limit().saturating_sub(*total_staked_balance).into()
+    }
+
+    /// returns the portion of the NEAR amount that would push the total staked balance over the cap
+    pub fn excess(&self, total_staked_balance: YoctoNear, amount: YoctoNear) -> YoctoNear {
+        amount
+            .saturating_sub(*self.capacity(total_staked_balance))
+            .into()
+    }
+}
@@ -0,0 +1,53 @@
+use crate::StakeAccountBalances;
+use oysterpack_smart_near::domain::{BlockHeight, BlockTimestamp, EpochHeight};
+use oysterpack_smart_near::near_sdk::{
+    serde::{Deserialize, Serialize},
+    serde_json, AccountId,
+};
+use oysterpack_smart_near::Hash;
+
+/// Proof-of-holdings statement for an account's balances at a specific block
+/// - `document` is the canonical JSON encoding of the [`StakeCertificateDocument`], i.e., compact
+///   JSON with fields in declaration order and locked balances ordered by epoch
+/// - `sha256` is the hash of the `document` bytes
+///
+/// Off-chain systems can pair the certificate with an RPC light-client proof of the view call
+/// result to produce audited proof-of-holdings statements. The document should be hashed as is,
+/// i.e., it should not be re-encoded.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakeCertificate {
+    pub document: String,
+    pub sha256: Hash,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakeCertificateDocument {
+    /// document format version - see [`StakeCertificateDocument::VERSION`]
+    pub version: u8,
+    /// staking pool contract account
+    pub contract_id: AccountId,
+    pub account_id: AccountId,
+    pub block_height: BlockHeight,
+    pub block_timestamp: BlockTimestamp,
+    pub epoch_height: EpochHeight,
+    pub balances: StakeAccountBalances,
+}
+
+impl StakeCertificateDocument {
+    pub const VERSION: u8 = 1;
+}
+
+impl StakeCertificate {
+    pub fn new(document: &StakeCertificateDocument) -> Self {
+        let document = serde_json::to_string(document).unwrap();
+        let sha256 = Hash::from(document.as_bytes());
+        Self { document, sha256 }
+    }
+
+    /// decodes the document
+    pub fn document(&self) -> StakeCertificateDocument {
+        serde_json::from_str(&self.document).unwrap()
+    }
+}
@@ -0,0 +1,123 @@
+use oysterpack_smart_near::data::{ComponentStorageUsage, Object};
+use oysterpack_smart_near::domain::{BlockHeight, EpochHeight, Gas, PublicKey};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::TERA;
+
+/// External key custodian contract, e.g., backed by an HSM, that must approve staking public key
+/// rotations - see [`crate::StakeKeyCustodian`]
+/// - when configured, [`crate::StakingPoolOperatorCommand::UpdatePublicKey`] submits the rotation
+///   request to the custodian, and the staking public key is only updated once the custodian has
+///   approved it
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakeKeyCustody {
+    pub account_id: AccountId,
+}
+
+const STAKE_KEY_CUSTODY_KEY: u128 = 1958170184463029758113205641730985561;
+type StakeKeyCustodyObject = Object<u128, StakeKeyCustody>;
+
+impl StakeKeyCustody {
+    /// gas that is allotted to the custodian contract `approve_stake_public_key` call
+    pub const GAS: Gas = Gas(10 * TERA);
+
+    pub fn load() -> Option<Self> {
+        StakeKeyCustodyObject::load(&STAKE_KEY_CUSTODY_KEY).map(|custody| (*custody).clone())
+    }
+
+    pub(crate) fn save(&self) {
+        StakeKeyCustodyObject::new(STAKE_KEY_CUSTODY_KEY, self.clone()).save();
+    }
+
+    pub(crate) fn clear() {
+        StakeKeyCustodyObject::delete_by_key(&STAKE_KEY_CUSTODY_KEY);
+    }
+}
+
+/// Staking public key rotation record
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakePublicKeyRotation {
+    pub public_key: PublicKey,
+    pub block_height: BlockHeight,
+    pub epoch_height: EpochHeight,
+    /// key custodian that approved the rotation - None if key custody was not configured
+    pub custodian: Option<AccountId>,
+}
+
+/// Staking public key rotations, ordered from oldest to newest
+/// - the history is bounded to the most recent [`StakePublicKeyHistory::MAX_ROTATIONS`] rotations
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakePublicKeyHistory(Vec<StakePublicKeyRotation>);
+
+const STAKE_PUBLIC_KEY_HISTORY_KEY: u128 = 1958170201748372059918463275109482713;
+type StakePublicKeyHistoryObject = Object<u128, StakePublicKeyHistory>;
+
+impl StakePublicKeyHistory {
+    pub const MAX_ROTATIONS: usize = 30;
+
+    pub fn load() -> Self {
+        StakePublicKeyHistoryObject::load(&STAKE_PUBLIC_KEY_HISTORY_KEY)
+            .map_or_else(Self::default, |history| (*history).clone())
+    }
+
+    pub fn rotations(&self) -> &[StakePublicKeyRotation] {
+        &self.0
+    }
+
+    /// records the rotation for the current block
+    pub(crate) fn record(public_key: PublicKey, custodian: Option<AccountId>) {
+        let mut history = Self::load();
+        history.0.push(StakePublicKeyRotation {
+            public_key,
+            block_height: BlockHeight::from_env(),
+            epoch_height: EpochHeight::from_env(),
+            custodian,
+        });
+        if history.0.len() > Self::MAX_ROTATIONS {
+            history.0.remove(0);
+        }
+        ComponentStorageUsage::track(ComponentStorageUsage::STAKING, || {
+            StakePublicKeyHistoryObject::new(STAKE_PUBLIC_KEY_HISTORY_KEY, history).save();
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near_test::*;
+
+    #[test]
+    fn history_is_bounded() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        let public_key: PublicKey = oysterpack_smart_near::near_sdk::serde_json::from_str(
+            r#""ed25519:AC1pVDXsE8sZiLAqLTDa3sD8DH74U5yUDaYKWeBwwyJj""#,
+        )
+        .unwrap();
+        for i in 0..(StakePublicKeyHistory::MAX_ROTATIONS as u64 + 5) {
+            ctx.block_index(i).apply();
+            StakePublicKeyHistory::record(public_key, None);
+        }
+
+        let history = StakePublicKeyHistory::load();
+        assert_eq!(
+            history.rotations().len(),
+            StakePublicKeyHistory::MAX_ROTATIONS
+        );
+        assert_eq!(history.rotations()[0].block_height, 5.into());
+        assert!(history
+            .rotations()
+            .windows(2)
+            .all(|pair| pair[0].block_height < pair[1].block_height));
+    }
+}
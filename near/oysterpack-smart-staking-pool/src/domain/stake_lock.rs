@@ -0,0 +1,102 @@
+use oysterpack_smart_account_management::AccountStorageEvent;
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::eventbus::post;
+use oysterpack_smart_near::near_sdk::env;
+use oysterpack_smart_near::Hash;
+
+/// STAKE that is locked on an account by an approved locker contract, e.g., a lending market that
+/// uses the STAKE as collateral
+/// - each lock is identified by the account, the locker, and the locker assigned lock ID
+/// - the locked STAKE is held in the account's locked STAKE balance, which cannot be transferred
+///   or unstaked
+///
+/// The lock record storage usage is charged to the locker account.
+pub struct StakeLock;
+
+const STAKE_LOCK_KEY: u128 = 1957921462748127305639204581736294017;
+
+type StakeLockObject = Object<Hash, u128>;
+
+impl StakeLock {
+    fn object_key(account_id: &str, locker_id: &str, lock_id: &str) -> Hash {
+        Hash::from((
+            format!("{}:{}:{}", account_id, locker_id, lock_id).as_str(),
+            STAKE_LOCK_KEY,
+        ))
+    }
+
+    /// returns the amount of STAKE that is locked by the locker on the account for the specified lock
+    pub fn balance(account_id: &str, locker_id: &str, lock_id: &str) -> TokenAmount {
+        StakeLockObject::load(&Self::object_key(account_id, locker_id, lock_id))
+            .map_or(TokenAmount::ZERO, |balance| (*balance).into())
+    }
+
+    /// if the balance is zero, then the lock record is deleted
+    /// - tracks storage usage against the locker account - emits
+    ///   [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn set_balance(
+        account_id: &str,
+        locker_id: &str,
+        lock_id: &str,
+        balance: TokenAmount,
+    ) {
+        let initial_storage_usage = env::storage_usage();
+        let key = Self::object_key(account_id, locker_id, lock_id);
+        if balance == TokenAmount::ZERO {
+            StakeLockObject::delete_by_key(&key);
+        } else {
+            StakeLockObject::new(key, *balance).save();
+        }
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                locker_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+}
+
+/// Tracks which lockers an account has approved to lock its STAKE
+/// - a locker can only lock an account's STAKE if the account owner has approved the locker
+/// - revoking the approval does not release existing locks
+///
+/// The approval record storage usage is charged to the account.
+pub struct StakeLockerApproval;
+
+const STAKE_LOCKER_APPROVAL_KEY: u128 = 1957922081376190542376620149858126783;
+
+type StakeLockerApprovalObject = Object<Hash, ()>;
+
+impl StakeLockerApproval {
+    fn object_key(account_id: &str, locker_id: &str) -> Hash {
+        Hash::from((
+            format!("{}:{}", account_id, locker_id).as_str(),
+            STAKE_LOCKER_APPROVAL_KEY,
+        ))
+    }
+
+    pub fn exists(account_id: &str, locker_id: &str) -> bool {
+        StakeLockerApprovalObject::exists(&Self::object_key(account_id, locker_id))
+    }
+
+    /// tracks storage usage against the account - emits
+    /// [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn set(account_id: &str, locker_id: &str, approved: bool) {
+        let initial_storage_usage = env::storage_usage();
+        let key = Self::object_key(account_id, locker_id);
+        if approved {
+            StakeLockerApprovalObject::new(key, ()).save();
+        } else {
+            StakeLockerApprovalObject::delete_by_key(&key);
+        }
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::{numbers::U64, ComponentStorageUsage, Object};
+use oysterpack_smart_near::domain::{BlockTimestamp, EpochHeight, YoctoNear};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::Hash;
+
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub enum StakeOperationKind {
+    Stake,
+    Unstake,
+    Withdraw,
+}
+
+/// Recorded stake, unstake, or withdraw operation, which enables custodians to reconcile account
+/// balances by operation ID
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakeOperation {
+    /// monotonically increasing operation ID
+    pub id: U64,
+    pub kind: StakeOperationKind,
+    pub account_id: AccountId,
+    /// NEAR amount that was staked, unstaked, or withdrawn
+    pub amount: YoctoNear,
+    /// STAKE amount that was minted or burned - zero for withdrawals
+    pub stake: TokenAmount,
+    pub epoch_height: EpochHeight,
+    pub block_timestamp: BlockTimestamp,
+}
+
+const STAKE_OPERATION_COUNT_KEY: u128 = 1958150911847302659184736520193847561;
+const STAKE_OPERATION_KEY: u128 = 1958150923650184729361057284619307452;
+
+type StakeOperationCountObject = Object<u128, u64>;
+type StakeOperationObject = Object<Hash, StakeOperation>;
+
+impl StakeOperation {
+    /// the most recent operations are retained, which bounds the storage used by the contract
+    /// - operation records are stored in a ring buffer, i.e., older records are overwritten
+    pub const MAX_RECORDED_OPERATIONS: u64 = 1000;
+
+    /// returns the number of operations that have been recorded, which is also the next operation ID
+    pub fn count() -> u64 {
+        StakeOperationCountObject::load(&STAKE_OPERATION_COUNT_KEY).map_or(0, |count| *count)
+    }
+
+    /// returns None if the operation does not exist or has been evicted
+    pub fn load(id: U64) -> Option<Self> {
+        StakeOperationObject::load(&Self::key(id.0))
+            .filter(|operation| operation.id == id)
+            .map(|operation| (*operation).clone())
+    }
+
+    /// assigns the next operation ID and records the operation
+    pub(crate) fn record(
+        kind: StakeOperationKind,
+        account_id: &str,
+        amount: YoctoNear,
+        stake: TokenAmount,
+    ) -> Self {
+        let id = Self::count();
+        let operation = Self {
+            id: id.into(),
+            kind,
+            account_id: account_id.to_string(),
+            amount,
+            stake,
+            epoch_height: EpochHeight::from_env(),
+            block_timestamp: BlockTimestamp::from_env(),
+        };
+        ComponentStorageUsage::track(ComponentStorageUsage::STAKING, || {
+            StakeOperationCountObject::new(STAKE_OPERATION_COUNT_KEY, id + 1).save();
+            StakeOperationObject::new(Self::key(id), operation.clone()).save();
+        });
+        operation
+    }
+
+    fn key(id: u64) -> Hash {
+        let slot = id % Self::MAX_RECORDED_OPERATIONS;
+        Hash::from((&slot.to_le_bytes()[..], STAKE_OPERATION_KEY))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near_test::*;
+
+    #[test]
+    fn record() {
+        // Arrange
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        // Act
+        let stake =
+            StakeOperation::record(StakeOperationKind::Stake, "bob", 1000.into(), 1000.into());
+        let withdraw = StakeOperation::record(
+            StakeOperationKind::Withdraw,
+            "bob",
+            100.into(),
+            TokenAmount::ZERO,
+        );
+
+        // Assert
+        assert_eq!(stake.id, 0.into());
+        assert_eq!(withdraw.id, 1.into());
+        assert_eq!(StakeOperation::count(), 2);
+        assert_eq!(StakeOperation::load(0.into()), Some(stake));
+        assert_eq!(StakeOperation::load(1.into()), Some(withdraw));
+        assert!(StakeOperation::load(2.into()).is_none());
+    }
+
+    #[test]
+    fn older_operations_are_evicted() {
+        // Arrange
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+        let operation =
+            StakeOperation::record(StakeOperationKind::Stake, "bob", 1000.into(), 1000.into());
+        StakeOperationCountObject::new(
+            STAKE_OPERATION_COUNT_KEY,
+            StakeOperation::MAX_RECORDED_OPERATIONS,
+        )
+        .save();
+
+        // Act - the operation is recorded in the same slot as the first operation
+        let operation_2 =
+            StakeOperation::record(StakeOperationKind::Unstake, "bob", 1000.into(), 1000.into());
+
+        // Assert
+        assert_eq!(
+            operation_2.id,
+            StakeOperation::MAX_RECORDED_OPERATIONS.into()
+        );
+        assert!(StakeOperation::load(operation.id).is_none());
+        assert_eq!(StakeOperation::load(operation_2.id), Some(operation_2));
+    }
+}
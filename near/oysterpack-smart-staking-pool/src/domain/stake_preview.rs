@@ -0,0 +1,46 @@
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::domain::YoctoNear;
+use oysterpack_smart_near::near_sdk::serde::{Deserialize, Serialize};
+
+/// Dry-run result for [`crate::StakingPool::ops_stake`], which enables wallets to show users an
+/// accurate preview, including rounding
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakePreview {
+    /// NEAR amount that would be staked, i.e., the deposit + the account's available storage balance
+    pub amount: YoctoNear,
+    /// portion of the amount that would not be staked because the stake cap would be exceeded
+    /// - see [`crate::StakeCap`]
+    pub stake_cap_excess: YoctoNear,
+    /// NEAR value of the STAKE that would be minted
+    pub staked: YoctoNear,
+    /// STAKE that would be minted, before the staking fee is charged
+    pub stake_minted: TokenAmount,
+    /// staking fee that would be charged in STAKE
+    pub staking_fee: TokenAmount,
+    /// NEAR that cannot be converted into STAKE because of rounding
+    pub remainder: YoctoNear,
+    /// account's resulting STAKE balance
+    pub stake_balance: TokenAmount,
+    /// account's resulting available storage balance - the remainder and the stake cap excess are
+    /// credited to the account's storage balance
+    pub storage_available_balance: YoctoNear,
+}
+
+/// Dry-run result for [`crate::StakingPool::ops_unstake`], which enables wallets to show users an
+/// accurate preview, including rounding
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct UnstakePreview {
+    /// NEAR amount that would be unstaked
+    pub amount: YoctoNear,
+    /// STAKE that would be burned - rounded up to ensure the staked balance is never overdrawn
+    pub stake_burned: TokenAmount,
+    /// NEAR value of the burned STAKE above the amount, which is credited to the unstaked balance
+    /// because of rounding
+    pub remainder: YoctoNear,
+    /// account's resulting STAKE balance
+    pub stake_balance: TokenAmount,
+    /// account's resulting total unstaked balance
+    pub unstaked_balance: YoctoNear,
+}
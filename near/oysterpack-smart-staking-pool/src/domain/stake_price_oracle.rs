@@ -0,0 +1,68 @@
+use crate::StakeTokenValueSample;
+use oysterpack_smart_near::data::{numbers::U256, Object};
+use oysterpack_smart_near::domain::{BasisPoints, Gas, YoctoNear};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::TERA;
+
+/// Push oracle contract that the STAKE token value is published to, which enables protocols that
+/// use STAKE, e.g., lending protocols, to receive timely rate updates without polling
+/// - the STAKE token value is published when earnings are collected and the STAKE token value moved
+///   more than [`StakePriceOracle::threshold`] since it was last published
+/// - the oracle is notified via [`crate::StakePriceOracleReceiver::on_stake_price_update`]
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakePriceOracle {
+    pub account_id: AccountId,
+    pub threshold: BasisPoints,
+    /// STAKE token value that was last published to the oracle
+    pub last_published: Option<StakeTokenValueSample>,
+}
+
+const STAKE_PRICE_ORACLE_KEY: u128 = 1957851290648213379015586230417625390;
+type StakePriceOracleObject = Object<u128, StakePriceOracle>;
+
+impl StakePriceOracle {
+    /// gas that is allotted to the oracle `on_stake_price_update` call
+    pub const GAS: Gas = Gas(10 * TERA);
+
+    pub fn new(account_id: &str, threshold: BasisPoints) -> Self {
+        Self {
+            account_id: account_id.to_string(),
+            threshold,
+            last_published: None,
+        }
+    }
+
+    pub fn load() -> Option<Self> {
+        StakePriceOracleObject::load(&STAKE_PRICE_ORACLE_KEY).map(|oracle| (*oracle).clone())
+    }
+
+    pub(crate) fn save(&self) {
+        StakePriceOracleObject::new(STAKE_PRICE_ORACLE_KEY, self.clone()).save();
+    }
+
+    pub(crate) fn clear() {
+        StakePriceOracleObject::delete_by_key(&STAKE_PRICE_ORACLE_KEY);
+    }
+
+    /// returns true if the STAKE token value has never been published or if it moved more than the
+    /// threshold since it was last published
+    pub fn is_publish_due(&self, value: YoctoNear) -> bool {
+        match self.last_published {
+            None => true,
+            Some(last_published) => {
+                let change = if value > last_published.value {
+                    value - last_published.value
+                } else {
+                    last_published.value - value
+                };
+                U256::from(*change) * U256::from(10000)
+                    > U256::from(*last_published.value) * U256::from(*self.threshold)
+            }
+        }
+    }
+}
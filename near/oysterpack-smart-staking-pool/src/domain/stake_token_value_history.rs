@@ -0,0 +1,162 @@
+use oysterpack_smart_near::data::{ComponentStorageUsage, Object};
+use oysterpack_smart_near::domain::{EpochHeight, YoctoNear};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+/// STAKE token NEAR value that was observed during the epoch
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakeTokenValueSample {
+    pub epoch_height: EpochHeight,
+    /// NEAR value for 1 STAKE token
+    pub value: YoctoNear,
+}
+
+/// Per epoch STAKE token value samples, ordered by epoch height
+/// - the history is bounded to the most recent [`StakeTokenValueHistory::MAX_EPOCHS`] samples
+/// - the first value observed within an epoch is kept, i.e., the token value can only be sampled
+///   once per epoch, which makes the TWAP resistant to manipulation within an epoch
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakeTokenValueHistory(Vec<StakeTokenValueSample>);
+
+const STAKE_TOKEN_VALUE_HISTORY_KEY: u128 = 1957562310436987213945287640253814779;
+
+type StakeTokenValueHistoryObject = Object<u128, StakeTokenValueHistory>;
+
+impl StakeTokenValueHistory {
+    pub const MAX_EPOCHS: u64 = 30;
+
+    pub fn load() -> Self {
+        StakeTokenValueHistoryObject::load(&STAKE_TOKEN_VALUE_HISTORY_KEY)
+            .map_or_else(Self::default, |history| (*history).clone())
+    }
+
+    pub fn samples(&self) -> &[StakeTokenValueSample] {
+        &self.0
+    }
+
+    /// records the sample if the epoch has not yet been sampled
+    /// - returns false if the epoch was already sampled
+    pub fn record(epoch_height: EpochHeight, value: YoctoNear) -> bool {
+        let mut history = Self::load();
+        if let Some(last) = history.0.last() {
+            if last.epoch_height >= epoch_height {
+                return false;
+            }
+        }
+        history.0.push(StakeTokenValueSample {
+            epoch_height,
+            value,
+        });
+        if history.0.len() > Self::MAX_EPOCHS as usize {
+            history.0.remove(0);
+        }
+        ComponentStorageUsage::track(ComponentStorageUsage::STAKING, || {
+            StakeTokenValueHistoryObject::new(STAKE_TOKEN_VALUE_HISTORY_KEY, history).save();
+        });
+        true
+    }
+
+    /// Computes the time weighted average STAKE token value over the specified number of epochs,
+    /// up to and including the current epoch.
+    ///
+    /// Each sample's value is in effect from the epoch it was sampled in until the next sample.
+    /// Epochs within the window that precede the first sample are excluded.
+    ///
+    /// Returns None if there are no samples within the window.
+    pub fn twap(&self, current_epoch: EpochHeight, epochs: u64) -> Option<YoctoNear> {
+        if epochs == 0 {
+            return None;
+        }
+        let window_end = current_epoch + 1;
+        let window_start = EpochHeight(window_end.value().saturating_sub(epochs));
+
+        let mut total_value = 0_u128;
+        let mut total_epochs = 0_u128;
+        for (i, sample) in self.0.iter().enumerate() {
+            let start = sample.epoch_height;
+            let end = self
+                .0
+                .get(i + 1)
+                .map_or(window_end, |next| next.epoch_height);
+            let start = start.max(window_start);
+            let end = end.min(window_end);
+            if end > start {
+                let weight = (end - start) as u128;
+                total_value += *sample.value * weight;
+                total_epochs += weight;
+            }
+        }
+
+        total_value.checked_div(total_epochs).map(YoctoNear::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near::YOCTO;
+    use oysterpack_smart_near_test::*;
+
+    #[test]
+    fn twap() {
+        // Arrange
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        // Assert - no samples
+        assert!(StakeTokenValueHistory::load().twap(10.into(), 5).is_none());
+
+        // Act
+        assert!(StakeTokenValueHistory::record(10.into(), YOCTO.into()));
+        assert!(!StakeTokenValueHistory::record(
+            10.into(),
+            (2 * YOCTO).into()
+        ));
+        assert!(StakeTokenValueHistory::record(
+            12.into(),
+            (2 * YOCTO).into()
+        ));
+
+        // Assert
+        let history = StakeTokenValueHistory::load();
+        assert_eq!(history.samples().len(), 2);
+        assert_eq!(history.samples()[0].value, YOCTO.into());
+
+        // epochs: 10 -> 1, 11 -> 1, 12 -> 2, 13 -> 2
+        assert_eq!(history.twap(13.into(), 4), Some((6 * YOCTO / 4).into()));
+        // epochs preceding the first sample are excluded
+        assert_eq!(history.twap(13.into(), 10), Some((6 * YOCTO / 4).into()));
+        assert_eq!(history.twap(13.into(), 2), Some((2 * YOCTO).into()));
+        assert_eq!(history.twap(11.into(), 1), Some(YOCTO.into()));
+        assert!(history.twap(9.into(), 5).is_none());
+        assert!(history.twap(13.into(), 0).is_none());
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        // Arrange
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        // Act
+        for epoch in 0..(StakeTokenValueHistory::MAX_EPOCHS + 5) {
+            StakeTokenValueHistory::record(epoch.into(), YOCTO.into());
+        }
+
+        // Assert
+        let history = StakeTokenValueHistory::load();
+        assert_eq!(
+            history.samples().len(),
+            StakeTokenValueHistory::MAX_EPOCHS as usize
+        );
+        assert_eq!(history.samples()[0].epoch_height, 5.into());
+    }
+}
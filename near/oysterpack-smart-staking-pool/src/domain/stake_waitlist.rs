@@ -0,0 +1,219 @@
+use oysterpack_smart_near::data::{ComponentStorageUsage, Object};
+use oysterpack_smart_near::domain::{BlockHeight, YoctoNear};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// NEAR that is waiting for capacity under the stake cap - see [`crate::StakeCap`]
+/// - the waitlisted NEAR is held in the account's storage balance, i.e., the entry reserves the
+///   NEAR for staking but the account can still withdraw it
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakeWaitlistEntry {
+    pub account_id: AccountId,
+    pub amount: YoctoNear,
+    /// block height when the account joined the waitlist
+    pub block_height: BlockHeight,
+}
+
+/// Account's position in the stake waitlist
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakeWaitlistPosition {
+    /// 1-based position, i.e., the account at the head of the waitlist is at position 1
+    pub position: u32,
+    pub amount: YoctoNear,
+    /// total NEAR that is waitlisted ahead of the account
+    pub amount_ahead: YoctoNear,
+    pub block_height: BlockHeight,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakeWaitlistSummary {
+    pub enabled: bool,
+    pub accounts: u32,
+    /// total NEAR that is waitlisted
+    pub amount: YoctoNear,
+}
+
+/// FIFO waitlist for NEAR that would push the total staked balance over the stake cap
+/// - when enabled, the NEAR that exceeds the cap is waitlisted instead of only being credited to the
+///   account's storage balance
+/// - waitlisted NEAR is staked in FIFO order when capacity frees up:
+///   - unstaking admits at most [`StakeWaitlist::MAX_UNSTAKE_ADMISSIONS`] entries, which bounds the
+///     gas that is charged to the unstaking account
+///   - changing the stake cap admits up to [`StakeWaitlist::MAX_ADMISSIONS`] entries
+///   - any account can admit up to [`StakeWaitlist::MAX_ADMISSIONS`] entries via
+///     [`crate::StakingPool::ops_stake_admit_waitlist`]
+/// - entries are revalidated when they are admitted - entries for accounts that are no longer
+///   allowed to stake, or whose waitlisted NEAR was withdrawn or is below [`crate::MinStake`], are
+///   removed
+/// - each account has at most 1 entry - NEAR that is waitlisted while the account is already on the
+///   waitlist is added to its entry, i.e., the account keeps its position
+/// - the waitlist is bounded to [`StakeWaitlist::MAX_ENTRIES`] accounts - once full, the NEAR that
+///   exceeds the cap is credited to the account's storage balance without being waitlisted
+/// - disabled by default
+pub struct StakeWaitlist;
+
+const STAKE_WAITLIST_ENABLED_KEY: u128 = 1958731946201735582947163028574619253;
+const STAKE_WAITLIST_KEY: u128 = 1958731946201735582947163028574619254;
+
+type StakeWaitlistEnabledObject = Object<u128, bool>;
+type StakeWaitlistObject = Object<u128, Vec<StakeWaitlistEntry>>;
+
+impl StakeWaitlist {
+    pub const MAX_ENTRIES: usize = 100;
+
+    /// max number of waitlist entries that are processed per admission, which bounds the gas used
+    pub const MAX_ADMISSIONS: usize = 10;
+
+    /// max number of waitlist entries that are processed when capacity frees up via unstaking
+    pub const MAX_UNSTAKE_ADMISSIONS: usize = 1;
+
+    pub fn enabled() -> bool {
+        StakeWaitlistEnabledObject::load(&STAKE_WAITLIST_ENABLED_KEY)
+            .is_some_and(|enabled| *enabled)
+    }
+
+    /// disabling the waitlist clears the waitlist - the waitlisted NEAR remains in the account
+    /// storage balances
+    pub(crate) fn set_enabled(enabled: bool) {
+        if enabled {
+            StakeWaitlistEnabledObject::new(STAKE_WAITLIST_ENABLED_KEY, true).save();
+        } else {
+            StakeWaitlistEnabledObject::delete_by_key(&STAKE_WAITLIST_ENABLED_KEY);
+            Self::save(vec![]);
+        }
+    }
+
+    /// returns the waitlist entries ordered from the head of the waitlist
+    pub fn entries() -> Vec<StakeWaitlistEntry> {
+        StakeWaitlistObject::load(&STAKE_WAITLIST_KEY)
+            .map_or_else(Vec::new, |entries| (*entries).clone())
+    }
+
+    pub fn summary() -> StakeWaitlistSummary {
+        let entries = Self::entries();
+        StakeWaitlistSummary {
+            enabled: Self::enabled(),
+            accounts: entries.len() as u32,
+            amount: Self::total(&entries),
+        }
+    }
+
+    pub fn position(account_id: &str) -> Option<StakeWaitlistPosition> {
+        let entries = Self::entries();
+        let index = entries
+            .iter()
+            .position(|entry| entry.account_id == account_id)?;
+        let entry = &entries[index];
+        Some(StakeWaitlistPosition {
+            position: index as u32 + 1,
+            amount: entry.amount,
+            amount_ahead: Self::total(&entries[..index]),
+            block_height: entry.block_height,
+        })
+    }
+
+    /// returns the total NEAR that is waitlisted for accounts other than the specified account
+    pub(crate) fn total_excluding(account_id: &str) -> YoctoNear {
+        Self::total(
+            &Self::entries()
+                .into_iter()
+                .filter(|entry| entry.account_id != account_id)
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// adds the NEAR to the account's entry, or appends a new entry to the tail of the waitlist
+    /// - the entry amount is capped at the account's storage available balance
+    ///
+    /// returns false if the account is not on the waitlist and the waitlist is full
+    pub(crate) fn enqueue(account_id: &str, amount: YoctoNear, available: YoctoNear) -> bool {
+        let mut entries = Self::entries();
+        match entries
+            .iter_mut()
+            .find(|entry| entry.account_id == account_id)
+        {
+            Some(entry) => entry.amount = (entry.amount + amount).min(available),
+            None => {
+                if entries.len() >= Self::MAX_ENTRIES {
+                    return false;
+                }
+                entries.push(StakeWaitlistEntry {
+                    account_id: account_id.to_string(),
+                    amount: amount.min(available),
+                    block_height: BlockHeight::from_env(),
+                });
+            }
+        }
+        Self::save(entries);
+        true
+    }
+
+    /// caps the account's entry at the account's storage available balance, i.e., NEAR that is
+    /// withdrawn from the storage balance is no longer waitlisted
+    /// - if nothing is left, then the entry is removed
+    pub(crate) fn revalidate(account_id: &str, available: YoctoNear) {
+        let mut entries = Self::entries();
+        let index = match entries
+            .iter()
+            .position(|entry| entry.account_id == account_id)
+        {
+            Some(index) => index,
+            None => return,
+        };
+        if entries[index].amount <= available {
+            return;
+        }
+        if available == YoctoNear::ZERO {
+            entries.remove(index);
+        } else {
+            entries[index].amount = available;
+        }
+        Self::save(entries);
+    }
+
+    /// removes the account's entry from the waitlist
+    pub(crate) fn remove(account_id: &str) -> Option<StakeWaitlistEntry> {
+        let mut entries = Self::entries();
+        let index = entries
+            .iter()
+            .position(|entry| entry.account_id == account_id)?;
+        let entry = entries.remove(index);
+        Self::save(entries);
+        Some(entry)
+    }
+
+    pub(crate) fn save(entries: Vec<StakeWaitlistEntry>) {
+        ComponentStorageUsage::track(ComponentStorageUsage::STAKING, || {
+            if entries.is_empty() {
+                StakeWaitlistObject::delete_by_key(&STAKE_WAITLIST_KEY);
+            } else {
+                StakeWaitlistObject::new(STAKE_WAITLIST_KEY, entries).save();
+            }
+        });
+    }
+
+    fn total(entries: &[StakeWaitlistEntry]) -> YoctoNear {
+        entries
+            .iter()
+            .fold(YoctoNear::ZERO, |total, entry| total + entry.amount)
+    }
+}
+
+/// log message format: `account_id={account_id}, amount={amount}, position={position}`
+pub const LOG_EVENT_STAKE_WAITLISTED: LogEvent = LogEvent(Level::INFO, "STAKE_WAITLISTED");
+/// log message format: `account_id={account_id}, amount={amount}, remaining={remaining}`
+pub const LOG_EVENT_STAKE_WAITLIST_ADMITTED: LogEvent =
+    LogEvent(Level::INFO, "STAKE_WAITLIST_ADMITTED");
+/// logged when an entry is removed from the waitlist without being admitted
+/// - log message format: `account_id={account_id}, amount={amount}, reason={reason}`
+pub const LOG_EVENT_STAKE_WAITLIST_REMOVED: LogEvent =
+    LogEvent(Level::WARN, "STAKE_WAITLIST_REMOVED");
+/// logged when the waitlist is full - message format: `account_id={account_id}, amount={amount}`
+pub const LOG_EVENT_STAKE_WAITLIST_FULL: LogEvent = LogEvent(Level::WARN, "STAKE_WAITLIST_FULL");
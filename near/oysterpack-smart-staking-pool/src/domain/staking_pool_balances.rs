@@ -1,12 +1,22 @@
 use crate::components::staking_pool::State;
+use crate::{EarningsBuffer, EarningsSources, GasReserve};
 use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::{ComponentStorageUsage, Object};
+use oysterpack_smart_near::domain::{BlockHeight, BlockTimestamp, EpochHeight};
 use oysterpack_smart_near::{
     domain::YoctoNear,
-    near_sdk::serde::{Deserialize, Serialize},
+    near_sdk::{
+        borsh::{self, BorshDeserialize, BorshSerialize},
+        serde::{Deserialize, Serialize},
+        serde_json,
+    },
+    Level, LogEvent,
 };
 
 /// Staking Pool Contract NEAR Balances
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq,
+)]
 #[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
 pub struct StakingPoolBalances {
     /// total NEAR funds that have been staked and confirmed
@@ -21,6 +31,13 @@ pub struct StakingPoolBalances {
     /// unstaked funds that can be withdrawn from liquidity added by staking
     pub unstaked_liquidity: YoctoNear,
 
+    /// rounding remainders that could not be converted into STAKE, which are tracked separately
+    /// from the total staked balance - see [`State::ROUNDING_DUST`]
+    pub rounding_dust: YoctoNear,
+
+    /// earnings that have been diverted into the contract gas reserve - see [`GasReserve`]
+    pub gas_reserve: YoctoNear,
+
     /// used to track the treasury STAKE NEAR value
     /// - staking rewards earned by the treasury are distributed as dividends
     /// - balance gets updated when funds are staked
@@ -33,17 +50,43 @@ pub struct StakingPoolBalances {
     /// [`StakingPoolBalances::last_contract_managed_total_balance`] - [`StakingPoolBalances::current_contract_managed_total_balance`]
     /// staking earnings + transaction fee earnings
     pub earnings: YoctoNear,
+    /// cumulative earnings broken down by source
+    pub earnings_sources: EarningsSources,
+    /// earnings that are being vested into the total staked balance, if an earnings vesting period
+    /// is configured
+    pub earnings_buffer: Option<EarningsBuffer>,
 }
 
 impl StakingPoolBalances {
     pub(crate) fn new(state: State, total_stake_supply: TokenAmount) -> Self {
-        let current_contract_managed_total_balance =
-            State::contract_managed_total_balance_in_view_mode();
+        Self::with_current_contract_managed_total_balance(
+            state,
+            total_stake_supply,
+            State::contract_managed_total_balance_in_view_mode(),
+        )
+    }
+
+    /// used in change mode, where the contract managed total balance excludes the attached deposit
+    pub(crate) fn snapshot(state: State, total_stake_supply: TokenAmount) -> Self {
+        Self::with_current_contract_managed_total_balance(
+            state,
+            total_stake_supply,
+            State::contract_managed_total_balance(),
+        )
+    }
+
+    fn with_current_contract_managed_total_balance(
+        state: State,
+        total_stake_supply: TokenAmount,
+        current_contract_managed_total_balance: YoctoNear,
+    ) -> Self {
         Self {
             total_staked: State::total_staked_balance(),
             total_stake_supply,
             total_unstaked: State::total_unstaked_balance(),
             unstaked_liquidity: State::liquidity(),
+            rounding_dust: State::rounding_dust(),
+            gas_reserve: GasReserve::balance(),
             treasury_balance: state.treasury_balance,
 
             current_contract_managed_total_balance,
@@ -51,6 +94,109 @@ impl StakingPoolBalances {
             earnings: current_contract_managed_total_balance
                 .saturating_sub(*state.last_contract_managed_total_balance)
                 .into(),
+            earnings_sources: State::earnings_sources(),
+            earnings_buffer: EarningsBuffer::load(),
+        }
+    }
+}
+
+/// [`StakingPoolBalances`] snapshot that is recorded when earnings are recognized
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakingPoolBalancesSnapshot {
+    pub block_height: BlockHeight,
+    pub block_timestamp: BlockTimestamp,
+    pub epoch_height: EpochHeight,
+    pub balances: StakingPoolBalances,
+}
+
+/// log message format: [`StakingPoolBalancesSnapshot`] JSON
+pub const LOG_EVENT_POOL_BALANCES: LogEvent = LogEvent(Level::INFO, "POOL_BALANCES");
+
+/// [`StakingPoolBalances`] snapshots that are recorded each time earnings are recognized, ordered by
+/// block height, which enables off-chain accounting to pinpoint when earnings landed
+/// - the history is bounded to the most recent [`StakingPoolBalancesHistory::MAX_SNAPSHOTS`] snapshots
+/// - if [`StakingPoolBalancesHistory::events_enabled`], then each snapshot is also logged via
+///   [`LOG_EVENT_POOL_BALANCES`] - the events are opt-in because the full snapshot adds a sizable
+///   log to every transaction that recognizes earnings
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakingPoolBalancesHistory(Vec<StakingPoolBalancesSnapshot>);
+
+const STAKING_POOL_BALANCES_HISTORY_KEY: u128 = 1958560314785502213689924036416250231;
+const POOL_BALANCES_EVENTS_KEY: u128 = 1958560348071630659264119453917340813;
+
+type StakingPoolBalancesHistoryObject = Object<u128, StakingPoolBalancesHistory>;
+type PoolBalancesEventsObject = Object<u128, bool>;
+
+impl StakingPoolBalancesHistory {
+    pub const MAX_SNAPSHOTS: u64 = 30;
+
+    pub fn load() -> Self {
+        StakingPoolBalancesHistoryObject::load(&STAKING_POOL_BALANCES_HISTORY_KEY)
+            .map_or_else(Self::default, |history| (*history).clone())
+    }
+
+    pub fn snapshots(&self) -> &[StakingPoolBalancesSnapshot] {
+        &self.0
+    }
+
+    /// returns up to `limit` of the most recent snapshots, ordered by block height
+    pub fn most_recent(&self, limit: u64) -> &[StakingPoolBalancesSnapshot] {
+        let start = self.0.len().saturating_sub(limit as usize);
+        &self.0[start..]
+    }
+
+    /// returns the most recent snapshot that was recorded at or before the specified block height
+    pub fn at(&self, block_height: BlockHeight) -> Option<StakingPoolBalancesSnapshot> {
+        self.0
+            .iter()
+            .rev()
+            .find(|snapshot| snapshot.block_height <= block_height)
+            .cloned()
+    }
+
+    /// records the balances snapshot for the current block
+    /// - logs [`LOG_EVENT_POOL_BALANCES`] if events are enabled
+    pub(crate) fn record(balances: StakingPoolBalances) {
+        let snapshot = StakingPoolBalancesSnapshot {
+            block_height: BlockHeight::from_env(),
+            block_timestamp: BlockTimestamp::from_env(),
+            epoch_height: EpochHeight::from_env(),
+            balances,
+        };
+        if Self::events_enabled() {
+            LOG_EVENT_POOL_BALANCES.log(serde_json::to_string(&snapshot).unwrap());
+        }
+
+        let mut history = Self::load();
+        match history.0.last_mut() {
+            // earnings can be recognized more than once within the same block
+            Some(last) if last.block_height == snapshot.block_height => *last = snapshot,
+            _ => history.0.push(snapshot),
+        }
+        if history.0.len() > Self::MAX_SNAPSHOTS as usize {
+            history.0.remove(0);
+        }
+        ComponentStorageUsage::track(ComponentStorageUsage::STAKING, || {
+            StakingPoolBalancesHistoryObject::new(STAKING_POOL_BALANCES_HISTORY_KEY, history)
+                .save();
+        });
+    }
+
+    pub fn events_enabled() -> bool {
+        PoolBalancesEventsObject::load(&POOL_BALANCES_EVENTS_KEY).is_some_and(|enabled| *enabled)
+    }
+
+    pub(crate) fn set_events_enabled(enabled: bool) {
+        if enabled {
+            PoolBalancesEventsObject::new(POOL_BALANCES_EVENTS_KEY, true).save();
+        } else {
+            PoolBalancesEventsObject::delete_by_key(&POOL_BALANCES_EVENTS_KEY);
         }
     }
 }
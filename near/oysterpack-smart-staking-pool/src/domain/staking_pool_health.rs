@@ -0,0 +1,190 @@
+use crate::components::staking_pool::State;
+use crate::{LiquidityPool, OfflineReason, Status};
+use oysterpack_smart_account_management::AccountMetrics;
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::{BlockHeight, BlockTimestamp, EpochHeight, YoctoNear};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    json_types::I128,
+    serde::{Deserialize, Serialize},
+};
+
+/// Composite staking pool health report, which node operators can use to alert on
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakingPoolHealth {
+    /// true if none of the following conditions are detected:
+    /// - the pool is offline because a stake action failed
+    /// - the last stake action failed
+    /// - the pool is online and the locked balance does not cover the total staked balance
+    pub healthy: bool,
+
+    pub status: Status,
+
+    /// contract account locked balance, i.e., the NEAR that is staked with the validator
+    pub locked_balance: YoctoNear,
+    /// total staked balance that is tracked by the contract
+    pub total_staked: YoctoNear,
+    /// [`StakingPoolHealth::locked_balance`] - ([`StakingPoolHealth::total_staked`] + buffered earnings)
+    /// - while online, positive drift is expected, i.e., staking rewards that have not yet been collected
+    /// - while online, negative drift means the locked balance does not cover the staked balance
+    pub staked_balance_drift: I128,
+
+    /// earnings that have been received but have not yet been collected
+    pub pending_earnings: YoctoNear,
+    /// unstaked funds that can be withdrawn from liquidity added by staking
+    pub unstaked_liquidity: YoctoNear,
+    pub treasury_balance: YoctoNear,
+    /// liquid NEAR that is not committed to contract storage or to tracked balances, which is
+    /// available to pay for contract operations
+    pub gas_reserve: YoctoNear,
+
+    pub last_stake_action: Option<StakeActionOutcome>,
+    /// stake actions that have been submitted to the validator, but have not yet been finalized
+    pub pending_stake_actions: Option<PendingStakeActions>,
+}
+
+impl StakingPoolHealth {
+    /// must only be called in view mode - see [`State::contract_managed_total_balance_in_view_mode`]
+    pub(crate) fn new(state: State) -> Self {
+        let locked_balance: YoctoNear = env::account_locked_balance().into();
+        let total_staked = State::total_staked_balance();
+        // buffered earnings are staked with the validator, but are not yet included in the total
+        // staked balance
+        let validator_staked = State::total_validator_staked_balance();
+        let staked_balance_drift = *locked_balance as i128 - *validator_staked as i128;
+        let last_stake_action = StakeActionOutcome::load();
+
+        let gas_reserve = {
+            let storage_staking_cost = env::storage_byte_cost() * env::storage_usage() as u128;
+            // while offline, the staked balance is held in the liquid balance
+            let liquid_staked_balance = validator_staked.saturating_sub(*locked_balance);
+            let committed = storage_staking_cost
+                + *AccountMetrics::load().total_near_balance
+                + *State::total_unstaked_balance()
+                + *State::liquidity()
+                + *LiquidityPool::near_reserve()
+                + liquid_staked_balance;
+            env::account_balance().saturating_sub(committed).into()
+        };
+
+        let healthy = state.status != Status::Offline(OfflineReason::StakeActionFailed)
+            && last_stake_action.is_none_or(|outcome| outcome.success)
+            && !(state.status.is_online() && staked_balance_drift < 0);
+
+        Self {
+            healthy,
+            status: state.status,
+            locked_balance,
+            total_staked,
+            staked_balance_drift: staked_balance_drift.into(),
+            pending_earnings: State::contract_managed_total_balance_in_view_mode()
+                .saturating_sub(*state.last_contract_managed_total_balance)
+                .into(),
+            unstaked_liquidity: State::liquidity(),
+            treasury_balance: state.treasury_balance,
+            gas_reserve,
+            last_stake_action,
+            pending_stake_actions: PendingStakeActions::load(),
+        }
+    }
+}
+
+/// Outcome of the last stake action that was submitted to the validator
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakeActionOutcome {
+    pub success: bool,
+    pub epoch_height: EpochHeight,
+    pub block_timestamp: BlockTimestamp,
+}
+
+const STAKE_ACTION_OUTCOME_KEY: u128 = 1957780539417432178163853215394652117;
+type StakeActionOutcomeObject = Object<u128, StakeActionOutcome>;
+
+impl StakeActionOutcome {
+    pub fn load() -> Option<Self> {
+        StakeActionOutcomeObject::load(&STAKE_ACTION_OUTCOME_KEY).map(|outcome| *outcome)
+    }
+
+    /// records the stake action outcome for the current block
+    pub(crate) fn record(success: bool) {
+        StakeActionOutcomeObject::new(
+            STAKE_ACTION_OUTCOME_KEY,
+            Self {
+                success,
+                epoch_height: EpochHeight::from_env(),
+                block_timestamp: BlockTimestamp::from_env(),
+            },
+        )
+        .save();
+    }
+}
+
+/// Latch that is set while stake actions are in flight, i.e., the stake action promise has been
+/// submitted, but its callback has not yet run
+/// - operator commands that change the staking status or the staking public key are rejected while
+///   stake actions are pending, which closes the race condition windows during validator transitions
+/// - stake actions are counted because account stake actions may be in flight concurrently
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct PendingStakeActions {
+    pub count: u32,
+    /// when the last stake action was submitted
+    pub submitted_at: BlockHeight,
+}
+
+const PENDING_STAKE_ACTIONS_KEY: u128 = 1958151025164290837465018273645091827;
+type PendingStakeActionsObject = Object<u128, PendingStakeActions>;
+
+impl PendingStakeActions {
+    /// stake action callbacks run within a few blocks - if the latch has not been released after
+    /// this many blocks since the last stake action was submitted, then the callback is assumed to
+    /// have been lost, e.g., it ran out of gas, and the latch expires
+    pub const EXPIRY_BLOCKS: u64 = 100;
+
+    /// returns None if no stake actions are pending or if the latch has expired
+    pub fn load() -> Option<Self> {
+        PendingStakeActionsObject::load(&PENDING_STAKE_ACTIONS_KEY)
+            .map(|pending| *pending)
+            .filter(|pending| {
+                pending.count > 0
+                    && BlockHeight::from_env() < pending.submitted_at + Self::EXPIRY_BLOCKS
+            })
+    }
+
+    /// must be called when the stake action promise is created
+    pub(crate) fn submitted() {
+        let count = Self::load().map_or(0, |pending| pending.count);
+        PendingStakeActionsObject::new(
+            PENDING_STAKE_ACTIONS_KEY,
+            Self {
+                count: count + 1,
+                submitted_at: BlockHeight::from_env(),
+            },
+        )
+        .save();
+    }
+
+    /// must be called by the stake action callback
+    pub(crate) fn finalized() {
+        match Self::load() {
+            Some(pending) if pending.count > 1 => PendingStakeActionsObject::new(
+                PENDING_STAKE_ACTIONS_KEY,
+                Self {
+                    count: pending.count - 1,
+                    ..pending
+                },
+            )
+            .save(),
+            _ => {
+                PendingStakeActionsObject::delete_by_key(&PENDING_STAKE_ACTIONS_KEY);
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::components::staking_pool::State;
+use oysterpack_smart_account_management::AccountMetrics;
+use oysterpack_smart_contract::ContractNearBalances;
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::asserts::ERR_ILLEGAL_STATE;
+use oysterpack_smart_near::domain::{TGas, YoctoNear};
+use oysterpack_smart_near::near_sdk::env;
+
+/// Staking pool consistency checks, which are run after every contract call when the
+/// `strict-invariants` feature is enabled:
+/// - STAKE supply must be backed by staked NEAR
+/// - the STAKE value must not drop below 1 yoctoNEAR, i.e., the total staked balance must cover the
+///   STAKE supply - STAKE is minted 1:1 and its value only grows as earnings are staked
+/// - the contract NEAR balance must cover the registered account balances plus the NEAR balances
+///   that are tracked by [`ContractNearBalances`], e.g., total staked and unstaked balances
+/// - the liquid contract balance, i.e., excluding the NEAR locked by the validator, must cover the
+///   registered account balances plus the unstaked liquidity, which are withdrawable at any time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StakingPoolInvariants {
+    pub stake_supply: TokenAmount,
+    pub total_staked: YoctoNear,
+    /// contract account balance + locked balance
+    pub contract_balance: YoctoNear,
+    /// contract account balance, which excludes the locked balance
+    pub liquid_balance: YoctoNear,
+    /// unstaked NEAR that is available for withdrawal before it is unlocked - see
+    /// [`State::UNSTAKED_LIQUIDITY_POOL`]
+    pub unstaked_liquidity: YoctoNear,
+    /// total NEAR balance held by registered accounts
+    pub accounts_balance: YoctoNear,
+    /// total of the NEAR balances that are tracked by [`ContractNearBalances`]
+    pub contract_near_balances: YoctoNear,
+}
+
+impl StakingPoolInvariants {
+    /// gas that is reserved for checking the staking pool and STAKE token invariants after the
+    /// contract call, i.e., after any callback gas has been computed from the remaining gas
+    pub const GAS: TGas = TGas(5);
+
+    pub fn load(stake_supply: TokenAmount) -> Self {
+        Self {
+            stake_supply,
+            total_staked: State::total_staked_balance(),
+            contract_balance: (env::account_balance() + env::account_locked_balance()).into(),
+            liquid_balance: env::account_balance().into(),
+            unstaked_liquidity: State::liquidity(),
+            accounts_balance: AccountMetrics::load().total_near_balance,
+            contract_near_balances: ContractNearBalances::load_near_balances()
+                .values()
+                .map(|balance| balance.value())
+                .sum::<u128>()
+                .into(),
+        }
+    }
+
+    /// returns descriptions of the invariants that are violated
+    pub fn violations(&self) -> Vec<&'static str> {
+        let mut violations = vec![];
+        if self.stake_supply > TokenAmount::ZERO && self.total_staked == YoctoNear::ZERO {
+            violations.push("STAKE supply is not backed by staked NEAR");
+        }
+        if *self.total_staked < *self.stake_supply {
+            violations.push("STAKE value is less than 1 yoctoNEAR");
+        }
+        if *self.contract_balance < *self.accounts_balance + *self.contract_near_balances {
+            violations.push("contract balance does not cover account and contract NEAR balances");
+        }
+        if *self.liquid_balance < *self.accounts_balance + *self.unstaked_liquidity {
+            violations
+                .push("liquid balance does not cover account balances and unstaked liquidity");
+        }
+        violations
+    }
+
+    /// ## Panics
+    /// with [`ERR_ILLEGAL_STATE`] and diagnostics if any invariants are violated
+    pub fn assert(&self) {
+        let violations = self.violations();
+        ERR_ILLEGAL_STATE.assert(
+            || violations.is_empty(),
+            || {
+                format!(
+                    "staking pool invariants violated: {:?}: {:?}",
+                    violations, self
+                )
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near::YOCTO;
+    use oysterpack_smart_near_test::*;
+
+    fn invariants() -> StakingPoolInvariants {
+        StakingPoolInvariants {
+            stake_supply: (100 * YOCTO).into(),
+            total_staked: (100 * YOCTO).into(),
+            contract_balance: (110 * YOCTO).into(),
+            liquid_balance: (10 * YOCTO).into(),
+            unstaked_liquidity: (5 * YOCTO).into(),
+            accounts_balance: (5 * YOCTO).into(),
+            contract_near_balances: (105 * YOCTO).into(),
+        }
+    }
+
+    #[test]
+    fn violations() {
+        assert!(invariants().violations().is_empty());
+
+        let mut unbacked_supply = invariants();
+        unbacked_supply.total_staked = YoctoNear::ZERO;
+        assert_eq!(
+            unbacked_supply.violations(),
+            vec![
+                "STAKE supply is not backed by staked NEAR",
+                "STAKE value is less than 1 yoctoNEAR"
+            ]
+        );
+
+        let mut stake_value_below_one = invariants();
+        stake_value_below_one.total_staked = (100 * YOCTO - 1).into();
+        assert_eq!(
+            stake_value_below_one.violations(),
+            vec!["STAKE value is less than 1 yoctoNEAR"]
+        );
+
+        let mut illiquid = invariants();
+        illiquid.liquid_balance = (10 * YOCTO - 1).into();
+        assert_eq!(
+            illiquid.violations(),
+            vec!["liquid balance does not cover account balances and unstaked liquidity"]
+        );
+
+        let mut insufficient_balance = invariants();
+        insufficient_balance.contract_balance = (110 * YOCTO - 1).into();
+        assert_eq!(
+            insufficient_balance.violations(),
+            vec!["contract balance does not cover account and contract NEAR balances"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "staking pool invariants violated")]
+    fn assert_with_violations() {
+        TestCtx::new("bob").apply();
+        let mut invariants = invariants();
+        invariants.total_staked = YoctoNear::ZERO;
+        invariants.assert();
+    }
+}
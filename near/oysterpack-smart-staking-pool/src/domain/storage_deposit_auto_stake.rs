@@ -0,0 +1,25 @@
+use oysterpack_smart_near::data::Object;
+
+/// When enabled, `storage_deposit` amounts above the storage balance minimum are automatically
+/// staked, i.e., the account's storage available balance is staked after the deposit
+/// - can be overridden per call via the `stake` arg
+/// - disabled by default
+pub struct StorageDepositAutoStake;
+
+const STORAGE_DEPOSIT_AUTO_STAKE_KEY: u128 = 1958150872039458210364718293650471823;
+type StorageDepositAutoStakeObject = Object<u128, bool>;
+
+impl StorageDepositAutoStake {
+    pub fn enabled() -> bool {
+        StorageDepositAutoStakeObject::load(&STORAGE_DEPOSIT_AUTO_STAKE_KEY)
+            .is_some_and(|enabled| *enabled)
+    }
+
+    pub(crate) fn set_enabled(enabled: bool) {
+        if enabled {
+            StorageDepositAutoStakeObject::new(STORAGE_DEPOSIT_AUTO_STAKE_KEY, true).save();
+        } else {
+            StorageDepositAutoStakeObject::delete_by_key(&STORAGE_DEPOSIT_AUTO_STAKE_KEY);
+        }
+    }
+}
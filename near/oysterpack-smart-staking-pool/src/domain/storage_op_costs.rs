@@ -0,0 +1,77 @@
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::{StorageUsage, YoctoNear};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+use std::ops::Deref;
+
+/// Account operations that allocate contract storage, which must be covered by the account's
+/// storage balance
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub enum StorageOpKind {
+    /// registering the account via `storage_deposit` - the storage balance bounds min
+    RegisterAccount,
+    /// the account's first stake, which records the account activity
+    /// - the STAKE balance storage is covered by the account storage usage bounds min
+    FirstStake,
+    /// the account's first unstake, which stores the account's unstaked balances and indexes the
+    /// account for withdrawal
+    FirstUnstake,
+}
+
+/// Projected storage usage for a [`StorageOpKind`]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StorageCost {
+    /// storage usage in bytes
+    pub storage_usage: StorageUsage,
+    /// NEAR cost of the storage usage based on the current storage byte cost
+    pub near: YoctoNear,
+}
+
+impl From<StorageUsage> for StorageCost {
+    fn from(storage_usage: StorageUsage) -> Self {
+        Self {
+            storage_usage,
+            near: storage_usage.cost(),
+        }
+    }
+}
+
+/// Storage usage that is allocated by account operations on top of the account's storage usage
+/// bounds min
+/// - measured when the staking pool is deployed, because storage cannot be written in view calls
+/// - pools that are upgraded from a version that predates the measurements must deploy them via
+///   [`StakingPoolOperatorCommand::MeasureStorageOpCosts`](crate::StakingPoolOperatorCommand::MeasureStorageOpCosts)
+/// - measured using a max length account ID, i.e., the measurements are upper bounds
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct StorageOpCosts {
+    pub first_stake: StorageUsage,
+    pub first_unstake: StorageUsage,
+}
+
+const STORAGE_OP_COSTS_KEY: u128 = 1958502739617420385120968375192843057;
+
+type StorageOpCostsObject = Object<u128, StorageOpCosts>;
+
+impl StorageOpCosts {
+    /// account ID that is used to measure storage usage - NEAR account IDs are max 64 bytes
+    pub const MEASUREMENT_ACCOUNT_ID: &'static str =
+        "1958502784530816972318853104623950172.storage-op-costs.near.pool";
+
+    pub fn load() -> Option<Self> {
+        StorageOpCostsObject::load(&STORAGE_OP_COSTS_KEY).map(|costs| *costs.deref())
+    }
+
+    pub(crate) fn save(&self) {
+        StorageOpCostsObject::new(STORAGE_OP_COSTS_KEY, *self).save();
+    }
+
+    /// simulates a pool that was upgraded from a version that predates the measurements
+    #[cfg(test)]
+    pub(crate) fn clear() {
+        StorageOpCostsObject::delete_by_key(&STORAGE_OP_COSTS_KEY);
+    }
+}
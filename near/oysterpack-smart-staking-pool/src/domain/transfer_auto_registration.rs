@@ -0,0 +1,25 @@
+use oysterpack_smart_near::data::Object;
+
+/// When enabled, STAKE transfers to unregistered receivers auto-register the receiver
+/// - the receiver's account storage is paid for by deducting the storage balance minimum NEAR
+///   value from the transferred STAKE
+/// - disabled by default, i.e., transfers to unregistered receivers fail
+pub struct TransferAutoRegistration;
+
+const TRANSFER_AUTO_REGISTRATION_KEY: u128 = 1958004718230565932174859126338170522;
+type TransferAutoRegistrationObject = Object<u128, bool>;
+
+impl TransferAutoRegistration {
+    pub fn enabled() -> bool {
+        TransferAutoRegistrationObject::load(&TRANSFER_AUTO_REGISTRATION_KEY)
+            .is_some_and(|enabled| *enabled)
+    }
+
+    pub(crate) fn set_enabled(enabled: bool) {
+        if enabled {
+            TransferAutoRegistrationObject::new(TRANSFER_AUTO_REGISTRATION_KEY, true).save();
+        } else {
+            TransferAutoRegistrationObject::delete_by_key(&TRANSFER_AUTO_REGISTRATION_KEY);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::{BasisPoints, Gas};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::TERA;
+
+/// External contract, e.g., a DAO or charity, that receives a share of the treasury dividends
+/// - the beneficiary share of the dividend is transferred to the beneficiary via the
+///   [`oysterpack_smart_fungible_token::FungibleToken::ft_transfer_call`] protocol
+/// - the rest of the dividend is burned
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct TreasuryBeneficiary {
+    pub account_id: AccountId,
+    /// share of the treasury dividend that is routed to the beneficiary
+    pub bps: BasisPoints,
+}
+
+const TREASURY_BENEFICIARY_KEY: u128 = 1957803946581309725140318760452881024;
+type TreasuryBeneficiaryObject = Object<u128, TreasuryBeneficiary>;
+
+impl TreasuryBeneficiary {
+    /// 100%
+    pub const MAX_BPS: BasisPoints = BasisPoints(10000);
+
+    /// gas that is allotted to the beneficiary `ft_on_transfer` call
+    pub const FT_ON_TRANSFER_GAS: Gas = Gas(10 * TERA);
+
+    pub fn load() -> Option<Self> {
+        TreasuryBeneficiaryObject::load(&TREASURY_BENEFICIARY_KEY)
+            .map(|beneficiary| (*beneficiary).clone())
+    }
+
+    pub(crate) fn save(self) {
+        TreasuryBeneficiaryObject::new(TREASURY_BENEFICIARY_KEY, self).save();
+    }
+
+    pub(crate) fn delete() {
+        TreasuryBeneficiaryObject::delete_by_key(&TREASURY_BENEFICIARY_KEY);
+    }
+
+    /// computes the beneficiary share of the specified treasury dividend STAKE amount, rounded down
+    pub fn share(&self, dividend: TokenAmount) -> TokenAmount {
+        (*(self.bps * *dividend)).into()
+    }
+}
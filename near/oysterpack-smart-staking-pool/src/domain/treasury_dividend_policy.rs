@@ -0,0 +1,69 @@
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::BasisPoints;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+use std::fmt::{self, Display, Formatter};
+
+/// Specifies how much of the treasury staking earnings is paid out as a dividend to STAKE owners,
+/// i.e., burned, vs retained by the treasury to build reserves
+/// - the [`crate::TreasuryBeneficiary`] share is taken from the dividend that is paid out
+#[derive(
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub enum TreasuryDividendPolicy {
+    /// all treasury staking earnings are paid out as a dividend
+    #[default]
+    BurnAll,
+    /// the specified percentage of the treasury staking earnings is paid out as a dividend, and the
+    /// rest is retained by the treasury
+    BurnPercent(BasisPoints),
+    /// all treasury staking earnings are retained by the treasury
+    Retain,
+}
+
+impl Display for TreasuryDividendPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+const TREASURY_DIVIDEND_POLICY_KEY: u128 = 1958151023417795201846372019483756201;
+type TreasuryDividendPolicyObject = Object<u128, TreasuryDividendPolicy>;
+
+impl TreasuryDividendPolicy {
+    /// 100%
+    pub const MAX_BPS: BasisPoints = BasisPoints(10000);
+
+    /// defaults to [`TreasuryDividendPolicy::BurnAll`]
+    pub fn load() -> Self {
+        TreasuryDividendPolicyObject::load(&TREASURY_DIVIDEND_POLICY_KEY)
+            .map_or_else(Self::default, |policy| *policy)
+    }
+
+    pub(crate) fn save(self) {
+        TreasuryDividendPolicyObject::new(TREASURY_DIVIDEND_POLICY_KEY, self).save();
+    }
+
+    /// computes the dividend that is paid out from the specified treasury staking earnings STAKE
+    /// amount, rounded down
+    pub fn dividend(&self, treasury_staking_earnings: TokenAmount) -> TokenAmount {
+        match self {
+            Self::BurnAll => treasury_staking_earnings,
+            Self::BurnPercent(bps) => (*(*bps * *treasury_staking_earnings)).into(),
+            Self::Retain => TokenAmount::ZERO,
+        }
+    }
+}
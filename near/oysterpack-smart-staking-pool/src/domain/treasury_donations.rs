@@ -0,0 +1,82 @@
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::{ComponentStorageUsage, Object};
+use oysterpack_smart_near::domain::{BlockHeight, YoctoNear};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+/// STAKE that was donated to the treasury via `ft_transfer_call` - see [`crate::TREASURY_DEPOSIT_MSG`]
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct TreasuryDonation {
+    pub donor: AccountId,
+    pub amount: TokenAmount,
+    /// STAKE NEAR value at the time of the donation
+    pub near_value: YoctoNear,
+    pub block_height: BlockHeight,
+}
+
+/// Totals for all treasury donations
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct TreasuryDonationTotals {
+    pub amount: TokenAmount,
+    /// sum of the STAKE NEAR values at the time of each donation
+    pub near_value: YoctoNear,
+}
+
+/// Attribution log for treasury donations
+/// - only the most recent [`TreasuryDonations::MAX_ENTRIES`] donations are retained, which bounds
+///   the contract storage that is used by the log
+/// - the totals cover all donations
+pub struct TreasuryDonations;
+
+const TREASURY_DONATIONS_KEY: u128 = 1958646377285040160215376218904528159;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+struct TreasuryDonationLog {
+    totals: TreasuryDonationTotals,
+    donations: Vec<TreasuryDonation>,
+}
+
+type TreasuryDonationLogObject = Object<u128, TreasuryDonationLog>;
+
+impl TreasuryDonations {
+    pub const MAX_ENTRIES: usize = 100;
+
+    /// returns up to `limit` donations ordered from most recent to oldest
+    pub fn most_recent(limit: usize) -> Vec<TreasuryDonation> {
+        Self::load()
+            .donations
+            .into_iter()
+            .rev()
+            .take(limit)
+            .collect()
+    }
+
+    pub fn totals() -> TreasuryDonationTotals {
+        Self::load().totals
+    }
+
+    pub(crate) fn record(donation: TreasuryDonation) {
+        let mut log = Self::load();
+        log.totals.amount += donation.amount;
+        log.totals.near_value += donation.near_value;
+        if log.donations.len() >= Self::MAX_ENTRIES {
+            log.donations.remove(0);
+        }
+        log.donations.push(donation);
+        ComponentStorageUsage::track(ComponentStorageUsage::STAKING, || {
+            TreasuryDonationLogObject::new(TREASURY_DONATIONS_KEY, log).save();
+        });
+    }
+
+    fn load() -> TreasuryDonationLog {
+        TreasuryDonationLogObject::load(&TREASURY_DONATIONS_KEY)
+            .map_or_else(TreasuryDonationLog::default, |log| (*log).clone())
+    }
+}
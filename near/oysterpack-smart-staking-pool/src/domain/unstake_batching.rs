@@ -0,0 +1,181 @@
+use oysterpack_smart_near::data::{ComponentStorageUsage, Object};
+use oysterpack_smart_near::domain::{EpochHeight, YoctoNear};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// Unstaked NEAR that is waiting to be unstaked from the validator
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct UnstakeBatch {
+    /// epoch of the first unstake in the batch
+    pub epoch_height: EpochHeight,
+    /// total NEAR that was unstaked
+    pub amount: YoctoNear,
+    /// number of unstake requests in the batch
+    pub unstakes: u32,
+}
+
+/// NEAR that was unstaked by an account within an epoch and added to the batch
+/// - used to relock the account's unstaked NEAR when the batch is submitted in a later epoch
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct UnstakeBatchEntry {
+    pub account_id: AccountId,
+    /// epoch when the NEAR was unstaked, which determined its unlock epoch
+    pub epoch_height: EpochHeight,
+    pub amount: YoctoNear,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct UnstakeBatchingSummary {
+    pub enabled: bool,
+    /// epoch when the batch was last processed via [`crate::StakingPool::ops_stake_process_epoch`]
+    pub last_processed_epoch: Option<EpochHeight>,
+    pub batch: Option<UnstakeBatch>,
+}
+
+/// Aggregates unstake requests, which are then unstaked from the validator via a single stake
+/// action at most once per epoch
+/// - the batch is processed by the [`crate::StakingPool::ops_stake_process_epoch`] keeper
+/// - any stake action that is submitted in the meantime, e.g., for a stake deposit, stakes the
+///   lowered total staked balance and clears the batch
+/// - the validator unlocks the NEAR based on the epoch in which the batch is submitted - thus, if
+///   the batch is submitted in a later epoch, then the NEAR that was unstaked in earlier epochs is
+///   relocked, i.e., its unlock epoch is computed from the epoch in which the batch is submitted
+/// - the batch is capped at [`UnstakeBatching::MAX_UNSTAKES`] - once the batch is full, the
+///   stake action is submitted for the next unstake
+/// - disabled by default
+pub struct UnstakeBatching;
+
+const UNSTAKE_BATCHING_ENABLED_KEY: u128 = 1958817502946183720587461027394851627;
+const UNSTAKE_BATCH_KEY: u128 = 1958817502946183720587461027394851628;
+const UNSTAKE_BATCH_LAST_PROCESSED_EPOCH_KEY: u128 = 1958817502946183720587461027394851629;
+const UNSTAKE_BATCH_ENTRIES_KEY: u128 = 1958817502946183720587461027394851630;
+
+type UnstakeBatchingEnabledObject = Object<u128, bool>;
+type UnstakeBatchObject = Object<u128, UnstakeBatch>;
+type UnstakeBatchEpochObject = Object<u128, EpochHeight>;
+type UnstakeBatchEntriesObject = Object<u128, Vec<UnstakeBatchEntry>>;
+
+impl UnstakeBatching {
+    /// max number of unstake requests per batch, which bounds the storage and the gas required
+    /// to submit the batch
+    pub const MAX_UNSTAKES: u32 = 100;
+
+    pub fn enabled() -> bool {
+        UnstakeBatchingEnabledObject::load(&UNSTAKE_BATCHING_ENABLED_KEY)
+            .is_some_and(|enabled| *enabled)
+    }
+
+    /// disabling batching does not clear the pending batch - it is unstaked on the next stake
+    /// action
+    pub(crate) fn set_enabled(enabled: bool) {
+        if enabled {
+            UnstakeBatchingEnabledObject::new(UNSTAKE_BATCHING_ENABLED_KEY, true).save();
+        } else {
+            UnstakeBatchingEnabledObject::delete_by_key(&UNSTAKE_BATCHING_ENABLED_KEY);
+        }
+    }
+
+    pub fn batch() -> Option<UnstakeBatch> {
+        UnstakeBatchObject::load(&UNSTAKE_BATCH_KEY).map(|batch| *batch)
+    }
+
+    pub fn last_processed_epoch() -> Option<EpochHeight> {
+        UnstakeBatchEpochObject::load(&UNSTAKE_BATCH_LAST_PROCESSED_EPOCH_KEY).map(|epoch| *epoch)
+    }
+
+    pub fn summary() -> UnstakeBatchingSummary {
+        UnstakeBatchingSummary {
+            enabled: Self::enabled(),
+            last_processed_epoch: Self::last_processed_epoch(),
+            batch: Self::batch(),
+        }
+    }
+
+    /// returns true if the batch has already been processed within the current epoch
+    pub fn processed_this_epoch() -> bool {
+        Self::last_processed_epoch().is_some_and(|epoch| epoch == EpochHeight::from_env())
+    }
+
+    /// returns true if the batch has reached [`UnstakeBatching::MAX_UNSTAKES`]
+    pub fn is_full() -> bool {
+        Self::batch().is_some_and(|batch| batch.unstakes >= Self::MAX_UNSTAKES)
+    }
+
+    pub(crate) fn entries() -> Vec<UnstakeBatchEntry> {
+        UnstakeBatchEntriesObject::load(&UNSTAKE_BATCH_ENTRIES_KEY)
+            .map_or_else(Vec::new, |entries| (*entries).clone())
+    }
+
+    /// adds the account's unstaked NEAR to the pending batch
+    pub(crate) fn add(account_id: &str, amount: YoctoNear) -> UnstakeBatch {
+        let batch = match Self::batch() {
+            Some(batch) => UnstakeBatch {
+                amount: batch.amount + amount,
+                unstakes: batch.unstakes + 1,
+                ..batch
+            },
+            None => UnstakeBatch {
+                epoch_height: EpochHeight::from_env(),
+                amount,
+                unstakes: 1,
+            },
+        };
+        let epoch_height = EpochHeight::from_env();
+        let mut entries = Self::entries();
+        match entries
+            .iter_mut()
+            .find(|entry| entry.account_id == account_id && entry.epoch_height == epoch_height)
+        {
+            Some(entry) => entry.amount += amount,
+            None => entries.push(UnstakeBatchEntry {
+                account_id: account_id.to_string(),
+                epoch_height,
+                amount,
+            }),
+        }
+        ComponentStorageUsage::track(ComponentStorageUsage::STAKING, || {
+            UnstakeBatchObject::new(UNSTAKE_BATCH_KEY, batch).save();
+            UnstakeBatchEntriesObject::new(UNSTAKE_BATCH_ENTRIES_KEY, entries).save();
+        });
+        batch
+    }
+
+    /// clears the pending batch, which is called when a stake action is submitted
+    ///
+    /// Returns the batch entries, which need to be relocked if they were unstaked in an earlier epoch
+    pub(crate) fn take() -> Vec<UnstakeBatchEntry> {
+        if Self::batch().is_none() {
+            return vec![];
+        }
+        let entries = Self::entries();
+        ComponentStorageUsage::track(ComponentStorageUsage::STAKING, || {
+            UnstakeBatchObject::delete_by_key(&UNSTAKE_BATCH_KEY);
+            UnstakeBatchEntriesObject::delete_by_key(&UNSTAKE_BATCH_ENTRIES_KEY);
+        });
+        entries
+    }
+
+    pub(crate) fn set_last_processed_epoch() {
+        ComponentStorageUsage::track(ComponentStorageUsage::STAKING, || {
+            UnstakeBatchEpochObject::new(
+                UNSTAKE_BATCH_LAST_PROCESSED_EPOCH_KEY,
+                EpochHeight::from_env(),
+            )
+            .save();
+        });
+    }
+}
+
+/// log message format: `amount={amount}, batch_amount={batch_amount}, unstakes={unstakes}`
+pub const LOG_EVENT_UNSTAKE_BATCHED: LogEvent = LogEvent(Level::INFO, "UNSTAKE_BATCHED");
+/// log message format: `keeper={keeper}, epoch_height={epoch_height}, amount={amount}, unstakes={unstakes}`
+pub const LOG_EVENT_UNSTAKE_BATCH_PROCESSED: LogEvent =
+    LogEvent(Level::INFO, "UNSTAKE_BATCH_PROCESSED");
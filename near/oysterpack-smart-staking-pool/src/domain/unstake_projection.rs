@@ -0,0 +1,25 @@
+use oysterpack_smart_near::domain::{BlockTimestamp, EpochHeight, YoctoNear};
+use oysterpack_smart_near::near_sdk::serde::{Deserialize, Serialize};
+
+/// Approximate epoch duration, which is used to estimate when unstaked NEAR will become available
+/// - mainnet epochs are 43,200 blocks, which is approximately 12 hours
+pub const APPROX_EPOCH_DURATION_NANOS: u64 = 12 * 60 * 60 * 1_000_000_000;
+
+/// Projects when the unstaked NEAR amount would become available for withdrawal, if it were unstaked
+/// in the current epoch
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct UnstakeProjection {
+    /// NEAR amount to unstake
+    pub amount: YoctoNear,
+    /// portion of the amount that is available for withdrawal immediately via the unstaked liquidity pool
+    pub available_via_liquidity: YoctoNear,
+    /// portion of the amount that remains locked until [`UnstakeProjection::available_on_epoch`]
+    pub locked: YoctoNear,
+    /// epoch height when the full amount becomes available for withdrawal
+    /// - if there is no locked balance, then this is the current epoch
+    pub available_on_epoch: EpochHeight,
+    /// approximate timestamp when the full amount becomes available for withdrawal - based on
+    /// [`APPROX_EPOCH_DURATION_NANOS`]
+    pub available_on_timestamp: BlockTimestamp,
+}
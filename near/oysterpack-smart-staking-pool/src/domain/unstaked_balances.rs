@@ -1,16 +1,21 @@
 use crate::components::staking_pool::State;
-use oysterpack_smart_near::asserts::ERR_INSUFFICIENT_FUNDS;
+use crate::{LiquidityPool, ERR_UNSTAKED_LOCK_SLOTS_FULL};
+use oysterpack_smart_account_management::AccountStorageEvent;
+use oysterpack_smart_near::asserts::{assert_sufficient_funds, ERR_INVALID};
+use oysterpack_smart_near::data::{numbers::U256, Object};
 use oysterpack_smart_near::domain::{EpochHeight, YoctoNear};
+use oysterpack_smart_near::eventbus::post;
 use oysterpack_smart_near::near_sdk::{
     borsh::{self, BorshDeserialize, BorshSerialize},
     env,
 };
-use std::cmp::Ordering;
+use oysterpack_smart_near::{Hash, YOCTO};
+use std::cmp::{min, Ordering};
 use std::collections::BTreeMap;
 
 /// unstaked NEAR is locked for 4 epochs before being able to be withdrawn
 /// https://github.com/near/nearcore/blob/037954e087fd5c8a65598ede502495530c73f835/chain/epoch_manager/src/lib.rs#L815
-const EPOCHS_LOCKED: usize = 4;
+pub(crate) const EPOCHS_LOCKED: usize = 4;
 
 #[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy, PartialEq, Default)]
 pub struct UnstakedBalances {
@@ -50,8 +55,37 @@ impl UnstakedBalances {
         }
     }
 
+    /// restores unstaked balances that were exported from another staking pool contract
+    ///
+    /// ## Panics
+    /// if there are more locked balances than the number of epochs that unstaked NEAR is locked
+    pub(crate) fn restore(
+        available: YoctoNear,
+        locked: Option<BTreeMap<EpochHeight, YoctoNear>>,
+    ) -> Self {
+        let mut balances = Self {
+            available,
+            ..Self::default()
+        };
+        let locked = locked.unwrap_or_default();
+        ERR_INVALID.assert(
+            || locked.len() <= EPOCHS_LOCKED,
+            || "too many locked unstaked balances",
+        );
+        for (i, (epoch, amount)) in locked.into_iter().enumerate() {
+            balances.locked[i] = (epoch, amount);
+        }
+        balances.unlock();
+        balances
+    }
+
+    /// returns the epoch when NEAR that is unstaked in the current epoch will be unlocked
+    pub fn unlock_epoch() -> EpochHeight {
+        EpochHeight::from_env() + EPOCHS_LOCKED as u64
+    }
+
     pub(crate) fn unlock(&mut self) {
-        let current_epoch: EpochHeight = env::epoch_height().into();
+        let current_epoch = EpochHeight::from_env();
 
         for i in 0..EPOCHS_LOCKED {
             let (epoch, balance) = self.locked[i];
@@ -64,22 +98,50 @@ impl UnstakedBalances {
         }
     }
 
-    /// If there are locked balances then try to use liquidity to unlock the funds for withdrawal.
+    /// If there are locked balances then try to use the account's liquidity claim to unlock the
+    /// funds for withdrawal - see [`LiquidityClaim`]
+    /// - the liquidity that is applied is debited from the account's liquidity claim and released
+    ///   from the liquidity pool - see [`State::release_liquidity`]
     ///
     /// returns the amount of liquidity that was applied
-    pub(crate) fn apply_liquidity(&mut self) -> YoctoNear {
+    pub(crate) fn apply_liquidity(&mut self, account_id: &str) -> YoctoNear {
         self.unlock();
-        let locked_balance = self.locked_balance();
-        if locked_balance == YoctoNear::ZERO {
-            return YoctoNear::ZERO;
-        }
+        let mut claim = LiquidityClaim::accrue(account_id, self);
+        let liquidity = self.apply_claim(min(claim.claim, State::liquidity()));
+        claim.claim -= liquidity;
+        claim.save(account_id);
+        State::release_liquidity(liquidity);
+        liquidity
+    }
+
+    /// Applies the account's liquidity claim without debiting it, i.e., it is used to preview the
+    /// balances in view mode
+    ///
+    /// returns the amount of liquidity that would be applied
+    pub(crate) fn preview_liquidity(&mut self, account_id: &str) -> YoctoNear {
+        self.unlock();
+        self.apply_claim(self.liquidity_claim(account_id))
+    }
+
+    /// Returns the liquidity that can be claimed by the account, which is limited to the liquidity
+    /// that is currently available - see [`LiquidityClaim`]
+    pub fn liquidity_claim(&self, account_id: &str) -> YoctoNear {
+        min(
+            LiquidityClaim::accrue(account_id, self).claim,
+            State::liquidity(),
+        )
+    }
 
-        let liquidity = State::liquidity();
-        if liquidity == YoctoNear::ZERO {
+    /// unlocks up to the specified amount of locked balances
+    ///
+    /// returns the amount that was unlocked
+    fn apply_claim(&mut self, claim: YoctoNear) -> YoctoNear {
+        let locked_balance = self.locked_balance();
+        if locked_balance == YoctoNear::ZERO || claim == YoctoNear::ZERO {
             return YoctoNear::ZERO;
         }
 
-        if liquidity >= locked_balance {
+        if claim >= locked_balance {
             self.available = self.total();
             for i in 0..EPOCHS_LOCKED {
                 self.locked[i] = Default::default();
@@ -87,15 +149,15 @@ impl UnstakedBalances {
             return locked_balance;
         }
 
-        self.available += liquidity;
-        self.debit_from_locked(liquidity);
-        liquidity
+        self.available += claim;
+        self.debit_from_locked(claim);
+        claim
     }
 
     /// adds the unstaked balance and locks it up for 4 epochs
     pub(crate) fn credit_unstaked(&mut self, amount: YoctoNear) {
         self.unlock();
-        let available_on: EpochHeight = (env::epoch_height() + EPOCHS_LOCKED as u64).into();
+        let available_on = Self::unlock_epoch();
         for i in 0..EPOCHS_LOCKED {
             let (epoch, balance) = self.locked[i];
             if balance > YoctoNear::ZERO {
@@ -116,6 +178,35 @@ impl UnstakedBalances {
         unreachable!()
     }
 
+    /// Relocks unstaked NEAR that was scheduled to unlock in `unlock_epoch`, i.e., the NEAR is
+    /// locked for another 4 epochs starting from the current epoch
+    /// - used when the NEAR is unstaked from the validator in a later epoch than it was credited
+    /// - if the NEAR was already unlocked, then it is relocked from the available balance
+    /// - NEAR that has since been debited, e.g., withdrawn or transferred, cannot be relocked
+    pub(crate) fn relock(&mut self, amount: YoctoNear, unlock_epoch: EpochHeight) {
+        self.unlock();
+        let mut relocked = YoctoNear::ZERO;
+        if let Some(i) = self
+            .locked
+            .iter()
+            .position(|(epoch, balance)| *balance > YoctoNear::ZERO && *epoch == unlock_epoch)
+        {
+            let (epoch, balance) = self.locked[i];
+            relocked = balance.min(amount);
+            self.locked[i] = if relocked == balance {
+                Default::default()
+            } else {
+                (epoch, balance - relocked)
+            };
+        }
+        let from_available = self.available.min(amount - relocked);
+        self.available -= from_available;
+        relocked += from_available;
+        if relocked > YoctoNear::ZERO {
+            self.credit_unstaked(relocked);
+        }
+    }
+
     fn sort_locked(&mut self) {
         self.locked.sort_by(|left, right| {
             if left.1 == YoctoNear::ZERO && right.1 == YoctoNear::ZERO {
@@ -140,13 +231,13 @@ impl UnstakedBalances {
     /// if there are insufficient funds
     pub(crate) fn debit_available_balance(&mut self, amount: YoctoNear) {
         self.unlock();
-        ERR_INSUFFICIENT_FUNDS.assert(|| self.available >= amount);
+        assert_sufficient_funds(amount, self.available);
         self.available -= amount;
     }
 
     pub(crate) fn debit_for_restaking(&mut self, amount: YoctoNear) {
         let total = self.total();
-        ERR_INSUFFICIENT_FUNDS.assert(|| total >= amount);
+        assert_sufficient_funds(amount, total);
 
         if total == self.available {
             self.available -= amount;
@@ -157,6 +248,75 @@ impl UnstakedBalances {
         self.available -= remainder;
     }
 
+    /// Debits the specified amount for transfer to another account.
+    /// - the available balance is debited first, and then the locked balances in unlock epoch order
+    ///
+    /// Returns the debited balances, which preserve the unlock epochs
+    ///
+    /// ## Panics
+    /// if there are insufficient funds
+    pub(crate) fn debit_for_transfer(&mut self, amount: YoctoNear) -> UnstakedBalances {
+        self.unlock();
+        assert_sufficient_funds(amount, self.total());
+
+        let mut transfer = UnstakedBalances {
+            available: amount.min(self.available),
+            ..Self::default()
+        };
+        self.available -= transfer.available;
+        let mut amount = amount - transfer.available;
+
+        self.sort_locked();
+        for i in 0..EPOCHS_LOCKED {
+            if amount == YoctoNear::ZERO {
+                break;
+            }
+            let (available_on, unstaked) = self.locked[i];
+            if unstaked > YoctoNear::ZERO {
+                let debit = unstaked.min(amount);
+                self.locked[i] = if debit == unstaked {
+                    Default::default()
+                } else {
+                    (available_on, unstaked - debit)
+                };
+                transfer.locked[i] = (available_on, debit);
+                amount -= debit;
+            }
+        }
+        transfer
+    }
+
+    /// Credits the unstaked balances that were transferred from another account - see [`Self::debit_for_transfer`]
+    /// - the unlock epochs are preserved
+    pub(crate) fn credit_transfer(&mut self, transfer: UnstakedBalances) {
+        self.unlock();
+        self.available += transfer.available;
+        // because unstaked NEAR is locked for at most 4 epochs, the locked balances for both accounts
+        // fall within the same 4 epochs
+        for (available_on, amount) in transfer.locked.iter() {
+            if *amount == YoctoNear::ZERO {
+                continue;
+            }
+            let slot = match self
+                .locked
+                .iter()
+                .position(|(epoch, balance)| *balance > YoctoNear::ZERO && epoch == available_on)
+                .or_else(|| {
+                    self.locked
+                        .iter()
+                        .position(|(_, balance)| *balance == YoctoNear::ZERO)
+                }) {
+                Some(slot) => slot,
+                None => {
+                    ERR_UNSTAKED_LOCK_SLOTS_FULL.panic();
+                    unreachable!()
+                }
+            };
+            let (_, balance) = self.locked[slot];
+            self.locked[slot] = (*available_on, balance + *amount);
+        }
+    }
+
     fn debit_from_locked(&mut self, mut amount: YoctoNear) -> YoctoNear {
         self.sort_locked();
 
@@ -182,26 +342,151 @@ impl UnstakedBalances {
     }
 }
 
+const LIQUIDITY_PER_SHARE_KEY: u128 = 1958290374615820394718263549018273645;
+const LIQUIDITY_CLAIM_KEY: u128 = 1958290374615820394718263549018273646;
+
+type LiquidityPerShareObject = Object<u128, u128>;
+type LiquidityClaimObject = Object<Hash, LiquidityClaim>;
+
+/// Account's claim on the liquidity in [`State::UNSTAKED_LIQUIDITY_POOL`]
+///
+/// When liquidity is added, it is granted to all accounts proportionally to their share of the
+/// total unstaked NEAR. This prevents early large withdrawers from draining the liquidity that is
+/// meant for all accounts that have unstaked NEAR.
+/// - granted liquidity is tracked as the cumulative liquidity per unstaked NEAR, which is
+///   checkpointed per account before the account's unstaked balance changes
+/// - the claim is debited when it is applied to unlock the account's unstaked NEAR
+/// - if the account has no record, then its claim is accrued from when liquidity claims were first
+///   tracked, i.e., the account's unstaked balance has not changed since then
+///
+/// The claim record is stored separately from the account's [`crate::StakeAccountData`], but its
+/// storage usage is charged to the account.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct LiquidityClaim {
+    /// liquidity per share when the account's claim was last checkpointed
+    liquidity_per_share: u128,
+    /// liquidity that has been granted to the account, but not yet applied
+    pub claim: YoctoNear,
+}
+
+impl LiquidityClaim {
+    pub const PRECISION: u128 = YOCTO;
+
+    /// cumulative liquidity that has been granted per unstaked NEAR, scaled by [`Self::PRECISION`]
+    pub fn liquidity_per_share() -> u128 {
+        LiquidityPerShareObject::load(&LIQUIDITY_PER_SHARE_KEY).map_or(0, |value| *value)
+    }
+
+    /// grants the liquidity across the total unstaked NEAR that is owned by all accounts
+    pub(crate) fn grant(liquidity: YoctoNear, total_unstaked_balance: YoctoNear) {
+        if liquidity == YoctoNear::ZERO || total_unstaked_balance == YoctoNear::ZERO {
+            return;
+        }
+        let increment = (U256::from(*liquidity) * U256::from(Self::PRECISION)
+            / U256::from(*total_unstaked_balance))
+        .as_u128();
+        LiquidityPerShareObject::new(
+            LIQUIDITY_PER_SHARE_KEY,
+            Self::liquidity_per_share() + increment,
+        )
+        .save();
+    }
+
+    fn object_key(account_id: &str) -> Hash {
+        Hash::from((account_id, LIQUIDITY_CLAIM_KEY))
+    }
+
+    pub fn load(account_id: &str) -> Option<LiquidityClaim> {
+        LiquidityClaimObject::load(&Self::object_key(account_id)).map(|object| *object)
+    }
+
+    /// accrues the liquidity that was granted on the account's unstaked balance since the account
+    /// was last checkpointed, rounded down
+    /// - the unstaked balance must be the balance before it is changed
+    /// - the claim is limited to the account's locked balance because liquidity is only used to
+    ///   unlock NEAR, i.e., the claim on NEAR that has since been unlocked is forfeited
+    pub fn accrue(account_id: &str, unstaked_balances: &UnstakedBalances) -> LiquidityClaim {
+        let mut claim = Self::load(account_id).unwrap_or_default();
+        let liquidity_per_share = Self::liquidity_per_share();
+        let growth = liquidity_per_share - claim.liquidity_per_share;
+        let liquidity = (U256::from(*unstaked_balances.total()) * U256::from(growth)
+            / U256::from(Self::PRECISION))
+        .as_u128();
+        let mut unstaked_balances = *unstaked_balances;
+        unstaked_balances.unlock();
+        claim.claim = min(
+            claim.claim + YoctoNear(liquidity),
+            unstaked_balances.locked_balance(),
+        );
+        claim.liquidity_per_share = liquidity_per_share;
+        claim
+    }
+
+    /// checkpoints the account's liquidity claim - must be called before the account's unstaked
+    /// balance changes
+    pub(crate) fn checkpoint(account_id: &str, unstaked_balances: &UnstakedBalances) {
+        Self::accrue(account_id, unstaked_balances).save(account_id);
+    }
+
+    /// if the claim is the default, then the record is not needed because no record implies the
+    /// default claim
+    /// - tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn save(&self, account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        if *self == Self::default() {
+            LiquidityClaimObject::delete_by_key(&Self::object_key(account_id));
+        } else {
+            LiquidityClaimObject::new(Self::object_key(account_id), *self).save();
+        }
+        Self::post_storage_usage_change(account_id, initial_storage_usage);
+    }
+
+    /// deletes the claim record if the account has no unstaked NEAR and no unapplied claim
+    /// - tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn delete_if_empty(account_id: &str, unstaked_balances: &UnstakedBalances) {
+        if unstaked_balances.total() > YoctoNear::ZERO
+            || Self::accrue(account_id, unstaked_balances).claim > YoctoNear::ZERO
+        {
+            return;
+        }
+        let initial_storage_usage = env::storage_usage();
+        LiquidityClaimObject::delete_by_key(&Self::object_key(account_id));
+        Self::post_storage_usage_change(account_id, initial_storage_usage);
+    }
+
+    fn post_storage_usage_change(account_id: &str, initial_storage_usage: u64) {
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        // the liquidity pool's storage is owned by the contract
+        if storage_usage_change != 0 && account_id != LiquidityPool::LIQUIDITY_CLAIM_ID {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use oysterpack_smart_contract::ContractNearBalances;
     use oysterpack_smart_near::domain::YoctoNear;
     use oysterpack_smart_near::YOCTO;
     use oysterpack_smart_near_test::*;
 
     #[test]
     fn unlock() {
-        let mut ctx = new_context("bob");
+        let mut ctx = TestCtx::new("bob");
 
-        ctx.epoch_height = 100;
-        testing_env!(ctx.clone());
+        ctx.epoch_height(100).apply();
 
         let mut unstaked_balances = UnstakedBalances::default();
         unstaked_balances.credit_unstaked(YOCTO.into());
         unstaked_balances.credit_unstaked(YOCTO.into());
 
         assert_eq!(unstaked_balances.available, YoctoNear::ZERO);
-        let expected_available_on: EpochHeight = (ctx.epoch_height + EPOCHS_LOCKED as u64).into();
+        let expected_available_on: EpochHeight =
+            (ctx.context().epoch_height + EPOCHS_LOCKED as u64).into();
         assert_eq!(
             *unstaked_balances
                 .locked()
@@ -212,8 +497,7 @@ mod tests {
         );
         assert_eq!(*unstaked_balances.total(), 2 * YOCTO);
 
-        ctx.epoch_height = 101;
-        testing_env!(ctx.clone());
+        ctx.epoch_height(101).apply();
         unstaked_balances.credit_unstaked(YOCTO.into());
         assert_eq!(unstaked_balances.available, YoctoNear::ZERO);
         assert_eq!(
@@ -224,7 +508,8 @@ mod tests {
                 .unwrap(),
             YoctoNear(2 * YOCTO)
         );
-        let expected_available_on: EpochHeight = (ctx.epoch_height + EPOCHS_LOCKED as u64).into();
+        let expected_available_on: EpochHeight =
+            (ctx.context().epoch_height + EPOCHS_LOCKED as u64).into();
         assert_eq!(
             *unstaked_balances
                 .locked()
@@ -235,14 +520,12 @@ mod tests {
         );
         assert_eq!(*unstaked_balances.total(), 3 * YOCTO);
 
-        ctx.epoch_height = 104;
-        testing_env!(ctx.clone());
+        ctx.epoch_height(104).apply();
         unstaked_balances.unlock();
         assert_eq!(*unstaked_balances.total(), 3 * YOCTO);
         assert_eq!(*unstaked_balances.available(), 2 * YOCTO);
 
-        ctx.epoch_height = 105;
-        testing_env!(ctx.clone());
+        ctx.epoch_height(105).apply();
         unstaked_balances.unlock();
         assert_eq!(*unstaked_balances.total(), 3 * YOCTO);
         assert_eq!(*unstaked_balances.available(), 3 * YOCTO);
@@ -250,29 +533,159 @@ mod tests {
         println!("{:?}", unstaked_balances);
     }
 
+    #[test]
+    fn apply_liquidity_is_proportional() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.epoch_height(100).apply();
+
+        // bob and alice own 100 NEAR of unstaked NEAR in total, and 10 NEAR of liquidity is added
+        let mut bob = UnstakedBalances::default();
+        bob.credit_unstaked((40 * YOCTO).into());
+        ctx.epoch_height(102).apply();
+        bob.credit_unstaked((10 * YOCTO).into());
+        let mut alice = UnstakedBalances::default();
+        alice.credit_unstaked((50 * YOCTO).into());
+        ContractNearBalances::incr_balance(State::TOTAL_UNSTAKED_BALANCE, (90 * YOCTO).into());
+        ContractNearBalances::incr_balance(State::UNSTAKED_LIQUIDITY_POOL, (10 * YOCTO).into());
+        LiquidityClaim::grant((10 * YOCTO).into(), (100 * YOCTO).into());
+
+        // bob owns 50% of the total unstaked NEAR, and thus can claim 50% of the liquidity
+        assert_eq!(bob.liquidity_claim("bob"), (5 * YOCTO).into());
+        assert_eq!(bob.apply_liquidity("bob"), (5 * YOCTO).into());
+        assert_eq!(bob.available(), (5 * YOCTO).into());
+        assert_eq!(bob.total(), (50 * YOCTO).into());
+
+        // the claim is debited when it is applied
+        assert_eq!(bob.liquidity_claim("bob"), YoctoNear::ZERO);
+        assert_eq!(bob.apply_liquidity("bob"), YoctoNear::ZERO);
+        assert_eq!(bob.available(), (5 * YOCTO).into());
+
+        // alice's claim is not affected by bob's withdrawal
+        assert_eq!(alice.liquidity_claim("alice"), (5 * YOCTO).into());
+        assert_eq!(alice.apply_liquidity("alice"), (5 * YOCTO).into());
+    }
+
+    #[test]
+    fn liquidity_claim_is_checkpointed_before_balance_changes() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.epoch_height(100).apply();
+        ContractNearBalances::incr_balance(State::UNSTAKED_LIQUIDITY_POOL, (20 * YOCTO).into());
+
+        let mut bob = UnstakedBalances::default();
+        LiquidityClaim::checkpoint("bob", &bob);
+        bob.credit_unstaked((10 * YOCTO).into());
+        LiquidityClaim::grant((10 * YOCTO).into(), (100 * YOCTO).into());
+
+        // liquidity that was granted before bob unstaked more NEAR is not claimable on the new NEAR
+        LiquidityClaim::checkpoint("bob", &bob);
+        bob.credit_unstaked((90 * YOCTO).into());
+        assert_eq!(bob.liquidity_claim("bob"), YOCTO.into());
+
+        LiquidityClaim::grant((10 * YOCTO).into(), (100 * YOCTO).into());
+        assert_eq!(bob.liquidity_claim("bob"), (11 * YOCTO).into());
+
+        // the claim is limited to the available liquidity
+        ContractNearBalances::decr_balance(State::UNSTAKED_LIQUIDITY_POOL, (15 * YOCTO).into());
+        assert_eq!(bob.liquidity_claim("bob"), (5 * YOCTO).into());
+    }
+
+    #[test]
+    fn transfer() {
+        let mut ctx = TestCtx::new("bob");
+
+        ctx.epoch_height(100).apply();
+        let mut sender = UnstakedBalances::default();
+        sender.credit_unstaked(YOCTO.into());
+        let mut receiver = UnstakedBalances::default();
+        receiver.credit_unstaked(YOCTO.into());
+
+        ctx.epoch_height(102).apply();
+        sender.credit_unstaked(YOCTO.into());
+
+        ctx.epoch_height(104).apply();
+        sender.credit_unstaked(YOCTO.into());
+
+        // available balance is debited first, and then the locked balances in unlock epoch order
+        let transfer = sender.debit_for_transfer((2 * YOCTO + 1000).into());
+        assert_eq!(
+            transfer,
+            UnstakedBalances {
+                available: YOCTO.into(),
+                locked: [
+                    (106.into(), YOCTO.into()),
+                    (108.into(), 1000.into()),
+                    Default::default(),
+                    Default::default()
+                ]
+            }
+        );
+        assert_eq!(*sender.total(), YOCTO - 1000);
+        assert_eq!(
+            sender.locked().unwrap().get(&108.into()).cloned(),
+            Some((YOCTO - 1000).into())
+        );
+
+        receiver.credit_transfer(transfer);
+        assert_eq!(*receiver.available(), 2 * YOCTO);
+        let locked = receiver.locked().unwrap();
+        assert_eq!(locked.get(&106.into()).cloned(), Some(YOCTO.into()));
+        assert_eq!(locked.get(&108.into()).cloned(), Some(1000.into()));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"UNSTAKED_LOCK_SLOTS_FULL\""#)]
+    fn credit_transfer_with_no_lock_slot_available() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+        let mut receiver = UnstakedBalances {
+            available: YoctoNear::ZERO,
+            locked: [
+                (201.into(), YOCTO.into()),
+                (202.into(), YOCTO.into()),
+                (203.into(), YOCTO.into()),
+                (204.into(), YOCTO.into()),
+            ],
+        };
+        let transfer = UnstakedBalances {
+            available: YoctoNear::ZERO,
+            locked: [
+                (205.into(), YOCTO.into()),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            ],
+        };
+        receiver.credit_transfer(transfer);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_FUNDS\""#)]
+    fn transfer_with_insufficient_funds() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+        let mut sender = UnstakedBalances::default();
+        sender.credit_unstaked(YOCTO.into());
+        sender.debit_for_transfer((YOCTO + 1).into());
+    }
+
     #[test]
     fn debit_for_restaking() {
-        let mut ctx = new_context("bob");
+        let mut ctx = TestCtx::new("bob");
 
-        ctx.epoch_height = 100;
-        testing_env!(ctx.clone());
+        ctx.epoch_height(100).apply();
         let mut unstaked_balances = UnstakedBalances::default();
         unstaked_balances.credit_unstaked(YOCTO.into());
 
-        ctx.epoch_height = 101;
-        testing_env!(ctx.clone());
+        ctx.epoch_height(101).apply();
         unstaked_balances.credit_unstaked(YOCTO.into());
 
-        ctx.epoch_height = 103;
-        testing_env!(ctx.clone());
+        ctx.epoch_height(103).apply();
         unstaked_balances.credit_unstaked(YOCTO.into());
 
-        ctx.epoch_height = 104;
-        testing_env!(ctx.clone());
+        ctx.epoch_height(104).apply();
         unstaked_balances.credit_unstaked(YOCTO.into());
 
-        ctx.epoch_height = 106;
-        testing_env!(ctx.clone());
+        ctx.epoch_height(106).apply();
         unstaked_balances.credit_unstaked(YOCTO.into());
 
         unstaked_balances.sort_locked();
@@ -338,12 +751,11 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [INSUFFICIENT_FUNDS]")]
+    #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_FUNDS\""#)]
     fn debit_for_restaking_with_insufficient_funds() {
-        let mut ctx = new_context("bob");
+        let mut ctx = TestCtx::new("bob");
 
-        ctx.epoch_height = 100;
-        testing_env!(ctx.clone());
+        ctx.epoch_height(100).apply();
         let mut unstaked_balances = UnstakedBalances::default();
         unstaked_balances.credit_unstaked(YOCTO.into());
 
@@ -352,27 +764,51 @@ mod tests {
 
     #[test]
     fn debit_available_balance() {
-        let mut ctx = new_context("bob");
+        let mut ctx = TestCtx::new("bob");
 
-        ctx.epoch_height = 100;
-        testing_env!(ctx.clone());
+        ctx.epoch_height(100).apply();
         let mut unstaked_balances = UnstakedBalances::default();
         unstaked_balances.credit_unstaked(YOCTO.into());
         assert_eq!(unstaked_balances.total(), YOCTO.into());
 
-        ctx.epoch_height = 104;
-        testing_env!(ctx.clone());
+        ctx.epoch_height(104).apply();
         unstaked_balances.debit_available_balance(YOCTO.into());
         assert_eq!(unstaked_balances.total(), YoctoNear::ZERO);
     }
 
     #[test]
-    #[should_panic(expected = "[ERR] [INSUFFICIENT_FUNDS]")]
+    fn relock() {
+        let mut ctx = TestCtx::new("bob");
+
+        ctx.epoch_height(100).apply();
+        let mut unstaked_balances = UnstakedBalances::default();
+        unstaked_balances.credit_unstaked((2 * YOCTO).into());
+
+        // relock part of the NEAR that was unstaked in epoch 100
+        ctx.epoch_height(102).apply();
+        unstaked_balances.relock(YOCTO.into(), 104.into());
+        let locked = unstaked_balances.locked().unwrap();
+        assert_eq!(locked.len(), 2);
+        assert_eq!(*locked.get(&104.into()).unwrap(), YOCTO.into());
+        assert_eq!(*locked.get(&106.into()).unwrap(), YOCTO.into());
+
+        // the NEAR was already unlocked
+        ctx.epoch_height(104).apply();
+        unstaked_balances.relock((2 * YOCTO).into(), 104.into());
+        assert_eq!(unstaked_balances.available(), YoctoNear::ZERO);
+        let locked = unstaked_balances.locked().unwrap();
+        assert_eq!(locked.len(), 2);
+        assert_eq!(*locked.get(&106.into()).unwrap(), YOCTO.into());
+        assert_eq!(*locked.get(&108.into()).unwrap(), YOCTO.into());
+        assert_eq!(unstaked_balances.total(), (2 * YOCTO).into());
+    }
+
+    #[test]
+    #[should_panic(expected = r#"{\"code\":\"INSUFFICIENT_FUNDS\""#)]
     fn debit_available_balance_insufficient_funds() {
-        let mut ctx = new_context("bob");
+        let mut ctx = TestCtx::new("bob");
 
-        ctx.epoch_height = 100;
-        testing_env!(ctx.clone());
+        ctx.epoch_height(100).apply();
         let mut unstaked_balances = UnstakedBalances::default();
         unstaked_balances.credit_unstaked(YOCTO.into());
         assert_eq!(unstaked_balances.total(), YOCTO.into());
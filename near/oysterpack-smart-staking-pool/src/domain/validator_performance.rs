@@ -0,0 +1,163 @@
+use oysterpack_smart_near::data::{numbers::U256, ComponentStorageUsage, Object};
+use oysterpack_smart_near::domain::{EpochHeight, YoctoNear};
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+use oysterpack_smart_near::YOCTO;
+
+/// Earnings that were recognized during the epoch
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct EpochPerformance {
+    pub epoch_height: EpochHeight,
+    /// total earnings recognized within the epoch, i.e., staking rewards plus transaction fees
+    pub earnings: YoctoNear,
+    /// portion of the earnings that were attributed to validator staking rewards
+    pub staking_rewards: YoctoNear,
+    /// locked balance that produced the staking rewards, i.e., the locked balance at the time
+    /// earnings were first recognized within the epoch, excluding the epoch's staking rewards
+    pub locked_balance: YoctoNear,
+    /// realized staking rewards per 1 NEAR of locked balance
+    pub reward_rate: YoctoNear,
+}
+
+impl EpochPerformance {
+    fn compute_reward_rate(&mut self) {
+        self.reward_rate = if self.locked_balance == YoctoNear::ZERO {
+            YoctoNear::ZERO
+        } else {
+            (U256::from(*self.staking_rewards) * U256::from(YOCTO)
+                / U256::from(*self.locked_balance))
+            .as_u128()
+            .into()
+        };
+    }
+}
+
+/// Per epoch validator performance, ordered by epoch height
+/// - the history is bounded to the most recent [`ValidatorPerformance::MAX_EPOCHS`] epochs
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct ValidatorPerformance(Vec<EpochPerformance>);
+
+const VALIDATOR_PERFORMANCE_KEY: u128 = 1958150897316248509127364859201746382;
+
+type ValidatorPerformanceObject = Object<u128, ValidatorPerformance>;
+
+impl ValidatorPerformance {
+    pub const MAX_EPOCHS: u64 = 30;
+
+    pub fn load() -> Self {
+        ValidatorPerformanceObject::load(&VALIDATOR_PERFORMANCE_KEY)
+            .map_or_else(Self::default, |performance| (*performance).clone())
+    }
+
+    pub fn epochs(&self) -> &[EpochPerformance] {
+        &self.0
+    }
+
+    /// returns up to `limit` of the most recent epochs, ordered by epoch height
+    pub fn most_recent(&self, limit: u64) -> &[EpochPerformance] {
+        let start = self.0.len().saturating_sub(limit as usize);
+        &self.0[start..]
+    }
+
+    /// accumulates the earnings into the epoch's performance record
+    /// - `locked_balance` is the current locked balance, which includes the staking rewards
+    pub(crate) fn record(
+        epoch_height: EpochHeight,
+        earnings: YoctoNear,
+        staking_rewards: YoctoNear,
+        locked_balance: YoctoNear,
+    ) {
+        let mut performance = Self::load();
+        match performance.0.last_mut() {
+            Some(last) if last.epoch_height == epoch_height => {
+                last.earnings += earnings;
+                last.staking_rewards += staking_rewards;
+                last.compute_reward_rate();
+            }
+            _ => {
+                let mut epoch = EpochPerformance {
+                    epoch_height,
+                    earnings,
+                    staking_rewards,
+                    locked_balance: locked_balance.saturating_sub(*staking_rewards).into(),
+                    reward_rate: YoctoNear::ZERO,
+                };
+                epoch.compute_reward_rate();
+                performance.0.push(epoch);
+                if performance.0.len() > Self::MAX_EPOCHS as usize {
+                    performance.0.remove(0);
+                }
+            }
+        }
+        ComponentStorageUsage::track(ComponentStorageUsage::STAKING, || {
+            ValidatorPerformanceObject::new(VALIDATOR_PERFORMANCE_KEY, performance).save();
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near_test::*;
+
+    #[test]
+    fn record() {
+        // Arrange
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        // Act
+        ValidatorPerformance::record(
+            10.into(),
+            (2 * YOCTO).into(),
+            YOCTO.into(),
+            (101 * YOCTO).into(),
+        );
+        ValidatorPerformance::record(10.into(), YOCTO.into(), YOCTO.into(), (102 * YOCTO).into());
+        ValidatorPerformance::record(
+            11.into(),
+            YOCTO.into(),
+            YoctoNear::ZERO,
+            (102 * YOCTO).into(),
+        );
+
+        // Assert
+        let performance = ValidatorPerformance::load();
+        assert_eq!(performance.epochs().len(), 2);
+        let epoch = performance.epochs()[0];
+        assert_eq!(epoch.epoch_height, 10.into());
+        assert_eq!(epoch.earnings, (3 * YOCTO).into());
+        assert_eq!(epoch.staking_rewards, (2 * YOCTO).into());
+        assert_eq!(epoch.locked_balance, (100 * YOCTO).into());
+        assert_eq!(epoch.reward_rate, (YOCTO / 50).into());
+        assert_eq!(performance.epochs()[1].reward_rate, YoctoNear::ZERO);
+
+        assert_eq!(performance.most_recent(1), &performance.epochs()[1..]);
+        assert_eq!(performance.most_recent(100), performance.epochs());
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut ctx = TestCtx::new("bob");
+        ctx.apply();
+
+        for epoch in 0..(ValidatorPerformance::MAX_EPOCHS + 5) {
+            ValidatorPerformance::record(epoch.into(), YOCTO.into(), YOCTO.into(), YOCTO.into());
+        }
+
+        let performance = ValidatorPerformance::load();
+        assert_eq!(
+            performance.epochs().len(),
+            ValidatorPerformance::MAX_EPOCHS as usize
+        );
+        assert_eq!(performance.epochs()[0].epoch_height, 5.into());
+    }
+}
@@ -0,0 +1,126 @@
+use oysterpack_smart_account_management::AccountStorageEvent;
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::{numbers::U256, Object};
+use oysterpack_smart_near::domain::BlockTimestamp;
+use oysterpack_smart_near::eventbus::post;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    serde::{Deserialize, Serialize},
+};
+use oysterpack_smart_near::Hash;
+
+/// STAKE vesting schedule
+/// - nothing vests until the `cliff` is reached
+/// - once the cliff is reached, the STAKE vests linearly from `start` until `end`
+///
+/// Unvested STAKE cannot be transferred, unstaked, or burned.
+///
+/// The vesting schedule is stored separately from the account's [`crate::StakeAccountData`], but
+/// its storage usage is charged to the account.
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq,
+)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct VestingSchedule {
+    /// total STAKE amount subject to vesting
+    pub amount: TokenAmount,
+    pub start: BlockTimestamp,
+    pub cliff: BlockTimestamp,
+    pub end: BlockTimestamp,
+}
+
+const VESTING_SCHEDULE_KEY: u128 = 1957623890157316286740313462780367221;
+
+type VestingScheduleObject = Object<Hash, VestingSchedule>;
+
+impl VestingSchedule {
+    fn object_key(account_id: &str) -> Hash {
+        Hash::from((account_id, VESTING_SCHEDULE_KEY))
+    }
+
+    pub fn load(account_id: &str) -> Option<VestingSchedule> {
+        VestingScheduleObject::load(&Self::object_key(account_id)).map(|schedule| *schedule)
+    }
+
+    /// replaces any existing vesting schedule
+    /// - tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub fn save(&self, account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        VestingScheduleObject::new(Self::object_key(account_id), *self).save();
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+
+    /// tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub fn delete(account_id: &str) {
+        let initial_storage_usage = env::storage_usage();
+        VestingScheduleObject::delete_by_key(&Self::object_key(account_id));
+        let storage_usage_change = initial_storage_usage - env::storage_usage();
+        if storage_usage_change > 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                (-(storage_usage_change as i64)).into(),
+            ));
+        }
+    }
+
+    /// returns the account's unvested STAKE balance based on the current block timestamp
+    pub fn unvested_balance(account_id: &str) -> TokenAmount {
+        Self::load(account_id).map_or(TokenAmount::ZERO, |schedule| {
+            schedule.unvested(BlockTimestamp::from_env())
+        })
+    }
+
+    /// vested amount is rounded down
+    pub fn vested(&self, timestamp: BlockTimestamp) -> TokenAmount {
+        if timestamp < self.cliff {
+            return TokenAmount::ZERO;
+        }
+        if timestamp >= self.end {
+            return self.amount;
+        }
+        let vested = U256::from(*self.amount) * U256::from(timestamp - self.start)
+            / U256::from(self.end - self.start);
+        vested.as_u128().into()
+    }
+
+    pub fn unvested(&self, timestamp: BlockTimestamp) -> TokenAmount {
+        self.amount - self.vested(timestamp)
+    }
+
+    pub fn fully_vested(&self, timestamp: BlockTimestamp) -> bool {
+        timestamp >= self.end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vesting() {
+        let schedule = VestingSchedule {
+            amount: 1000.into(),
+            start: 100.into(),
+            cliff: 200.into(),
+            end: 500.into(),
+        };
+
+        assert_eq!(schedule.vested(0.into()), TokenAmount::ZERO);
+        assert_eq!(schedule.vested(199.into()), TokenAmount::ZERO);
+        assert_eq!(schedule.vested(200.into()), 250.into());
+        assert_eq!(schedule.unvested(200.into()), 750.into());
+        assert_eq!(schedule.vested(301.into()), 502.into());
+        assert_eq!(schedule.vested(500.into()), 1000.into());
+        assert_eq!(schedule.vested(1000.into()), 1000.into());
+        assert_eq!(schedule.unvested(1000.into()), TokenAmount::ZERO);
+        assert!(!schedule.fully_vested(499.into()));
+        assert!(schedule.fully_vested(500.into()));
+    }
+}
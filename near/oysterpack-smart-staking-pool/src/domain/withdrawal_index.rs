@@ -0,0 +1,290 @@
+use crate::UnstakedBalances;
+use oysterpack_smart_account_management::AccountStorageEvent;
+use oysterpack_smart_near::data::Object;
+use oysterpack_smart_near::domain::{EpochHeight, YoctoNear};
+use oysterpack_smart_near::eventbus::post;
+use oysterpack_smart_near::near_sdk::{
+    env,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::Hash;
+
+/// Account with unstaked NEAR that is available to withdraw
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct WithdrawableAccount {
+    pub account_id: AccountId,
+    pub available: YoctoNear,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct WithdrawableAccounts {
+    pub accounts: Vec<WithdrawableAccount>,
+    /// aggregate available unstaked balance across the listed accounts
+    pub total_available: YoctoNear,
+}
+
+/// Epoch bucketed index of accounts with unstaked NEAR
+/// - each account is indexed in the bucket for the earliest epoch in which its unstaked NEAR is
+///   available to withdraw
+/// - the index is maintained whenever the account's unstaked balances change, i.e., when the account
+///   unstakes, restakes, or withdraws
+/// - accounts with unstaked NEAR that is available to withdraw are found by scanning the buckets
+///   for epochs that have been reached
+/// - buckets are split into pages that are capped at [`WithdrawalIndex::MAX_BUCKET_PAGE_SIZE`]
+///   accounts, which bounds the size of each storage entry that is read and written when the index
+///   is updated
+///
+/// The index storage usage is charged to the account.
+pub struct WithdrawalIndex;
+
+/// sorted list of epochs that have non-empty buckets
+const WITHDRAWAL_INDEX_EPOCHS_KEY: u128 = 1957946620381557109713924350166702125;
+/// the bucket page storage key is computed from the epoch and the page
+const WITHDRAWAL_INDEX_BUCKET_KEY: u128 = 1957946649528216043395617906254120858;
+const ACCOUNT_WITHDRAWAL_INDEX_KEY: u128 = 1957946673302493862908591137650817064;
+/// the bucket page count storage key is computed as `WITHDRAWAL_INDEX_BUCKET_PAGES_KEY + epoch`
+const WITHDRAWAL_INDEX_BUCKET_PAGES_KEY: u128 = 1958172418063591420759614371238095172;
+
+type WithdrawalIndexEpochsObject = Object<u128, Vec<EpochHeight>>;
+type WithdrawalIndexBucketPageObject = Object<Hash, Vec<AccountId>>;
+type WithdrawalIndexBucketPagesObject = Object<u128, u32>;
+/// maps the account to the epoch bucket page that it is indexed in
+type AccountWithdrawalIndexObject = Object<Hash, (EpochHeight, u32)>;
+
+impl WithdrawalIndex {
+    pub const MAX_BUCKET_PAGE_SIZE: usize = 100;
+
+    /// re-indexes the account based on its unstaked balances
+    /// - if the account has no unstaked balance, then the account is removed from the index
+    /// - tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn update(account_id: &str, unstaked_balances: &UnstakedBalances) {
+        let current_epoch = EpochHeight::from_env();
+        let indexed = AccountWithdrawalIndexObject::load(&Self::account_key(account_id))
+            .map(|indexed| *indexed);
+        let indexed_epoch = indexed.map(|(epoch, _)| epoch);
+        let epoch = if unstaked_balances.total() == YoctoNear::ZERO {
+            None
+        } else if unstaked_balances.available() > YoctoNear::ZERO {
+            match indexed_epoch {
+                Some(epoch) if epoch <= current_epoch => Some(epoch),
+                _ => Some(current_epoch),
+            }
+        } else {
+            unstaked_balances
+                .locked()
+                .and_then(|locked| locked.keys().next().cloned())
+        };
+        if epoch == indexed_epoch {
+            return;
+        }
+
+        let initial_storage_usage = env::storage_usage();
+        if let Some((indexed_epoch, page)) = indexed {
+            Self::remove_from_bucket(account_id, indexed_epoch, page);
+        }
+        match epoch {
+            Some(epoch) => {
+                let page = Self::add_to_bucket(account_id, epoch);
+                AccountWithdrawalIndexObject::new(Self::account_key(account_id), (epoch, page))
+                    .save();
+            }
+            None => {
+                AccountWithdrawalIndexObject::delete_by_key(&Self::account_key(account_id));
+            }
+        }
+        Self::track_storage_usage(account_id, initial_storage_usage);
+    }
+
+    /// removes the account from the index
+    /// - tracks storage usage - emits [`AccountStorageEvent::StorageUsageChanged`] event
+    pub(crate) fn remove(account_id: &str) {
+        Self::update(account_id, &UnstakedBalances::default());
+    }
+
+    /// returns the epoch bucket that the account is indexed in
+    pub fn indexed_epoch(account_id: &str) -> Option<EpochHeight> {
+        AccountWithdrawalIndexObject::load(&Self::account_key(account_id)).map(|indexed| indexed.0)
+    }
+
+    /// returns the accounts that are indexed in buckets for epochs that have been reached, in epoch
+    /// order, i.e., the accounts that have unstaked NEAR available to withdraw
+    /// - at most `limit` accounts are returned
+    pub fn due(limit: usize) -> Vec<AccountId> {
+        let current_epoch = EpochHeight::from_env();
+        Self::epochs()
+            .into_iter()
+            .take_while(|epoch| *epoch <= current_epoch)
+            .flat_map(|epoch| (0..Self::bucket_pages(epoch)).map(move |page| (epoch, page)))
+            .flat_map(|(epoch, page)| Self::bucket_page(epoch, page))
+            .take(limit)
+            .collect()
+    }
+
+    /// adds the account to the last page in the epoch bucket - a new page is added if the last page
+    /// is full
+    ///
+    /// Returns the page that the account was added to
+    fn add_to_bucket(account_id: &str, epoch: EpochHeight) -> u32 {
+        let pages = Self::bucket_pages(epoch);
+        if pages == 0 {
+            let mut epochs = Self::epochs();
+            if let Err(i) = epochs.binary_search(&epoch) {
+                epochs.insert(i, epoch);
+            }
+            WithdrawalIndexEpochsObject::new(WITHDRAWAL_INDEX_EPOCHS_KEY, epochs).save();
+        }
+        let (page, mut accounts) = match pages.checked_sub(1) {
+            Some(last_page) => {
+                let accounts = Self::bucket_page(epoch, last_page);
+                if accounts.len() < Self::MAX_BUCKET_PAGE_SIZE {
+                    (last_page, accounts)
+                } else {
+                    (pages, vec![])
+                }
+            }
+            None => (0, vec![]),
+        };
+        if page == pages {
+            WithdrawalIndexBucketPagesObject::new(Self::bucket_pages_key(epoch), pages + 1).save();
+        }
+        accounts.push(account_id.to_string());
+        WithdrawalIndexBucketPageObject::new(Self::bucket_page_key(epoch, page), accounts).save();
+        page
+    }
+
+    /// removes the account from the bucket page
+    /// - empty pages are deleted, and trailing empty pages are trimmed from the bucket
+    /// - when the bucket is empty, then the epoch is removed from the index
+    fn remove_from_bucket(account_id: &str, epoch: EpochHeight, page: u32) {
+        let mut accounts = Self::bucket_page(epoch, page);
+        accounts.retain(|id| id != account_id);
+        if !accounts.is_empty() {
+            WithdrawalIndexBucketPageObject::new(Self::bucket_page_key(epoch, page), accounts)
+                .save();
+            return;
+        }
+
+        WithdrawalIndexBucketPageObject::delete_by_key(&Self::bucket_page_key(epoch, page));
+        let mut pages = Self::bucket_pages(epoch);
+        while pages > 0
+            && !WithdrawalIndexBucketPageObject::exists(&Self::bucket_page_key(epoch, pages - 1))
+        {
+            pages -= 1;
+        }
+        if pages > 0 {
+            WithdrawalIndexBucketPagesObject::new(Self::bucket_pages_key(epoch), pages).save();
+            return;
+        }
+
+        WithdrawalIndexBucketPagesObject::delete_by_key(&Self::bucket_pages_key(epoch));
+        let mut epochs = Self::epochs();
+        epochs.retain(|bucket_epoch| *bucket_epoch != epoch);
+        if epochs.is_empty() {
+            WithdrawalIndexEpochsObject::delete_by_key(&WITHDRAWAL_INDEX_EPOCHS_KEY);
+        } else {
+            WithdrawalIndexEpochsObject::new(WITHDRAWAL_INDEX_EPOCHS_KEY, epochs).save();
+        }
+    }
+
+    fn epochs() -> Vec<EpochHeight> {
+        WithdrawalIndexEpochsObject::load(&WITHDRAWAL_INDEX_EPOCHS_KEY)
+            .map_or_else(Vec::new, |epochs| (*epochs).clone())
+    }
+
+    fn bucket_pages(epoch: EpochHeight) -> u32 {
+        WithdrawalIndexBucketPagesObject::load(&Self::bucket_pages_key(epoch))
+            .map_or(0, |pages| *pages)
+    }
+
+    fn bucket_page(epoch: EpochHeight, page: u32) -> Vec<AccountId> {
+        WithdrawalIndexBucketPageObject::load(&Self::bucket_page_key(epoch, page))
+            .map_or_else(Vec::new, |accounts| (*accounts).clone())
+    }
+
+    fn bucket_pages_key(epoch: EpochHeight) -> u128 {
+        WITHDRAWAL_INDEX_BUCKET_PAGES_KEY + *epoch as u128
+    }
+
+    fn bucket_page_key(epoch: EpochHeight, page: u32) -> Hash {
+        let mut bytes = epoch.value().to_le_bytes().to_vec();
+        bytes.extend_from_slice(&page.to_le_bytes());
+        Hash::from((bytes.as_slice(), WITHDRAWAL_INDEX_BUCKET_KEY))
+    }
+
+    fn account_key(account_id: &str) -> Hash {
+        Hash::from((account_id, ACCOUNT_WITHDRAWAL_INDEX_KEY))
+    }
+
+    fn track_storage_usage(account_id: &str, initial_storage_usage: u64) {
+        let storage_usage_change = env::storage_usage() as i64 - initial_storage_usage as i64;
+        if storage_usage_change != 0 {
+            post(&AccountStorageEvent::StorageUsageChanged(
+                account_id.into(),
+                storage_usage_change.into(),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oysterpack_smart_near_test::*;
+
+    fn account_id(i: usize) -> String {
+        format!("account-{}", i)
+    }
+
+    #[test]
+    fn bucket_pages_are_capped() {
+        // Arrange
+        TestCtx::new("bob").epoch_height(10).apply();
+        let epoch: EpochHeight = 10.into();
+
+        // Act
+        let pages: Vec<u32> = (0..=WithdrawalIndex::MAX_BUCKET_PAGE_SIZE)
+            .map(|i| WithdrawalIndex::add_to_bucket(&account_id(i), epoch))
+            .collect();
+
+        // Assert - the account that overflows the first page is added to a new page
+        assert!(pages[..WithdrawalIndex::MAX_BUCKET_PAGE_SIZE]
+            .iter()
+            .all(|page| *page == 0));
+        assert_eq!(pages[WithdrawalIndex::MAX_BUCKET_PAGE_SIZE], 1);
+        assert_eq!(WithdrawalIndex::bucket_pages(epoch), 2);
+        assert_eq!(
+            WithdrawalIndex::bucket_page(epoch, 0).len(),
+            WithdrawalIndex::MAX_BUCKET_PAGE_SIZE
+        );
+        let due = WithdrawalIndex::due(usize::MAX);
+        assert_eq!(due.len(), WithdrawalIndex::MAX_BUCKET_PAGE_SIZE + 1);
+        assert_eq!(
+            due.last().unwrap(),
+            &account_id(WithdrawalIndex::MAX_BUCKET_PAGE_SIZE)
+        );
+
+        // Act - emptying the last page trims it from the bucket
+        WithdrawalIndex::remove_from_bucket(
+            &account_id(WithdrawalIndex::MAX_BUCKET_PAGE_SIZE),
+            epoch,
+            1,
+        );
+
+        // Assert
+        assert_eq!(WithdrawalIndex::bucket_pages(epoch), 1);
+        assert_eq!(WithdrawalIndex::epochs(), vec![epoch]);
+
+        // Act - emptying the bucket removes the epoch from the index
+        for i in 0..WithdrawalIndex::MAX_BUCKET_PAGE_SIZE {
+            WithdrawalIndex::remove_from_bucket(&account_id(i), epoch, 0);
+        }
+
+        // Assert
+        assert_eq!(WithdrawalIndex::bucket_pages(epoch), 0);
+        assert!(WithdrawalIndex::epochs().is_empty());
+        assert!(WithdrawalIndex::due(usize::MAX).is_empty());
+    }
+}
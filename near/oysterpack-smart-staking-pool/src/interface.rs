@@ -1,7 +1,23 @@
+pub use contract::account_migration::*;
+pub use contract::governance::*;
+pub use contract::inactive_accounts::*;
+pub use contract::liquidity_pool::*;
 pub use contract::near_staking_pool::*;
 pub use contract::operator::*;
+pub use contract::owner_earnings::*;
+pub use contract::permissioned_pool::*;
+pub use contract::referrals::*;
+pub use contract::relayed_staking::*;
+pub use contract::rewards_beneficiary::*;
+pub use contract::rewards_farm::*;
 pub use contract::stake_action_callbacks::*;
+pub use contract::stake_attestation::*;
+pub use contract::stake_key_custody::*;
+pub use contract::stake_locks::*;
+pub use contract::stake_on_transfer::*;
+pub use contract::stake_price_oracle::*;
 pub use contract::staking_pool::*;
 pub use contract::treasury::*;
+pub use contract::vesting::*;
 
 pub mod contract;
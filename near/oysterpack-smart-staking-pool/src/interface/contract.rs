@@ -1,5 +1,21 @@
+pub mod account_migration;
+pub mod governance;
+pub mod inactive_accounts;
+pub mod liquidity_pool;
 pub mod near_staking_pool;
 pub mod operator;
+pub mod owner_earnings;
+pub mod permissioned_pool;
+pub mod referrals;
+pub mod relayed_staking;
+pub mod rewards_beneficiary;
+pub mod rewards_farm;
 pub mod stake_action_callbacks;
+pub mod stake_attestation;
+pub mod stake_key_custody;
+pub mod stake_locks;
+pub mod stake_on_transfer;
+pub mod stake_price_oracle;
 pub mod staking_pool;
 pub mod treasury;
+pub mod vesting;
@@ -0,0 +1,71 @@
+use crate::{StakedBalance, UnstakedBalance};
+use oysterpack_smart_account_management::Permissions;
+use oysterpack_smart_near::domain::YoctoNear;
+use oysterpack_smart_near::near_sdk::{
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// # **Contract Interface**: Staking Pool Account Migration API
+///
+/// Used to migrate accounts to a new staking pool contract deployment:
+/// 1. stop staking on the current contract
+/// 2. page through the registered accounts via [`StakeAccountMigration::ops_operator_export_accounts`]
+/// 3. deploy the new contract, which is deployed as stopped
+/// 4. import the exported accounts in batches via [`StakeAccountMigration::ops_operator_import_accounts`],
+///    attaching the NEAR that backs the imported balances
+///
+/// ## NOTES
+/// - STAKE balances are imported along with their NEAR value, which preserves the STAKE token value
+/// - accounts should be imported into a fresh deployment - once STAKE has been minted, imported
+///   STAKE balances must be backed at the current STAKE token value
+/// - locked unstaked balances keep their unlock epochs
+/// - the treasury account is exported with `treasury=true` and is mapped to the new contract account
+///   on import
+/// - vesting schedules, referrals, and liquidity pool shares are not migrated
+pub trait StakeAccountMigration {
+    /// Returns up to `limit` registered accounts starting from the `from` position
+    /// - accounts positions are not stable while accounts are being registered and unregistered,
+    ///   thus the staking pool should be stopped while accounts are being exported
+    fn ops_operator_export_accounts(&self, from: u64, limit: u64) -> Vec<StakeAccountExport>;
+
+    /// Imports accounts that were exported from another staking pool contract
+    /// - accounts that are not registered are registered using the exported storage balance - the
+    ///   storage balance is bumped up to the min required storage balance if needed
+    /// - accounts that are already registered keep their current storage balance
+    /// - logs [`LOG_EVENT_ACCOUNT_IMPORTED`] for each imported account
+    ///
+    /// The attached deposit must exactly match the NEAR required to back the imported accounts, i.e.,
+    /// the storage balances for newly registered accounts plus the staked and unstaked balances.
+    ///
+    /// Returns the number of accounts that were imported
+    ///
+    /// ## Panics
+    /// - if the predecessor account is not authorized - requires operator permission
+    /// - if any of the accounts have permissions, then admin permission is required
+    /// - if the staking pool is online
+    /// - if an account already has a STAKE or unstaked balance, i.e., it was already imported
+    /// - if STAKE has already been minted and an imported STAKE balance's NEAR value is below the
+    ///   current STAKE token value, i.e., the import would dilute existing STAKE holders
+    /// - if the attached deposit does not match the required deposit
+    ///
+    /// `#[payable]`
+    fn ops_operator_import_accounts(&mut self, accounts: Vec<StakeAccountExport>) -> u32;
+}
+
+/// Account state that is exported from the staking pool contract
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakeAccountExport {
+    pub account_id: AccountId,
+    /// true if the account is the contract treasury account
+    pub treasury: bool,
+    /// account total storage balance
+    pub storage_balance: YoctoNear,
+    pub permissions: Option<Permissions>,
+    pub staked: Option<StakedBalance>,
+    pub unstaked: Option<UnstakedBalance>,
+}
+
+pub const LOG_EVENT_ACCOUNT_IMPORTED: LogEvent = LogEvent(Level::INFO, "ACCOUNT_IMPORTED");
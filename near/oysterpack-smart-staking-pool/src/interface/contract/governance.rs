@@ -0,0 +1,66 @@
+use crate::{GovProposal, GovProposalResult, GovVote, VotingWindow};
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::numbers::U64;
+use oysterpack_smart_near::near_sdk::json_types::{Base64VecU8, ValidAccountId};
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// # **Contract Interface**: Staking Pool STAKE Holder Governance API
+///
+/// Lightweight governance signaling, which enables STAKE holders to vote on proposals created by
+/// the contract owner, e.g., fee changes or validator upgrades.
+/// - the proposal text is published off-chain and the proposal records the text hash
+/// - votes are weighted by the voter's total STAKE balance, i.e., available + locked, at the time
+///   the proposal was created - see [`oysterpack_smart_fungible_token::TokenService::ft_snapshot`]
+/// - voting results are signals only, i.e., they are not enforced on-chain
+pub trait StakeGovernance {
+    /// Creates a new proposal and snapshots the STAKE balances that votes will be weighted by
+    /// - logs [`LOG_EVENT_GOV_PROPOSAL`]
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if not invoked by the contract owner
+    /// - if the text hash is not 32 bytes
+    /// - if the number of options is not within [`GovProposal::MIN_OPTIONS`] and [`GovProposal::MAX_OPTIONS`]
+    /// - if any option is blank
+    /// - if the voting window starts in the past or ends before it starts
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_gov_propose(
+        &mut self,
+        text_hash: Base64VecU8,
+        options: Vec<String>,
+        voting_window: VotingWindow,
+    ) -> GovProposal;
+
+    /// Casts the predecessor account's vote on the proposal
+    /// - if the account already voted on the proposal, then its vote is changed
+    /// - the vote storage usage is charged to the account
+    /// - logs [`LOG_EVENT_GOV_VOTE`]
+    ///
+    /// Returns the vote weight, i.e., the account's STAKE balance at the time the proposal was created
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if the account is not registered
+    /// - if the proposal does not exist
+    /// - if the voting window is not active
+    /// - if the option does not exist
+    /// - if the account had no STAKE balance when the proposal was created
+    /// - if the proposal's STAKE balance snapshot has expired, i.e., more than
+    ///   [`oysterpack_smart_fungible_token::MAX_TOKEN_BALANCE_SNAPSHOTS`] proposals have been created
+    ///   since the proposal was created
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_gov_vote(&mut self, proposal_id: U64, option: u8) -> TokenAmount;
+
+    /// returns the proposal along with its current vote tallies
+    fn ops_gov_result(&self, proposal_id: U64) -> Option<GovProposalResult>;
+
+    /// returns the account's vote on the proposal
+    fn ops_gov_vote_of(&self, proposal_id: U64, account_id: ValidAccountId) -> Option<GovVote>;
+}
+
+/// log message format: `id={id}, snapshot_id={snapshot_id}, options={options}, starts_at={starts_at}, ends_at={ends_at}`
+pub const LOG_EVENT_GOV_PROPOSAL: LogEvent = LogEvent(Level::INFO, "GOV_PROPOSAL");
+/// log message format: `proposal_id={proposal_id}, account_id={account_id}, option={option}, weight={weight}`
+pub const LOG_EVENT_GOV_VOTE: LogEvent = LogEvent(Level::INFO, "GOV_VOTE");
@@ -0,0 +1,137 @@
+use crate::ArchivedAccount;
+use oysterpack_smart_contract::JobId;
+use oysterpack_smart_near::data::numbers::U128;
+use oysterpack_smart_near::domain::EpochHeight;
+use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// # **Contract Interface**: Staking Pool Inactive Accounts API
+///
+/// Used by the operator to reclaim storage from accounts that have been inactive for a long period
+/// of time, which keeps the contract state size bounded:
+/// - inactive accounts can be force unregistered, which refunds their balances
+/// - inactive accounts with zero balances can be archived, which keeps the accounts registered - see
+///   [`ArchivedAccount`]
+///
+/// Account activity is recorded when the account stakes, unstakes, restakes, or withdraws -
+/// see [`crate::AccountActivity`].
+pub trait StakeInactiveAccounts {
+    /// Force unregisters the specified accounts that have been inactive for at least `min_inactive_epochs`:
+    /// 1. the account's STAKE balance is unstaked
+    /// 2. the account's available unstaked balance is withdrawn and transferred to the account
+    /// 3. if the account has no locked unstaked balance, then the account is unregistered and its
+    ///    storage balance is refunded to the account
+    ///    - otherwise, the account's withdrawal is queued and the account can be unregistered once
+    ///      the unstaked NEAR unlocks
+    ///
+    /// Accounts that fail any of the safety checks are skipped:
+    /// - account is not registered
+    /// - account is the contract treasury or owner account
+    /// - account has permissions
+    /// - account owns liquidity pool shares
+    /// - account has unvested STAKE
+    /// - account has locked STAKE
+    /// - account has not been inactive for at least `min_inactive_epochs`
+    /// - account has no recorded activity, in which case the current epoch is recorded as its last
+    ///   active epoch
+    ///
+    /// Logs per account events:
+    /// - [`LOG_EVENT_INACTIVE_ACCOUNT_UNREGISTERED`]
+    /// - [`LOG_EVENT_INACTIVE_ACCOUNT_PENDING`]
+    /// - [`LOG_EVENT_INACTIVE_ACCOUNT_SKIPPED`]
+    ///
+    /// If the staking pool is online and STAKE was unstaked, then the stake action is submitted.
+    ///
+    /// Progress is tracked via the [`FORCE_UNREGISTER_INACTIVE_JOB_ID`] job cursor - see
+    /// [`oysterpack_smart_contract::ContractJobs`]:
+    /// - `position` is the number of accounts that have been checked, which can be used as the
+    ///   offset when paging through the accounts in the next transaction
+    /// - `processed` is the number of accounts that have been unregistered
+    /// - the job is completed when a batch of less than [`MAX_INACTIVE_ACCOUNTS_BATCH_SIZE`]
+    ///   accounts is submitted, i.e., the last page
+    ///
+    /// Returns the number of accounts that were unregistered
+    ///
+    /// ## Panics
+    /// - if the predecessor account is not authorized - requires operator permission
+    /// - if `min_inactive_epochs` is less than [`MIN_INACTIVE_EPOCHS`]
+    /// - if more than [`MAX_INACTIVE_ACCOUNTS_BATCH_SIZE`] accounts are specified
+    fn ops_stake_force_unregister_inactive(
+        &mut self,
+        accounts: Vec<ValidAccountId>,
+        min_inactive_epochs: u64,
+    ) -> u32;
+
+    /// Archives the specified accounts that have zero balances and have been inactive for at least
+    /// `min_inactive_epochs`:
+    /// 1. the account's activity record and referrer are moved into an [`ArchivedAccount`] tombstone
+    /// 2. the account's rewards beneficiary and relay key settings are deleted
+    /// 3. the storage that is released is deducted from the account's storage usage, i.e., it is
+    ///    released back to the account's storage balance
+    ///
+    /// The account's activity record and referrer are restored on the account's next interaction
+    /// with the staking pool, but the deleted settings need to be reconfigured by the account. If
+    /// the account's storage balance no longer covers the restored data, e.g., because the account
+    /// withdrew its available storage balance, then the account must deposit more storage balance.
+    ///
+    /// Accounts that fail any of the safety checks are skipped:
+    /// - account is not registered
+    /// - account is already archived
+    /// - account has STAKE
+    /// - account has unstaked NEAR
+    /// - account owns liquidity pool shares
+    /// - account has not been inactive for at least `min_inactive_epochs`
+    /// - account has no recorded activity, in which case the current epoch is recorded as its last
+    ///   active epoch
+    ///
+    /// Logs per account events:
+    /// - [`LOG_EVENT_ACCOUNT_ARCHIVED`]
+    /// - [`LOG_EVENT_INACTIVE_ACCOUNT_SKIPPED`]
+    ///
+    /// Progress is tracked via the [`ARCHIVE_INACTIVE_JOB_ID`] job cursor - see
+    /// [`oysterpack_smart_contract::ContractJobs`]:
+    /// - `position` is the number of accounts that have been checked, which can be used as the
+    ///   offset when paging through the accounts in the next transaction
+    /// - `processed` is the number of accounts that have been archived
+    /// - the job is completed when a batch of less than [`MAX_INACTIVE_ACCOUNTS_BATCH_SIZE`]
+    ///   accounts is submitted, i.e., the last page
+    ///
+    /// Returns the number of accounts that were archived
+    ///
+    /// ## Panics
+    /// - if the predecessor account is not authorized - requires operator permission
+    /// - if `min_inactive_epochs` is less than [`MIN_INACTIVE_EPOCHS`]
+    /// - if more than [`MAX_INACTIVE_ACCOUNTS_BATCH_SIZE`] accounts are specified
+    fn ops_stake_archive_inactive(
+        &mut self,
+        accounts: Vec<ValidAccountId>,
+        min_inactive_epochs: u64,
+    ) -> u32;
+
+    /// returns the epoch in which the account was last active
+    /// - for archived accounts, the last active epoch is retrieved from the tombstone
+    fn ops_stake_last_active_epoch(&self, account_id: ValidAccountId) -> Option<EpochHeight>;
+
+    /// returns the account's tombstone, if the account is archived
+    fn ops_stake_archived_account(&self, account_id: ValidAccountId) -> Option<ArchivedAccount>;
+}
+
+/// roughly 90 days
+pub const MIN_INACTIVE_EPOCHS: u64 = 180;
+
+pub const MAX_INACTIVE_ACCOUNTS_BATCH_SIZE: usize = 10;
+
+/// [`StakeInactiveAccounts::ops_stake_force_unregister_inactive`] job cursor
+pub const FORCE_UNREGISTER_INACTIVE_JOB_ID: JobId =
+    JobId(U128(1958012503361820563957163154604727846));
+/// [`StakeInactiveAccounts::ops_stake_archive_inactive`] job cursor
+pub const ARCHIVE_INACTIVE_JOB_ID: JobId = JobId(U128(1958012537725186912093742281308462993));
+
+pub const LOG_EVENT_INACTIVE_ACCOUNT_UNREGISTERED: LogEvent =
+    LogEvent(Level::INFO, "INACTIVE_ACCOUNT_UNREGISTERED");
+pub const LOG_EVENT_INACTIVE_ACCOUNT_PENDING: LogEvent =
+    LogEvent(Level::INFO, "INACTIVE_ACCOUNT_PENDING");
+pub const LOG_EVENT_INACTIVE_ACCOUNT_SKIPPED: LogEvent =
+    LogEvent(Level::WARN, "INACTIVE_ACCOUNT_SKIPPED");
+pub const LOG_EVENT_ACCOUNT_ARCHIVED: LogEvent = LogEvent(Level::INFO, "ACCOUNT_ARCHIVED");
+pub const LOG_EVENT_ACCOUNT_RESTORED: LogEvent = LogEvent(Level::INFO, "ACCOUNT_RESTORED");
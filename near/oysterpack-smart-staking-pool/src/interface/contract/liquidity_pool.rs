@@ -0,0 +1,96 @@
+use crate::{LiquidityPoolAccountBalance, LiquidityPoolBalances};
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::domain::YoctoNear;
+use oysterpack_smart_near::near_sdk::json_types::{ValidAccountId, U128};
+use oysterpack_smart_near::{ErrCode, ErrorConst, Level, LogEvent};
+
+/// # **Contract Interface**: STAKE/NEAR Instant-Swap Liquidity Pool API
+///
+/// Unstaked NEAR is locked for 4 epochs before it can be withdrawn. The liquidity pool enables
+/// stakers to swap STAKE for NEAR instantly, which bypasses the unbonding delay.
+///
+/// The workflow is:
+/// 1. liquidity providers deposit NEAR into the pool via [`StakeLiquidityPool::ops_liquidity_add`]
+///    in exchange for pool shares
+/// 2. stakers swap STAKE for NEAR via [`StakeLiquidityPool::ops_liquidity_swap`] - the swapped STAKE
+///    is unstaked on behalf of the pool and the NEAR is paid out from the pool's NEAR reserve
+/// 3. once the pool's unstaked NEAR unlocks, it is returned to the pool's NEAR reserve
+/// 4. liquidity providers redeem their shares for NEAR via [`StakeLiquidityPool::ops_liquidity_remove`]
+///
+/// ## NOTES
+/// - swaps are priced as a constant-product market - see [`crate::LiquidityPool::swap_output`]
+/// - the swap fee is retained by the pool, which increases the NEAR value of the pool shares
+/// - the swap fee is set by the operator via [`crate::StakingPoolOperatorCommand::UpdateLiquidityPoolFee`]
+///   and defaults to [`crate::LiquidityPool::DEFAULT_FEE`]
+/// - the pool shares storage usage is charged to the account
+/// - an account that owns pool shares cannot be unregistered, unless forced in which case the shares
+///   are forfeited to the pool
+pub trait StakeLiquidityPool {
+    /// Deposits the attached NEAR into the pool in exchange for pool shares
+    /// - logs [`LOG_EVENT_LIQUIDITY_POOL_ADD`]
+    ///
+    /// Returns the account's updated pool balance
+    ///
+    /// ## Panics
+    /// - if the account is not registered
+    /// - if no deposit is attached
+    ///
+    /// `#[payable]`
+    fn ops_liquidity_add(&mut self) -> LiquidityPoolAccountBalance;
+
+    /// Redeems the pool shares for NEAR, which is transferred to the predecessor account
+    /// - if `shares` is not specified, then all of the account's shares are redeemed
+    /// - logs [`LOG_EVENT_LIQUIDITY_POOL_REMOVE`]
+    ///
+    /// Returns the amount of NEAR that was transferred
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if the account is not registered
+    /// - if the account has insufficient shares
+    /// - if the pool's NEAR reserve is insufficient to redeem the shares, i.e., the pool's value is
+    ///   locked in unstaked NEAR
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_liquidity_remove(&mut self, shares: Option<U128>) -> YoctoNear;
+
+    /// Swaps the STAKE for NEAR, which is transferred to the predecessor account
+    /// - logs [`LOG_EVENT_LIQUIDITY_POOL_SWAP`]
+    ///
+    /// Returns the amount of NEAR that was transferred
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if the account is not registered
+    /// - if the account has insufficient vested STAKE
+    /// - if the swap output is zero or less than `min_near_out` - [`ERR_LIQUIDITY_POOL_SLIPPAGE`]
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_liquidity_swap(
+        &mut self,
+        amount: TokenAmount,
+        min_near_out: Option<YoctoNear>,
+    ) -> YoctoNear;
+
+    /// returns the amount of NEAR that would be paid out for swapping the STAKE amount
+    fn ops_liquidity_swap_quote(&self, amount: TokenAmount) -> YoctoNear;
+
+    fn ops_liquidity_pool_balances(&self) -> LiquidityPoolBalances;
+
+    /// returns None if the account owns no pool shares
+    fn ops_liquidity_balance(
+        &self,
+        account_id: ValidAccountId,
+    ) -> Option<LiquidityPoolAccountBalance>;
+}
+
+pub const LOG_EVENT_LIQUIDITY_POOL_ADD: LogEvent = LogEvent(Level::INFO, "LIQUIDITY_POOL_ADD");
+pub const LOG_EVENT_LIQUIDITY_POOL_REMOVE: LogEvent =
+    LogEvent(Level::INFO, "LIQUIDITY_POOL_REMOVE");
+pub const LOG_EVENT_LIQUIDITY_POOL_SWAP: LogEvent = LogEvent(Level::INFO, "LIQUIDITY_POOL_SWAP");
+pub const LOG_EVENT_LIQUIDITY_POOL_FEE: LogEvent = LogEvent(Level::INFO, "LIQUIDITY_POOL_FEE");
+
+pub const ERR_LIQUIDITY_POOL_SLIPPAGE: ErrorConst = ErrorConst(
+    ErrCode("LIQUIDITY_POOL_SLIPPAGE"),
+    "swap output is less than the minimum amount",
+);
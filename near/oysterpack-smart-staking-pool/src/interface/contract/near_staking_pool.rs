@@ -1,4 +1,4 @@
-use oysterpack_smart_near::domain::YoctoNear;
+use oysterpack_smart_near::domain::{BasisPoints, YoctoNear};
 use oysterpack_smart_near::near_sdk::AccountId;
 use oysterpack_smart_near::near_sdk::{
     json_types::ValidAccountId,
@@ -23,10 +23,21 @@ pub trait NearStakingPool {
 
     fn get_account(&self, account_id: ValidAccountId) -> NearStakingPoolAccount;
 
+    /// Returns the earnings fee as a fraction, i.e., the earnings fee [`BasisPoints`] over 10000
+    fn get_reward_fee_fraction(&self) -> RewardFeeFraction;
+
+    /// Returns the number of registered accounts
+    fn get_number_of_accounts(&self) -> u64;
+
+    /// Returns up to `limit` registered accounts starting from the `from_index` position in the
+    /// account registry
+    /// - account positions are not stable across account unregistrations
+    fn get_accounts(&self, from_index: u64, limit: u64) -> Vec<NearStakingPoolAccount>;
+
     /// delegates to `StorageManagement::storage_deposit(None, None)`
     fn deposit(&mut self);
 
-    /// delegates to `StakingPool::ops_stake()`
+    /// delegates to `StakingPool::ops_stake(None)`
     fn deposit_and_stake(&mut self);
 
     fn withdraw(&mut self, amount: YoctoNear);
@@ -53,3 +64,20 @@ pub struct NearStakingPoolAccount {
     /// Whether the unstaked balance is available for withdrawal now.
     pub can_withdraw: bool,
 }
+
+/// Reward fee expressed as a fraction, which is how the legacy staking pool reports its fee
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, Copy)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct RewardFeeFraction {
+    pub numerator: u32,
+    pub denominator: u32,
+}
+
+impl From<BasisPoints> for RewardFeeFraction {
+    fn from(fee: BasisPoints) -> Self {
+        Self {
+            numerator: fee.value() as u32,
+            denominator: 10000,
+        }
+    }
+}
@@ -1,32 +1,340 @@
-use crate::Fees;
-use oysterpack_smart_near::domain::{BasisPoints, PublicKey};
-use oysterpack_smart_near::near_sdk::serde::{Deserialize, Serialize};
+use crate::{
+    FeeChangePreview, FeeHoliday, Fees, GasReserve, PendingCommand, StakeAttestationGate, StakeCap,
+    StakeKeyCustody, StakePriceOracle, StakePublicKeyRotation, StakeWaitlistPosition,
+    StakeWaitlistSummary, UnstakeBatchingSummary,
+};
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::numbers::U64;
+use oysterpack_smart_near::domain::{BasisPoints, EpochHeight, PublicKey, YoctoNear};
+use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
+use oysterpack_smart_near::near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+use oysterpack_smart_near::{ErrCode, ErrorConst, Level, LogEvent};
 
 /// # **Contract Interface**: Staking Pool Operator API
 pub trait StakingPoolOperator {
     /// Executes the specified operator command
     ///
+    /// If a command timelock is configured, then sensitive commands are queued and can only be
+    /// executed once the timelock delay has elapsed, which gives stakers time to exit, e.g., before
+    /// fee hikes - see [`StakingPoolOperatorCommand::UpdateCommandTimelock`]. The following commands
+    /// are timelocked:
+    /// - [`StakingPoolOperatorCommand::UpdateFees`]
+    /// - [`StakingPoolOperatorCommand::UpdatePublicKey`]
+    /// - [`StakingPoolOperatorCommand::ClearKeyCustodian`]
+    /// - [`StakingPoolOperatorCommand::UpdateCommandTimelock`], when the delay is decreased
+    ///
+    /// Queued commands log [`LOG_EVENT_COMMAND_QUEUED`]
+    ///
     /// ## Panics
     /// - if predecessor account is not registered
     /// - if predecessor account is not authorized - requires operator permission
+    /// - [`ERR_STAKE_ACTION_PENDING`] if the command changes the staking status or the staking
+    ///   public key while stake actions are in flight
+    /// - [`oysterpack_smart_near::asserts::ERR_INVALID`] if the command is invalid, e.g., fees that
+    ///   exceed the max fee - timelocked commands are validated before they are queued
+    /// - [`oysterpack_smart_near::asserts::ERR_ILLEGAL_STATE`] if the timelock queue is full - see
+    ///   [`crate::CommandTimelock::MAX_PENDING_COMMANDS`]
     fn ops_stake_operator_command(&mut self, command: StakingPoolOperatorCommand);
+
+    /// returns the STAKE price oracle configuration - see [`StakingPoolOperatorCommand::SetPriceOracle`]
+    fn ops_stake_price_oracle(&self) -> Option<StakePriceOracle>;
+
+    /// returns true if STAKE transfers to unregistered receivers auto-register the receiver - see
+    /// [`StakingPoolOperatorCommand::EnableTransferAutoRegistration`]
+    fn ops_stake_transfer_auto_registration(&self) -> bool;
+
+    /// returns true if `storage_deposit` amounts above the storage balance minimum are staked by
+    /// default - see [`StakingPoolOperatorCommand::EnableStorageDepositAutoStake`]
+    fn ops_stake_storage_deposit_auto_stake(&self) -> bool;
+
+    /// returns true if pool balances snapshots are logged when earnings are recognized - see
+    /// [`StakingPoolOperatorCommand::EnablePoolBalancesEvents`]
+    fn ops_stake_pool_balances_events(&self) -> bool;
+
+    /// returns the total staked balance cap - see [`StakingPoolOperatorCommand::SetStakeCap`]
+    fn ops_stake_cap(&self) -> Option<StakeCap>;
+
+    /// returns the stake waitlist summary - see [`StakingPoolOperatorCommand::EnableStakeWaitlist`]
+    fn ops_stake_waitlist(&self) -> StakeWaitlistSummary;
+
+    /// returns the account's position in the stake waitlist, or None if the account is not
+    /// waitlisted
+    fn ops_stake_waitlist_position(
+        &self,
+        account_id: ValidAccountId,
+    ) -> Option<StakeWaitlistPosition>;
+
+    /// returns the unstake batching config and pending batch - see
+    /// [`StakingPoolOperatorCommand::EnableUnstakeBatching`]
+    fn ops_stake_unstake_batching(&self) -> UnstakeBatchingSummary;
+
+    /// returns the minimum NEAR deposit that can be staked - see [`StakingPoolOperatorCommand::UpdateMinStake`]
+    fn ops_stake_min_stake(&self) -> YoctoNear;
+
+    /// returns the staking attestation gate - see [`StakingPoolOperatorCommand::SetAttestationGate`]
+    fn ops_stake_attestation_gate(&self) -> Option<StakeAttestationGate>;
+
+    /// returns the gas reserve configuration - see [`StakingPoolOperatorCommand::SetGasReserve`]
+    /// - the current gas reserve balance is reported via [`crate::StakingPoolBalances::gas_reserve`]
+    fn ops_stake_gas_reserve(&self) -> Option<GasReserve>;
+
+    /// returns the key custodian configuration - see [`StakingPoolOperatorCommand::SetKeyCustodian`]
+    fn ops_stake_key_custodian(&self) -> Option<StakeKeyCustody>;
+
+    /// returns the staking public key rotations, ordered from oldest to newest
+    /// - the history is bounded to the most recent [`crate::StakePublicKeyHistory::MAX_ROTATIONS`]
+    ///   rotations
+    fn ops_stake_public_key_history(&self) -> Vec<StakePublicKeyRotation>;
+
+    /// returns the commands that are queued by the timelock, ordered by ID
+    fn ops_stake_pending_commands(&self) -> Vec<PendingCommand>;
+
+    /// returns the command timelock delay in blocks - zero means the timelock is disabled
+    fn ops_stake_command_timelock(&self) -> U64;
+
+    /// Executes the pending command once its execution-eligible block height has been reached
+    /// - logs [`LOG_EVENT_COMMAND_EXECUTED`]
+    ///
+    /// ## Panics
+    /// - if predecessor account is not authorized - requires operator permission
+    /// - if the pending command does not exist
+    /// - if the pending command is not yet eligible for execution
+    fn ops_stake_execute_pending_command(&mut self, id: U64);
+
+    /// Cancels the pending command
+    /// - logs [`LOG_EVENT_COMMAND_CANCELLED`]
+    ///
+    /// ## Panics
+    /// - if predecessor account is not authorized - requires operator permission
+    /// - if the pending command does not exist
+    fn ops_stake_cancel_pending_command(&mut self, id: U64);
+
+    /// Schedules a promotional window during which the reduced staking fee applies automatically
+    /// - the window is inclusive, i.e., the reduced fee applies from the start epoch through the
+    ///   end epoch
+    /// - if the configured staking fee is lowered below the fee holiday fee, then the configured
+    ///   staking fee applies
+    /// - logs [`crate::LOG_EVENT_FEE_HOLIDAY_SCHEDULED`]
+    ///
+    /// ## Panics
+    /// - if predecessor account is not authorized - requires operator permission
+    /// - if the end epoch is before the start epoch or the current epoch
+    /// - if the staking fee is not less than the configured staking fee
+    /// - if the window overlaps with a scheduled fee holiday
+    /// - if [`crate::FeeHolidays::MAX_SCHEDULED`] fee holidays are already scheduled
+    fn ops_stake_schedule_fee_holiday(
+        &mut self,
+        start_epoch: EpochHeight,
+        end_epoch: EpochHeight,
+        staking_fee_bps: BasisPoints,
+    );
+
+    /// Cancels the fee holiday that starts on the specified epoch
+    /// - logs [`crate::LOG_EVENT_FEE_HOLIDAY_CANCELLED`]
+    ///
+    /// ## Panics
+    /// - if predecessor account is not authorized - requires operator permission
+    /// - if the fee holiday does not exist
+    fn ops_stake_cancel_fee_holiday(&mut self, start_epoch: EpochHeight);
+
+    /// returns the fee holidays that have not yet expired, ordered by start epoch
+    fn ops_stake_fee_holidays(&self) -> Vec<FeeHoliday>;
+
+    /// Dry run for [`StakingPoolOperatorCommand::UpdateFees`], which projects the owner revenue
+    /// and staker APY impact of the proposed fees based on the trailing earnings history - see
+    /// [`FeeChangePreview`]
+    /// - returns None if there is not enough earnings history, i.e., the recorded earnings
+    ///   snapshots do not span at least 1 epoch
+    ///
+    /// ## Panics
+    /// - if the proposed fees are invalid, i.e., the same validation that is applied by
+    ///   [`StakingPoolOperatorCommand::UpdateFees`]
+    fn ops_stake_fee_change_preview(&self, fees: Fees) -> Option<FeeChangePreview>;
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
 pub enum StakingPoolOperatorCommand {
+    /// rejected with [`ERR_STAKE_ACTION_PENDING`] while stake actions are in flight
     StopStaking,
+    /// rejected with [`ERR_STAKE_ACTION_PENDING`] while stake actions are in flight
     StartStaking,
 
     /// the staking pool public key can only be changed while the staking pool is offline
+    /// - rejected with [`ERR_STAKE_ACTION_PENDING`] while stake actions are in flight
+    /// - if a key custodian is configured, then the rotation must be approved by the custodian -
+    ///   see [`StakingPoolOperatorCommand::SetKeyCustodian`]
+    /// - rotations are recorded - see [`StakingPoolOperator::ops_stake_public_key_history`]
     UpdatePublicKey(PublicKey),
     /// max fee is 1000 BPS (10%)
     UpdateFees(Fees),
+    /// share of the staking fee that is paid to referrers - see [`crate::StakeReferrals`]
+    /// - max referral fee is 10000 BPS (100%)
+    UpdateReferralFee(BasisPoints),
+    /// fee that is charged on liquidity pool swaps - see [`crate::StakeLiquidityPool`]
+    /// - max liquidity pool fee is 1000 BPS (10%)
+    UpdateLiquidityPoolFee(BasisPoints),
+
+    /// collects any earnings that have been received since the last time earnings were checked
+    /// and pays the treasury dividend
+    /// - if the pool is online and earnings were staked, then the stake action is submitted
+    UpdateEarnings,
+    /// updates earnings and then verifies the staking pool balance invariants:
+    /// - the validator locked balance covers the total staked balance, including buffered earnings
+    ///   - only checked while the pool is online and no stake actions are in flight
+    /// - the contract managed total balance covers the total staked, unstaked, liquidity, and
+    ///   liquidity pool NEAR reserve balances
+    /// - the last contract managed total balance is in sync with the contract managed total balance
+    ///
+    /// Any drift that is detected is logged via [`LOG_EVENT_BALANCE_DRIFT`]. If `correct_drift` is
+    /// true, then the last contract managed total balance will be reset to the current contract managed
+    /// total balance.
+    ReconcileBalances {
+        correct_drift: bool,
+    },
+
+    /// registers the oracle contract that the STAKE token value is published to when it moves more
+    /// than the `threshold` since it was last published - see [`crate::StakePriceOracleReceiver`]
+    /// - replaces any existing oracle
+    SetPriceOracle {
+        account_id: ValidAccountId,
+        threshold: BasisPoints,
+    },
+    ClearPriceOracle,
+
+    /// opt-in mode where STAKE transfers to unregistered receivers auto-register the receiver
+    /// - the storage balance minimum NEAR value is deducted from the transferred STAKE, i.e., the
+    ///   STAKE is burned and the NEAR is credited to the receiver's storage balance
+    /// - logs [`LOG_EVENT_TRANSFER_AUTO_REGISTRATION`]
+    EnableTransferAutoRegistration,
+    DisableTransferAutoRegistration,
+
+    /// opt-in mode where `storage_deposit` amounts above the storage balance minimum are staked,
+    /// i.e., the account's storage available balance is staked after the deposit
+    /// - callers can override the mode per call via the `storage_deposit` `stake` arg
+    EnableStorageDepositAutoStake,
+    DisableStorageDepositAutoStake,
+
+    /// invite-only mode where only accounts with staker permission can stake, and new accounts can
+    /// only be registered by admins - see [`crate::StakePermissionedPool`]
+    /// - registered accounts can always unstake and withdraw
+    EnablePermissionedPool,
+    DisablePermissionedPool,
+
+    /// sets the minimum NEAR deposit that can be staked - see [`crate::MinStake`]
+    /// - zero means there is no minimum
+    UpdateMinStake(YoctoNear),
+
+    /// logs the full [`crate::StakingPoolBalancesSnapshot`] via [`crate::LOG_EVENT_POOL_BALANCES`]
+    /// each time earnings are recognized - see [`crate::StakingPoolBalancesHistory`]
+    EnablePoolBalancesEvents,
+    DisablePoolBalancesEvents,
+
+    /// sets the bounty that is paid to keepers that call [`crate::StakingPool::ops_stake_compound`]
+    /// as a percentage of the newly recognized earnings - see [`crate::CompoundBounty`]
+    /// - max bounty is 100 BPS (1%)
+    UpdateCompoundBounty(BasisPoints),
+
+    /// sets the number of blocks that timelocked commands are delayed by before they can be executed
+    /// - zero disables the timelock
+    /// - decreasing the delay is itself timelocked
+    UpdateCommandTimelock(U64),
+
+    /// stakes the accumulated rounding dust into the treasury, i.e., the dust is converted into
+    /// STAKE that is minted on the treasury account - see [`crate::components::staking_pool::State::ROUNDING_DUST`]
+    /// - any dust that is too small to convert into STAKE remains in the rounding dust balance
+    /// - if the pool is online and dust was staked, then the stake action is submitted
+    /// - logs [`crate::LOG_EVENT_ROUNDING_DUST_SWEEP`]
+    SweepRoundingDust,
+
+    /// sets the ceiling on the total staked balance - NEAR that would push the total staked balance
+    /// over the cap is credited to the account's storage balance instead of being staked
+    /// - replaces any existing cap
+    /// - the cap amount or share must be > 0, and the share must not exceed 10000 BPS (100%)
+    /// - waitlisted NEAR is admitted up to the new cap capacity
+    SetStakeCap(StakeCap),
+    /// waitlisted NEAR is admitted when the cap is cleared
+    ClearStakeCap,
+
+    /// NEAR that would push the total staked balance over the stake cap is placed in a FIFO
+    /// waitlist, which is staked when capacity frees up via unstaking or a stake cap change, or via
+    /// the [`crate::StakingPool::ops_stake_admit_waitlist`] keeper - see [`crate::StakeWaitlist`]
+    /// - disabling the waitlist clears it - the waitlisted NEAR remains in the account storage
+    ///   balances
+    EnableStakeWaitlist,
+    DisableStakeWaitlist,
+
+    /// unstake requests are aggregated into a batch instead of submitting a stake action per
+    /// unstake, which reduces promise volume and validator stake churn - the batch is unstaked from
+    /// the validator at most once per epoch via [`crate::StakingPool::ops_stake_process_epoch`] - see
+    /// [`crate::UnstakeBatching`]
+    /// - disabling batching does not clear the pending batch
+    EnableUnstakeBatching,
+    DisableUnstakeBatching,
+
+    /// registers the external attestation contract that accounts must be verified by before they
+    /// can stake - see [`crate::StakeAttestationVerifier`]
+    /// - verified attestations are cached per account for `ttl` blocks
+    /// - replaces any existing gate
+    SetAttestationGate {
+        account_id: ValidAccountId,
+        ttl: U64,
+    },
+    ClearAttestationGate,
+
+    /// configures the rewards farm that distributes the NEP-141 reward token to STAKE holders at
+    /// the specified emission rate per second - see [`crate::StakeRewardsFarm`]
+    /// - if the farm is already configured, then the reward token cannot be changed - rewards are
+    ///   emitted at the current rate up to the current block before the new rate is applied
+    /// - logs [`crate::LOG_EVENT_REWARDS_FARM`]
+    SetRewardsFarm {
+        token_id: ValidAccountId,
+        emission_rate: TokenAmount,
+    },
+
+    /// diverts the specified percentage of earnings into the contract gas reserve balance until the
+    /// target balance is reached - see [`GasReserve`]
+    /// - max fee is 100 BPS (1%)
+    /// - the target must be > 0
+    /// - earnings that were collected up to now are distributed using the current gas reserve
+    ///   configuration
+    /// - logs [`LOG_EVENT_GAS_RESERVE`] when earnings are diverted
+    SetGasReserve(GasReserve),
+    /// stops diverting earnings into the gas reserve - the gas reserve balance is retained
+    ClearGasReserve,
+
+    /// delegates staking public key management to the external key custodian contract - see
+    /// [`crate::StakeKeyCustodian`]
+    /// - replaces any existing custodian
+    SetKeyCustodian(ValidAccountId),
+    ClearKeyCustodian,
+
+    /// re-measures the storage usage that is allocated by account operations, e.g., after a contract
+    /// upgrade that changes the account storage layout - see [`crate::StakingPool::ops_storage_cost_of`]
+    MeasureStorageOpCosts,
 }
 
 /// 10%
 pub const MAX_FEE: BasisPoints = BasisPoints(1000);
 
+pub const ERR_STAKE_ACTION_PENDING: ErrorConst = ErrorConst(
+    ErrCode("STAKE_ACTION_PENDING"),
+    "stake action is in flight - retry once the stake action has been finalized",
+);
+
+pub const LOG_EVENT_BALANCE_DRIFT: LogEvent = LogEvent(Level::WARN, "BALANCE_DRIFT");
+pub const LOG_EVENT_TRANSFER_AUTO_REGISTRATION: LogEvent =
+    LogEvent(Level::INFO, "TRANSFER_AUTO_REGISTRATION");
+pub const LOG_EVENT_COMMAND_QUEUED: LogEvent = LogEvent(Level::INFO, "COMMAND_QUEUED");
+pub const LOG_EVENT_COMMAND_EXECUTED: LogEvent = LogEvent(Level::INFO, "COMMAND_EXECUTED");
+pub const LOG_EVENT_COMMAND_CANCELLED: LogEvent = LogEvent(Level::INFO, "COMMAND_CANCELLED");
+pub const LOG_EVENT_BALANCES_RECONCILED: LogEvent = LogEvent(Level::INFO, "BALANCES_RECONCILED");
+/// log message format: `amount={amount}, balance={balance}, target={target}`
+pub const LOG_EVENT_GAS_RESERVE: LogEvent = LogEvent(Level::INFO, "GAS_RESERVE");
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,5 +362,32 @@ mod tests {
             ))
             .unwrap()
         );
+        println!(
+            "{}",
+            serde_json::to_string(&StakingPoolOperatorCommand::UpdateReferralFee(2000.into()))
+                .unwrap()
+        );
+        println!(
+            "{}",
+            serde_json::to_string(&StakingPoolOperatorCommand::UpdateLiquidityPoolFee(
+                50.into()
+            ))
+            .unwrap()
+        );
+        println!(
+            "{}",
+            serde_json::to_string(&StakingPoolOperatorCommand::SetPriceOracle {
+                account_id: serde_json::from_str(r#""oracle.near""#).unwrap(),
+                threshold: 10.into()
+            })
+            .unwrap()
+        );
+        println!(
+            "{}",
+            serde_json::to_string(&StakingPoolOperatorCommand::ReconcileBalances {
+                correct_drift: true
+            })
+            .unwrap()
+        );
     }
 }
@@ -0,0 +1,62 @@
+use crate::{EarningsFeeRecipient, EarningsFeeSplit, OwnerEarningsMode, OwnerRevenue};
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// # **Contract Interface**: Staking Pool Owner Earnings API
+///
+/// The owner earns the earnings fee on all staking pool earnings, which by default is minted as STAKE
+/// on the owner's account, i.e., the owner's earnings are restaked. Owners who want liquid income can
+/// switch to [`OwnerEarningsMode::Near`].
+///
+/// The earnings fee can be split between multiple recipients, e.g., owner, operating team, and a DAO -
+/// see [`EarningsFeeSplit`]. Each recipient is paid out using the owner earnings mode.
+pub trait StakingPoolOwnerEarnings {
+    /// Sets how the owner's earnings fee is paid out
+    /// - earnings are collected before the mode is changed, i.e., earnings that were received up to
+    ///   now are paid out using the current mode
+    /// - logs [`LOG_EVENT_OWNER_EARNINGS_MODE`]
+    ///
+    /// ## NOTES
+    /// - in [`OwnerEarningsMode::Near`] mode, the earnings fee is credited to the owner's unstaked
+    ///   balance. Because staking rewards are earned on the staked balance, the NEAR is only liquid
+    ///   after the earnings fee is unstaked by the next stake action and the unstaked NEAR is
+    ///   unlocked by the validator. Until then, it is locked like any other unstaked balance.
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if not invoked by the owner
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_owner_set_earnings_mode(&mut self, mode: OwnerEarningsMode);
+
+    fn ops_owner_earnings_mode(&self) -> OwnerEarningsMode;
+
+    /// Sets the earnings fee split table
+    /// - the recipient BPS must sum up to the current earnings fee
+    /// - if a recipient is no longer registered when earnings are distributed, then its share is
+    ///   paid to the owner
+    /// - earnings are collected before the split is changed, i.e., earnings that were received up
+    ///   to now are paid out using the current split
+    /// - setting the split to None clears the split, i.e., the full earnings fee is paid to the owner
+    /// - logs [`LOG_EVENT_EARNINGS_FEE_SPLIT`]
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if not invoked by the owner
+    /// - if no recipients are specified or more than [`EarningsFeeSplit::MAX_RECIPIENTS`] recipients
+    ///   are specified
+    /// - if a recipient is specified more than once, is not registered, or has zero BPS
+    /// - if the recipient BPS do not sum up to the current earnings fee
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_owner_set_earnings_fee_split(&mut self, recipients: Option<Vec<EarningsFeeRecipient>>);
+
+    fn ops_owner_earnings_fee_split(&self) -> Option<EarningsFeeSplit>;
+
+    /// Returns lifetime totals for the revenue that the pool ownership collected, i.e., staking
+    /// fees, earnings fees, and treasury transfers
+    fn ops_owner_revenue(&self) -> OwnerRevenue;
+}
+
+pub const LOG_EVENT_OWNER_EARNINGS_MODE: LogEvent = LogEvent(Level::INFO, "OWNER_EARNINGS_MODE");
+
+pub const LOG_EVENT_EARNINGS_FEE_SPLIT: LogEvent = LogEvent(Level::INFO, "EARNINGS_FEE_SPLIT");
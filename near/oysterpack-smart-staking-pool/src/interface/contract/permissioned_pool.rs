@@ -0,0 +1,50 @@
+use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
+use oysterpack_smart_near::{ErrCode, ErrorConst};
+
+/// # **Contract Interface**: Permissioned Staking Pool API
+///
+/// When the permissioned pool mode is enabled, the staking pool is invite-only, e.g., for family
+/// offices and DAOs that run internal pools:
+/// - only accounts that are granted [`PERMISSION_STAKER`] permission can stake
+/// - new accounts can only be registered by admins, i.e., accounts are invited by registering them
+///   on their behalf via `storage_deposit` and then granting them the staker permission
+/// - STAKE transfers do not auto-register receivers - see [`crate::StakingPoolOperatorCommand::EnableTransferAutoRegistration`]
+/// - registered accounts can always unstake and withdraw, i.e., revoking the staker permission
+///   does not lock up the account's funds
+///
+/// The mode is toggled via [`crate::StakingPoolOperatorCommand::EnablePermissionedPool`] and
+/// [`crate::StakingPoolOperatorCommand::DisablePermissionedPool`]
+pub trait StakePermissionedPool {
+    /// returns true if the permissioned pool mode is enabled
+    fn ops_stake_permissioned_pool(&self) -> bool;
+
+    /// grants staker permission to specified account
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if not invoked by admin
+    /// - if specified account is not registered
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_stake_grant_staker(&mut self, account_id: ValidAccountId);
+
+    /// revokes staker permission from specified account
+    /// - the account can still unstake and withdraw
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if not invoked by admin
+    /// - if specified account is not registered
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_stake_revoke_staker(&mut self, account_id: ValidAccountId);
+
+    fn ops_stake_is_staker(&self, account_id: ValidAccountId) -> bool;
+}
+
+pub const PERMISSION_STAKER: &str = "staker";
+
+pub const ERR_STAKER_PERMISSION_REQUIRED: ErrorConst = ErrorConst(
+    ErrCode("STAKER_PERMISSION_REQUIRED"),
+    "staking pool is permissioned - account requires staker permission",
+);
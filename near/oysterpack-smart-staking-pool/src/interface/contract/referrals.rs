@@ -0,0 +1,48 @@
+use crate::StakeAccountBalances;
+use oysterpack_smart_near::domain::BasisPoints;
+use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
+use oysterpack_smart_near::near_sdk::{AccountId, PromiseOrValue};
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// # **Contract Interface**: Stake Referrals API
+///
+/// Enables integrators to earn a share of the staking fees that are charged on the stake they refer.
+///
+/// The workflow is:
+/// 1. the integrator registers its account with the contract, which enables it to hold STAKE
+/// 2. the staker stakes via [`StakeReferrals::ops_stake_with_referrer`], which records the referrer
+///    on the staker's account
+/// 3. each time staking fees are charged on the staker's account, the referral fee share of the
+///    staking fee is paid to the referrer's STAKE balance instead of the owner
+///
+/// ## NOTES
+/// - the referral fee is set by the operator via [`crate::StakingPoolOperatorCommand::UpdateReferralFee`]
+///   and is expressed as a percentage of the staking fee - it defaults to zero
+/// - the referrer storage usage is charged to the account, and the referrer is deleted when the
+///   account unregisters
+/// - if the referrer unregisters, then the full staking fee is paid to the owner
+pub trait StakeReferrals {
+    /// Records the referrer on the predecessor account, replacing any existing referrer, and then
+    /// stakes NEAR - see [`crate::StakingPool::ops_stake`]
+    ///
+    /// ## Panics
+    /// - if the account is not registered
+    /// - if the referrer is not registered
+    /// - if the referrer is the predecessor account
+    /// - if the attached deposit is less than the minimum stake - [`crate::ERR_STAKE_BELOW_MIN`]
+    ///
+    /// `#[payable]`
+    fn ops_stake_with_referrer(
+        &mut self,
+        referrer: ValidAccountId,
+    ) -> PromiseOrValue<StakeAccountBalances>;
+
+    /// returns the account's referrer
+    fn ops_stake_referrer(&self, account_id: ValidAccountId) -> Option<AccountId>;
+
+    /// returns the share of the staking fee that is paid to referrers
+    fn ops_stake_referral_fee(&self) -> BasisPoints;
+}
+
+pub const LOG_EVENT_STAKE_REFERRER: LogEvent = LogEvent(Level::INFO, "STAKE_REFERRER");
+pub const LOG_EVENT_REFERRAL_FEE: LogEvent = LogEvent(Level::INFO, "REFERRAL_FEE");
@@ -0,0 +1,98 @@
+use crate::{RelayKey, RelayedStakeRequest, StakeAccountBalances};
+use oysterpack_smart_near::domain::PublicKey;
+use oysterpack_smart_near::near_sdk::json_types::{Base64VecU8, ValidAccountId};
+use oysterpack_smart_near::near_sdk::PromiseOrValue;
+use oysterpack_smart_near::{ErrCode, ErrorConst, Level, LogEvent};
+
+/// # **Contract Interface**: Relayed Staking API
+///
+/// Enables accounts to stake and unstake via signed requests that are submitted by a relayer, i.e.,
+/// the relayer pays for the transaction gas. This enables wallets that do not hold NEAR for gas to
+/// manage their stake.
+///
+/// The workflow is:
+/// 1. the account registers an ED25519 relay key via [`RelayedStaking::ops_stake_relay_set_key`]
+/// 2. the account signs a [`RelayedStakeRequest`] using the relay key - the signature is computed
+///    over the borsh serialized request
+/// 3. the relayer submits the request and signature via [`RelayedStaking::ops_stake_relayed`]
+///
+/// ## NOTES
+/// - each request must use the next nonce, i.e., `relay_key.nonce + 1`, which protects against
+///   replay attacks
+/// - when the relay key is set, the nonce is initialized to `block_height * 1_000_000`, which ensures
+///   that signed requests from a previous key registration cannot be replayed
+/// - the relay key storage usage is charged to the account, and the key is deleted when the account
+///   unregisters
+/// - withdrawals are not supported because funds are always transferred to the predecessor account
+pub trait RelayedStaking {
+    /// Registers the relay key for the predecessor account, replacing any existing key
+    ///
+    /// ## Panics
+    /// - if the account is not registered
+    /// - if the key is not an ED25519 key
+    fn ops_stake_relay_set_key(&mut self, public_key: PublicKey) -> RelayKey;
+
+    /// Removes the predecessor account's relay key, which revokes relayed requests
+    ///
+    /// ## Panics
+    /// - if the account is not registered
+    fn ops_stake_relay_clear_key(&mut self);
+
+    fn ops_stake_relay_key(&self, account_id: ValidAccountId) -> Option<RelayKey>;
+
+    /// Verifies the request signature and nonce, and then executes the request on behalf of the
+    /// account that signed the request
+    ///
+    /// Returns the account's updated stake account balance after the contract's stake action completes
+    ///
+    /// ## Panics
+    /// - if a deposit is attached
+    /// - if the request is not for this contract
+    /// - if the account is not registered or has no relay key
+    /// - [`ERR_INVALID_RELAY_NONCE`] - if the nonce is not the next expected nonce
+    /// - [`ERR_INVALID_RELAY_SIGNATURE`] - if the signature fails verification
+    fn ops_stake_relayed(
+        &mut self,
+        request: RelayedStakeRequest,
+        signature: Base64VecU8,
+    ) -> PromiseOrValue<StakeAccountBalances>;
+}
+
+pub const LOG_EVENT_RELAYED_STAKE: LogEvent = LogEvent(Level::INFO, "RELAYED_STAKE");
+
+pub const ERR_RELAY_KEY_NOT_SET: ErrorConst =
+    ErrorConst(ErrCode("RELAY_KEY_NOT_SET"), "account has no relay key");
+
+pub const ERR_INVALID_RELAY_NONCE: ErrorConst = ErrorConst(
+    ErrCode("INVALID_RELAY_NONCE"),
+    "invalid relayed request nonce",
+);
+
+pub const ERR_INVALID_RELAY_SIGNATURE: ErrorConst = ErrorConst(
+    ErrCode("INVALID_RELAY_SIGNATURE"),
+    "invalid relayed request signature",
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RelayedStakeAction;
+    use oysterpack_smart_near::near_sdk::serde_json;
+
+    #[test]
+    fn request_json_serialization() {
+        let request = RelayedStakeRequest {
+            account_id: "alfio".to_string(),
+            contract_id: "stake".to_string(),
+            nonce: 1.into(),
+            action: RelayedStakeAction::Unstake(Some(100.into())),
+        };
+        let json = serde_json::to_string(&request).unwrap();
+        println!("{}", json);
+        let request2: RelayedStakeRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(request, request2);
+
+        let json = serde_json::to_string(&RelayedStakeAction::Stake).unwrap();
+        println!("{}", json);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::RewardsBeneficiary;
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// # **Contract Interface**: Stake Rewards Beneficiary API
+///
+/// Enables stakers to donate their staking rewards, e.g., "stake for charity", while retaining their
+/// principal.
+///
+/// The workflow is:
+/// 1. the beneficiary registers its account with the contract, which enables it to hold STAKE
+/// 2. the staker sets the beneficiary via [`StakeRewardsBeneficiary::ops_stake_set_rewards_beneficiary`],
+///    which checkpoints the staker's principal at the current STAKE token value
+/// 3. as earnings are distributed, the growth in the staker's STAKE NEAR value above the principal
+///    is skimmed and transferred as STAKE to the beneficiary
+///
+/// Rewards are skimmed:
+/// - before the account stakes or unstakes
+/// - when the beneficiary is changed or cleared
+/// - periodically via [`StakeRewardsBeneficiary::ops_stake_skim_rewards`], which anyone can call,
+///   e.g., a keeper
+///
+/// ## NOTES
+/// - STAKE that is received between skims is treated as if it was held since the last skim, i.e.,
+///   skimming more frequently improves precision
+/// - only vested STAKE is transferred - see [`crate::StakeVesting`]
+/// - if the beneficiary unregisters, then rewards accrue on the account until a new beneficiary is set
+/// - the rewards beneficiary storage usage is charged to the account, and it is deleted when the
+///   account unregisters
+pub trait StakeRewardsBeneficiary {
+    /// Sets the predecessor account's rewards beneficiary, replacing any existing beneficiary
+    /// - any rewards that were earned are skimmed to the current beneficiary before it is replaced
+    /// - setting the beneficiary to None stops the rewards from being donated
+    /// - logs [`LOG_EVENT_REWARDS_BENEFICIARY`]
+    ///
+    /// ## Panics
+    /// - if the account is not registered
+    /// - if the beneficiary is not registered
+    /// - if the beneficiary is the predecessor account
+    fn ops_stake_set_rewards_beneficiary(
+        &mut self,
+        beneficiary: Option<ValidAccountId>,
+    ) -> Option<RewardsBeneficiary>;
+
+    fn ops_stake_rewards_beneficiary(
+        &self,
+        account_id: ValidAccountId,
+    ) -> Option<RewardsBeneficiary>;
+
+    /// Skims the rewards that were earned on the account's STAKE since the last skim, and transfers
+    /// them as STAKE to the account's rewards beneficiary
+    /// - logs [`LOG_EVENT_REWARDS_SKIMMED`]
+    ///
+    /// Returns the amount of STAKE that was transferred to the beneficiary
+    fn ops_stake_skim_rewards(&mut self, account_id: ValidAccountId) -> TokenAmount;
+}
+
+pub const LOG_EVENT_REWARDS_BENEFICIARY: LogEvent = LogEvent(Level::INFO, "REWARDS_BENEFICIARY");
+pub const LOG_EVENT_REWARDS_SKIMMED: LogEvent = LogEvent(Level::INFO, "REWARDS_SKIMMED");
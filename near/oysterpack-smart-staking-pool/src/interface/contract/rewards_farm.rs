@@ -0,0 +1,60 @@
+use crate::RewardsFarm;
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
+use oysterpack_smart_near::near_sdk::PromiseOrValue;
+use oysterpack_smart_near::{ErrCode, ErrorConst, Level, LogEvent};
+
+/// # **Contract Interface**: STAKE Rewards Farm API
+///
+/// Distributes an external NEP-141 reward token to STAKE holders, i.e., "staking farm" style rewards.
+///
+/// The workflow is:
+/// 1. the operator configures the farm's reward token and emission rate via
+///    [`crate::StakingPoolOperatorCommand::SetRewardsFarm`]
+/// 2. the operator deposits reward tokens by transferring them to the STAKE pool contract via the
+///    reward token contract's `ft_transfer_call` - logs [`LOG_EVENT_REWARDS_FARM_DEPOSIT`]
+/// 3. the deposited reward tokens are emitted per second, and accrue to STAKE holders proportionally
+///    to their STAKE balances, including locked STAKE
+/// 4. STAKE holders claim their accrued rewards via [`StakeRewardsFarm::ops_farm_claim`]
+///
+/// ## NOTES
+/// - STAKE that is owned by the contract, i.e., the treasury, does not accrue rewards
+/// - the reward token cannot be changed once the farm is configured, but the emission rate can be
+/// - the claiming account must be registered with the reward token contract - if the reward token
+///   transfer fails, then the rewards are restored to the account
+/// - unclaimed rewards are returned to the farm's undistributed balance when the account unregisters
+/// - the account's farm rewards are checkpointed each time its STAKE balance changes, and the farm
+///   record storage usage is charged to the account
+pub trait StakeRewardsFarm {
+    /// returns the rewards farm with the rewards that have been emitted up to the current block
+    fn ops_farm(&self) -> Option<RewardsFarm>;
+
+    /// returns the account's unclaimed rewards, including the rewards that have accrued since the
+    /// account was last checkpointed
+    fn ops_farm_rewards(&self, account_id: ValidAccountId) -> TokenAmount;
+
+    /// Transfers the predecessor account's unclaimed rewards to the account via the reward token
+    /// contract's `ft_transfer`
+    /// - logs [`LOG_EVENT_REWARDS_FARM_CLAIM`]
+    ///
+    /// Returns the amount of reward tokens that were claimed
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if the account is not registered
+    /// - if the rewards farm is not configured
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_farm_claim(&mut self) -> PromiseOrValue<TokenAmount>;
+}
+
+pub const ERR_REWARDS_FARM_NOT_CONFIGURED: ErrorConst = ErrorConst(
+    ErrCode("REWARDS_FARM_NOT_CONFIGURED"),
+    "rewards farm is not configured",
+);
+
+pub const LOG_EVENT_REWARDS_FARM: LogEvent = LogEvent(Level::INFO, "REWARDS_FARM");
+pub const LOG_EVENT_REWARDS_FARM_DEPOSIT: LogEvent = LogEvent(Level::INFO, "REWARDS_FARM_DEPOSIT");
+pub const LOG_EVENT_REWARDS_FARM_CLAIM: LogEvent = LogEvent(Level::INFO, "REWARDS_FARM_CLAIM");
+pub const LOG_EVENT_REWARDS_FARM_CLAIM_FAILED: LogEvent =
+    LogEvent(Level::WARN, "REWARDS_FARM_CLAIM_FAILED");
@@ -1,5 +1,8 @@
 use crate::StakeAccountBalances;
-use oysterpack_smart_near::near_sdk::AccountId;
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::data::numbers::U64;
+use oysterpack_smart_near::domain::PublicKey;
+use oysterpack_smart_near::near_sdk::{AccountId, PromiseOrValue};
 use oysterpack_smart_near::ErrCode;
 
 /// # **Contract Interface**: Private Staking Pool Callbacks
@@ -8,20 +11,79 @@ pub trait StakeActionCallbacks {
     ///
     /// If the stake action failed, then the contract will fully unstake and go offline.
     ///
-    /// `#[private]`
-    fn ops_stake_finalize(&mut self, account_id: AccountId) -> StakeAccountBalances;
+    /// guarded by [`oysterpack_smart_near::CallbackGuard`]
+    /// - `operation_id` is the ID of the stake operation that triggered the stake action, which is
+    ///   returned in the balances
+    /// - `intent_id` is the ID of the [`crate::FinalizeIntent`] that was recorded for the stake
+    ///   action, which is removed from the [`crate::FinalizeRetryQueue`]
+    fn ops_stake_finalize(
+        &mut self,
+        account_id: AccountId,
+        operation_id: Option<U64>,
+        intent_id: Option<U64>,
+    ) -> StakeAccountBalances;
 
     /// invoked when the staking pool is brought back online and staking is resumed
     /// - the callback ensures that the retaking succeeded
+    /// - `intent_id` is the ID of the [`crate::FinalizeIntent`] that was recorded for the stake
+    ///   action
     ///
-    /// `#[private]`
-    fn ops_stake_start_finalize(&mut self);
+    /// guarded by [`oysterpack_smart_near::CallbackGuard`]
+    fn ops_stake_start_finalize(&mut self, intent_id: Option<U64>);
 
     /// invoked when the staking pool is taken offline and all NEAR is unstaked
     /// - the callback ensures that the unstaking succeeded
+    /// - `intent_id` is the ID of the [`crate::FinalizeIntent`] that was recorded for the stake
+    ///   action
+    ///
+    /// guarded by [`oysterpack_smart_near::CallbackGuard`]
+    fn ops_stake_stop_finalize(&mut self, intent_id: Option<U64>);
+
+    /// invoked when the account's attestation has been checked by the attestation contract - see
+    /// [`crate::StakeAttestationVerifier`]
+    /// - if the account is verified, then the attestation is cached and the account's storage
+    ///   balance is staked
+    /// - if the account is not verified, then the deposit remains in the account's storage balance
+    ///   and [`crate::LOG_EVENT_STAKE_ATTESTATION_REJECTED`] is logged
+    /// - `min_stake_out` is forwarded from [`crate::StakingPool::ops_stake`] - if the check fails,
+    ///   then the deposit remains in the account's storage balance
+    ///
+    /// `#[private]`
+    fn ops_stake_attestation_callback(
+        &mut self,
+        account_id: AccountId,
+        min_stake_out: Option<TokenAmount>,
+    ) -> PromiseOrValue<StakeAccountBalances>;
+
+    /// invoked when the key custodian has responded to the staking public key rotation request -
+    /// see [`crate::StakeKeyCustodian`]
+    /// - if the rotation is approved, the staking pool is still offline, and the custodian is still
+    ///   the configured key custodian, then the staking public key is updated and
+    ///   [`crate::LOG_EVENT_STAKE_PUBLIC_KEY_ROTATED`] is logged
+    /// - otherwise, [`crate::LOG_EVENT_STAKE_PUBLIC_KEY_ROTATION_REJECTED`] is logged
+    ///
+    /// Returns true if the staking public key was updated
+    ///
+    /// `#[private]`
+    fn ops_stake_public_key_rotation_callback(
+        &mut self,
+        public_key: PublicKey,
+        custodian: AccountId,
+    ) -> bool;
+
+    /// invoked when the reward token transfer for a rewards farm claim completes - see
+    /// [`crate::StakeRewardsFarm::ops_farm_claim`]
+    /// - if the transfer failed, then the rewards are restored to the account and
+    ///   [`crate::LOG_EVENT_REWARDS_FARM_CLAIM_FAILED`] is logged
+    ///
+    /// Returns the amount of reward tokens that were claimed
     ///
     /// `#[private]`
-    fn ops_stake_stop_finalize(&mut self);
+    fn ops_farm_claim_callback(
+        &mut self,
+        account_id: AccountId,
+        amount: TokenAmount,
+    ) -> TokenAmount;
 }
 
 pub const ERR_STAKE_ACTION_FAILED: ErrCode = ErrCode("STAKE_ACTION_FAILED");
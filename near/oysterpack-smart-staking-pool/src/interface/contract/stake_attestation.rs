@@ -0,0 +1,38 @@
+use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
+use oysterpack_smart_near::near_sdk::serde::{Deserialize, Serialize};
+use oysterpack_smart_near::near_sdk::AccountId;
+use oysterpack_smart_near::{ErrCode, Level, LogEvent};
+
+/// # **Contract Interface**: Stake Attestation Verifier API
+///
+/// Interface that the attestation contract must implement to gate staking, e.g., for KYC - see
+/// [`crate::StakeAttestationGate`]
+///
+/// The attestation contract is configured by the operator via
+/// [`crate::StakingPoolOperatorCommand::SetAttestationGate`].
+///
+/// ## NOTES
+/// - [`crate::StakingPool::ops_stake`] checks the account's attestation when the account has no
+///   cached attestation, or when its cached attestation expired. While the attestation is being
+///   checked, the attached deposit is held in the account's storage balance. If the account is
+///   verified, then the account's storage balance is staked. Otherwise, the deposit remains in the
+///   account's storage balance, where it can be withdrawn.
+/// - all other staking entry points require the account to have a valid cached attestation
+/// - if the attestation contract call fails, then the account is treated as not verified
+pub trait StakeAttestationVerifier {
+    /// returns true if the account is verified
+    fn is_verified(&self, account_id: ValidAccountId) -> bool;
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct IsVerifiedArgs {
+    pub account_id: AccountId,
+}
+
+pub const LOG_EVENT_STAKE_ATTESTATION_VERIFIED: LogEvent =
+    LogEvent(Level::INFO, "STAKE_ATTESTATION_VERIFIED");
+pub const LOG_EVENT_STAKE_ATTESTATION_REJECTED: LogEvent =
+    LogEvent(Level::WARN, "STAKE_ATTESTATION_REJECTED");
+
+pub const ERR_STAKE_ATTESTATION_REQUIRED: ErrCode = ErrCode("STAKE_ATTESTATION_REQUIRED");
@@ -0,0 +1,50 @@
+use oysterpack_smart_near::domain::PublicKey;
+use oysterpack_smart_near::near_sdk::{
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// # **Contract Interface**: Stake Key Custodian API
+///
+/// Interface that the key custodian contract must implement to manage the staking public key, e.g.,
+/// for institutional validators with HSM-backed key workflows - see [`crate::StakeKeyCustody`]
+///
+/// The key custodian contract is configured by the operator via
+/// [`crate::StakingPoolOperatorCommand::SetKeyCustodian`].
+///
+/// ## NOTES
+/// - when key custody is configured, [`crate::StakingPoolOperatorCommand::UpdatePublicKey`] submits
+///   the rotation request to the custodian, and the staking public key is updated by
+///   [`crate::StakeActionCallbacks::ops_stake_public_key_rotation_callback`] once the custodian
+///   has approved it
+/// - if the custodian contract call fails, then the rotation is treated as rejected
+/// - the staking pool must still be offline when the approval is received
+pub trait StakeKeyCustodian {
+    /// returns true if the staking public key rotation is approved
+    fn approve_stake_public_key(&mut self, public_key: PublicKey) -> bool;
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct ApproveStakePublicKeyArgs {
+    pub public_key: PublicKey,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakePublicKeyRotationCallbackArgs {
+    pub public_key: PublicKey,
+    pub custodian: AccountId,
+}
+
+/// log message format: `public_key={public_key}, custodian={custodian}`
+pub const LOG_EVENT_STAKE_PUBLIC_KEY_ROTATION_REQUESTED: LogEvent =
+    LogEvent(Level::INFO, "STAKE_PUBLIC_KEY_ROTATION_REQUESTED");
+/// log message format: `public_key={public_key}, custodian={custodian}, reason={reason}`
+pub const LOG_EVENT_STAKE_PUBLIC_KEY_ROTATION_REJECTED: LogEvent =
+    LogEvent(Level::WARN, "STAKE_PUBLIC_KEY_ROTATION_REJECTED");
+/// log message format: `public_key={public_key}, custodian={custodian}`
+/// - custodian is `none` if key custody is not configured
+pub const LOG_EVENT_STAKE_PUBLIC_KEY_ROTATED: LogEvent =
+    LogEvent(Level::INFO, "STAKE_PUBLIC_KEY_ROTATED");
@@ -0,0 +1,157 @@
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// # **Contract Interface**: Staking Pool STAKE Locks API
+///
+/// Enables approved locker contracts, e.g., lending markets, to use an account's STAKE as collateral
+/// without transferring custody of the STAKE. Locked STAKE stays on the account and keeps earning
+/// staking rewards, but it cannot be transferred or unstaked until it is unlocked.
+///
+/// A locker can only lock an account's STAKE if the account owner has approved the locker - see
+/// [`crate::StakeLockerApproval`].
+///
+/// Each lock is identified by the account, the locker, and a lock ID assigned by the locker - see
+/// [`crate::StakeLock`]. Only the locker that created the lock can unlock it, unless an admin
+/// force unlocks it.
+///
+/// ## Notes
+/// - accounts with locked STAKE cannot be unregistered, even when forced
+/// - locked STAKE is exported as part of the account's STAKE balance, but the locks are not migrated
+pub trait StakeLocks {
+    /// Locks the specified amount of the account's STAKE
+    /// - if the lock already exists, then the amount is added to the lock balance
+    /// - the lock storage usage is charged to the locker account
+    /// - logs [`LOG_EVENT_STAKE_LOCK`]
+    ///
+    /// Returns the updated lock balance
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if the predecessor account does not have [`PERMISSION_LOCKER`] permission
+    /// - if the account owner has not approved the predecessor account as a locker
+    /// - if the lock ID is blank
+    /// - if the amount is zero
+    /// - if the account is not registered
+    /// - if the account has insufficient available STAKE balance
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_ft_lock(
+        &mut self,
+        account_id: ValidAccountId,
+        amount: TokenAmount,
+        lock_id: String,
+    ) -> TokenAmount;
+
+    /// Unlocks STAKE that was locked by the predecessor account
+    /// - if the amount is not specified, then the full lock balance is unlocked
+    /// - once the lock balance is zero, the lock is deleted and its storage is freed up
+    /// - the locker permission is not required, which enables lockers whose permission has been
+    ///   revoked to release their locks
+    /// - logs [`LOG_EVENT_STAKE_UNLOCK`]
+    ///
+    /// Returns the remaining lock balance
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if the amount is zero, or the lock does not exist
+    /// - if the amount is greater than the lock balance
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_ft_unlock(
+        &mut self,
+        account_id: ValidAccountId,
+        lock_id: String,
+        amount: Option<TokenAmount>,
+    ) -> TokenAmount;
+
+    /// Force unlocks STAKE that was locked by the specified locker, e.g., if the locker contract is
+    /// broken or has been decommissioned
+    /// - if the amount is not specified, then the full lock balance is unlocked
+    /// - subject to the admin approval policy
+    /// - logs [`LOG_EVENT_STAKE_UNLOCK`]
+    ///
+    /// Returns the remaining lock balance
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if not invoked by admin
+    /// - if the amount is zero, or the lock does not exist
+    /// - if the amount is greater than the lock balance
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_ft_admin_unlock(
+        &mut self,
+        account_id: ValidAccountId,
+        locker_id: ValidAccountId,
+        lock_id: String,
+        amount: Option<TokenAmount>,
+    ) -> TokenAmount;
+
+    /// Approves the locker to lock the predecessor account's STAKE
+    /// - the approval storage usage is charged to the predecessor account
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if the predecessor account is not registered
+    /// - if the predecessor account has insufficient storage available to store the approval
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_ft_approve_locker(&mut self, locker_id: ValidAccountId);
+
+    /// Revokes the locker's approval to lock the predecessor account's STAKE
+    /// - existing locks are not released
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if the predecessor account is not registered
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_ft_revoke_locker_approval(&mut self, locker_id: ValidAccountId);
+
+    fn ops_ft_is_locker_approved(
+        &self,
+        account_id: ValidAccountId,
+        locker_id: ValidAccountId,
+    ) -> bool;
+
+    /// returns the amount of STAKE that is locked on the account for the specified locker and lock ID
+    fn ops_ft_lock_balance(
+        &self,
+        account_id: ValidAccountId,
+        locker_id: ValidAccountId,
+        lock_id: String,
+    ) -> TokenAmount;
+
+    /// returns the account's total locked STAKE balance across all lockers
+    /// - returns None if the account is not registered or has no locked STAKE
+    fn ops_ft_locked_balance(&self, account_id: ValidAccountId) -> Option<TokenAmount>;
+
+    /// grants locker permission to specified account
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if not invoked by admin
+    /// - if specified account is not registered
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_stake_grant_locker(&mut self, account_id: ValidAccountId);
+
+    /// revokes locker permission from specified account
+    /// - existing locks are not released
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if not invoked by admin
+    /// - if specified account is not registered
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_stake_revoke_locker(&mut self, account_id: ValidAccountId);
+
+    fn ops_stake_is_locker(&self, account_id: ValidAccountId) -> bool;
+}
+
+pub const PERMISSION_LOCKER: &str = "locker";
+
+pub const LOG_EVENT_STAKE_LOCK: LogEvent = LogEvent(Level::INFO, "STAKE_LOCK");
+pub const LOG_EVENT_STAKE_UNLOCK: LogEvent = LogEvent(Level::INFO, "STAKE_UNLOCK");
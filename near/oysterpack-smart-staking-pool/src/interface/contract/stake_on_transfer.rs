@@ -0,0 +1,47 @@
+use crate::StakeAccountBalances;
+use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
+use oysterpack_smart_near::near_sdk::{
+    serde::{Deserialize, Serialize},
+    PromiseOrValue,
+};
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// # **Contract Interface**: Stake On Transfer API
+///
+/// Enables other contracts, e.g., aggregators and wNEAR unwrap flows, to route NEAR into the pool in
+/// one hop by staking the attached deposit on behalf of a beneficiary account.
+///
+/// ## NOTES
+/// - only the attached deposit is staked, i.e., the beneficiary's available storage balance is
+///   not staked
+/// - the beneficiary is charged the staking fee, as if it staked the NEAR itself
+/// - any NEAR that cannot be staked because of rounding or the stake cap is credited to the
+///   beneficiary's storage balance - see [`crate::StakingPool::ops_stake`]
+pub trait StakeOnTransfer {
+    /// Stakes the attached deposit on behalf of the beneficiary account that is specified by the
+    /// JSON message - see [`StakeOnTransferMessage`], e.g., `{"account_id": "alfio.near"}`
+    /// - logs [`LOG_EVENT_STAKE_ON_TRANSFER`]
+    ///
+    /// Returns the beneficiary's updated stake account balance after the contract's stake action
+    /// completes
+    ///
+    /// ## Panics
+    /// - if no deposit is attached
+    /// - if the message is not valid JSON
+    /// - if the beneficiary account is not registered
+    /// - if the attached deposit is less than the minimum stake - [`crate::ERR_STAKE_BELOW_MIN`]
+    ///
+    /// `#[payable]`
+    fn stake_on_transfer(&mut self, msg: String) -> PromiseOrValue<StakeAccountBalances>;
+}
+
+/// [`StakeOnTransfer::stake_on_transfer`] JSON message
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakeOnTransferMessage {
+    /// beneficiary account that the STAKE is minted for
+    pub account_id: ValidAccountId,
+}
+
+/// log message format: `sender_id={sender_id}, account_id={account_id}, amount={amount}`
+pub const LOG_EVENT_STAKE_ON_TRANSFER: LogEvent = LogEvent(Level::INFO, "STAKE_ON_TRANSFER");
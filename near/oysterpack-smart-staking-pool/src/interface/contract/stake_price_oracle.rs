@@ -0,0 +1,30 @@
+use crate::StakeTokenValueSample;
+use oysterpack_smart_near::near_sdk::serde::{Deserialize, Serialize};
+use oysterpack_smart_near::{Level, LogEvent};
+
+/// # **Contract Interface**: STAKE Price Oracle Receiver API
+///
+/// Interface that the oracle contract must implement to receive STAKE token value updates from the
+/// staking pool - see [`crate::StakePriceOracle`]
+///
+/// The oracle is configured by the operator via [`crate::StakingPoolOperatorCommand::SetPriceOracle`].
+///
+/// ## NOTES
+/// - the STAKE token value is published when earnings are collected, i.e., the update is piggybacked
+///   on staking pool transactions
+/// - if there is not enough prepaid gas to publish the update, then it is published by a later
+///   transaction
+/// - the staking pool does not check the result of the oracle call
+pub trait StakePriceOracleReceiver {
+    /// invoked by the staking pool when the STAKE token value moved more than the configured threshold
+    fn on_stake_price_update(&mut self, price: StakeTokenValueSample);
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "oysterpack_smart_near::near_sdk::serde")]
+pub struct StakePriceUpdateArgs {
+    pub price: StakeTokenValueSample,
+}
+
+pub const LOG_EVENT_STAKE_PRICE_PUBLISHED: LogEvent =
+    LogEvent(Level::INFO, "STAKE_PRICE_PUBLISHED");
@@ -1,7 +1,18 @@
-use crate::{Fees, StakeAccountBalances, StakingPoolBalances, StakingPoolOperator};
-use crate::{Status, Treasury};
+use crate::{
+    EpochPerformance, PendingWithdrawal, StakeOperation, StakeTokenValueSample, UnstakeProjection,
+};
+use crate::{
+    Fees, StakeAccountBalances, StakingPoolBalances, StakingPoolBalancesSnapshot,
+    StakingPoolHealth, StakingPoolOperator,
+};
+use crate::{FinalizeIntent, UnstakeBatch};
+use crate::{RoundingMode, StakedBalance};
+use crate::{StakeCertificate, StakePreview, UnstakePreview};
+use crate::{Status, StorageCost, StorageOpKind, WithdrawableAccounts};
+use oysterpack_smart_contract::{CounterId, JobId};
 use oysterpack_smart_fungible_token::{Memo, TokenAmount, TransferCallMessage};
-use oysterpack_smart_near::domain::{PublicKey, YoctoNear};
+use oysterpack_smart_near::data::numbers::{U128, U64};
+use oysterpack_smart_near::domain::{BlockHeight, PublicKey, YoctoNear};
 use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
 use oysterpack_smart_near::near_sdk::{Promise, PromiseOrValue};
 use oysterpack_smart_near::{ErrCode, ErrorConst, Level, LogEvent};
@@ -21,11 +32,12 @@ use oysterpack_smart_near::{ErrCode, ErrorConst, Level, LogEvent};
 ///    implementation, unstaking in the 10 NEAR in EPOCH 3 would reset the lock period for the total
 ///    unstaked, i.e., you would not be able to withdraw the 100 NEAR that was unstaked in EPOCH 1
 ///    until EPOCH 7.
-/// 3. Staking adds liquidity for withdrawing unstaked NEAR that is locked on a first come, first
-///    withdraw basis.
+/// 3. Staking adds liquidity for withdrawing unstaked NEAR that is locked. Each account can claim
+///    a share of the liquidity that is proportional to its share of the total unstaked NEAR.
 /// 4. More flexible commercial model supporting 2 types of fees that can be combined
 ///    - staking fee - upfront 1 time fee that is charged based on percentage of NEAR staked
 ///      - defaults to 80 BPS (0.8%)
+///      - a share of the staking fee can be paid to referrers - see [`crate::StakeReferrals`]
 ///    - earnings fee - charged as a percentage of earnings
 ///      - default to 0 BPS
 /// 5. Profit sharing through dividends
@@ -34,7 +46,7 @@ use oysterpack_smart_near::{ErrCode, ErrorConst, Level, LogEvent};
 ///    - dividends are paid out on each staking/unstaking/withdrawal event, i.e., if the treasury
 ///      has received staking rewards since the last staking, then the STAKE token equivalent will be burned
 /// 6. Earnings revenue distributions
-///    - earnings can be distributed via ['Treasury::ops_stake_treasury_distribution']
+///    - earnings can be distributed via [`crate::Treasury::ops_stake_treasury_distribution`]
 ///
 /// The staking pool is integrated with the storage management API:
 /// - accounts must be registered with the contract in order to stake
@@ -47,12 +59,29 @@ use oysterpack_smart_near::{ErrCode, ErrorConst, Level, LogEvent};
 /// - STAKE received through transfer calls are treated as treasury deposits, i.e., the treasury
 ///   balance will be updated to reflect the deposit.
 ///
-pub trait StakingPool: Treasury + StakingPoolOperator {
+pub trait StakingPool: StakingPoolOperator {
     /// Consolidates the account's storage balance with the STAKE token balance
     ///
     /// Returns None if the account is not registered with the contract
     fn ops_stake_balance(&self, account_id: ValidAccountId) -> Option<StakeAccountBalances>;
 
+    /// Bulk version of [`StakingPool::ops_stake_balance`], which enables balances to be retrieved
+    /// for many accounts in a single view call
+    /// - balances are returned in the same order as the specified accounts
+    /// - None is returned for accounts that are not registered with the contract
+    fn ops_stake_balances(
+        &self,
+        accounts: Vec<ValidAccountId>,
+    ) -> Vec<Option<StakeAccountBalances>>;
+
+    /// Returns a canonical, hashable JSON document of the account's balances as of the current
+    /// block, which off-chain systems can pair with an RPC light-client proof for audited
+    /// proof-of-holdings statements - see [`StakeCertificate`]
+    /// - the operation ID is not included in the certified balances
+    ///
+    /// Returns None if the account is not registered with the contract
+    fn ops_stake_certificate(&self, account_id: ValidAccountId) -> Option<StakeCertificate>;
+
     /// Used to stake NEAR for the predecessor's account.
     ///
     /// Any attached deposit will be fully staked in addition to any available account storage balance.
@@ -72,16 +101,37 @@ pub trait StakingPool: Treasury + StakingPoolOperator {
     ///   exchange ratio, a minimum amount of NEAR is required to stake. If there is not enough to stake
     ///   then the funds will be transferred over to the account's storage balance.
     ///   - [`LOG_EVENT_NOT_ENOUGH_TO_STAKE`] event will be logged
+    /// - if a stake cap is configured, then only the NEAR that fits under the cap is staked and the
+    ///   excess is credited to the account's storage balance - see [`crate::StakeCap`]
+    ///   - [`LOG_EVENT_STAKE_CAP_REACHED`] event will be logged
+    ///   - if the stake waitlist is enabled, then the excess is also waitlisted and staked once
+    ///     capacity frees up - see [`crate::StakeWaitlist`]
+    /// - if an attestation gate is configured and the account has no valid cached attestation, then
+    ///   the account's attestation is first checked by the attestation contract - see
+    ///   [`crate::StakeAttestationVerifier`]
     /// - the NEAR stake action is async - thus, balances will not be updated until the stake action
     ///   has completed
     /// - if there was no attached deposit and zero available storage balance, then the current balances
     ///   are simply returned
+    /// - if a memo is specified, then it is logged via [`LOG_EVENT_STAKE_MEMO`], e.g., to enable
+    ///   custodians to tag transactions
+    /// - the stake operation is recorded and its ID is returned in the balances, which can be used
+    ///   to lookup the operation via [`StakingPool::ops_stake_operation`]
+    /// - `min_stake_out` protects the account from STAKE token value changes, e.g., earnings that
+    ///   are collected between the time the transaction is signed and executed - it is checked
+    ///   against the STAKE credited to the account, i.e., net of staking fees
     ///
     /// ## Panics
     /// - if the account is not registered
+    /// - if the STAKE credited to the account is less than `min_stake_out` - [`ERR_STAKE_SLIPPAGE`]
+    /// - if the attached deposit is less than the minimum stake - [`ERR_STAKE_BELOW_MIN`]
     ///
     /// `#[payable]`
-    fn ops_stake(&mut self) -> PromiseOrValue<StakeAccountBalances>;
+    fn ops_stake(
+        &mut self,
+        memo: Option<Memo>,
+        min_stake_out: Option<TokenAmount>,
+    ) -> PromiseOrValue<StakeAccountBalances>;
 
     /// Used to unstake staked NEAR.
     ///
@@ -91,11 +141,101 @@ pub trait StakingPool: Treasury + StakingPoolOperator {
     /// - If unstaking all, i.e., `amount` is None, then a zero staked balance is fine. However, if
     ///   an `amount` is specified, then the method will panic if there are insufficient staked funds
     ///   to fulfill the request
+    /// - if a memo is specified, then it is logged via [`LOG_EVENT_STAKE_MEMO`]
+    /// - the unstake operation is recorded and its ID is returned in the balances
+    /// - `min_near_out` protects the account from STAKE token value changes when unstaking all, i.e.,
+    ///   when the NEAR amount is computed from the account's STAKE balance
     ///
     /// ## Panics
     /// - if account is not registered
     /// - if there are insufficient staked funds to fulfill the request to unstake the specified amount
-    fn ops_unstake(&mut self, amount: Option<YoctoNear>) -> PromiseOrValue<StakeAccountBalances>;
+    /// - if the NEAR unstaked is less than `min_near_out` - [`ERR_STAKE_SLIPPAGE`]
+    fn ops_unstake(
+        &mut self,
+        amount: Option<YoctoNear>,
+        memo: Option<Memo>,
+        min_near_out: Option<YoctoNear>,
+    ) -> PromiseOrValue<StakeAccountBalances>;
+
+    /// Used to unstake the specified amount of STAKE, i.e., burns exactly the specified amount of
+    /// STAKE and credits the account's unstaked balance with its NEAR value.
+    ///
+    /// This is the natural unit for STAKE token holders, which avoids the NEAR to STAKE conversion
+    /// that [`StakingPool::ops_unstake`] requires, where the STAKE burned is rounded up.
+    ///
+    /// ## Notes
+    /// - the NEAR value of the STAKE is rounded down
+    /// - if a memo is specified, then it is logged via [`LOG_EVENT_STAKE_MEMO`]
+    /// - the unstake operation is recorded and its ID is returned in the balances
+    ///
+    /// ## Panics
+    /// - if account is not registered
+    /// - if `amount` is zero
+    /// - if the account's STAKE balance is insufficient
+    /// - if the STAKE is not vested - [`crate::ERR_STAKE_NOT_VESTED`]
+    /// - if the NEAR unstaked is less than `min_near_out` - [`ERR_STAKE_SLIPPAGE`]
+    fn ops_unstake_tokens(
+        &mut self,
+        amount: TokenAmount,
+        memo: Option<Memo>,
+        min_near_out: Option<YoctoNear>,
+    ) -> PromiseOrValue<StakeAccountBalances>;
+
+    /// Projects when the specified NEAR amount would become available for withdrawal if it were
+    /// unstaked now by the account.
+    /// - unstaked NEAR is locked for 4 epochs, but locked balances can be withdrawn immediately if
+    ///   there is enough liquidity in the unstaked liquidity pool
+    /// - the account's liquidity claim is applied to the most recently unstaked NEAR first, which
+    ///   means it would be applied to the projected amount first - see [`crate::LiquidityClaim`]
+    ///
+    /// ## NOTES
+    /// - liquidity is granted to accounts proportionally to their share of the total unstaked NEAR
+    ///   when the liquidity is added, thus NEAR that is unstaked afterwards has no claim on it
+    ///
+    /// ## Panics
+    /// - if the account is not registered
+    /// - if amount is zero
+    /// - if the account's staked balance is insufficient to unstake the specified amount - STAKE that
+    ///   is locked, i.e., STAKE that is locked by lockers or is not yet vested, cannot be unstaked
+    fn ops_stake_unstake_projection(
+        &self,
+        account_id: ValidAccountId,
+        amount: YoctoNear,
+    ) -> UnstakeProjection;
+
+    /// Dry-run for [`StakingPool::ops_stake`], which computes the result of staking the specified
+    /// deposit amount without modifying state
+    /// - the account's available storage balance is staked along with the deposit
+    ///
+    /// ## NOTES
+    /// - the preview is based on the STAKE token value after the earnings that have been received
+    ///   are recognized, net of the earnings fee, i.e., the same STAKE token value the stake
+    ///   transaction would use if it were processed in the current block - earnings that are
+    ///   received before the stake transaction is processed will raise the STAKE token value,
+    ///   which can reduce the STAKE minted
+    ///
+    /// ## Panics
+    /// - if the account is not registered
+    /// - if the deposit amount and the account's available storage balance are both zero
+    fn ops_stake_preview(&self, account_id: ValidAccountId, amount: YoctoNear) -> StakePreview;
+
+    /// Dry-run for [`StakingPool::ops_unstake`], which computes the result of unstaking the specified
+    /// amount without modifying state
+    /// - if the amount is not specified, then all vested STAKE would be unstaked
+    ///
+    /// ## NOTES
+    /// - the preview is based on the STAKE token value after earnings are recognized - see
+    ///   [`StakingPool::ops_stake_preview`]
+    ///
+    /// ## Panics
+    /// - if the account is not registered
+    /// - if the account's staked balance is insufficient to unstake the specified amount
+    /// - if the STAKE that would be burned is not vested
+    fn ops_unstake_preview(
+        &self,
+        account_id: ValidAccountId,
+        amount: Option<YoctoNear>,
+    ) -> UnstakePreview;
 
     /// Re-stakes unstaked funds
     ///
@@ -108,6 +248,9 @@ pub trait StakingPool: Treasury + StakingPoolOperator {
     /// - the actual NEAR amount restaked might be less than the requested specified amount because
     ///   of rounding when converting the restake NEAR amount to STAKE - check balances to confirm
     ///   the actual amount restaked
+    /// - if a stake cap is configured, then only the NEAR that fits under the cap is restaked - the
+    ///   excess remains unstaked - see [`crate::StakeCap`]
+    ///   - [`LOG_EVENT_STAKE_CAP_REACHED`] event will be logged
     ///
     /// ## Panics
     /// - if account is not registered
@@ -118,19 +261,109 @@ pub trait StakingPool: Treasury + StakingPoolOperator {
     ///
     /// If no amount is specified, then all available unstaked NEAR will be withdrawn.
     ///
+    /// If a memo is specified, then it is logged via [`LOG_EVENT_STAKE_MEMO`].
+    ///
+    /// If NEAR is withdrawn, then the withdraw operation is recorded and its ID is returned in the
+    /// balances.
+    ///
+    /// ## Panics
+    /// - if account is not registered
+    /// - if there are insufficient funds to fulfill the request
+    fn ops_stake_withdraw(
+        &mut self,
+        amount: Option<YoctoNear>,
+        memo: Option<Memo>,
+    ) -> StakeAccountBalances;
+
+    /// Withdraws unstaked NEAR that is not locked, and transfers it to the receiver account, e.g., a
+    /// cold wallet
+    /// - the receiver account does not need to be registered with the contract
+    /// - if no amount is specified, then all available unstaked NEAR will be withdrawn
+    /// - logs [`LOG_EVENT_WITHDRAW_TO`]
+    ///
+    /// If NEAR is withdrawn, then the withdraw operation is recorded and its ID is returned in the
+    /// balances.
+    ///
+    /// Returns the predecessor account's updated balances
+    ///
     /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
     /// - if account is not registered
     /// - if there are insufficient funds to fulfill the request
-    fn ops_stake_withdraw(&mut self, amount: Option<YoctoNear>) -> StakeAccountBalances;
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_stake_withdraw_to(
+        &mut self,
+        receiver: ValidAccountId,
+        amount: Option<YoctoNear>,
+    ) -> StakeAccountBalances;
+
+    /// Unstakes the specified amount, and schedules the unstaked NEAR to be withdrawn automatically
+    /// once it unlocks, i.e., the withdrawal is processed by [`StakingPool::ops_stake_process_pending_withdrawals`]
+    /// after the 4 epoch lock period passes.
+    ///
+    /// If amount is not specified, then the full staked balance will be unstaked.
+    ///
+    /// ## NOTES
+    /// - the account can only have 1 pending withdrawal - any prior pending withdrawal is replaced,
+    ///   and when processed, the account's total available unstaked balance is withdrawn
+    /// - the pending withdrawal storage usage is charged to the account
+    ///
+    /// ## Panics
+    /// - if account is not registered
+    /// - if there are insufficient staked funds to fulfill the request to unstake the specified amount
+    fn ops_unstake_all_and_withdraw(
+        &mut self,
+        amount: Option<YoctoNear>,
+    ) -> PromiseOrValue<StakeAccountBalances>;
+
+    /// Keeper method that processes pending withdrawals that are due, in the order they were requested.
+    /// - anyone can call this method - the withdrawn funds are always transferred to the account
+    ///   that requested the withdrawal
+    /// - at most [`MAX_PENDING_WITHDRAWALS_BATCH_SIZE`] withdrawals are processed per call
+    /// - logs [`LOG_EVENT_PENDING_WITHDRAWAL`] for each withdrawal that is processed
+    /// - progress is tracked via the [`PENDING_WITHDRAWALS_JOB_ID`] job cursor - see
+    ///   [`oysterpack_smart_contract::ContractJobs`]:
+    ///   - `position` is the number of due withdrawals that have been dequeued
+    ///   - `processed` is the number of withdrawals that transferred funds
+    ///   - the job is completed once all due withdrawals have been processed
+    ///
+    /// Returns the number of withdrawals that were processed
+    fn ops_stake_process_pending_withdrawals(&mut self) -> u32;
+
+    /// returns the account's pending withdrawal, if one is scheduled
+    fn ops_stake_pending_withdrawal(&self, account_id: ValidAccountId)
+        -> Option<PendingWithdrawal>;
+
+    /// Lists accounts with unstaked NEAR that is available to withdraw, along with the aggregate
+    /// available balance across the listed accounts, which keepers and operators can use to monitor
+    /// NEAR liquidity outflows
+    /// - accounts are listed in the order in which their unstaked NEAR became available
+    /// - at most `limit` accounts are returned
+    ///
+    /// The accounts are found using the [`crate::WithdrawalIndex`], which is maintained when accounts
+    /// unstake, restake, and withdraw.
+    ///
+    /// NOTE: withdrawable accounts are not the same as pending withdrawals, which are withdrawals
+    /// that accounts have scheduled - see [`StakingPool::ops_stake_pending_withdrawal`]
+    fn ops_stake_withdrawable_accounts(&self, limit: u32) -> WithdrawableAccounts;
 
     /// converts the specified NEAR amount to STAKE and transfers the funds to the specified receiver
     /// account
     /// - proxies [`oysterpack_smart_fungible_token::FungibleToken::ft_transfer`] as a convenience
     ///   method that enables the staker to transfer STAKE by specifying the transfer amount in NEAR vs in STAKE
-    /// - returns the amount of STAKE tokens that were transferred based on the current STAKE token value
+    /// - `rounding` specifies how the NEAR amount is converted to STAKE - defaults to [`RoundingMode::Up`]
+    /// - returns the amount of STAKE tokens that were transferred along with their NEAR value based
+    ///   on the current STAKE token value
     ///
     /// ## NOTES
     /// - earnings are applied before the transfer
+    /// - if transfer auto-registration is enabled, then unregistered receivers are registered and the
+    ///   registration fee is deducted from the transferred STAKE - see
+    ///   [`crate::StakingPoolOperatorCommand::EnableTransferAutoRegistration`]
+    ///
+    /// ## Panics
+    /// - if [`RoundingMode::Fail`] is specified and the NEAR amount cannot be converted to STAKE exactly
     ///
     /// `#[payable]`
     fn ops_stake_transfer(
@@ -138,7 +371,8 @@ pub trait StakingPool: Treasury + StakingPoolOperator {
         receiver_id: ValidAccountId,
         amount: YoctoNear,
         memo: Option<Memo>,
-    ) -> TokenAmount;
+        rounding: Option<RoundingMode>,
+    ) -> StakedBalance;
 
     /// converts the specified NEAR amount to STAKE and transfers the funds to the specified receiver
     /// account via the FT transfer call mechanism
@@ -148,6 +382,9 @@ pub trait StakingPool: Treasury + StakingPoolOperator {
     ///
     /// ## NOTES
     /// - earnings are applied before the transfer
+    /// - if transfer auto-registration is enabled, then unregistered receivers are registered and the
+    ///   registration fee is deducted from the transferred STAKE - see
+    ///   [`crate::StakingPoolOperatorCommand::EnableTransferAutoRegistration`]
     ///
     /// `#[payable]`
     fn ops_stake_transfer_call(
@@ -158,6 +395,57 @@ pub trait StakingPool: Treasury + StakingPoolOperator {
         msg: TransferCallMessage,
     ) -> Promise;
 
+    /// Transfers the specified amount of unstaked NEAR to the receiver account, preserving the unlock
+    /// epochs, which enables custodians to consolidate accounts without waiting out the lock period
+    /// or restaking
+    /// - the available unstaked balance is transferred first, and then the locked unstaked balances
+    ///   in unlock epoch order
+    /// - logs [`LOG_EVENT_UNSTAKED_TRANSFER`]
+    /// - if transfer auto-registration is enabled, then an unregistered receiver is registered and the
+    ///   registration fee is debited from the transferred available unstaked balance - see
+    ///   [`crate::StakingPoolOperatorCommand::EnableTransferAutoRegistration`]
+    ///
+    /// Returns the predecessor account's updated balances
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if the predecessor account is not registered
+    /// - if the receiver account is not registered and transfer auto-registration is not enabled
+    /// - if the receiver is auto-registered and the transferred available unstaked balance does not
+    ///   cover the registration fee
+    /// - if the receiver has no lock slot available for a transferred unlock epoch - see
+    ///   [`ERR_UNSTAKED_LOCK_SLOTS_FULL`]
+    /// - if the receiver is the predecessor account
+    /// - if amount is zero
+    /// - if the predecessor account has insufficient unstaked funds
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_stake_transfer_unstaked(
+        &mut self,
+        receiver_id: ValidAccountId,
+        amount: YoctoNear,
+    ) -> StakeAccountBalances;
+
+    /// Burns the specified amount of STAKE from the predecessor account's STAKE balance.
+    ///
+    /// The total staked NEAR balance is left untouched, i.e., the burned STAKE NEAR value is distributed
+    /// to the remaining STAKE holders, which increases the STAKE token value.
+    ///
+    /// Returns the account's updated stake balance.
+    ///
+    /// ## NOTES
+    /// - earnings are applied before the burn
+    ///
+    /// ## Panics
+    /// - if exactly 1 yoctoNEAR is not attached
+    /// - if the account is not registered
+    /// - if amount is zero
+    /// - if the account has insufficient STAKE funds
+    /// - if the burn would burn the entire STAKE supply
+    ///
+    /// `#[payable]`
+    fn ops_stake_burn(&mut self, amount: TokenAmount) -> StakeAccountBalances;
+
     /// returns the current NEAR value for the specified amount
     /// - if no amount is specified, then the value for 1 STAKE token will be returned
     /// - value includes estimated earnings minus dividend payouts
@@ -174,28 +462,214 @@ pub trait StakingPool: Treasury + StakingPoolOperator {
     /// transaction gas fees earned from this call are applied on the next.
     fn ops_stake_token_value_with_earnings(&mut self, amount: Option<TokenAmount>) -> YoctoNear;
 
+    /// Keeps the pool compounding without operator intervention - can be called by any registered
+    /// account:
+    /// 1. collects earnings and pays the treasury dividend
+    /// 2. if the pool is online and the locked balance does not cover the total staked balance,
+    ///    then the idle NEAR is restaked
+    /// 3. pays the caller the [`crate::CompoundBounty`], which is minted as STAKE
+    ///
+    /// Returns the STAKE bounty that was paid to the caller
+    /// - logs [`LOG_EVENT_COMPOUND`]
+    ///
+    /// ## Panics
+    /// - if the predecessor account is not registered
+    fn ops_stake_compound(&mut self) -> TokenAmount;
+
+    /// Keeper for unstake batching - can be called by any account, see
+    /// [`crate::StakingPoolOperatorCommand::EnableUnstakeBatching`]
+    ///
+    /// If there is a pending unstake batch, then a single stake action is submitted to unstake
+    /// the batched NEAR from the validator, i.e., the lowered total staked balance is staked.
+    /// The batch is processed at most once per epoch - unstakes that are batched after the batch
+    /// is processed are unstaked on the next epoch.
+    /// The unlock epoch for the batched NEAR is computed from the epoch in which the batch is
+    /// submitted, i.e., NEAR that was unstaked in an earlier epoch is relocked.
+    ///
+    /// Returns the batch that was processed, or None if there was nothing to process:
+    /// - there is no pending batch
+    /// - the batch was already processed within the current epoch
+    /// - the pool is offline
+    ///
+    /// logs [`crate::LOG_EVENT_UNSTAKE_BATCH_PROCESSED`]
+    fn ops_stake_process_epoch(&mut self) -> Option<UnstakeBatch>;
+
+    /// Keeper for the stake waitlist - can be called by any account, see
+    /// [`crate::StakingPoolOperatorCommand::EnableStakeWaitlist`]
+    ///
+    /// Stakes waitlisted NEAR in FIFO order up to the stake cap capacity - at most
+    /// [`crate::StakeWaitlist::MAX_ADMISSIONS`] entries are processed per call. Entries for accounts
+    /// that can no longer stake are removed. If any NEAR was staked and the pool is online, then
+    /// the stake action is submitted.
+    ///
+    /// Returns the amount of NEAR that was staked
+    ///
+    /// logs [`crate::LOG_EVENT_STAKE_WAITLIST_ADMITTED`], [`crate::LOG_EVENT_STAKE_WAITLIST_REMOVED`]
+    fn ops_stake_admit_waitlist(&mut self) -> YoctoNear;
+
+    /// Replays stake action finalize callbacks that were lost, e.g., the callback ran out of gas,
+    /// which would otherwise leave the pending stake action and callback nonce in flight - can be
+    /// called by any account, see [`crate::FinalizeRetryQueue`]
+    /// - intents are retryable once [`crate::PendingStakeActions::EXPIRY_BLOCKS`] have elapsed
+    ///   since the stake action was submitted
+    /// - the callback nonce is consumed, which means the lost callback will not be authorized to run
+    ///   if it is later invoked - if the callback already ran, then the intent is skipped
+    /// - because the promise result is no longer available, the stake action outcome is unknown -
+    ///   once no other stake action is pending, earnings are collected and the locked balance is
+    ///   resynced against the current total staked balance:
+    ///   - if the pool is online and the locked balance does not match, then the total staked
+    ///     balance is restaked
+    ///   - if the pool is offline and NEAR is still locked, then it is unstaked
+    ///
+    /// Returns the intents that were replayed - at most [`crate::FinalizeRetryQueue::MAX_RETRIES_BATCH`]
+    /// - logs [`crate::LOG_EVENT_FINALIZE_RETRY`]
+    /// - logs [`crate::LOG_EVENT_FINALIZE_RESYNC`]
+    fn ops_stake_retry_finalize(&mut self) -> Vec<FinalizeIntent>;
+
+    /// returns finalize callbacks that have not yet run, ordered from oldest to newest
+    fn ops_stake_finalize_retry_queue(&self) -> Vec<FinalizeIntent>;
+
+    /// Returns the time weighted average NEAR value for 1 STAKE token over the specified number of
+    /// epochs, up to and including the current epoch
+    /// - the STAKE token value is sampled once per epoch, the first time earnings are applied within
+    ///   the epoch, which makes the TWAP resistant to intra-epoch manipulation
+    /// - epochs that precede the first sample are excluded
+    /// - returns None if the STAKE token value has not yet been sampled within the window
+    ///
+    /// ## Panics
+    /// - if `epochs` is zero or exceeds [`crate::StakeTokenValueHistory::MAX_EPOCHS`]
+    fn ops_stake_token_value_twap(&self, epochs: u64) -> Option<YoctoNear>;
+
+    /// returns the STAKE token value samples, ordered by epoch height
+    /// - the history is bounded to the last [`crate::StakeTokenValueHistory::MAX_EPOCHS`] samples
+    fn ops_stake_token_value_history(&self) -> Vec<StakeTokenValueSample>;
+
+    /// Looks up a recorded stake, unstake, or withdraw operation by its ID, e.g., to enable
+    /// custodians to reconcile account balances
+    /// - each operation is also logged via [`LOG_EVENT_STAKE_OPERATION`]
+    /// - only the last [`crate::StakeOperation::MAX_RECORDED_OPERATIONS`] operations are retained
+    ///
+    /// Returns None if the operation does not exist or is no longer retained
+    fn ops_stake_operation(&self, id: U64) -> Option<StakeOperation>;
+
+    /// returns the validator's realized per epoch performance, which is recorded each time earnings
+    /// are recognized, ordered by epoch height
+    /// - at most `limit` of the most recent epochs are returned - defaults to all recorded epochs
+    /// - the history is bounded to the last [`crate::ValidatorPerformance::MAX_EPOCHS`] epochs
+    fn ops_stake_validator_performance(&self, limit: Option<u64>) -> Vec<EpochPerformance>;
+
     fn ops_stake_status(&self) -> Status;
 
     fn ops_stake_pool_balances(&self) -> StakingPoolBalances;
 
+    /// returns the pool balances snapshots that were recorded when earnings were recognized,
+    /// ordered by block height - see [`crate::StakingPoolBalancesHistory`]
+    /// - at most `limit` of the most recent snapshots are returned - defaults to all recorded snapshots
+    fn ops_stake_pool_balances_history(
+        &self,
+        limit: Option<u64>,
+    ) -> Vec<StakingPoolBalancesSnapshot>;
+
+    /// returns the most recent pool balances snapshot that was recorded at or before the specified
+    /// block height
+    /// - returns None if no snapshot is retained for the block height
+    fn ops_stake_pool_balances_at(
+        &self,
+        block_height: BlockHeight,
+    ) -> Option<StakingPoolBalancesSnapshot>;
+
+    /// returns a composite health report, which node operators can use to alert on
+    fn ops_stake_health(&self) -> StakingPoolHealth;
+
     fn ops_stake_fees(&self) -> Fees;
 
     fn ops_stake_public_key(&self) -> PublicKey;
+
+    /// Returns the projected storage usage and NEAR cost for the specified operation, which enables
+    /// wallets to pre-compute the required storage deposit
+    /// - [`StorageOpKind::RegisterAccount`] is the account storage usage bounds min
+    /// - [`StorageOpKind::FirstStake`] and [`StorageOpKind::FirstUnstake`] are the storage usage
+    ///   that is allocated on top of the min - see [`crate::StorageOpCosts`]
+    ///
+    /// Returns None for [`StorageOpKind::FirstStake`] and [`StorageOpKind::FirstUnstake`] if the
+    /// storage op costs have not been measured, i.e., the pool was upgraded from a version that
+    /// predates the measurements and [`crate::StakingPoolOperatorCommand::MeasureStorageOpCosts`]
+    /// has not been run yet
+    fn ops_storage_cost_of(&self, operation: StorageOpKind) -> Option<StorageCost>;
 }
 
+/// max number of pending withdrawals that are processed per [`StakingPool::ops_stake_process_pending_withdrawals`] call
+pub const MAX_PENDING_WITHDRAWALS_BATCH_SIZE: u32 = 10;
+
+/// [`StakingPool::ops_stake_process_pending_withdrawals`] job cursor
+pub const PENDING_WITHDRAWALS_JOB_ID: JobId = JobId(U128(1958012448217316218262364587915420371));
+
+/// STAKE total supply metrics history counter
+pub const METRICS_COUNTER_STAKE_SUPPLY: CounterId =
+    CounterId(U128(1957573071389163207429381738316150931));
+/// total staked NEAR balance metrics history counter
+pub const METRICS_COUNTER_TOTAL_STAKED: CounterId =
+    CounterId(U128(1957573110294623567591637453197562704));
+/// cumulative earnings metrics history counter - see [`crate::EarningsSources`]
+pub const METRICS_COUNTER_TOTAL_EARNINGS: CounterId =
+    CounterId(U128(1957573141627913475862049174338947411));
+
 pub const LOG_EVENT_STATUS_ONLINE: LogEvent = LogEvent(Level::INFO, "STATUS_ONLINE");
 pub const LOG_EVENT_STATUS_OFFLINE: LogEvent = LogEvent(Level::WARN, "STATUS_OFFLINE");
 
 pub const LOG_EVENT_NOT_ENOUGH_TO_STAKE: LogEvent = LogEvent(Level::INFO, "NOT_ENOUGH_TO_STAKE");
 
+/// log message format: `account_id={account_id}, limit={limit}, total_staked={total_staked}, excess={excess}`
+pub const LOG_EVENT_STAKE_CAP_REACHED: LogEvent = LogEvent(Level::WARN, "STAKE_CAP_REACHED");
+
 pub const LOG_EVENT_STAKE: LogEvent = LogEvent(Level::INFO, "STAKE");
 pub const LOG_EVENT_UNSTAKE: LogEvent = LogEvent(Level::INFO, "UNSTAKE");
+pub const LOG_EVENT_STAKE_BURN: LogEvent = LogEvent(Level::INFO, "STAKE_BURN");
+pub const LOG_EVENT_UNSTAKED_TRANSFER: LogEvent = LogEvent(Level::INFO, "UNSTAKED_TRANSFER");
+pub const LOG_EVENT_PENDING_WITHDRAWAL: LogEvent = LogEvent(Level::INFO, "PENDING_WITHDRAWAL");
+pub const LOG_EVENT_WITHDRAW_TO: LogEvent = LogEvent(Level::INFO, "WITHDRAW_TO");
+/// echoes the memo that is specified when staking, unstaking, or withdrawing
+/// log message format: `id={id}, kind={kind}, account_id={account_id}, amount={amount}, stake={stake}`
+pub const LOG_EVENT_STAKE_OPERATION: LogEvent = LogEvent(Level::INFO, "STAKE_OPERATION");
+
+pub const LOG_EVENT_STAKE_MEMO: LogEvent = LogEvent(Level::INFO, "STAKE_MEMO");
 
 pub const LOG_EVENT_TREASURY_DIVIDEND: LogEvent = LogEvent(Level::INFO, "TREASURY_DIVIDEND");
 pub const LOG_EVENT_TREASURY_DEPOSIT: LogEvent = LogEvent(Level::INFO, "TREASURY_DEPOSIT");
 pub const LOG_EVENT_EARNINGS: LogEvent = LogEvent(Level::INFO, "EARNINGS");
+pub const LOG_EVENT_COMPOUND: LogEvent = LogEvent(Level::INFO, "COMPOUND");
+pub const LOG_EVENT_EARNINGS_VESTED: LogEvent = LogEvent(Level::INFO, "EARNINGS_VESTED");
 
 pub const LOG_EVENT_LIQUIDITY: LogEvent = LogEvent(Level::INFO, "LIQUIDITY");
 
+/// log message format: `source={owner_earnings|treasury_deposit}, amount={amount}, total={total}`
+pub const LOG_EVENT_ROUNDING_DUST: LogEvent = LogEvent(Level::INFO, "ROUNDING_DUST");
+/// log message format: `amount={amount}, stake={stake}, remaining={remaining}`
+pub const LOG_EVENT_ROUNDING_DUST_SWEEP: LogEvent = LogEvent(Level::INFO, "ROUNDING_DUST_SWEEP");
+
 pub const ERR_STAKED_BALANCE_TOO_LOW_TO_UNSTAKE: ErrorConst =
     ErrorConst(ErrCode("STAKED_BALANCE_TOO_LOW_TO_UNSTAKE"), "");
+
+pub const ERR_STAKE_BELOW_MIN: ErrorConst = ErrorConst(
+    ErrCode("STAKE_BELOW_MIN"),
+    "attached deposit is less than the minimum stake - see `ops_stake_min_stake`",
+);
+
+/// guards against any stake path that would push the total staked balance over the stake cap -
+/// see [`crate::StakeCap`]
+pub const ERR_STAKE_CAP_EXCEEDED: ErrorConst = ErrorConst(
+    ErrCode("STAKE_CAP_EXCEEDED"),
+    "staking the NEAR would push the total staked balance over the stake cap",
+);
+
+pub const ERR_STAKE_SLIPPAGE: ErrorConst = ErrorConst(
+    ErrCode("STAKE_SLIPPAGE"),
+    "realized conversion is worse than the specified limit",
+);
+
+/// the receiver of an unstaked balance transfer has no lock slot available for the transferred
+/// unlock epoch - see [`StakingPool::ops_stake_transfer_unstaked`]
+pub const ERR_UNSTAKED_LOCK_SLOTS_FULL: ErrorConst = ErrorConst(
+    ErrCode("UNSTAKED_LOCK_SLOTS_FULL"),
+    "receiver has no unstaked balance lock slot available for the transferred unlock epoch",
+);
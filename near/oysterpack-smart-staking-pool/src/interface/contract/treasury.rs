@@ -1,7 +1,11 @@
-use crate::StakeAccountBalances;
-use oysterpack_smart_near::domain::YoctoNear;
+use crate::{
+    StakeAccountBalances, TreasuryBeneficiary, TreasuryDividendPolicy, TreasuryDonation,
+    TreasuryDonationTotals,
+};
+use oysterpack_smart_near::domain::{BasisPoints, YoctoNear};
 use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
 use oysterpack_smart_near::near_sdk::PromiseOrValue;
+use oysterpack_smart_near::{Level, LogEvent};
 
 /// # **Contract Interface**: Staking Pool Treasury API
 pub trait Treasury {
@@ -29,6 +33,8 @@ pub trait Treasury {
     /// ## Notes
     /// - An alternative way to make a distribution is to transfer STAKE to the treasury account
     ///   directly, i.e., via the [`oysterpack_smart_fungible_token::FungibleToken::ft_transfer`] API
+    /// - STAKE holders can donate STAKE to the treasury with attribution via
+    ///   `ft_transfer_call(receiver_id=<contract>, msg="treasury")` - see [`TREASURY_DEPOSIT_MSG`]
     /// - if no deposit is made, then any collected earnings will simply be staked
     ///
     /// `#[payable]`
@@ -48,19 +54,85 @@ pub trait Treasury {
     /// grants treasurer permission to specified account
     ///
     /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
     /// - if not invoked by admin
     /// - if specified account is not registered
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
     fn ops_stake_grant_treasurer(&mut self, account_id: ValidAccountId);
 
     /// revokes treasurer permission to specified account
     ///
     /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
     /// - if not invoked by admin
     /// - if specified account is not registered
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
     fn ops_stake_revoke_treasurer(&mut self, account_id: ValidAccountId);
 
     /// revokes treasurer permission to specified account
     fn ops_stake_is_treasurer(&mut self, account_id: ValidAccountId) -> bool;
+
+    /// Routes the specified share of the treasury dividends to the beneficiary account, which is
+    /// expected to be an external contract, e.g., a DAO or charity. Instead of burning the beneficiary
+    /// share of the dividend, the STAKE is transferred to the beneficiary via
+    /// [`oysterpack_smart_fungible_token::FungibleToken::ft_transfer_call`].
+    /// - setting `bps` to zero clears the beneficiary
+    /// - the dividend is paid out before the beneficiary is changed
+    ///
+    /// ## Notes
+    /// - if there is not enough prepaid gas to notify the beneficiary, then the STAKE is transferred
+    ///   without invoking `ft_on_transfer`
+    /// - if the beneficiary is no longer registered, then the full dividend is burned
+    /// - any STAKE that is refunded by the beneficiary is credited back to the treasury
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - if not invoked by the owner
+    /// - if `bps` is greater than 10000 (100%)
+    /// - if the beneficiary account is not registered or is the contract account
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_stake_treasury_set_beneficiary(&mut self, account_id: ValidAccountId, bps: BasisPoints);
+
+    fn ops_stake_treasury_beneficiary(&self) -> Option<TreasuryBeneficiary>;
+
+    /// Sets the policy that determines how much of the treasury staking earnings is paid out as a
+    /// dividend vs retained by the treasury to build reserves
+    /// - the dividend is paid out using the current policy before the policy is changed
+    /// - logs [`LOG_EVENT_TREASURY_DIVIDEND_POLICY`]
+    ///
+    /// ## Panics
+    /// - if 1 yoctoNEAR is not attached
+    /// - requires [`PERMISSION_TREASURER`] permission or the owner
+    /// - if [`TreasuryDividendPolicy::BurnPercent`] is greater than 10000 BPS (100%)
+    ///
+    /// `#[payable]` - requires exactly 1 yoctoNEAR to be attached
+    fn ops_stake_treasury_set_dividend_policy(&mut self, policy: TreasuryDividendPolicy);
+
+    /// defaults to [`TreasuryDividendPolicy::BurnAll`]
+    fn ops_stake_treasury_dividend_policy(&self) -> TreasuryDividendPolicy;
+
+    /// returns the most recent treasury donations, ordered from most recent to oldest
+    /// - `limit` defaults to all retained donations - see [`crate::TreasuryDonations::MAX_ENTRIES`]
+    fn ops_stake_treasury_donations(&self, limit: Option<u16>) -> Vec<TreasuryDonation>;
+
+    /// returns the totals for all treasury donations
+    fn ops_stake_treasury_donation_totals(&self) -> TreasuryDonationTotals;
 }
 
+/// `ft_transfer_call` message that donates the transferred STAKE to the treasury
+/// - an empty message is also accepted for backwards compatibility
+/// - STAKE that is transferred to the contract with any other message is refunded
+pub const TREASURY_DEPOSIT_MSG: &str = "treasury";
+
 pub const PERMISSION_TREASURER: &str = "treasurer";
+
+pub const LOG_EVENT_TREASURY_BENEFICIARY: LogEvent = LogEvent(Level::INFO, "TREASURY_BENEFICIARY");
+pub const LOG_EVENT_TREASURY_BENEFICIARY_PAYMENT: LogEvent =
+    LogEvent(Level::INFO, "TREASURY_BENEFICIARY_PAYMENT");
+pub const LOG_EVENT_TREASURY_DIVIDEND_POLICY: LogEvent =
+    LogEvent(Level::INFO, "TREASURY_DIVIDEND_POLICY");
+/// log message format: `donor={account_id}, amount={yoctoSTAKE}, near_value={yoctoNEAR}`
+pub const LOG_EVENT_TREASURY_DONATION: LogEvent = LogEvent(Level::INFO, "TREASURY_DONATION");
@@ -0,0 +1,56 @@
+use crate::{StakeAccountBalances, VestingSchedule};
+use oysterpack_smart_fungible_token::TokenAmount;
+use oysterpack_smart_near::domain::BlockTimestamp;
+use oysterpack_smart_near::near_sdk::json_types::ValidAccountId;
+use oysterpack_smart_near::near_sdk::PromiseOrValue;
+use oysterpack_smart_near::{ErrCode, ErrorConst, Level, LogEvent};
+
+/// # **Contract Interface**: STAKE Vesting API
+///
+/// Enables the contract operator or owner to distribute STAKE subject to a vesting schedule, e.g.,
+/// for team or treasury distributions.
+///
+/// The vesting STAKE is minted by staking the attached deposit on behalf of the beneficiary account.
+/// The STAKE vests linearly between the schedule's start and end timestamps, but nothing vests until
+/// the cliff is reached - see [`VestingSchedule`].
+///
+/// ## NOTES
+/// - unvested STAKE cannot be transferred, unstaked, or burned
+/// - STAKE earnings accrue on the total STAKE balance, including the unvested STAKE
+/// - the vesting schedule storage usage is charged to the account
+pub trait StakeVesting {
+    /// Stakes the attached deposit on behalf of the beneficiary account, and the minted STAKE is
+    /// subject to the specified vesting schedule
+    /// - if `start` is not specified, then vesting starts at the current block timestamp
+    /// - logs [`LOG_EVENT_STAKE_VESTING`]
+    ///
+    /// Returns the beneficiary's balances
+    ///
+    /// ## Panics
+    /// - if not invoked by the owner or an operator
+    /// - if the beneficiary account is not registered
+    /// - if no deposit is attached
+    /// - if the beneficiary has STAKE that is still vesting
+    /// - if the schedule is invalid, i.e., it must satisfy `start <= cliff <= end` and `start < end`
+    ///
+    /// `#[payable]`
+    fn ops_stake_vest(
+        &mut self,
+        account_id: ValidAccountId,
+        start: Option<BlockTimestamp>,
+        cliff: BlockTimestamp,
+        end: BlockTimestamp,
+    ) -> PromiseOrValue<StakeAccountBalances>;
+
+    fn ops_stake_vesting_schedule(&self, account_id: ValidAccountId) -> Option<VestingSchedule>;
+
+    /// returns the account's STAKE balance that has not yet vested
+    fn ops_stake_unvested_balance(&self, account_id: ValidAccountId) -> TokenAmount;
+}
+
+pub const LOG_EVENT_STAKE_VESTING: LogEvent = LogEvent(Level::INFO, "STAKE_VESTING");
+
+pub const ERR_STAKE_NOT_VESTED: ErrorConst = ErrorConst(
+    ErrCode("STAKE_NOT_VESTED"),
+    "unvested STAKE cannot be transferred, unstaked, or burned",
+);